@@ -0,0 +1,890 @@
+//! Request and response shapes for each LLM API the bot speaks, from
+//! `llm_providers.kind`. The conversation is kept in the OpenAI chat shape
+//! (`ChatMessage`) and translated per call, so tool use and structured
+//! output work the same way whichever provider answers.
+
+use anyhow::{Context, Result, anyhow};
+use serde_json::{Map, Value, json};
+
+use crate::structured::{Constraint, StructuredOutput};
+use crate::tools::{FunctionCall, ToolCall};
+use crate::{ChatMessage, ChatRequest, ChatResponse, ChatUsage, LlmReply, LlmTarget};
+
+/// Output tokens allowed on top of any thinking budget, for APIs that need
+/// a limit.
+const MAX_OUTPUT_TOKENS: u32 = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderKind {
+    /// OpenAI chat completions, and servers copying it (vLLM, DeepSeek,
+    /// OpenRouter).
+    OpenAi,
+    /// Anthropic Messages API.
+    Anthropic,
+    /// Ollama's native `/api/chat`.
+    Ollama,
+    /// Gemini `generateContent`.
+    Gemini,
+    /// llama.cpp server, through its OpenAI-compatible endpoint with its own
+    /// grammar and thinking fields.
+    LlamaCpp,
+}
+
+impl ProviderKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "openai" => Some(Self::OpenAi),
+            "anthropic" => Some(Self::Anthropic),
+            "ollama" => Some(Self::Ollama),
+            "gemini" => Some(Self::Gemini),
+            "llamacpp" => Some(Self::LlamaCpp),
+            _ => None,
+        }
+    }
+
+    /// The structured output the API can actually give for the configured
+    /// one. Ollama and Gemini only take JSON schemas, llama.cpp has no regex
+    /// support but takes the same grammar as GBNF, and Anthropic has no
+    /// grammar support at all, so its output is validated after the fact.
+    pub fn structured_output(
+        self,
+        configured: Option<StructuredOutput>,
+    ) -> Option<StructuredOutput> {
+        let configured = configured?;
+        match self {
+            Self::OpenAi => Some(configured),
+            Self::Anthropic => None,
+            Self::Ollama | Self::Gemini => Some(StructuredOutput::JsonSchema),
+            Self::LlamaCpp => Some(match configured {
+                StructuredOutput::Regex => StructuredOutput::Gbnf,
+                other => other,
+            }),
+        }
+    }
+}
+
+/// An HTTP call to a provider, before `extra_body` is merged in.
+#[derive(Debug)]
+pub struct HttpRequest {
+    pub url: String,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: Value,
+}
+
+/// Thinking tokens for a `reasoning_effort`, for APIs that take a budget
+/// rather than a level. `None` leaves the provider's default.
+fn thinking_budget(effort: Option<&str>) -> Option<u32> {
+    match effort? {
+        "none" => Some(0),
+        "minimal" | "low" => Some(1024),
+        "medium" => Some(4096),
+        "high" => Some(16384),
+        _ => None,
+    }
+}
+
+pub fn build_request(
+    target: &LlmTarget<'_>,
+    messages: &[ChatMessage],
+    constraint: Option<&Constraint>,
+    tools: Option<&[Value]>,
+    refuse_tools: bool,
+) -> Result<HttpRequest> {
+    let bearer = || {
+        target
+            .api_key
+            .map(|key| ("Authorization", format!("Bearer {}", key)))
+            .into_iter()
+            .collect()
+    };
+    Ok(match target.kind {
+        ProviderKind::OpenAi => HttpRequest {
+            url: format!("{}/v1/chat/completions", target.url),
+            headers: bearer(),
+            body: {
+                let mut body = chat_request(
+                    target,
+                    messages,
+                    tools,
+                    refuse_tools,
+                    target.reasoning_effort,
+                )?;
+                if let Some(c) = constraint {
+                    crate::merge_json_patch(&mut body, &c.request_fields());
+                }
+                body
+            },
+        },
+        ProviderKind::LlamaCpp => HttpRequest {
+            url: format!("{}/v1/chat/completions", target.url),
+            headers: bearer(),
+            body: {
+                let mut body = chat_request(target, messages, tools, refuse_tools, None)?;
+                if thinking_budget(target.reasoning_effort) == Some(0) {
+                    body["chat_template_kwargs"] = json!({ "enable_thinking": false });
+                }
+                match constraint {
+                    Some(c) if c.mode == StructuredOutput::JsonSchema => {
+                        body["json_schema"] = c.json_schema();
+                    }
+                    Some(c) => body["grammar"] = json!(c.gbnf()),
+                    None => {}
+                }
+                body
+            },
+        },
+        ProviderKind::Ollama => HttpRequest {
+            url: format!("{}/api/chat", target.url),
+            headers: bearer(),
+            body: ollama_request(target, messages, constraint, tools, refuse_tools),
+        },
+        ProviderKind::Anthropic => HttpRequest {
+            url: format!("{}/v1/messages", target.url),
+            headers: [
+                target.api_key.map(|key| ("x-api-key", key.to_string())),
+                Some(("anthropic-version", "2023-06-01".to_string())),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            body: anthropic_request(target, messages, tools, refuse_tools),
+        },
+        ProviderKind::Gemini => HttpRequest {
+            url: format!(
+                "{}/v1beta/models/{}:generateContent",
+                target.url, target.model
+            ),
+            headers: target
+                .api_key
+                .map(|key| ("x-goog-api-key", key.to_string()))
+                .into_iter()
+                .collect(),
+            body: gemini_request(target, messages, constraint, tools, refuse_tools),
+        },
+    })
+}
+
+pub fn parse_reply(kind: ProviderKind, body: Value) -> Result<LlmReply> {
+    match kind {
+        ProviderKind::OpenAi | ProviderKind::LlamaCpp => {
+            let chat_resp: ChatResponse =
+                serde_json::from_value(body).context("Failed to parse LLM response")?;
+            let message = chat_resp
+                .choices
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow!("LLM returned no choices"))?
+                .message;
+            Ok(LlmReply {
+                content: message.content,
+                tool_calls: message.tool_calls.unwrap_or_default(),
+                usage: chat_resp.usage.unwrap_or_default(),
+            })
+        }
+        ProviderKind::Ollama => ollama_reply(body),
+        ProviderKind::Anthropic => anthropic_reply(body),
+        ProviderKind::Gemini => gemini_reply(body),
+    }
+}
+
+fn chat_request(
+    target: &LlmTarget<'_>,
+    messages: &[ChatMessage],
+    tools: Option<&[Value]>,
+    refuse_tools: bool,
+    reasoning_effort: Option<&str>,
+) -> Result<Value> {
+    serde_json::to_value(ChatRequest {
+        model: target.model.to_string(),
+        messages: messages.to_vec(),
+        stream: false,
+        temperature: target.temperature,
+        reasoning_effort: reasoning_effort.map(str::to_string),
+        tools: tools.map(<[_]>::to_vec),
+        tool_choice: (tools.is_some() && refuse_tools).then(|| "none".to_string()),
+    })
+    .context("Failed to serialize LLM request body")
+}
+
+/// Tool call arguments as a JSON object, for APIs that don't take them as a
+/// string.
+fn arguments(call: &ToolCall) -> Value {
+    serde_json::from_str(&call.function.arguments).unwrap_or_else(|_| json!({}))
+}
+
+/// The name of the tool a `tool` message answers, for APIs that match
+/// results by name rather than id.
+fn tool_name<'a>(messages: &'a [ChatMessage], id: Option<&str>) -> &'a str {
+    messages
+        .iter()
+        .flat_map(|m| m.tool_calls.iter().flatten())
+        .find(|call| Some(call.id.as_str()) == id)
+        .map_or("", |call| call.function.name.as_str())
+}
+
+/// A tool call from an API that gives the arguments as an object.
+fn tool_call(id: String, name: &str, args: &Value) -> ToolCall {
+    ToolCall {
+        id,
+        kind: "function".to_string(),
+        function: FunctionCall {
+            name: name.to_string(),
+            arguments: args.to_string(),
+        },
+    }
+}
+
+/// The `function` halves of OpenAI-style tool definitions.
+fn functions(tools: &[Value]) -> impl Iterator<Item = &Value> {
+    tools.iter().filter_map(|t| t.get("function"))
+}
+
+fn usage(prompt_tokens: Option<&Value>, completion_tokens: Option<&Value>) -> ChatUsage {
+    ChatUsage {
+        prompt_tokens: prompt_tokens.and_then(Value::as_u64).unwrap_or_default(),
+        completion_tokens: completion_tokens
+            .and_then(Value::as_u64)
+            .unwrap_or_default(),
+    }
+}
+
+fn ollama_request(
+    target: &LlmTarget<'_>,
+    messages: &[ChatMessage],
+    constraint: Option<&Constraint>,
+    tools: Option<&[Value]>,
+    refuse_tools: bool,
+) -> Value {
+    let messages: Vec<Value> = messages
+        .iter()
+        .map(|m| {
+            let mut message = json!({ "role": m.role, "content": m.content });
+            if let Some(calls) = &m.tool_calls {
+                message["tool_calls"] = calls
+                    .iter()
+                    .map(|c| json!({ "function": { "name": c.function.name, "arguments": arguments(c) } }))
+                    .collect();
+            }
+            if m.role == "tool" {
+                message["tool_name"] = json!(tool_name(messages, m.tool_call_id.as_deref()));
+            }
+            message
+        })
+        .collect();
+    let mut body = json!({
+        "model": target.model,
+        "messages": messages,
+        "stream": false,
+        "options": { "temperature": target.temperature },
+    });
+    if let Some(budget) = thinking_budget(target.reasoning_effort) {
+        body["think"] = json!(budget > 0);
+    }
+    if let Some(c) = constraint {
+        body["format"] = c.json_schema();
+    }
+    // There's no tool_choice, so refusing tools means not offering them.
+    if let Some(tools) = tools.filter(|_| !refuse_tools) {
+        body["tools"] = json!(tools);
+    }
+    body
+}
+
+fn ollama_reply(body: Value) -> Result<LlmReply> {
+    let message = body
+        .get("message")
+        .ok_or_else(|| anyhow!("Ollama returned no message"))?;
+    let tool_calls = message
+        .get("tool_calls")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(i, c)| {
+            let function = &c["function"];
+            tool_call(
+                format!("call_{}", i),
+                function["name"].as_str().unwrap_or_default(),
+                &function["arguments"],
+            )
+        })
+        .collect();
+    Ok(LlmReply {
+        content: message["content"].as_str().map(str::to_string),
+        tool_calls,
+        usage: usage(body.get("prompt_eval_count"), body.get("eval_count")),
+    })
+}
+
+fn anthropic_request(
+    target: &LlmTarget<'_>,
+    messages: &[ChatMessage],
+    tools: Option<&[Value]>,
+    refuse_tools: bool,
+) -> Value {
+    let system: Vec<&str> = messages
+        .iter()
+        .filter(|m| m.role == "system")
+        .map(|m| m.content.as_str())
+        .collect();
+    let mut turns: Vec<Value> = vec![];
+    for m in messages.iter().filter(|m| m.role != "system") {
+        let (role, blocks) = match m.role.as_str() {
+            "tool" => (
+                "user",
+                vec![json!({
+                    "type": "tool_result",
+                    "tool_use_id": m.tool_call_id,
+                    "content": m.content,
+                })],
+            ),
+            "assistant" => (
+                "assistant",
+                (!m.content.is_empty())
+                    .then(|| json!({ "type": "text", "text": m.content }))
+                    .into_iter()
+                    .chain(m.tool_calls.iter().flatten().map(|c| {
+                        json!({
+                            "type": "tool_use",
+                            "id": c.id,
+                            "name": c.function.name,
+                            "input": arguments(c),
+                        })
+                    }))
+                    .collect(),
+            ),
+            _ => ("user", vec![json!({ "type": "text", "text": m.content })]),
+        };
+        // Roles must alternate, so tool results and follow-ups share a turn.
+        match turns.last_mut() {
+            Some(last) if last["role"] == role => {
+                if let Some(content) = last["content"].as_array_mut() {
+                    content.extend(blocks);
+                }
+            }
+            _ => turns.push(json!({ "role": role, "content": blocks })),
+        }
+    }
+    let mut body = json!({
+        "model": target.model,
+        "max_tokens": MAX_OUTPUT_TOKENS,
+        "messages": turns,
+        "temperature": target.temperature,
+    });
+    if !system.is_empty() {
+        body["system"] = json!(system.join("\n\n"));
+    }
+    // Thinking can't be combined with a set temperature, and after tool use
+    // it needs the earlier thinking blocks sent back, which aren't kept.
+    let used_tools = messages.iter().any(|m| m.tool_calls.is_some());
+    if let Some(budget) = thinking_budget(target.reasoning_effort).filter(|&b| b > 0)
+        && !used_tools
+    {
+        body["thinking"] = json!({ "type": "enabled", "budget_tokens": budget });
+        body["max_tokens"] = json!(MAX_OUTPUT_TOKENS + budget);
+        if let Some(body) = body.as_object_mut() {
+            body.remove("temperature");
+        }
+    }
+    if let Some(tools) = tools {
+        body["tools"] = functions(tools)
+            .map(|f| {
+                json!({
+                    "name": f["name"],
+                    "description": f["description"],
+                    "input_schema": f["parameters"],
+                })
+            })
+            .collect();
+        if refuse_tools {
+            body["tool_choice"] = json!({ "type": "none" });
+        }
+    }
+    body
+}
+
+fn anthropic_reply(body: Value) -> Result<LlmReply> {
+    let blocks = body
+        .get("content")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("Anthropic returned no content"))?;
+    let text: Vec<&str> = blocks
+        .iter()
+        .filter(|b| b["type"] == "text")
+        .filter_map(|b| b["text"].as_str())
+        .collect();
+    let tool_calls = blocks
+        .iter()
+        .filter(|b| b["type"] == "tool_use")
+        .map(|b| {
+            tool_call(
+                b["id"].as_str().unwrap_or_default().to_string(),
+                b["name"].as_str().unwrap_or_default(),
+                &b["input"],
+            )
+        })
+        .collect();
+    Ok(LlmReply {
+        content: (!text.is_empty()).then(|| text.concat()),
+        tool_calls,
+        usage: usage(
+            body.pointer("/usage/input_tokens"),
+            body.pointer("/usage/output_tokens"),
+        ),
+    })
+}
+
+fn gemini_request(
+    target: &LlmTarget<'_>,
+    messages: &[ChatMessage],
+    constraint: Option<&Constraint>,
+    tools: Option<&[Value]>,
+    refuse_tools: bool,
+) -> Value {
+    let system: Vec<Value> = messages
+        .iter()
+        .filter(|m| m.role == "system")
+        .map(|m| json!({ "text": m.content }))
+        .collect();
+    let mut contents: Vec<Value> = vec![];
+    for m in messages.iter().filter(|m| m.role != "system") {
+        let (role, parts) = match m.role.as_str() {
+            "tool" => (
+                "user",
+                vec![json!({
+                    "functionResponse": {
+                        "name": tool_name(messages, m.tool_call_id.as_deref()),
+                        "response": { "result": m.content },
+                    },
+                })],
+            ),
+            "assistant" => (
+                "model",
+                (!m.content.is_empty())
+                    .then(|| json!({ "text": m.content }))
+                    .into_iter()
+                    .chain(m.tool_calls.iter().flatten().map(|c| {
+                        json!({ "functionCall": { "name": c.function.name, "args": arguments(c) } })
+                    }))
+                    .collect(),
+            ),
+            _ => ("user", vec![json!({ "text": m.content })]),
+        };
+        // Answers to parallel calls go back together in one turn.
+        match contents.last_mut() {
+            Some(last)
+                if m.role == "tool" && last["parts"][0].get("functionResponse").is_some() =>
+            {
+                if let Some(existing) = last["parts"].as_array_mut() {
+                    existing.extend(parts);
+                }
+            }
+            _ => contents.push(json!({ "role": role, "parts": parts })),
+        }
+    }
+    let mut config = Map::new();
+    config.insert("temperature".to_string(), json!(target.temperature));
+    if let Some(budget) = thinking_budget(target.reasoning_effort) {
+        config.insert(
+            "thinkingConfig".to_string(),
+            json!({ "thinkingBudget": budget }),
+        );
+    }
+    if let Some(c) = constraint {
+        config.insert("responseMimeType".to_string(), json!("application/json"));
+        config.insert("responseJsonSchema".to_string(), c.json_schema());
+    }
+    let mut body = json!({ "contents": contents, "generationConfig": config });
+    if !system.is_empty() {
+        body["systemInstruction"] = json!({ "parts": system });
+    }
+    if let Some(tools) = tools {
+        body["tools"] = json!([{
+            "functionDeclarations": functions(tools).collect::<Vec<_>>(),
+        }]);
+        if refuse_tools {
+            body["toolConfig"] = json!({ "functionCallingConfig": { "mode": "NONE" } });
+        }
+    }
+    body
+}
+
+fn gemini_reply(body: Value) -> Result<LlmReply> {
+    let parts = body
+        .pointer("/candidates/0/content/parts")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("Gemini returned no candidates"))?;
+    // Thought summaries come back as text parts marked `thought`.
+    let text: Vec<&str> = parts
+        .iter()
+        .filter(|p| p["thought"] != true)
+        .filter_map(|p| p["text"].as_str())
+        .collect();
+    let tool_calls = parts
+        .iter()
+        .filter_map(|p| p.get("functionCall"))
+        .enumerate()
+        .map(|(i, c)| {
+            tool_call(
+                format!("call_{}", i),
+                c["name"].as_str().unwrap_or_default(),
+                &c["args"],
+            )
+        })
+        .collect();
+    Ok(LlmReply {
+        content: (!text.is_empty()).then(|| text.concat()),
+        tool_calls,
+        usage: usage(
+            body.pointer("/usageMetadata/promptTokenCount"),
+            body.pointer("/usageMetadata/candidatesTokenCount"),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brdgme_game::command::Spec;
+    use std::sync::{Arc, Mutex};
+
+    /// A request the mock provider saw.
+    #[derive(Debug, Clone)]
+    struct Seen {
+        path: String,
+        headers: axum::http::HeaderMap,
+        body: Value,
+    }
+
+    /// Answers every request with `reply`, recording what was sent.
+    async fn mock_provider(reply: Value) -> (String, Arc<Mutex<Vec<Seen>>>) {
+        use axum::Json;
+        use axum::http::{HeaderMap, Uri};
+        let seen = Arc::new(Mutex::new(vec![]));
+        let recorded = seen.clone();
+        let app = axum::Router::new().fallback(
+            move |uri: Uri, headers: HeaderMap, Json(body): Json<Value>| {
+                let recorded = recorded.clone();
+                let reply = reply.clone();
+                async move {
+                    recorded.lock().unwrap().push(Seen {
+                        path: uri.path().to_string(),
+                        headers,
+                        body,
+                    });
+                    Json(reply)
+                }
+            },
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (url, seen)
+    }
+
+    fn target<'a>(
+        http: &'a reqwest::Client,
+        kind: ProviderKind,
+        url: &'a str,
+        reasoning_effort: Option<&'a str>,
+    ) -> LlmTarget<'a> {
+        LlmTarget {
+            http,
+            kind,
+            url,
+            model: "m",
+            api_key: Some("secret"),
+            temperature: 0.3,
+            reasoning_effort,
+            extra_body: None,
+        }
+    }
+
+    fn constraint(mode: StructuredOutput) -> Constraint {
+        Constraint {
+            mode,
+            grammar: Spec::Token("call".into()).grammar(&[]).unwrap(),
+            notes: false,
+            reason: false,
+        }
+    }
+
+    /// A conversation that has been through one round of tool use.
+    fn after_tool_use() -> Vec<ChatMessage> {
+        let call = |id: &str, name: &str, args: &str| ToolCall {
+            id: id.into(),
+            kind: "function".into(),
+            function: FunctionCall {
+                name: name.into(),
+                arguments: args.into(),
+            },
+        };
+        vec![
+            ChatMessage::new("system", "rules".into()),
+            ChatMessage::new("user", "your turn".into()),
+            ChatMessage {
+                tool_calls: Some(vec![
+                    call("a", "suggest", r#"{"partial_command": ""}"#),
+                    call("b", "rules_section", r#"{"name": "End"}"#),
+                ]),
+                ..ChatMessage::new("assistant", String::new())
+            },
+            ChatMessage {
+                tool_call_id: Some("a".into()),
+                ..ChatMessage::new("tool", "- call".into())
+            },
+            ChatMessage {
+                tool_call_id: Some("b".into()),
+                ..ChatMessage::new("tool", "## End".into())
+            },
+        ]
+    }
+
+    #[test]
+    fn parses_kinds_and_maps_structured_output() {
+        assert_eq!(
+            Some(ProviderKind::LlamaCpp),
+            ProviderKind::parse("llamacpp")
+        );
+        assert_eq!(None, ProviderKind::parse("OpenAI"));
+        let regex = Some(StructuredOutput::Regex);
+        assert_eq!(regex, ProviderKind::OpenAi.structured_output(regex));
+        assert_eq!(None, ProviderKind::Anthropic.structured_output(regex));
+        assert_eq!(
+            Some(StructuredOutput::JsonSchema),
+            ProviderKind::Ollama.structured_output(regex)
+        );
+        assert_eq!(
+            Some(StructuredOutput::Gbnf),
+            ProviderKind::LlamaCpp.structured_output(regex)
+        );
+        assert_eq!(None, ProviderKind::Gemini.structured_output(None));
+    }
+
+    #[tokio::test]
+    async fn openai_sends_chat_completions() {
+        let (url, seen) = mock_provider(json!({
+            "choices": [{"message": {"content": "call"}}],
+            "usage": {"prompt_tokens": 7, "completion_tokens": 2},
+        }))
+        .await;
+        let http = reqwest::Client::new();
+        let c = constraint(StructuredOutput::Regex);
+        let reply = crate::call_llm(
+            &target(&http, ProviderKind::OpenAi, &url, Some("low")),
+            &[ChatMessage::new("user", "go".into())],
+            Some(&c),
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(Some("call".to_string()), reply.content);
+        assert_eq!(7, reply.usage.prompt_tokens);
+        let seen = seen.lock().unwrap();
+        assert_eq!("/v1/chat/completions", seen[0].path);
+        assert_eq!("Bearer secret", seen[0].headers["authorization"]);
+        assert_eq!(json!("low"), seen[0].body["reasoning_effort"]);
+        assert_eq!(json!(c.regex()), seen[0].body["guided_regex"]);
+    }
+
+    #[tokio::test]
+    async fn llamacpp_maps_thinking_and_grammar() {
+        let (url, seen) = mock_provider(json!({
+            "choices": [{"message": {"content": "call"}}],
+        }))
+        .await;
+        let http = reqwest::Client::new();
+        let c = constraint(StructuredOutput::Gbnf);
+        crate::call_llm(
+            &target(&http, ProviderKind::LlamaCpp, &url, Some("none")),
+            &[ChatMessage::new("user", "go".into())],
+            Some(&c),
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+        let seen = seen.lock().unwrap();
+        let body = &seen[0].body;
+        assert_eq!("/v1/chat/completions", seen[0].path);
+        assert_eq!(None, body.get("reasoning_effort"));
+        assert_eq!(
+            json!({"enable_thinking": false}),
+            body["chat_template_kwargs"]
+        );
+        assert_eq!(json!(c.gbnf()), body["grammar"]);
+    }
+
+    #[tokio::test]
+    async fn ollama_speaks_native_chat() {
+        let (url, seen) = mock_provider(json!({
+            "message": {
+                "role": "assistant",
+                "content": "",
+                "tool_calls": [{"function": {"name": "preview", "arguments": {"command": "call"}}}],
+            },
+            "prompt_eval_count": 11,
+            "eval_count": 3,
+        }))
+        .await;
+        let http = reqwest::Client::new();
+        let tools = crate::tools::definitions();
+        let c = constraint(StructuredOutput::JsonSchema);
+        let reply = crate::call_llm(
+            &target(&http, ProviderKind::Ollama, &url, Some("high")),
+            &after_tool_use(),
+            Some(&c),
+            Some(&tools),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(1, reply.tool_calls.len());
+        assert_eq!("preview", reply.tool_calls[0].function.name);
+        assert_eq!(
+            json!({"command": "call"}),
+            serde_json::from_str::<Value>(&reply.tool_calls[0].function.arguments).unwrap()
+        );
+        assert_eq!(11, reply.usage.prompt_tokens);
+        assert_eq!(3, reply.usage.completion_tokens);
+        let seen = seen.lock().unwrap();
+        let body = &seen[0].body;
+        assert_eq!("/api/chat", seen[0].path);
+        assert!((body["options"]["temperature"].as_f64().unwrap() - 0.3).abs() < 1e-6);
+        assert_eq!(json!(true), body["think"]);
+        assert_eq!(c.json_schema(), body["format"]);
+        assert_eq!(json!(3), json!(body["tools"].as_array().unwrap().len()));
+        let messages = body["messages"].as_array().unwrap();
+        assert_eq!(
+            json!({"partial_command": ""}),
+            messages[2]["tool_calls"][0]["function"]["arguments"]
+        );
+        assert_eq!(json!("rules_section"), messages[4]["tool_name"]);
+    }
+
+    #[tokio::test]
+    async fn anthropic_speaks_messages() {
+        let (url, seen) = mock_provider(json!({
+            "content": [
+                {"type": "thinking", "thinking": "hmm"},
+                {"type": "text", "text": "call"},
+            ],
+            "usage": {"input_tokens": 20, "output_tokens": 4},
+        }))
+        .await;
+        let http = reqwest::Client::new();
+        let tools = crate::tools::definitions();
+        let reply = crate::call_llm(
+            &target(&http, ProviderKind::Anthropic, &url, Some("medium")),
+            &after_tool_use(),
+            None,
+            Some(&tools),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(Some("call".to_string()), reply.content);
+        assert_eq!(20, reply.usage.prompt_tokens);
+        assert_eq!(4, reply.usage.completion_tokens);
+        let seen = seen.lock().unwrap();
+        let body = &seen[0].body;
+        assert_eq!("/v1/messages", seen[0].path);
+        assert_eq!("secret", seen[0].headers["x-api-key"]);
+        assert!(seen[0].headers.get("authorization").is_none());
+        assert_eq!(json!("rules"), body["system"]);
+        assert_eq!(json!({"type": "none"}), body["tool_choice"]);
+        assert_eq!(json!("suggest"), body["tools"][0]["name"]);
+        assert!(body["tools"][0]["input_schema"].is_object());
+        // Thinking blocks from earlier tool use aren't kept, so it's off.
+        assert_eq!(None, body.get("thinking"));
+        let messages = body["messages"].as_array().unwrap();
+        assert_eq!(3, messages.len());
+        assert_eq!(json!("tool_use"), messages[1]["content"][0]["type"]);
+        assert_eq!(json!({"name": "End"}), messages[1]["content"][1]["input"]);
+        assert_eq!(json!("user"), messages[2]["role"]);
+        assert_eq!(json!("b"), messages[2]["content"][1]["tool_use_id"]);
+    }
+
+    #[test]
+    fn anthropic_thinking_replaces_temperature() {
+        let http = reqwest::Client::new();
+        let request = build_request(
+            &target(&http, ProviderKind::Anthropic, "http://x", Some("medium")),
+            &[ChatMessage::new("user", "go".into())],
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            json!({"type": "enabled", "budget_tokens": 4096}),
+            request.body["thinking"]
+        );
+        assert_eq!(json!(MAX_OUTPUT_TOKENS + 4096), request.body["max_tokens"]);
+        assert_eq!(None, request.body.get("temperature"));
+    }
+
+    #[tokio::test]
+    async fn gemini_speaks_generate_content() {
+        let (url, seen) = mock_provider(json!({
+            "candidates": [{"content": {"role": "model", "parts": [
+                {"text": "thinking it over", "thought": true},
+                {"text": "{\"command\": \"call\"}"},
+            ]}}],
+            "usageMetadata": {"promptTokenCount": 30, "candidatesTokenCount": 6},
+        }))
+        .await;
+        let http = reqwest::Client::new();
+        let tools = crate::tools::definitions();
+        let c = constraint(StructuredOutput::JsonSchema);
+        let reply = crate::call_llm(
+            &target(&http, ProviderKind::Gemini, &url, Some("none")),
+            &after_tool_use(),
+            Some(&c),
+            Some(&tools),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(Some("{\"command\": \"call\"}".to_string()), reply.content);
+        assert_eq!(30, reply.usage.prompt_tokens);
+        assert_eq!(6, reply.usage.completion_tokens);
+        let seen = seen.lock().unwrap();
+        let body = &seen[0].body;
+        assert_eq!("/v1beta/models/m:generateContent", seen[0].path);
+        assert_eq!("secret", seen[0].headers["x-goog-api-key"]);
+        assert_eq!(
+            json!("rules"),
+            body["systemInstruction"]["parts"][0]["text"]
+        );
+        let config = &body["generationConfig"];
+        assert_eq!(json!(0), config["thinkingConfig"]["thinkingBudget"]);
+        assert_eq!(json!("application/json"), config["responseMimeType"]);
+        assert_eq!(c.json_schema(), config["responseJsonSchema"]);
+        assert_eq!(
+            json!("NONE"),
+            body["toolConfig"]["functionCallingConfig"]["mode"]
+        );
+        assert_eq!(
+            json!("suggest"),
+            body["tools"][0]["functionDeclarations"][0]["name"]
+        );
+        let contents = body["contents"].as_array().unwrap();
+        assert_eq!(3, contents.len());
+        assert_eq!(json!("model"), contents[1]["role"]);
+        assert_eq!(
+            json!("rules_section"),
+            contents[1]["parts"][1]["functionCall"]["name"]
+        );
+        assert_eq!(
+            json!("rules_section"),
+            contents[2]["parts"][1]["functionResponse"]["name"]
+        );
+    }
+}
@@ -0,0 +1,260 @@
+//! `bot arena`: plays one turn for `brdgme_arena` the way a live turn is
+//! played. It reads a `bot_cli::LlmRequest` on stdin, renders the bot's
+//! prompt, calls the provider set by the `LLM_*` env vars and resolves the
+//! reply to a command, then writes it as a JSON list of `BotCommand`s.
+//!
+//! There is no database here, so no logs, notes or strategy docs go in the
+//! prompt, and tools are off as in `bot eval`.
+
+use std::io::Read;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use brdgme_cmd::bot_cli::LlmRequest;
+use brdgme_game::bot::BotCommand;
+
+use crate::config::{BotConfig, ProviderConfig};
+use crate::eval::Templates;
+use crate::prompt::FailedCommand;
+use crate::{BotContext, LlmTarget, MENU_LIMIT};
+
+pub const USAGE: &str = "usage: bot arena [--system-template PATH] [--user-template PATH] \
+[--model MODEL] [--temperature T] < TURN
+
+Plays a brdgme_arena turn against the provider set by LLM_URL, BOT_MODEL and
+the other LLM_* env vars.";
+
+/// The temperature without `--temperature`, the bots table's default.
+const DEFAULT_TEMPERATURE: f32 = 0.2;
+
+#[derive(Debug, PartialEq)]
+struct Opts {
+    templates: Templates,
+    model: Option<String>,
+    temperature: f32,
+}
+
+fn parse_opts(args: &[String]) -> Result<Opts, String> {
+    let mut opts = Opts {
+        templates: Templates::default(),
+        model: None,
+        temperature: DEFAULT_TEMPERATURE,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("{} requires a value", arg))
+        };
+        match arg.as_str() {
+            "--system-template" => {
+                opts.templates.system = Some(crate::eval::read_template(&value()?)?)
+            }
+            "--user-template" => opts.templates.user = Some(crate::eval::read_template(&value()?)?),
+            "--model" => opts.model = Some(value()?),
+            "--temperature" => {
+                let raw = value()?;
+                opts.temperature = raw
+                    .parse()
+                    .map_err(|_| format!("invalid --temperature '{}'", raw))?;
+            }
+            other => return Err(format!("unknown option '{}'", other)),
+        }
+    }
+    Ok(opts)
+}
+
+fn json_to_yaml(json: &str) -> Result<String> {
+    let value: serde_json::Value = serde_json::from_str(json).context("state is not JSON")?;
+    serde_yaml_ng::to_string(&value).context("Failed to convert state to YAML")
+}
+
+/// Plays `turn` through the live bot's prompt, provider request and answer
+/// parsing, returning the command it resolves to.
+async fn play(
+    http: &reqwest::Client,
+    provider: &ProviderConfig,
+    templates: &Templates,
+    temperature: f32,
+    turn: &LlmRequest,
+) -> Result<String> {
+    let request = &turn.request;
+    let names = &request.players;
+    let bot_cfg = BotConfig {
+        id: None,
+        name: "arena".to_string(),
+        include_basic_strategy: false,
+        include_advanced_strategy: false,
+        temperature,
+        tool_steps: 0,
+        memory_chars: 0,
+        explain_moves: false,
+        budget: Default::default(),
+    };
+    let bot_ctx = BotContext {
+        game_state: String::new(),
+        game_data: brdgme_game_client::GameData {
+            pub_state_yaml: json_to_yaml(&turn.pub_state)?,
+            player_state_yaml: json_to_yaml(&request.player_state)?,
+            data_docs: String::new(),
+            basic_strategy: String::new(),
+            advanced_strategy: String::new(),
+            command_spec: Some(request.command_spec.clone()),
+            rules: turn.rules.clone(),
+        },
+        recent_logs: vec![],
+        notes: String::new(),
+    };
+    let menu = request
+        .command_spec
+        .enumerate(names, MENU_LIMIT)
+        .unwrap_or_default();
+    let constraint = crate::output_constraint(
+        provider,
+        Some(&request.command_spec),
+        names,
+        &menu,
+        false,
+        false,
+    );
+    let failed_commands = turn
+        .rejected
+        .iter()
+        .map(|r| FailedCommand {
+            command: r.command.clone(),
+            error: r.error.clone(),
+        })
+        .collect();
+    let (system_ctx, user_ctx) = crate::build_contexts(
+        &bot_cfg,
+        &bot_ctx,
+        names,
+        request.player,
+        &bot_cfg.name,
+        &menu,
+        failed_commands,
+    );
+    let messages = templates.render(&system_ctx, &user_ctx)?;
+    let target = LlmTarget {
+        http,
+        kind: provider.kind,
+        url: &provider.url,
+        model: &provider.model,
+        api_key: provider.api_key.as_deref(),
+        temperature,
+        reasoning_effort: provider.reasoning_effort.as_deref(),
+        extra_body: provider.extra_body.as_ref(),
+    };
+    let reply = crate::call_llm(&target, &messages, constraint.as_ref(), None, true).await?;
+    let answer = crate::answer_from(reply.content, constraint.as_ref())?;
+    Ok(crate::resolve_menu_choice(&answer.command, &menu))
+}
+
+/// Runs `bot arena` with the arguments after `arena`.
+pub async fn main(args: &[String]) -> Result<()> {
+    let opts = parse_opts(args).map_err(|message| anyhow!("{}\n{}", message, USAGE))?;
+    let mut provider = crate::config::env_fallback_provider()
+        .ok_or_else(|| anyhow!("LLM_URL and BOT_MODEL must be set\n{}", USAGE))?;
+    if let Some(model) = opts.model {
+        provider.model = model;
+    }
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .context("Failed to read the turn from stdin")?;
+    let turn: LlmRequest = serde_json::from_str(&input).context("Invalid arena turn")?;
+    let http = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(300))
+        .build()
+        .context("Failed to build HTTP client")?;
+    let command = play(&http, &provider, &opts.templates, opts.temperature, &turn).await?;
+    println!(
+        "{}",
+        serde_json::to_string(&[BotCommand {
+            quality: u8::MAX,
+            commands: vec![command],
+        }])
+        .context("Failed to encode command")?
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::tests::mock_provider;
+    use brdgme_cmd::bot_cli::{Rejection, Request};
+    use brdgme_game::command::Spec;
+
+    fn turn() -> LlmRequest {
+        LlmRequest {
+            request: Request {
+                player: 1,
+                player_state: r#"{"chips":4}"#.to_string(),
+                players: vec!["Alice".to_string(), "Bob".to_string()],
+                command_spec: Spec::OneOf(vec![
+                    Spec::Token("take".to_string()),
+                    Spec::Token("pass".to_string()),
+                ]),
+                game_id: None,
+            },
+            pub_state: r#"{"card":30}"#.to_string(),
+            rules: "Take cards or pay chips.".to_string(),
+            rejected: vec![Rejection {
+                command: "take it".to_string(),
+                error: "unexpected input: it".to_string(),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn play_prompts_like_a_live_turn_and_resolves_the_menu() {
+        let (provider, seen) = mock_provider(vec!["2."]).await;
+        let command = play(
+            &reqwest::Client::new(),
+            &provider,
+            &Templates::default(),
+            0.2,
+            &turn(),
+        )
+        .await
+        .unwrap();
+        assert_eq!("pass", command);
+
+        let seen = seen.lock().unwrap();
+        let body = serde_json::to_string(&seen[0]).unwrap();
+        for expected in [
+            "Take cards or pay chips.",
+            "card: 30",
+            "chips: 4",
+            "Bob",
+            "take it",
+            "unexpected input: it",
+        ] {
+            assert!(
+                body.contains(expected),
+                "{} missing from {}",
+                expected,
+                body
+            );
+        }
+    }
+
+    #[test]
+    fn parse_opts_reads_overrides() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            Opts {
+                templates: Templates::default(),
+                model: Some("other".to_string()),
+                temperature: 0.1,
+            },
+            parse_opts(&args(&["--model", "other", "--temperature", "0.1"])).unwrap()
+        );
+        assert!(parse_opts(&args(&["--temperature", "warm"])).is_err());
+        assert!(parse_opts(&args(&["--model"])).is_err());
+        assert!(parse_opts(&args(&["corpus.jsonl"])).is_err());
+    }
+}
@@ -0,0 +1,276 @@
+//! Spend accounting for LLM bots. Each conversation with a provider records
+//! its tokens and cost in `bot_llm_usage`, priced by the bot-provider link.
+//! Bots and providers may cap their spend per UTC day and calendar month: a
+//! provider over budget is skipped for the turn, and a bot left without an
+//! affordable provider plays random moves instead.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+
+use crate::TokenUsage;
+use crate::config::ProviderConfig;
+
+/// A daily and monthly spend cap in US dollars, either of which may be
+/// unset.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Budget {
+    pub daily_usd: Option<f64>,
+    pub monthly_usd: Option<f64>,
+}
+
+impl Budget {
+    pub fn is_set(&self) -> bool {
+        self.daily_usd.is_some() || self.monthly_usd.is_some()
+    }
+
+    pub fn exhausted(&self, spent: Spend) -> bool {
+        self.daily_usd.is_some_and(|cap| spent.today_usd >= cap)
+            || self.monthly_usd.is_some_and(|cap| spent.month_usd >= cap)
+    }
+}
+
+/// Spend so far in the current UTC day and calendar month.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Spend {
+    pub today_usd: f64,
+    pub month_usd: f64,
+}
+
+/// What `usage` cost on `provider`.
+pub fn cost_usd(provider: &ProviderConfig, usage: &TokenUsage) -> f64 {
+    (usage.prompt_tokens as f64 * provider.input_usd_per_mtok
+        + usage.completion_tokens as f64 * provider.output_usd_per_mtok)
+        / 1_000_000.0
+}
+
+/// One conversation's usage, as recorded in `bot_llm_usage`.
+pub struct UsageRecord<'a> {
+    pub turn_id: Uuid,
+    pub bot_id: Option<Uuid>,
+    pub bot_name: &'a str,
+    pub provider_id: Option<Uuid>,
+    pub model: &'a str,
+    pub game_id: Uuid,
+    pub usage: TokenUsage,
+    pub cost_usd: f64,
+}
+
+pub async fn record(pool: &PgPool, record: &UsageRecord<'_>) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO bot_llm_usage \
+         (turn_id, bot_id, bot_name, provider_id, model, game_id, llm_calls, prompt_tokens, \
+          completion_tokens, cost_usd) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+    )
+    .bind(record.turn_id)
+    .bind(record.bot_id)
+    .bind(record.bot_name)
+    .bind(record.provider_id)
+    .bind(record.model)
+    .bind(record.game_id)
+    .bind(record.usage.llm_calls as i32)
+    .bind(record.usage.prompt_tokens as i64)
+    .bind(record.usage.completion_tokens as i64)
+    .bind(record.cost_usd)
+    .execute(pool)
+    .await
+    .context("Failed to record LLM usage")?;
+    Ok(())
+}
+
+/// Sums this UTC day and calendar month's spend for rows matching `$1` on
+/// `column`.
+macro_rules! spend_query {
+    ($column:literal) => {
+        concat!(
+            "SELECT \
+               COALESCE(SUM(cost_usd) FILTER ( \
+                 WHERE created_at >= date_trunc('day', now() AT TIME ZONE 'utc')), 0) AS today_usd, \
+               COALESCE(SUM(cost_usd), 0) AS month_usd \
+             FROM bot_llm_usage \
+             WHERE ",
+            $column,
+            " = $1 AND created_at >= date_trunc('month', now() AT TIME ZONE 'utc')"
+        )
+    };
+}
+
+pub async fn bot_spend(pool: &PgPool, bot_id: Uuid) -> Result<Spend> {
+    spend(pool, spend_query!("bot_id"), bot_id).await
+}
+
+pub async fn provider_spend(pool: &PgPool, provider_id: Uuid) -> Result<Spend> {
+    spend(pool, spend_query!("provider_id"), provider_id).await
+}
+
+async fn spend(pool: &PgPool, query: &'static str, id: Uuid) -> Result<Spend> {
+    let row = sqlx::query(query)
+        .bind(id)
+        .fetch_one(pool)
+        .await
+        .context("Failed to load LLM spend")?;
+    Ok(Spend {
+        today_usd: row.try_get("today_usd").context("today_usd")?,
+        month_usd: row.try_get("month_usd").context("month_usd")?,
+    })
+}
+
+/// The providers still within their budgets, in the same order. Links to
+/// the same provider share its budget.
+pub async fn affordable(
+    pool: &PgPool,
+    providers: Vec<ProviderConfig>,
+) -> Result<Vec<ProviderConfig>> {
+    let mut exhausted: HashMap<Uuid, bool> = HashMap::new();
+    let mut kept = Vec::with_capacity(providers.len());
+    for provider in providers {
+        if let Some(id) = provider.provider_id.filter(|_| provider.budget.is_set()) {
+            let over = match exhausted.get(&id) {
+                Some(&over) => over,
+                None => {
+                    let over = provider.budget.exhausted(provider_spend(pool, id).await?);
+                    exhausted.insert(id, over);
+                    over
+                }
+            };
+            if over {
+                tracing::info!(provider_url = %provider.url, model = %provider.model, "provider_over_budget");
+                continue;
+            }
+        }
+        kept.push(provider);
+    }
+    Ok(kept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ProviderKind;
+
+    fn provider(input: f64, output: f64) -> ProviderConfig {
+        ProviderConfig {
+            kind: ProviderKind::OpenAi,
+            provider_id: None,
+            url: "http://unused".to_string(),
+            api_key: None,
+            model: "m".to_string(),
+            reasoning_effort: None,
+            extra_body: None,
+            structured_output: None,
+            priority: 0,
+            weight: 1,
+            input_usd_per_mtok: input,
+            output_usd_per_mtok: output,
+            budget: Budget::default(),
+        }
+    }
+
+    #[test]
+    fn cost_prices_prompt_and_completion_tokens_separately() {
+        let usage = TokenUsage {
+            llm_calls: 2,
+            prompt_tokens: 2_000_000,
+            completion_tokens: 500_000,
+        };
+        assert_eq!(7.0, cost_usd(&provider(3.0, 2.0), &usage));
+        assert_eq!(0.0, cost_usd(&provider(0.0, 0.0), &usage));
+    }
+
+    #[test]
+    fn budget_is_exhausted_by_either_cap() {
+        let spent = Spend {
+            today_usd: 2.0,
+            month_usd: 30.0,
+        };
+        assert!(!Budget::default().exhausted(spent));
+        assert!(
+            Budget {
+                daily_usd: Some(2.0),
+                monthly_usd: None,
+            }
+            .exhausted(spent)
+        );
+        assert!(
+            Budget {
+                daily_usd: Some(5.0),
+                monthly_usd: Some(25.0),
+            }
+            .exhausted(spent)
+        );
+        assert!(
+            !Budget {
+                daily_usd: Some(5.0),
+                monthly_usd: Some(50.0),
+            }
+            .exhausted(spent)
+        );
+    }
+
+    #[sqlx::test(migrations = "../web/migrations")]
+    async fn affordable_skips_providers_over_budget(pool: PgPool) {
+        let cheap: Uuid = sqlx::query_scalar(
+            "INSERT INTO llm_providers (name, url, daily_budget_usd) \
+             VALUES ('cheap', 'http://cheap', 1.0) RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let pricey: Uuid = sqlx::query_scalar(
+            "INSERT INTO llm_providers (name, url, monthly_budget_usd) \
+             VALUES ('pricey', 'http://pricey', 10.0) RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        // Rows without a game, so the test needn't create one.
+        for (provider_id, cost_usd) in [(cheap, 0.5), (pricey, 6.0), (pricey, 4.0)] {
+            sqlx::query(
+                "INSERT INTO bot_llm_usage \
+                 (turn_id, bot_name, provider_id, model, llm_calls, prompt_tokens, \
+                  completion_tokens, cost_usd) \
+                 VALUES ($1, 'easy', $2, 'm', 1, 0, 0, $3)",
+            )
+            .bind(Uuid::new_v4())
+            .bind(provider_id)
+            .bind(cost_usd)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(
+            Spend {
+                today_usd: 10.0,
+                month_usd: 10.0,
+            },
+            provider_spend(&pool, pricey).await.unwrap()
+        );
+
+        let with = |id, daily_usd, monthly_usd| ProviderConfig {
+            provider_id: Some(id),
+            budget: Budget {
+                daily_usd,
+                monthly_usd,
+            },
+            ..provider(0.0, 0.0)
+        };
+        let kept = affordable(
+            &pool,
+            vec![
+                with(pricey, None, Some(10.0)),
+                with(cheap, Some(1.0), None),
+                provider(0.0, 0.0),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            vec![Some(cheap), None],
+            kept.iter().map(|p| p.provider_id).collect::<Vec<_>>()
+        );
+    }
+}
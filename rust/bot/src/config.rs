@@ -1,32 +1,59 @@
 use anyhow::{Context, Result};
 use serde_json::Value;
 use sqlx::{PgPool, Row};
+use uuid::Uuid;
 
+use crate::adapters::ProviderKind;
+use crate::budget::Budget;
 use crate::crypto;
+use crate::structured::StructuredOutput;
 
 #[derive(Debug, Clone)]
 pub struct BotConfig {
-    #[allow(dead_code)]
+    /// `bots.id`, or `None` for the synthetic config used while the bots
+    /// table is empty.
+    pub id: Option<Uuid>,
     pub name: String,
     pub include_basic_strategy: bool,
     pub include_advanced_strategy: bool,
     pub temperature: f32,
+    /// Rounds of tool calls the bot may make before it must answer; 0 makes
+    /// it answer in one shot.
+    pub tool_steps: i32,
+    /// Characters of private notes the bot keeps between turns; 0 turns
+    /// memory off.
+    pub memory_chars: i32,
+    /// Whether the bot gives a public reason with each move.
+    pub explain_moves: bool,
+    pub budget: Budget,
 }
 
 #[derive(Debug, Clone)]
 pub struct ProviderConfig {
+    pub kind: ProviderKind,
+    /// `llm_providers.id`, or `None` for the env fallback.
+    pub provider_id: Option<Uuid>,
     pub url: String,
     pub api_key: Option<String>,
     pub model: String,
     pub reasoning_effort: Option<String>,
     pub extra_body: Option<Value>,
-    #[allow(dead_code)]
+    pub structured_output: Option<StructuredOutput>,
     pub priority: i32,
+    /// Share of a priority tier's calls, relative to the tier's other
+    /// providers.
+    pub weight: i32,
+    /// Prices for this link's model, in US dollars per million tokens.
+    pub input_usd_per_mtok: f64,
+    pub output_usd_per_mtok: f64,
+    /// The provider's budget, shared by every link to it.
+    pub budget: Budget,
 }
 
 pub async fn load_bot_config(pool: &PgPool, bot_name: &str) -> Result<Option<BotConfig>> {
     let row = sqlx::query(
-        "SELECT name, include_basic_strategy, include_advanced_strategy, temperature \
+        "SELECT id, name, include_basic_strategy, include_advanced_strategy, temperature, tool_steps, \
+         memory_chars, explain_moves, daily_budget_usd, monthly_budget_usd \
          FROM bots WHERE LOWER(name) = LOWER($1) AND enabled = true",
     )
     .bind(bot_name)
@@ -35,6 +62,7 @@ pub async fn load_bot_config(pool: &PgPool, bot_name: &str) -> Result<Option<Bot
 
     row.map(|row| {
         Ok(BotConfig {
+            id: Some(row.try_get("id").context("bots.id")?),
             name: row.try_get("name").context("bots.name")?,
             include_basic_strategy: row
                 .try_get("include_basic_strategy")
@@ -43,6 +71,17 @@ pub async fn load_bot_config(pool: &PgPool, bot_name: &str) -> Result<Option<Bot
                 .try_get("include_advanced_strategy")
                 .context("bots.include_advanced_strategy")?,
             temperature: row.try_get("temperature").context("bots.temperature")?,
+            tool_steps: row.try_get("tool_steps").context("bots.tool_steps")?,
+            memory_chars: row.try_get("memory_chars").context("bots.memory_chars")?,
+            explain_moves: row.try_get("explain_moves").context("bots.explain_moves")?,
+            budget: Budget {
+                daily_usd: row
+                    .try_get("daily_budget_usd")
+                    .context("bots.daily_budget_usd")?,
+                monthly_usd: row
+                    .try_get("monthly_budget_usd")
+                    .context("bots.monthly_budget_usd")?,
+            },
         })
     })
     .transpose()
@@ -62,7 +101,8 @@ pub async fn load_providers(
     encryption_key: &[u8; 32],
 ) -> Result<Vec<ProviderConfig>> {
     let rows = sqlx::query(
-        "SELECT lp.url, lp.api_key_encrypted, bp.model, bp.reasoning_effort, bp.extra_body, bp.priority \
+        "SELECT lp.id, lp.kind, lp.url, lp.api_key_encrypted, lp.structured_output, bp.model, bp.reasoning_effort, bp.extra_body, bp.priority, bp.weight, \
+         bp.input_usd_per_mtok, bp.output_usd_per_mtok, lp.daily_budget_usd, lp.monthly_budget_usd \
          FROM bot_providers bp \
          JOIN bots b ON b.id = bp.bot_id \
          JOIN llm_providers lp ON lp.id = bp.provider_id \
@@ -75,6 +115,9 @@ pub async fn load_providers(
 
     let mut providers = Vec::with_capacity(rows.len());
     for row in rows {
+        let kind: String = row.try_get("kind").context("lp.kind")?;
+        let kind = ProviderKind::parse(&kind)
+            .ok_or_else(|| anyhow::anyhow!("unknown provider kind {:?}", kind))?;
         let url: String = row.try_get("url").context("provider url")?;
         let model: String = row.try_get("model").context("provider model")?;
         let reasoning_effort: Option<String> = row
@@ -84,6 +127,27 @@ pub async fn load_providers(
             .try_get::<Option<Value>, _>("extra_body")
             .context("bp.extra_body")?;
         let priority: i32 = row.try_get("priority").context("bp.priority")?;
+        let weight: i32 = row.try_get("weight").context("bp.weight")?;
+        let provider_id: Uuid = row.try_get("id").context("lp.id")?;
+        let input_usd_per_mtok: f64 = row
+            .try_get("input_usd_per_mtok")
+            .context("bp.input_usd_per_mtok")?;
+        let output_usd_per_mtok: f64 = row
+            .try_get("output_usd_per_mtok")
+            .context("bp.output_usd_per_mtok")?;
+        let budget = Budget {
+            daily_usd: row
+                .try_get("daily_budget_usd")
+                .context("lp.daily_budget_usd")?,
+            monthly_usd: row
+                .try_get("monthly_budget_usd")
+                .context("lp.monthly_budget_usd")?,
+        };
+        let structured_output = row
+            .try_get::<Option<String>, _>("structured_output")
+            .context("lp.structured_output")?
+            .as_deref()
+            .and_then(StructuredOutput::parse);
         let api_key_encrypted: Option<Vec<u8>> = row
             .try_get::<Option<Vec<u8>>, _>("api_key_encrypted")
             .context("lp.api_key_encrypted")?;
@@ -98,12 +162,19 @@ pub async fn load_providers(
         };
 
         providers.push(ProviderConfig {
+            kind,
+            provider_id: Some(provider_id),
             url,
             api_key,
             model,
             reasoning_effort,
             extra_body,
+            structured_output,
             priority,
+            weight,
+            input_usd_per_mtok,
+            output_usd_per_mtok,
+            budget,
         });
     }
 
@@ -125,14 +196,44 @@ pub fn env_fallback_provider() -> Option<ProviderConfig> {
         },
         Err(_) => None,
     };
+    let kind = match std::env::var("LLM_PROVIDER_KIND") {
+        Ok(raw) => ProviderKind::parse(&raw).unwrap_or_else(|| {
+            tracing::warn!(
+                "LLM_PROVIDER_KIND is set but not openai, anthropic, ollama, gemini or llamacpp ({}), using openai",
+                raw
+            );
+            ProviderKind::OpenAi
+        }),
+        Err(_) => ProviderKind::OpenAi,
+    };
+    let structured_output = match std::env::var("LLM_STRUCTURED_OUTPUT") {
+        Ok(raw) => {
+            let parsed = StructuredOutput::parse(&raw);
+            if parsed.is_none() {
+                tracing::warn!(
+                    "LLM_STRUCTURED_OUTPUT is set but not gbnf, regex or json_schema ({}), ignoring",
+                    raw
+                );
+            }
+            parsed
+        }
+        Err(_) => None,
+    };
 
     Some(ProviderConfig {
+        kind,
+        provider_id: None,
         url,
         api_key,
         model,
         reasoning_effort,
         extra_body,
+        structured_output,
         priority: 0,
+        weight: 1,
+        input_usd_per_mtok: 0.0,
+        output_usd_per_mtok: 0.0,
+        budget: Budget::default(),
     })
 }
 
@@ -149,6 +250,8 @@ mod tests {
         "LLM_API_KEY",
         "REASONING_EFFORT",
         "LLM_EXTRA_BODY",
+        "LLM_STRUCTURED_OUTPUT",
+        "LLM_PROVIDER_KIND",
     ];
 
     fn clear_env() {
@@ -174,6 +277,8 @@ mod tests {
             std::env::set_var("LLM_API_KEY", "secret");
             std::env::set_var("REASONING_EFFORT", "low");
             std::env::set_var("LLM_EXTRA_BODY", r#"{"temperature":0.5}"#);
+            std::env::set_var("LLM_STRUCTURED_OUTPUT", "gbnf");
+            std::env::set_var("LLM_PROVIDER_KIND", "llamacpp");
         }
 
         let provider = env_fallback_provider().unwrap();
@@ -188,6 +293,8 @@ mod tests {
                 .and_then(|v| v.get("temperature")),
             Some(&serde_json::json!(0.5))
         );
+        assert_eq!(provider.structured_output, Some(StructuredOutput::Gbnf));
+        assert_eq!(provider.kind, ProviderKind::LlamaCpp);
         assert_eq!(provider.priority, 0);
 
         clear_env();
@@ -223,7 +330,7 @@ mod tests {
             .await
             .unwrap();
         let provider_id: uuid::Uuid = sqlx::query_scalar(
-            "INSERT INTO llm_providers (name, url) VALUES ('test-provider', 'http://localhost:11434') RETURNING id",
+            "INSERT INTO llm_providers (name, url, structured_output, kind) VALUES ('test-provider', 'http://localhost:11434', 'regex', 'ollama') RETURNING id",
         )
         .fetch_one(&pool)
         .await
@@ -242,5 +349,10 @@ mod tests {
         assert_eq!(providers.len(), 1);
         assert_eq!(providers[0].model, "test-model");
         assert_eq!(providers[0].url, "http://localhost:11434");
+        assert_eq!(
+            providers[0].structured_output,
+            Some(StructuredOutput::Regex)
+        );
+        assert_eq!(providers[0].kind, ProviderKind::Ollama);
     }
 }
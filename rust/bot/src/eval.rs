@@ -0,0 +1,631 @@
+//! Offline prompt evaluation. With `BOT_EVAL_CORPUS` set, the service appends
+//! every turn it plays to that JSONL file: the exact prompt contexts, the
+//! model's answer and the command the game accepted. `bot eval` replays a
+//! corpus against the provider configured by the `LLM_*` env vars, optionally
+//! with draft prompt templates, and reports how the model did. Run it twice
+//! with different settings to compare them.
+//!
+//! Replays check commands against the recorded command spec, not the game
+//! service, so a command that parses but breaks a game rule (a bid that's
+//! too low) still counts as valid. Tools are off during replay.
+//!
+//! The corpus holds each bot's private hand: keep it as private as the
+//! database.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, anyhow};
+use brdgme_game::command::Spec;
+use brdgme_game::command::parser::Parser;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+
+use crate::config::ProviderConfig;
+use crate::memory::Answer;
+use crate::prompt::{
+    FailedCommand, SystemContext, UserContext, render_system, render_system_from, render_user,
+    render_user_from,
+};
+use crate::{ChatMessage, LlmTarget, TokenUsage};
+
+pub const USAGE: &str = "usage: bot eval [--system-template PATH] [--user-template PATH] \
+[--max-attempts N] [--json] CORPUS...
+
+Replays recorded bot turns against the provider set by LLM_URL, BOT_MODEL and
+the other LLM_* env vars.";
+
+/// Attempts per turn during replay, unless `--max-attempts` says otherwise.
+const DEFAULT_MAX_ATTEMPTS: usize = 3;
+
+/// One recorded bot turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Turn {
+    pub game: String,
+    pub version: String,
+    pub bot_name: String,
+    pub provider_url: String,
+    pub model: String,
+    pub temperature: f32,
+    pub names: Vec<String>,
+    pub command_spec: Option<Spec>,
+    pub system: SystemContext,
+    pub user: UserContext,
+    /// The model's answer on the attempt the game accepted.
+    pub response: Answer,
+    /// The command the game accepted, with any menu number resolved.
+    pub command: String,
+    /// Attempts the turn took, including the accepted one.
+    pub attempts: usize,
+    pub latency_ms: u64,
+}
+
+/// Appends turns to a corpus file.
+pub struct Recorder {
+    path: PathBuf,
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl Recorder {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub async fn record(&self, turn: &Turn) -> Result<()> {
+        let mut line = serde_json::to_vec(turn).context("Failed to serialize eval turn")?;
+        line.push(b'\n');
+        // Concurrent turns would otherwise interleave long lines.
+        let _guard = self.lock.lock().await;
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .with_context(|| format!("Failed to open {}", self.path.display()))?;
+        file.write_all(&line)
+            .await
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+        // tokio hands the write to a blocking task; flush waits for it.
+        file.flush()
+            .await
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+        Ok(())
+    }
+}
+
+pub fn load_corpus(path: &Path) -> Result<Vec<Turn>> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    raw.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("{}:{}: not a recorded turn", path.display(), i + 1))
+        })
+        .collect()
+}
+
+/// Prompt templates to replay with; `None` uses the built-in one.
+#[derive(Debug, Default, PartialEq)]
+pub struct Templates {
+    pub system: Option<String>,
+    pub user: Option<String>,
+}
+
+impl Templates {
+    pub(crate) fn render(
+        &self,
+        system: &SystemContext,
+        user: &UserContext,
+    ) -> Result<Vec<ChatMessage>> {
+        let system = match &self.system {
+            Some(template) => render_system_from(template, system),
+            None => render_system(system),
+        }
+        .context("Failed to render system prompt")?;
+        let user = match &self.user {
+            Some(template) => render_user_from(template, user),
+            None => render_user(user),
+        }
+        .context("Failed to render user prompt")?;
+        Ok(vec![
+            ChatMessage::new("system", system),
+            ChatMessage::new("user", user),
+        ])
+    }
+}
+
+/// How one turn went on replay.
+#[derive(Debug, Clone, Serialize)]
+pub struct TurnResult {
+    pub game: String,
+    /// The last command the model gave, if any call succeeded.
+    pub command: Option<String>,
+    pub valid: bool,
+    /// Whether the valid command matches the recorded one.
+    pub agrees: bool,
+    pub attempts: usize,
+    pub llm_errors: usize,
+    pub latencies_ms: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub turns: usize,
+    pub valid: usize,
+    pub agreed: usize,
+    /// Attempts beyond the first, over all turns.
+    pub retries: usize,
+    pub llm_errors: usize,
+    pub llm_calls: u32,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub mean_latency_ms: u64,
+    pub p50_latency_ms: u64,
+    pub p95_latency_ms: u64,
+    pub results: Vec<TurnResult>,
+}
+
+impl Report {
+    fn new(results: Vec<TurnResult>, usage: TokenUsage) -> Self {
+        let mut latencies: Vec<u64> = results
+            .iter()
+            .flat_map(|r| r.latencies_ms.iter().copied())
+            .collect();
+        latencies.sort_unstable();
+        let percentile = |p: usize| {
+            latencies
+                .get((latencies.len() * p / 100).min(latencies.len().saturating_sub(1)))
+                .copied()
+                .unwrap_or(0)
+        };
+        Self {
+            turns: results.len(),
+            valid: results.iter().filter(|r| r.valid).count(),
+            agreed: results.iter().filter(|r| r.agrees).count(),
+            retries: results.iter().map(|r| r.attempts.saturating_sub(1)).sum(),
+            llm_errors: results.iter().map(|r| r.llm_errors).sum(),
+            llm_calls: usage.llm_calls,
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            mean_latency_ms: latencies
+                .iter()
+                .sum::<u64>()
+                .checked_div(latencies.len() as u64)
+                .unwrap_or(0),
+            p50_latency_ms: percentile(50),
+            p95_latency_ms: percentile(95),
+            results,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let rate = |n: usize| {
+            if self.turns == 0 {
+                0.0
+            } else {
+                n as f64 * 100.0 / self.turns as f64
+            }
+        };
+        format!(
+            "turns:      {}\n\
+             valid:      {} ({:.1}%)\n\
+             agreement:  {} ({:.1}%)\n\
+             retries:    {}\n\
+             llm errors: {}\n\
+             latency:    mean {}ms, p50 {}ms, p95 {}ms\n\
+             tokens:     {} prompt, {} completion over {} calls\n",
+            self.turns,
+            self.valid,
+            rate(self.valid),
+            self.agreed,
+            rate(self.agreed),
+            self.retries,
+            self.llm_errors,
+            self.mean_latency_ms,
+            self.p50_latency_ms,
+            self.p95_latency_ms,
+            self.prompt_tokens,
+            self.completion_tokens,
+            self.llm_calls,
+        )
+    }
+}
+
+/// Why a command doesn't parse in full, if it doesn't. Without a recorded
+/// spec, any command passes.
+fn parse_error(spec: Option<&Spec>, command: &str, names: &[String]) -> Option<String> {
+    let spec = spec?;
+    match spec.parse(command, names) {
+        Ok(out) if out.remaining.trim().is_empty() => None,
+        Ok(out) => Some(format!("Unexpected input: {}", out.remaining.trim())),
+        Err(e) => Some(e.to_string()),
+    }
+}
+
+fn same_command(a: &str, b: &str) -> bool {
+    a.split_whitespace()
+        .map(str::to_lowercase)
+        .eq(b.split_whitespace().map(str::to_lowercase))
+}
+
+/// Replays one turn, retrying with the parse error shown to the model as the
+/// bot service would with the game's error.
+async fn replay_turn(
+    http: &reqwest::Client,
+    provider: &ProviderConfig,
+    templates: &Templates,
+    max_attempts: usize,
+    turn: &Turn,
+    usage: &mut TokenUsage,
+) -> Result<TurnResult> {
+    let menu = turn.user.candidate_commands.clone();
+    let constraint = crate::output_constraint(
+        provider,
+        turn.command_spec.as_ref(),
+        &turn.names,
+        &menu,
+        turn.user.memory_chars > 0,
+        turn.user.explain,
+    );
+    let target = LlmTarget {
+        http,
+        kind: provider.kind,
+        url: &provider.url,
+        model: &provider.model,
+        api_key: provider.api_key.as_deref(),
+        temperature: turn.temperature,
+        reasoning_effort: provider.reasoning_effort.as_deref(),
+        extra_body: provider.extra_body.as_ref(),
+    };
+    let mut user = turn.user.clone();
+    let mut result = TurnResult {
+        game: turn.game.clone(),
+        command: None,
+        valid: false,
+        agrees: false,
+        attempts: 0,
+        llm_errors: 0,
+        latencies_ms: vec![],
+    };
+    for _ in 0..max_attempts {
+        result.attempts += 1;
+        let messages = templates.render(&turn.system, &user)?;
+        let start = Instant::now();
+        let reply = crate::call_llm(&target, &messages, constraint.as_ref(), None, true).await;
+        result.latencies_ms.push(elapsed_ms(start.elapsed()));
+        let answer = reply.and_then(|reply| {
+            usage.add(&reply.usage);
+            crate::answer_from(reply.content, constraint.as_ref())
+        });
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(_) => {
+                result.llm_errors += 1;
+                continue;
+            }
+        };
+        let command = crate::resolve_menu_choice(&answer.command, &menu);
+        result.command = Some(command.clone());
+        match parse_error(turn.command_spec.as_ref(), &command, &turn.names) {
+            None => {
+                result.valid = true;
+                result.agrees = same_command(&command, &turn.command);
+                break;
+            }
+            Some(error) => user.failed_commands.push(FailedCommand { command, error }),
+        }
+    }
+    Ok(result)
+}
+
+fn elapsed_ms(elapsed: Duration) -> u64 {
+    elapsed.as_millis() as u64
+}
+
+pub async fn replay(
+    http: &reqwest::Client,
+    provider: &ProviderConfig,
+    templates: &Templates,
+    max_attempts: usize,
+    turns: &[Turn],
+) -> Result<Report> {
+    let mut usage = TokenUsage::default();
+    let mut results = Vec::with_capacity(turns.len());
+    for turn in turns {
+        results.push(replay_turn(http, provider, templates, max_attempts, turn, &mut usage).await?);
+    }
+    Ok(Report::new(results, usage))
+}
+
+#[derive(Debug, PartialEq)]
+struct Opts {
+    corpora: Vec<PathBuf>,
+    templates: Templates,
+    max_attempts: usize,
+    json: bool,
+}
+
+fn parse_opts(args: &[String]) -> Result<Opts, String> {
+    let mut opts = Opts {
+        corpora: vec![],
+        templates: Templates::default(),
+        max_attempts: DEFAULT_MAX_ATTEMPTS,
+        json: false,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("{} requires a value", arg))
+        };
+        match arg.as_str() {
+            "--system-template" => opts.templates.system = Some(read_template(&value()?)?),
+            "--user-template" => opts.templates.user = Some(read_template(&value()?)?),
+            "--max-attempts" => {
+                let raw = value()?;
+                opts.max_attempts = raw
+                    .parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| format!("invalid --max-attempts '{}'", raw))?;
+            }
+            "--json" => opts.json = true,
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            path => opts.corpora.push(PathBuf::from(path)),
+        }
+    }
+    if opts.corpora.is_empty() {
+        return Err("no corpus given".to_string());
+    }
+    Ok(opts)
+}
+
+pub(crate) fn read_template(path: &str) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path, e))
+}
+
+/// Runs `bot eval` with the arguments after `eval`.
+pub async fn main(args: &[String]) -> Result<()> {
+    let opts = parse_opts(args).map_err(|message| anyhow!("{}\n{}", message, USAGE))?;
+    let provider = crate::config::env_fallback_provider()
+        .ok_or_else(|| anyhow!("LLM_URL and BOT_MODEL must be set\n{}", USAGE))?;
+    let mut turns = vec![];
+    for path in &opts.corpora {
+        turns.extend(load_corpus(path)?);
+    }
+    let http = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(300))
+        .build()
+        .context("Failed to build HTTP client")?;
+    let report = replay(&http, &provider, &opts.templates, opts.max_attempts, &turns).await?;
+    if opts.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).context("Failed to encode report")?
+        );
+    } else {
+        print!("{}", report.summary());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::adapters::ProviderKind;
+    use crate::prompt::PlayerInfo;
+    use axum::{Json, Router, routing::post};
+    use serde_json::{Value, json};
+    use std::sync::{Arc, Mutex};
+
+    /// Serves canned chat completion contents in order, recording each
+    /// request body.
+    pub(crate) async fn mock_provider(
+        contents: Vec<&str>,
+    ) -> (ProviderConfig, Arc<Mutex<Vec<Value>>>) {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let replies = Arc::new(Mutex::new(
+            contents
+                .into_iter()
+                .map(|c| json!({"choices": [{"message": {"content": c}}]}))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ));
+        let recorded = seen.clone();
+        let app = Router::new().route(
+            "/v1/chat/completions",
+            post(move |Json(body): Json<Value>| {
+                let recorded = recorded.clone();
+                let replies = replies.clone();
+                async move {
+                    recorded.lock().unwrap().push(body);
+                    Json(replies.lock().unwrap().next().expect("unexpected LLM call"))
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        let provider = ProviderConfig {
+            kind: ProviderKind::OpenAi,
+            provider_id: None,
+            url,
+            api_key: None,
+            model: "candidate".to_string(),
+            reasoning_effort: None,
+            extra_body: None,
+            structured_output: None,
+            priority: 0,
+            weight: 1,
+            input_usd_per_mtok: 0.0,
+            output_usd_per_mtok: 0.0,
+            budget: Default::default(),
+        };
+        (provider, seen)
+    }
+
+    /// A No Thanks turn where the recorded bot took the card.
+    fn turn() -> Turn {
+        let names = vec!["Alice".to_string(), "Bob".to_string()];
+        Turn {
+            game: "No Thanks".to_string(),
+            version: "2".to_string(),
+            bot_name: "easy".to_string(),
+            provider_url: "http://recorded".to_string(),
+            model: "recorded".to_string(),
+            temperature: 0.2,
+            command_spec: Some(Spec::OneOf(vec![
+                Spec::Token("take".to_string()),
+                Spec::Token("pass".to_string()),
+            ])),
+            system: SystemContext {
+                game_rules: "Take cards or pay chips.".to_string(),
+                include_basic_strategy: false,
+                basic_strategy: String::new(),
+                include_advanced_strategy: false,
+                advanced_strategy: String::new(),
+                data_docs: String::new(),
+                use_tools: false,
+            },
+            user: UserContext {
+                my_name: "Alice".to_string(),
+                my_colour: "Green".to_string(),
+                players: names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| PlayerInfo {
+                        name: name.clone(),
+                        colour: "Green".to_string(),
+                        is_me: i == 0,
+                    })
+                    .collect(),
+                pub_state_yaml: "card: 30".to_string(),
+                player_state_yaml: "chips: 4".to_string(),
+                command_spec: String::new(),
+                candidate_commands: vec!["take".to_string(), "pass".to_string()],
+                recent_logs: vec![],
+                failed_commands: vec![],
+                memory_chars: 0,
+                notes: String::new(),
+                explain: false,
+                reason_is_private: false,
+            },
+            names,
+            response: Answer {
+                command: "1".to_string(),
+                notes: None,
+                reason: None,
+            },
+            command: "take".to_string(),
+            attempts: 1,
+            latency_ms: 1200,
+        }
+    }
+
+    #[tokio::test]
+    async fn replay_reports_validity_retries_and_agreement() {
+        // Turn one: a bad command, then menu pick 1 ("take"), agreeing.
+        // Turn two: "pass" straight away, disagreeing.
+        // Turn three: never valid within the attempt limit.
+        let (provider, seen) =
+            mock_provider(vec!["jump", "1", "pass", "fold", "raise", "call"]).await;
+        let report = replay(
+            &reqwest::Client::new(),
+            &provider,
+            &Templates::default(),
+            3,
+            &[turn(), turn(), turn()],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(3, report.turns);
+        assert_eq!(2, report.valid);
+        assert_eq!(1, report.agreed);
+        assert_eq!(3, report.retries);
+        assert_eq!(0, report.llm_errors);
+        assert_eq!(6, report.llm_calls);
+        assert_eq!(Some("take"), report.results[0].command.as_deref());
+        assert!(!report.results[2].valid);
+        assert_eq!(
+            6,
+            report
+                .results
+                .iter()
+                .map(|r| r.latencies_ms.len())
+                .sum::<usize>()
+        );
+
+        // The retry showed the model why its command failed.
+        let seen = seen.lock().unwrap();
+        let retry_prompt = seen[1]["messages"][1]["content"].as_str().unwrap();
+        assert!(retry_prompt.contains("jump"), "{retry_prompt}");
+        assert_eq!("candidate", seen[0]["model"]);
+    }
+
+    #[tokio::test]
+    async fn replay_uses_draft_templates() {
+        let (provider, seen) = mock_provider(vec!["take"]).await;
+        let templates = Templates {
+            system: Some("DRAFT {{ game_rules }}".to_string()),
+            user: Some("{{ my_name }} holds {{ player_state_yaml }}".to_string()),
+        };
+        let report = replay(&reqwest::Client::new(), &provider, &templates, 1, &[turn()])
+            .await
+            .unwrap();
+        assert_eq!(1, report.agreed);
+        let seen = seen.lock().unwrap();
+        assert_eq!(
+            "DRAFT Take cards or pay chips.",
+            seen[0]["messages"][0]["content"]
+        );
+        assert_eq!("Alice holds chips: 4", seen[0]["messages"][1]["content"]);
+    }
+
+    #[tokio::test]
+    async fn recorder_appends_turns_that_load_back() {
+        let path = std::env::temp_dir().join(format!("bot-eval-{}.jsonl", uuid::Uuid::new_v4()));
+        let recorder = Recorder::new(path.clone());
+        recorder.record(&turn()).await.unwrap();
+        recorder.record(&turn()).await.unwrap();
+        let turns = load_corpus(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(2, turns.len());
+        assert_eq!("take", turns[1].command);
+        assert_eq!(
+            turn().user.player_state_yaml,
+            turns[0].user.player_state_yaml
+        );
+    }
+
+    #[test]
+    fn parse_opts_needs_a_corpus() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            Ok(Opts {
+                corpora: vec![PathBuf::from("a.jsonl"), PathBuf::from("b.jsonl")],
+                templates: Templates::default(),
+                max_attempts: 5,
+                json: true,
+            }),
+            parse_opts(&args(&[
+                "--max-attempts",
+                "5",
+                "a.jsonl",
+                "--json",
+                "b.jsonl"
+            ]))
+        );
+        assert!(parse_opts(&args(&["--json"])).is_err());
+        assert!(parse_opts(&args(&["--max-attempts", "0", "a.jsonl"])).is_err());
+        assert!(parse_opts(&args(&["--bogus", "a.jsonl"])).is_err());
+        assert!(parse_opts(&args(&["--system-template"])).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+//! Public explanations of a bot's moves. A bot with `bots.explain_moves`
+//! gives a reason with each command, which the monolith posts to the game
+//! log for every player to read. The prompt tells the model to keep its
+//! private state out of it, and `screen` checks it did: a reason naming
+//! anything found only in the bot's player state is withheld.
+
+use brdgme_game::command::Spec;
+use serde_yaml_ng::Value;
+
+use crate::memory;
+
+/// The most characters of a reason posted to the game log.
+pub const MAX_CHARS: usize = 300;
+
+/// Mappings with at most this many values are read as a single item, like a
+/// card with an expedition and a value.
+const ITEM_VALUES: usize = 3;
+
+/// The reason as it may be shown to every player: on one line and capped,
+/// or `None` if it's empty or might give away the bot's private state.
+///
+/// Private state is matched as a player would write it: its raw values,
+/// small structured items spelt out ("Red 8", "R8"), and the enum values of
+/// the bot's command spec, which list its hand as the game prints it.
+pub fn screen(
+    reason: &str,
+    pub_state_yaml: &str,
+    player_state_yaml: &str,
+    command_spec: Option<&Spec>,
+) -> Option<String> {
+    let reason = reason.split_whitespace().collect::<Vec<_>>().join(" ");
+    if reason.is_empty() {
+        return None;
+    }
+    // Private state we can't read can't be checked against.
+    let private = serde_yaml_ng::from_str::<Value>(player_state_yaml).ok()?;
+    let public = serde_yaml_ng::from_str::<Value>(pub_state_yaml).unwrap_or(Value::Null);
+    let mut public_spellings = spellings(&public);
+    // A name on the table makes its initial public too, so "R" isn't held
+    // back once a red card has been played.
+    public_spellings.extend(
+        public_spellings
+            .iter()
+            .filter(|s| s.chars().count() > 1)
+            .filter_map(|s| s.chars().next())
+            .map(String::from)
+            .collect::<Vec<_>>(),
+    );
+    let public_spellings = public_spellings.iter().map(|s| fold(s)).collect::<Vec<_>>();
+    let mut private_spellings = spellings(&private);
+    if let Some(spec) = command_spec {
+        enum_values(spec, &mut private_spellings);
+    }
+    if private_spellings
+        .iter()
+        .filter(|s| !public_spellings.contains(&fold(s)))
+        .any(|s| mentions(&reason, s))
+    {
+        return None;
+    }
+    Some(memory::cap(&reason, MAX_CHARS).to_string())
+}
+
+/// The ways `value` could be named: every scalar, plus each small mapping's
+/// values run together with and without a space, and abbreviated to the
+/// first one's initial, so `{expedition: Red, value: 8}` gives "Red 8",
+/// "Red8", "R 8" and "R8".
+fn spellings(value: &Value) -> Vec<String> {
+    let mut out = vec![];
+    scalars(value, &mut out);
+    items(value, &mut out);
+    out
+}
+
+/// Spellings of every small mapping in `value`.
+fn items(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::Sequence(values) => values.iter().for_each(|v| items(v, out)),
+        Value::Mapping(map) => {
+            let mut parts = vec![];
+            scalars(value, &mut parts);
+            if (2..=ITEM_VALUES).contains(&parts.len()) {
+                let rest = parts[1..].join(" ");
+                let initial = parts[0]
+                    .chars()
+                    .next()
+                    .map(String::from)
+                    .unwrap_or_default();
+                for head in [&parts[0], &initial] {
+                    out.push(format!("{head} {rest}"));
+                    out.push(format!("{head}{}", rest.replace(' ', "")));
+                }
+            }
+            map.values().for_each(|v| items(v, out));
+        }
+        Value::Tagged(tagged) => items(&tagged.value, out),
+        Value::String(_) | Value::Number(_) | Value::Bool(_) | Value::Null => {}
+    }
+}
+
+/// Every string and number in `value`, skipping keys, booleans and nulls.
+fn scalars(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(s) => {
+            let s = s.trim();
+            if !s.is_empty() {
+                out.push(s.to_string());
+            }
+        }
+        Value::Number(n) => out.push(n.to_string()),
+        Value::Sequence(items) => items.iter().for_each(|v| scalars(v, out)),
+        Value::Mapping(map) => map.values().for_each(|v| scalars(v, out)),
+        Value::Tagged(tagged) => scalars(&tagged.value, out),
+        Value::Bool(_) | Value::Null => {}
+    }
+}
+
+/// Every enum value in `spec`.
+fn enum_values(spec: &Spec, out: &mut Vec<String>) {
+    match spec {
+        Spec::Enum { values, .. } => out.extend(
+            values
+                .iter()
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+                .map(String::from),
+        ),
+        Spec::OneOf(specs) | Spec::Chain(specs) => specs.iter().for_each(|s| enum_values(s, out)),
+        Spec::Many { spec, delim, .. } => {
+            enum_values(spec, out);
+            if let Some(delim) = delim {
+                enum_values(delim, out);
+            }
+        }
+        Spec::Opt(spec) | Spec::Doc { spec, .. } => enum_values(spec, out),
+        Spec::Int { .. } | Spec::Token(_) | Spec::Player | Spec::Space => {}
+    }
+}
+
+/// A spelling as `mentions` compares it: case is ignored, except for single
+/// letters.
+fn fold(value: &str) -> String {
+    if value.chars().count() == 1 {
+        value.to_string()
+    } else {
+        value.to_lowercase()
+    }
+}
+
+/// Whether `text` contains `value` as a whole word. Case is ignored, except
+/// for single letters, so a hand holding an "A" doesn't catch every "a".
+fn mentions(text: &str, value: &str) -> bool {
+    let (text, value) = if value.chars().count() == 1 {
+        (text.to_string(), value.to_string())
+    } else {
+        (text.to_lowercase(), value.to_lowercase())
+    };
+    text.match_indices(&value).any(|(i, m)| {
+        let before = text[..i].chars().next_back();
+        let after = text[i + m.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLIC: &str = "board:\n  - chain: Tower\n    size: 4\nround: 3\n";
+    const PRIVATE: &str = "tiles:\n  - B7\n  - Festival\n  - A\nshares:\n  Tower: 3\n";
+
+    #[test]
+    fn screen_passes_reasons_about_the_table() {
+        assert_eq!(
+            Some("Tower is the biggest chain, so a merger is a long way off.".to_string()),
+            screen(
+                "Tower is the biggest chain,\n  so a merger is a long way off.",
+                PUBLIC,
+                PRIVATE,
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn screen_withholds_reasons_naming_private_state() {
+        assert_eq!(None, screen("Holding B7 for later.", PUBLIC, PRIVATE, None));
+        assert_eq!(None, screen("festival is coming", PUBLIC, PRIVATE, None));
+        assert_eq!(None, screen("Keeping my A.", PUBLIC, PRIVATE, None));
+        // Values that are also public don't count, nor do parts of words.
+        assert!(screen("3 rounds in, with a tile at AB7.", PUBLIC, PRIVATE, None).is_some());
+    }
+
+    #[test]
+    fn screen_withholds_what_it_cannot_check() {
+        assert_eq!(None, screen("  \n ", PUBLIC, PRIVATE, None));
+        assert_eq!(None, screen("Fine move.", PUBLIC, "tiles: [B7", None));
+    }
+
+    #[test]
+    fn screen_caps_long_reasons() {
+        let reason = "x".repeat(MAX_CHARS + 10);
+        assert_eq!(
+            Some(MAX_CHARS),
+            screen(&reason, PUBLIC, "", None).map(|r| r.len())
+        );
+    }
+
+    #[test]
+    fn screen_withholds_structured_cards_however_they_are_spelt() {
+        let public = "expeditions:\n  - - expedition: Green\n      value:\n        N: 8\n";
+        let private = "hand:\n  - expedition: Red\n    value:\n      N: 8\n  - expedition: Blue\n    value: Investment\n";
+        let spec = Spec::Chain(vec![
+            Spec::Token("play".into()),
+            Spec::Enum {
+                values: vec!["BX".into(), "R8".into()],
+                exact: true,
+            },
+        ]);
+        for reason in [
+            "Keeping my red 8 for later.",
+            "Saving r8.",
+            "The Red8 can wait.",
+            "Holding BX for now.",
+        ] {
+            assert_eq!(
+                None,
+                screen(reason, public, private, Some(&spec)),
+                "{reason}"
+            );
+        }
+        // The green 8 and the bare 8 are on the table.
+        assert!(
+            screen(
+                "Green 8 is out, so G8 won't come back.",
+                public,
+                private,
+                Some(&spec)
+            )
+            .is_some()
+        );
+        assert!(screen("8 is a good card.", public, private, Some(&spec)).is_some());
+    }
+}
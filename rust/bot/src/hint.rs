@@ -0,0 +1,353 @@
+//! Hints for human players: what a bot would play in their seat, and why.
+//! The monolith asks over core NATS request/reply on `bot.hint`. A hint is
+//! built like a bot turn from the asking player's seat, so it sees only the
+//! public state, that player's private state and the logs shown to them.
+//! Nothing is played: the command is only validated.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{Context, Result, anyhow};
+use futures_util::StreamExt;
+use sqlx::Row;
+use tokio::sync::Semaphore;
+use uuid::Uuid;
+
+use crate::nats::{self, BotHintReply, BotHintRequest};
+use crate::prompt::FailedCommand;
+use crate::routing::ProviderRouter;
+use crate::tools::ToolContext;
+use crate::{AppState, LlmTarget, MENU_LIMIT, TokenUsage, config, memory};
+
+/// Commands a hint may try before giving up. Fewer than a turn, since the
+/// player is waiting.
+const MAX_HINT_ATTEMPTS: usize = 3;
+
+/// Hints worked on at once by this replica, alongside its turns.
+const MAX_CONCURRENT_HINTS: usize = 4;
+
+/// The most characters of rationale passed on to the player.
+const RATIONALE_CHARS: usize = 500;
+
+/// The coach's config as used for a hint. The seat isn't the bot's, so there
+/// are no notes of its own to keep, and no tools: a preview is checked
+/// against the full game state, so a hint must not lean on one.
+fn hint_config(bot_cfg: config::BotConfig) -> config::BotConfig {
+    config::BotConfig {
+        memory_chars: 0,
+        tool_steps: 0,
+        ..bot_cfg
+    }
+}
+
+fn unavailable(message: &str) -> BotHintReply {
+    BotHintReply::Unavailable {
+        message: message.to_string(),
+    }
+}
+
+/// Answers `bot.hint` requests until the subscription ends.
+pub async fn serve(state: AppState) -> Result<()> {
+    let client = state.jetstream.client();
+    let mut requests = client
+        .queue_subscribe(nats::SUBJECT_HINT, nats::QUEUE_HINT.to_string())
+        .await
+        .context("Failed to subscribe to bot.hint")?;
+    let permits = Arc::new(Semaphore::new(MAX_CONCURRENT_HINTS));
+    tracing::info!("Bot subscribed to bot.hint");
+
+    while let Some(message) = requests.next().await {
+        let Some(reply_to) = message.reply.clone() else {
+            tracing::warn!("bot.hint request has no reply subject");
+            continue;
+        };
+        let permit = permits
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("hint semaphore is never closed");
+        let state = state.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let _permit = permit;
+            let reply = answer(&state, &message.payload).await;
+            let payload = match serde_json::to_vec(&reply) {
+                Ok(payload) => payload,
+                Err(e) => {
+                    tracing::error!("Failed to serialize bot.hint reply: {}", e);
+                    return;
+                }
+            };
+            if let Err(e) = client.publish(reply_to, payload.into()).await {
+                tracing::warn!("Failed to reply to bot.hint: {}", e);
+            }
+        });
+    }
+    Err(anyhow!("bot.hint subscription ended"))
+}
+
+async fn answer(state: &AppState, payload: &[u8]) -> BotHintReply {
+    let req: BotHintRequest = match serde_json::from_slice(payload) {
+        Ok(req) => req,
+        Err(e) => {
+            tracing::error!("Failed to parse bot.hint payload: {}", e);
+            return unavailable("The hint request couldn't be read.");
+        }
+    };
+    let hint_id = Uuid::new_v4();
+    match run_hint(state, &req, hint_id).await {
+        Ok(reply) => reply,
+        Err(e) => {
+            tracing::error!(hint_id = %hint_id, error = ?e, "Hint failed");
+            unavailable("No hint could be found. Please try again later.")
+        }
+    }
+}
+
+#[tracing::instrument(
+    name = "bot_hint",
+    skip(state, req),
+    fields(
+        hint_id = %hint_id,
+        game_id = %req.game_id,
+        player_position = req.player_position,
+        bot_name = %req.bot_name,
+    )
+)]
+async fn run_hint(state: &AppState, req: &BotHintRequest, hint_id: Uuid) -> Result<BotHintReply> {
+    let hint_start = Instant::now();
+    let row = sqlx::query(
+        r#"
+        SELECT g.game_state, gv.uri, gv.name as version_name, gv.interface_version, gp.id as game_player_id, gp.is_turn
+        FROM games g
+        JOIN game_versions gv ON gv.id = g.game_version_id
+        JOIN game_players gp ON gp.game_id = g.id AND gp.position = $2
+        WHERE g.id = $1 AND g.is_finished = false
+        "#,
+    )
+    .bind(req.game_id)
+    .bind(req.player_position)
+    .fetch_optional(&state.pool)
+    .await
+    .context("Failed to fetch game from database")?;
+    let Some(row) = row else {
+        return Ok(unavailable("This game is no longer being played."));
+    };
+    if !row.try_get::<bool, _>("is_turn").context("gp.is_turn")? {
+        return Ok(unavailable("It isn't your turn."));
+    }
+    let game_state: String = row.try_get("game_state").context("game_state")?;
+    let game_service_uri: String = row.try_get("uri").context("uri")?;
+    let version_name: String = row.try_get("version_name").context("version_name")?;
+    let interface_version: i32 = row
+        .try_get("interface_version")
+        .context("gv.interface_version")?;
+    let game_player_id: Uuid = row.try_get("game_player_id").context("game_player_id")?;
+
+    let names = crate::load_player_names(&state.pool, req.game_id).await?;
+    let fallback_name = format!("Player {}", req.player_position + 1);
+    let bot_cfg = hint_config(crate::resolve_bot_config(&state.pool, &req.bot_name).await?);
+    let providers = crate::configured_providers(state, &req.bot_name).await?;
+    let providers = crate::within_budget(state, &bot_cfg, providers).await?;
+    if providers.is_empty() {
+        return Ok(unavailable("Hints aren't available right now."));
+    }
+    let mut router = ProviderRouter::new(providers, &state.health, &mut rand::rng());
+
+    let bot_ctx = crate::load_bot_context(
+        state,
+        &game_service_uri,
+        &version_name,
+        req.game_id,
+        req.player_position,
+        game_player_id,
+        None,
+        game_state,
+        &names,
+        interface_version,
+    )
+    .await
+    .context("Failed to load hint context")?;
+
+    let mut failed_commands: Vec<FailedCommand> = Vec::new();
+    let mut cost_usd = 0.0;
+    for attempt in 0..MAX_HINT_ATTEMPTS {
+        let provider = router
+            .current()
+            .ok_or_else(|| anyhow!("All LLM providers exhausted"))?
+            .clone();
+        let menu = bot_ctx
+            .game_data
+            .command_spec
+            .as_ref()
+            .and_then(|spec| spec.enumerate(&names, MENU_LIMIT))
+            .unwrap_or_default();
+        let structured = crate::output_constraint(
+            &provider,
+            bot_ctx.game_data.command_spec.as_ref(),
+            &names,
+            &menu,
+            false,
+            true,
+        );
+        let (system_ctx, mut user_ctx) = crate::build_contexts(
+            &bot_cfg,
+            &bot_ctx,
+            &names,
+            req.player_position as usize,
+            &fallback_name,
+            &menu,
+            failed_commands.clone(),
+        );
+        user_ctx.explain = true;
+        user_ctx.reason_is_private = true;
+        let messages = crate::render_messages(&system_ctx, &user_ctx)?;
+        crate::log_messages_redacted(&messages);
+
+        let target = LlmTarget {
+            http: &state.http,
+            kind: provider.kind,
+            url: &provider.url,
+            model: &provider.model,
+            api_key: provider.api_key.as_deref(),
+            temperature: bot_cfg.temperature,
+            reasoning_effort: provider.reasoning_effort.as_deref(),
+            extra_body: provider.extra_body.as_ref(),
+        };
+        let tool_ctx = ToolContext {
+            game_http: &state.game_http,
+            game_service_uri: &game_service_uri,
+            version_name: &version_name,
+            game_state: &bot_ctx.game_state,
+            game_data: &bot_ctx.game_data,
+            names: &names,
+            player: req.player_position as usize,
+        };
+        let llm_start = Instant::now();
+        let mut usage = TokenUsage::default();
+        let result = crate::converse(
+            &target,
+            messages,
+            structured.as_ref(),
+            bot_cfg.tool_steps.max(0) as usize,
+            &tool_ctx,
+            &mut usage,
+        )
+        .await;
+        cost_usd +=
+            crate::record_usage(state, req.game_id, hint_id, &bot_cfg, &provider, usage).await;
+        let answer = match result {
+            Ok(answer) => {
+                crate::report_health(state, &router, true, llm_start.elapsed()).await;
+                answer
+            }
+            Err(e) => {
+                tracing::warn!(
+                    provider_url = %provider.url,
+                    model = %provider.model,
+                    attempt,
+                    error = %e,
+                    "hint_llm_error"
+                );
+                crate::report_health(state, &router, false, llm_start.elapsed()).await;
+                router.fail_over();
+                continue;
+            }
+        };
+
+        let command = crate::resolve_menu_choice(&answer.command, &menu);
+        match crate::validate_command(
+            state,
+            &game_service_uri,
+            &version_name,
+            req.player_position,
+            &bot_ctx,
+            &names,
+            &command,
+        )
+        .await
+        {
+            Ok(()) => {
+                tracing::info!(
+                    elapsed_ms = hint_start.elapsed().as_millis() as u64,
+                    outcome = "success",
+                    attempts = attempt + 1,
+                    cost_usd,
+                    "bot_hint_end"
+                );
+                return Ok(BotHintReply::Hint {
+                    command,
+                    rationale: answer
+                        .reason
+                        .map(|r| memory::cap(&r, RATIONALE_CHARS).to_string()),
+                });
+            }
+            Err(error) => failed_commands.push(FailedCommand { command, error }),
+        }
+    }
+    Err(anyhow!(
+        "No hint accepted after {} attempts",
+        MAX_HINT_ATTEMPTS
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{mock_llm, reply, test_bot_cfg, test_bot_ctx};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn hints_make_no_tool_calls() {
+        let bot_cfg = hint_config(config::BotConfig {
+            tool_steps: 3,
+            memory_chars: 200,
+            ..test_bot_cfg()
+        });
+        assert_eq!(0, bot_cfg.tool_steps);
+        assert_eq!(0, bot_cfg.memory_chars);
+
+        let bot_ctx = test_bot_ctx("R8");
+        let (system_ctx, _) =
+            crate::build_contexts(&bot_cfg, &bot_ctx, &[], 0, "Player 1", &[], vec![]);
+        assert!(!system_ctx.use_tools);
+
+        let (url, seen) = mock_llm(vec![reply(json!({"content": "draw"}), 10)]).await;
+        let http = reqwest::Client::new();
+        let target = LlmTarget {
+            http: &http,
+            kind: crate::adapters::ProviderKind::OpenAi,
+            url: &url,
+            model: "m",
+            api_key: None,
+            temperature: 0.2,
+            reasoning_effort: None,
+            extra_body: None,
+        };
+        let tool_ctx = ToolContext {
+            game_http: &http,
+            game_service_uri: "http://unused",
+            version_name: "v",
+            game_state: &bot_ctx.game_state,
+            game_data: &bot_ctx.game_data,
+            names: &[],
+            player: 0,
+        };
+        let mut usage = TokenUsage::default();
+        let answer = crate::converse(
+            &target,
+            vec![crate::ChatMessage::new("user", "go".to_string())],
+            None,
+            bot_cfg.tool_steps.max(0) as usize,
+            &tool_ctx,
+            &mut usage,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!("draw", answer.command);
+        let seen = seen.lock().unwrap();
+        assert_eq!(1, seen.len());
+        assert_eq!(None, seen[0].get("tools"));
+    }
+}
@@ -1,22 +1,36 @@
+mod adapters;
+mod arena;
+mod budget;
 mod config;
 mod crypto;
+mod eval;
+mod explain;
+mod hint;
+mod memory;
 mod nats;
 mod prompt;
 mod routing;
+mod structured;
+mod tools;
 
 use anyhow::{Context, Result, anyhow};
 use axum::{Router, extract::State as AxumState, http::StatusCode, routing::get};
 use brdgme_cmd::api::{Request, Response};
 use brdgme_color::LIGHT;
+use brdgme_game::command::Spec;
 use futures_util::StreamExt;
+use memory::Answer;
 use nats::{BotCommandEvent, BotTurnEvent};
 use prompt::{
     FailedCommand, PlayerInfo, SystemContext, UserContext, markup_resolve_players, render_system,
     render_user, spec_to_yaml,
 };
-use routing::ProviderRouter;
+use rand::seq::SliceRandom;
+use routing::{HealthRegistry, ProviderRouter};
 use serde::{Deserialize, Serialize};
 use sqlx::{PgPool, Row};
+use structured::Constraint;
+use tools::{ToolCall, ToolContext};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use uuid::Uuid;
@@ -25,6 +39,13 @@ use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::Semaphore;
 
+/// The most commands to list as a numbered menu. Bigger action spaces are
+/// left to the parser rules alone.
+const MENU_LIMIT: usize = 30;
+
+/// Commands a turn may try before giving up.
+const MAX_ATTEMPTS: usize = 20;
+
 const ACK_HEARTBEAT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
 
 #[derive(Clone)]
@@ -36,12 +57,32 @@ struct AppState {
     game_http: reqwest::Client,
     encryption_key: zeroize::Zeroizing<[u8; 32]>,
     jetstream: async_nats::jetstream::Context,
+    /// Provider health, shared across turns so routing avoids failing
+    /// providers.
+    health: Arc<HealthRegistry>,
+    /// Where played turns are recorded for offline evaluation, if anywhere.
+    corpus: Option<Arc<eval::Recorder>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ChatMessage {
     role: String,
     content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tool_calls: Option<Vec<ToolCall>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tool_call_id: Option<String>,
+}
+
+impl ChatMessage {
+    fn new(role: &str, content: String) -> Self {
+        ChatMessage {
+            role: role.to_string(),
+            content,
+            tool_calls: None,
+            tool_call_id: None,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -52,11 +93,17 @@ struct ChatRequest {
     temperature: f32,
     #[serde(skip_serializing_if = "Option::is_none")]
     reasoning_effort: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 struct ChatResponseMessage {
     content: Option<String>,
+    #[serde(default)]
+    tool_calls: Option<Vec<ToolCall>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -64,9 +111,61 @@ struct ChatChoice {
     message: ChatResponseMessage,
 }
 
+#[derive(Debug, Default, Deserialize)]
+struct ChatUsage {
+    #[serde(default)]
+    prompt_tokens: u64,
+    #[serde(default)]
+    completion_tokens: u64,
+}
+
 #[derive(Debug, Deserialize)]
 struct ChatResponse {
     choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
+}
+
+/// One provider's settings for a turn's LLM calls.
+struct LlmTarget<'a> {
+    http: &'a reqwest::Client,
+    kind: adapters::ProviderKind,
+    url: &'a str,
+    model: &'a str,
+    api_key: Option<&'a str>,
+    temperature: f32,
+    reasoning_effort: Option<&'a str>,
+    extra_body: Option<&'a serde_json::Value>,
+}
+
+/// The assistant's reply to one LLM call.
+struct LlmReply {
+    content: Option<String>,
+    tool_calls: Vec<ToolCall>,
+    usage: ChatUsage,
+}
+
+/// Tokens spent over a bot turn, across every attempt, tool step and
+/// provider. Providers that don't report usage count as zero.
+#[derive(Debug, Default, Clone, Copy)]
+struct TokenUsage {
+    llm_calls: u32,
+    prompt_tokens: u64,
+    completion_tokens: u64,
+}
+
+impl TokenUsage {
+    fn add(&mut self, usage: &ChatUsage) {
+        self.llm_calls += 1;
+        self.prompt_tokens += usage.prompt_tokens;
+        self.completion_tokens += usage.completion_tokens;
+    }
+
+    fn merge(&mut self, other: TokenUsage) {
+        self.llm_calls += other.llm_calls;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+    }
 }
 
 async fn resolve_bot_config(pool: &PgPool, bot_name: &str) -> Result<config::BotConfig> {
@@ -81,10 +180,15 @@ async fn resolve_bot_config(pool: &PgPool, bot_name: &str) -> Result<config::Bot
         None => {
             if table_empty {
                 Ok(config::BotConfig {
+                    id: None,
                     name: bot_name.to_string(),
                     include_basic_strategy: true,
                     include_advanced_strategy: false,
                     temperature: 0.2,
+                    tool_steps: 0,
+                    memory_chars: 0,
+                    explain_moves: false,
+                    budget: Default::default(),
                 })
             } else {
                 Err(anyhow!("Bot '{}' not found or disabled", bot_name))
@@ -109,7 +213,7 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
     // 1. Fetch game data from DB.
     let row = sqlx::query(
         r#"
-        SELECT g.game_state, gv.uri, gv.name as version_name, gv.rules, gv.interface_version, gt.name as game_name, gb.name as bot_name, gp.is_turn, gp.id as game_player_id
+        SELECT g.game_state, gv.uri, gv.name as version_name, gv.rules, gv.interface_version, gt.name as game_name, gb.name as bot_name, gp.is_turn, gp.id as game_player_id, gp.game_bot_id
         FROM games g
         JOIN game_versions gv ON gv.id = g.game_version_id
         JOIN game_types gt ON gt.id = gv.game_type_id
@@ -145,6 +249,7 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
         .try_get("interface_version")
         .context("gv.interface_version")?;
     let game_player_id: Uuid = row.try_get("game_player_id").context("game_player_id")?;
+    let game_bot_id: Option<Uuid> = row.try_get("game_bot_id").context("gp.game_bot_id")?;
     let game_name: String = row.try_get("game_name").context("gt.name")?;
     let bot_name: String = row
         .try_get::<Option<String>, _>("bot_name")
@@ -152,32 +257,7 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
         .unwrap_or_else(|| format!("Bot {}", req.player_position + 1));
 
     // 2. Fetch all player names for the game (needed for Status call).
-    let player_rows = sqlx::query(
-        r#"
-        SELECT gp.position, u.name as user_name, gb.name as bot_name
-        FROM game_players gp
-        LEFT JOIN users u ON u.id = gp.user_id
-        LEFT JOIN game_bots gb ON gb.id = gp.game_bot_id
-        WHERE gp.game_id = $1
-        ORDER BY gp.position
-        "#,
-    )
-    .bind(req.game_id)
-    .fetch_all(&state.pool)
-    .await
-    .context("Failed to fetch game players")?;
-
-    let names: Vec<String> = player_rows
-        .iter()
-        .map(|p| {
-            let user_name: Option<String> = p.try_get("user_name").context("u.name")?;
-            let bot_name: Option<String> = p.try_get("bot_name").context("gb.name")?;
-            let position: i32 = p.try_get("position").context("gp.position")?;
-            Ok(user_name
-                .or(bot_name)
-                .unwrap_or_else(|| format!("Player {}", position + 1)))
-        })
-        .collect::<Result<Vec<String>>>()?;
+    let names = load_player_names(&state.pool, req.game_id).await?;
 
     tracing::info!(
         game = %game_name,
@@ -199,18 +279,7 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
         }
     };
 
-    let mut providers = config::load_providers(&state.pool, &req.bot_name, &state.encryption_key)
-        .await
-        .context("Failed to load providers")?;
-    let table_empty = config::bots_table_empty(&state.pool)
-        .await
-        .context("Failed to check bots table")?;
-    if providers.is_empty()
-        && table_empty
-        && let Some(p) = config::env_fallback_provider()
-    {
-        providers = vec![p];
-    }
+    let providers = configured_providers(state, &req.bot_name).await?;
     if providers.is_empty() {
         tracing::error!(
             elapsed_ms = turn_start.elapsed().as_millis() as u64,
@@ -223,7 +292,9 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
             req.bot_name
         ));
     }
-    let mut router = ProviderRouter::new(providers);
+    let providers = within_budget(state, &bot_cfg, providers).await?;
+    let play_randomly = providers.is_empty();
+    let mut router = ProviderRouter::new(providers, &state.health, &mut rand::rng());
 
     // 3. Load game context (state, structured game data, logs). Extracted into a helper
     //    so it can be refreshed mid-loop if the game state changes while the LLM is thinking.
@@ -235,6 +306,7 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
         req.game_id,
         req.player_position,
         game_player_id,
+        game_bot_id,
         game_state,
         &names,
         interface_version,
@@ -247,57 +319,125 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
         "game_service_call"
     );
 
-    const MAX_ATTEMPTS: usize = 20;
+    if play_randomly {
+        return play_random(
+            state,
+            &req,
+            &bot_ctx,
+            &names,
+            &game_service_uri,
+            &version_name,
+            turn_start,
+        )
+        .await;
+    }
+
     let mut failed_commands: Vec<FailedCommand> = Vec::new();
+    let mut usage = TokenUsage::default();
+    let mut cost_usd = 0.0;
 
     for attempt in 0..MAX_ATTEMPTS {
         let provider = router
             .current()
-            .ok_or_else(|| anyhow!("All LLM providers exhausted"))?;
+            .ok_or_else(|| anyhow!("All LLM providers exhausted"))?
+            .clone();
         let url = provider.url.clone();
         let model = provider.model.clone();
         let api_key = provider.api_key.clone();
+        let kind = provider.kind;
         let reasoning_effort = provider.reasoning_effort.clone();
         let extra_body = provider.extra_body.clone();
+        let menu = bot_ctx
+            .game_data
+            .command_spec
+            .as_ref()
+            .and_then(|spec| spec.enumerate(&names, MENU_LIMIT))
+            .unwrap_or_default();
+        let structured = output_constraint(
+            &provider,
+            bot_ctx.game_data.command_spec.as_ref(),
+            &names,
+            &menu,
+            bot_cfg.memory_chars > 0,
+            bot_cfg.explain_moves,
+        );
 
         tracing::info!(
             provider_url = %url,
             model = %model,
             attempt,
+            kind = ?provider.kind,
+            structured_output = ?structured.as_ref().map(|c| c.mode),
+            menu_size = menu.len(),
             "llm_request_start"
         );
 
-        let messages = build_messages(
+        let (system_ctx, user_ctx) = build_contexts(
             &bot_cfg,
             &bot_ctx,
             &names,
             req.player_position as usize,
             &bot_name,
+            &menu,
             failed_commands.clone(),
-        )
-        .with_context(|| format!("Failed to build messages on attempt {}", attempt + 1))?;
+        );
+        let messages = render_messages(&system_ctx, &user_ctx)
+            .with_context(|| format!("Failed to build messages on attempt {}", attempt + 1))?;
         log_messages_redacted(&messages);
 
         let llm_start = Instant::now();
-        let raw_response = match call_llm(
-            &state.http,
-            &url,
-            &model,
-            &messages,
-            api_key.as_deref(),
-            bot_cfg.temperature,
-            reasoning_effort,
-            extra_body.as_ref(),
+        let target = LlmTarget {
+            http: &state.http,
+            kind,
+            url: &url,
+            model: &model,
+            api_key: api_key.as_deref(),
+            temperature: bot_cfg.temperature,
+            reasoning_effort: reasoning_effort.as_deref(),
+            extra_body: extra_body.as_ref(),
+        };
+        let tool_ctx = ToolContext {
+            game_http: &state.game_http,
+            game_service_uri: &game_service_uri,
+            version_name: &version_name,
+            game_state: &bot_ctx.game_state,
+            game_data: &bot_ctx.game_data,
+            names: &names,
+            player: req.player_position as usize,
+        };
+        let mut call_usage = TokenUsage::default();
+        let result = converse(
+            &target,
+            messages,
+            structured.as_ref(),
+            bot_cfg.tool_steps.max(0) as usize,
+            &tool_ctx,
+            &mut call_usage,
         )
-        .await
-        {
+        .await;
+        let llm_elapsed = llm_start.elapsed();
+        usage.merge(call_usage);
+        cost_usd += record_usage(
+            state,
+            req.game_id,
+            trace_id,
+            &bot_cfg,
+            &provider,
+            call_usage,
+        )
+        .await;
+        let answer = match result {
             Ok(r) => {
+                report_health(state, &router, true, llm_start.elapsed()).await;
                 tracing::info!(
                     provider_url = %url,
                     model = %model,
                     attempt,
                     elapsed_ms = llm_start.elapsed().as_millis() as u64,
                     outcome = "success",
+                    llm_calls = usage.llm_calls,
+                    prompt_tokens = usage.prompt_tokens,
+                    completion_tokens = usage.completion_tokens,
                     "llm_request_end"
                 );
                 r
@@ -312,12 +452,13 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
                     error = %e,
                     "llm_request_end"
                 );
+                report_health(state, &router, false, llm_start.elapsed()).await;
                 router.fail_over();
                 continue;
             }
         };
 
-        let command = raw_response.trim().to_string();
+        let command = resolve_menu_choice(&answer.command, &menu);
 
         // Re-check DB after the LLM responds: it may have taken a while and the game
         // state could have changed (e.g., the player hit undo).
@@ -365,6 +506,7 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
                 req.game_id,
                 req.player_position,
                 game_player_id,
+                game_bot_id,
                 current_game_state,
                 &names,
                 interface_version,
@@ -383,49 +525,75 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
         // Validate the command against the game service directly. `Play` is
         // stateless (returns the new state but doesn't persist), so this
         // retry loop never round-trips through the monolith.
-        let validate_start = Instant::now();
-        let validate_result = brdgme_game_client::request(
-            &state.game_http,
+        let error_body = match validate_command(
+            state,
             &game_service_uri,
             &version_name,
-            &Request::Play {
-                player: req.player_position as usize,
-                game: bot_ctx.game_state.clone(),
-                command: command.clone(),
-                names: names.clone(),
-            },
+            req.player_position,
+            &bot_ctx,
+            &names,
+            &command,
         )
-        .await;
-        tracing::info!(
-            elapsed_ms = validate_start.elapsed().as_millis() as u64,
-            phase = "validate_command",
-            "game_service_call"
-        );
-
-        let error_body = match validate_result {
-            Ok(Response::Play { .. }) => {
+        .await
+        {
+            Ok(()) => {
+                let rationale = public_rationale(&bot_cfg, &bot_ctx, &answer);
                 publish_bot_command(
                     state,
                     req.game_id,
                     req.player_position,
                     command.clone(),
                     req.attempt,
+                    rationale,
                 )
                 .await?;
+                if bot_cfg.memory_chars > 0
+                    && let Some(notes) = &answer.notes
+                    && let Some(game_bot_id) = game_bot_id
+                {
+                    let notes = memory::cap(notes, bot_cfg.memory_chars as usize);
+                    // The move is already in; losing the notes only costs
+                    // the bot its memory of this turn.
+                    if let Err(e) =
+                        memory::save(&state.pool, game_player_id, game_bot_id, notes).await
+                    {
+                        tracing::warn!(error = %e, "Failed to save bot memory");
+                    }
+                }
+                if let Some(corpus) = &state.corpus {
+                    let turn = eval::Turn {
+                        game: game_name.clone(),
+                        version: version_name.clone(),
+                        bot_name: bot_cfg.name.clone(),
+                        provider_url: url.clone(),
+                        model: model.clone(),
+                        temperature: bot_cfg.temperature,
+                        names: names.clone(),
+                        command_spec: bot_ctx.game_data.command_spec.clone(),
+                        system: system_ctx,
+                        user: user_ctx,
+                        response: answer,
+                        command: command.clone(),
+                        attempts: attempt + 1,
+                        latency_ms: llm_elapsed.as_millis() as u64,
+                    };
+                    if let Err(e) = corpus.record(&turn).await {
+                        tracing::warn!(error = %e, "Failed to record eval turn");
+                    }
+                }
                 tracing::info!(
                     elapsed_ms = turn_start.elapsed().as_millis() as u64,
                     outcome = "success",
                     command = %command,
+                    llm_calls = usage.llm_calls,
+                    prompt_tokens = usage.prompt_tokens,
+                    completion_tokens = usage.completion_tokens,
+                    cost_usd,
                     "bot_turn_end"
                 );
                 return Ok(());
             }
-            Ok(Response::UserError { message }) => message,
-            Ok(_) => "Unexpected response from game service".to_string(),
-            Err(e) => match e {
-                brdgme_game_client::GameClientError::UserError { message } => message,
-                e => e.to_string(),
-            },
+            Err(error_body) => error_body,
         };
 
         if attempt + 1 == MAX_ATTEMPTS {
@@ -436,6 +604,10 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
                 attempts = MAX_ATTEMPTS,
                 last_command = %command,
                 last_error = %error_body,
+                llm_calls = usage.llm_calls,
+                prompt_tokens = usage.prompt_tokens,
+                completion_tokens = usage.completion_tokens,
+                cost_usd,
                 "bot_turn_end"
             );
             return Err(anyhow!(
@@ -469,18 +641,275 @@ async fn run_bot_turn(state: &AppState, req: BotTurnEvent, trace_id: Uuid) -> Re
     ))
 }
 
+/// Display names of a game's players, in seat order.
+async fn load_player_names(pool: &PgPool, game_id: Uuid) -> Result<Vec<String>> {
+    let player_rows = sqlx::query(
+        r#"
+        SELECT gp.position, u.name as user_name, gb.name as bot_name
+        FROM game_players gp
+        LEFT JOIN users u ON u.id = gp.user_id
+        LEFT JOIN game_bots gb ON gb.id = gp.game_bot_id
+        WHERE gp.game_id = $1
+        ORDER BY gp.position
+        "#,
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await
+    .context("Failed to fetch game players")?;
+
+    player_rows
+        .iter()
+        .map(|p| {
+            let user_name: Option<String> = p.try_get("user_name").context("u.name")?;
+            let bot_name: Option<String> = p.try_get("bot_name").context("gb.name")?;
+            let position: i32 = p.try_get("position").context("gp.position")?;
+            Ok(user_name
+                .or(bot_name)
+                .unwrap_or_else(|| format!("Player {}", position + 1)))
+        })
+        .collect()
+}
+
+/// The bot's providers, or the env fallback provider while the bots table
+/// is empty. Empty if it has none.
+async fn configured_providers(
+    state: &AppState,
+    bot_name: &str,
+) -> Result<Vec<config::ProviderConfig>> {
+    let providers = config::load_providers(&state.pool, bot_name, &state.encryption_key)
+        .await
+        .context("Failed to load providers")?;
+    if !providers.is_empty() {
+        return Ok(providers);
+    }
+    let table_empty = config::bots_table_empty(&state.pool)
+        .await
+        .context("Failed to check bots table")?;
+    Ok(config::env_fallback_provider()
+        .filter(|_| table_empty)
+        .into_iter()
+        .collect())
+}
+
+/// Past its budget, a bot skips the providers that are, and has none left
+/// if it is itself.
+async fn within_budget(
+    state: &AppState,
+    bot_cfg: &config::BotConfig,
+    providers: Vec<config::ProviderConfig>,
+) -> Result<Vec<config::ProviderConfig>> {
+    let bot_over_budget = match bot_cfg.id.filter(|_| bot_cfg.budget.is_set()) {
+        Some(id) => bot_cfg
+            .budget
+            .exhausted(budget::bot_spend(&state.pool, id).await?),
+        None => false,
+    };
+    if bot_over_budget {
+        tracing::info!("bot_over_budget");
+        return Ok(vec![]);
+    }
+    budget::affordable(&state.pool, providers).await
+}
+
+/// Checks a command against the game service, returning the error to show
+/// the bot if it's rejected.
+async fn validate_command(
+    state: &AppState,
+    game_service_uri: &str,
+    version_name: &str,
+    player_position: i32,
+    bot_ctx: &BotContext,
+    names: &[String],
+    command: &str,
+) -> std::result::Result<(), String> {
+    let validate_start = Instant::now();
+    let validate_result = brdgme_game_client::request(
+        &state.game_http,
+        game_service_uri,
+        version_name,
+        &Request::Play {
+            player: player_position as usize,
+            game: bot_ctx.game_state.clone(),
+            command: command.to_string(),
+            names: names.to_vec(),
+        },
+    )
+    .await;
+    tracing::info!(
+        elapsed_ms = validate_start.elapsed().as_millis() as u64,
+        phase = "validate_command",
+        "game_service_call"
+    );
+    match validate_result {
+        Ok(Response::Play { .. }) => Ok(()),
+        Ok(Response::UserError { message }) => Err(message),
+        Ok(_) => Err("Unexpected response from game service".to_string()),
+        Err(brdgme_game_client::GameClientError::UserError { message }) => Err(message),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Plays random commands from the command spec, for a bot with no LLM
+/// budget left. Small action spaces are drawn from the move menu, so each
+/// attempt tries a different command.
+async fn play_random(
+    state: &AppState,
+    req: &BotTurnEvent,
+    bot_ctx: &BotContext,
+    names: &[String],
+    game_service_uri: &str,
+    version_name: &str,
+    turn_start: Instant,
+) -> Result<()> {
+    let spec = bot_ctx
+        .game_data
+        .command_spec
+        .as_ref()
+        .ok_or_else(|| anyhow!("No command spec to play randomly from"))?;
+    let mut menu = spec.enumerate(names, MENU_LIMIT).unwrap_or_default();
+    menu.shuffle(&mut rand::rng());
+    let from_menu = !menu.is_empty();
+    for attempt in 0..MAX_ATTEMPTS {
+        let command = if from_menu {
+            match menu.pop() {
+                Some(command) => command,
+                None => break,
+            }
+        } else {
+            brdgme_rand_bot::spec_to_command(spec, spec, names, &mut rand::rng()).join("")
+        };
+        match validate_command(
+            state,
+            game_service_uri,
+            version_name,
+            req.player_position,
+            bot_ctx,
+            names,
+            &command,
+        )
+        .await
+        {
+            Ok(()) => {
+                publish_bot_command(
+                    state,
+                    req.game_id,
+                    req.player_position,
+                    command.clone(),
+                    req.attempt,
+                    None,
+                )
+                .await?;
+                tracing::info!(
+                    elapsed_ms = turn_start.elapsed().as_millis() as u64,
+                    outcome = "success",
+                    reason = "over budget, played randomly",
+                    command = %command,
+                    attempt,
+                    "bot_turn_end"
+                );
+                return Ok(());
+            }
+            Err(error) => {
+                tracing::warn!(attempt, command = %command, error = %error, "Random command rejected");
+            }
+        }
+    }
+    tracing::error!(
+        elapsed_ms = turn_start.elapsed().as_millis() as u64,
+        outcome = "failure",
+        reason = "over budget, no random command accepted",
+        "bot_turn_end"
+    );
+    Err(anyhow!(
+        "No random command accepted after {} attempts",
+        MAX_ATTEMPTS
+    ))
+}
+
+/// Records a conversation's token usage and cost, returning the cost.
+/// Failing to record only loses the accounting.
+async fn record_usage(
+    state: &AppState,
+    game_id: Uuid,
+    turn_id: Uuid,
+    bot_cfg: &config::BotConfig,
+    provider: &config::ProviderConfig,
+    usage: TokenUsage,
+) -> f64 {
+    if usage.llm_calls == 0 {
+        return 0.0;
+    }
+    let cost_usd = budget::cost_usd(provider, &usage);
+    let record = budget::UsageRecord {
+        turn_id,
+        bot_id: bot_cfg.id,
+        bot_name: &bot_cfg.name,
+        provider_id: provider.provider_id,
+        model: &provider.model,
+        game_id,
+        usage,
+        cost_usd,
+    };
+    if let Err(e) = budget::record(&state.pool, &record).await {
+        tracing::warn!(error = %e, "Failed to record LLM usage");
+    }
+    cost_usd
+}
+
+/// Records an LLM call against the current provider's health and reports
+/// the result for the admin pages.
+async fn report_health(
+    state: &AppState,
+    router: &ProviderRouter<'_>,
+    ok: bool,
+    latency: std::time::Duration,
+) {
+    let Some(status) = router.record(ok, latency) else {
+        return;
+    };
+    if let Err(e) = routing::save_status(&state.pool, &status).await {
+        tracing::warn!(error = %e, "Failed to report provider health");
+    }
+}
+
+/// The reason the bot gave for its move, if it explains its moves and the
+/// reason passes screening. Withheld reasons are logged without their text.
+fn public_rationale(
+    bot_cfg: &config::BotConfig,
+    bot_ctx: &BotContext,
+    answer: &memory::Answer,
+) -> Option<String> {
+    if !bot_cfg.explain_moves {
+        return None;
+    }
+    let reason = answer.reason.as_deref()?;
+    let screened = explain::screen(
+        reason,
+        &bot_ctx.game_data.pub_state_yaml,
+        &bot_ctx.game_data.player_state_yaml,
+        bot_ctx.game_data.command_spec.as_ref(),
+    );
+    if screened.is_none() {
+        tracing::info!("bot_rationale_withheld");
+    }
+    screened
+}
+
 async fn publish_bot_command(
     state: &AppState,
     game_id: Uuid,
     player_position: i32,
     command: String,
     attempt: i32,
+    rationale: Option<String>,
 ) -> Result<()> {
     let event = BotCommandEvent {
         game_id,
         player_position,
         command,
         attempt,
+        rationale,
     };
     let payload = serde_json::to_vec(&event).context("Failed to serialize bot.command event")?;
     state
@@ -497,6 +926,8 @@ struct BotContext {
     game_state: String,
     game_data: brdgme_game_client::GameData,
     recent_logs: Vec<String>,
+    /// The notes the bot left itself, empty if it has none.
+    notes: String,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -507,6 +938,7 @@ async fn load_bot_context(
     game_id: uuid::Uuid,
     player_position: i32,
     game_player_id: uuid::Uuid,
+    game_bot_id: Option<uuid::Uuid>,
     game_state: String,
     names: &[String],
     interface_version: i32,
@@ -545,21 +977,66 @@ async fn load_bot_context(
         })
         .collect::<Result<Vec<String>>>()?;
 
+    // Only the bot in the seat has notes; a player asking for a hint has none.
+    let notes = match game_bot_id {
+        Some(game_bot_id) => memory::load(&state.pool, game_player_id, game_bot_id)
+            .await?
+            .unwrap_or_default(),
+        None => String::new(),
+    };
+
     Ok(BotContext {
         game_state,
         game_data,
         recent_logs,
+        notes,
+    })
+}
+
+/// Constrains output to the command grammar where the provider supports it,
+/// so a bot never burns a retry on an unparseable command. With a menu, a
+/// bare menu number is allowed too.
+fn output_constraint(
+    provider: &config::ProviderConfig,
+    spec: Option<&Spec>,
+    names: &[String],
+    menu: &[String],
+    notes: bool,
+    reason: bool,
+) -> Option<Constraint> {
+    let mode = provider
+        .kind
+        .structured_output(provider.structured_output)?;
+    let spec = spec?;
+    let grammar = if menu.is_empty() {
+        spec.grammar(names)?
+    } else {
+        Spec::OneOf(vec![
+            Spec::Int {
+                min: Some(1),
+                max: Some(menu.len() as i32),
+            },
+            spec.clone(),
+        ])
+        .grammar(names)?
+    };
+    Some(Constraint {
+        mode,
+        grammar,
+        notes,
+        reason,
     })
 }
 
-fn build_messages(
+fn build_contexts(
     bot_cfg: &config::BotConfig,
     bot_ctx: &BotContext,
     names: &[String],
     player_position: usize,
     fallback_name: &str,
+    menu: &[String],
     failed_commands: Vec<FailedCommand>,
-) -> Result<Vec<ChatMessage>> {
+) -> (SystemContext, UserContext) {
     let system_ctx = SystemContext {
         game_rules: bot_ctx.game_data.rules.clone(),
         include_basic_strategy: bot_cfg.include_basic_strategy,
@@ -567,8 +1044,8 @@ fn build_messages(
         include_advanced_strategy: bot_cfg.include_advanced_strategy,
         advanced_strategy: bot_ctx.game_data.advanced_strategy.clone(),
         data_docs: bot_ctx.game_data.data_docs.clone(),
+        use_tools: bot_cfg.tool_steps > 0,
     };
-    let system_content = render_system(&system_ctx).context("Failed to render system prompt")?;
 
     let my_name = names
         .get(player_position)
@@ -598,23 +1075,44 @@ fn build_messages(
         pub_state_yaml: bot_ctx.game_data.pub_state_yaml.clone(),
         player_state_yaml: bot_ctx.game_data.player_state_yaml.clone(),
         command_spec,
+        candidate_commands: menu.to_vec(),
         recent_logs: bot_ctx.recent_logs.clone(),
         failed_commands,
+        memory_chars: bot_cfg.memory_chars.max(0) as usize,
+        notes: bot_ctx.notes.clone(),
+        explain: bot_cfg.explain_moves,
+        reason_is_private: false,
     };
-    let user_content = render_user(&user_ctx).context("Failed to render user prompt")?;
+    (system_ctx, user_ctx)
+}
 
+fn render_messages(system_ctx: &SystemContext, user_ctx: &UserContext) -> Result<Vec<ChatMessage>> {
+    let system_content = render_system(system_ctx).context("Failed to render system prompt")?;
+    let user_content = render_user(user_ctx).context("Failed to render user prompt")?;
     Ok(vec![
-        ChatMessage {
-            role: "system".to_string(),
-            content: system_content,
-        },
-        ChatMessage {
-            role: "user".to_string(),
-            content: user_content,
-        },
+        ChatMessage::new("system", system_content),
+        ChatMessage::new("user", user_content),
     ])
 }
 
+/// The command a response names: a menu number such as `3`, `#3` or `3.`
+/// picks that menu entry, anything else is taken as the command itself. A
+/// number that is itself on the menu (e.g. a bid amount) is left alone.
+fn resolve_menu_choice(response: &str, menu: &[String]) -> String {
+    let response = response.trim();
+    if menu.iter().any(|c| c.eq_ignore_ascii_case(response)) {
+        return response.to_string();
+    }
+    response
+        .trim_start_matches('#')
+        .trim_end_matches(['.', ')'])
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| menu.get(n.checked_sub(1)?))
+        .cloned()
+        .unwrap_or_else(|| response.to_string())
+}
+
 /// Redacted logging boundary for LLM messages: emits only the message count,
 /// never message contents or any derived measure of them (e.g. lengths) - the
 /// user prompt embeds `player_state_yaml`, i.e. the bot's own hand.
@@ -656,37 +1154,77 @@ fn merge_json_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-async fn call_llm(
-    http: &reqwest::Client,
-    llm_url: &str,
-    model: &str,
-    messages: &[ChatMessage],
-    api_key: Option<&str>,
-    temperature: f32,
-    reasoning_effort: Option<String>,
-    extra_body: Option<&serde_json::Value>,
-) -> Result<String> {
-    let url = format!("{}/v1/chat/completions", llm_url);
-    let body = ChatRequest {
-        model: model.to_string(),
-        messages: messages.to_vec(),
-        stream: false,
-        temperature,
-        reasoning_effort,
-    };
+/// The command, and any notes, in the model's final reply.
+fn answer_from(content: Option<String>, constraint: Option<&Constraint>) -> Result<Answer> {
+    let content = content
+        .ok_or_else(|| anyhow!("LLM returned null content (reasoning budget exhausted?)"))?;
+    match constraint {
+        Some(c) => c.mode.extract_answer(&content),
+        None => Ok(memory::split_answer(&content)),
+    }
+}
 
-    let mut req = match extra_body {
-        Some(patch) => {
-            let mut value =
-                serde_json::to_value(&body).context("Failed to serialize LLM request body")?;
-            merge_json_patch(&mut value, patch);
-            http.post(&url).json(&value)
+/// Runs the model to a command. With `tool_steps`, it may make that many
+/// rounds of tool calls first; the last call refuses tools to force an
+/// answer. The grammar constraint applies only to that last call, since
+/// constrained output and tool calls don't mix.
+async fn converse(
+    target: &LlmTarget<'_>,
+    mut messages: Vec<ChatMessage>,
+    structured: Option<&Constraint>,
+    tool_steps: usize,
+    tool_ctx: &ToolContext<'_>,
+    usage: &mut TokenUsage,
+) -> Result<Answer> {
+    let definitions = tools::definitions();
+    let tools = (tool_steps > 0).then_some(definitions.as_slice());
+    for step in 0..=tool_steps {
+        let last = step == tool_steps;
+        let constraint = structured.filter(|_| last);
+        let reply = call_llm(target, &messages, constraint, tools, last).await?;
+        usage.add(&reply.usage);
+        if reply.tool_calls.is_empty() {
+            return answer_from(reply.content, constraint);
         }
-        None => http.post(&url).json(&body),
-    };
-    if let Some(key) = api_key {
-        req = req.header("Authorization", format!("Bearer {}", key));
+        if last {
+            break;
+        }
+        messages.push(ChatMessage {
+            tool_calls: Some(reply.tool_calls.clone()),
+            ..ChatMessage::new("assistant", reply.content.unwrap_or_default())
+        });
+        for call in &reply.tool_calls {
+            // The name only: arguments can quote the bot's private state.
+            tracing::info!(tool = %call.function.name, step, "bot_tool_call");
+            let result = tools::run(call, tool_ctx).await;
+            messages.push(ChatMessage {
+                tool_call_id: Some(call.id.clone()),
+                ..ChatMessage::new("tool", result)
+            });
+        }
+    }
+    Err(anyhow!(
+        "LLM kept calling tools after its {} tool steps",
+        tool_steps
+    ))
+}
+
+async fn call_llm(
+    target: &LlmTarget<'_>,
+    messages: &[ChatMessage],
+    constraint: Option<&Constraint>,
+    tools: Option<&[serde_json::Value]>,
+    refuse_tools: bool,
+) -> Result<LlmReply> {
+    let mut request = adapters::build_request(target, messages, constraint, tools, refuse_tools)?;
+    // The provider's extra_body is applied last so it can override or null
+    // out anything the adapter set, grammar fields included.
+    if let Some(patch) = target.extra_body {
+        merge_json_patch(&mut request.body, patch);
+    }
+    let mut req = target.http.post(&request.url).json(&request.body);
+    for (name, value) in request.headers {
+        req = req.header(name, value);
     }
 
     let resp = req.send().await.context("HTTP request to LLM failed")?;
@@ -697,15 +1235,8 @@ async fn call_llm(
         return Err(anyhow!("LLM returned {}: {}", status, body));
     }
 
-    let chat_resp: ChatResponse = resp.json().await.context("Failed to parse LLM response")?;
-    chat_resp
-        .choices
-        .into_iter()
-        .next()
-        .ok_or_else(|| anyhow!("LLM returned no choices"))?
-        .message
-        .content
-        .ok_or_else(|| anyhow!("LLM returned null content (reasoning budget exhausted?)"))
+    let body: serde_json::Value = resp.json().await.context("Failed to parse LLM response")?;
+    adapters::parse_reply(target.kind, body)
 }
 
 /// Liveness content only (k8s/base/bot/deployment.yaml wires /healthz as a
@@ -714,14 +1245,30 @@ async fn call_llm(
 /// whereas a DB outage is not — probing the pool here would crashloop the
 /// pod against a down database while sqlx's PgPool reconnects on its own
 /// (review bo F8: DB-check recommendation declined for a liveness probe).
-async fn healthz(AxumState(state): AxumState<AppState>) -> StatusCode {
-    match state.jetstream.client().connection_state() {
-        async_nats::connection::State::Connected => StatusCode::OK,
-        _ => StatusCode::SERVICE_UNAVAILABLE,
-    }
+///
+/// The body also lists provider health for operators. Open circuits don't
+/// fail the probe: restarting wouldn't bring a provider back.
+async fn healthz(
+    AxumState(state): AxumState<AppState>,
+) -> (StatusCode, axum::Json<serde_json::Value>) {
+    let connected = matches!(
+        state.jetstream.client().connection_state(),
+        async_nats::connection::State::Connected
+    );
+    let status = if connected {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let body = serde_json::json!({
+        "nats_connected": connected,
+        "providers": state.health.snapshot(),
+    });
+    (status, axum::Json(body))
 }
 
-/// Serves `/healthz` on `LISTEN_ADDR`, reporting NATS connection health.
+/// Serves `/healthz` on `LISTEN_ADDR`, reporting NATS connection and
+/// provider health.
 /// Spawned alongside the bot.turn consumer loop; the loop is not expected to
 /// exit in normal operation, so this runs for the lifetime of the process.
 async fn serve_health(state: AppState, listen_addr: String) -> Result<()> {
@@ -789,6 +1336,13 @@ async fn main() -> Result<()> {
         .install_default()
         .expect("failed to install rustls crypto provider");
 
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(String::as_str) {
+        Some("eval") => return eval::main(&args[2..]).await,
+        Some("arena") => return arena::main(&args[2..]).await,
+        _ => {}
+    }
+
     let _sentry_guard = std::env::var("SENTRY_DSN_SERVER").ok().map(|dsn| {
         sentry::init((
             dsn,
@@ -847,6 +1401,9 @@ async fn main() -> Result<()> {
         game_http,
         encryption_key,
         jetstream: jetstream.clone(),
+        health: Arc::new(HealthRegistry::default()),
+        corpus: std::env::var_os("BOT_EVAL_CORPUS")
+            .map(|path| Arc::new(eval::Recorder::new(path.into()))),
     };
 
     let listen_addr = std::env::var("LISTEN_ADDR").unwrap_or_else(|_| "0.0.0.0:4000".to_string());
@@ -857,6 +1414,13 @@ async fn main() -> Result<()> {
         }
     });
 
+    let hint_state = state.clone();
+    tokio::spawn(async move {
+        if let Err(e) = hint::serve(hint_state).await {
+            tracing::error!("Bot hint responder failed: {}", e);
+        }
+    });
+
     let consumer = wait_for_turn_consumer(&jetstream).await?;
     let mut messages = consumer.messages().await?;
 
@@ -982,6 +1546,176 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    /// Serves canned chat completions in order, recording each request body.
+    pub(crate) async fn mock_llm(
+        replies: Vec<serde_json::Value>,
+    ) -> (String, Arc<std::sync::Mutex<Vec<serde_json::Value>>>) {
+        use axum::{Json, routing::post};
+        let seen = Arc::new(std::sync::Mutex::new(vec![]));
+        let replies = Arc::new(std::sync::Mutex::new(replies.into_iter()));
+        let recorded = seen.clone();
+        let app = Router::new().route(
+            "/v1/chat/completions",
+            post(move |Json(body): Json<serde_json::Value>| {
+                let recorded = recorded.clone();
+                let replies = replies.clone();
+                async move {
+                    recorded.lock().unwrap().push(body);
+                    Json(replies.lock().unwrap().next().expect("unexpected LLM call"))
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (url, seen)
+    }
+
+    pub(crate) fn reply(message: serde_json::Value, prompt_tokens: u64) -> serde_json::Value {
+        json!({
+            "choices": [{"message": message}],
+            "usage": {"prompt_tokens": prompt_tokens, "completion_tokens": 5},
+        })
+    }
+
+    #[tokio::test]
+    async fn converse_runs_tools_then_answers_within_the_step_budget() {
+        let tool_call = json!({
+            "content": null,
+            "tool_calls": [{
+                "id": "call_1",
+                "type": "function",
+                "function": {"name": "rules_section", "arguments": "{\"name\": \"Scoring\"}"},
+            }],
+        });
+        let (url, seen) = mock_llm(vec![
+            reply(tool_call.clone(), 100),
+            reply(tool_call, 150),
+            reply(json!({"content": "bid 2 5"}), 200),
+        ])
+        .await;
+        let http = reqwest::Client::new();
+        let target = LlmTarget {
+            http: &http,
+            kind: adapters::ProviderKind::OpenAi,
+            url: &url,
+            model: "m",
+            api_key: None,
+            temperature: 0.2,
+            reasoning_effort: None,
+            extra_body: None,
+        };
+        let mut bot_ctx = test_bot_ctx("hand");
+        bot_ctx.game_data.rules = "# Game\n\n## Scoring\n\nMost dice wins.".to_string();
+        let tool_ctx = ToolContext {
+            game_http: &http,
+            game_service_uri: "http://unused",
+            version_name: "v",
+            game_state: &bot_ctx.game_state,
+            game_data: &bot_ctx.game_data,
+            names: &[],
+            player: 0,
+        };
+        let mut usage = TokenUsage::default();
+
+        let answer = converse(
+            &target,
+            vec![ChatMessage::new("user", "go".to_string())],
+            None,
+            2,
+            &tool_ctx,
+            &mut usage,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!("bid 2 5", answer.command);
+        assert_eq!(3, usage.llm_calls);
+        assert_eq!(450, usage.prompt_tokens);
+        assert_eq!(15, usage.completion_tokens);
+        let seen = seen.lock().unwrap();
+        assert_eq!(None, seen[0].get("tool_choice"));
+        assert_eq!(json!("none"), seen[2]["tool_choice"]);
+        let last_messages = seen[2]["messages"].as_array().unwrap();
+        assert_eq!(5, last_messages.len());
+        assert_eq!(json!("tool"), last_messages[2]["role"]);
+        assert_eq!(json!("call_1"), last_messages[2]["tool_call_id"]);
+        assert_eq!(
+            json!("## Scoring\n\nMost dice wins."),
+            last_messages[2]["content"]
+        );
+    }
+
+    #[tokio::test]
+    async fn converse_without_tool_steps_is_single_shot() {
+        let (url, seen) = mock_llm(vec![reply(
+            json!({"content": "call\nNOTES: Bob bluffs"}),
+            10,
+        )])
+        .await;
+        let http = reqwest::Client::new();
+        let target = LlmTarget {
+            http: &http,
+            kind: adapters::ProviderKind::OpenAi,
+            url: &url,
+            model: "m",
+            api_key: None,
+            temperature: 0.2,
+            reasoning_effort: None,
+            extra_body: None,
+        };
+        let bot_ctx = test_bot_ctx("hand");
+        let tool_ctx = ToolContext {
+            game_http: &http,
+            game_service_uri: "http://unused",
+            version_name: "v",
+            game_state: &bot_ctx.game_state,
+            game_data: &bot_ctx.game_data,
+            names: &[],
+            player: 0,
+        };
+        let mut usage = TokenUsage::default();
+        let structured = Constraint {
+            mode: structured::StructuredOutput::Gbnf,
+            grammar: Spec::Token("call".into()).grammar(&[]).unwrap(),
+            notes: false,
+            reason: false,
+        };
+
+        let answer = converse(
+            &target,
+            vec![ChatMessage::new("user", "go".to_string())],
+            Some(&structured),
+            0,
+            &tool_ctx,
+            &mut usage,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!("call", answer.command);
+        assert_eq!(Some("Bob bluffs".to_string()), answer.notes);
+        let seen = seen.lock().unwrap();
+        assert_eq!(None, seen[0].get("tools"));
+        assert_eq!(json!("root ::= \"call\"\n"), seen[0]["grammar"]);
+    }
+
+    #[test]
+    fn resolve_menu_choice_maps_numbers_to_menu_entries() {
+        let menu = vec!["take".to_string(), "pass".to_string()];
+        assert_eq!("pass", resolve_menu_choice("2", &menu));
+        assert_eq!("take", resolve_menu_choice(" #1.\n", &menu));
+        assert_eq!("pass", resolve_menu_choice("pass", &menu));
+        // Out of range or not a number: passed through for the game to judge.
+        assert_eq!("3", resolve_menu_choice("3", &menu));
+        assert_eq!("0", resolve_menu_choice("0", &menu));
+        assert_eq!("take all", resolve_menu_choice("take all", &menu));
+        assert_eq!("2", resolve_menu_choice("2", &[]));
+        // A number that is a command in its own right wins over its index.
+        let menu = vec!["2".to_string(), "1".to_string()];
+        assert_eq!("1", resolve_menu_choice("1", &menu));
+    }
+
     #[test]
     fn merge_json_patch_empty_patch_is_noop() {
         let mut target = json!({"model": "gpt", "temperature": 0.2});
@@ -1087,7 +1821,7 @@ mod tests {
         }
     }
 
-    fn test_bot_ctx(private_hand: &str) -> BotContext {
+    pub(crate) fn test_bot_ctx(private_hand: &str) -> BotContext {
         BotContext {
             game_state: "{}".to_string(),
             game_data: brdgme_game_client::GameData {
@@ -1100,15 +1834,21 @@ mod tests {
                 rules: "rules".to_string(),
             },
             recent_logs: vec![],
+            notes: String::new(),
         }
     }
 
-    fn test_bot_cfg() -> config::BotConfig {
+    pub(crate) fn test_bot_cfg() -> config::BotConfig {
         config::BotConfig {
+            id: None,
             name: "test".to_string(),
             include_basic_strategy: true,
             include_advanced_strategy: false,
             temperature: 0.2,
+            tool_steps: 0,
+            memory_chars: 0,
+            explain_moves: false,
+            budget: Default::default(),
         }
     }
 
@@ -1130,15 +1870,16 @@ mod tests {
         let bot_ctx = test_bot_ctx(SENTINEL);
         let bot_cfg = test_bot_cfg();
         let messages = tracing::subscriber::with_default(subscriber, || {
-            let messages = build_messages(
+            let (system_ctx, user_ctx) = build_contexts(
                 &bot_cfg,
                 &bot_ctx,
                 &["Alice".to_string(), "Bob".to_string()],
                 0,
                 "Bot 1",
+                &[],
                 vec![],
-            )
-            .expect("build_messages failed");
+            );
+            let messages = render_messages(&system_ctx, &user_ctx).expect("render_messages failed");
             log_messages_redacted(&messages);
             messages
         });
@@ -1162,14 +1903,14 @@ mod tests {
         );
     }
 
-    /// F-192/F-193/U12 regression: `build_messages` must render the user prompt
+    /// F-192/F-193/U12 regression: `build_contexts` must render the user prompt
     /// exclusively from `game_data` (the redaction-boundary PubRender/PlayerRender
     /// outputs). The raw `game_state` is a status-like body carrying opponent
     /// private state and the full Status render; if a Status/opponent path were
     /// reintroduced, its markers would leak into the prompt. Each marker is
     /// distinct so any leak is unambiguous.
     #[test]
-    fn build_messages_renders_game_data_not_raw_game_state() {
+    fn build_contexts_renders_game_data_not_raw_game_state() {
         const PUBLIC_MARKER: &str = "PUB_STATE_MARKER_public_board";
         const PRIVATE_MARKER: &str = "PLAYER_STATE_MARKER_my_hand";
         const OPPONENT_MARKER: &str = "OPPONENT_PRIVATE_MARKER_bobs_hand";
@@ -1190,18 +1931,20 @@ mod tests {
                 rules: String::new(),
             },
             recent_logs: vec![],
+            notes: String::new(),
         };
         let bot_cfg = test_bot_cfg();
 
-        let messages = build_messages(
+        let (system_ctx, user_ctx) = build_contexts(
             &bot_cfg,
             &bot_ctx,
             &["Alice".to_string(), "Bob".to_string()],
             0,
             "Bot 1",
+            &[],
             vec![],
-        )
-        .expect("build_messages failed");
+        );
+        let messages = render_messages(&system_ctx, &user_ctx).expect("render_messages failed");
 
         let user_message = &messages[1].content;
         assert!(
@@ -0,0 +1,282 @@
+//! Private notes a bot keeps for itself across turns of one game, such as
+//! cards it has counted or a plan it's working towards. The bot writes them
+//! after its command, following a `NOTES:` line, and they're shown back to
+//! it on its next turn. Stored per bot seat in `bot_memories`, which only
+//! this service reads, and only shown to the bot that wrote them: a bot
+//! taking over a seat starts afresh.
+//!
+//! When asked to explain itself, the bot also gives a short reason for its
+//! command on a `REASON:` line, which unlike its notes is meant to be read.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+
+/// Starts the notes section of a response.
+pub const NOTES_MARKER: &str = "NOTES:";
+
+/// Starts the reason section of a response.
+pub const REASON_MARKER: &str = "REASON:";
+
+/// A model's answer: the command to play, any notes it left itself and any
+/// reason it gave for the command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answer {
+    pub command: String,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// The rest of `line` after `marker`, if it starts with it.
+fn strip_marker<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    let trimmed = line.trim_start();
+    (trimmed.len() >= marker.len()
+        && trimmed.is_char_boundary(marker.len())
+        && trimmed[..marker.len()].eq_ignore_ascii_case(marker))
+    .then(|| &trimmed[marker.len()..])
+}
+
+/// Splits a response into sections at its `NOTES:` and `REASON:` lines.
+/// Everything before the first is the command, and each section runs
+/// (including the rest of its marker line) to the next. A response without
+/// notes, or with empty ones, keeps the old notes.
+pub fn split_answer(response: &str) -> Answer {
+    let mut command = vec![];
+    let mut notes = vec![];
+    let mut reason = vec![];
+    let mut section = &mut command;
+    for line in response.lines() {
+        if let Some(rest) = strip_marker(line, NOTES_MARKER) {
+            section = &mut notes;
+            section.push(rest);
+        } else if let Some(rest) = strip_marker(line, REASON_MARKER) {
+            section = &mut reason;
+            section.push(rest);
+        } else {
+            section.push(line);
+        }
+    }
+    let text = |lines: Vec<&str>| {
+        let text = lines.join("\n");
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    };
+    Answer {
+        command: command.join("\n").trim().to_string(),
+        notes: text(notes),
+        reason: text(reason),
+    }
+}
+
+/// The first `limit` characters of `notes`.
+pub fn cap(notes: &str, limit: usize) -> &str {
+    match notes.char_indices().nth(limit) {
+        Some((i, _)) => &notes[..i],
+        None => notes,
+    }
+}
+
+pub async fn load(
+    pool: &PgPool,
+    game_player_id: Uuid,
+    game_bot_id: Uuid,
+) -> Result<Option<String>> {
+    let row = sqlx::query(
+        "SELECT notes FROM bot_memories WHERE game_player_id = $1 AND game_bot_id = $2",
+    )
+    .bind(game_player_id)
+    .bind(game_bot_id)
+    .fetch_optional(pool)
+    .await
+    .context("Failed to load bot memory")?;
+    row.map(|r| r.try_get("notes").context("bot_memories.notes"))
+        .transpose()
+}
+
+pub async fn save(
+    pool: &PgPool,
+    game_player_id: Uuid,
+    game_bot_id: Uuid,
+    notes: &str,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO bot_memories (game_player_id, game_bot_id, notes) VALUES ($1, $2, $3) \
+         ON CONFLICT (game_player_id) DO UPDATE \
+         SET game_bot_id = EXCLUDED.game_bot_id, notes = EXCLUDED.notes, \
+             updated_at = now() AT TIME ZONE 'utc'",
+    )
+    .bind(game_player_id)
+    .bind(game_bot_id)
+    .bind(notes)
+    .execute(pool)
+    .await
+    .context("Failed to save bot memory")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_answer_separates_command_and_notes() {
+        assert_eq!(
+            Answer {
+                command: "play guard mick priest".to_string(),
+                notes: Some("Mick holds the priest.\nTwo guards gone.".to_string()),
+                reason: None,
+            },
+            split_answer(
+                "play guard mick priest\n\nnotes: Mick holds the priest.\nTwo guards gone.\n"
+            )
+        );
+        assert_eq!(
+            Answer {
+                command: "done".to_string(),
+                notes: Some("save tiles".to_string()),
+                reason: None,
+            },
+            split_answer("done\nNOTES:\nsave tiles")
+        );
+    }
+
+    #[test]
+    fn split_answer_without_notes_keeps_the_response() {
+        assert_eq!(
+            Answer {
+                command: "bid 3 5".to_string(),
+                notes: None,
+                reason: None,
+            },
+            split_answer(" bid 3 5\n")
+        );
+        assert_eq!(None, split_answer("call\nNOTES:  \n").notes);
+    }
+
+    #[test]
+    fn split_answer_separates_a_reason_in_either_order() {
+        let answer = Answer {
+            command: "play guard mick priest".to_string(),
+            notes: Some("Mick holds the priest.".to_string()),
+            reason: Some("Mick showed the priest last round.".to_string()),
+        };
+        assert_eq!(
+            answer,
+            split_answer(
+                "play guard mick priest\nREASON: Mick showed the priest last round.\n\
+                 NOTES: Mick holds the priest."
+            )
+        );
+        assert_eq!(
+            answer,
+            split_answer(
+                "play guard mick priest\nnotes: Mick holds the priest.\n\
+                 Reason: Mick showed the priest last round."
+            )
+        );
+    }
+
+    #[test]
+    fn cap_counts_characters_not_bytes() {
+        assert_eq!("ab", cap("abc", 2));
+        assert_eq!("äö", cap("äöü", 2));
+        assert_eq!("abc", cap("abc", 10));
+        assert_eq!("", cap("abc", 0));
+    }
+
+    /// A game with one seat, held by a bot; returns the seat and the bot.
+    async fn bot_seat(pool: &PgPool) -> (Uuid, Uuid) {
+        let game_type_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO game_types (name, player_counts, weight) \
+             VALUES ('Memory Test', '{2}', 1.0) RETURNING id",
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        let game_version_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO game_versions (game_type_id, name, uri, is_public, is_deprecated) \
+             VALUES ($1, 'v1', 'http://unused', true, false) RETURNING id",
+        )
+        .bind(game_type_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        let game_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO games (game_version_id, is_finished, game_state) \
+             VALUES ($1, false, '{}') RETURNING id",
+        )
+        .bind(game_version_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        let game_bot_id = add_bot(pool, game_id, "Bot 1").await;
+        let seat: Uuid = sqlx::query_scalar(
+            "INSERT INTO game_players \
+             (game_id, game_bot_id, position, color, has_accepted, is_turn, is_turn_at, \
+              last_turn_at, is_eliminated, is_read) \
+             VALUES ($1, $2, 0, 'Green', true, true, now(), now(), false, false) RETURNING id",
+        )
+        .bind(game_id)
+        .bind(game_bot_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        (seat, game_bot_id)
+    }
+
+    async fn add_bot(pool: &PgPool, game_id: Uuid, name: &str) -> Uuid {
+        sqlx::query_scalar(
+            "INSERT INTO game_bots (game_id, name, bot_name) VALUES ($1, $2, 'easy') RETURNING id",
+        )
+        .bind(game_id)
+        .bind(name)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../web/migrations")]
+    async fn save_replaces_a_seats_notes(pool: PgPool) {
+        let (seat, bot) = bot_seat(&pool).await;
+        assert_eq!(None, load(&pool, seat, bot).await.unwrap());
+        save(&pool, seat, bot, "first").await.unwrap();
+        save(&pool, seat, bot, "second").await.unwrap();
+        assert_eq!(
+            Some("second".to_string()),
+            load(&pool, seat, bot).await.unwrap()
+        );
+    }
+
+    #[sqlx::test(migrations = "../web/migrations")]
+    async fn a_new_occupant_does_not_inherit_notes(pool: PgPool) {
+        let (seat, first) = bot_seat(&pool).await;
+        save(&pool, seat, first, "counted two 7s").await.unwrap();
+        assert_eq!(
+            Some("counted two 7s".to_string()),
+            load(&pool, seat, first).await.unwrap()
+        );
+
+        let game_id: Uuid = sqlx::query_scalar("SELECT game_id FROM game_players WHERE id = $1")
+            .bind(seat)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let second = add_bot(&pool, game_id, "Bot 2").await;
+        sqlx::query("UPDATE game_players SET game_bot_id = $1 WHERE id = $2")
+            .bind(second)
+            .bind(seat)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(None, load(&pool, seat, second).await.unwrap());
+
+        save(&pool, seat, second, "fresh start").await.unwrap();
+        assert_eq!(
+            Some("fresh start".to_string()),
+            load(&pool, seat, second).await.unwrap()
+        );
+        assert_eq!(None, load(&pool, seat, first).await.unwrap());
+    }
+}
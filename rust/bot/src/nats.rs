@@ -1,6 +1,7 @@
 //! Minimal NATS/JetStream constants and event types for the bot, re-exported
 //! from the shared `brdgme_nats` wire-protocol crate (R-14). The bot only ever
-//! consumes `bot.turn` and publishes `bot.command`; the monolith owns creating
+//! consumes `bot.turn`, publishes `bot.command` and answers `bot.hint`
+//! requests; the monolith owns creating
 //! the stream and both durable consumers on its own startup (see
 //! docs/changes/archive/2026-07-05-13-nats-bot-eventing/plan.md).
 
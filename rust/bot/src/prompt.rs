@@ -2,25 +2,25 @@ use std::sync::LazyLock;
 
 use brdgme_game::command::Spec;
 use minijinja::{Environment, context};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 const SYSTEM_TEMPLATE: &str = include_str!("../system_prompt.md");
 const USER_TEMPLATE: &str = include_str!("../user_prompt.md");
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerInfo {
     pub name: String,
     pub colour: String,
     pub is_me: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FailedCommand {
     pub command: String,
     pub error: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemContext {
     pub game_rules: String,
     pub include_basic_strategy: bool,
@@ -28,9 +28,10 @@ pub struct SystemContext {
     pub include_advanced_strategy: bool,
     pub advanced_strategy: String,
     pub data_docs: String,
+    pub use_tools: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserContext {
     pub my_name: String,
     pub my_colour: String,
@@ -38,8 +39,21 @@ pub struct UserContext {
     pub pub_state_yaml: String,
     pub player_state_yaml: String,
     pub command_spec: String,
+    /// Every command the spec allows, when there are few enough to list.
+    pub candidate_commands: Vec<String>,
     pub recent_logs: Vec<String>,
     pub failed_commands: Vec<FailedCommand>,
+    /// Characters of notes the bot may keep; 0 when memory is off.
+    pub memory_chars: usize,
+    /// The notes the bot left itself last turn.
+    pub notes: String,
+    /// Whether to give a reason for the command after it.
+    #[serde(default)]
+    pub explain: bool,
+    /// Whether only the seat's own player reads the reason, as with a hint.
+    /// Otherwise every player does, so it mustn't give away private state.
+    #[serde(default)]
+    pub reason_is_private: bool,
 }
 
 /// Resolve `{{player N}}` references in brdgme markup to player names.
@@ -120,28 +134,53 @@ static USER_ENV: LazyLock<Environment<'static>> = LazyLock::new(|| {
 
 pub fn render_system(ctx: &SystemContext) -> Result<String, minijinja::Error> {
     let tmpl = SYSTEM_ENV.get_template("prompt")?;
-    tmpl.render(context! {
+    tmpl.render(system_values(ctx))
+}
+
+pub fn render_user(ctx: &UserContext) -> Result<String, minijinja::Error> {
+    let tmpl = USER_ENV.get_template("prompt")?;
+    tmpl.render(user_values(ctx))
+}
+
+/// Renders `ctx` with a system prompt template other than the built-in one,
+/// such as a draft being evaluated.
+pub fn render_system_from(template: &str, ctx: &SystemContext) -> Result<String, minijinja::Error> {
+    Environment::new().render_str(template, system_values(ctx))
+}
+
+/// Renders `ctx` with a user prompt template other than the built-in one.
+pub fn render_user_from(template: &str, ctx: &UserContext) -> Result<String, minijinja::Error> {
+    Environment::new().render_str(template, user_values(ctx))
+}
+
+fn system_values(ctx: &SystemContext) -> minijinja::Value {
+    context! {
         game_rules => &ctx.game_rules,
         include_basic_strategy => ctx.include_basic_strategy,
         basic_strategy => &ctx.basic_strategy,
         include_advanced_strategy => ctx.include_advanced_strategy,
         advanced_strategy => &ctx.advanced_strategy,
         data_docs => &ctx.data_docs,
-    })
+        use_tools => ctx.use_tools,
+    }
 }
 
-pub fn render_user(ctx: &UserContext) -> Result<String, minijinja::Error> {
-    let tmpl = USER_ENV.get_template("prompt")?;
-    tmpl.render(context! {
+fn user_values(ctx: &UserContext) -> minijinja::Value {
+    context! {
         my_name => &ctx.my_name,
         my_colour => &ctx.my_colour,
         players => &ctx.players,
         pub_state_yaml => &ctx.pub_state_yaml,
         player_state_yaml => &ctx.player_state_yaml,
         command_spec => &ctx.command_spec,
+        candidate_commands => &ctx.candidate_commands,
         recent_logs => &ctx.recent_logs,
         failed_commands => &ctx.failed_commands,
-    })
+        memory_chars => ctx.memory_chars,
+        notes => &ctx.notes,
+        explain => ctx.explain,
+        reason_is_private => ctx.reason_is_private,
+    }
 }
 
 #[cfg(test)]
@@ -156,6 +195,7 @@ mod tests {
             include_advanced_strategy: false,
             advanced_strategy: "Track tile counts.".to_string(),
             data_docs: "board: the board grid".to_string(),
+            use_tools: false,
         }
     }
 
@@ -178,11 +218,16 @@ mod tests {
             pub_state_yaml: "board: empty\nround: 1".to_string(),
             player_state_yaml: "hand:\n  - A\n  - K".to_string(),
             command_spec: "Token: done".to_string(),
+            candidate_commands: vec![],
             recent_logs: vec![
                 "Alice placed {{b}}C4{{/b}}".to_string(),
                 "Bob bought 2 Sackson".to_string(),
             ],
             failed_commands: vec![],
+            memory_chars: 0,
+            notes: String::new(),
+            explain: false,
+            reason_is_private: false,
         }
     }
 
@@ -357,6 +402,81 @@ mod tests {
         assert!(output.contains("minimum 1 share"), "error 2 missing");
     }
 
+    #[test]
+    fn render_user_numbers_candidate_commands_when_present() {
+        let output = render_user(&user_ctx()).unwrap();
+        assert!(
+            !output.contains("Your available commands"),
+            "menu shown when there are no candidates"
+        );
+
+        let mut ctx = user_ctx();
+        ctx.candidate_commands = vec!["take".to_string(), "pass".to_string()];
+        let output = render_user(&ctx).unwrap();
+        assert!(
+            output.contains("Your available commands"),
+            "menu header missing"
+        );
+        assert!(
+            output.contains("1. take\n"),
+            "first entry missing: {output}"
+        );
+        assert!(
+            output.contains("2. pass\n"),
+            "second entry missing: {output}"
+        );
+    }
+
+    #[test]
+    fn render_user_shows_notes_only_with_memory() {
+        let mut ctx = user_ctx();
+        ctx.notes = "Bob holds the princess.".to_string();
+        let output = render_user(&ctx).unwrap();
+        assert!(
+            !output.contains("NOTES:"),
+            "notes format shown without memory"
+        );
+        assert!(
+            !output.contains("Bob holds the princess"),
+            "notes shown without memory"
+        );
+
+        ctx.memory_chars = 500;
+        let output = render_user(&ctx).unwrap();
+        assert!(output.contains("NOTES:"), "notes format missing");
+        assert!(output.contains("500 characters"), "notes cap missing");
+        assert!(
+            output.contains("Bob holds the princess"),
+            "previous notes missing"
+        );
+    }
+
+    #[test]
+    fn render_user_asks_for_a_reason_only_when_explaining() {
+        let mut ctx = user_ctx();
+        let output = render_user(&ctx).unwrap();
+        assert!(!output.contains("REASON:"), "reason asked for: {output}");
+
+        ctx.explain = true;
+        let output = render_user(&ctx).unwrap();
+        assert!(output.contains("REASON:"), "reason format missing");
+        assert!(
+            output.contains("on a single line, followed by your reason,"),
+            "final instruction doesn't mention the reason: {output}"
+        );
+        assert!(
+            output.contains("Every player reads it"),
+            "public reason isn't warned about: {output}"
+        );
+
+        ctx.reason_is_private = true;
+        let output = render_user(&ctx).unwrap();
+        assert!(
+            !output.contains("Every player reads it"),
+            "private reason warned about: {output}"
+        );
+    }
+
     #[test]
     fn render_user_ends_with_command_instruction() {
         let output = render_user(&user_ctx()).unwrap();
@@ -1,23 +1,295 @@
+//! Picks which provider serves each LLM call. Providers are tried a priority
+//! tier at a time, in a weighted random order within the tier, skipping any
+//! whose circuit is open. Health is shared across turns, so a dead provider
+//! costs a few failed calls rather than a timeout on every turn.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use rand::prelude::*;
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
 use crate::config::ProviderConfig;
 
+/// Calls kept per provider for the rolling error rate and latency.
+const WINDOW: usize = 20;
+/// Consecutive failures that open a provider's circuit.
+const FAILURE_THRESHOLD: u32 = 3;
+/// How long an open circuit skips its provider before letting a probe
+/// through. A probe that never reports back is given up on after as long.
+const COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Identifies a provider's health: the same endpoint and model share it
+/// across bots.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct HealthKey {
+    url: String,
+    model: String,
+}
+
+impl HealthKey {
+    fn of(provider: &ProviderConfig) -> Self {
+        Self {
+            url: provider.url.clone(),
+            model: provider.model.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Circuit {
+    /// Taking calls.
+    Closed,
+    /// Skipped until the cooldown ends.
+    Open,
+    /// Cooled down, with one probe call in flight deciding whether to close.
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Outcome {
+    ok: bool,
+    latency: Duration,
+}
+
 #[derive(Debug)]
-pub struct ProviderRouter {
+struct Health {
+    provider_id: Option<Uuid>,
+    outcomes: VecDeque<Outcome>,
+    consecutive_failures: u32,
+    circuit: Circuit,
+    /// When the circuit last opened or started probing.
+    since: Instant,
+}
+
+impl Health {
+    fn new(provider_id: Option<Uuid>, now: Instant) -> Self {
+        Self {
+            provider_id,
+            outcomes: VecDeque::with_capacity(WINDOW),
+            consecutive_failures: 0,
+            circuit: Circuit::Closed,
+            since: now,
+        }
+    }
+
+    /// Whether a call may go to the provider now, moving an open circuit
+    /// that has cooled down to half-open for the probe.
+    fn admit(&mut self, now: Instant) -> bool {
+        match self.circuit {
+            Circuit::Closed => true,
+            Circuit::Open | Circuit::HalfOpen if now.duration_since(self.since) >= COOLDOWN => {
+                self.circuit = Circuit::HalfOpen;
+                self.since = now;
+                true
+            }
+            Circuit::Open | Circuit::HalfOpen => false,
+        }
+    }
+
+    fn record(&mut self, outcome: Outcome, now: Instant) {
+        if self.outcomes.len() == WINDOW {
+            self.outcomes.pop_front();
+        }
+        self.outcomes.push_back(outcome);
+        if outcome.ok {
+            self.consecutive_failures = 0;
+            self.circuit = Circuit::Closed;
+            return;
+        }
+        self.consecutive_failures += 1;
+        if self.circuit == Circuit::HalfOpen || self.consecutive_failures >= FAILURE_THRESHOLD {
+            self.circuit = Circuit::Open;
+            self.since = now;
+        }
+    }
+
+    fn status(&self, key: &HealthKey) -> ProviderStatus {
+        let failures = self.outcomes.iter().filter(|o| !o.ok).count();
+        let successes: Vec<Duration> = self
+            .outcomes
+            .iter()
+            .filter(|o| o.ok)
+            .map(|o| o.latency)
+            .collect();
+        ProviderStatus {
+            provider_id: self.provider_id,
+            url: key.url.clone(),
+            model: key.model.clone(),
+            circuit: self.circuit,
+            samples: self.outcomes.len(),
+            error_rate: if self.outcomes.is_empty() {
+                0.0
+            } else {
+                failures as f32 / self.outcomes.len() as f32
+            },
+            avg_latency_ms: (!successes.is_empty()).then(|| {
+                (successes.iter().sum::<Duration>() / successes.len() as u32).as_millis() as u64
+            }),
+            consecutive_failures: self.consecutive_failures,
+        }
+    }
+}
+
+/// A provider's health as reported on `/healthz` and to the admin pages.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProviderStatus {
+    pub provider_id: Option<Uuid>,
+    pub url: String,
+    pub model: String,
+    pub circuit: Circuit,
+    pub samples: usize,
+    pub error_rate: f32,
+    /// Mean latency of the successful calls in the window.
+    pub avg_latency_ms: Option<u64>,
+    pub consecutive_failures: u32,
+}
+
+impl Circuit {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Reports a provider's status to `llm_provider_health` for the admin
+/// pages. The env fallback provider has no row to report against.
+pub async fn save_status(pool: &PgPool, status: &ProviderStatus) -> Result<()> {
+    let Some(provider_id) = status.provider_id else {
+        return Ok(());
+    };
+    sqlx::query(
+        "INSERT INTO llm_provider_health \
+         (provider_id, model, circuit, samples, error_rate, avg_latency_ms, consecutive_failures) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) \
+         ON CONFLICT (provider_id, model) DO UPDATE SET \
+         circuit = EXCLUDED.circuit, samples = EXCLUDED.samples, \
+         error_rate = EXCLUDED.error_rate, avg_latency_ms = EXCLUDED.avg_latency_ms, \
+         consecutive_failures = EXCLUDED.consecutive_failures, \
+         updated_at = now() AT TIME ZONE 'utc'",
+    )
+    .bind(provider_id)
+    .bind(&status.model)
+    .bind(status.circuit.as_str())
+    .bind(status.samples as i32)
+    .bind(status.error_rate)
+    .bind(
+        status
+            .avg_latency_ms
+            .map(|ms| ms.min(i32::MAX as u64) as i32),
+    )
+    .bind(status.consecutive_failures as i32)
+    .execute(pool)
+    .await
+    .context("Failed to save provider health")?;
+    Ok(())
+}
+
+/// Provider health shared by every turn the process runs.
+#[derive(Debug, Default)]
+pub struct HealthRegistry {
+    health: Mutex<HashMap<HealthKey, Health>>,
+}
+
+impl HealthRegistry {
+    fn admit(&self, provider: &ProviderConfig, now: Instant) -> bool {
+        let mut health = self.health.lock().expect("health lock poisoned");
+        health
+            .entry(HealthKey::of(provider))
+            .or_insert_with(|| Health::new(provider.provider_id, now))
+            .admit(now)
+    }
+
+    /// Records a call's outcome, returning the provider's updated status.
+    pub fn record(
+        &self,
+        provider: &ProviderConfig,
+        ok: bool,
+        latency: Duration,
+        now: Instant,
+    ) -> ProviderStatus {
+        let key = HealthKey::of(provider);
+        let mut health = self.health.lock().expect("health lock poisoned");
+        let entry = health
+            .entry(key.clone())
+            .or_insert_with(|| Health::new(provider.provider_id, now));
+        entry.record(Outcome { ok, latency }, now);
+        entry.status(&key)
+    }
+
+    pub fn snapshot(&self) -> Vec<ProviderStatus> {
+        let health = self.health.lock().expect("health lock poisoned");
+        let mut statuses: Vec<ProviderStatus> =
+            health.iter().map(|(key, h)| h.status(key)).collect();
+        statuses.sort_by(|a, b| (&a.url, &a.model).cmp(&(&b.url, &b.model)));
+        statuses
+    }
+}
+
+/// One turn's walk through its bot's providers.
+#[derive(Debug)]
+pub struct ProviderRouter<'a> {
     providers: Vec<ProviderConfig>,
+    health: &'a HealthRegistry,
     index: usize,
+    /// The index already admitted, so retries on it don't need a new probe.
+    admitted: Option<usize>,
 }
 
-impl ProviderRouter {
-    pub fn new(providers: Vec<ProviderConfig>) -> Self {
+impl<'a> ProviderRouter<'a> {
+    /// Orders `providers` by priority, shuffling each tier so a provider
+    /// comes first in proportion to its weight.
+    pub fn new<R: Rng + ?Sized>(
+        providers: Vec<ProviderConfig>,
+        health: &'a HealthRegistry,
+        rng: &mut R,
+    ) -> Self {
+        // Weighted random order (Efraimidis-Spirakis): sort by u^(1/w).
+        let mut keyed: Vec<(f64, ProviderConfig)> = providers
+            .into_iter()
+            .map(|p| {
+                let key = rng.random::<f64>().powf(1.0 / f64::from(p.weight.max(1)));
+                (key, p)
+            })
+            .collect();
+        keyed.sort_by(|(ka, a), (kb, b)| a.priority.cmp(&b.priority).then(kb.total_cmp(ka)));
         Self {
-            providers,
+            providers: keyed.into_iter().map(|(_, p)| p).collect(),
+            health,
             index: 0,
+            admitted: None,
         }
     }
 
+    /// The provider to call, skipping any whose circuit is open.
     pub fn current(&mut self) -> Option<&ProviderConfig> {
+        let now = Instant::now();
+        while self.admitted != Some(self.index) {
+            let provider = self.providers.get(self.index)?;
+            if self.health.admit(provider, now) {
+                self.admitted = Some(self.index);
+            } else {
+                tracing::info!(provider_url = %provider.url, model = %provider.model, "provider_circuit_open");
+                self.index += 1;
+            }
+        }
         self.providers.get(self.index)
     }
 
+    /// Records the current provider's call, returning its updated status.
+    pub fn record(&self, ok: bool, latency: Duration) -> Option<ProviderStatus> {
+        let provider = self.providers.get(self.index)?;
+        Some(self.health.record(provider, ok, latency, Instant::now()))
+    }
+
     pub fn fail_over(&mut self) {
         self.index = self.index.saturating_add(1);
     }
@@ -26,28 +298,52 @@ impl ProviderRouter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::ProviderKind;
+    use brdgme_game::rng::GameRng;
 
-    fn provider(model: &str, priority: i32) -> ProviderConfig {
+    fn provider(model: &str, priority: i32, weight: i32) -> ProviderConfig {
         ProviderConfig {
+            kind: ProviderKind::OpenAi,
+            provider_id: None,
             url: "http://x".to_string(),
             api_key: None,
             model: model.to_string(),
             reasoning_effort: None,
             extra_body: None,
+            structured_output: None,
             priority,
+            weight,
+            input_usd_per_mtok: 0.0,
+            output_usd_per_mtok: 0.0,
+            budget: Default::default(),
         }
     }
 
+    fn router<'a>(
+        providers: Vec<ProviderConfig>,
+        health: &'a HealthRegistry,
+    ) -> ProviderRouter<'a> {
+        ProviderRouter::new(providers, health, &mut GameRng::seed_from_u64(1))
+    }
+
     #[test]
     fn empty_router_returns_none() {
-        let mut router = ProviderRouter::new(vec![]);
+        let health = HealthRegistry::default();
+        let mut router = router(vec![], &health);
         assert!(router.current().is_none());
     }
 
     #[test]
     fn walks_providers_in_order_then_exhausts() {
-        let mut router =
-            ProviderRouter::new(vec![provider("a", 0), provider("b", 1), provider("c", 2)]);
+        let health = HealthRegistry::default();
+        let mut router = router(
+            vec![
+                provider("c", 2, 1),
+                provider("a", 0, 1),
+                provider("b", 1, 1),
+            ],
+            &health,
+        );
 
         assert_eq!(router.current().unwrap().model, "a");
         router.fail_over();
@@ -60,9 +356,96 @@ mod tests {
 
     #[test]
     fn fail_over_past_end_stays_none() {
-        let mut router = ProviderRouter::new(vec![provider("a", 0)]);
+        let health = HealthRegistry::default();
+        let mut router = router(vec![provider("a", 0, 1)], &health);
         router.fail_over();
         router.fail_over();
         assert!(router.current().is_none());
     }
+
+    #[test]
+    fn balances_a_tier_by_weight() {
+        let health = HealthRegistry::default();
+        let mut rng = GameRng::seed_from_u64(7);
+        let mut heavy_first = 0;
+        for _ in 0..1000 {
+            let mut router = ProviderRouter::new(
+                vec![
+                    provider("light", 0, 1),
+                    provider("heavy", 0, 3),
+                    provider("fallback", 1, 100),
+                ],
+                &health,
+                &mut rng,
+            );
+            if router.current().unwrap().model == "heavy" {
+                heavy_first += 1;
+            }
+            router.fail_over();
+            router.fail_over();
+            assert_eq!("fallback", router.current().unwrap().model);
+        }
+        // 3:1 weights put the heavy provider first about 750 times in 1000.
+        assert!((700..800).contains(&heavy_first), "{heavy_first}");
+    }
+
+    #[test]
+    fn open_circuits_are_skipped_until_a_probe_closes_them() {
+        let health = HealthRegistry::default();
+        let dead = provider("dead", 0, 1);
+        let now = Instant::now();
+        for i in 0..FAILURE_THRESHOLD {
+            assert!(health.admit(&dead, now));
+            let status = health.record(&dead, false, Duration::from_secs(30), now);
+            let expected = if i + 1 < FAILURE_THRESHOLD {
+                Circuit::Closed
+            } else {
+                Circuit::Open
+            };
+            assert_eq!(expected, status.circuit);
+        }
+        assert!(!health.admit(&dead, now));
+
+        // A turn starting now goes straight to the next tier.
+        let mut router = router(vec![dead.clone(), provider("backup", 1, 1)], &health);
+        assert_eq!("backup", router.current().unwrap().model);
+
+        // After the cooldown one probe goes through; a failure reopens it.
+        let later = now + COOLDOWN;
+        assert!(health.admit(&dead, later));
+        assert!(!health.admit(&dead, later));
+        let status = health.record(&dead, false, Duration::from_secs(1), later);
+        assert_eq!(Circuit::Open, status.circuit);
+        assert!(!health.admit(&dead, later));
+
+        // A successful probe closes it again.
+        let much_later = later + COOLDOWN;
+        assert!(health.admit(&dead, much_later));
+        let status = health.record(&dead, true, Duration::from_millis(400), much_later);
+        assert_eq!(Circuit::Closed, status.circuit);
+        assert_eq!(0, status.consecutive_failures);
+        assert_eq!(5, status.samples);
+        assert_eq!(0.8, status.error_rate);
+        assert_eq!(Some(400), status.avg_latency_ms);
+        assert!(health.admit(&dead, much_later));
+    }
+
+    #[test]
+    fn retries_on_a_probing_provider_stay_with_it() {
+        let health = HealthRegistry::default();
+        let flaky = provider("flaky", 0, 1);
+        let past = Instant::now() - COOLDOWN;
+        for _ in 0..FAILURE_THRESHOLD {
+            health.record(&flaky, false, Duration::ZERO, past);
+        }
+        let mut router = router(vec![flaky, provider("backup", 1, 1)], &health);
+        assert_eq!("flaky", router.current().unwrap().model);
+        // The probe's own retry isn't refused as a second probe.
+        assert_eq!("flaky", router.current().unwrap().model);
+        assert_eq!(
+            Circuit::HalfOpen,
+            health.snapshot()[0].circuit,
+            "circuit should be half-open while probing"
+        );
+    }
 }
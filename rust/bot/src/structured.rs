@@ -0,0 +1,308 @@
+use anyhow::{Context, Result, anyhow};
+use brdgme_game::command::grammar::Grammar;
+use serde_json::{Value, json};
+
+use crate::memory::{Answer, NOTES_MARKER, REASON_MARKER, split_answer};
+
+/// How a provider constrains output to the command grammar, from
+/// `llm_providers.structured_output`. Providers without one get free-form
+/// chat, validated after the fact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuredOutput {
+    /// llama.cpp's `grammar` field, taking GBNF.
+    Gbnf,
+    /// vLLM's `guided_regex` field.
+    Regex,
+    /// OpenAI-style `response_format` with a JSON schema, for servers that
+    /// support nothing else. The command comes back wrapped in an object.
+    JsonSchema,
+}
+
+impl StructuredOutput {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "gbnf" => Some(Self::Gbnf),
+            "regex" => Some(Self::Regex),
+            "json_schema" => Some(Self::JsonSchema),
+            _ => None,
+        }
+    }
+
+    /// The command, and any notes and reason, in a constrained response.
+    pub fn extract_answer(self, content: &str) -> Result<Answer> {
+        match self {
+            Self::Gbnf | Self::Regex => Ok(split_answer(content)),
+            Self::JsonSchema => {
+                let value: Value = serde_json::from_str(content.trim())
+                    .context("structured response is not JSON")?;
+                let command = value
+                    .get("command")
+                    .and_then(Value::as_str)
+                    .map(|c| c.trim().to_string())
+                    .ok_or_else(|| anyhow!("structured response has no command string"))?;
+                let notes = value
+                    .get("notes")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|n| !n.is_empty())
+                    .map(str::to_string);
+                let reason = value
+                    .get("reason")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|r| !r.is_empty())
+                    .map(str::to_string);
+                Ok(Answer {
+                    command,
+                    notes,
+                    reason,
+                })
+            }
+        }
+    }
+}
+
+/// A turn's output constraint: the command grammar, in the provider's
+/// format. With `reason`, the command must be followed by a one-line reason
+/// for it; with `notes`, it may be followed by a notes section for the bot's
+/// memory.
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    pub mode: StructuredOutput,
+    pub grammar: Grammar,
+    pub notes: bool,
+    pub reason: bool,
+}
+
+impl Constraint {
+    pub fn gbnf(&self) -> String {
+        if !self.notes && !self.reason {
+            return self.grammar.gbnf();
+        }
+        let mut root = "root ::= command".to_string();
+        let mut rules = self.grammar.gbnf().replacen("root ::=", "command ::=", 1);
+        if self.reason {
+            root.push_str(" reason");
+            rules.push_str(&format!("reason ::= \"\\n{}\" [^\\n]*\n", REASON_MARKER));
+        }
+        if self.notes {
+            root.push_str(" notes?");
+            rules.push_str(&format!(
+                "notes ::= \"\\n{}\" ([^\\n] | \"\\n\")*\n",
+                NOTES_MARKER
+            ));
+        }
+        format!("{}\n{}", root, rules)
+    }
+
+    pub fn regex(&self) -> String {
+        if !self.notes && !self.reason {
+            return self.grammar.regex();
+        }
+        let mut regex = format!("(?:{})", self.grammar.regex());
+        if self.reason {
+            regex.push_str(&format!("\\n{}.*", REASON_MARKER));
+        }
+        if self.notes {
+            regex.push_str(&format!("(?:\\n{}(?:.|\\n)*)?", NOTES_MARKER));
+        }
+        regex
+    }
+
+    pub fn json_schema(&self) -> Value {
+        let mut schema = self.grammar.json_schema();
+        // Strict mode wants every property required, so no notes is an
+        // empty string.
+        let mut required = vec!["command"];
+        if self.reason {
+            schema["properties"]["reason"] = json!({ "type": "string" });
+            required.push("reason");
+        }
+        if self.notes {
+            schema["properties"]["notes"] = json!({ "type": "string" });
+            required.push("notes");
+        }
+        if required.len() > 1 {
+            schema["required"] = json!(required);
+        }
+        schema
+    }
+
+    /// The OpenAI-compatible request fields for the constraint, merged into
+    /// the request body before the provider's `extra_body`.
+    pub fn request_fields(&self) -> Value {
+        match self.mode {
+            StructuredOutput::Gbnf => json!({ "grammar": self.gbnf() }),
+            StructuredOutput::Regex => json!({ "guided_regex": self.regex() }),
+            StructuredOutput::JsonSchema => json!({
+                "response_format": {
+                    "type": "json_schema",
+                    "json_schema": {
+                        "name": "command",
+                        "strict": true,
+                        "schema": self.json_schema(),
+                    },
+                },
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brdgme_game::command::Spec;
+
+    fn grammar() -> Grammar {
+        Spec::OneOf(vec![
+            Spec::Token("call".into()),
+            Spec::Chain(vec![
+                Spec::Token("bid".into()),
+                Spec::Space,
+                Spec::Int {
+                    min: Some(1),
+                    max: Some(6),
+                },
+            ]),
+        ])
+        .grammar(&[])
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_config_values() {
+        assert_eq!(
+            Some(StructuredOutput::Gbnf),
+            StructuredOutput::parse("gbnf")
+        );
+        assert_eq!(
+            Some(StructuredOutput::Regex),
+            StructuredOutput::parse("regex")
+        );
+        assert_eq!(
+            Some(StructuredOutput::JsonSchema),
+            StructuredOutput::parse("json_schema")
+        );
+        assert_eq!(None, StructuredOutput::parse("GBNF"));
+    }
+
+    fn constraint(mode: StructuredOutput, notes: bool) -> Constraint {
+        Constraint {
+            mode,
+            grammar: grammar(),
+            notes,
+            reason: false,
+        }
+    }
+
+    #[test]
+    fn request_fields_carry_the_grammar() {
+        let g = grammar();
+        assert_eq!(
+            json!(g.gbnf()),
+            constraint(StructuredOutput::Gbnf, false).request_fields()["grammar"]
+        );
+        assert_eq!(
+            json!("call|bid [1-6]"),
+            constraint(StructuredOutput::Regex, false).request_fields()["guided_regex"]
+        );
+        let fields = constraint(StructuredOutput::JsonSchema, false).request_fields();
+        assert_eq!(
+            g.json_schema(),
+            fields["response_format"]["json_schema"]["schema"]
+        );
+    }
+
+    #[test]
+    fn request_fields_make_room_for_notes() {
+        let g = grammar();
+        assert_eq!(
+            json!(format!(
+                "root ::= command notes?\ncommand ::= {}notes ::= \"\\nNOTES:\" ([^\\n] | \"\\n\")*\n",
+                &g.gbnf()["root ::= ".len()..]
+            )),
+            constraint(StructuredOutput::Gbnf, true).request_fields()["grammar"]
+        );
+        let regex = constraint(StructuredOutput::Regex, true).regex();
+        let regex = regex::Regex::new(&format!("^(?:{})$", regex)).unwrap();
+        assert!(regex.is_match("bid 3"));
+        assert!(regex.is_match("bid 3\nNOTES: Bob is bluffing\nabout sixes"));
+        assert!(!regex.is_match("bid 3 Bob is bluffing"));
+        let fields = constraint(StructuredOutput::JsonSchema, true).request_fields();
+        let schema = &fields["response_format"]["json_schema"]["schema"];
+        assert_eq!(json!({"type": "string"}), schema["properties"]["notes"]);
+        assert_eq!(json!(["command", "notes"]), schema["required"]);
+    }
+
+    #[test]
+    fn request_fields_require_a_reason() {
+        let with_reason = |mode, notes| Constraint {
+            reason: true,
+            ..constraint(mode, notes)
+        };
+        let gbnf = with_reason(StructuredOutput::Gbnf, true).gbnf();
+        assert!(gbnf.starts_with("root ::= command reason notes?\n"));
+        assert!(gbnf.contains("reason ::= \"\\nREASON:\" [^\\n]*\n"));
+        let regex = with_reason(StructuredOutput::Regex, true).regex();
+        let regex = regex::Regex::new(&format!("^(?:{})$", regex)).unwrap();
+        assert!(regex.is_match("bid 3\nREASON: Bob is bluffing"));
+        assert!(regex.is_match("bid 3\nREASON: Bob is bluffing\nNOTES: plan\nmore"));
+        assert!(!regex.is_match("bid 3"));
+        assert!(!regex.is_match("bid 3\nREASON: Bob is\nbluffing"));
+        let fields = with_reason(StructuredOutput::JsonSchema, false).request_fields();
+        let schema = &fields["response_format"]["json_schema"]["schema"];
+        assert_eq!(json!({"type": "string"}), schema["properties"]["reason"]);
+        assert_eq!(json!(["command", "reason"]), schema["required"]);
+        assert_eq!(
+            Some("Bob is bluffing".to_string()),
+            StructuredOutput::JsonSchema
+                .extract_answer(r#"{"command": "bid 3", "reason": " Bob is bluffing"}"#)
+                .unwrap()
+                .reason
+        );
+    }
+
+    #[test]
+    fn extracts_answers() {
+        assert_eq!(
+            Answer {
+                command: "bid 3".to_string(),
+                notes: None,
+                reason: None,
+            },
+            StructuredOutput::Regex.extract_answer(" bid 3\n").unwrap()
+        );
+        assert_eq!(
+            Answer {
+                command: "bid 3".to_string(),
+                notes: Some("plan".to_string()),
+                reason: None,
+            },
+            StructuredOutput::Gbnf
+                .extract_answer("bid 3\nNOTES: plan")
+                .unwrap()
+        );
+        assert_eq!(
+            Answer {
+                command: "bid 3".to_string(),
+                notes: None,
+                reason: None,
+            },
+            StructuredOutput::JsonSchema
+                .extract_answer(r#"{"command": "bid 3", "notes": ""}"#)
+                .unwrap()
+        );
+        assert_eq!(
+            Some("plan".to_string()),
+            StructuredOutput::JsonSchema
+                .extract_answer(r#"{"command": "bid 3", "notes": " plan "}"#)
+                .unwrap()
+                .notes
+        );
+        assert!(
+            StructuredOutput::JsonSchema
+                .extract_answer("bid 3")
+                .is_err()
+        );
+    }
+}
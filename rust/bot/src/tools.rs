@@ -0,0 +1,354 @@
+//! Tools the bot can call through the provider's function-calling API before
+//! committing to a command: `suggest` completions for a partial command,
+//! `preview` whether a command is accepted, and read a `rules_section`.
+
+use brdgme_cmd::api::{Request, Response};
+use brdgme_game_client::GameData;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCall {
+    pub id: String,
+    #[serde(rename = "type", default = "function_type")]
+    pub kind: String,
+    pub function: FunctionCall,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionCall {
+    pub name: String,
+    /// JSON-encoded arguments, as the function-calling API sends them.
+    pub arguments: String,
+}
+
+fn function_type() -> String {
+    "function".to_string()
+}
+
+/// Everything a tool needs to answer for the current turn.
+pub struct ToolContext<'a> {
+    pub game_http: &'a reqwest::Client,
+    pub game_service_uri: &'a str,
+    pub version_name: &'a str,
+    pub game_state: &'a str,
+    pub game_data: &'a GameData,
+    pub names: &'a [String],
+    pub player: usize,
+}
+
+/// Tool definitions in the chat completions `tools` format.
+pub fn definitions() -> Vec<Value> {
+    vec![
+        function(
+            "suggest",
+            "List the ways a partial command can continue, per the command parser rules. Pass an empty string to list the commands available now.",
+            "partial_command",
+            "The start of a command, e.g. \"play \"",
+        ),
+        function(
+            "preview",
+            "Check a command against the game without playing it. Returns only whether it's accepted, never its outcome.",
+            "command",
+            "The full command to preview",
+        ),
+        function(
+            "rules_section",
+            "Read one section of the game rules by its heading. Lists the headings if there's no match.",
+            "name",
+            "The section heading, e.g. \"Scoring\"",
+        ),
+    ]
+}
+
+fn function(name: &str, description: &str, arg: &str, arg_description: &str) -> Value {
+    json!({
+        "type": "function",
+        "function": {
+            "name": name,
+            "description": description,
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    arg: { "type": "string", "description": arg_description },
+                },
+                "required": [arg],
+            },
+        },
+    })
+}
+
+/// Runs a tool call, answering problems with the call itself in the result
+/// so the model can correct them.
+pub async fn run(call: &ToolCall, ctx: &ToolContext<'_>) -> String {
+    let args: Value = match serde_json::from_str(&call.function.arguments) {
+        Ok(args) => args,
+        Err(e) => return format!("Error: arguments are not valid JSON ({})", e),
+    };
+    let arg = |name: &str| args.get(name).and_then(Value::as_str).unwrap_or_default();
+    match call.function.name.as_str() {
+        "suggest" => suggest(ctx, arg("partial_command")),
+        "preview" => preview(ctx, arg("command")).await,
+        "rules_section" => rules_section(&ctx.game_data.rules, arg("name")),
+        other => format!("Error: there is no tool named {:?}", other),
+    }
+}
+
+fn suggest(ctx: &ToolContext<'_>, partial: &str) -> String {
+    let Some(spec) = &ctx.game_data.command_spec else {
+        return "You have no commands available right now.".to_string();
+    };
+    let suggestions = spec.suggest(partial, ctx.names);
+    if suggestions.is_empty() {
+        return format!("Nothing can follow {:?}.", partial);
+    }
+    suggestions
+        .into_iter()
+        .map(|s| match s.desc {
+            Some(desc) => format!("- {}: {}", s.value, desc),
+            None => format!("- {}", s.value),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Plays the command on the real state and reports only whether the game
+/// accepts it. The outcome stays hidden: decks are already shuffled in the
+/// state, so the resulting hand, logs or even whose turn it is could give
+/// away the card a draw would take.
+async fn preview(ctx: &ToolContext<'_>, command: &str) -> String {
+    let result = brdgme_game_client::request(
+        ctx.game_http,
+        ctx.game_service_uri,
+        ctx.version_name,
+        &Request::Play {
+            player: ctx.player,
+            game: ctx.game_state.to_string(),
+            command: command.to_string(),
+            names: ctx.names.to_vec(),
+        },
+    )
+    .await;
+    match result {
+        Ok(Response::Play {
+            remaining_input, ..
+        }) if !remaining_input.trim().is_empty() => {
+            format!("Accepted, with unused input: {:?}", remaining_input)
+        }
+        Ok(Response::Play { .. }) => "Accepted.".to_string(),
+        Ok(Response::UserError { message }) => format!("Rejected: {}", message),
+        Ok(_) => "Error: unexpected response from the game service".to_string(),
+        Err(brdgme_game_client::GameClientError::UserError { message }) => {
+            format!("Rejected: {}", message)
+        }
+        Err(e) => format!("Error: the preview failed ({})", e),
+    }
+}
+
+/// The markdown section under the first heading matching `name`, up to the
+/// next heading at the same level or above.
+fn rules_section(rules: &str, name: &str) -> String {
+    let headings: Vec<(usize, usize, &str)> = rules
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let level = line.chars().take_while(|&c| c == '#').count();
+            (level > 0 && line[level..].starts_with(' ')).then(|| (i, level, line[level..].trim()))
+        })
+        .collect();
+    let wanted = name.trim().to_lowercase();
+    let Some(&(start, level, _)) = headings
+        .iter()
+        .find(|(_, _, h)| h.to_lowercase() == wanted)
+        .or_else(|| {
+            headings
+                .iter()
+                .find(|(_, _, h)| !wanted.is_empty() && h.to_lowercase().contains(&wanted))
+        })
+    else {
+        let list: Vec<&str> = headings.iter().map(|(_, _, h)| *h).collect();
+        return format!(
+            "No section named {:?}. The sections are: {}",
+            name,
+            list.join(", ")
+        );
+    };
+    let end = headings
+        .iter()
+        .find(|(i, l, _)| *i > start && *l <= level)
+        .map_or(usize::MAX, |(i, _, _)| *i);
+    rules
+        .lines()
+        .enumerate()
+        .filter(|(i, _)| *i >= start && *i < end)
+        .map(|(_, line)| line)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brdgme_game::command::Spec;
+
+    const RULES: &str = "# Game\n\nIntro.\n\n## Setup\n\nDeal.\n\n## Scoring\n\nPoints.\n\n### Bonus scoring\n\nMore.\n\n## End\n\nDone.\n";
+
+    fn game_data(command_spec: Option<Spec>) -> GameData {
+        GameData {
+            pub_state_yaml: String::new(),
+            player_state_yaml: String::new(),
+            data_docs: String::new(),
+            basic_strategy: String::new(),
+            advanced_strategy: String::new(),
+            command_spec,
+            rules: RULES.to_string(),
+        }
+    }
+
+    #[test]
+    fn rules_section_takes_subsections_and_stops_at_siblings() {
+        assert_eq!(
+            "## Scoring\n\nPoints.\n\n### Bonus scoring\n\nMore.",
+            rules_section(RULES, "scoring")
+        );
+        assert_eq!("## End\n\nDone.", rules_section(RULES, "End"));
+        assert_eq!("### Bonus scoring\n\nMore.", rules_section(RULES, "bonus"));
+        assert_eq!(
+            "No section named \"Trading\". The sections are: Game, Setup, Scoring, Bonus scoring, End",
+            rules_section(RULES, "Trading")
+        );
+    }
+
+    #[test]
+    fn suggest_lists_completions_with_descriptions() {
+        let data = game_data(Some(Spec::OneOf(vec![
+            Spec::Doc {
+                name: "call".into(),
+                desc: Some("call the bid".into()),
+                spec: Box::new(Spec::Token("call".into())),
+            },
+            Spec::Token("bid".into()),
+        ])));
+        let http = reqwest::Client::new();
+        let ctx = ToolContext {
+            game_http: &http,
+            game_service_uri: "http://unused",
+            version_name: "v",
+            game_state: "{}",
+            game_data: &data,
+            names: &[],
+            player: 0,
+        };
+        assert_eq!("- call: call the bid\n- bid", suggest(&ctx, ""));
+        assert_eq!("- bid", suggest(&ctx, "b"));
+        assert_eq!("Nothing can follow \"x\".", suggest(&ctx, "x"));
+    }
+
+    /// Serves a game in-process, the way its service binary would.
+    async fn spawn_game_service<G>() -> String
+    where
+        G: brdgme_game::Gamer
+            + std::fmt::Debug
+            + Clone
+            + Serialize
+            + serde::de::DeserializeOwned
+            + Send
+            + 'static,
+    {
+        use brdgme_cmd::requester::Requester;
+        let app = axum::Router::new().fallback(axum::routing::post(
+            |axum::Json(req): axum::Json<Request>| async move {
+                axum::Json(
+                    brdgme_cmd::requester::gamer::new::<G>()
+                        .request(&req)
+                        .unwrap(),
+                )
+            },
+        ));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    /// A draw in Lost Cities takes the top of an already shuffled deck, so a
+    /// preview showing its outcome would show the real next card.
+    #[tokio::test]
+    async fn preview_of_a_draw_cannot_reveal_the_deck() {
+        use brdgme_game::Gamer;
+        use lost_cities_2::{Game, Phase};
+
+        let uri = spawn_game_service::<Game>().await;
+        let (mut game, _) = Game::start(2, 1).unwrap();
+        game.phase = Phase::DrawOrTake;
+        game.current_player = 0;
+        let mut swapped = game.clone();
+        let mut cards = game.deck.to_vec();
+        let other = cards.iter().position(|c| *c != cards[0]).unwrap();
+        cards.swap(0, other);
+        swapped.deck = cards.into();
+
+        let data = game_data(None);
+        let http = reqwest::Client::new();
+        let names = ["Mick".to_string(), "Steve".to_string()];
+        let mut previews = vec![];
+        for (state, top) in [(&game, game.deck[0]), (&swapped, swapped.deck[0])] {
+            let state = serde_json::to_string(state).unwrap();
+            let ctx = ToolContext {
+                game_http: &http,
+                game_service_uri: &uri,
+                version_name: "lost-cities-2",
+                game_state: &state,
+                game_data: &data,
+                names: &names,
+                player: 0,
+            };
+            let out = preview(&ctx, "draw").await;
+            assert!(!out.contains(&top.to_string()), "deck top leaked: {out}");
+            previews.push(out);
+        }
+        assert_eq!("Accepted.", previews[0]);
+        assert_eq!(
+            previews[0], previews[1],
+            "preview depends on the deck order"
+        );
+    }
+
+    #[tokio::test]
+    async fn run_reports_bad_calls_to_the_model() {
+        let data = game_data(None);
+        let http = reqwest::Client::new();
+        let ctx = ToolContext {
+            game_http: &http,
+            game_service_uri: "http://unused",
+            version_name: "v",
+            game_state: "{}",
+            game_data: &data,
+            names: &[],
+            player: 0,
+        };
+        let call = |name: &str, arguments: &str| ToolCall {
+            id: "1".into(),
+            kind: function_type(),
+            function: FunctionCall {
+                name: name.into(),
+                arguments: arguments.into(),
+            },
+        };
+        assert!(run(&call("suggest", "{"), &ctx).await.starts_with("Error:"));
+        assert_eq!(
+            "Error: there is no tool named \"cheat\"",
+            run(&call("cheat", "{}"), &ctx).await
+        );
+        assert_eq!(
+            "You have no commands available right now.",
+            run(&call("suggest", r#"{"partial_command": ""}"#), &ctx).await
+        );
+        assert_eq!(
+            "## End\n\nDone.",
+            run(&call("rules_section", r#"{"name": "end"}"#), &ctx).await
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! `mock-llm` arena seats play through the real `bot arena` binary.
+
+use brdgme_arena::{Config, Entrant, MockLlmSeat, RandSeat, Report, Seat, Turn, run};
+use brdgme_cmd::bot_cli::Request;
+use brdgme_cmd::requester::gamer;
+use brdgme_game::command::Spec;
+use no_thanks_2::Game;
+
+fn bot() -> &'static str {
+    env!("CARGO_BIN_EXE_bot")
+}
+
+fn play(chatter_rate: f64) -> Report {
+    let mut entrants = vec![
+        Entrant::new("rand", RandSeat::new(1)),
+        Entrant::new("llm", MockLlmSeat::new(2, chatter_rate, bot()).unwrap()),
+        Entrant::new("rand-2", RandSeat::new(3)),
+    ];
+    run(
+        &mut gamer::new::<Game>(),
+        &mut entrants,
+        &Config {
+            games: 2,
+            seed: 7,
+            ..Config::default()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn mock_llm_seats_play_through_the_bot() {
+    let report = play(0.0);
+    assert_eq!(2, report.finished, "{}", report);
+    let llm = &report.entrants[1];
+    assert!(llm.invalid_rate.total > 0, "{:?}", llm);
+    assert_eq!(0, llm.invalid_rate.hits, "{:?}", llm);
+}
+
+#[test]
+fn chatter_from_the_mock_provider_is_rejected() {
+    let llm = &play(0.5).entrants[1];
+    assert!(llm.invalid_rate.hits > 0, "{:?}", llm);
+}
+
+#[test]
+fn the_bot_prompts_the_mock_provider_and_resolves_its_menu_number() {
+    let mut seat = MockLlmSeat::new(1, 0.0, bot()).unwrap();
+    let turn = Turn {
+        request: Request {
+            player: 0,
+            player_state: r#"{"chips":4}"#.to_string(),
+            players: vec!["Alice".to_string(), "Bob".to_string()],
+            command_spec: Spec::Token("take".to_string()),
+            game_id: None,
+        },
+        pub_state: r#"{"card":30}"#,
+        rules: "Take cards or pay chips.",
+    };
+    assert_eq!("take", seat.command(&turn, &[]).unwrap());
+    let requests = seat.requests();
+    assert_eq!(1, requests.len());
+    let body = requests[0].to_string();
+    for expected in ["Take cards or pay chips.", "card: 30", "chips: 4"] {
+        assert!(
+            body.contains(expected),
+            "{} missing from {}",
+            expected,
+            body
+        );
+    }
+}
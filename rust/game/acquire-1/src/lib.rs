@@ -204,6 +204,8 @@ able to win the game.",
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
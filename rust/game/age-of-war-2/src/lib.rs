@@ -466,6 +466,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.calc_placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
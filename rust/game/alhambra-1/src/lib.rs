@@ -932,6 +932,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -464,6 +464,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else if self.phase == Phase::Placing {
             let whose: Vec<usize> = (0..self.players)
@@ -463,6 +463,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else if self.resolving {
             Status::Active {
@@ -586,6 +586,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.calc_placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
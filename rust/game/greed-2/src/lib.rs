@@ -16,10 +16,13 @@ use std::sync::LazyLock;
 
 use command::Command;
 
-const MIN_PLAYERS: usize = 2;
+const MIN_PLAYERS: usize = 1;
 const MAX_PLAYERS: usize = 6;
 pub const DICE_COUNT: usize = 6;
 pub const WIN_SCORE: i32 = 5000;
+/// Turns in a solo game, which scores as much as possible rather than racing
+/// to `WIN_SCORE`.
+pub const SOLO_TURNS: usize = 10;
 
 #[repr(usize)]
 #[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
@@ -188,6 +191,9 @@ pub struct Game {
     pub turn_score: i32,
     pub remaining_dice: Vec<Die>,
     pub taken_this_roll: bool,
+    /// Turns completed, banked or bust. Only ends a solo game.
+    #[serde(default)]
+    pub turns: usize,
     // Migration shim: pre-seed games get a fresh RNG on first load.
     // Remove once no pre-RNG games remain active.
     #[serde(default = "GameRng::from_entropy")]
@@ -212,6 +218,8 @@ pub struct PubState {
     pub finished: bool,
     /// Final standings once finished (empty while active).
     pub placings: Vec<usize>,
+    /// Turns completed so far, which ends a solo game at `SOLO_TURNS`.
+    pub turns: usize,
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -236,11 +244,20 @@ impl Game {
     }
 
     /// Direct port of Go `IsFinished`: the round closes when play returns to
-    /// the first player AND someone has reached the target score.
+    /// the first player AND someone has reached the target score. A solo game
+    /// instead ends after `SOLO_TURNS` turns.
     fn finished(&self) -> bool {
+        if self.players == 1 {
+            return self.turns >= SOLO_TURNS;
+        }
         self.current_player == self.first_player && self.scores.iter().any(|&s| s >= WIN_SCORE)
     }
 
+    fn end_turn(&mut self) {
+        self.turns += 1;
+        self.current_player = (self.current_player + 1) % self.players;
+    }
+
     fn random_dice(rng: &mut GameRng, n: usize) -> Vec<Die> {
         let mut dice: Vec<Die> = (0..n).map(|_| DIE_FACES[rng.random_range(0..6)]).collect();
         dice.sort();
@@ -256,7 +273,7 @@ impl Game {
             N::Bold(vec![N::text(self.turn_score.to_string())]),
             N::text(" points!"),
         ])];
-        self.current_player = (self.current_player + 1) % self.players;
+        self.end_turn();
         logs
     }
 
@@ -365,7 +382,7 @@ impl Game {
             N::Bold(vec![N::text(banked.to_string())]),
         ]));
         self.scores[player] = banked;
-        self.current_player = (self.current_player + 1) % self.players;
+        self.end_turn();
         if !self.finished() {
             logs.extend(self.start_turn());
         }
@@ -442,6 +459,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -465,6 +484,7 @@ impl Gamer for Game {
             } else {
                 vec![]
             },
+            turns: self.turns,
         }
     }
 
@@ -527,7 +547,7 @@ impl Gamer for Game {
     }
 
     fn player_counts() -> Vec<usize> {
-        vec![2, 3, 4, 5, 6]
+        vec![1, 2, 3, 4, 5, 6]
     }
 
     fn player_count(&self) -> usize {
@@ -586,8 +606,9 @@ mod tests {
 
     #[test]
     fn test_player_counts() {
-        assert_eq!(vec![2, 3, 4, 5, 6], Game::player_counts());
-        assert!(Game::start(1, 1).is_err());
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], Game::player_counts());
+        assert!(Game::start(0, 1).is_err());
+        assert!(Game::start(1, 1).is_ok());
         assert!(Game::start(7, 1).is_err());
         assert!(Game::start(2, 1).is_ok());
         assert!(Game::start(6, 1).is_ok());
@@ -681,6 +702,22 @@ mod tests {
         assert!(!g.can_done(other));
     }
 
+    #[test]
+    fn solo_game_ends_after_ten_turns_regardless_of_score() {
+        let (mut g, _) = Game::start(1, 1).unwrap();
+        g.scores = vec![WIN_SCORE];
+        assert!(!g.finished());
+        for turn in 1..=SOLO_TURNS {
+            g.remaining_dice = vec![Die::R, Die::R];
+            g.turn_score = 100;
+            g.command(0, "done", &players(1)).unwrap();
+            assert_eq!(turn, g.turns);
+        }
+        assert!(g.is_finished());
+        assert_eq!(WIN_SCORE + 100 * SOLO_TURNS as i32, g.scores[0]);
+        assert_eq!(vec![1], g.placings());
+    }
+
     #[test]
     fn test_finished_and_placings() {
         let (mut g, _) = Game::start(3, 1).unwrap();
@@ -810,6 +847,8 @@ mod tests {
             Status::Finished {
                 placings: vec![2, 1],
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             g.status()
         );
@@ -758,6 +758,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: self.finished_stats(),
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
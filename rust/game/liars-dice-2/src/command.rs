@@ -8,6 +8,7 @@ use crate::{MAX_BID_VALUE, MIN_BID_QUANTITY, MIN_BID_VALUE, START_DICE_COUNT};
 pub enum Command {
     Bid { quantity: i32, value: i32 },
     Call,
+    Exact,
 }
 
 impl Game {
@@ -17,11 +18,14 @@ impl Game {
         }
         let mut parsers: Vec<Box<dyn Parser<T = Command>>> = vec![];
         if self.can_bid(player) {
-            parsers.push(Box::new(bid_parser(self.players)));
+            parsers.push(Box::new(bid_parser(self.players, self.ones_wild())));
         }
         if self.can_call(player) {
             parsers.push(Box::new(call_parser()));
         }
+        if self.can_exact(player) {
+            parsers.push(Box::new(exact_parser()));
+        }
         if parsers.is_empty() {
             None
         } else {
@@ -30,7 +34,7 @@ impl Game {
     }
 }
 
-pub fn bid_parser(players: usize) -> impl Parser<T = Command> {
+pub fn bid_parser(players: usize, ones_wild: bool) -> impl Parser<T = Command> {
     Map::new(
         Chain3::new(
             Doc::name_desc(
@@ -51,7 +55,11 @@ pub fn bid_parser(players: usize) -> impl Parser<T = Command> {
             }),
             AfterSpace::new(Doc::name_desc(
                 "value",
-                "the face value of dice to bid, including wild dice (1)",
+                if ones_wild {
+                    "the face value of dice to bid, including wild dice (1)"
+                } else {
+                    "the face value of dice to bid, ones aren't wild"
+                },
                 Int {
                     min: Some(MIN_BID_VALUE),
                     max: Some(MAX_BID_VALUE),
@@ -69,6 +77,17 @@ pub fn call_parser() -> impl Parser<T = Command> {
     )
 }
 
+pub fn exact_parser() -> impl Parser<T = Command> {
+    Map::new(
+        Doc::name_desc(
+            "exact",
+            "call that the bid is exactly right, to win a die back",
+            Token::new("exact"),
+        ),
+        |_| Command::Exact,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,7 +110,7 @@ mod tests {
     fn bid_parser_accepts_every_boundary_bid() {
         let players = 3;
         let cap = (players * START_DICE_COUNT) as i32;
-        let parser = bid_parser(players);
+        let parser = bid_parser(players, true);
 
         // The quantity parser must not reject a bid the rules allow: any
         // strictly-increasing quantity is a legal (if losing) bluff, even
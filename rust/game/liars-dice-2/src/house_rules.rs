@@ -0,0 +1,93 @@
+//! Perudo house rules, each chosen by a start option. Wild ones are on by
+//! default, matching the original game; the rest are off.
+
+use serde::{Deserialize, Serialize};
+
+use brdgme_game::{GameOption, GameOptions};
+
+pub const WILD_ONES_OPTION: &str = "wild_ones";
+pub const PALIFICO_OPTION: &str = "palifico";
+pub const EXACT_OPTION: &str = "exact";
+pub const HALVING_OPTION: &str = "halving";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct HouseRules {
+    /// Ones count towards every face, except in bids on ones.
+    pub wild_ones: bool,
+    /// The first time a player drops to one die, the next round is played
+    /// with ones not wild and the bid face fixed by its opening bid.
+    pub palifico: bool,
+    /// The player on turn may call a bid exactly right instead of too high,
+    /// winning a die back if it is.
+    pub exact: bool,
+    /// Bids switching to ones may halve the quantity, and bids switching
+    /// back must more than double it.
+    pub halving: bool,
+}
+
+impl Default for HouseRules {
+    fn default() -> Self {
+        HouseRules {
+            wild_ones: true,
+            palifico: false,
+            exact: false,
+            halving: false,
+        }
+    }
+}
+
+impl HouseRules {
+    pub fn from_options(options: &GameOptions) -> Self {
+        HouseRules {
+            wild_ones: options.get(WILD_ONES_OPTION) != Some("off"),
+            palifico: options.is_on(PALIFICO_OPTION),
+            exact: options.is_on(EXACT_OPTION),
+            halving: options.is_on(HALVING_OPTION),
+        }
+    }
+
+    /// Names of the rules in play that differ from plain bid and call, for
+    /// the board.
+    pub fn names(self) -> Vec<&'static str> {
+        let mut names = vec![];
+        if self.wild_ones {
+            names.push("wild ones");
+        }
+        if self.palifico {
+            names.push("Palifico");
+        }
+        if self.exact {
+            names.push("exact calls");
+        }
+        if self.halving && self.wild_ones {
+            names.push("halving on ones");
+        }
+        names
+    }
+}
+
+pub fn options() -> Vec<GameOption> {
+    vec![
+        GameOption::new(
+            WILD_ONES_OPTION,
+            "Wild ones",
+            "Ones count towards every face, except in bids on ones.",
+            &[("on", "On"), ("off", "Off")],
+        ),
+        GameOption::toggle(
+            PALIFICO_OPTION,
+            "Palifico",
+            "A player's first drop to one die starts a round without wild ones, where the opening face can't change.",
+        ),
+        GameOption::toggle(
+            EXACT_OPTION,
+            "Exact calls",
+            "Call a bid exactly right to win a die back, or lose one if it's wrong.",
+        ),
+        GameOption::toggle(
+            HALVING_OPTION,
+            "Halving on ones",
+            "With wild ones, a bid on ones needs only half the quantity and a bid off ones needs double plus one.",
+        ),
+    ]
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 mod command;
+pub mod house_rules;
 mod render;
 
 use brdgme_game::command::Spec as CommandSpec;
@@ -8,10 +9,11 @@ use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
 use brdgme_game::rng::GameRng;
-use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
+use brdgme_game::{CommandResponse, GameOption, GameOptions, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
 
 use command::Command;
+use house_rules::HouseRules;
 use rand::prelude::*;
 
 pub const START_DICE_COUNT: usize = 5;
@@ -29,6 +31,15 @@ pub struct Game {
     pub bid_quantity: i32,
     pub bid_value: i32,
     pub bid_player: usize,
+    #[serde(default)]
+    pub house_rules: HouseRules,
+    /// Whether this round is a Palifico round: ones aren't wild and the
+    /// opening bid's face can't change.
+    #[serde(default)]
+    pub palifico_round: bool,
+    /// Who has had their Palifico round, by seat. Empty until the first one.
+    #[serde(default)]
+    pub palifico_used: Vec<bool>,
     // Migration shim: pre-seed games get a fresh RNG on first load.
     // Remove once no pre-RNG games remain active.
     #[serde(default = "GameRng::from_entropy")]
@@ -49,6 +60,10 @@ pub struct PubState {
     pub bid_player: usize,
     /// Number of dice each player still has, by seat index.
     pub remaining_dice: Vec<usize>,
+    /// The house rules chosen when the game was created.
+    pub house_rules: HouseRules,
+    /// Whether this round is a Palifico round.
+    pub palifico_round: bool,
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -70,6 +85,30 @@ impl Game {
         !self.is_finished() && self.current_player == player && self.bid_quantity != 0
     }
 
+    pub fn can_exact(&self, player: usize) -> bool {
+        self.house_rules.exact && self.can_call(player)
+    }
+
+    /// Whether ones count towards the bid face this round.
+    pub fn ones_wild(&self) -> bool {
+        self.house_rules.wild_ones && !self.palifico_round
+    }
+
+    /// Whether halving applies when switching to or from ones this round.
+    pub fn ones_halving(&self) -> bool {
+        self.house_rules.halving && self.ones_wild()
+    }
+
+    /// The number of dice under every cup counting towards the current bid.
+    pub fn matching_dice(&self) -> i32 {
+        let ones_wild = self.ones_wild();
+        self.player_dice
+            .iter()
+            .flatten()
+            .filter(|&&d| d as i32 == self.bid_value || (ones_wild && d == 1))
+            .count() as i32
+    }
+
     pub fn active_players(&self) -> Vec<usize> {
         (0..self.players)
             .filter(|&p| !self.player_dice[p].is_empty())
@@ -121,21 +160,44 @@ impl Game {
                 "quantity must be a positive number, eg. 5",
             ));
         }
-        if quantity < self.bid_quantity {
-            return Err(GameError::invalid_input(format!(
-                "you can't reduce the quantity of the bid, it is currently at {}",
-                self.bid_quantity
-            )));
-        }
         if !(1..=6).contains(&value) {
             return Err(GameError::invalid_input(
                 "value must be a number between 1 and 6",
             ));
         }
-        if quantity == self.bid_quantity && value <= self.bid_value {
-            return Err(GameError::invalid_input(
-                "if you don't increase the bid quantity, you must increase the bid value",
-            ));
+        if self.bid_quantity != 0 && self.palifico_round && value != self.bid_value {
+            return Err(GameError::invalid_input(format!(
+                "the value can't change in a Palifico round, it is {}",
+                self.bid_value
+            )));
+        }
+        if self.bid_quantity != 0 && self.ones_halving() && (value == 1) != (self.bid_value == 1) {
+            // Ones are twice as likely to be short, so switching to them
+            // halves the quantity (rounding up) and switching back more than
+            // doubles it.
+            let (min, direction) = if value == 1 {
+                ((self.bid_quantity + 1) / 2, "to")
+            } else {
+                (self.bid_quantity * 2 + 1, "from")
+            };
+            if quantity < min {
+                return Err(GameError::invalid_input(format!(
+                    "switching {} ones needs a quantity of at least {}",
+                    direction, min
+                )));
+            }
+        } else {
+            if quantity < self.bid_quantity {
+                return Err(GameError::invalid_input(format!(
+                    "you can't reduce the quantity of the bid, it is currently at {}",
+                    self.bid_quantity
+                )));
+            }
+            if quantity == self.bid_quantity && value <= self.bid_value {
+                return Err(GameError::invalid_input(
+                    "if you don't increase the bid quantity, you must increase the bid value",
+                ));
+            }
         }
         let verb = if self.bid_quantity == 0 {
             "set the starting bid to"
@@ -162,15 +224,7 @@ impl Game {
         if !self.can_call(player) {
             return Err(GameError::invalid_input("can't call at the moment"));
         }
-        let mut matching = 0;
-        for pd in &self.player_dice {
-            for d in pd {
-                if *d as i32 == self.bid_value || *d == 1 {
-                    matching += 1;
-                }
-            }
-        }
-        let (losing_player, result_text) = if matching < self.bid_quantity {
+        let (loser, result_text) = if self.matching_dice() < self.bid_quantity {
             (
                 self.bid_player,
                 vec![
@@ -189,13 +243,67 @@ impl Game {
                 ],
             )
         };
-        let table = render::reveal_table(&self.player_dice, &self.active_players(), self.bid_value);
-        if !self.player_dice[losing_player].is_empty() {
-            self.player_dice[losing_player].remove(0);
+        let logs = self.end_round(player, "called", result_text, Some(loser), None);
+        Ok(CommandResponse {
+            logs,
+            can_undo: false,
+            remaining_input: remaining.to_string(),
+        })
+    }
+
+    /// Calls the bid exactly right (calza): a die back if it is, capped at
+    /// the starting count, or a die lost if it isn't.
+    pub fn exact(&mut self, player: usize, remaining: &str) -> Result<CommandResponse, GameError> {
+        if !self.can_exact(player) {
+            return Err(GameError::invalid_input("can't call exact at the moment"));
         }
+        let logs = if self.matching_dice() == self.bid_quantity {
+            if self.player_dice[player].len() < START_DICE_COUNT {
+                let result_text = vec![
+                    N::Player(player),
+                    N::text(" was exactly right and won a die back"),
+                ];
+                self.end_round(player, "called exact on", result_text, None, Some(player))
+            } else {
+                let result_text = vec![
+                    N::Player(player),
+                    N::text(" was exactly right but already has every die"),
+                ];
+                self.end_round(player, "called exact on", result_text, None, None)
+            }
+        } else {
+            let result_text = vec![
+                N::Player(player),
+                N::text(" wasn't exactly right and lost a die"),
+            ];
+            self.end_round(player, "called exact on", result_text, Some(player), None)
+        };
+        Ok(CommandResponse {
+            logs,
+            can_undo: false,
+            remaining_input: remaining.to_string(),
+        })
+    }
+
+    /// Reveals every cup, moves a die and starts the next round, which is a
+    /// Palifico round if `loser` just dropped to one die for the first time.
+    fn end_round(
+        &mut self,
+        player: usize,
+        call_text: &str,
+        result_text: Vec<N>,
+        loser: Option<usize>,
+        gainer: Option<usize>,
+    ) -> Vec<Log> {
+        let table = render::reveal_table(
+            &self.player_dice,
+            &self.active_players(),
+            self.bid_value,
+            self.ones_wild(),
+        );
         let mut content: Vec<N> = vec![
             N::Player(player),
-            N::text(" called the bid of "),
+            N::text(format!(" {} the bid of ", call_text)),
             render::render_bid(self.bid_quantity, self.bid_value),
             N::text(" by "),
             N::Player(self.bid_player),
@@ -204,16 +312,42 @@ impl Game {
             N::text("\n"),
         ];
         content.extend(result_text);
-        let logs = vec![Log::public(content)];
+        let mut logs = vec![Log::public(content)];
+
+        if let Some(loser) = loser
+            && !self.player_dice[loser].is_empty()
+        {
+            self.player_dice[loser].remove(0);
+        }
+        if let Some(gainer) = gainer {
+            self.player_dice[gainer].push(0);
+        }
+        let palifico_player = loser.filter(|&p| {
+            self.house_rules.palifico
+                && self.player_dice[p].len() == 1
+                && !self.palifico_used.get(p).copied().unwrap_or(false)
+        });
+
+        self.palifico_round = false;
         if !self.is_finished() {
             self.start_round();
-            self.current_player = self.next_active_player(self.current_player);
+            match palifico_player {
+                Some(p) => {
+                    self.palifico_used.resize(self.players, false);
+                    self.palifico_used[p] = true;
+                    self.palifico_round = true;
+                    self.current_player = p;
+                    logs.push(Log::public(vec![
+                        N::Player(p),
+                        N::text(" is down to one die, "),
+                        N::Bold(vec![N::text("Palifico!")]),
+                        N::text(" Ones aren't wild this round and the opening value can't change."),
+                    ]));
+                }
+                None => self.current_player = self.next_active_player(self.current_player),
+            }
         }
-        Ok(CommandResponse {
-            logs,
-            can_undo: false,
-            remaining_input: remaining.to_string(),
-        })
+        logs
     }
 
     fn placings(&self) -> Vec<usize> {
@@ -229,6 +363,19 @@ impl Gamer for Game {
     type PlayerState = PlayerState;
 
     fn start(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError> {
+        Self::start_with_options(players, seed, &GameOptions::new())
+    }
+
+    fn options() -> Vec<GameOption> {
+        house_rules::options()
+    }
+
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let options = options.resolve(&Self::options())?;
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
             return Err(GameError::PlayerCount {
                 min: MIN_PLAYERS,
@@ -242,6 +389,7 @@ impl Gamer for Game {
             players,
             current_player,
             player_dice: vec![vec![0u8; START_DICE_COUNT]; players],
+            house_rules: HouseRules::from_options(&options),
             rng,
             ..Game::default()
         };
@@ -269,6 +417,33 @@ impl Gamer for Game {
                 self.bid_player, self.players
             )));
         }
+        if let Some(p) = (0..self.players).find(|&p| self.player_dice[p].len() > START_DICE_COUNT) {
+            return Err(GameError::internal(format!(
+                "liars-dice-2: player {} has more than {} dice",
+                p, START_DICE_COUNT
+            )));
+        }
+        if !(0..=MAX_BID_VALUE).contains(&self.bid_value)
+            || self.bid_quantity < 0
+            || (self.bid_quantity == 0) != (self.bid_value == 0)
+        {
+            return Err(GameError::internal(format!(
+                "liars-dice-2: invalid bid of {} x {}",
+                self.bid_quantity, self.bid_value
+            )));
+        }
+        if self.palifico_round && !self.house_rules.palifico {
+            return Err(GameError::internal(
+                "liars-dice-2: Palifico round without the Palifico rule",
+            ));
+        }
+        if !self.palifico_used.is_empty() && self.palifico_used.len() != self.players {
+            return Err(GameError::internal(format!(
+                "liars-dice-2: palifico_used length {} does not match players {}",
+                self.palifico_used.len(),
+                self.players
+            )));
+        }
         Ok(())
     }
 
@@ -278,6 +453,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -297,6 +474,8 @@ impl Gamer for Game {
             remaining_dice: (0..self.players)
                 .map(|p| self.player_dice[p].len())
                 .collect(),
+            house_rules: self.house_rules,
+            palifico_round: self.palifico_round,
         }
     }
 
@@ -331,12 +510,16 @@ impl Gamer for Game {
                 ..
             }) => self.bid(player, quantity, value, remaining),
             Ok(ParseOutput {
-                value: Command::Call,
+                value: command @ (Command::Call | Command::Exact),
                 remaining,
                 ..
             }) => {
                 let was_finished = self.is_finished();
-                let mut resp = self.call(player, remaining)?;
+                let mut resp = if command == Command::Call {
+                    self.call(player, remaining)?
+                } else {
+                    self.exact(player, remaining)?
+                };
                 if !was_finished && self.is_finished() {
                     let scores: Vec<(usize, i32)> = (0..self.players)
                         .map(|p| (p, self.player_dice[p].len() as i32))
@@ -560,4 +743,144 @@ mod tests {
         assert!(g.command(1, "call", &p).is_err());
         assert!(g.command(0, "bid 2 5", &p).is_err());
     }
+
+    fn start_with(rules: &[(&str, &str)]) -> Game {
+        let options = rules
+            .iter()
+            .fold(GameOptions::new(), |o, (k, v)| o.with(*k, *v));
+        Game::start_with_options(2, 1, &options).unwrap().0
+    }
+
+    #[test]
+    fn options_choose_house_rules() {
+        assert_eq!(
+            HouseRules::default(),
+            Game::start(2, 1).unwrap().0.house_rules
+        );
+        let g = start_with(&[
+            (house_rules::WILD_ONES_OPTION, "off"),
+            (house_rules::PALIFICO_OPTION, "on"),
+            (house_rules::EXACT_OPTION, "on"),
+            (house_rules::HALVING_OPTION, "on"),
+        ]);
+        assert_eq!(
+            HouseRules {
+                wild_ones: false,
+                palifico: true,
+                exact: true,
+                halving: true,
+            },
+            g.house_rules
+        );
+    }
+
+    #[test]
+    fn without_wild_ones_only_the_bid_face_counts() {
+        let mut g = start_with(&[(house_rules::WILD_ONES_OPTION, "off")]);
+        let p = players(2);
+        g.player_dice = vec![vec![1, 1, 3, 4, 5], vec![3, 6, 6, 6, 6]];
+        g.bid_quantity = 3;
+        g.bid_value = 3;
+        g.bid_player = 0;
+        g.current_player = 1;
+
+        g.command(1, "call", &p).unwrap();
+
+        assert_eq!(4, g.player_dice[0].len());
+        assert_eq!(5, g.player_dice[1].len());
+    }
+
+    #[test]
+    fn exact_call_wins_a_die_back_or_loses_one() {
+        let mut g = start_with(&[(house_rules::EXACT_OPTION, "on")]);
+        let p = players(2);
+        g.player_dice = vec![vec![1, 3, 4], vec![3, 6, 6, 6]];
+        g.bid_quantity = 3;
+        g.bid_value = 3;
+        g.bid_player = 0;
+        g.current_player = 1;
+        g.command(1, "exact", &p).unwrap();
+        assert_eq!(3, g.player_dice[0].len());
+        assert_eq!(5, g.player_dice[1].len());
+
+        g.player_dice = vec![vec![1, 3, 4], vec![3, 6, 6, 6, 6]];
+        g.bid_quantity = 2;
+        g.bid_value = 3;
+        g.bid_player = 0;
+        g.current_player = 1;
+        g.command(1, "exact", &p).unwrap();
+        assert_eq!(3, g.player_dice[0].len());
+        assert_eq!(4, g.player_dice[1].len());
+    }
+
+    #[test]
+    fn exact_needs_the_house_rule() {
+        let (mut g, _) = Game::start(2, 1).unwrap();
+        let p = players(2);
+        g.current_player = 0;
+        g.command(0, "bid 2 3", &p).unwrap();
+        assert!(g.command(1, "exact", &p).is_err());
+    }
+
+    #[test]
+    fn halving_switches_to_and_from_ones() {
+        let mut g = start_with(&[(house_rules::HALVING_OPTION, "on")]);
+        let p = players(2);
+        g.current_player = 0;
+        g.command(0, "bid 5 4", &p).unwrap();
+        assert!(g.command(1, "bid 2 1", &p).is_err());
+        g.command(1, "bid 3 1", &p).unwrap();
+        assert!(g.command(0, "bid 6 5", &p).is_err());
+        g.command(0, "bid 7 2", &p).unwrap();
+    }
+
+    #[test]
+    fn dropping_to_one_die_starts_a_palifico_round_once() {
+        let mut g = start_with(&[(house_rules::PALIFICO_OPTION, "on")]);
+        let p = players(2);
+        g.player_dice = vec![vec![2, 2], vec![6, 6, 6]];
+        g.current_player = 0;
+        g.command(0, "bid 4 6", &p).unwrap();
+        g.command(1, "call", &p).unwrap();
+
+        assert!(g.palifico_round);
+        assert!(!g.ones_wild());
+        assert_eq!(0, g.current_player);
+        assert_eq!(vec![true, false], g.palifico_used);
+        assert!(g.pub_state().palifico_round);
+
+        g.command(0, "bid 1 4", &p).unwrap();
+        assert!(g.command(1, "bid 2 5", &p).is_err());
+        g.player_dice = vec![vec![1], vec![4, 6, 6]];
+        g.command(1, "bid 2 4", &p).unwrap();
+        // The one isn't wild, so the bid is too high.
+        g.command(0, "call", &p).unwrap();
+
+        assert!(!g.palifico_round);
+        assert_eq!(
+            vec![1, 2],
+            vec![g.player_dice[0].len(), g.player_dice[1].len()]
+        );
+        assert!(g.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_checks_house_rule_state() {
+        let (mut g, _) = Game::start(2, 1).unwrap();
+        g.palifico_round = true;
+        assert!(matches!(g.validate(), Err(GameError::Internal { .. })));
+
+        let (mut g, _) = Game::start(2, 1).unwrap();
+        g.palifico_used = vec![false];
+        assert!(matches!(g.validate(), Err(GameError::Internal { .. })));
+
+        let (mut g, _) = Game::start(2, 1).unwrap();
+        g.player_dice[0].push(1);
+        assert!(matches!(g.validate(), Err(GameError::Internal { .. })));
+
+        let (mut g, _) = Game::start(2, 1).unwrap();
+        g.bid_value = 7;
+        g.bid_quantity = 1;
+        assert!(matches!(g.validate(), Err(GameError::Internal { .. })));
+    }
 }
@@ -13,6 +13,19 @@ fn render(pub_state: &PubState, _player: Option<usize>, hand: Option<&[u8]>) ->
         render_bid(pub_state.bid_quantity, pub_state.bid_value)
     };
     out.push(N::Group(vec![N::text("Current bid: "), bid, N::text("\n")]));
+    if pub_state.palifico_round {
+        out.push(N::Group(vec![
+            N::Bold(vec![N::text("Palifico round: ")]),
+            N::text("ones aren't wild and the value can't change\n"),
+        ]));
+    }
+    let rules = pub_state.house_rules.names();
+    if !rules.is_empty() {
+        out.push(N::Fg(
+            NamedColor::Grey.into(),
+            vec![N::text(format!("House rules: {}\n", rules.join(", ")))],
+        ));
+    }
 
     if let Some(h) = hand
         && !h.is_empty()
@@ -161,7 +174,12 @@ pub fn render_die(value: i32) -> N {
     N::Bold(vec![N::Fg(color.into(), vec![N::text(value.to_string())])])
 }
 
-pub fn reveal_table(player_dice: &[Vec<u8>], active: &[usize], bid_value: i32) -> N {
+pub fn reveal_table(
+    player_dice: &[Vec<u8>],
+    active: &[usize],
+    bid_value: i32,
+    ones_wild: bool,
+) -> N {
     let mut rows: Vec<Row> = vec![];
     for &p in active {
         let mut dice_nodes: Vec<N> = vec![];
@@ -169,7 +187,7 @@ pub fn reveal_table(player_dice: &[Vec<u8>], active: &[usize], bid_value: i32) -
             if i > 0 {
                 dice_nodes.push(N::text(" "));
             }
-            if *d as i32 == bid_value || *d == 1 {
+            if *d as i32 == bid_value || (ones_wild && *d == 1) {
                 dice_nodes.push(N::Fg(NamedColor::Red.into(), vec![N::text(d.to_string())]));
             } else {
                 dice_nodes.push(N::text(d.to_string()));
@@ -199,6 +199,8 @@ impl Gamer for Game {
                         .collect::<Vec<Vec<i32>>>(),
                 ),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -535,6 +535,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![self.player_stats(0), self.player_stats(1)],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -1,3 +1,4 @@
+use brdgme_components::parser::card_parser;
 use brdgme_game::Gamer;
 use brdgme_game::command::parser::*;
 
@@ -62,11 +63,8 @@ impl Game {
         )
     }
 
-    pub fn player_card_parser(&self, player: usize, desc: &str) -> impl Parser<T = Card> {
-        let mut player_hand = self.hands.get(player).cloned().unwrap_or_else(Vec::new);
-        player_hand.sort();
-        player_hand.dedup();
-        Doc::name_desc("card", desc, Enum::exact(player_hand))
+    pub fn player_card_parser(&self, player: usize, desc: &str) -> impl Parser<T = Card> + use<> {
+        card_parser(self.hands.get(player).map_or(&[][..], Vec::as_slice), desc)
     }
 }
 
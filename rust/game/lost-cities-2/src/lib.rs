@@ -1,9 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use std::default::Default;
 
-use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use brdgme_components::Deck;
 use brdgme_game::command::Spec as CommandSpec;
 use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
@@ -55,7 +55,7 @@ pub struct Game {
     pub players: usize,
     pub round: usize,
     pub phase: Phase,
-    pub deck: Vec<Card>,
+    pub deck: Deck<Card>,
     pub discards: Vec<Card>,
     pub hands: Vec<Vec<Card>>,
     pub scores: Vec<Vec<isize>>,
@@ -136,10 +136,7 @@ impl Game {
             "Starting round {}",
             self.round
         ))])];
-        // Grab a new deck and shuffle it.
-        let mut deck = initial_deck();
-        deck.as_mut_slice().shuffle(&mut self.rng);
-        self.deck = deck;
+        self.deck = Deck::shuffled(initial_deck(), &mut self.rng);
         // Clear out discards, hands and expeditions.
         self.discards = vec![];
         self.hands = vec![];
@@ -426,16 +423,9 @@ impl Game {
         let mut logs: Vec<Log> = vec![];
         match self.hands.get_mut(player) {
             Some(hand) => {
-                let mut num = hand_size(self.players)?.saturating_sub(hand.len());
-                let dl = self.deck.len();
-                if num > dl {
-                    num = dl;
-                }
-                let mut drawn: Vec<Card> = vec![];
-                for c in self.deck.drain(..num) {
-                    hand.push(c);
-                    drawn.push(c);
-                }
+                let num = hand_size(self.players)?.saturating_sub(hand.len());
+                let mut drawn = self.deck.draw_n(num);
+                hand.extend_from_slice(&drawn);
                 drawn.sort();
                 let d_len = drawn.len();
                 let mut public_log: Vec<N> = vec![N::Player(player), N::text(" drew ")];
@@ -581,6 +571,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: (0..self.players).map(|p| self.player_stats(p)).collect(),
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -1059,7 +1051,9 @@ mod tests {
         }
         assert!(game.is_finished());
         match game.status() {
-            Status::Finished { placings, stats } => {
+            Status::Finished {
+                placings, stats, ..
+            } => {
                 assert_eq!(3, placings.len(), "placings must cover all players");
                 assert_eq!(3, stats.len(), "stats must cover all players");
                 for (p, s) in stats.iter().enumerate() {
@@ -1158,7 +1152,7 @@ mod tests {
         // hand ever exceeds the hand size. Unreachable in normal play, so this
         // constructs the state directly.
         let mut game = Game::start(2, 1).unwrap().0;
-        let extra = game.deck.pop().expect("deck must not be empty");
+        let extra = game.deck.draw().expect("deck must not be empty");
         game.hands[0].push(extra);
         let over = game.hands[0].len();
         let logs = game
@@ -1200,7 +1194,7 @@ mod tests {
         game.current_player = 0;
         game.hands = vec![vec![], vec![]];
         game.discards = vec![];
-        game.deck = game.deck[..HAND_SIZE_2P].to_vec();
+        game.deck = game.deck[..HAND_SIZE_2P].to_vec().into();
         let resp = game.command(0, "draw", &names).unwrap();
         assert!(game.is_finished());
         assert!(!resp.can_undo, "Draw arm can_undo must be unchanged");
@@ -3,8 +3,9 @@ use std::fmt;
 use brdgme_color::NamedColor;
 use serde::{Deserialize, Serialize};
 
-/// Card values match the Go `love_letter_1` int constants: `Guard = 1` up to
-/// `Princess = 8`.
+/// Classic card values match the Go `love_letter_1` int constants: `Guard = 1`
+/// up to `Princess = 8`. The 2019 edition adds the `Spy` and `Chancellor` and
+/// renumbers the top cards, see `Card::value`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Card {
     Guard = 1,
@@ -15,10 +16,89 @@ pub enum Card {
     King = 6,
     Countess = 7,
     Princess = 8,
+    Spy = 9,
+    Chancellor = 10,
 }
 
-/// All eight cards, ordered from `Princess` down to `Guard` - matches the Go
-/// `CardParserValues` iteration order (`for c := Princess; c >= Guard; c--`).
+/// Which edition of the game is being played.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Edition {
+    /// The original 16 card game for 2-4 players.
+    #[default]
+    Classic,
+    /// The 2019 edition: 21 cards including the Spy and Chancellor, for 2-6
+    /// players.
+    Edition2019,
+}
+
+impl Edition {
+    pub fn key(self) -> &'static str {
+        match self {
+            Edition::Classic => "classic",
+            Edition::Edition2019 => "2019",
+        }
+    }
+
+    /// Cards in this edition, ordered from highest to lowest value.
+    pub fn cards(self) -> Vec<Card> {
+        match self {
+            Edition::Classic => princess_to_guard(),
+            Edition::Edition2019 => vec![
+                Card::Princess,
+                Card::Countess,
+                Card::King,
+                Card::Chancellor,
+                Card::Prince,
+                Card::Handmaid,
+                Card::Baron,
+                Card::Priest,
+                Card::Guard,
+                Card::Spy,
+            ],
+        }
+    }
+
+    /// The unshuffled deck for this edition.
+    pub fn deck(self) -> Vec<Card> {
+        match self {
+            Edition::Classic => initial_deck(),
+            Edition::Edition2019 => {
+                let mut deck = initial_deck();
+                deck.extend([
+                    Card::Guard,
+                    Card::Spy,
+                    Card::Spy,
+                    Card::Chancellor,
+                    Card::Chancellor,
+                ]);
+                deck
+            }
+        }
+    }
+
+    /// Number of copies of `card` in this edition's deck, used by the help
+    /// table.
+    pub fn deck_count(self, card: Card) -> usize {
+        self.deck().iter().filter(|&&c| c == card).count()
+    }
+
+    /// Points required to win the game, keyed by player count. The classic
+    /// numbers match the Go `endScores` map.
+    pub fn end_score(self, players: usize) -> usize {
+        match (self, players) {
+            (Edition::Classic, 2) => 7,
+            (Edition::Edition2019, 2) => 6,
+            (_, 3) => 5,
+            (_, 4) => 4,
+            (Edition::Edition2019, 5 | 6) => 3,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// All eight classic cards, ordered from `Princess` down to `Guard` - matches
+/// the Go `CardParserValues` iteration order (`for c := Princess; c >= Guard;
+/// c--`).
 pub fn princess_to_guard() -> Vec<Card> {
     vec![
         Card::Princess,
@@ -33,8 +113,16 @@ pub fn princess_to_guard() -> Vec<Card> {
 }
 
 impl Card {
-    pub fn number(self) -> u8 {
-        self as u8
+    /// The card's number, which decides Baron comparisons and who wins the
+    /// round. The 2019 edition slots the Chancellor in at 6, pushing the
+    /// King, Countess and Princess up by one.
+    pub fn value(self, edition: Edition) -> u8 {
+        match (self, edition) {
+            (Card::Spy, _) => 0,
+            (Card::Chancellor, _) => 6,
+            (Card::King | Card::Countess | Card::Princess, Edition::Edition2019) => self as u8 + 1,
+            _ => self as u8,
+        }
     }
 
     pub fn name(self) -> &'static str {
@@ -47,6 +135,8 @@ impl Card {
             Card::King => "King",
             Card::Countess => "Countess",
             Card::Princess => "Princess",
+            Card::Spy => "Spy",
+            Card::Chancellor => "Chancellor",
         }
     }
 
@@ -60,6 +150,12 @@ impl Card {
             Card::King => "Trade your hand with another player",
             Card::Countess => "Discard the Countess if you have the King or Prince in your hand",
             Card::Princess => "You are eliminated if you discard the Princess",
+            Card::Spy => {
+                "Gain a point at the end of the round if you are the only player left in it who played or discarded a Spy"
+            }
+            Card::Chancellor => {
+                "Draw two cards, keep one of your three and put the other two on the bottom of the deck"
+            }
         }
     }
 
@@ -73,6 +169,8 @@ impl Card {
             Card::King => NamedColor::Blue,
             Card::Countess => NamedColor::Red,
             Card::Princess => NamedColor::Yellow,
+            Card::Spy => NamedColor::Brown,
+            Card::Chancellor => NamedColor::Orange,
         }
     }
 }
@@ -105,8 +203,3 @@ pub fn initial_deck() -> Vec<Card> {
         Card::Princess,
     ]
 }
-
-/// Number of copies of `card` in the full deck, used by the help table.
-pub fn deck_count(card: Card) -> usize {
-    initial_deck().iter().filter(|&&c| c == card).count()
-}
@@ -1,7 +1,7 @@
 use brdgme_game::command::parser::*;
 
 use crate::Game;
-use crate::card::{Card, princess_to_guard};
+use crate::card::{Card, Edition};
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Command {
@@ -13,6 +13,9 @@ pub enum Command {
     Baron(usize),
     Priest(usize),
     Guard(usize, Card),
+    Spy,
+    Chancellor,
+    Keep(Card),
 }
 
 impl Game {
@@ -29,6 +32,12 @@ impl Game {
         }
         let mut parsers: Vec<Box<dyn Parser<T = Command>>> = vec![];
         let hand = self.hands.get(player).cloned().unwrap_or_default();
+        if self.chancellor_pending {
+            let mut choices = hand;
+            choices.sort();
+            choices.dedup();
+            return Some(Box::new(keep_parser(choices)));
+        }
         if hand.contains(&Card::Princess) {
             parsers.push(Box::new(princess_parser()));
         }
@@ -38,6 +47,9 @@ impl Game {
         if hand.contains(&Card::King) {
             parsers.push(Box::new(king_parser()));
         }
+        if hand.contains(&Card::Chancellor) {
+            parsers.push(Box::new(chancellor_parser()));
+        }
         if hand.contains(&Card::Prince) {
             parsers.push(Box::new(prince_parser()));
         }
@@ -51,7 +63,10 @@ impl Game {
             parsers.push(Box::new(priest_parser()));
         }
         if hand.contains(&Card::Guard) {
-            parsers.push(Box::new(guard_parser()));
+            parsers.push(Box::new(guard_parser(self.edition)));
+        }
+        if hand.contains(&Card::Spy) {
+            parsers.push(Box::new(spy_parser()));
         }
         if parsers.is_empty() {
             None
@@ -166,11 +181,11 @@ pub fn priest_parser() -> impl Parser<T = Command> {
     )
 }
 
-pub fn card_parser() -> impl Parser<T = Card> {
-    Enum::exact(princess_to_guard())
+pub fn card_parser(edition: Edition) -> impl Parser<T = Card> {
+    Enum::exact(edition.cards())
 }
 
-pub fn guard_parser() -> impl Parser<T = Command> {
+pub fn guard_parser(edition: Edition) -> impl Parser<T = Command> {
     Map::new(
         Chain3::new(
             Doc::name_desc(
@@ -182,9 +197,49 @@ pub fn guard_parser() -> impl Parser<T = Command> {
             AfterSpace::new(Doc::name_desc(
                 "card",
                 "the card you think they are",
-                card_parser(),
+                card_parser(edition),
             )),
         ),
         |(_, target, card)| Command::Guard(target, card),
     )
 }
+
+pub fn spy_parser() -> impl Parser<T = Command> {
+    Map::new(
+        Doc::name_desc(
+            "spy",
+            "play the Spy card, gaining a point at the end of the round if nobody else left in it played one",
+            Token::new("spy"),
+        ),
+        |_| Command::Spy,
+    )
+}
+
+pub fn chancellor_parser() -> impl Parser<T = Command> {
+    Map::new(
+        Doc::name_desc(
+            "chancellor",
+            "play the Chancellor card to draw two cards, then keep one and return the others",
+            Token::new("chancellor"),
+        ),
+        |_| Command::Chancellor,
+    )
+}
+
+pub fn keep_parser(choices: Vec<Card>) -> impl Parser<T = Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "keep",
+                "keep a card, putting the others on the bottom of the deck",
+                Token::new("keep"),
+            ),
+            AfterSpace::new(Doc::name_desc(
+                "card",
+                "the card to keep",
+                Enum::exact(choices),
+            )),
+        ),
+        |(_, card)| Command::Keep(card),
+    )
+}
@@ -6,10 +6,10 @@ use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
 use brdgme_game::rng::GameRng;
-use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
+use brdgme_game::{CommandResponse, GameOption, GameOptions, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
 
-use crate::card::Card;
+use crate::card::{Card, Edition};
 use crate::command::Command;
 
 pub mod card;
@@ -17,18 +17,11 @@ mod command;
 mod render;
 
 const MIN_PLAYERS: usize = 2;
-const MAX_PLAYERS: usize = 4;
+const MAX_PLAYERS: usize = 6;
+/// The classic 16 card game only plays up to 4.
+const MAX_CLASSIC_PLAYERS: usize = 4;
 
-/// Points required to win the game, keyed by player count - matches the Go
-/// `endScores` map.
-fn end_score(players: usize) -> usize {
-    match players {
-        2 => 7,
-        3 => 5,
-        4 => 4,
-        _ => unreachable!(),
-    }
-}
+pub const EDITION_OPTION: &str = "edition";
 
 #[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
@@ -42,6 +35,12 @@ pub struct Game {
     pub current_player: usize,
     pub eliminated: Vec<bool>,
     pub protected: Vec<bool>,
+    #[serde(default)]
+    pub edition: Edition,
+    /// Set while the current player chooses which card to keep after
+    /// playing the Chancellor.
+    #[serde(default)]
+    pub chancellor_pending: bool,
     // Migration shim: pre-seed games get a fresh RNG on first load.
     #[serde(default = "GameRng::from_entropy")]
     pub rng: GameRng,
@@ -52,7 +51,7 @@ pub struct Game {
 /// public consequences (discards, points, elimination/protection) are.
 #[derive(Default, Serialize, Deserialize)]
 pub struct PubState {
-    /// Number of players in the game, 2 through 4.
+    /// Number of players in the game, 2 through 4 for the classic edition, up to 6 for the 2019 edition.
     pub players: usize,
     /// Number of cards left in the draw pile. When 0, the round ends after the current turn.
     pub deck_remaining: usize,
@@ -70,6 +69,10 @@ pub struct PubState {
     pub end_score: usize,
     /// The highest point total held by any player.
     pub leader_points: usize,
+    /// The edition being played, `Classic` or `Edition2019`.
+    pub edition: Edition,
+    /// True while the current player is choosing which card to keep after playing the Chancellor.
+    pub chancellor_pending: bool,
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -87,7 +90,7 @@ impl Game {
         self.round += 1;
         self.eliminated = vec![false; self.players];
         self.protected = vec![false; self.players];
-        let mut deck = card::initial_deck();
+        let mut deck = self.edition.deck();
         deck.shuffle(&mut self.rng);
         let remove = if self.players == 2 { 4 } else { 1 };
         let mut logs = vec![Log::public(vec![
@@ -132,7 +135,7 @@ impl Game {
         let mut logs = vec![Log::public(vec![
             N::Player(player),
             N::text(" discarded "),
-            render::card(card),
+            self.card_node(card),
         ])];
         logs.extend(self.discard_card(player, card));
         logs
@@ -182,21 +185,24 @@ impl Game {
                 continue;
             }
             let c = self.hands[p][0];
-            let discarded: u32 = self.discards[p].iter().map(|c| c.number() as u32).sum();
+            let discarded: u32 = self.discards[p]
+                .iter()
+                .map(|c| c.value(self.edition) as u32)
+                .sum();
             output.push(N::text("\n"));
             output.extend(vec![
                 N::Player(p),
                 N::text(" had "),
-                render::card(c),
+                self.card_node(c),
                 N::text(" (total "),
                 N::Bold(vec![N::text(format!("{}", discarded))]),
                 N::text(" discarded)"),
             ]);
-            if c.number() > highest_card {
-                highest_card = c.number();
+            if c.value(self.edition) > highest_card {
+                highest_card = c.value(self.edition);
                 discard_total = -1;
             }
-            if c.number() == highest_card && discarded as i64 > discard_total {
+            if c.value(self.edition) == highest_card && discarded as i64 > discard_total {
                 discard_total = discarded as i64;
                 highest_player = p;
             }
@@ -213,6 +219,21 @@ impl Game {
                 plural(self.player_points[highest_player], "point")
             ))]),
         ]);
+        if let Some(spy) = self.lone_spy() {
+            self.player_points[spy] += 1;
+            output.push(N::text("\n"));
+            output.extend(vec![
+                N::Player(spy),
+                N::text(" was the only player left in the round with a "),
+                self.card_node(Card::Spy),
+                N::text(" and gained a point, moving to "),
+                N::Bold(vec![N::text(format!(
+                    "{} {}",
+                    self.player_points[spy],
+                    plural(self.player_points[spy], "point")
+                ))]),
+            ]);
+        }
 
         let is_finished = self.check_finished();
         if is_finished {
@@ -230,6 +251,26 @@ impl Game {
         logs
     }
 
+    /// The player who gets the Spy bonus: the only one still in the round
+    /// who played or discarded a Spy.
+    fn lone_spy(&self) -> Option<usize> {
+        let spies: Vec<usize> = (0..self.players)
+            .filter(|&p| !self.eliminated[p] && self.discards[p].contains(&Card::Spy))
+            .collect();
+        match spies.as_slice() {
+            [spy] => Some(*spy),
+            _ => None,
+        }
+    }
+
+    fn end_score(&self) -> usize {
+        self.edition.end_score(self.players)
+    }
+
+    fn card_node(&self, c: Card) -> N {
+        render::card(c, self.edition)
+    }
+
     fn leader(&self) -> usize {
         let mut highest = 0;
         let mut player = 0;
@@ -262,7 +303,7 @@ impl Game {
             card
         };
         logs.push(Log::private(
-            vec![N::text("You drew "), render::card(card)],
+            vec![N::text("You drew "), self.card_node(card)],
             vec![player],
         ));
         self.hands[player].push(card);
@@ -270,7 +311,7 @@ impl Game {
     }
 
     fn check_finished(&self) -> bool {
-        self.player_points.iter().copied().max().unwrap_or(0) >= end_score(self.players)
+        self.player_points.iter().copied().max().unwrap_or(0) >= self.end_score()
     }
 
     fn available_targets(&self, for_player: usize) -> Vec<usize> {
@@ -326,6 +367,11 @@ impl Game {
         if self.current_player != player {
             return Err(GameError::NotYourTurn);
         }
+        if self.chancellor_pending {
+            return Err(GameError::invalid_input(
+                "you must choose which card to keep for the Chancellor",
+            ));
+        }
         Ok(())
     }
 
@@ -351,7 +397,7 @@ impl Game {
         let mut logs = vec![Log::public(vec![
             N::Player(player),
             N::text(" played "),
-            render::card(Card::Princess),
+            self.card_node(Card::Princess),
         ])];
         logs.extend(self.discard_card(player, Card::Princess));
 
@@ -367,11 +413,11 @@ impl Game {
         logs.push(Log::public(vec![
             N::Player(player),
             N::text(" discarded "),
-            render::card(Card::Countess),
+            self.card_node(Card::Countess),
             N::text(", they might have been forced to if they also had "),
-            render::card(Card::King),
+            self.card_node(Card::King),
             N::text(" or "),
-            render::card(Card::Prince),
+            self.card_node(Card::Prince),
         ]));
 
         logs.extend(self.maybe_next_player(cur_round));
@@ -390,7 +436,7 @@ impl Game {
             logs.push(Log::public(vec![
                 N::Player(player),
                 N::text(" played "),
-                render::card(Card::King),
+                self.card_node(Card::King),
                 N::text(", but had nobody to target so just discarded the card"),
             ]));
             logs.extend(self.maybe_next_player(cur_round));
@@ -400,25 +446,25 @@ impl Game {
         logs.push(Log::public(vec![
             N::Player(player),
             N::text(" played "),
-            render::card(Card::King),
+            self.card_node(Card::King),
             N::text(" and swapped hands with "),
             N::Player(target),
         ]));
         logs.push(Log::private(
             vec![
                 N::text("You traded your "),
-                render::card(self.hands[player][0]),
+                self.card_node(self.hands[player][0]),
                 N::text(" for "),
-                render::card(self.hands[target][0]),
+                self.card_node(self.hands[target][0]),
             ],
             vec![player],
         ));
         logs.push(Log::private(
             vec![
                 N::text("You traded your "),
-                render::card(self.hands[target][0]),
+                self.card_node(self.hands[target][0]),
                 N::text(" for "),
-                render::card(self.hands[player][0]),
+                self.card_node(self.hands[player][0]),
             ],
             vec![target],
         ));
@@ -440,7 +486,7 @@ impl Game {
         logs.push(Log::public(vec![
             N::Player(player),
             N::text(" played "),
-            render::card(Card::Prince),
+            self.card_node(Card::Prince),
             N::text(" and made "),
             N::Player(target),
             N::text(" discard their hand and draw a new card"),
@@ -466,7 +512,7 @@ impl Game {
         logs.push(Log::public(vec![
             N::Player(player),
             N::text(" played "),
-            render::card(Card::Handmaid),
+            self.card_node(Card::Handmaid),
             N::text(
                 " and is immune to the effects of other players' cards until the start of their next turn",
             ),
@@ -487,7 +533,7 @@ impl Game {
             logs.push(Log::public(vec![
                 N::Player(player),
                 N::text(" played "),
-                render::card(Card::Baron),
+                self.card_node(Card::Baron),
                 N::text(", but had nobody to target so just discarded the card"),
             ]));
             logs.extend(self.maybe_next_player(cur_round));
@@ -497,7 +543,7 @@ impl Game {
         logs.push(Log::public(vec![
             N::Player(player),
             N::text(" played "),
-            render::card(Card::Baron),
+            self.card_node(Card::Baron),
             N::text(" and is comparing hands with "),
             N::Player(target),
             N::text(" to see who has a lower card"),
@@ -507,28 +553,28 @@ impl Game {
         logs.push(Log::private(
             vec![
                 N::text("You have "),
-                render::card(player_card),
+                self.card_node(player_card),
                 N::text(", "),
                 N::Player(target),
                 N::text(" has "),
-                render::card(target_card),
+                self.card_node(target_card),
             ],
             vec![player],
         ));
         logs.push(Log::private(
             vec![
                 N::text("You have "),
-                render::card(target_card),
+                self.card_node(target_card),
                 N::text(", "),
                 N::Player(player),
                 N::text(" has "),
-                render::card(player_card),
+                self.card_node(player_card),
             ],
             vec![target],
         ));
 
         let mut eliminate: Option<usize> = None;
-        let diff = player_card.number() as i32 - target_card.number() as i32;
+        let diff = player_card.value(self.edition) as i32 - target_card.value(self.edition) as i32;
         if diff < 0 {
             eliminate = Some(player);
         } else if diff > 0 {
@@ -561,7 +607,7 @@ impl Game {
             logs.push(Log::public(vec![
                 N::Player(player),
                 N::text(" played "),
-                render::card(Card::Priest),
+                self.card_node(Card::Priest),
                 N::text(", but had nobody to target so just discarded the card"),
             ]));
             logs.extend(self.maybe_next_player(cur_round));
@@ -571,19 +617,106 @@ impl Game {
         logs.push(Log::public(vec![
             N::Player(player),
             N::text(" played "),
-            render::card(Card::Priest),
+            self.card_node(Card::Priest),
             N::text(" and looked at "),
             N::Player(target),
             N::text("'s hand"),
         ]));
         let mut private_log = vec![N::Player(target), N::text(" has ")];
-        private_log.extend(render::comma_cards(&self.hands[target]));
+        private_log.extend(render::comma_cards(&self.hands[target], self.edition));
         logs.push(Log::private(private_log, vec![player]));
 
         logs.extend(self.maybe_next_player(cur_round));
         Ok(logs)
     }
 
+    fn play_spy(&mut self, player: usize) -> Result<Vec<Log>, GameError> {
+        self.assert_can_play(player)?;
+        let cur_round = self.round;
+
+        let mut logs = self.discard_card(player, Card::Spy);
+        logs.push(Log::public(vec![
+            N::Player(player),
+            N::text(" played "),
+            self.card_node(Card::Spy),
+        ]));
+
+        logs.extend(self.maybe_next_player(cur_round));
+        Ok(logs)
+    }
+
+    /// Plays the Chancellor, drawing up to two cards. The turn only moves on
+    /// once the player has chosen which card to keep with `keep_card`.
+    fn play_chancellor(&mut self, player: usize) -> Result<Vec<Log>, GameError> {
+        self.assert_can_play(player)?;
+        let cur_round = self.round;
+
+        let mut logs = self.discard_card(player, Card::Chancellor);
+
+        let draw = self.deck.len().min(2);
+        if draw == 0 {
+            logs.push(Log::public(vec![
+                N::Player(player),
+                N::text(" played "),
+                self.card_node(Card::Chancellor),
+                N::text(", but the draw pile is empty so just discarded the card"),
+            ]));
+            logs.extend(self.maybe_next_player(cur_round));
+            return Ok(logs);
+        }
+
+        logs.push(Log::public(vec![
+            N::Player(player),
+            N::text(" played "),
+            self.card_node(Card::Chancellor),
+            N::text(format!(" and drew {} {}", draw, plural(draw, "card"))),
+        ]));
+        let drawn: Vec<Card> = self.deck.drain(..draw).collect();
+        let mut private_log = vec![N::text("You drew ")];
+        private_log.extend(render::comma_cards(&drawn, self.edition));
+        logs.push(Log::private(private_log, vec![player]));
+        self.hands[player].extend(drawn);
+        self.chancellor_pending = true;
+        Ok(logs)
+    }
+
+    /// Finishes a Chancellor play: keeps `card` and puts the rest of the hand
+    /// on the bottom of the draw pile.
+    fn keep_card(&mut self, player: usize, card: Card) -> Result<Vec<Log>, GameError> {
+        if self.current_player != player {
+            return Err(GameError::NotYourTurn);
+        }
+        if !self.chancellor_pending {
+            return Err(GameError::invalid_input(
+                "you can only keep a card after playing the Chancellor",
+            ));
+        }
+        let Some(pos) = self.hands[player].iter().position(|&c| c == card) else {
+            return Err(GameError::invalid_input("you don't have that card"));
+        };
+        let cur_round = self.round;
+        let kept = self.hands[player].remove(pos);
+        let returned = std::mem::replace(&mut self.hands[player], vec![kept]);
+        self.chancellor_pending = false;
+
+        let mut logs = vec![Log::public(vec![
+            N::Player(player),
+            N::text(format!(
+                " kept a card and put {} {} on the bottom of the draw pile",
+                returned.len(),
+                plural(returned.len(), "card")
+            )),
+        ])];
+        let mut private_log = vec![N::text("You kept "), self.card_node(kept)];
+        private_log.push(N::text(" and returned "));
+        private_log.extend(render::comma_cards(&returned, self.edition));
+        logs.push(Log::private(private_log, vec![player]));
+        self.deck.extend(returned);
+
+        logs.extend(self.maybe_next_player(cur_round));
+        Ok(logs)
+    }
+
     fn play_guard(
         &mut self,
         player: usize,
@@ -599,7 +732,7 @@ impl Game {
             logs.push(Log::public(vec![
                 N::Player(player),
                 N::text(" played "),
-                render::card(Card::Guard),
+                self.card_node(Card::Guard),
                 N::text(", but had nobody to target so just discarded the card"),
             ]));
             logs.extend(self.discard_card(player, Card::Guard));
@@ -618,11 +751,11 @@ impl Game {
         let mut prefix = vec![
             N::Player(player),
             N::text(" played "),
-            render::card(Card::Guard),
+            self.card_node(Card::Guard),
             N::text(" and guessed that "),
             N::Player(target),
             N::text(" is a "),
-            render::card(card),
+            self.card_node(card),
             N::text(", "),
         ];
 
@@ -645,6 +778,30 @@ impl Gamer for Game {
     type PlayerState = PlayerState;
 
     fn start(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError> {
+        Self::start_with_options(players, seed, &GameOptions::new())
+    }
+
+    fn options() -> Vec<GameOption> {
+        vec![GameOption::new(
+            EDITION_OPTION,
+            "Edition",
+            "The classic 16 card game, or the 2019 edition with the Spy and Chancellor for up to 6 players",
+            &[
+                (
+                    Edition::Classic.key(),
+                    "Classic, 16 cards (the 2019 edition with 5 or 6 players)",
+                ),
+                (Edition::Edition2019.key(), "2019 edition, 21 cards"),
+            ],
+        )]
+    }
+
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let options = options.resolve(&Self::options())?;
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
             return Err(GameError::PlayerCount {
                 min: MIN_PLAYERS,
@@ -652,8 +809,16 @@ impl Gamer for Game {
                 given: players,
             });
         }
+        let edition = if options.get(EDITION_OPTION) == Some(Edition::Edition2019.key())
+            || players > MAX_CLASSIC_PLAYERS
+        {
+            Edition::Edition2019
+        } else {
+            Edition::Classic
+        };
         let mut g = Game {
             players,
+            edition,
             player_points: vec![0; players],
             rng: GameRng::seed_from_u64(seed),
             ..Game::default()
@@ -667,6 +832,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![Default::default(); self.players],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -685,8 +852,10 @@ impl Gamer for Game {
             current_player: self.current_player,
             eliminated: self.eliminated.clone(),
             protected: self.protected.clone(),
-            end_score: end_score(self.players),
+            end_score: self.end_score(),
             leader_points: self.player_points.iter().copied().max().unwrap_or(0),
+            edition: self.edition,
+            chancellor_pending: self.chancellor_pending,
         }
     }
 
@@ -769,6 +938,21 @@ impl Gamer for Game {
                 false,
                 remaining.to_string(),
             ),
+            Ok(ParseOutput {
+                value: Command::Spy,
+                remaining,
+                ..
+            }) => (self.play_spy(player)?, false, remaining.to_string()),
+            Ok(ParseOutput {
+                value: Command::Chancellor,
+                remaining,
+                ..
+            }) => (self.play_chancellor(player)?, false, remaining.to_string()),
+            Ok(ParseOutput {
+                value: Command::Keep(card),
+                remaining,
+                ..
+            }) => (self.keep_card(player, card)?, false, remaining.to_string()),
             Ok(ParseOutput {
                 value: Command::Guard(target, card),
                 remaining,
@@ -810,6 +994,11 @@ impl Gamer for Game {
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&self.players) {
             return Err(GameError::internal("love-letter-2: players out of range"));
         }
+        if self.edition == Edition::Classic && self.players > MAX_CLASSIC_PLAYERS {
+            return Err(GameError::internal(
+                "love-letter-2: the classic edition only plays up to 4",
+            ));
+        }
         if self.current_player >= self.players {
             return Err(GameError::internal(
                 "love-letter-2: current_player out of range",
@@ -901,7 +1090,7 @@ mod tests {
     const BJ: usize = 2;
 
     fn end_score_of(players: usize) -> usize {
-        end_score(players)
+        Edition::Classic.end_score(players)
     }
 
     #[test]
@@ -1261,4 +1450,153 @@ mod tests {
         assert!(!resp.logs.iter().any(|l| l.content == expected));
         assert!(!g.is_finished());
     }
+
+    fn start_2019(players: usize) -> Game {
+        Game::start_with_options(
+            players,
+            1,
+            &GameOptions::new().with(EDITION_OPTION, Edition::Edition2019.key()),
+        )
+        .unwrap()
+        .0
+    }
+
+    fn names(players: usize) -> Vec<String> {
+        ["Mick", "Steve", "BJ", "Ross", "Max", "Ella"][..players]
+            .iter()
+            .map(|n| n.to_string())
+            .collect()
+    }
+
+    fn end_score_2019(players: usize) -> usize {
+        Edition::Edition2019.end_score(players)
+    }
+
+    #[test]
+    fn edition_2019_uses_21_cards() {
+        let g = start_2019(2);
+        assert_eq!(Edition::Edition2019, g.edition);
+        let in_play = g.deck.len() + g.removed.len() + g.hands.iter().flatten().count();
+        assert_eq!(21, in_play);
+        assert_eq!(2, Edition::Edition2019.deck_count(Card::Spy));
+        assert_eq!(2, Edition::Edition2019.deck_count(Card::Chancellor));
+        assert_eq!(6, Edition::Edition2019.deck_count(Card::Guard));
+        assert_eq!(6, end_score_2019(2));
+    }
+
+    #[test]
+    fn five_and_six_players_play_the_2019_edition() {
+        assert_eq!(vec![2, 3, 4, 5, 6], Game::player_counts());
+        for players in [5, 6] {
+            let g = Game::start(players, 1).unwrap().0;
+            assert_eq!(Edition::Edition2019, g.edition);
+            assert_eq!(3, g.pub_state().end_score);
+            assert!(g.validate().is_ok());
+        }
+        // Four players keep the classic game by default.
+        assert_eq!(Edition::Classic, Game::start(4, 1).unwrap().0.edition);
+        let mut g = Game::start(5, 1).unwrap().0;
+        g.edition = Edition::Classic;
+        assert!(g.validate().is_err());
+    }
+
+    #[test]
+    fn edition_2019_renumbers_the_top_cards() {
+        assert_eq!(6, Card::King.value(Edition::Classic));
+        assert_eq!(7, Card::King.value(Edition::Edition2019));
+        assert_eq!(9, Card::Princess.value(Edition::Edition2019));
+        assert_eq!(0, Card::Spy.value(Edition::Edition2019));
+        // The King now beats the Chancellor in a Baron comparison.
+        let mut g = start_2019(3);
+        g.hands[MICK] = vec![Card::Baron, Card::King];
+        g.hands[STEVE] = vec![Card::Chancellor];
+        g.command(MICK, "baron steve", &names(3)).unwrap();
+        assert!(g.eliminated[STEVE]);
+    }
+
+    #[test]
+    fn guard_can_guess_the_spy_in_2019() {
+        let mut g = start_2019(3);
+        g.hands[MICK] = vec![Card::Guard, Card::King];
+        g.hands[STEVE] = vec![Card::Spy];
+        g.command(MICK, "guard steve spy", &names(3)).unwrap();
+        assert!(g.eliminated[STEVE]);
+
+        let mut g = Game::start(3, 1).unwrap().0;
+        g.hands[MICK] = vec![Card::Guard, Card::King];
+        assert!(g.command(MICK, "guard steve spy", &names(3)).is_err());
+    }
+
+    #[test]
+    fn lone_spy_gains_a_point() {
+        let mut g = start_2019(3);
+        g.deck = vec![];
+        g.hands[MICK] = vec![Card::Priest];
+        g.hands[STEVE] = vec![Card::Princess];
+        g.hands[BJ] = vec![Card::Guard];
+        g.discards[MICK] = vec![Card::Spy];
+        g.end_round();
+        // Steve wins the round with the Princess, Mick gets the Spy bonus.
+        assert_eq!(vec![1, 1, 0], g.player_points);
+    }
+
+    #[test]
+    fn spy_bonus_needs_a_lone_spy() {
+        let mut g = start_2019(3);
+        g.deck = vec![];
+        g.hands[MICK] = vec![Card::Priest];
+        g.hands[STEVE] = vec![Card::Princess];
+        g.hands[BJ] = vec![Card::Guard];
+        g.discards[MICK] = vec![Card::Spy];
+        g.discards[BJ] = vec![Card::Spy];
+        g.end_round();
+        assert_eq!(vec![0, 1, 0], g.player_points);
+
+        // An eliminated spy doesn't count, leaving BJ alone.
+        let mut g = start_2019(3);
+        g.deck = vec![];
+        g.hands[MICK] = vec![];
+        g.eliminated[MICK] = true;
+        g.hands[STEVE] = vec![Card::Princess];
+        g.hands[BJ] = vec![Card::Guard];
+        g.discards[MICK] = vec![Card::Spy];
+        g.discards[BJ] = vec![Card::Spy];
+        g.end_round();
+        assert_eq!(vec![0, 1, 1], g.player_points);
+    }
+
+    #[test]
+    fn chancellor_draws_two_and_returns_two() {
+        let mut g = start_2019(3);
+        g.current_player = MICK;
+        g.hands[MICK] = vec![Card::Chancellor, Card::Priest];
+        g.deck = vec![Card::Baron, Card::Princess, Card::Guard];
+        g.command(MICK, "chancellor", &names(3)).unwrap();
+        assert!(g.chancellor_pending);
+        assert_eq!(MICK, g.current_player);
+        assert_eq!(
+            vec![Card::Priest, Card::Baron, Card::Princess],
+            g.hands[MICK]
+        );
+        // Only keeping is allowed until the choice is made.
+        assert!(g.command(MICK, "priest steve", &names(3)).is_err());
+        g.command(MICK, "keep princess", &names(3)).unwrap();
+        assert!(!g.chancellor_pending);
+        assert_eq!(vec![Card::Princess], g.hands[MICK]);
+        assert!(!g.eliminated[MICK]);
+        assert_eq!(STEVE, g.current_player);
+        // Steve drew the Guard, the returned cards are at the bottom.
+        assert_eq!(vec![Card::Priest, Card::Baron], g.deck);
+    }
+
+    #[test]
+    fn chancellor_with_an_empty_deck_does_nothing() {
+        let mut g = start_2019(3);
+        g.current_player = MICK;
+        g.hands[MICK] = vec![Card::Chancellor, Card::Priest];
+        g.deck = vec![];
+        let logs = g.play_chancellor(MICK).unwrap();
+        assert!(!g.chancellor_pending);
+        assert!(!logs.is_empty());
+    }
 }
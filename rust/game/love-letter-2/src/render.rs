@@ -2,7 +2,7 @@ use brdgme_color::NamedColor;
 use brdgme_game::Renderer;
 use brdgme_markup::{Align as A, Node as N, Row, table_with_gap};
 
-use crate::card::{Card, deck_count, princess_to_guard};
+use crate::card::{Card, Edition};
 use crate::{PlayerState, PubState, plural};
 
 /// Column spacing used by the Go `render.Table(cells, 0, 2)` calls in
@@ -43,12 +43,20 @@ fn render(pub_state: &PubState, player: Option<usize>, hand: Option<&[Card]>) ->
                     plural(h.len(), "card")
                 ))])],
             )]);
+            if pub_state.chancellor_pending {
+                rows.push(vec![(
+                    A::Center,
+                    vec![N::text(
+                        "Choose a card to keep, the others go to the bottom of the deck",
+                    )],
+                )]);
+            }
             let mut hand_line: Vec<N> = vec![];
             for (i, &c) in h.iter().enumerate() {
                 if i > 0 {
                     hand_line.push(N::text("   "));
                 }
-                hand_line.push(card(c));
+                hand_line.push(card(c, pub_state.edition));
             }
             rows.push(vec![(A::Center, hand_line)]);
         }
@@ -69,7 +77,7 @@ fn render(pub_state: &PubState, player: Option<usize>, hand: Option<&[Card]>) ->
 
     rows.push(vec![(
         A::Center,
-        vec![table_with_gap(&help_table(), COL_SPACING)],
+        vec![table_with_gap(&help_table(pub_state.edition), COL_SPACING)],
     )]);
 
     vec![N::Table(rows)]
@@ -111,7 +119,7 @@ fn player_table(pub_state: &PubState) -> Vec<Row> {
             if i > 0 {
                 discards.push(N::text("  "));
             }
-            discards.push(card(c));
+            discards.push(card(c, pub_state.edition));
         }
         table.push(vec![
             (A::Left, vec![N::Player(p)]),
@@ -129,7 +137,7 @@ fn player_table(pub_state: &PubState) -> Vec<Row> {
     table
 }
 
-fn help_table() -> Vec<Row> {
+fn help_table(edition: Edition) -> Vec<Row> {
     let mut table: Vec<Row> = vec![
         vec![],
         vec![],
@@ -139,10 +147,10 @@ fn help_table() -> Vec<Row> {
             (A::Left, vec![N::Bold(vec![N::text("Description")])]),
         ],
     ];
-    for c in princess_to_guard() {
+    for c in edition.cards() {
         table.push(vec![
-            (A::Left, vec![card(c)]),
-            (A::Left, vec![N::text(format!("{}", deck_count(c)))]),
+            (A::Left, vec![card(c, edition)]),
+            (A::Left, vec![N::text(format!("{}", edition.deck_count(c)))]),
             (
                 A::Left,
                 vec![N::Fg(NamedColor::Grey.into(), vec![N::text(c.text())])],
@@ -164,20 +172,20 @@ impl Renderer for PlayerState {
     }
 }
 
-pub fn card(c: Card) -> N {
+pub fn card(c: Card, edition: Edition) -> N {
     N::Bold(vec![N::Fg(
         c.color().into(),
-        vec![N::text(format!("{} ({})", c.name(), c.number()))],
+        vec![N::text(format!("{} ({})", c.name(), c.value(edition)))],
     )])
 }
 
-pub fn comma_cards(cards: &[Card]) -> Vec<N> {
+pub fn comma_cards(cards: &[Card], edition: Edition) -> Vec<N> {
     let mut output: Vec<N> = vec![];
     for &c in cards {
         if !output.is_empty() {
             output.push(N::text(", "));
         }
-        output.push(card(c));
+        output.push(card(c, edition));
     }
     output
 }
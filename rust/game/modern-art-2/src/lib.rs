@@ -652,6 +652,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![HashMap::new(); self.players],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -7,6 +7,7 @@ use brdgme_game::command::Spec as CommandSpec;
 use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
+use brdgme_game::i18n::Locale;
 use brdgme_game::rng::GameRng;
 use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
@@ -257,6 +258,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -384,6 +387,14 @@ impl Gamer for Game {
     fn advanced_strategy() -> String {
         include_str!("../ADVANCED_STRATEGY.md").to_string()
     }
+
+    fn catalog_yaml(locale: Locale) -> Option<&'static str> {
+        match locale {
+            Locale::Es => Some(include_str!("../i18n/es.yaml")),
+            Locale::De => Some(include_str!("../i18n/de.yaml")),
+            Locale::En => None,
+        }
+    }
 }
 
 #[cfg(test)]
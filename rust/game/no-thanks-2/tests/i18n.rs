@@ -0,0 +1,78 @@
+use brdgme_cmd::api::{Request, Response};
+use brdgme_cmd::requester::{Requester, gamer};
+use brdgme_game::i18n::Locale;
+use no_thanks_2::Game;
+
+fn new_game(r: &mut impl Requester) -> String {
+    match r
+        .request(&Request::New {
+            players: 3,
+            seed: Some(1),
+            options: Default::default(),
+        })
+        .unwrap()
+    {
+        Response::New { game, .. } => game.state,
+        resp => panic!("expected New, got {:?}", resp),
+    }
+}
+
+fn whose_turn(state: &str, r: &mut impl Requester) -> usize {
+    match r
+        .request(&Request::Status {
+            game: state.to_string(),
+        })
+        .unwrap()
+    {
+        Response::Status { game, .. } => match game.status {
+            brdgme_game::Status::Active { whose_turn, .. } => whose_turn[0],
+            s => panic!("expected active game, got {:?}", s),
+        },
+        resp => panic!("expected Status, got {:?}", resp),
+    }
+}
+
+#[test]
+fn localized_commands_are_accepted() {
+    let mut r = gamer::new::<Game>();
+    let state = new_game(&mut r);
+    let player = whose_turn(&state, &mut r);
+    for (locale, word) in [(Locale::Es, "tomar"), (Locale::De, "nehmen")] {
+        let resp = r
+            .request(&Request::Localized {
+                locale,
+                request: Box::new(Request::Play {
+                    player,
+                    command: word.to_string(),
+                    names: vec!["a".into(), "b".into(), "c".into()],
+                    game: state.clone(),
+                }),
+            })
+            .unwrap();
+        match resp {
+            Response::Play { logs, .. } => {
+                assert!(!logs.is_empty(), "{}: expected logs", locale);
+            }
+            resp => panic!("{}: expected Play, got {:?}", locale, resp),
+        }
+    }
+}
+
+#[test]
+fn localized_status_translates_render() {
+    let mut r = gamer::new::<Game>();
+    let state = new_game(&mut r);
+    match r
+        .request(&Request::Localized {
+            locale: Locale::Es,
+            request: Box::new(Request::Status { game: state }),
+        })
+        .unwrap()
+    {
+        Response::Status { public_render, .. } => {
+            assert!(public_render.render.contains("Jugadores"));
+            assert!(!public_render.render.contains("Players"));
+        }
+        resp => panic!("expected Status, got {:?}", resp),
+    }
+}
@@ -0,0 +1,7 @@
+use brdgme_cmd::scenario::assert_scenarios;
+use no_thanks_2::Game;
+
+#[test]
+fn golden_scenarios() {
+    assert_scenarios::<Game>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/scenarios"));
+}
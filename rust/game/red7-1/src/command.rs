@@ -7,6 +7,8 @@ use crate::card::Card;
 pub enum Command {
     Play { card: Card },
     Discard { card: Card },
+    Remove { target: usize, card: Card },
+    Swap { target: usize },
     Done,
 }
 
@@ -89,6 +91,43 @@ impl Game {
             )));
         }
 
+        if self.can_remove(player) {
+            parsers.push(Box::new(Map::new(
+                Chain3::new(
+                    Doc::name_desc(
+                        "remove",
+                        "discard a card from the palette of a player with more cards in their palette than you, eg. remove mick b4",
+                        Token::new("remove"),
+                    ),
+                    AfterSpace::new(Doc::name_desc(
+                        "player",
+                        "the player whose palette to discard from",
+                        Player {},
+                    )),
+                    AfterSpace::new(CardParser),
+                ),
+                |(_, target, card): (String, usize, Card)| Command::Remove { target, card },
+            )));
+        }
+
+        if self.can_swap(player) {
+            parsers.push(Box::new(Map::new(
+                Chain2::new(
+                    Doc::name_desc(
+                        "swap",
+                        "swap your palette with another player's, eg. swap mick",
+                        Token::new("swap"),
+                    ),
+                    AfterSpace::new(Doc::name_desc(
+                        "player",
+                        "the player to swap palettes with",
+                        Player {},
+                    )),
+                ),
+                |(_, target): (String, usize)| Command::Swap { target },
+            )));
+        }
+
         if self.can_done(player) {
             parsers.push(Box::new(Map::new(
                 Doc::name_desc(
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
 use brdgme_game::rng::GameRng;
-use brdgme_game::{Gamer, Log, Status, placings_log};
+use brdgme_game::{GameOption, GameOptions, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
 
 mod card;
@@ -26,6 +26,26 @@ pub fn end_points(players: usize) -> u32 {
     50usize.saturating_sub(players.saturating_mul(5)) as u32
 }
 
+/// Option key choosing between the basic and advanced rules.
+pub const MODE_OPTION: &str = "mode";
+pub const MODE_ADVANCED: &str = "advanced";
+
+/// An action from an odd card played to a palette in the advanced game,
+/// waiting for the player to take it. Actions are optional and lapse when
+/// the player discards or finishes their turn. Playing a 5 draws a card
+/// straight away so it never waits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// From a 7: discard a card from the palette of another player who has
+    /// more cards in their palette than you.
+    RemoveFromPalette,
+    /// From a 3: play another card to your palette. Its action doesn't
+    /// trigger.
+    ExtraPlay,
+    /// From a 1: swap your palette with another player's.
+    SwapPalettes,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
     pub num_players: usize,
@@ -38,6 +58,14 @@ pub struct Game {
     pub palettes: Vec<Vec<Card>>,
     pub scored_cards: Vec<Vec<Card>>,
     pub eliminated: Vec<bool>,
+    #[serde(default)]
+    pub advanced: bool,
+    #[serde(default)]
+    pub pending_action: Option<Action>,
+    /// Cards discarded from palettes by actions, out of play until the next
+    /// round is dealt.
+    #[serde(default)]
+    pub removed: Vec<Card>,
     #[serde(default = "GameRng::from_entropy")]
     pub rng: GameRng,
 }
@@ -60,6 +88,11 @@ pub struct PubState {
     pub scored_cards: Vec<Vec<Card>>,
     /// Whether each player has been eliminated this round, indexed by player.
     pub eliminated: Vec<bool>,
+    /// True when playing the advanced rules, where odd cards played to a
+    /// palette trigger actions.
+    pub advanced: bool,
+    /// The action the current player may still take this turn, if any.
+    pub pending_action: Option<Action>,
     /// True when the game is over.
     pub finished: bool,
 }
@@ -84,6 +117,7 @@ impl Game {
             self.deck.append(&mut self.palettes[p]);
         }
         self.deck.append(&mut self.discard_pile);
+        self.deck.append(&mut self.removed);
         self.hands = vec![vec![]; l];
         self.palettes = vec![vec![]; l];
         self.eliminated = vec![false; l];
@@ -155,6 +189,7 @@ impl Game {
     }
 
     fn end_turn(&mut self, logs: &mut Vec<Log>) {
+        self.pending_action = None;
         if !self.eliminated[self.current_player] {
             let leader_idx = self.leader().map(|(i, _)| i);
             if leader_idx.is_some_and(|i| i != self.current_player) {
@@ -298,7 +333,59 @@ impl Game {
     }
 
     fn can_play(&self, player: usize) -> bool {
-        self.current_player == player && !self.has_played && !self.finished
+        self.current_player == player
+            && !self.finished
+            && (!self.has_played || self.pending_action == Some(Action::ExtraPlay))
+    }
+
+    fn can_remove(&self, player: usize) -> bool {
+        self.current_player == player
+            && !self.finished
+            && self.pending_action == Some(Action::RemoveFromPalette)
+    }
+
+    fn can_swap(&self, player: usize) -> bool {
+        self.current_player == player
+            && !self.finished
+            && self.pending_action == Some(Action::SwapPalettes)
+    }
+
+    /// Players whose palette a 7 can discard from: still in the round, with
+    /// more cards in their palette than `player`.
+    pub fn remove_targets(&self, player: usize) -> Vec<usize> {
+        (0..self.num_players)
+            .filter(|&p| {
+                p != player
+                    && !self.eliminated[p]
+                    && self.palettes[p].len() > self.palettes[player].len()
+            })
+            .collect()
+    }
+
+    /// Players a 1 can swap palettes with: anyone else still in the round.
+    pub fn swap_targets(&self, player: usize) -> Vec<usize> {
+        (0..self.num_players)
+            .filter(|&p| p != player && !self.eliminated[p])
+            .collect()
+    }
+
+    /// Sets up the action for a card just played in the advanced game.
+    fn trigger_action(&mut self, player: usize, card: Card) -> Vec<Log> {
+        let (action, hint) = match card.rank {
+            7 if !self.remove_targets(player).is_empty() => (
+                Action::RemoveFromPalette,
+                " may discard a card from a bigger palette",
+            ),
+            5 => return self.draw(player, 1),
+            3 if !self.hands[player].is_empty() => (Action::ExtraPlay, " may play another card"),
+            1 if !self.swap_targets(player).is_empty() => (
+                Action::SwapPalettes,
+                " may swap palettes with another player",
+            ),
+            _ => return vec![],
+        };
+        self.pending_action = Some(action);
+        vec![Log::public(vec![N::Player(player), N::text(hint)])]
     }
 
     fn can_discard(&self, player: usize) -> bool {
@@ -319,14 +406,78 @@ impl Game {
             .ok_or_else(|| GameError::invalid_input("you don't have that card"))?;
         self.hands[player].remove(index);
         self.palettes[player].push(card);
+        let extra = self.pending_action == Some(Action::ExtraPlay);
+        self.pending_action = None;
         self.has_played = true;
-        Ok(vec![Log::public(vec![
+        let mut logs = vec![Log::public(vec![
             N::Player(player),
             N::text(" played "),
             N::Fg(
                 card.suit.color().into(),
                 vec![N::Bold(vec![N::text(format!("{}", card))])],
             ),
+        ])];
+        if self.advanced && !extra {
+            logs.extend(self.trigger_action(player, card));
+        }
+        Ok(logs)
+    }
+
+    /// Takes a 7's action, discarding `card` from `target`'s palette.
+    pub fn remove(
+        &mut self,
+        player: usize,
+        target: usize,
+        card: Card,
+    ) -> Result<Vec<Log>, GameError> {
+        if !self.can_remove(player) {
+            return Err(GameError::invalid_input(
+                "you can only discard from a palette after playing a 7",
+            ));
+        }
+        if !self.remove_targets(player).contains(&target) {
+            return Err(GameError::invalid_input(
+                "you can only discard from the palette of a player in the round with more cards in their palette than you",
+            ));
+        }
+        let index = self.palettes[target]
+            .iter()
+            .position(|&c| c == card)
+            .ok_or_else(|| GameError::invalid_input("that card isn't in their palette"))?;
+        self.palettes[target].remove(index);
+        self.removed.push(card);
+        self.pending_action = None;
+        Ok(vec![Log::public(vec![
+            N::Player(player),
+            N::text(" discarded "),
+            N::Fg(
+                card.suit.color().into(),
+                vec![N::Bold(vec![N::text(format!("{}", card))])],
+            ),
+            N::text(" from "),
+            N::Player(target),
+            N::text("'s palette"),
+        ])])
+    }
+
+    /// Takes a 1's action, swapping palettes with `target`.
+    pub fn swap(&mut self, player: usize, target: usize) -> Result<Vec<Log>, GameError> {
+        if !self.can_swap(player) {
+            return Err(GameError::invalid_input(
+                "you can only swap palettes after playing a 1",
+            ));
+        }
+        if !self.swap_targets(player).contains(&target) {
+            return Err(GameError::invalid_input(
+                "you can only swap palettes with another player in the round",
+            ));
+        }
+        self.palettes.swap(player, target);
+        self.pending_action = None;
+        Ok(vec![Log::public(vec![
+            N::Player(player),
+            N::text(" swapped palettes with "),
+            N::Player(target),
         ])])
     }
 
@@ -396,6 +547,24 @@ impl Gamer for Game {
     type PlayerState = PlayerState;
 
     fn start(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError> {
+        Self::start_with_options(players, seed, &GameOptions::new())
+    }
+
+    fn options() -> Vec<GameOption> {
+        vec![GameOption::new(
+            MODE_OPTION,
+            "Rules",
+            "The advanced rules give odd cards played to your palette an action",
+            &[("basic", "Basic"), (MODE_ADVANCED, "Advanced")],
+        )]
+    }
+
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let options = options.resolve(&Self::options())?;
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
             return Err(GameError::PlayerCount {
                 min: MIN_PLAYERS,
@@ -414,6 +583,9 @@ impl Gamer for Game {
             palettes: vec![vec![]; players],
             scored_cards: vec![vec![]; players],
             eliminated: vec![false; players],
+            advanced: options.get(MODE_OPTION) == Some(MODE_ADVANCED),
+            pending_action: None,
+            removed: vec![],
             rng: GameRng::seed_from_u64(seed),
         };
         let logs = g.start_round();
@@ -428,6 +600,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: gen_placings(&metrics),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -449,6 +623,8 @@ impl Gamer for Game {
             palettes: self.palettes.clone(),
             scored_cards: self.scored_cards.clone(),
             eliminated: self.eliminated.clone(),
+            advanced: self.advanced,
+            pending_action: self.pending_action,
             finished: self.finished,
         }
     }
@@ -495,6 +671,30 @@ impl Gamer for Game {
                     remaining_input: remaining.to_string(),
                 })
             }
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Remove { target, card },
+                ..
+            }) => {
+                let logs = self.remove(player, target, card)?;
+                Ok(CommandResponse {
+                    logs,
+                    can_undo: false,
+                    remaining_input: remaining.to_string(),
+                })
+            }
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Swap { target },
+                ..
+            }) => {
+                let logs = self.swap(player, target)?;
+                Ok(CommandResponse {
+                    logs,
+                    can_undo: false,
+                    remaining_input: remaining.to_string(),
+                })
+            }
             Ok(ParseOutput {
                 remaining,
                 value: Command::Discard { card },
@@ -681,6 +881,130 @@ mod tests {
         assert_eq!(g.deck.len(), initial_len - 1);
     }
 
+    fn advanced_game() -> Game {
+        let (mut g, _) =
+            Game::start_with_options(3, 0, &GameOptions::new().with(MODE_OPTION, MODE_ADVANCED))
+                .unwrap();
+        g.current_player = 0;
+        g.discard_pile = vec![];
+        g.hands = vec![
+            crds(&["r7", "b5", "g3", "y1", "o2"]),
+            crds(&["v4"]),
+            crds(&["i4"]),
+        ];
+        g.palettes = vec![crds(&["r6"]), crds(&["b2", "g4", "y6"]), crds(&["o3"])];
+        g
+    }
+
+    fn names() -> Vec<String> {
+        vec!["mick".to_string(), "steve".to_string(), "ruth".to_string()]
+    }
+
+    #[test]
+    fn mode_option_selects_advanced_rules() {
+        let (basic, _) = Game::start(2, 0).unwrap();
+        assert!(!basic.advanced);
+        let (advanced, _) =
+            Game::start_with_options(2, 0, &GameOptions::new().with(MODE_OPTION, MODE_ADVANCED))
+                .unwrap();
+        assert!(advanced.advanced);
+        assert!(advanced.pub_state().advanced);
+        assert!(
+            Game::start_with_options(2, 0, &GameOptions::new().with(MODE_OPTION, "expert"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn basic_rules_ignore_card_actions() {
+        let mut g = advanced_game();
+        g.advanced = false;
+        g.command(0, "play r7", &names()).unwrap();
+        assert_eq!(None, g.pending_action);
+        assert!(g.command(0, "remove steve b2", &names()).is_err());
+    }
+
+    #[test]
+    fn seven_discards_from_a_bigger_palette() {
+        let mut g = advanced_game();
+        g.command(0, "play r7", &names()).unwrap();
+        assert_eq!(Some(Action::RemoveFromPalette), g.pending_action);
+        assert_eq!(vec![1], g.remove_targets(0));
+        // Ruth's palette isn't bigger than mick's.
+        assert!(g.command(0, "remove ruth o3", &names()).is_err());
+        assert!(g.command(0, "remove steve r1", &names()).is_err());
+        g.command(0, "remove steve y6", &names()).unwrap();
+        assert_eq!(crds(&["b2", "g4"]), g.palettes[1]);
+        assert_eq!(crds(&["y6"]), g.removed);
+        assert_eq!(None, g.pending_action);
+        assert!(g.command(0, "remove steve b2", &names()).is_err());
+    }
+
+    #[test]
+    fn five_draws_a_card() {
+        let mut g = advanced_game();
+        let hand = g.hands[0].len();
+        g.command(0, "play b5", &names()).unwrap();
+        assert_eq!(hand, g.hands[0].len());
+        assert_eq!(None, g.pending_action);
+    }
+
+    #[test]
+    fn three_plays_an_extra_card_without_its_action() {
+        let mut g = advanced_game();
+        g.command(0, "play g3", &names()).unwrap();
+        assert_eq!(Some(Action::ExtraPlay), g.pending_action);
+        g.command(0, "play r7", &names()).unwrap();
+        assert_eq!(None, g.pending_action);
+        assert_eq!(crds(&["r6", "g3", "r7"]), g.palettes[0]);
+        assert!(g.command(0, "play o2", &names()).is_err());
+    }
+
+    #[test]
+    fn one_swaps_palettes() {
+        let mut g = advanced_game();
+        g.command(0, "play y1", &names()).unwrap();
+        assert_eq!(Some(Action::SwapPalettes), g.pending_action);
+        g.eliminated[2] = true;
+        assert!(g.command(0, "swap ruth", &names()).is_err());
+        g.command(0, "swap steve", &names()).unwrap();
+        assert_eq!(crds(&["b2", "g4", "y6"]), g.palettes[0]);
+        assert_eq!(crds(&["r6", "y1"]), g.palettes[1]);
+    }
+
+    #[test]
+    fn pending_actions_lapse_at_end_of_turn() {
+        let mut g = advanced_game();
+        g.palettes[1] = crds(&["b2"]);
+        g.command(0, "play r7", &names()).unwrap();
+        // Nobody has a bigger palette, so there is nothing to wait for.
+        assert_eq!(None, g.pending_action);
+        assert!(g.command(0, "play y1", &names()).is_err());
+        let mut g = advanced_game();
+        g.command(0, "play y1", &names()).unwrap();
+        g.palettes[0] = crds(&["r7", "r6"]);
+        g.command(0, "done", &names()).unwrap();
+        assert_eq!(None, g.pending_action);
+        assert_ne!(0, g.current_player);
+    }
+
+    #[test]
+    fn removed_cards_return_next_round() {
+        let mut g = advanced_game();
+        g.removed = crds(&["y6"]);
+        let total = g.deck.len()
+            + g.discard_pile.len()
+            + g.removed.len()
+            + g.hands.iter().map(Vec::len).sum::<usize>()
+            + g.palettes.iter().map(Vec::len).sum::<usize>();
+        g.start_round();
+        assert!(g.removed.is_empty());
+        let after = g.deck.len()
+            + g.hands.iter().map(Vec::len).sum::<usize>()
+            + g.palettes.iter().map(Vec::len).sum::<usize>();
+        assert_eq!(total, after);
+    }
+
     #[test]
     fn test_parse_card() {
         let cases: Vec<(&str, bool, Option<Card>)> = vec![
@@ -3,7 +3,7 @@ use brdgme_game::Renderer;
 use brdgme_markup::{Align as A, Node as N, Row, table_with_gap};
 
 use crate::card::{Card, Suit, sort_by_suit};
-use crate::{PlayerState, PubState, end_points};
+use crate::{Action, PlayerState, PubState, end_points};
 
 fn render_card(card: Card) -> N {
     N::Fg(
@@ -120,6 +120,41 @@ fn render_color_table() -> N {
     table_with_gap(&rows, 2)
 }
 
+fn action_hint(action: Action) -> &'static str {
+    match action {
+        Action::RemoveFromPalette => "discard a card from a bigger palette",
+        Action::ExtraPlay => "play another card",
+        Action::SwapPalettes => "swap palettes with another player",
+    }
+}
+
+fn action_command(action: Action) -> &'static str {
+    match action {
+        Action::RemoveFromPalette => "remove <player> <card>",
+        Action::ExtraPlay => "play <card>",
+        Action::SwapPalettes => "swap <player>",
+    }
+}
+
+/// What the current player's pending action lets them do, addressed to
+/// them if they're `viewer`.
+fn action_rows(pub_state: &PubState, viewer: Option<usize>) -> Vec<Row> {
+    let Some(action) = pub_state.pending_action else {
+        return vec![];
+    };
+    let who = if viewer == Some(pub_state.current_player) {
+        N::text("You")
+    } else {
+        N::Player(pub_state.current_player)
+    };
+    let mut line = vec![who, N::text(format!(" may {}", action_hint(action)))];
+    if viewer == Some(pub_state.current_player) {
+        line.push(N::text(" with "));
+        line.push(N::Bold(vec![N::text(action_command(action))]));
+    }
+    vec![blank_row(), centered_row(line)]
+}
+
 fn common_rows(pub_state: &PubState) -> Vec<Row> {
     let rule = pub_state
         .discard_pile
@@ -127,7 +162,14 @@ fn common_rows(pub_state: &PubState) -> Vec<Row> {
         .map(|c| c.suit)
         .unwrap_or(Suit::Red);
 
-    vec![
+    let mut rows = vec![];
+    if pub_state.advanced {
+        rows.push(centered_row(vec![
+            N::Bold(vec![N::text("Advanced rules:")]),
+            N::text(" 7 discard from a bigger palette, 5 draw, 3 play again, 1 swap palettes"),
+        ]));
+    }
+    rows.extend(vec![
         centered_row(vec![
             N::text("First to "),
             N::Bold(vec![N::text(format!(
@@ -142,12 +184,14 @@ fn common_rows(pub_state: &PubState) -> Vec<Row> {
             rule.color().into(),
             vec![N::Bold(vec![N::text(rule.rule_str().to_string())])],
         )]),
-    ]
+    ]);
+    rows
 }
 
 impl Renderer for PubState {
     fn render(&self) -> Vec<N> {
         let mut rows = common_rows(self);
+        rows.extend(action_rows(self, None));
         rows.push(blank_row());
         rows.push(centered_row(vec![
             N::Bold(vec![N::text("Deck remaining:")]),
@@ -165,6 +209,7 @@ impl Renderer for PubState {
 impl Renderer for PlayerState {
     fn render(&self) -> Vec<N> {
         let mut rows = common_rows(&self.public);
+        rows.extend(action_rows(&self.public, Some(self.player)));
         rows.push(blank_row());
         rows.push(centered_row(vec![N::Bold(vec![N::text("Your hand")])]));
         rows.push(centered_row(vec![render_hand_table(&self.hand)]));
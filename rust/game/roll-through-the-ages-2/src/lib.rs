@@ -38,8 +38,11 @@ use take::TakeAction;
 
 pub use command::{BuildTarget, BuyGoods, Command};
 
-pub const MIN_PLAYERS: usize = 2;
+pub const MIN_PLAYERS: usize = 1;
 pub const MAX_PLAYERS: usize = 4;
+/// Turns in a solo game, which also ends early if the usual end is
+/// triggered.
+pub const SOLO_TURNS: usize = 10;
 
 /// Phase enum, ported from `game.go`'s `Phase` iota.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -72,6 +75,9 @@ pub struct Game {
 
     pub final_round: bool,
     pub finished: bool,
+    /// Turns completed. Only ends a solo game.
+    #[serde(default)]
+    pub turns: usize,
 
     #[serde(default = "GameRng::from_entropy")]
     pub rng: GameRng,
@@ -92,6 +98,7 @@ impl Default for Game {
             remaining_coins: 0,
             final_round: false,
             finished: false,
+            turns: 0,
             rng: GameRng::default(),
         }
     }
@@ -120,6 +127,7 @@ pub struct RenderState {
     pub remaining_coins: i32,
     pub final_round: bool,
     pub finished: bool,
+    pub turns: usize,
 }
 
 impl From<&Game> for RenderState {
@@ -137,6 +145,7 @@ impl From<&Game> for RenderState {
             remaining_coins: game.remaining_coins,
             final_round: game.final_round,
             finished: game.finished,
+            turns: game.turns,
         }
     }
 }
@@ -606,12 +615,16 @@ impl Game {
         vec![]
     }
 
-    /// Port of `NextTurn`.
+    /// Port of `NextTurn`, plus the solo game's turn limit.
     fn next_turn(&mut self) -> Vec<Log> {
+        self.turns += 1;
         self.current_player = (self.current_player + 1) % self.players;
         if self.current_player == 0 && self.final_round {
             self.finished = true;
         }
+        if self.players == 1 && self.turns >= SOLO_TURNS {
+            self.finished = true;
+        }
         if !self.finished {
             return self.start_turn();
         }
@@ -703,8 +716,7 @@ impl Game {
     /// phase advanced (possibly full circle back to the same phase value)".
     fn keep_skulls(&mut self) -> (Vec<Log>, bool) {
         // Go: "You can reroll skulls in single player" - `PlayerCount()==1`
-        // guard. Dead in this platform (`PlayerCounts()` is `[2,3,4]`), but
-        // ported anyway for source fidelity.
+        // guard.
         if self.players == 1 {
             return (vec![], false);
         }
@@ -1681,6 +1693,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -1699,7 +1713,7 @@ impl Gamer for Game {
     }
 
     fn player_counts() -> Vec<usize> {
-        vec![2, 3, 4]
+        vec![1, 2, 3, 4]
     }
 
     fn rules() -> String {
@@ -1818,13 +1832,13 @@ mod tests {
 
     #[test]
     fn start_rejects_invalid_player_counts() {
-        assert!(Game::start(1, 1).is_err());
+        assert!(Game::start(0, 1).is_err());
         assert!(Game::start(5, 1).is_err());
     }
 
     #[test]
     fn start_ok_for_valid_player_counts_and_initial_state() {
-        for n in 2..=4 {
+        for n in 1..=4 {
             let (g, _) = Game::start(n, 1).unwrap();
             assert_eq!(n, g.boards.len());
             assert_eq!(0, g.current_player);
@@ -2288,6 +2302,29 @@ mod tests {
         assert!(g.finished);
     }
 
+    #[test]
+    fn next_turn_finishes_solo_game_after_ten_turns() {
+        let mut g = new_blank(1);
+        for _ in 1..SOLO_TURNS {
+            g.next_turn();
+            assert!(!g.finished);
+        }
+        g.next_turn();
+        assert_eq!(SOLO_TURNS, g.turns);
+        assert!(g.finished);
+    }
+
+    #[test]
+    fn solo_skulls_can_be_rerolled() {
+        let mut g = new_blank(1);
+        g.rolled_dice = vec![Die::Skull, Die::Skull, Die::Food];
+        let (logs, advanced) = g.keep_skulls();
+        assert!(logs.is_empty());
+        assert!(!advanced);
+        assert_eq!(3, g.rolled_dice.len());
+        assert!(g.kept_dice.is_empty());
+    }
+
     #[test]
     fn next_turn_does_not_finish_without_final_round() {
         let mut g = new_blank(2);
@@ -3483,7 +3520,7 @@ mod tests {
     #[test]
     fn validate_rejects_malformed_state() {
         let mut g = new_blank(2);
-        g.players = 1;
+        g.players = 0;
         assert!(matches!(g.validate(), Err(GameError::Internal { .. })));
         let mut g = new_blank(2);
         g.players = 5;
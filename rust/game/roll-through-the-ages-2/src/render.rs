@@ -134,6 +134,14 @@ fn render(game: &RenderState, player: usize) -> Vec<N> {
     out.push(N::text("\n\n"));
 
     // Remaining turns
+    if game.players == 1 && !game.finished {
+        out.push(N::Bold(vec![N::text(format!(
+            "Solo turn {} of {}",
+            game.turns + 1,
+            crate::SOLO_TURNS
+        ))]));
+        out.push(N::text("\n\n"));
+    }
     if game.final_round {
         out.push(N::Bold(vec![N::text("This is the final round")]));
         out.push(N::text("\n\n"));
@@ -25,6 +25,7 @@ pub enum CardKind {
     Military,
     Guild,
     Wonder,
+    Leader,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
@@ -90,6 +91,39 @@ pub enum CardEffect {
     },
     MimicGuild,
     PlayFinalCard,
+    /// VP for each military victory.
+    VictoryVP {
+        vp: i32,
+    },
+    /// VP on top of the usual 7 for each complete set of science symbols.
+    ScienceSetVP {
+        vp: i32,
+    },
+    /// VP for each set of one card of every listed kind.
+    SetVP {
+        kinds: Vec<CardKind>,
+        vp: i32,
+    },
+    /// Coins score a second time, 1 VP per 3 coins.
+    TreasuryVP,
+    /// Coins from the bank for each card of a kind built afterwards.
+    KindCoins {
+        kind: CardKind,
+        coins: i32,
+    },
+    /// Coins from the bank for each card built for free through a chain.
+    ChainCoins {
+        coins: i32,
+    },
+    /// Coins from the bank on each turn resources are bought from a
+    /// neighbour.
+    TradeRebate {
+        coins: i32,
+    },
+    /// Coins from the bank for each military victory.
+    MilitaryCoins {
+        coins: i32,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1478,6 +1512,114 @@ pub fn cities() -> Vec<City> {
     ]
 }
 
+fn leader(name: &str, coins: i32, effect: CardEffect) -> Card {
+    card(
+        name,
+        CardKind::Leader,
+        cost(&[(Good::Coin, coins)]),
+        &[],
+        &[],
+        effect,
+    )
+}
+
+fn self_bonus(kind: CardKind, vp: i32) -> CardEffect {
+    CardEffect::Bonus {
+        target_kinds: vec![BonusTarget::Kind(kind)],
+        directions: DIR_SELF.to_vec(),
+        vp,
+        coins: 0,
+    }
+}
+
+/// The leaders from the Leaders expansion, recruited for coins at the start
+/// of each age. Leaders that change costs are left out.
+pub fn leaders() -> Vec<Card> {
+    vec![
+        leader("Alexander", 3, CardEffect::VictoryVP { vp: 1 }),
+        leader("Amytis", 4, self_bonus(CardKind::Wonder, 2)),
+        leader("Aristotle", 3, CardEffect::ScienceSetVP { vp: 3 }),
+        leader("Caesar", 5, CardEffect::Military { strength: 2 }),
+        leader("Cleopatra", 4, CardEffect::VP { vp: 5 }),
+        leader(
+            "Croesus",
+            1,
+            CardEffect::Multi {
+                resources: multi_cost(&[(MultiResource::Coin, 6)]),
+            },
+        ),
+        leader(
+            "Euclid",
+            5,
+            CardEffect::Science {
+                fields: vec![Field::Mathematics],
+            },
+        ),
+        leader("Hannibal", 2, CardEffect::Military { strength: 1 }),
+        leader("Hatshepsut", 3, CardEffect::TradeRebate { coins: 1 }),
+        leader("Hiram", 3, self_bonus(CardKind::Guild, 2)),
+        leader("Hypatia", 4, self_bonus(CardKind::Scientific, 1)),
+        leader(
+            "Justinian",
+            3,
+            CardEffect::SetVP {
+                kinds: vec![CardKind::Military, CardKind::Civilian, CardKind::Scientific],
+                vp: 3,
+            },
+        ),
+        leader("Midas", 3, CardEffect::TreasuryVP),
+        leader("Nebuchadnezzar", 3, self_bonus(CardKind::Civilian, 1)),
+        leader("Nefertiti", 2, CardEffect::VP { vp: 4 }),
+        leader("Nero", 1, CardEffect::MilitaryCoins { coins: 2 }),
+        leader("Pericles", 6, self_bonus(CardKind::Military, 2)),
+        leader("Phidias", 3, self_bonus(CardKind::Raw, 1)),
+        leader(
+            "Plato",
+            4,
+            CardEffect::SetVP {
+                kinds: vec![
+                    CardKind::Raw,
+                    CardKind::Manufactured,
+                    CardKind::Civilian,
+                    CardKind::Commercial,
+                    CardKind::Scientific,
+                    CardKind::Military,
+                    CardKind::Guild,
+                ],
+                vp: 7,
+            },
+        ),
+        leader("Praxiteles", 3, self_bonus(CardKind::Manufactured, 2)),
+        leader(
+            "Ptolemy",
+            5,
+            CardEffect::Science {
+                fields: vec![Field::Theology],
+            },
+        ),
+        leader(
+            "Pythagoras",
+            5,
+            CardEffect::Science {
+                fields: vec![Field::Engineering],
+            },
+        ),
+        leader("Sappho", 1, CardEffect::VP { vp: 2 }),
+        leader("Solomon", 3, CardEffect::DrawDiscard { vp: 0 }),
+        leader("Varro", 3, self_bonus(CardKind::Commercial, 1)),
+        leader("Vitruvius", 1, CardEffect::ChainCoins { coins: 2 }),
+        leader(
+            "Xenophon",
+            2,
+            CardEffect::KindCoins {
+                kind: CardKind::Commercial,
+                coins: 2,
+            },
+        ),
+        leader("Zenobia", 2, CardEffect::VP { vp: 3 }),
+    ]
+}
+
 struct CardForPlayers {
     name: &'static str,
     players: &'static [usize],
@@ -1824,6 +1966,16 @@ mod tests {
     use super::*;
     use brdgme_game::rng::GameRng;
 
+    #[test]
+    fn leaders_deal_four_to_seven_players() {
+        let leaders = leaders();
+        assert!(leaders.len() >= 7 * 4);
+        let mut names: Vec<&str> = leaders.iter().map(|l| l.name.as_str()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(leaders.len(), names.len(), "leader names must be unique");
+    }
+
     #[test]
     fn deck_age1_sizes() {
         for p in 3..=7 {
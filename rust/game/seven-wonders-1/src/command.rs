@@ -1,15 +1,76 @@
 use brdgme_game::command::parser::*;
 
-use crate::Game;
+use crate::{Game, Phase, Side};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Command {
-    Build { card: usize },
-    Free { card: usize },
-    Wonder { card: usize },
-    Discard { card: usize },
-    Deal { deal: usize },
-    Take { card: usize },
+    Build {
+        card: usize,
+    },
+    Free {
+        card: usize,
+    },
+    Wonder {
+        card: usize,
+    },
+    Discard {
+        card: usize,
+    },
+    Deal {
+        deal: usize,
+    },
+    Take {
+        card: usize,
+    },
+    Keep {
+        card: usize,
+    },
+    Recruit {
+        card: usize,
+    },
+    Side {
+        side: Side,
+    },
+    /// A command for the Free City from the player running it this turn.
+    City {
+        command: Box<Command>,
+    },
+}
+
+type CommandParsers = Vec<Box<dyn Parser<T = Command>>>;
+
+fn card_arg(hand_len: usize) -> impl Parser<T = usize> {
+    Map::new(Int::bounded(1, hand_len as i32), |n: i32| (n - 1) as usize)
+}
+
+fn take_parser() -> Box<dyn Parser<T = Command>> {
+    Box::new(Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "take",
+                "take a card from the discard pile for free, eg. take 1",
+                Token::new("take"),
+            ),
+            AfterSpace::new(Map::new(Int::positive(), |n: i32| (n - 1) as usize)),
+        ),
+        |(_, card): (String, usize)| Command::Take { card },
+    ))
+}
+
+fn city_parser(parsers: CommandParsers) -> Box<dyn Parser<T = Command>> {
+    Box::new(Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "city",
+                "choose the Free City's card this turn, eg. city build 2",
+                Token::new("city"),
+            ),
+            AfterSpace::new(OneOf::new(parsers)),
+        ),
+        |(_, command): (String, Command)| Command::City {
+            command: Box::new(command),
+        },
+    ))
 }
 
 impl Game {
@@ -18,30 +79,63 @@ impl Game {
             return None;
         }
 
-        if player >= self.players {
+        if player >= self.player_total() {
             return None;
         }
 
+        let city = self
+            .free_city_seat()
+            .filter(|_| self.free_city_controller == player);
+
         if let Some(crate::Resolver::DrawDiscard { player: rp }) = self.to_resolve.first() {
-            if *rp != player {
+            let parsers: CommandParsers = if *rp == player {
+                vec![take_parser()]
+            } else if Some(*rp) == city {
+                vec![city_parser(vec![take_parser()])]
+            } else {
+                return None;
+            };
+            return Some(Box::new(OneOf::new(parsers)));
+        }
+
+        if self.phase == Phase::ChooseSides {
+            if self.sides_chosen.get(player).copied().unwrap_or(true) {
                 return None;
             }
-            let parsers: Vec<Box<dyn Parser<T = Command>>> = vec![Box::new(Map::new(
+            let parsers: CommandParsers = vec![Box::new(Map::new(
                 Chain2::new(
                     Doc::name_desc(
-                        "take",
-                        "take a card from the discard pile for free, eg. take 1",
-                        Token::new("take"),
+                        "side",
+                        "choose which side of your wonder board to play, eg. side b",
+                        Token::new("side"),
                     ),
-                    AfterSpace::new(Map::new(Int::positive(), |n: i32| (n - 1) as usize)),
+                    AfterSpace::new(Doc::name("side", Enum::exact(vec![Side::A, Side::B]))),
                 ),
-                |(_, card): (String, usize)| Command::Take { card },
+                |(_, side): (String, Side)| Command::Side { side },
             ))];
             return Some(Box::new(OneOf::new(parsers)));
         }
 
-        if let Some(crate::Action::Build { chosen: false, .. }) = &self.actions[player] {
-            let parsers: Vec<Box<dyn Parser<T = Command>>> = vec![Box::new(Map::new(
+        let mut parsers = self.seat_parsers(player);
+        if let Some(city) = city {
+            let city_parsers = self.seat_parsers(city);
+            if !city_parsers.is_empty() {
+                parsers.push(city_parser(city_parsers));
+            }
+        }
+
+        if parsers.is_empty() {
+            None
+        } else {
+            Some(Box::new(OneOf::new(parsers)))
+        }
+    }
+
+    /// The commands for choosing the action of a seat, which for the Free
+    /// City are given by the player running it.
+    fn seat_parsers(&self, seat: usize) -> CommandParsers {
+        if let Some(crate::Action::Build { chosen: false, .. }) = &self.actions[seat] {
+            return vec![Box::new(Map::new(
                 Chain2::new(
                     Doc::name_desc(
                         "deal",
@@ -52,50 +146,76 @@ impl Game {
                 ),
                 |(_, deal): (String, usize)| Command::Deal { deal },
             ))];
-            return Some(Box::new(OneOf::new(parsers)));
         }
 
-        if self.actions[player].is_some() || self.hands[player].is_empty() {
-            return None;
+        if self.actions[seat].is_some() || self.hands[seat].is_empty() {
+            return vec![];
         }
 
-        let hand_len = self.hands[player].len() as i32;
-        let mut parsers: Vec<Box<dyn Parser<T = Command>>> = vec![];
+        let hand_len = self.hands[seat].len();
+        let mut parsers: CommandParsers = vec![];
 
-        let any_buildable = (0..self.hands[player].len()).any(|i| self.can_build_card(player, i).0);
-        if any_buildable {
+        if self.phase == Phase::DraftLeaders {
             parsers.push(Box::new(Map::new(
                 Chain2::new(
                     Doc::name_desc(
-                        "build",
-                        "build a card from your hand, eg. build 1",
-                        Token::new("build"),
+                        "keep",
+                        "keep a leader and pass the rest on, eg. keep 2",
+                        Token::new("keep"),
                     ),
-                    AfterSpace::new(Map::new(Int::bounded(1, hand_len), |n: i32| {
-                        (n - 1) as usize
-                    })),
+                    AfterSpace::new(card_arg(hand_len)),
                 ),
-                |(_, card): (String, usize)| Command::Build { card },
+                |(_, card): (String, usize)| Command::Keep { card },
             )));
+            return parsers;
         }
 
-        if self.has_free_build(player) {
-            parsers.push(Box::new(Map::new(
-                Chain2::new(
-                    Doc::name_desc(
-                        "free",
-                        "build a card for free using a wonder ability, eg. free 2",
-                        Token::new("free"),
+        let any_buildable = (0..hand_len).any(|i| self.can_build_card(seat, i).0);
+        if self.phase == Phase::Recruit {
+            if any_buildable {
+                parsers.push(Box::new(Map::new(
+                    Chain2::new(
+                        Doc::name_desc(
+                            "recruit",
+                            "recruit a leader, paying their cost in coins, eg. recruit 1",
+                            Token::new("recruit"),
+                        ),
+                        AfterSpace::new(card_arg(hand_len)),
                     ),
-                    AfterSpace::new(Map::new(Int::bounded(1, hand_len), |n: i32| {
-                        (n - 1) as usize
-                    })),
-                ),
-                |(_, card): (String, usize)| Command::Free { card },
-            )));
+                    |(_, card): (String, usize)| Command::Recruit { card },
+                )));
+            }
+        } else {
+            if any_buildable {
+                parsers.push(Box::new(Map::new(
+                    Chain2::new(
+                        Doc::name_desc(
+                            "build",
+                            "build a card from your hand, eg. build 1",
+                            Token::new("build"),
+                        ),
+                        AfterSpace::new(card_arg(hand_len)),
+                    ),
+                    |(_, card): (String, usize)| Command::Build { card },
+                )));
+            }
+
+            if self.has_free_build(seat) {
+                parsers.push(Box::new(Map::new(
+                    Chain2::new(
+                        Doc::name_desc(
+                            "free",
+                            "build a card for free using a wonder ability, eg. free 2",
+                            Token::new("free"),
+                        ),
+                        AfterSpace::new(card_arg(hand_len)),
+                    ),
+                    |(_, card): (String, usize)| Command::Free { card },
+                )));
+            }
         }
 
-        if self.can_build_wonder(player) {
+        if self.can_build_wonder(seat) {
             parsers.push(Box::new(Map::new(
                 Chain2::new(
                     Doc::name_desc(
@@ -103,9 +223,7 @@ impl Game {
                         "build a wonder stage using a card from your hand, eg. wonder 3",
                         Token::new("wonder"),
                     ),
-                    AfterSpace::new(Map::new(Int::bounded(1, hand_len), |n: i32| {
-                        (n - 1) as usize
-                    })),
+                    AfterSpace::new(card_arg(hand_len)),
                 ),
                 |(_, card): (String, usize)| Command::Wonder { card },
             )));
@@ -118,13 +236,11 @@ impl Game {
                     "discard a card from your hand for 3 coins, eg. discard 4",
                     Token::new("discard"),
                 ),
-                AfterSpace::new(Map::new(Int::bounded(1, hand_len), |n: i32| {
-                    (n - 1) as usize
-                })),
+                AfterSpace::new(card_arg(hand_len)),
             ),
             |(_, card): (String, usize)| Command::Discard { card },
         )));
 
-        Some(Box::new(OneOf::new(parsers)))
+        parsers
     }
 }
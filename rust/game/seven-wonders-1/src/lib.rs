@@ -8,19 +8,33 @@ pub use card::*;
 pub use command::Command;
 
 use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 
 use brdgme_game::command::Spec as CommandSpec;
 use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
 use brdgme_game::rng::GameRng;
-use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
+use brdgme_game::{CommandResponse, GameOption, GameOptions, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
-pub const MIN_PLAYERS: usize = 3;
+pub const MIN_PLAYERS: usize = 2;
 pub const MAX_PLAYERS: usize = 7;
+/// Two players share the table with the Free City, so there are always at
+/// least three seats.
+const MIN_SEATS: usize = 3;
+const STARTING_COINS: i32 = 3;
+const LEADERS_STARTING_COINS: i32 = 6;
+const LEADERS_DEALT: usize = 4;
+
+pub const SIDES_OPTION: &str = "sides";
+pub const SIDES_RANDOM: &str = "random";
+pub const SIDES_A: &str = "a";
+pub const SIDES_B: &str = "b";
+pub const SIDES_CHOOSE: &str = "choose";
+pub const LEADERS_OPTION: &str = "leaders";
 const TAVERN_COINS: i32 = 5;
 const DISCARD_COINS: i32 = 3;
 const BASE_TRADE_COST: i32 = 2;
@@ -47,6 +61,39 @@ pub enum Action {
     Discard {
         card: usize,
     },
+    /// Keep a leader from the hand being drafted.
+    Keep {
+        card: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    A,
+    B,
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Side::A => write!(f, "A"),
+            Side::B => write!(f, "B"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// Players pick which side of their wonder board to play.
+    ChooseSides,
+    /// Players keep one leader from each hand of leaders passed to them.
+    DraftLeaders,
+    /// At the start of each age players recruit a leader, build a wonder
+    /// stage with one, or discard one for coins.
+    Recruit,
+    /// Players draft the age's cards.
+    #[default]
+    Age,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -68,13 +115,33 @@ pub struct Game {
     pub victory_tokens: Vec<i32>,
     pub defeat_tokens: Vec<i32>,
     pub cities: Vec<City>,
+    #[serde(default)]
+    pub phase: Phase,
+    /// Whether each player has picked their wonder side, while
+    /// `Phase::ChooseSides`.
+    #[serde(default)]
+    pub sides_chosen: Vec<bool>,
+    /// In a two-player game the last seat is the Free City, run by the
+    /// players in turn.
+    #[serde(default)]
+    pub free_city: bool,
+    #[serde(default)]
+    pub free_city_controller: usize,
+    #[serde(default)]
+    pub with_leaders: bool,
+    /// Leaders each seat holds but hasn't used yet.
+    #[serde(default)]
+    pub leaders: Vec<Vec<Card>>,
+    /// Military victories per seat.
+    #[serde(default)]
+    pub victories: Vec<i32>,
     #[serde(default = "GameRng::from_entropy")]
     pub rng: GameRng,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PubState {
-    /// Number of players (3-7).
+    /// Number of seats (3-7), including the Free City in a two-player game.
     pub players: usize,
     /// Current age (1, 2, or 3).
     pub round: u8,
@@ -98,6 +165,16 @@ pub struct PubState {
     pub actions_chosen: Vec<bool>,
     /// If set, this player must resolve a DrawDiscard effect (take a card from the discard pile).
     pub to_resolve_player: Option<usize>,
+    /// What players are currently doing.
+    pub phase: Phase,
+    /// The Free City's seat in a two-player game.
+    pub free_city: Option<usize>,
+    /// The player choosing the Free City's card this turn.
+    pub free_city_controller: Option<usize>,
+    /// Number of unused leaders each seat holds.
+    pub leader_counts: Vec<usize>,
+    /// Military victories per seat.
+    pub victories: Vec<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -108,10 +185,43 @@ pub struct PlayerState {
     pub player: usize,
     /// Cards in this player's current hand.
     pub hand: Vec<Card>,
+    /// Leaders this player holds but hasn't used yet, outside the recruit
+    /// phase.
+    pub leaders: Vec<Card>,
+    /// The Free City's hand when this player chooses its card this turn.
+    pub free_city_hand: Vec<Card>,
+}
+
+/// The board a city is a side of, eg. "Rhodes" for "Rhodes B".
+fn board_name(city: &City) -> &str {
+    city.name
+        .strip_suffix(" A")
+        .or_else(|| city.name.strip_suffix(" B"))
+        .unwrap_or(&city.name)
+}
+
+/// Picks a side from the cities of one board, falling back to the first
+/// if the board doesn't have it.
+fn board_side(board: &[City], side: Side) -> City {
+    let suffix = format!(" {}", side);
+    board
+        .iter()
+        .find(|c| c.name.ends_with(&suffix))
+        .unwrap_or(&board[0])
+        .clone()
 }
 
 impl Game {
     pub fn start_game(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError> {
+        Self::start_game_with_options(players, seed, &GameOptions::new())
+    }
+
+    pub fn start_game_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let options = options.resolve(&<Self as Gamer>::options())?;
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
             return Err(GameError::PlayerCount {
                 min: MIN_PLAYERS,
@@ -119,66 +229,225 @@ impl Game {
                 given: players,
             });
         }
+        let free_city = players < MIN_SEATS;
+        let seats = players.max(MIN_SEATS);
+        let with_leaders = options.is_on(LEADERS_OPTION);
+        let sides = options.get(SIDES_OPTION).unwrap_or(SIDES_RANDOM);
 
         let mut rng = GameRng::seed_from_u64(seed);
 
         let mut by_board: BTreeMap<String, Vec<City>> = BTreeMap::new();
         for c in cities() {
-            let board = c
-                .name
-                .strip_suffix(" A")
-                .or_else(|| c.name.strip_suffix(" B"))
-                .unwrap_or(&c.name)
-                .to_string();
-            by_board.entry(board).or_default().push(c);
+            by_board
+                .entry(board_name(&c).to_string())
+                .or_default()
+                .push(c);
         }
         let mut boards: Vec<Vec<City>> = by_board.into_values().collect();
         boards.shuffle(&mut rng);
-        if boards.len() < players {
+        if boards.len() < seats {
             return Err(GameError::internal(format!(
-                "seven-wonders-1: city data yielded {} boards, fewer than {} players",
+                "seven-wonders-1: city data yielded {} boards, fewer than {} seats",
                 boards.len(),
-                players
+                seats
             )));
         }
-        let assigned_cities: Vec<City> = boards[..players]
+        let assigned_cities: Vec<City> = boards[..seats]
             .iter()
-            .map(|sides| sides[rng.random_range(0..sides.len())].clone())
+            .enumerate()
+            .map(|(seat, board)| match sides {
+                SIDES_A => board_side(board, Side::A),
+                SIDES_B => board_side(board, Side::B),
+                SIDES_CHOOSE if seat < players => board_side(board, Side::A),
+                _ => board[rng.random_range(0..board.len())].clone(),
+            })
             .collect();
 
-        let mut logs = vec![];
-        for (p, city) in assigned_cities.iter().enumerate() {
-            logs.push(Log::public(vec![
-                N::Player(p),
-                N::text(format!(" was assigned {}", city.name)),
-            ]));
-        }
-
+        let starting_coins = if with_leaders {
+            LEADERS_STARTING_COINS
+        } else {
+            STARTING_COINS
+        };
         let mut g = Game {
-            players,
+            players: seats,
             round: 0,
             finished: false,
-            hands: vec![],
+            hands: vec![vec![]; seats],
             discard: vec![],
-            actions: vec![None; players],
+            actions: vec![None; seats],
             to_resolve: vec![],
-            cards: vec![vec![]; players],
-            coins: vec![3; players],
-            victory_tokens: vec![0; players],
-            defeat_tokens: vec![0; players],
+            cards: vec![vec![]; seats],
+            coins: vec![starting_coins; seats],
+            victory_tokens: vec![0; seats],
+            defeat_tokens: vec![0; seats],
             cities: assigned_cities,
+            phase: Phase::Age,
+            sides_chosen: vec![],
+            free_city,
+            free_city_controller: 0,
+            with_leaders,
+            leaders: vec![vec![]; seats],
+            victories: vec![0; seats],
             rng,
         };
 
-        let round_logs = g.start_round(1);
-        logs.extend(round_logs);
+        let mut logs = vec![];
+        let choose = sides == SIDES_CHOOSE;
+        for (p, city) in g.cities.iter().enumerate() {
+            let assigned = if choose && p < players {
+                format!(" was assigned the {} board", board_name(city))
+            } else {
+                format!(" was assigned {}", city.name)
+            };
+            logs.push(Log::public(vec![g.seat_node(p), N::text(assigned)]));
+        }
+
+        if choose {
+            g.phase = Phase::ChooseSides;
+            g.sides_chosen = vec![false; players];
+            logs.push(Log::public(vec![N::text(
+                "Each player chooses which side of their wonder board to play",
+            )]));
+        } else {
+            logs.extend(g.end_setup());
+        }
 
         Ok((g, logs))
     }
 
+    /// The number of players, leaving out the Free City.
+    pub fn player_total(&self) -> usize {
+        if self.free_city {
+            self.players - 1
+        } else {
+            self.players
+        }
+    }
+
+    pub fn free_city_seat(&self) -> Option<usize> {
+        self.free_city.then(|| self.players - 1)
+    }
+
+    /// The player who acts for a seat, which is the controller of the Free
+    /// City for its seat.
+    fn seat_owner(&self, seat: usize) -> usize {
+        if Some(seat) == self.free_city_seat() {
+            self.free_city_controller
+        } else {
+            seat
+        }
+    }
+
+    fn seat_node(&self, seat: usize) -> N {
+        if Some(seat) == self.free_city_seat() {
+            N::Bold(vec![N::text("Free City")])
+        } else {
+            N::Player(seat)
+        }
+    }
+
+    /// Moves on from the side choice, to the leader draft if playing with
+    /// leaders or straight into the first age.
+    fn end_setup(&mut self) -> Vec<Log> {
+        self.sides_chosen = vec![];
+        if !self.with_leaders {
+            return self.start_round(1);
+        }
+        let mut deck = leaders();
+        deck.shuffle(&mut self.rng);
+        let humans = self.player_total();
+        self.hands = (0..self.players)
+            .map(|p| {
+                if p < humans {
+                    deck[p * LEADERS_DEALT..(p + 1) * LEADERS_DEALT].to_vec()
+                } else {
+                    vec![]
+                }
+            })
+            .collect();
+        self.actions = vec![None; self.players];
+        self.phase = Phase::DraftLeaders;
+        vec![Log::public(vec![N::text(
+            "Players draft leaders, keeping one from each hand passed to them",
+        )])]
+    }
+
+    fn choose_side(&mut self, player: usize, side: Side) -> Result<Vec<Log>, GameError> {
+        if self.sides_chosen.get(player).copied().unwrap_or(true) {
+            return Err(GameError::invalid_input("you have already chosen a side"));
+        }
+        let board = board_name(&self.cities[player]).to_string();
+        let sides: Vec<City> = cities()
+            .into_iter()
+            .filter(|c| board_name(c) == board)
+            .collect();
+        self.cities[player] = board_side(&sides, side);
+        self.sides_chosen[player] = true;
+        let mut logs = vec![Log::public(vec![
+            N::Player(player),
+            N::text(format!(" will play {}", self.cities[player].name)),
+        ])];
+        if self.sides_chosen.iter().all(|&c| c) {
+            logs.extend(self.end_setup());
+        }
+        Ok(logs)
+    }
+
+    fn choose_keep(&mut self, player: usize, card_idx: usize) -> Result<Vec<Log>, GameError> {
+        if card_idx >= self.hands[player].len() {
+            return Err(GameError::invalid_input("card index out of range"));
+        }
+        self.actions[player] = Some(Action::Keep { card: card_idx });
+        Ok(self.check_hand_complete())
+    }
+
+    fn end_draft_pick(&mut self) -> Vec<Log> {
+        if self.hands.iter().all(|h| h.is_empty()) {
+            return self.start_round(1);
+        }
+        // The Free City doesn't draft, so leaders only pass between players.
+        let humans = self.player_total();
+        self.hands[..humans].rotate_left(1);
+        vec![]
+    }
+
+    fn end_recruit(&mut self) -> Vec<Log> {
+        for (held, hand) in self.leaders.iter_mut().zip(self.hands.iter_mut()) {
+            held.append(hand);
+        }
+        self.deal_age();
+        vec![]
+    }
+
     fn start_round(&mut self, round: u8) -> Vec<Log> {
         self.round = round;
-        let mut deck = match round {
+
+        for p in 0..self.players {
+            for c in &mut self.cards[p] {
+                if let CardEffect::FreeBuild { has_built } = &mut c.effect {
+                    *has_built = false;
+                }
+            }
+        }
+
+        let mut logs = vec![Log::public(vec![N::text(format!("Age {} begins", round))])];
+        if self.with_leaders {
+            self.phase = Phase::Recruit;
+            self.hands = self.leaders.iter_mut().map(std::mem::take).collect();
+            self.actions = vec![None; self.players];
+            self.to_resolve = vec![];
+            logs.push(Log::public(vec![N::text(
+                "Each player recruits, builds a wonder stage with or discards a leader",
+            )]));
+        } else {
+            self.deal_age();
+        }
+        logs
+    }
+
+    fn deal_age(&mut self) {
+        self.phase = Phase::Age;
+        let mut deck = match self.round {
             1 => deck_age1(self.players),
             2 => deck_age2(self.players),
             _ => deck_age3(self.players, &mut self.rng),
@@ -192,19 +461,14 @@ impl Game {
 
         self.actions = vec![None; self.players];
         self.to_resolve = vec![];
-
-        for p in 0..self.players {
-            for c in &mut self.cards[p] {
-                if let CardEffect::FreeBuild { has_built } = &mut c.effect {
-                    *has_built = false;
-                }
-            }
-        }
-
-        vec![Log::public(vec![N::text(format!("Age {} begins", round))])]
     }
 
     fn end_hand(&mut self) -> Vec<Log> {
+        match self.phase {
+            Phase::DraftLeaders => return self.end_draft_pick(),
+            Phase::Recruit => return self.end_recruit(),
+            Phase::ChooseSides | Phase::Age => {}
+        }
         let max_hand = self.hands.iter().map(|h| h.len()).max().unwrap_or(0);
 
         if max_hand == 0 {
@@ -218,7 +482,7 @@ impl Game {
                     let card = self.hands[p].pop().unwrap();
                     self.discard.push(card);
                     logs.push(Log::public(vec![
-                        N::Player(p),
+                        self.seat_node(p),
                         N::text(" discarded their last card"),
                     ]));
                 }
@@ -318,11 +582,22 @@ impl Game {
                         let dl = self.execute_discard(p, *card);
                         logs.extend(dl);
                     }
+                    Action::Keep { card } => {
+                        let card = self.hands[p].remove(*card);
+                        logs.push(Log::private(
+                            vec![N::text(format!("You kept {}", card.name))],
+                            vec![p],
+                        ));
+                        self.leaders[p].push(card);
+                    }
                 }
             }
         }
 
         self.actions = vec![None; self.players];
+        if self.phase == Phase::Age && self.free_city {
+            self.free_city_controller = (self.free_city_controller + 1) % self.player_total();
+        }
         logs
     }
 
@@ -353,11 +628,11 @@ impl Game {
             }
 
             let hand_card = self.hands[player].remove(card_idx);
-            self.discard.push(hand_card);
+            self.discard_card(hand_card);
             self.cards[player].push(stage_card.clone());
 
             logs.push(Log::public(vec![
-                N::Player(player),
+                self.seat_node(player),
                 N::text(format!(" built wonder stage {}", stage_card.name)),
             ]));
 
@@ -375,15 +650,31 @@ impl Game {
                     }
                 }
             } else {
+                let chained = card
+                    .free_with
+                    .iter()
+                    .any(|prereq| self.cards[player].iter().any(|c| &c.name == prereq));
                 let deal_map = self.resolve_deal(player, &card.cost, deal, deal_coins);
                 self.pay_cost(player, &card.cost, &deal_map);
+                if chained {
+                    let coins = self.effect_coins(player, |e| match e {
+                        CardEffect::ChainCoins { coins } => *coins,
+                        _ => 0,
+                    });
+                    self.coins[player] += coins;
+                }
             }
 
             self.cards[player].push(card.clone());
 
+            let verb = if card.kind == CardKind::Leader {
+                "recruited"
+            } else {
+                "built"
+            };
             logs.push(Log::public(vec![
-                N::Player(player),
-                N::text(format!(" built {}", card.name)),
+                self.seat_node(player),
+                N::text(format!(" {} {}", verb, card.name)),
             ]));
 
             (logs, Some(card))
@@ -392,10 +683,10 @@ impl Game {
 
     fn execute_discard(&mut self, player: usize, card_idx: usize) -> Vec<Log> {
         let card = self.hands[player].remove(card_idx);
-        self.discard.push(card.clone());
+        self.discard_card(card.clone());
         self.coins[player] += DISCARD_COINS;
         vec![Log::public(vec![
-            N::Player(player),
+            self.seat_node(player),
             N::text(format!(
                 " discarded {} for {} coins",
                 card.name, DISCARD_COINS
@@ -403,13 +694,40 @@ impl Game {
         ])]
     }
 
+    /// Leaders used for a wonder stage or discarded leave the game rather
+    /// than going to the discard pile.
+    fn discard_card(&mut self, card: Card) {
+        if card.kind != CardKind::Leader {
+            self.discard.push(card);
+        }
+    }
+
+    /// Sums a coin amount over the effects of a seat's built cards.
+    fn effect_coins(&self, player: usize, coins: impl Fn(&CardEffect) -> i32) -> i32 {
+        self.cards[player].iter().map(|c| coins(&c.effect)).sum()
+    }
+
     fn post_build_hook(&mut self, player: usize, card: &Card) -> Vec<Log> {
         let mut logs = vec![];
+        let kind_coins = self.effect_coins(player, |e| match e {
+            CardEffect::KindCoins { kind, coins } if *kind == card.kind => *coins,
+            _ => 0,
+        });
+        if kind_coins > 0 {
+            self.coins[player] += kind_coins;
+            logs.push(Log::public(vec![
+                self.seat_node(player),
+                N::text(format!(
+                    " gained {} coins for building {}",
+                    kind_coins, card.name
+                )),
+            ]));
+        }
         match &card.effect {
             CardEffect::Tavern => {
                 self.coins[player] += TAVERN_COINS;
                 logs.push(Log::public(vec![
-                    N::Player(player),
+                    self.seat_node(player),
                     N::text(format!(" gained {} coins from Tavern", TAVERN_COINS)),
                 ]));
             }
@@ -432,7 +750,7 @@ impl Game {
                     if earned > 0 {
                         self.coins[player] += earned;
                         logs.push(Log::public(vec![
-                            N::Player(player),
+                            self.seat_node(player),
                             N::text(format!(" gained {} coins from {}", earned, card.name)),
                         ]));
                     }
@@ -571,15 +889,29 @@ impl Game {
             if my_str > their_str {
                 self.victory_tokens[p] += tokens;
                 self.defeat_tokens[right] += 1;
+                if let Some(victories) = self.victories.get_mut(p) {
+                    *victories += 1;
+                }
                 logs.push(Log::public(vec![
-                    N::Player(p),
+                    self.seat_node(p),
                     N::text(" defeated "),
-                    N::Player(right),
+                    self.seat_node(right),
                     N::text(format!(
                         " in military conflict (+{} victory, +1 defeat)",
                         tokens
                     )),
                 ]));
+                let coins = self.effect_coins(p, |e| match e {
+                    CardEffect::MilitaryCoins { coins } => *coins,
+                    _ => 0,
+                });
+                if coins > 0 {
+                    self.coins[p] += coins;
+                    logs.push(Log::public(vec![
+                        self.seat_node(p),
+                        N::text(format!(" gained {} coins for the victory", coins)),
+                    ]));
+                }
             }
         }
 
@@ -731,10 +1063,10 @@ impl Game {
             self.to_resolve.remove(0);
             logs.push(Log::private(
                 vec![
-                    N::Player(player),
+                    self.seat_node(player),
                     N::text(" has no cards they can take from the discard pile"),
                 ],
-                vec![player],
+                vec![self.seat_owner(player)],
             ));
         }
         logs
@@ -760,7 +1092,7 @@ impl Game {
         self.cards[player].push(card.clone());
 
         let mut logs = vec![Log::public(vec![
-            N::Player(player),
+            self.seat_node(player),
             N::text(format!(" took {} from the discard pile", card.name)),
         ])];
 
@@ -779,14 +1111,16 @@ impl Game {
         // F-19: single placings source for status() and the finish epilogue.
         // Coins are read defensively (status()'s form), resolving the old
         // epilogue's direct coins[p] index.
-        let metrics: Vec<Vec<i32>> = (0..self.players)
+        let metrics: Vec<Vec<i32>> = (0..self.player_total())
             .map(|p| vec![self.player_vp(p), self.coins.get(p).copied().unwrap_or(0)])
             .collect();
         gen_placings(&metrics)
     }
 
     fn finish_epilogue(&self, logs: &mut Vec<Log>) {
-        let scores: Vec<(usize, i32)> = (0..self.players).map(|p| (p, self.player_vp(p))).collect();
+        let scores: Vec<(usize, i32)> = (0..self.player_total())
+            .map(|p| (p, self.player_vp(p)))
+            .collect();
         logs.push(placings_log(&self.placings(), Some(&scores)));
     }
 }
@@ -799,6 +1133,35 @@ impl Gamer for Game {
         Game::start_game(players, seed)
     }
 
+    fn options() -> Vec<GameOption> {
+        vec![
+            GameOption::new(
+                SIDES_OPTION,
+                "Wonder sides",
+                "Which side of their wonder board each player plays",
+                &[
+                    (SIDES_RANDOM, "Random"),
+                    (SIDES_A, "All A"),
+                    (SIDES_B, "All B"),
+                    (SIDES_CHOOSE, "Players choose"),
+                ],
+            ),
+            GameOption::toggle(
+                LEADERS_OPTION,
+                "Leaders",
+                "Draft leaders and recruit one at the start of each age",
+            ),
+        ]
+    }
+
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        Game::start_game_with_options(players, seed, options)
+    }
+
     fn pub_state(&self) -> Self::PubState {
         let to_resolve_player = self.to_resolve.first().map(|r| match r {
             Resolver::DrawDiscard { player } => *player,
@@ -820,19 +1183,32 @@ impl Gamer for Game {
                 .iter()
                 .map(|a| match a {
                     Some(Action::Build { chosen, .. }) => *chosen,
-                    Some(Action::Discard { .. }) => true,
+                    Some(Action::Discard { .. } | Action::Keep { .. }) => true,
                     None => false,
                 })
                 .collect(),
             to_resolve_player,
+            phase: self.phase,
+            free_city: self.free_city_seat(),
+            free_city_controller: self.free_city.then_some(self.free_city_controller),
+            leader_counts: self.leaders.iter().map(|l| l.len()).collect(),
+            victories: self.victories.clone(),
         }
     }
 
     fn player_state(&self, player: usize) -> Self::PlayerState {
+        let free_city_hand = match self.free_city_seat() {
+            Some(seat) if self.free_city_controller == player => {
+                self.hands.get(seat).cloned().unwrap_or_default()
+            }
+            _ => vec![],
+        };
         PlayerState {
             public: self.pub_state(),
             player,
             hand: self.hands.get(player).cloned().unwrap_or_default(),
+            leaders: self.leaders.get(player).cloned().unwrap_or_default(),
+            free_city_hand,
         }
     }
 
@@ -854,90 +1230,83 @@ impl Gamer for Game {
             }
         };
         let was_finished = self.is_finished();
-        let (mut logs, can_undo, remaining) = match output {
-            Ok(ParseOutput {
-                remaining,
-                value: Command::Build { card },
-                ..
-            }) => (
-                self.choose_build(player, card, false, false)?,
-                false,
-                remaining,
-            ),
+        let (command, remaining) = match output {
             Ok(ParseOutput {
-                remaining,
-                value: Command::Free { card },
-                ..
-            }) => (
-                self.choose_build(player, card, true, false)?,
-                false,
-                remaining,
-            ),
-            Ok(ParseOutput {
-                remaining,
-                value: Command::Wonder { card },
-                ..
-            }) => (
-                self.choose_build(player, card, false, true)?,
-                false,
-                remaining,
-            ),
-            Ok(ParseOutput {
-                remaining,
-                value: Command::Discard { card },
-                ..
-            }) => (self.choose_discard(player, card)?, false, remaining),
-            Ok(ParseOutput {
-                remaining,
-                value: Command::Deal { deal },
-                ..
-            }) => (self.choose_deal(player, deal)?, false, remaining),
-            Ok(ParseOutput {
-                remaining,
-                value: Command::Take { card },
-                ..
-            }) => (self.take_from_discard(player, card)?, false, remaining),
+                value, remaining, ..
+            }) => (value, remaining),
             Err(e) => return Err(GameError::invalid_input(e.to_string())),
         };
+        let (seat, command) = match command {
+            Command::City { command } => match self.free_city_seat() {
+                Some(seat) => (seat, *command),
+                None => return Err(GameError::invalid_input("there is no Free City")),
+            },
+            command => (player, command),
+        };
+        let mut logs = match command {
+            Command::Build { card } | Command::Recruit { card } => {
+                self.choose_build(seat, card, false, false)?
+            }
+            Command::Free { card } => self.choose_build(seat, card, true, false)?,
+            Command::Wonder { card } => self.choose_build(seat, card, false, true)?,
+            Command::Discard { card } => self.choose_discard(seat, card)?,
+            Command::Deal { deal } => self.choose_deal(seat, deal)?,
+            Command::Take { card } => self.take_from_discard(seat, card)?,
+            Command::Keep { card } => self.choose_keep(seat, card)?,
+            Command::Side { side } => self.choose_side(seat, side)?,
+            Command::City { .. } => {
+                return Err(GameError::invalid_input(
+                    "the Free City can't run another city",
+                ));
+            }
+        };
         if !was_finished && self.is_finished() {
             self.finish_epilogue(&mut logs);
         }
         Ok(CommandResponse {
             logs,
-            can_undo,
+            can_undo: false,
             remaining_input: remaining.to_string(),
         })
     }
 
     fn status(&self) -> Status {
         if self.finished {
-            Status::Finished {
+            return Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
-            }
-        } else if let Some(Resolver::DrawDiscard { player }) = self.to_resolve.first() {
-            Status::Active {
-                whose_turn: vec![*player],
-                eliminated: vec![],
-            }
-        } else {
-            let whose_turn: Vec<usize> = (0..self.players)
-                .filter(|&p| {
-                    if self.hands.get(p).map(|h| h.is_empty()).unwrap_or(true) {
-                        return false;
-                    }
-                    match self.actions.get(p) {
-                        Some(None) => true,
-                        Some(Some(Action::Build { chosen, .. })) => !chosen,
-                        Some(Some(Action::Discard { .. })) => false,
-                        None => false,
-                    }
-                })
-                .collect();
-            Status::Active {
-                whose_turn,
-                eliminated: vec![],
-            }
+                teams: vec![],
+                cooperative: None,
+            };
+        }
+        let mut whose_turn: Vec<usize> =
+            if let Some(Resolver::DrawDiscard { player }) = self.to_resolve.first() {
+                vec![self.seat_owner(*player)]
+            } else if self.phase == Phase::ChooseSides {
+                (0..self.sides_chosen.len())
+                    .filter(|&p| !self.sides_chosen[p])
+                    .collect()
+            } else {
+                (0..self.players)
+                    .filter(|&p| {
+                        if self.hands.get(p).map(|h| h.is_empty()).unwrap_or(true) {
+                            return false;
+                        }
+                        match self.actions.get(p) {
+                            Some(None) => true,
+                            Some(Some(Action::Build { chosen, .. })) => !chosen,
+                            Some(Some(Action::Discard { .. } | Action::Keep { .. })) => false,
+                            None => false,
+                        }
+                    })
+                    .map(|p| self.seat_owner(p))
+                    .collect()
+            };
+        whose_turn.sort_unstable();
+        whose_turn.dedup();
+        Status::Active {
+            whose_turn,
+            eliminated: vec![],
         }
     }
 
@@ -946,7 +1315,7 @@ impl Gamer for Game {
     }
 
     fn player_count(&self) -> usize {
-        self.players
+        self.player_total()
     }
 
     fn player_counts() -> Vec<usize> {
@@ -970,12 +1339,27 @@ impl Gamer for Game {
     }
 
     fn validate(&self) -> Result<(), GameError> {
-        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&self.players) {
+        if !(MIN_SEATS..=MAX_PLAYERS).contains(&self.players) {
             return Err(GameError::internal(format!(
                 "seven-wonders-1: players {} out of range",
                 self.players
             )));
         }
+        if self.free_city && self.players != MIN_SEATS {
+            return Err(GameError::internal(
+                "seven-wonders-1: the Free City only joins two-player games",
+            ));
+        }
+        if self.free_city_controller >= self.player_total() {
+            return Err(GameError::internal(
+                "seven-wonders-1: Free City controller out of range",
+            ));
+        }
+        if self.with_leaders && self.leaders.len() != self.players {
+            return Err(GameError::internal(
+                "seven-wonders-1: leaders length mismatch",
+            ));
+        }
         if self.hands.len() != self.players {
             return Err(GameError::internal(
                 "seven-wonders-1: hands length mismatch",
@@ -2003,8 +2387,9 @@ mod tests {
                     .collect();
                 board_names.sort();
                 board_names.dedup();
+                // Two players share the table with the Free City.
                 assert_eq!(
-                    players,
+                    players.max(MIN_SEATS),
                     board_names.len(),
                     "seed {seed}: duplicate board dealt"
                 );
@@ -2012,6 +2397,267 @@ mod tests {
         }
     }
 
+    fn start_with(players: usize, options: GameOptions) -> Game {
+        Game::start_game_with_options(players, 42, &options)
+            .unwrap()
+            .0
+    }
+
+    /// Plays out a game by trying simple commands for whoever is up.
+    fn play_out(g: &mut Game) {
+        let names: Vec<String> = (0..g.player_total()).map(|p| format!("p{}", p)).collect();
+        let mut moves = 0;
+        while !g.is_finished() {
+            moves += 1;
+            assert!(moves < 2000, "game didn't finish");
+            let player = g.whose_turn()[0];
+            let mut inputs: Vec<String> = [
+                "side b",
+                "keep 1",
+                "recruit 1",
+                "build 1",
+                "wonder 1",
+                "discard 1",
+                "deal 1",
+            ]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+            inputs.extend((1..=g.discard.len()).map(|n| format!("take {}", n)));
+            let city_inputs: Vec<String> = inputs.iter().map(|i| format!("city {}", i)).collect();
+            inputs.extend(city_inputs);
+            assert!(
+                inputs.iter().any(|i| g.command(player, i, &names).is_ok()),
+                "no command worked for player {} in {:?}",
+                player,
+                g.phase
+            );
+        }
+    }
+
+    #[test]
+    fn sides_option_picks_board_sides() {
+        let (default, _) = Game::start_game(3, 42).unwrap();
+        let random = start_with(3, GameOptions::new().with(SIDES_OPTION, SIDES_RANDOM));
+        assert_eq!(default.cities, random.cities);
+        let a = start_with(5, GameOptions::new().with(SIDES_OPTION, SIDES_A));
+        assert!(a.cities.iter().all(|c| c.name.ends_with(" A")));
+        let b = start_with(5, GameOptions::new().with(SIDES_OPTION, SIDES_B));
+        assert!(b.cities.iter().all(|c| c.name.ends_with(" B")));
+        assert_eq!(
+            a.cities.iter().map(board_name).collect::<Vec<_>>(),
+            b.cities.iter().map(board_name).collect::<Vec<_>>(),
+            "the option only changes the side, not the board"
+        );
+    }
+
+    #[test]
+    fn players_choose_sides_before_the_first_age() {
+        let mut g = start_with(3, GameOptions::new().with(SIDES_OPTION, SIDES_CHOOSE));
+        assert_eq!(Phase::ChooseSides, g.phase);
+        assert_eq!(vec![MICK, STEVE, GREG], g.whose_turn());
+        assert!(cmd(&mut g, MICK, "build 1").is_err());
+
+        cmd(&mut g, MICK, "side b").unwrap();
+        assert!(g.cities[MICK].name.ends_with(" B"));
+        assert_eq!(vec![STEVE, GREG], g.whose_turn());
+        assert!(cmd(&mut g, MICK, "side a").is_err());
+
+        cmd(&mut g, STEVE, "side a").unwrap();
+        cmd(&mut g, GREG, "side b").unwrap();
+        assert!(g.cities[STEVE].name.ends_with(" A"));
+        assert_eq!(Phase::Age, g.phase);
+        assert_eq!(1, g.round);
+        assert!(g.hands.iter().all(|h| h.len() == 7));
+    }
+
+    #[test]
+    fn leaders_are_drafted_then_recruited_each_age() {
+        let mut g = start_with(3, GameOptions::new().with(LEADERS_OPTION, "on"));
+        assert_eq!(Phase::DraftLeaders, g.phase);
+        assert_eq!(vec![6; 3], g.coins);
+        assert!(g.hands.iter().all(|h| h.len() == LEADERS_DEALT));
+
+        let passed = g.hands[STEVE].clone();
+        for p in [MICK, STEVE, GREG] {
+            cmd(&mut g, p, "keep 1").unwrap();
+        }
+        assert_eq!(
+            &passed[1..],
+            &g.hands[MICK][..],
+            "leaders pass between picks"
+        );
+        for _ in 1..LEADERS_DEALT {
+            for p in [MICK, STEVE, GREG] {
+                cmd(&mut g, p, "keep 1").unwrap();
+            }
+        }
+        assert_eq!(Phase::Recruit, g.phase);
+        assert_eq!(1, g.round);
+        assert!(g.hands.iter().all(|h| h.len() == LEADERS_DEALT));
+
+        for p in [MICK, STEVE, GREG] {
+            cmd(&mut g, p, "discard 1").unwrap();
+        }
+        assert_eq!(Phase::Age, g.phase);
+        assert!(g.hands.iter().all(|h| h.len() == 7));
+        assert!(g.leaders.iter().all(|l| l.len() == LEADERS_DEALT - 1));
+        assert_eq!(vec![9; 3], g.coins);
+        assert!(g.discard.is_empty(), "discarded leaders leave the game");
+    }
+
+    fn leader_card(name: &str) -> Card {
+        leaders().into_iter().find(|l| l.name == name).unwrap()
+    }
+
+    #[test]
+    fn recruiting_pays_the_leader_cost_in_coins() {
+        let mut g = start_with(3, GameOptions::new().with(LEADERS_OPTION, "on"));
+        g.phase = Phase::Recruit;
+        g.round = 1;
+        g.hands = vec![
+            vec![leader_card("Cleopatra"), leader_card("Pericles")],
+            vec![leader_card("Croesus")],
+            vec![leader_card("Sappho")],
+        ];
+        g.coins = vec![5, 6, 6];
+
+        assert!(cmd(&mut g, MICK, "recruit 2").is_err(), "Pericles costs 6");
+        cmd(&mut g, MICK, "recruit 1").unwrap();
+        cmd(&mut g, STEVE, "recruit 1").unwrap();
+        let logs = cmd(&mut g, GREG, "recruit 1").unwrap().logs;
+        assert!(
+            logs.iter()
+                .any(|l| log_plain_named(l) == "<Mick> recruited Cleopatra")
+        );
+        assert_eq!(1, g.coins[MICK]);
+        assert_eq!(11, g.coins[STEVE], "Croesus brings 6 coins");
+        assert_eq!(5, g.coins[GREG]);
+        assert_eq!(vec![leader_card("Pericles")], g.leaders[MICK]);
+        assert_eq!(Phase::Age, g.phase);
+        assert_eq!(5, g.player_vp(MICK));
+    }
+
+    #[test]
+    fn leader_scoring_effects() {
+        let mut g = new_game();
+        g.coins = vec![0; 3];
+        g.cards[MICK] = vec![
+            db_card("Apothecary"),
+            db_card("Workshop"),
+            db_card("Scriptorium"),
+        ];
+        let science = g.player_vp(MICK);
+        g.cards[MICK].push(leader_card("Aristotle"));
+        assert_eq!(science + 3, g.player_vp(MICK));
+
+        g.cards[STEVE] = vec![db_card("Stockade"), db_card("Altar"), db_card("Apothecary")];
+        let without = g.player_vp(STEVE);
+        g.cards[STEVE].push(leader_card("Justinian"));
+        assert_eq!(
+            without + 3,
+            g.player_vp(STEVE),
+            "a set of red, blue and green"
+        );
+        g.cards[STEVE].push(db_card("Barracks"));
+        assert_eq!(without + 3, g.player_vp(STEVE), "sets need one of each");
+
+        g.coins[GREG] = 7;
+        g.cards[GREG] = vec![leader_card("Midas")];
+        assert_eq!(4, g.player_vp(GREG));
+        g.cards[GREG] = vec![leader_card("Alexander")];
+        g.victories[GREG] = 3;
+        assert_eq!(2 + 3, g.player_vp(GREG));
+    }
+
+    #[test]
+    fn leader_coin_effects() {
+        let mut g = new_game();
+        g.cities[MICK] = giza_a();
+        g.cards[MICK] = vec![leader_card("Hatshepsut"), leader_card("Xenophon")];
+        g.cards[STEVE] = vec![db_card("Lumber Yard")];
+        g.coins[MICK] = 2;
+        g.hands[MICK][0] = db_card("Tavern");
+        cmd(&mut g, MICK, "build 1").unwrap();
+        cmd(&mut g, STEVE, "discard 1").unwrap();
+        cmd(&mut g, GREG, "discard 1").unwrap();
+        assert_eq!(2 + 2 + 5, g.coins[MICK], "Xenophon pays for Tavern");
+
+        let mut g = new_game();
+        g.cards[MICK] = vec![db_card("Stockade"), leader_card("Nero")];
+        g.coins[MICK] = 0;
+        g.military_conflicts();
+        assert_eq!(2, g.coins[MICK]);
+        assert_eq!(1, g.victories[MICK]);
+
+        let mut g = new_game();
+        for p in 0..3 {
+            g.cities[p] = giza_a();
+        }
+        g.cards[MICK] = vec![leader_card("Hatshepsut")];
+        g.cards[STEVE] = vec![db_card("Lumber Yard")];
+        g.coins[MICK] = 2;
+        g.hands[MICK][0] = db_card("Stockade");
+        // Stockade needs wood, bought from Steve with Hatshepsut's rebate.
+        cmd(&mut g, MICK, "build 1").unwrap();
+        cmd(&mut g, STEVE, "discard 1").unwrap();
+        cmd(&mut g, GREG, "discard 1").unwrap();
+        assert_eq!(1, g.coins[MICK]);
+    }
+
+    #[test]
+    fn two_players_share_the_free_city() {
+        let mut g = new_two_player_game();
+        assert_eq!(3, g.players);
+        assert_eq!(2, g.player_count());
+        assert_eq!(Some(2), g.free_city_seat());
+        assert_eq!(vec![MICK, STEVE], g.whose_turn());
+        assert_eq!(7, g.player_state(MICK).free_city_hand.len());
+        assert!(g.player_state(STEVE).free_city_hand.is_empty());
+
+        assert!(cmd(&mut g, STEVE, "city discard 1").is_err());
+        cmd(&mut g, MICK, "discard 1").unwrap();
+        assert_eq!(
+            vec![MICK, STEVE],
+            g.whose_turn(),
+            "Mick still runs the city"
+        );
+        cmd(&mut g, MICK, "city discard 1").unwrap();
+        assert_eq!(vec![STEVE], g.whose_turn());
+        cmd(&mut g, STEVE, "discard 1").unwrap();
+
+        assert_eq!(STEVE, g.free_city_controller);
+        assert_eq!(6, g.coins[2], "the Free City keeps its own coins");
+        assert!(cmd(&mut g, MICK, "city discard 1").is_err());
+        cmd(&mut g, STEVE, "city discard 1").unwrap();
+    }
+
+    fn new_two_player_game() -> Game {
+        Game::start_game(2, 42).unwrap().0
+    }
+
+    #[test]
+    fn every_setup_plays_to_the_end() {
+        for players in MIN_PLAYERS..=MAX_PLAYERS {
+            for sides in [SIDES_RANDOM, SIDES_CHOOSE] {
+                for leaders in ["off", "on"] {
+                    let mut g = start_with(
+                        players,
+                        GameOptions::new()
+                            .with(SIDES_OPTION, sides)
+                            .with(LEADERS_OPTION, leaders),
+                    );
+                    play_out(&mut g);
+                    assert!(g.validate().is_ok());
+                    match g.status() {
+                        Status::Finished { placings, .. } => assert_eq!(players, placings.len()),
+                        s => panic!("expected a finished game, got {:?}", s),
+                    }
+                }
+            }
+        }
+    }
+
     // --- R-25 (F-33): render path must not panic on a short/inconsistent state ---
 
     fn shorten_all(g: &mut Game) {
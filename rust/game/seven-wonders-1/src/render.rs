@@ -2,7 +2,7 @@ use brdgme_color::NamedColor;
 use brdgme_game::Renderer;
 use brdgme_markup::{Align as A, Node as N, Row, table_with_gap};
 
-use crate::{CardEffect, CardKind, PlayerState, PubState};
+use crate::{Card, CardEffect, CardKind, Phase, PlayerState, PubState};
 
 fn kind_color(kind: CardKind) -> NamedColor {
     match kind {
@@ -14,6 +14,7 @@ fn kind_color(kind: CardKind) -> NamedColor {
         CardKind::Military => NamedColor::Red,
         CardKind::Guild => NamedColor::Orange,
         CardKind::Wonder => NamedColor::Grey,
+        CardKind::Leader => NamedColor::Foreground,
     }
 }
 
@@ -44,6 +45,22 @@ fn effect_desc(effect: &CardEffect) -> String {
         CardEffect::DrawDiscard { .. } => "draw discard".to_string(),
         CardEffect::MimicGuild => "mimic guild".to_string(),
         CardEffect::PlayFinalCard => "play final card".to_string(),
+        CardEffect::VictoryVP { vp } => format!("{}vp per victory", vp),
+        CardEffect::ScienceSetVP { vp } => format!("+{}vp per science set", vp),
+        CardEffect::SetVP { kinds, vp } => format!("{}vp per set of {} kinds", vp, kinds.len()),
+        CardEffect::TreasuryVP => "coins score twice".to_string(),
+        CardEffect::KindCoins { kind, coins } => format!("{} coins per {:?} built", coins, kind),
+        CardEffect::ChainCoins { coins } => format!("{} coins per chain build", coins),
+        CardEffect::TradeRebate { coins } => format!("{} coin back when trading", coins),
+        CardEffect::MilitaryCoins { coins } => format!("{} coins per victory", coins),
+    }
+}
+
+fn seat_node(state: &PubState, seat: usize) -> N {
+    if Some(seat) == state.free_city {
+        N::Bold(vec![N::text("Free City")])
+    } else {
+        N::Player(seat)
     }
 }
 
@@ -78,6 +95,10 @@ fn render_player_summary(state: &PubState) -> N {
         (A::Center, vec![N::Bold(vec![N::text("Cards")])]),
         (A::Center, vec![N::Bold(vec![N::text("Hand")])]),
     ]];
+    let show_leaders = state.leader_counts.iter().any(|&c| c > 0);
+    if show_leaders {
+        rows[0].push((A::Center, vec![N::Bold(vec![N::text("Leaders")])]));
+    }
 
     for p in 0..state.players {
         let Some(city) = state.cities.get(p) else {
@@ -94,8 +115,8 @@ fn render_player_summary(state: &PubState) -> N {
         let defeat_tokens = state.defeat_tokens.get(p).copied().unwrap_or(0);
         let hand_size = state.hand_sizes.get(p).copied().unwrap_or(0);
 
-        rows.push(vec![
-            (A::Left, vec![N::Player(p)]),
+        let mut row = vec![
+            (A::Left, vec![seat_node(state, p)]),
             (A::Left, vec![N::text(city.name.clone())]),
             (A::Center, vec![N::text(format!("{}", coins))]),
             (A::Center, vec![N::text(format!("{}", victory_tokens))]),
@@ -106,7 +127,12 @@ fn render_player_summary(state: &PubState) -> N {
             ),
             (A::Center, vec![N::text(format!("{}", tableau_count))]),
             (A::Center, vec![N::text(format!("{}", hand_size))]),
-        ]);
+        ];
+        if show_leaders {
+            let leaders = state.leader_counts.get(p).copied().unwrap_or(0);
+            row.push((A::Center, vec![N::text(format!("{}", leaders))]));
+        }
+        rows.push(row);
     }
     table_with_gap(&rows, 2)
 }
@@ -115,10 +141,15 @@ fn render_pending(state: &PubState) -> Vec<N> {
     if state.finished {
         return vec![N::Bold(vec![N::text("Game over")])];
     }
+    if state.phase == Phase::ChooseSides {
+        return vec![N::Bold(vec![N::text(
+            "Waiting for players to choose their wonder sides",
+        )])];
+    }
     if let Some(p) = state.to_resolve_player {
         return vec![
             N::Bold(vec![N::text("Waiting for ")]),
-            N::Player(p),
+            seat_node(state, p),
             N::Bold(vec![N::text(" to take from discard")]),
         ];
     }
@@ -136,24 +167,60 @@ fn render_pending(state: &PubState) -> Vec<N> {
         if i > 0 {
             nodes.push(N::text(", "));
         }
-        nodes.push(N::Player(p));
+        nodes.push(seat_node(state, p));
     }
     nodes
 }
 
-fn render_game(state: &PubState, viewer: Option<usize>, hand: Option<&[crate::Card]>) -> Vec<N> {
+fn render_hand(title: String, cards: &[Card]) -> Vec<Row> {
+    let mut hand_rows: Vec<Row> = vec![];
+    for (i, card) in cards.iter().enumerate() {
+        hand_rows.push(vec![
+            (
+                A::Right,
+                vec![N::Bold(vec![N::text(format!("{}:", i + 1))])],
+            ),
+            (A::Left, vec![render_card_name(&card.name, card.kind)]),
+            (
+                A::Left,
+                vec![N::text(format!("({})", effect_desc(&card.effect)))],
+            ),
+        ]);
+    }
+    vec![
+        vec![(A::Center, vec![N::Bold(vec![N::text(title)])])],
+        vec![(A::Center, vec![table_with_gap(&hand_rows, 1)])],
+        vec![],
+    ]
+}
+
+fn phase_heading(state: &PubState) -> Vec<N> {
+    match state.phase {
+        Phase::ChooseSides => vec![N::Bold(vec![N::text("Choosing wonder sides")])],
+        Phase::DraftLeaders => vec![N::Bold(vec![N::text("Leader draft")])],
+        Phase::Recruit | Phase::Age => {
+            let mut nodes = vec![
+                N::Bold(vec![N::text("Age ")]),
+                N::text(format!("{}", state.round)),
+            ];
+            if state.phase == Phase::Recruit {
+                nodes.push(N::text(" (recruiting leaders)"));
+            }
+            nodes
+        }
+    }
+}
+
+fn render_game(state: &PubState, viewer: Option<&PlayerState>) -> Vec<N> {
     let mut rows: Vec<Row> = vec![];
 
-    rows.push(vec![(
-        A::Center,
-        vec![
-            N::Bold(vec![N::text("Age ")]),
-            N::text(format!("{}", state.round)),
-            N::text("    "),
-            N::Bold(vec![N::text("Discard: ")]),
-            N::text(format!("{}", state.discard_count)),
-        ],
-    )]);
+    let mut heading = phase_heading(state);
+    heading.extend([
+        N::text("    "),
+        N::Bold(vec![N::text("Discard: ")]),
+        N::text(format!("{}", state.discard_count)),
+    ]);
+    rows.push(vec![(A::Center, heading)]);
     rows.push(vec![]);
 
     let pending = render_pending(state);
@@ -165,35 +232,33 @@ fn render_game(state: &PubState, viewer: Option<usize>, hand: Option<&[crate::Ca
     rows.push(vec![(A::Center, vec![render_player_summary(state)])]);
     rows.push(vec![]);
 
-    if let Some(hand) = hand
-        && !hand.is_empty()
-    {
-        rows.push(vec![(
-            A::Center,
-            vec![N::Bold(vec![N::text(format!(
-                "Your hand ({} cards)",
-                hand.len()
-            ))])],
-        )]);
-        let mut hand_rows: Vec<Row> = vec![];
-        for (i, card) in hand.iter().enumerate() {
-            hand_rows.push(vec![
-                (
-                    A::Right,
-                    vec![N::Bold(vec![N::text(format!("{}:", i + 1))])],
-                ),
-                (A::Left, vec![render_card_name(&card.name, card.kind)]),
-                (
-                    A::Left,
-                    vec![N::text(format!("({})", effect_desc(&card.effect)))],
+    if let Some(ps) = viewer {
+        if !ps.hand.is_empty() {
+            let title = match state.phase {
+                Phase::DraftLeaders => format!("Leaders to draft ({} cards)", ps.hand.len()),
+                Phase::Recruit => format!("Your leaders ({} cards)", ps.hand.len()),
+                _ => format!("Your hand ({} cards)", ps.hand.len()),
+            };
+            rows.extend(render_hand(title, &ps.hand));
+        }
+        if !ps.free_city_hand.is_empty() {
+            rows.extend(render_hand(
+                format!(
+                    "Free City hand ({} cards), choose its card with city",
+                    ps.free_city_hand.len()
                 ),
-            ]);
+                &ps.free_city_hand,
+            ));
+        }
+        if !ps.leaders.is_empty() {
+            rows.extend(render_hand(
+                format!("Leaders you hold ({} cards)", ps.leaders.len()),
+                &ps.leaders,
+            ));
         }
-        rows.push(vec![(A::Center, vec![table_with_gap(&hand_rows, 1)])]);
-        rows.push(vec![]);
     }
 
-    let start = viewer.unwrap_or(0);
+    let start = viewer.map(|ps| ps.player).unwrap_or(0);
     for i in 0..state.players {
         let p = (start + i) % state.players;
         let city_name = state
@@ -204,7 +269,7 @@ fn render_game(state: &PubState, viewer: Option<usize>, hand: Option<&[crate::Ca
         rows.push(vec![(
             A::Center,
             vec![
-                N::Bold(vec![N::Player(p)]),
+                N::Bold(vec![seat_node(state, p)]),
                 N::text(format!(" - {}", city_name)),
             ],
         )]);
@@ -217,12 +282,12 @@ fn render_game(state: &PubState, viewer: Option<usize>, hand: Option<&[crate::Ca
 
 impl Renderer for PubState {
     fn render(&self) -> Vec<N> {
-        render_game(self, None, None)
+        render_game(self, None)
     }
 }
 
 impl Renderer for PlayerState {
     fn render(&self) -> Vec<N> {
-        render_game(&self.public, Some(self.player), Some(&self.hand))
+        render_game(&self.public, Some(self))
     }
 }
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use crate::Game;
 use crate::card::{CardEffect, CardKind, DIR_LEFT, DIR_NEIGHBOURS, Field, all_fields};
 
+const SCIENCE_SET_VP: i32 = 7;
+
 impl Game {
     pub fn science_vp(&self, player: usize) -> i32 {
         let mut field_options: Vec<Vec<Field>> = vec![];
@@ -14,20 +16,32 @@ impl Game {
         if field_options.is_empty() {
             return 0;
         }
+        let set_vp = SCIENCE_SET_VP
+            + self
+                .cards
+                .get(player)
+                .into_iter()
+                .flatten()
+                .map(|c| match c.effect {
+                    CardEffect::ScienceSetVP { vp } => vp,
+                    _ => 0,
+                })
+                .sum::<i32>();
         let mut best = 0;
         let mut counts: HashMap<Field, i32> = HashMap::new();
-        Self::science_permute(&field_options, &mut counts, 0, &mut best);
+        Self::science_permute(&field_options, set_vp, &mut counts, 0, &mut best);
         best
     }
 
     fn science_permute(
         options: &[Vec<Field>],
+        set_vp: i32,
         counts: &mut HashMap<Field, i32>,
         idx: usize,
         best: &mut i32,
     ) {
         if idx == options.len() {
-            let score = Self::score_science(counts);
+            let score = Self::score_science_sets(counts, set_vp);
             if score > *best {
                 *best = score;
             }
@@ -35,12 +49,17 @@ impl Game {
         }
         for &field in &options[idx] {
             *counts.entry(field).or_insert(0) += 1;
-            Self::science_permute(options, counts, idx + 1, best);
+            Self::science_permute(options, set_vp, counts, idx + 1, best);
             *counts.get_mut(&field).unwrap() -= 1;
         }
     }
 
+    #[cfg(test)]
     pub(crate) fn score_science(counts: &HashMap<Field, i32>) -> i32 {
+        Self::score_science_sets(counts, SCIENCE_SET_VP)
+    }
+
+    fn score_science_sets(counts: &HashMap<Field, i32>, set_vp: i32) -> i32 {
         let mut score = 0;
         let mut min_count = i32::MAX;
         for field in all_fields() {
@@ -53,13 +72,14 @@ impl Game {
         if min_count == i32::MAX {
             min_count = 0;
         }
-        score + min_count * 7
+        score + min_count * set_vp
     }
 
     pub fn player_vp(&self, player: usize) -> i32 {
         let mut vp = self.victory_tokens.get(player).copied().unwrap_or(0)
             - self.defeat_tokens.get(player).copied().unwrap_or(0);
-        vp += self.coins.get(player).copied().unwrap_or(0) / 3;
+        let treasury_vp = self.coins.get(player).copied().unwrap_or(0) / 3;
+        vp += treasury_vp;
         vp += self.science_vp(player);
 
         for card in self.cards.get(player).into_iter().flatten() {
@@ -77,6 +97,13 @@ impl Game {
                     vp += self.mimic_guild_vp(player);
                 }
                 CardEffect::DrawDiscard { vp: stage_vp } => vp += stage_vp,
+                CardEffect::VictoryVP { vp: victory_vp } => {
+                    vp += self.victories.get(player).copied().unwrap_or(0) * victory_vp;
+                }
+                CardEffect::SetVP { kinds, vp: set_vp } => {
+                    vp += self.set_count(player, kinds) * set_vp;
+                }
+                CardEffect::TreasuryVP => vp += treasury_vp,
                 _ => {}
             }
         }
@@ -84,6 +111,16 @@ impl Game {
         vp
     }
 
+    /// How many complete sets of one card of each kind the player has built.
+    fn set_count(&self, player: usize, kinds: &[CardKind]) -> i32 {
+        let cards = self.cards.get(player).map(Vec::as_slice).unwrap_or(&[]);
+        kinds
+            .iter()
+            .map(|kind| cards.iter().filter(|c| c.kind == *kind).count() as i32)
+            .min()
+            .unwrap_or(0)
+    }
+
     fn mimic_guild_vp(&self, player: usize) -> i32 {
         let mut best = 0;
         for &dir in DIR_NEIGHBOURS {
@@ -110,6 +110,17 @@ impl Game {
             self.coins[player] -= coins;
             self.coins[neighbor] += coins;
         }
+
+        if deal.values().any(|&coins| coins > 0) {
+            let rebate: i32 = self.cards[player]
+                .iter()
+                .map(|c| match c.effect {
+                    CardEffect::TradeRebate { coins } => coins,
+                    _ => 0,
+                })
+                .sum();
+            self.coins[player] += rebate;
+        }
     }
 
     fn player_goods_options(&self, player: usize) -> Vec<Vec<Cost<Good>>> {
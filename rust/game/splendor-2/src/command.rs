@@ -1,13 +1,15 @@
 //! Ported from `brdgme-go/splendor_1/command.go`, `take_command.go`,
 //! `buy_command.go`, `reserve_command.go`, `discard_command.go`,
-//! `visit_command.go`.
+//! `visit_command.go`. The `cascade`, `place`, `remove` and `pass` commands
+//! belong to the Cities of Splendor modules.
 
 use brdgme_game::command::parser::*;
 
 use crate::Game;
 use crate::card::{GEMS, Resource};
 
-/// Ported from `command.go`'s `ParsedLoc`.
+/// Ported from `command.go`'s `ParsedLoc`. Rows 0-2 are the main board, row
+/// 3 the player's reserve and rows 4-6 the Orient board.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ParsedLoc {
     pub row: usize,
@@ -21,6 +23,10 @@ pub enum Command {
     Reserve(ParsedLoc),
     Take(Vec<Resource>),
     Visit(usize),
+    Cascade(ParsedLoc),
+    Place(ParsedLoc),
+    Remove(ParsedLoc),
+    Pass,
 }
 
 /// A loc choice paired with its positional letter+row name, for use with the
@@ -71,6 +77,18 @@ impl Game {
         if self.can_visit(player) {
             parsers.push(Box::new(self.visit_parser()));
         }
+        if self.can_cascade(player) {
+            parsers.push(Box::new(self.cascade_parser(player)));
+        }
+        if self.can_stronghold(player) {
+            if self.strongholds_left(player) > 0 {
+                parsers.push(Box::new(self.place_parser()));
+            }
+            if self.strongholds.iter().any(|s| s.player != player) {
+                parsers.push(Box::new(self.remove_parser(player)));
+            }
+            parsers.push(Box::new(pass_parser()));
+        }
         if parsers.is_empty() {
             None
         } else {
@@ -81,26 +99,108 @@ impl Game {
     /// Port of `LocParser` (`command.go`): board locations named
     /// `{'A'+col}{row+1}`, then the player's own reserve locations named
     /// `{'A'+col}4`. Computed fresh from current board/reserve state on
-    /// every call - column letters are positional, not stable ids.
+    /// every call - column letters are positional, not stable ids. Buying
+    /// also offers the Orient board as rows 5-7.
     fn loc_parser(&self, player: usize, include_reserve: bool) -> impl Parser<T = ParsedLoc> {
-        let mut values: Vec<LocChoice> = vec![];
+        let mut locs: Vec<ParsedLoc> = vec![];
         for (row, cards) in self.board.iter().enumerate() {
-            for col in 0..cards.len() {
-                values.push(LocChoice {
-                    loc: ParsedLoc { row, col },
-                    name: [b'A' + col as u8, b'1' + row as u8],
-                });
+            if include_reserve {
+                locs.extend((0..cards.len()).map(|col| ParsedLoc { row, col }));
+            } else {
+                locs.extend(
+                    self.reservable_cols(player, row)
+                        .into_iter()
+                        .map(|col| ParsedLoc { row, col }),
+                );
             }
         }
         if include_reserve {
             for col in 0..self.player_boards[player].reserve.len() {
-                values.push(LocChoice {
-                    loc: ParsedLoc { row: 3, col },
-                    name: [b'A' + col as u8, b'4'],
-                });
+                locs.push(ParsedLoc { row: 3, col });
+            }
+            for (level, cards) in self.orient_board.iter().enumerate() {
+                for col in 0..cards.len() {
+                    locs.push(ParsedLoc {
+                        row: 4 + level,
+                        col,
+                    });
+                }
             }
         }
-        Map::new(Enum::exact(values), |c: LocChoice| c.loc)
+        loc_enum(locs)
+    }
+
+    /// Main board cards in the cascade row that can be taken for free.
+    fn cascade_parser(&self, player: usize) -> impl Parser<T = Command> {
+        let row = match self.phase {
+            crate::Phase::Cascade(row) => row,
+            _ => 0,
+        };
+        let locs = self
+            .cascade_cols(player, row)
+            .into_iter()
+            .map(|col| ParsedLoc { row, col })
+            .collect();
+        Map::new(
+            Chain2::new(
+                Doc::name_desc(
+                    "cascade",
+                    "take a card one level lower for free",
+                    Token::new("cascade"),
+                ),
+                AfterSpace::new(Doc::name_desc("card", "the card to take", loc_enum(locs))),
+            ),
+            |(_, loc)| Command::Cascade(loc),
+        )
+    }
+
+    fn place_parser(&self) -> impl Parser<T = Command> {
+        Map::new(
+            Chain2::new(
+                Doc::name_desc("place", "place a stronghold on a card", Token::new("place")),
+                AfterSpace::new(Doc::name_desc(
+                    "card",
+                    "the card to place a stronghold on",
+                    loc_enum(self.board_locs()),
+                )),
+            ),
+            |(_, loc)| Command::Place(loc),
+        )
+    }
+
+    fn remove_parser(&self, player: usize) -> impl Parser<T = Command> {
+        let locs = self
+            .board_locs()
+            .into_iter()
+            .filter(|loc| {
+                self.strongholds
+                    .iter()
+                    .any(|s| s.player != player && s.row == loc.row && s.col == loc.col)
+            })
+            .collect();
+        Map::new(
+            Chain2::new(
+                Doc::name_desc(
+                    "remove",
+                    "remove another player's stronghold from a card",
+                    Token::new("remove"),
+                ),
+                AfterSpace::new(Doc::name_desc(
+                    "card",
+                    "the card to remove a stronghold from",
+                    loc_enum(locs),
+                )),
+            ),
+            |(_, loc)| Command::Remove(loc),
+        )
+    }
+
+    fn board_locs(&self) -> Vec<ParsedLoc> {
+        self.board
+            .iter()
+            .enumerate()
+            .flat_map(|(row, cards)| (0..cards.len()).map(move |col| ParsedLoc { row, col }))
+            .collect()
     }
 
     pub fn buy_parser(&self, player: usize) -> impl Parser<T = Command> {
@@ -155,6 +255,28 @@ impl Game {
     }
 }
 
+fn loc_enum(locs: Vec<ParsedLoc>) -> impl Parser<T = ParsedLoc> {
+    let values: Vec<LocChoice> = locs
+        .into_iter()
+        .map(|loc| LocChoice {
+            loc,
+            name: [b'A' + loc.col as u8, b'1' + loc.row as u8],
+        })
+        .collect();
+    Map::new(Enum::exact(values), |c: LocChoice| c.loc)
+}
+
+pub fn pass_parser() -> impl Parser<T = Command> {
+    Map::new(
+        Doc::name_desc(
+            "pass",
+            "leave the strongholds as they are",
+            Token::new("pass"),
+        ),
+        |_| Command::Pass,
+    )
+}
+
 /// Port of `TokenParser` (`command.go`).
 fn token_parser(include_gold: bool) -> impl Parser<T = Resource> {
     let mut values: Vec<TokenChoice> = GEMS.iter().map(|&r| TokenChoice { resource: r }).collect();
@@ -3,13 +3,20 @@ use crate::card::Resource;
 pub type Cost = brdgme_cost::Cost<Resource>;
 
 pub fn can_afford(a: &Cost, c: &Cost) -> bool {
+    can_afford_with_gold_value(a, c, 1)
+}
+
+/// Like `can_afford`, but each Gold covers `gold_value` missing gems (the
+/// Gold trading post makes it 2).
+pub fn can_afford_with_gold_value(a: &Cost, c: &Cost, gold_value: i32) -> bool {
     let mut short = 0;
     for (&g, &n) in &c.0 {
-        if a.get(&g) < n {
+        if g != Resource::Gold && a.get(&g) < n {
             short += n - a.get(&g);
         }
     }
-    a.get(&Resource::Gold) - c.get(&Resource::Gold) >= short
+    let gold = a.get(&Resource::Gold) - c.get(&Resource::Gold);
+    gold >= 0 && gold * gold_value >= short
 }
 
 #[cfg(test)]
@@ -79,6 +86,14 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_can_afford_with_double_gold() {
+        let tokens = brdgme_cost::Cost(HashMap::from([(Emerald, 1), (Gold, 2)]));
+        let cost = brdgme_cost::Cost(HashMap::from([(Emerald, 3), (Ruby, 2)]));
+        assert!(!can_afford(&tokens, &cost));
+        assert!(can_afford_with_gold_value(&tokens, &cost, 2));
+    }
+
     #[test]
     fn test_game_serde_round_trip() {
         let (game, _) = crate::Game::start(2, 42).unwrap();
@@ -6,17 +6,23 @@ use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
 use brdgme_game::rng::GameRng;
-use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
+use brdgme_game::{CommandResponse, GameOption, GameOptions, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
 
 use crate::card::{Card, GEMS, Noble, Resource, level_1_cards, level_2_cards, level_3_cards};
 use crate::command::{Command, ParsedLoc};
 use crate::cost::Cost;
+use crate::modules::{
+    CITIES_DEALT, CITIES_OPTION, City, Modules, ORIENT_BOARD_SIZE, ORIENT_OPTION, OrientCard,
+    OrientEffect, STRONGHOLDS_OPTION, STRONGHOLDS_PER_PLAYER, Stronghold, TRADING_POSTS,
+    TRADING_POSTS_OPTION, TradingPost,
+};
 use crate::player_board::PlayerBoard;
 
 pub mod card;
 pub mod command;
 pub mod cost;
+pub mod modules;
 pub mod player_board;
 pub mod render;
 
@@ -34,6 +40,12 @@ pub enum Phase {
     Main,
     Visit,
     Discard,
+    /// The Orient: reserve a main board card without taking Gold.
+    OrientReserve,
+    /// The Orient: take a card from this main board row (0-based) for free.
+    Cascade(usize),
+    /// Strongholds: place or remove a stronghold after taking gems.
+    Stronghold,
 }
 
 /// Ported from `game.go`'s `Game`.
@@ -52,6 +64,22 @@ pub struct Game {
     pub end_triggered: bool,
     pub ended: bool,
     pub rng: GameRng,
+    /// Expansion modules chosen at the start of the game.
+    #[serde(default)]
+    pub modules: Modules,
+    /// City tiles in play in place of the nobles. Cities stay out after
+    /// being claimed, so several players can claim the same one.
+    #[serde(default)]
+    pub cities: Vec<City>,
+    /// Orient draw piles per level, empty without The Orient.
+    #[serde(default)]
+    pub orient_decks: Vec<Vec<OrientCard>>,
+    /// Face-up Orient cards per level, empty without The Orient.
+    #[serde(default)]
+    pub orient_board: Vec<Vec<OrientCard>>,
+    /// Strongholds currently on main board cards.
+    #[serde(default)]
+    pub strongholds: Vec<Stronghold>,
 }
 
 /// Per-player public info shown by `render.go`'s Player table plus
@@ -71,6 +99,14 @@ pub struct PubPlayer {
     pub reserve_count: usize,
     /// This player's current prestige score.
     pub prestige: i32,
+    /// Orient cards this player has bought, with their effects.
+    pub orient_cards: Vec<OrientCard>,
+    /// Trading posts this player has unlocked.
+    pub trading_posts: Vec<TradingPost>,
+    /// The city this player has claimed, if any.
+    pub city: Option<City>,
+    /// Strongholds this player has not yet placed on the board.
+    pub strongholds_left: usize,
 }
 
 /// Ported from what `render.go`'s `PubRender` (`pNum == -1`) actually shows:
@@ -95,6 +131,16 @@ pub struct PubState {
     pub phase: Phase,
     /// True once the game has ended.
     pub finished: bool,
+    /// Expansion modules in play.
+    pub modules: Modules,
+    /// City tiles that can be claimed, replacing the nobles with the Cities module.
+    pub cities: Vec<City>,
+    /// Face-up Orient cards indexed by level, empty without The Orient.
+    pub orient_board: Vec<Vec<OrientCard>>,
+    /// Remaining Orient draw pile size per level.
+    pub orient_deck_counts: Vec<usize>,
+    /// Strongholds on main board cards.
+    pub strongholds: Vec<Stronghold>,
 }
 
 /// `{ public: PubState, player: usize, reserve: Vec<Card> }` per the port
@@ -176,13 +222,20 @@ impl Game {
         max_gems(self.players)
     }
 
-    /// Ported from `game.go`'s `CheckEndTriggered`.
+    /// Ported from `game.go`'s `CheckEndTriggered`. With the Cities module a
+    /// claimed city triggers the end instead of 15 prestige.
     fn check_end_triggered(&mut self) -> Vec<Log> {
         if self.end_triggered {
             return vec![];
         }
         for p in 0..self.players {
-            if self.player_boards[p].prestige() >= 15 {
+            let pb = &self.player_boards[p];
+            let triggered = if self.modules.cities {
+                pb.city.is_some()
+            } else {
+                pb.prestige() >= 15
+            };
+            if triggered {
                 self.end_triggered = true;
                 return vec![Log::public(vec![N::Bold(vec![N::text(
                     "The end of the game has been triggered",
@@ -192,13 +245,24 @@ impl Game {
         vec![]
     }
 
-    /// Ported from `game.go`'s `Placings`.
+    /// Ported from `game.go`'s `Placings`. With the Cities module only
+    /// players who claimed a city can win.
     fn placings(&self) -> Vec<usize> {
         gen_placings(
             &(0..self.players)
-                .map(|p| match self.player_boards.get(p) {
-                    Some(pb) => vec![pb.prestige(), pb.cards.len() as i32],
-                    None => vec![0, 0],
+                .map(|p| {
+                    let mut metrics = match self.player_boards.get(p) {
+                        Some(pb) => vec![pb.prestige(), pb.card_count() as i32],
+                        None => vec![0, 0],
+                    };
+                    if self.modules.cities {
+                        let claimed = self
+                            .player_boards
+                            .get(p)
+                            .is_some_and(|pb| pb.city.is_some());
+                        metrics.insert(0, claimed as i32);
+                    }
+                    metrics
                 })
                 .collect::<Vec<Vec<i32>>>(),
         )
@@ -224,18 +288,74 @@ impl Game {
         Ok(vec![])
     }
 
-    /// Ported from `game.go`'s `VisitPhase`.
+    /// Ported from `game.go`'s `VisitPhase`. Trading posts and cities are
+    /// checked first, as they only depend on what was bought this turn.
     fn visit_phase(&mut self) -> Result<Vec<Log>, GameError> {
         self.phase = Phase::Visit;
+        let mut logs = self.unlock_trading_posts();
+        logs.extend(self.claim_city());
         let pb_bonuses = self.player_boards[self.current_player].bonuses();
         let can_visit: Vec<usize> = (0..self.nobles.len())
             .filter(|&i| cost::can_afford(&pb_bonuses, &self.nobles[i].cost))
             .collect();
-        match can_visit.len() {
-            0 => self.next_phase(),
-            1 => self.visit(self.current_player, can_visit[0]),
-            _ => Ok(vec![]),
+        logs.extend(match can_visit.len() {
+            0 => self.next_phase()?,
+            1 => self.visit(self.current_player, can_visit[0])?,
+            _ => vec![],
+        });
+        Ok(logs)
+    }
+
+    /// Unlocks every trading post the current player's bonuses now meet.
+    fn unlock_trading_posts(&mut self) -> Vec<Log> {
+        if !self.modules.trading_posts {
+            return vec![];
         }
+        let p = self.current_player;
+        let bonuses = self.player_boards[p].bonuses();
+        let mut logs = vec![];
+        for tp in TRADING_POSTS {
+            if !self.player_boards[p].trading_posts.contains(&tp)
+                && cost::can_afford(&bonuses, &tp.requirement())
+            {
+                self.player_boards[p].trading_posts.push(tp);
+                logs.push(Log::public(vec![
+                    N::Player(p),
+                    N::text(" unlocked a trading post: "),
+                    N::Bold(vec![N::text(tp.desc())]),
+                ]));
+            }
+        }
+        logs
+    }
+
+    /// Claims the first city the current player now qualifies for.
+    fn claim_city(&mut self) -> Vec<Log> {
+        let p = self.current_player;
+        if !self.modules.cities || self.player_boards[p].city.is_some() {
+            return vec![];
+        }
+        let bonuses = self.player_boards[p].bonuses();
+        let prestige = self.player_boards[p].prestige();
+        let Some(city) = self
+            .cities
+            .iter()
+            .find(|c| c.can_claim(&bonuses, prestige))
+            .cloned()
+        else {
+            return vec![];
+        };
+        let log = Log::public(vec![
+            N::Player(p),
+            N::text(" claimed the "),
+            N::Bold(vec![N::Fg(
+                resource_color(Resource::Prestige).into(),
+                vec![N::text(city.prestige.to_string())],
+            )]),
+            N::text(" prestige city"),
+        ]);
+        self.player_boards[p].city = Some(city);
+        vec![log]
     }
 
     /// Ported from `game.go`'s `NextPlayer`.
@@ -253,7 +373,9 @@ impl Game {
     /// Ported from `game.go`'s `NextPhase`.
     fn next_phase(&mut self) -> Result<Vec<Log>, GameError> {
         match self.phase {
-            Phase::Main => self.visit_phase(),
+            Phase::Main | Phase::OrientReserve | Phase::Cascade(_) | Phase::Stronghold => {
+                self.visit_phase()
+            }
             Phase::Visit => self.discard_phase(),
             Phase::Discard => Ok(self.next_player()),
         }
@@ -269,27 +391,54 @@ impl Game {
             self.player_boards[player].can_afford(amount),
             "invariant: pay called without a prior can_afford check"
         );
-        let offset = self.player_boards[player].bonuses().sub(amount);
+        let bonuses = self.player_boards[player].bonuses();
+        let mut short = 0;
         for gem in GEMS {
-            let off = offset.get(&gem);
-            if off < 0 {
-                let new_pb_gem = self.player_boards[player].tokens.get(&gem) + off;
-                self.player_boards[player].tokens.set(gem, new_pb_gem);
-                self.tokens.set(gem, self.tokens.get(&gem) - off);
-                if new_pb_gem < 0 {
-                    let gold = self.player_boards[player].tokens.get(&Resource::Gold) + new_pb_gem;
-                    self.player_boards[player].tokens.set(Resource::Gold, gold);
-                    self.tokens.set(gem, self.tokens.get(&gem) + new_pb_gem);
-                    self.tokens.set(
-                        Resource::Gold,
-                        self.tokens.get(&Resource::Gold) - new_pb_gem,
-                    );
-                    self.player_boards[player].tokens.set(gem, 0);
+            let need = (amount.get(&gem) - bonuses.get(&gem)).max(0);
+            let held = self.player_boards[player].tokens.get(&gem);
+            let paid = need.min(held);
+            self.player_boards[player].tokens.set(gem, held - paid);
+            self.tokens.set(gem, self.tokens.get(&gem) + paid);
+            short += need - paid;
+        }
+        // Gold covers whatever is left, each one paying for `gold_value`
+        // gems.
+        let gold_value = self.player_boards[player].gold_value();
+        let gold = (short + gold_value - 1) / gold_value;
+        let pb_gold = self.player_boards[player].tokens.get(&Resource::Gold) - gold;
+        self.player_boards[player]
+            .tokens
+            .set(Resource::Gold, pb_gold);
+        self.tokens
+            .set(Resource::Gold, self.tokens.get(&Resource::Gold) + gold);
+    }
+
+    /// Refills a main board slot from its deck, or removes the slot once the
+    /// deck is empty. Strongholds on the old card go back to their owners.
+    fn refill_slot(&mut self, row: usize, col: usize) {
+        self.strongholds.retain(|s| s.row != row || s.col != col);
+        if !self.decks[row].is_empty() {
+            self.board[row][col] = self.decks[row].remove(0);
+        } else {
+            self.board[row].remove(col);
+            for s in &mut self.strongholds {
+                if s.row == row && s.col > col {
+                    s.col -= 1;
                 }
             }
         }
     }
 
+    /// Refills an Orient board slot from its deck, or removes the slot once
+    /// the deck is empty.
+    fn refill_orient_slot(&mut self, level: usize, col: usize) {
+        if !self.orient_decks[level].is_empty() {
+            self.orient_board[level][col] = self.orient_decks[level].remove(0);
+        } else {
+            self.orient_board[level].remove(col);
+        }
+    }
+
     /// Ported from `take_command.go`'s `CanTake`.
     pub fn can_take(&self, player: usize) -> bool {
         self.current_player == player && self.phase == Phase::Main
@@ -323,6 +472,32 @@ impl Game {
                     N::Bold(vec![N::text("2 "), resource_node(tokens[0])]),
                 ]));
             }
+            3 if self.takes_pair_and_one(player, tokens) => {
+                let (pair, other) = if tokens[0] == tokens[1] {
+                    (tokens[0], tokens[2])
+                } else if tokens[0] == tokens[2] {
+                    (tokens[0], tokens[1])
+                } else {
+                    (tokens[1], tokens[0])
+                };
+                if self.tokens.get(&pair) < 4 {
+                    return Err(GameError::invalid_input(
+                        "can only take two when there are four or more remaining",
+                    ));
+                }
+                if self.tokens.get(&other) == 0 {
+                    return Err(GameError::invalid_input(
+                        "there aren't enough tokens remaining to take that",
+                    ));
+                }
+                logs.push(Log::public(vec![
+                    N::Player(player),
+                    N::text(" took "),
+                    N::Bold(vec![N::text("2 "), resource_node(pair)]),
+                    N::text(" and "),
+                    bold_resource_node(other),
+                ]));
+            }
             3 => {
                 for i in 0..3 {
                     if tokens[i] == tokens[(i + 1) % 3] {
@@ -351,10 +526,24 @@ impl Game {
         let amount = Cost::from_keys(tokens.iter().copied());
         self.player_boards[player].tokens = self.player_boards[player].tokens.add(&amount);
         self.tokens = self.tokens.sub(&amount);
-        logs.extend(self.next_phase()?);
+        if self.modules.strongholds && self.can_use_strongholds(player) {
+            self.phase = Phase::Stronghold;
+        } else {
+            logs.extend(self.next_phase()?);
+        }
         Ok(logs)
     }
 
+    /// Whether a three token take is two of one gem and one of another, which
+    /// the Take trading post allows.
+    fn takes_pair_and_one(&self, player: usize, tokens: &[Resource]) -> bool {
+        let pairs = (0..3).filter(|&i| tokens[i] == tokens[(i + 1) % 3]).count();
+        pairs == 1
+            && self.player_boards[player]
+                .trading_posts
+                .contains(&TradingPost::Take)
+    }
+
     /// Ported from `buy_command.go`'s `CanBuy`.
     pub fn can_buy(&self, player: usize) -> bool {
         self.current_player == player && self.phase == Phase::Main
@@ -368,28 +557,31 @@ impl Game {
         }
         let ParsedLoc { row, col } = loc;
         let mut logs = vec![];
-        match row {
+        let mut effect = None;
+        let bought = match row {
             0..=2 => {
                 if col >= self.board[row].len() {
                     return Err(GameError::invalid_input("that is not a valid card"));
                 }
+                if self.locked_by(row, col).is_some_and(|p| p != player) {
+                    return Err(GameError::invalid_input(
+                        "that card is held by another player's strongholds",
+                    ));
+                }
                 let c = self.board[row][col].clone();
                 if !self.player_boards[player].can_afford(&c.cost) {
                     return Err(GameError::invalid_input("you can't afford that card"));
                 }
                 self.pay(player, &c.cost);
                 self.player_boards[player].cards.push(c.clone());
-                if !self.decks[row].is_empty() {
-                    self.board[row][col] = self.decks[row].remove(0);
-                } else {
-                    self.board[row].remove(col);
-                }
+                self.refill_slot(row, col);
                 logs.push(Log::public(vec![
                     N::Player(player),
                     N::text(" bought "),
                     card_node(&c),
                     N::text(" from the board"),
                 ]));
+                c.resource
             }
             3 => {
                 if col >= self.player_boards[player].reserve.len() {
@@ -408,17 +600,67 @@ impl Game {
                     card_node(&c),
                     N::text(" from their reserve"),
                 ]));
+                c.resource
+            }
+            4..=6 => {
+                let level = row - 4;
+                if col >= self.orient_board.get(level).map_or(0, |r| r.len()) {
+                    return Err(GameError::invalid_input("that is not a valid card"));
+                }
+                let oc = self.orient_board[level][col].clone();
+                if !self.player_boards[player].can_afford(&oc.card.cost) {
+                    return Err(GameError::invalid_input("you can't afford that card"));
+                }
+                self.pay(player, &oc.card.cost);
+                self.player_boards[player].orient_cards.push(oc.clone());
+                self.refill_orient_slot(level, col);
+                logs.push(Log::public(vec![
+                    N::Player(player),
+                    N::text(" bought "),
+                    card_node(&oc.card),
+                    N::text(format!(" ({}) from the Orient", oc.effect.abbr())),
+                ]));
+                effect = Some((oc.effect, level));
+                oc.card.resource
             }
             _ => return Err(GameError::invalid_input("that is not a valid row")),
+        };
+        if self.player_boards[player]
+            .trading_posts
+            .contains(&TradingPost::Gem)
+            && self.tokens.get(&bought) > 0
+        {
+            let pb_gem = self.player_boards[player].tokens.get(&bought) + 1;
+            self.player_boards[player].tokens.set(bought, pb_gem);
+            self.tokens.set(bought, self.tokens.get(&bought) - 1);
+            logs.push(Log::public(vec![
+                N::Player(player),
+                N::text(" took "),
+                bold_resource_node(bought),
+                N::text(" from their trading post"),
+            ]));
+        }
+        match effect {
+            Some((OrientEffect::Reserve, _))
+                if self.player_boards[player].reserve.len() < 3
+                    && (0..3).any(|row| !self.reservable_cols(player, row).is_empty()) =>
+            {
+                self.phase = Phase::OrientReserve;
+            }
+            Some((OrientEffect::Cascade, level))
+                if level > 0 && !self.cascade_cols(player, level - 1).is_empty() =>
+            {
+                self.phase = Phase::Cascade(level - 1);
+            }
+            _ => logs.extend(self.next_phase()?),
         }
-        logs.extend(self.next_phase()?);
         Ok(logs)
     }
 
     /// Ported from `reserve_command.go`'s `CanReserve`.
     pub fn can_reserve(&self, player: usize) -> bool {
         self.current_player == player
-            && self.phase == Phase::Main
+            && matches!(self.phase, Phase::Main | Phase::OrientReserve)
             && self.player_boards[player].reserve.len() < 3
     }
 
@@ -435,6 +677,11 @@ impl Game {
         if col >= self.board[row].len() {
             return Err(GameError::invalid_input("that is not a valid card"));
         }
+        if !self.reservable_cols(player, row).contains(&col) {
+            return Err(GameError::invalid_input(
+                "you can't reserve a card with another player's stronghold on it",
+            ));
+        }
         let c = self.board[row][col].clone();
         let mut logs = vec![Log::public(vec![
             N::Player(player),
@@ -442,7 +689,8 @@ impl Game {
             card_node(&c),
         ])];
         self.player_boards[player].reserve.push(c);
-        if self.tokens.get(&Resource::Gold) > 0 {
+        // Reserving from an Orient card's effect never gives Gold.
+        if self.phase == Phase::Main && self.tokens.get(&Resource::Gold) > 0 {
             let pb_gold = self.player_boards[player].tokens.get(&Resource::Gold) + 1;
             self.player_boards[player]
                 .tokens
@@ -450,15 +698,179 @@ impl Game {
             self.tokens
                 .set(Resource::Gold, self.tokens.get(&Resource::Gold) - 1);
         }
-        if !self.decks[row].is_empty() {
-            self.board[row][col] = self.decks[row].remove(0);
-        } else {
-            self.board[row].remove(col);
+        self.refill_slot(row, col);
+        logs.extend(self.next_phase()?);
+        Ok(logs)
+    }
+
+    /// Main board columns in `row` the player may reserve: any card without
+    /// another player's stronghold on it.
+    pub fn reservable_cols(&self, player: usize, row: usize) -> Vec<usize> {
+        (0..self.board.get(row).map_or(0, |r| r.len()))
+            .filter(|&col| {
+                !self
+                    .strongholds
+                    .iter()
+                    .any(|s| s.row == row && s.col == col && s.player != player)
+            })
+            .collect()
+    }
+
+    /// Main board columns in `row` the player may take with a cascade: any
+    /// card not held by another player's strongholds.
+    pub fn cascade_cols(&self, player: usize, row: usize) -> Vec<usize> {
+        (0..self.board.get(row).map_or(0, |r| r.len()))
+            .filter(|&col| !self.locked_by(row, col).is_some_and(|p| p != player))
+            .collect()
+    }
+
+    pub fn can_cascade(&self, player: usize) -> bool {
+        self.current_player == player && matches!(self.phase, Phase::Cascade(_))
+    }
+
+    /// Takes a card one level below a just bought Orient cascade card for
+    /// free.
+    pub fn cascade(&mut self, player: usize, loc: ParsedLoc) -> Result<Vec<Log>, GameError> {
+        self.assert_not_finished()?;
+        let Phase::Cascade(cascade_row) = self.phase else {
+            return Err(GameError::invalid_input("unable to cascade right now"));
+        };
+        if !self.can_cascade(player) {
+            return Err(GameError::invalid_input("unable to cascade right now"));
+        }
+        let ParsedLoc { row, col } = loc;
+        if row != cascade_row {
+            return Err(GameError::invalid_input(format!(
+                "you must take a level {} card",
+                cascade_row + 1
+            )));
+        }
+        if !self.cascade_cols(player, row).contains(&col) {
+            return Err(GameError::invalid_input("that is not a valid card"));
+        }
+        let c = self.board[row][col].clone();
+        self.player_boards[player].cards.push(c.clone());
+        self.refill_slot(row, col);
+        let mut logs = vec![Log::public(vec![
+            N::Player(player),
+            N::text(" took "),
+            card_node(&c),
+            N::text(" for free"),
+        ])];
+        logs.extend(self.next_phase()?);
+        Ok(logs)
+    }
+
+    /// Strongholds the player has not placed on the board.
+    pub fn strongholds_left(&self, player: usize) -> usize {
+        STRONGHOLDS_PER_PLAYER
+            - self
+                .strongholds
+                .iter()
+                .filter(|s| s.player == player)
+                .count()
+    }
+
+    /// The player with all of their strongholds on a card, if any. Only they
+    /// may buy it.
+    pub fn locked_by(&self, row: usize, col: usize) -> Option<usize> {
+        (0..self.players).find(|&p| {
+            self.strongholds
+                .iter()
+                .filter(|s| s.player == p && s.row == row && s.col == col)
+                .count()
+                == STRONGHOLDS_PER_PLAYER
+        })
+    }
+
+    /// Whether the player has anything to do with their strongholds: place
+    /// one of their own, or remove another player's.
+    fn can_use_strongholds(&self, player: usize) -> bool {
+        (self.strongholds_left(player) > 0 && self.board.iter().any(|r| !r.is_empty()))
+            || self.strongholds.iter().any(|s| s.player != player)
+    }
+
+    pub fn can_stronghold(&self, player: usize) -> bool {
+        self.current_player == player && self.phase == Phase::Stronghold
+    }
+
+    /// Places one of the player's strongholds on a main board card.
+    pub fn place_stronghold(
+        &mut self,
+        player: usize,
+        loc: ParsedLoc,
+    ) -> Result<Vec<Log>, GameError> {
+        self.assert_not_finished()?;
+        if !self.can_stronghold(player) {
+            return Err(GameError::invalid_input(
+                "unable to place a stronghold right now",
+            ));
+        }
+        let ParsedLoc { row, col } = loc;
+        if row > 2 || col >= self.board[row].len() {
+            return Err(GameError::invalid_input("that is not a valid card"));
+        }
+        if self.strongholds_left(player) == 0 {
+            return Err(GameError::invalid_input("you have no strongholds left"));
         }
+        self.strongholds.push(Stronghold { player, row, col });
+        let mut logs = vec![Log::public(vec![
+            N::Player(player),
+            N::text(" placed a stronghold on "),
+            card_node(&self.board[row][col]),
+        ])];
         logs.extend(self.next_phase()?);
         Ok(logs)
     }
 
+    /// Removes another player's stronghold from a main board card. When
+    /// several players have one there, the next player in turn order loses
+    /// theirs.
+    pub fn remove_stronghold(
+        &mut self,
+        player: usize,
+        loc: ParsedLoc,
+    ) -> Result<Vec<Log>, GameError> {
+        self.assert_not_finished()?;
+        if !self.can_stronghold(player) {
+            return Err(GameError::invalid_input(
+                "unable to remove a stronghold right now",
+            ));
+        }
+        let ParsedLoc { row, col } = loc;
+        let Some(idx) = (1..self.players)
+            .map(|offset| (player + offset) % self.players)
+            .find_map(|p| {
+                self.strongholds
+                    .iter()
+                    .position(|s| s.player == p && s.row == row && s.col == col)
+            })
+        else {
+            return Err(GameError::invalid_input(
+                "there is no other player's stronghold on that card",
+            ));
+        };
+        let removed = self.strongholds.remove(idx);
+        let mut logs = vec![Log::public(vec![
+            N::Player(player),
+            N::text(" removed a stronghold of "),
+            N::Player(removed.player),
+            N::text(" from "),
+            card_node(&self.board[row][col]),
+        ])];
+        logs.extend(self.next_phase()?);
+        Ok(logs)
+    }
+
+    /// Leaves the strongholds as they are.
+    pub fn pass(&mut self, player: usize) -> Result<Vec<Log>, GameError> {
+        self.assert_not_finished()?;
+        if !self.can_stronghold(player) {
+            return Err(GameError::invalid_input("unable to pass right now"));
+        }
+        self.next_phase()
+    }
+
     /// Ported from `discard_command.go`'s `CanDiscard`.
     pub fn can_discard(&self, player: usize) -> bool {
         self.current_player == player && self.phase == Phase::Discard
@@ -528,6 +940,40 @@ impl Gamer for Game {
     type PlayerState = PlayerState;
 
     fn start(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError> {
+        Self::start_with_options(players, seed, &GameOptions::new())
+    }
+
+    fn options() -> Vec<GameOption> {
+        vec![
+            GameOption::toggle(
+                CITIES_OPTION,
+                "Cities",
+                "City tiles replace the nobles; claiming a city triggers the end of the game",
+            ),
+            GameOption::toggle(
+                TRADING_POSTS_OPTION,
+                "Trading Posts",
+                "Unlock trading posts with card bonuses for special abilities and prestige",
+            ),
+            GameOption::toggle(
+                ORIENT_OPTION,
+                "The Orient",
+                "Extra card rows with double bonus, reserve and cascade effects",
+            ),
+            GameOption::toggle(
+                STRONGHOLDS_OPTION,
+                "Strongholds",
+                "Place strongholds on cards when taking gems to block or claim them",
+            ),
+        ]
+    }
+
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let options = options.resolve(&Self::options())?;
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
             return Err(GameError::PlayerCount {
                 min: MIN_PLAYERS,
@@ -546,9 +992,36 @@ impl Gamer for Game {
             decks.push(rest);
         }
 
-        let mut nobles = card::noble_cards();
-        nobles.shuffle(&mut rng);
-        nobles.truncate(players + 1);
+        let modules = Modules {
+            cities: options.is_on(CITIES_OPTION),
+            trading_posts: options.is_on(TRADING_POSTS_OPTION),
+            orient: options.is_on(ORIENT_OPTION),
+            strongholds: options.is_on(STRONGHOLDS_OPTION),
+        };
+
+        let mut nobles = vec![];
+        let mut cities = vec![];
+        if modules.cities {
+            cities = modules::city_tiles();
+            cities.shuffle(&mut rng);
+            cities.truncate(CITIES_DEALT);
+        } else {
+            nobles = card::noble_cards();
+            nobles.shuffle(&mut rng);
+            nobles.truncate(players + 1);
+        }
+
+        let mut orient_decks = vec![];
+        let mut orient_board = vec![];
+        if modules.orient {
+            for level in 0..3 {
+                let mut cards = modules::orient_cards(level);
+                cards.shuffle(&mut rng);
+                let rest = cards.split_off(ORIENT_BOARD_SIZE);
+                orient_board.push(cards);
+                orient_decks.push(rest);
+            }
+        }
 
         let mut tokens = Cost::new();
         tokens.set(Resource::Gold, MAX_GOLD);
@@ -569,6 +1042,11 @@ impl Gamer for Game {
             end_triggered: false,
             ended: false,
             rng,
+            modules,
+            cities,
+            orient_decks,
+            orient_board,
+            strongholds: vec![],
         };
         Ok((g, vec![]))
     }
@@ -578,6 +1056,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![Default::default(); self.players],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -597,18 +1077,32 @@ impl Gamer for Game {
             player_boards: self
                 .player_boards
                 .iter()
-                .map(|pb| PubPlayer {
+                .enumerate()
+                .map(|(p, pb)| PubPlayer {
                     bonuses: pb.bonuses(),
                     tokens: pb.tokens.clone(),
                     nobles: pb.nobles.clone(),
-                    card_count: pb.cards.len(),
+                    card_count: pb.card_count(),
                     reserve_count: pb.reserve.len(),
                     prestige: pb.prestige(),
+                    orient_cards: pb.orient_cards.clone(),
+                    trading_posts: pb.trading_posts.clone(),
+                    city: pb.city.clone(),
+                    strongholds_left: if self.modules.strongholds {
+                        self.strongholds_left(p)
+                    } else {
+                        0
+                    },
                 })
                 .collect(),
             current_player: self.current_player,
             phase: self.phase,
             finished: self.ended,
+            modules: self.modules,
+            cities: self.cities.clone(),
+            orient_board: self.orient_board.clone(),
+            orient_deck_counts: self.orient_decks.iter().map(|d| d.len()).collect(),
+            strongholds: self.strongholds.clone(),
         }
     }
 
@@ -666,6 +1160,26 @@ impl Gamer for Game {
                 value: Command::Visit(noble),
                 ..
             }) => (self.visit(player, noble)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Cascade(loc),
+                ..
+            }) => (self.cascade(player, loc)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Place(loc),
+                ..
+            }) => (self.place_stronghold(player, loc)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Remove(loc),
+                ..
+            }) => (self.remove_stronghold(player, loc)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Pass,
+                ..
+            }) => (self.pass(player)?, false, remaining),
             Err(e) => return Err(e),
         };
         if !was_finished && self.is_finished() {
@@ -735,6 +1249,19 @@ impl Gamer for Game {
         if self.decks.len() != 3 {
             return Err(GameError::internal("splendor-2: decks length mismatch"));
         }
+        let orient_rows = if self.modules.orient { 3 } else { 0 };
+        if self.orient_board.len() != orient_rows || self.orient_decks.len() != orient_rows {
+            return Err(GameError::internal(
+                "splendor-2: orient board length mismatch",
+            ));
+        }
+        if self
+            .strongholds
+            .iter()
+            .any(|s| s.row > 2 || s.col >= self.board[s.row].len() || s.player >= self.players)
+        {
+            return Err(GameError::internal("splendor-2: stronghold out of range"));
+        }
         if self.current_player >= self.players {
             return Err(GameError::internal(
                 "splendor-2: current_player out of range",
@@ -954,13 +1481,9 @@ mod tests {
     fn test_buy_gold_fallback_arithmetic() {
         // Player has 1 diamond bonus, 0 plain diamond tokens, 2 gold; card
         // costs 3 diamond.
-        // offset = bonuses(1) - cost(3) = -2 for diamond.
-        // pb.tokens[diamond] += -2 => -2, bank.tokens[diamond] -= -2 => +2.
-        // pb.tokens[diamond] < 0, so gold fallback:
-        //   pb.tokens[gold] += pb.tokens[diamond] (-2) => gold 0
-        //   bank.tokens[diamond] += pb.tokens[diamond] (-2) => back to original
-        //   bank.tokens[gold] -= pb.tokens[diamond] (-2) => bank gold +2
-        //   pb.tokens[diamond] = 0
+        // need = cost(3) - bonuses(1) = 2 diamond, none paid in diamond
+        // tokens, so the 2 short are paid in gold: pb gold 0, bank gold +2,
+        // bank diamond unchanged.
         let (mut g, _) = Game::start(2, 1).unwrap();
         g.player_boards[0].cards = vec![card_with_cost(Resource::Diamond, &[])];
         g.player_boards[0].tokens.set(Resource::Gold, 2);
@@ -1582,4 +2105,232 @@ mod tests {
         g.current_player = g.players;
         assert!(matches!(g.validate(), Err(GameError::Internal { .. })));
     }
+
+    fn start_with(players: usize, option: &str) -> Game {
+        Game::start_with_options(players, 1, &GameOptions::new().with(option, "on"))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn modules_are_off_by_default() {
+        let (g, _) = Game::start(2, 1).unwrap();
+        assert_eq!(Modules::default(), g.modules);
+        assert!(g.cities.is_empty());
+        assert!(g.orient_board.is_empty());
+    }
+
+    #[test]
+    fn cities_replace_the_nobles() {
+        let g = start_with(3, CITIES_OPTION);
+        assert!(g.modules.cities);
+        assert!(g.nobles.is_empty());
+        assert_eq!(CITIES_DEALT, g.cities.len());
+    }
+
+    #[test]
+    fn claiming_a_city_triggers_the_end_and_wins() {
+        let mut g = start_with(2, CITIES_OPTION);
+        g.cities = vec![City {
+            prestige: 3,
+            cost: Cost::from_keys([Resource::Ruby]),
+            same: 0,
+        }];
+        // Player 1 has more prestige but no city.
+        let mut rich = card_with_cost(Resource::Onyx, &[]);
+        rich.prestige = 10;
+        g.player_boards[1].cards = vec![rich];
+        let mut ruby = card_with_cost(Resource::Ruby, &[]);
+        ruby.prestige = 3;
+        g.board[0][0] = ruby;
+        let logs = g.buy(0, ParsedLoc { row: 0, col: 0 }).unwrap();
+        assert!(g.player_boards[0].city.is_some());
+        assert!(g.end_triggered);
+        assert!(
+            logs.iter()
+                .any(|l| brdgme_markup::to_string(&l.content).contains("claimed the"))
+        );
+        g.take(1, &[GEMS[0], GEMS[1], GEMS[2]]).unwrap();
+        assert!(g.is_finished());
+        assert_eq!(vec![1, 2], g.placings());
+    }
+
+    #[test]
+    fn trading_posts_unlock_from_bonuses() {
+        let mut g = start_with(2, TRADING_POSTS_OPTION);
+        g.player_boards[0].cards = vec![card_with_cost(Resource::Emerald, &[]); 5];
+        g.take(0, &[GEMS[0], GEMS[1], GEMS[2]]).unwrap();
+        assert_eq!(
+            vec![TradingPost::Prestige],
+            g.player_boards[0].trading_posts
+        );
+        assert_eq!(5, g.player_boards[0].prestige());
+        g.player_boards[0].trading_posts.push(TradingPost::Posts);
+        assert_eq!(7, g.player_boards[0].prestige());
+    }
+
+    #[test]
+    fn gem_trading_post_gives_a_gem_after_buying() {
+        let mut g = start_with(2, TRADING_POSTS_OPTION);
+        g.player_boards[0].trading_posts = vec![TradingPost::Gem];
+        g.board[0][0] = card_with_cost(Resource::Ruby, &[]);
+        g.buy(0, ParsedLoc { row: 0, col: 0 }).unwrap();
+        assert_eq!(1, g.player_boards[0].tokens.get(&Resource::Ruby));
+    }
+
+    #[test]
+    fn take_trading_post_allows_two_and_one() {
+        let mut g = start_with(2, TRADING_POSTS_OPTION);
+        let tokens = [Resource::Ruby, Resource::Ruby, Resource::Onyx];
+        assert!(g.clone().take(0, &tokens).is_err());
+        g.player_boards[0].trading_posts = vec![TradingPost::Take];
+        g.take(0, &tokens).unwrap();
+        assert_eq!(2, g.player_boards[0].tokens.get(&Resource::Ruby));
+        assert_eq!(1, g.player_boards[0].tokens.get(&Resource::Onyx));
+    }
+
+    #[test]
+    fn gold_trading_post_pays_double() {
+        let mut g = start_with(2, TRADING_POSTS_OPTION);
+        g.player_boards[0].trading_posts = vec![TradingPost::Gold];
+        g.player_boards[0].tokens.set(Resource::Gold, 2);
+        g.board[0][0] = card_with_cost(
+            Resource::Ruby,
+            &[(Resource::Diamond, 2), (Resource::Onyx, 1)],
+        );
+        g.buy(0, ParsedLoc { row: 0, col: 0 }).unwrap();
+        assert_eq!(0, g.player_boards[0].tokens.get(&Resource::Gold));
+        assert_eq!(MAX_GOLD + 2, g.tokens.get(&Resource::Gold));
+    }
+
+    fn orient_card(effect: OrientEffect) -> OrientCard {
+        OrientCard {
+            card: card_with_cost(Resource::Emerald, &[]),
+            effect,
+        }
+    }
+
+    #[test]
+    fn orient_deals_two_cards_per_level() {
+        let g = start_with(2, ORIENT_OPTION);
+        for level in 0..3 {
+            assert_eq!(ORIENT_BOARD_SIZE, g.orient_board[level].len());
+            assert_eq!(8, g.orient_decks[level].len());
+        }
+        assert!(g.validate().is_ok());
+    }
+
+    #[test]
+    fn orient_double_counts_twice() {
+        let mut g = start_with(2, ORIENT_OPTION);
+        g.orient_board[0][0] = orient_card(OrientEffect::Double);
+        g.command(0, "buy A5", &players(2)).unwrap();
+        assert_eq!(2, g.player_boards[0].bonuses().get(&Resource::Emerald));
+        assert_eq!(1, g.player_boards[0].card_count());
+        assert_eq!(1, g.current_player);
+    }
+
+    #[test]
+    fn orient_reserve_takes_no_gold() {
+        let mut g = start_with(2, ORIENT_OPTION);
+        g.orient_board[0][0] = orient_card(OrientEffect::Reserve);
+        g.command(0, "buy A5", &players(2)).unwrap();
+        assert_eq!(Phase::OrientReserve, g.phase);
+        g.command(0, "reserve B2", &players(2)).unwrap();
+        assert_eq!(1, g.player_boards[0].reserve.len());
+        assert_eq!(0, g.player_boards[0].tokens.get(&Resource::Gold));
+        assert_eq!(1, g.current_player);
+    }
+
+    #[test]
+    fn orient_cascade_takes_a_lower_card_free() {
+        let mut g = start_with(2, ORIENT_OPTION);
+        g.orient_board[2][1] = orient_card(OrientEffect::Cascade);
+        g.command(0, "buy B7", &players(2)).unwrap();
+        assert_eq!(Phase::Cascade(1), g.phase);
+        assert!(g.command(0, "cascade A1", &players(2)).is_err());
+        let card = g.board[1][2].clone();
+        g.command(0, "cascade C2", &players(2)).unwrap();
+        assert_eq!(vec![card], g.player_boards[0].cards);
+        assert_eq!(1, g.current_player);
+    }
+
+    #[test]
+    fn strongholds_are_placed_after_taking_gems() {
+        let mut g = start_with(2, STRONGHOLDS_OPTION);
+        g.command(0, "take Diamond Sapphire Ruby", &players(2))
+            .unwrap();
+        assert_eq!(Phase::Stronghold, g.phase);
+        g.command(0, "place B1", &players(2)).unwrap();
+        assert_eq!(
+            vec![Stronghold {
+                player: 0,
+                row: 0,
+                col: 1
+            }],
+            g.strongholds
+        );
+        assert_eq!(2, g.strongholds_left(0));
+        // Player 1 can no longer reserve it, and can remove it instead.
+        assert!(g.reserve(1, ParsedLoc { row: 0, col: 1 }).is_err());
+        g.command(1, "take Diamond Sapphire Ruby", &players(2))
+            .unwrap();
+        g.command(1, "remove B1", &players(2)).unwrap();
+        assert!(g.strongholds.is_empty());
+    }
+
+    #[test]
+    fn three_strongholds_hold_a_card() {
+        let mut g = start_with(2, STRONGHOLDS_OPTION);
+        g.board[0][0] = card_with_cost(Resource::Ruby, &[]);
+        g.strongholds = vec![
+            Stronghold {
+                player: 1,
+                row: 0,
+                col: 0
+            };
+            STRONGHOLDS_PER_PLAYER
+        ];
+        assert!(g.buy(0, ParsedLoc { row: 0, col: 0 }).is_err());
+        g.current_player = 1;
+        g.buy(1, ParsedLoc { row: 0, col: 0 }).unwrap();
+        assert!(g.strongholds.is_empty());
+    }
+
+    #[test]
+    fn strongholds_shift_when_a_slot_empties() {
+        let mut g = start_with(2, STRONGHOLDS_OPTION);
+        g.decks[0] = vec![];
+        g.board[0] = vec![
+            card_with_cost(Resource::Ruby, &[]),
+            card_with_cost(Resource::Onyx, &[]),
+        ];
+        g.strongholds = vec![Stronghold {
+            player: 1,
+            row: 0,
+            col: 1,
+        }];
+        g.buy(0, ParsedLoc { row: 0, col: 0 }).unwrap();
+        assert_eq!(0, g.strongholds[0].col);
+    }
+
+    #[test]
+    fn every_module_renders() {
+        let mut options = GameOptions::new();
+        for o in Game::options() {
+            options.set(o.key, "on");
+        }
+        let (mut g, _) = Game::start_with_options(3, 1, &options).unwrap();
+        g.strongholds.push(Stronghold {
+            player: 2,
+            row: 1,
+            col: 0,
+        });
+        g.player_boards[1].trading_posts.push(TradingPost::Gold);
+        let out = brdgme_markup::to_string(&brdgme_game::Renderer::render(&g.player_state(1)));
+        assert!(out.contains("Cities"));
+        assert!(out.contains("Orient 3"));
+        assert!(out.contains("Gold pays for 2 gems"));
+        assert!(out.contains("Forts"));
+    }
 }
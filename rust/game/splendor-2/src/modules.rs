@@ -0,0 +1,279 @@
+//! The Cities of Splendor expansion modules. Each module is switched on at
+//! start time by its own game option and can be combined with any other.
+
+use serde::{Deserialize, Serialize};
+
+use crate::card::{Card, GEMS, Resource};
+use crate::cost::Cost;
+
+/// Option key for the Cities module.
+pub const CITIES_OPTION: &str = "cities";
+/// Option key for the Trading Posts module.
+pub const TRADING_POSTS_OPTION: &str = "trading_posts";
+/// Option key for The Orient module.
+pub const ORIENT_OPTION: &str = "orient";
+/// Option key for the Strongholds module.
+pub const STRONGHOLDS_OPTION: &str = "strongholds";
+
+/// Number of city tiles dealt when playing with the Cities module.
+pub const CITIES_DEALT: usize = 3;
+/// Face-up Orient cards per level.
+pub const ORIENT_BOARD_SIZE: usize = 2;
+/// Strongholds each player starts with.
+pub const STRONGHOLDS_PER_PLAYER: usize = 3;
+
+/// Which expansion modules are in play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Modules {
+    pub cities: bool,
+    pub trading_posts: bool,
+    pub orient: bool,
+    pub strongholds: bool,
+}
+
+/// A city tile. Cities replace the nobles and the 15 prestige goal: the first
+/// player to meet a city's requirements triggers the end of the game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct City {
+    /// Prestige needed to claim the city.
+    pub prestige: i32,
+    /// Card bonuses needed to claim the city.
+    pub cost: Cost,
+    /// Card bonuses needed in any single gem, or 0 if there is no such
+    /// requirement.
+    pub same: i32,
+}
+
+impl City {
+    /// Whether a player with these bonuses and prestige can claim the city.
+    pub fn can_claim(&self, bonuses: &Cost, prestige: i32) -> bool {
+        prestige >= self.prestige
+            && GEMS.iter().all(|g| bonuses.get(g) >= self.cost.get(g))
+            && GEMS.iter().any(|g| bonuses.get(g) >= self.same)
+    }
+}
+
+/// Build a `Cost` from `(Resource, i32)` pairs, e.g. `cost!(Ruby: 4)`.
+macro_rules! cost {
+    ($($r:ident: $n:expr),* $(,)?) => {
+        brdgme_cost::Cost(std::collections::HashMap::from([
+            $((Resource::$r, $n)),*
+        ]))
+    };
+}
+
+pub fn city_tiles() -> Vec<City> {
+    vec![
+        City {
+            prestige: 13,
+            cost: cost!(Ruby: 4, Onyx: 3),
+            same: 0,
+        },
+        City {
+            prestige: 13,
+            cost: cost!(Sapphire: 4, Emerald: 3),
+            same: 0,
+        },
+        City {
+            prestige: 13,
+            cost: cost!(Diamond: 4, Sapphire: 3),
+            same: 0,
+        },
+        City {
+            prestige: 13,
+            cost: cost!(Emerald: 4, Ruby: 3),
+            same: 0,
+        },
+        City {
+            prestige: 13,
+            cost: cost!(Onyx: 4, Diamond: 3),
+            same: 0,
+        },
+        City {
+            prestige: 11,
+            cost: cost!(),
+            same: 6,
+        },
+        City {
+            prestige: 12,
+            cost: cost!(Diamond: 1, Sapphire: 1, Emerald: 1, Ruby: 1, Onyx: 1),
+            same: 4,
+        },
+        City {
+            prestige: 16,
+            cost: cost!(),
+            same: 0,
+        },
+    ]
+}
+
+/// A trading post on the player board. Each is unlocked for good once the
+/// player's card bonuses meet its requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradingPost {
+    /// After buying a card, take a gem token of the card's colour.
+    Gem,
+    /// When taking two of the same gem, also take one different gem.
+    Take,
+    /// Each Gold token pays for two gems.
+    Gold,
+    /// Worth 5 prestige.
+    Prestige,
+    /// Worth 1 prestige for each unlocked trading post, this one included.
+    Posts,
+}
+
+pub const TRADING_POSTS: [TradingPost; 5] = [
+    TradingPost::Gem,
+    TradingPost::Take,
+    TradingPost::Gold,
+    TradingPost::Prestige,
+    TradingPost::Posts,
+];
+
+impl TradingPost {
+    /// Card bonuses needed to unlock the trading post.
+    pub fn requirement(self) -> Cost {
+        match self {
+            TradingPost::Gem => cost!(Ruby: 3, Diamond: 1),
+            TradingPost::Take => cost!(Diamond: 2),
+            TradingPost::Gold => cost!(Sapphire: 3, Onyx: 1),
+            TradingPost::Prestige => cost!(Emerald: 5),
+            TradingPost::Posts => cost!(Onyx: 3),
+        }
+    }
+
+    pub fn desc(self) -> &'static str {
+        match self {
+            TradingPost::Gem => "take a gem of each card you buy",
+            TradingPost::Take => "take 2 same plus 1 different",
+            TradingPost::Gold => "Gold pays for 2 gems",
+            TradingPost::Prestige => "5 prestige",
+            TradingPost::Posts => "1 prestige per post",
+        }
+    }
+}
+
+/// The special effect printed on an Orient card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrientEffect {
+    /// The card's bonus counts twice.
+    Double,
+    /// After buying it, reserve a card from the main board without taking
+    /// Gold.
+    Reserve,
+    /// After buying it, take a card one level lower from the main board for
+    /// free.
+    Cascade,
+}
+
+impl OrientEffect {
+    pub fn abbr(self) -> &'static str {
+        match self {
+            OrientEffect::Double => "x2",
+            OrientEffect::Reserve => "Res",
+            OrientEffect::Cascade => "Casc",
+        }
+    }
+}
+
+/// A development card from The Orient decks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrientCard {
+    pub card: Card,
+    pub effect: OrientEffect,
+}
+
+impl OrientCard {
+    /// How many bonuses of its resource the card gives.
+    pub fn bonus(&self) -> i32 {
+        match self.effect {
+            OrientEffect::Double => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// The Orient cards for a level (0 = level 1): one card of each effect in
+/// every gem colour. Level 1 has no cascade as there is no lower level.
+pub fn orient_cards(level: usize) -> Vec<OrientCard> {
+    let (effects, prestige, main, other): (&[OrientEffect], &[i32], i32, i32) = match level {
+        0 => (
+            &[OrientEffect::Double, OrientEffect::Reserve],
+            &[0, 1],
+            3,
+            1,
+        ),
+        1 => (
+            &[OrientEffect::Double, OrientEffect::Cascade],
+            &[1, 2],
+            4,
+            2,
+        ),
+        _ => (
+            &[OrientEffect::Double, OrientEffect::Cascade],
+            &[3, 4],
+            6,
+            3,
+        ),
+    };
+    let mut cards = vec![];
+    for (i, &effect) in effects.iter().enumerate() {
+        for (g, &resource) in GEMS.iter().enumerate() {
+            let mut cost = Cost::new();
+            cost.set(GEMS[(g + 1 + i) % GEMS.len()], main);
+            cost.set(GEMS[(g + 3 + i) % GEMS.len()], other);
+            cards.push(OrientCard {
+                card: Card {
+                    resource,
+                    prestige: prestige[i],
+                    cost,
+                },
+                effect,
+            });
+        }
+    }
+    cards
+}
+
+/// A player's stronghold sitting on a main board card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stronghold {
+    pub player: usize,
+    pub row: usize,
+    pub col: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orient_decks_have_a_card_per_effect_and_gem() {
+        for level in 0..3 {
+            let cards = orient_cards(level);
+            assert_eq!(10, cards.len());
+            for c in &cards {
+                assert!(!c.card.cost.0.contains_key(&c.card.resource));
+            }
+        }
+        assert!(
+            orient_cards(0)
+                .iter()
+                .all(|c| c.effect != OrientEffect::Cascade)
+        );
+    }
+
+    #[test]
+    fn city_claim_needs_prestige_and_bonuses() {
+        let city = City {
+            prestige: 11,
+            cost: cost!(Ruby: 2),
+            same: 4,
+        };
+        assert!(city.can_claim(&cost!(Ruby: 2, Onyx: 4), 11));
+        assert!(!city.can_claim(&cost!(Ruby: 2, Onyx: 4), 10));
+        assert!(!city.can_claim(&cost!(Ruby: 2, Onyx: 3), 11));
+        assert!(city.can_claim(&cost!(Ruby: 4), 12));
+    }
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::card::{Card, Noble};
 use crate::cost::{self, Cost};
+use crate::modules::{City, OrientCard, TradingPost};
 
 /// Ported from `brdgme-go/splendor_1/player_board.go`.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -10,6 +11,15 @@ pub struct PlayerBoard {
     pub reserve: Vec<Card>,
     pub nobles: Vec<Noble>,
     pub tokens: Cost,
+    /// Cards bought from The Orient decks.
+    #[serde(default)]
+    pub orient_cards: Vec<OrientCard>,
+    /// Trading posts unlocked so far, in the order they were unlocked.
+    #[serde(default)]
+    pub trading_posts: Vec<TradingPost>,
+    /// The city this player claimed, if any.
+    #[serde(default)]
+    pub city: Option<City>,
 }
 
 impl PlayerBoard {
@@ -23,6 +33,10 @@ impl PlayerBoard {
             let entry = bonuses.0.entry(c.resource).or_insert(0);
             *entry += 1;
         }
+        for oc in &self.orient_cards {
+            let entry = bonuses.0.entry(oc.card.resource).or_insert(0);
+            *entry += oc.bonus();
+        }
         bonuses
     }
 
@@ -30,8 +44,22 @@ impl PlayerBoard {
         self.bonuses().add(&self.tokens)
     }
 
+    /// How many gems each Gold token pays for.
+    pub fn gold_value(&self) -> i32 {
+        if self.trading_posts.contains(&TradingPost::Gold) {
+            2
+        } else {
+            1
+        }
+    }
+
     pub fn can_afford(&self, cost: &Cost) -> bool {
-        cost::can_afford(&self.buying_power(), cost)
+        cost::can_afford_with_gold_value(&self.buying_power(), cost, self.gold_value())
+    }
+
+    /// Development cards owned, including Orient cards.
+    pub fn card_count(&self) -> usize {
+        self.cards.len() + self.orient_cards.len()
     }
 
     pub fn prestige(&self) -> i32 {
@@ -39,9 +67,19 @@ impl PlayerBoard {
         for c in &self.cards {
             prestige += c.prestige;
         }
+        for oc in &self.orient_cards {
+            prestige += oc.card.prestige;
+        }
         for n in &self.nobles {
             prestige += n.prestige;
         }
+        for tp in &self.trading_posts {
+            prestige += match tp {
+                TradingPost::Prestige => 5,
+                TradingPost::Posts => self.trading_posts.len() as i32,
+                _ => 0,
+            };
+        }
         prestige
     }
 }
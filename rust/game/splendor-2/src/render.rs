@@ -12,6 +12,7 @@ use brdgme_markup::{Align as A, Node as N, Row, table_with_gap};
 
 use crate::card::{Card, GEMS, RESOURCES, Resource};
 use crate::cost::{self, Cost};
+use crate::modules::{City, OrientCard, TRADING_POSTS, TradingPost};
 use crate::{PlayerState, PubState};
 
 /// Ported from `render.go`'s `ResourceAbbr`.
@@ -83,18 +84,21 @@ fn render_card_bonus_vp(c: &Card) -> N {
     N::Bold(parts)
 }
 
+/// The viewing player's `(bonuses, buying_power, gold_value)`, used to mark
+/// affordable cards.
+type Afford<'a> = (&'a Cost, &'a Cost, i32);
+
 /// One board/reserve card cell pair (upper: afford-marker + bonus/VP, lower:
-/// cost). `afford` is `(bonuses, buying_power)` for the viewing player, only
-/// present in player views (`pNum >= 0` in Go).
-fn card_cells(c: &Card, afford: Option<(&Cost, &Cost)>) -> (Cell, Cell) {
+/// cost). `afford` is only present in player views (`pNum >= 0` in Go).
+fn card_cells(c: &Card, afford: Option<Afford>) -> (Cell, Cell) {
     let mut upper: Vec<N> = vec![];
-    if let Some((bonuses, buying_power)) = afford {
+    if let Some((bonuses, buying_power, gold_value)) = afford {
         if cost::can_afford(bonuses, &c.cost) {
             upper.push(N::Bold(vec![N::Fg(
                 NamedColor::Green.into(),
                 vec![N::text("X ")],
             )]));
-        } else if cost::can_afford(buying_power, &c.cost) {
+        } else if cost::can_afford_with_gold_value(buying_power, &c.cost, gold_value) {
             upper.push(N::Bold(vec![N::Fg(
                 NamedColor::Yellow.into(),
                 vec![N::text("X ")],
@@ -108,6 +112,51 @@ fn card_cells(c: &Card, afford: Option<(&Cost, &Cost)>) -> (Cell, Cell) {
     )
 }
 
+/// An Orient card's cells: a normal card with its effect after the bonus.
+fn orient_card_cells(oc: &OrientCard, afford: Option<Afford>) -> (Cell, Cell) {
+    let ((align, mut upper), lower) = card_cells(&oc.card, afford);
+    upper.push(grey(vec![N::text(format!(" {}", oc.effect.abbr()))]));
+    ((align, upper), lower)
+}
+
+/// A city's requirements: prestige, then card bonuses, then any count
+/// needed in a single gem.
+fn render_city(c: &City) -> Vec<N> {
+    let mut out = vec![N::Bold(vec![render_resource_colour(
+        format!("{} VP", c.prestige),
+        Resource::Prestige,
+    )])];
+    let bonuses = render_amount(&c.cost);
+    if !bonuses.is_empty() {
+        out.push(N::text(" "));
+        out.extend(bonuses);
+    }
+    if c.same > 0 {
+        out.push(grey(vec![N::text(format!(" {} same", c.same))]));
+    }
+    out
+}
+
+/// The strongholds on a main board card, as each owner's name and count.
+fn stronghold_cell(pub_state: &PubState, row: usize, col: usize) -> Cell {
+    let mut nodes: Vec<N> = vec![];
+    for p in 0..pub_state.players {
+        let n = pub_state
+            .strongholds
+            .iter()
+            .filter(|s| s.player == p && s.row == row && s.col == col)
+            .count();
+        if n > 0 {
+            if !nodes.is_empty() {
+                nodes.push(N::text(" "));
+            }
+            nodes.push(N::Player(p));
+            nodes.push(grey(vec![N::text(format!(" x{}", n))]));
+        }
+    }
+    cel(A::Center, nodes)
+}
+
 /// Port of `PlayerRender` (`render.go`), shared by `PubState` (`player =
 /// None`, matching Go's `pNum == -1`) and `PlayerState` (`player = Some((idx,
 /// reserve))`, the viewing player's own reserve cards - the only hidden
@@ -121,28 +170,55 @@ fn render(pub_state: &PubState, player: Option<(usize, &[Card])>) -> Vec<N> {
             .get(p)
             .map(|pb| (pb.bonuses.clone(), pb.tokens.clone()))
     });
+    let gold_value = player
+        .and_then(|(p, _)| pub_state.player_boards.get(p))
+        .map_or(1, |pb| {
+            if pb.trading_posts.contains(&TradingPost::Gold) {
+                2
+            } else {
+                1
+            }
+        });
     let afford = bonuses_tokens
         .as_ref()
         .map(|(bonuses, tokens)| (bonuses, bonuses.add(tokens)));
+    let afford = || afford.as_ref().map(|(b, bp)| (*b, bp, gold_value));
 
-    // Nobles.
-    let mut noble_header: Row = vec![blank_cell()];
-    let mut noble_row: Row = vec![cel(
-        A::Left,
-        vec![grey(vec![
-            N::text("Nobles ("),
-            N::Bold(vec![render_resource_colour("3", Resource::Prestige)]),
-            N::text(" each)"),
-        ])],
-    )];
-    for (i, n) in pub_state.nobles.iter().enumerate() {
-        noble_header.push(cel(
-            A::Center,
-            vec![grey(vec![N::text((i + 1).to_string())])],
-        ));
-        noble_row.push(cel(A::Left, render_amount(&n.cost)));
+    if pub_state.modules.cities {
+        // Cities, in place of the nobles.
+        let mut city_header: Row = vec![blank_cell()];
+        let mut city_row: Row = vec![cel(
+            A::Left,
+            vec![grey(vec![N::text("Cities (claim one to end the game)")])],
+        )];
+        for (i, c) in pub_state.cities.iter().enumerate() {
+            city_header.push(cel(
+                A::Center,
+                vec![grey(vec![N::text((i + 1).to_string())])],
+            ));
+            city_row.push(cel(A::Left, render_city(c)));
+        }
+        out.push(table_with_gap(&[city_header, city_row], 2));
+    } else {
+        // Nobles.
+        let mut noble_header: Row = vec![blank_cell()];
+        let mut noble_row: Row = vec![cel(
+            A::Left,
+            vec![grey(vec![
+                N::text("Nobles ("),
+                N::Bold(vec![render_resource_colour("3", Resource::Prestige)]),
+                N::text(" each)"),
+            ])],
+        )];
+        for (i, n) in pub_state.nobles.iter().enumerate() {
+            noble_header.push(cel(
+                A::Center,
+                vec![grey(vec![N::text((i + 1).to_string())])],
+            ));
+            noble_row.push(cel(A::Left, render_amount(&n.cost)));
+        }
+        out.push(table_with_gap(&[noble_header, noble_row], 2));
     }
-    out.push(table_with_gap(&[noble_header, noble_row], 2));
     out.push(N::text("\n\n"));
 
     // Board.
@@ -172,12 +248,19 @@ fn render(pub_state: &PubState, player: Option<(usize, &[Card])>) -> Vec<N> {
         )];
         let mut lower: Row = vec![blank_cell()];
         for c in r {
-            let (u, lo) = card_cells(c, afford.as_ref().map(|(b, bp)| (*b, bp)));
+            let (u, lo) = card_cells(c, afford());
             upper.push(u);
             lower.push(lo);
         }
         rows.push(upper);
         rows.push(lower);
+        if pub_state.modules.strongholds {
+            let mut forts: Row = vec![blank_cell()];
+            for col in 0..r.len() {
+                forts.push(stronghold_cell(pub_state, l, col));
+            }
+            rows.push(forts);
+        }
         rows.push(vec![]);
     }
     let mut upper: Row = vec![cel(
@@ -187,13 +270,40 @@ fn render(pub_state: &PubState, player: Option<(usize, &[Card])>) -> Vec<N> {
     let mut lower: Row = vec![cel(A::Left, vec![grey(vec![N::text("Reserved")])])];
     if let Some((_, reserve)) = player {
         for c in reserve {
-            let (u, lo) = card_cells(c, afford.as_ref().map(|(b, bp)| (*b, bp)));
+            let (u, lo) = card_cells(c, afford());
             upper.push(u);
             lower.push(lo);
         }
     }
     rows.push(upper);
     rows.push(lower);
+    // The Orient rows follow the reserve as levels 5 to 7.
+    for (l, r) in pub_state.orient_board.iter().enumerate() {
+        let deck_count = pub_state.orient_deck_counts.get(l).copied().unwrap_or(0);
+        rows.push(vec![]);
+        let mut upper: Row = vec![cel(
+            A::Left,
+            vec![grey(vec![
+                N::text("Level "),
+                N::Bold(vec![N::text((l + 5).to_string())]),
+            ])],
+        )];
+        let mut lower: Row = vec![cel(
+            A::Left,
+            vec![grey(vec![N::text(format!(
+                "Orient {} ({} left)",
+                l + 1,
+                deck_count
+            ))])],
+        )];
+        for oc in r {
+            let (u, lo) = orient_card_cells(oc, afford());
+            upper.push(u);
+            lower.push(lo);
+        }
+        rows.push(upper);
+        rows.push(lower);
+    }
     out.push(table_with_gap(&rows, 3));
     out.push(N::text("\n\n\n"));
 
@@ -245,6 +355,32 @@ fn render(pub_state: &PubState, player: Option<(usize, &[Card])>) -> Vec<N> {
     out.push(table_with_gap(&rows, 3));
     out.push(N::text("\n\n\n"));
 
+    if pub_state.modules.trading_posts {
+        // Trading posts, with who has unlocked each.
+        let mut rows: Vec<Row> = vec![vec![
+            cel(A::Left, vec![N::Bold(vec![N::text("Trading post")])]),
+            cel(A::Center, vec![N::Bold(vec![N::text("Needs")])]),
+            cel(A::Left, vec![N::Bold(vec![N::text("Unlocked by")])]),
+        ]];
+        for tp in TRADING_POSTS {
+            let owners: Vec<N> = pub_state
+                .player_boards
+                .iter()
+                .enumerate()
+                .filter(|(_, pb)| pb.trading_posts.contains(&tp))
+                .flat_map(|(p, _)| [N::text(" "), N::Player(p)])
+                .skip(1)
+                .collect();
+            rows.push(vec![
+                cel(A::Left, vec![N::text(tp.desc())]),
+                cel(A::Center, render_amount(&tp.requirement())),
+                cel(A::Left, owners),
+            ]);
+        }
+        out.push(table_with_gap(&rows, 2));
+        out.push(N::text("\n\n\n"));
+    }
+
     // Player table.
     let mut header: Row = vec![blank_cell()];
     for &gem in GEMS.iter() {
@@ -279,6 +415,12 @@ fn render(pub_state: &PubState, player: Option<(usize, &[Card])>) -> Vec<N> {
         )])],
     ));
     header.push(cel(A::Center, vec![N::Bold(vec![N::text("Dev")])]));
+    if pub_state.modules.cities {
+        header.push(cel(A::Center, vec![N::Bold(vec![N::text("City")])]));
+    }
+    if pub_state.modules.strongholds {
+        header.push(cel(A::Center, vec![N::Bold(vec![N::text("Forts")])]));
+    }
     let mut rows: Vec<Row> = vec![header];
     for p in 0..pub_state.players {
         let bold = player.map(|(pi, _)| pi == p).unwrap_or(false);
@@ -297,13 +439,19 @@ fn render(pub_state: &PubState, player: Option<(usize, &[Card])>) -> Vec<N> {
                 },
             ));
         }
-        let cells = [
+        let mut cells = vec![
             pb.tokens.get(&Resource::Gold).to_string(),
             pb.tokens.sum().to_string(),
             pb.reserve_count.to_string(),
             pb.prestige.to_string(),
             pb.card_count.to_string(),
         ];
+        if pub_state.modules.cities {
+            cells.push(if pb.city.is_some() { "Yes" } else { "" }.to_string());
+        }
+        if pub_state.modules.strongholds {
+            cells.push(pb.strongholds_left.to_string());
+        }
         for text in cells {
             row.push(cel(
                 A::Center,
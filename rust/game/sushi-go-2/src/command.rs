@@ -1,11 +1,17 @@
 use brdgme_game::command::parser::*;
 
 use crate::Game;
+use crate::party::{MenuItem, Pending};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Command {
     Play(Vec<usize>),
     Dummy(usize),
+    Spoon(usize, MenuItem),
+    Pick(usize),
+    Copy(usize),
+    Box(Vec<usize>),
+    Pass,
 }
 
 impl Game {
@@ -14,9 +20,27 @@ impl Game {
             return None;
         }
         let mut parsers: Vec<Box<dyn Parser<T = Command>>> = vec![];
+        let tray_len = self.played.get(player).map(Vec::len).unwrap_or(0);
+        match self.pending_for(player) {
+            Some(Pending::Menu(cards)) => parsers.push(Box::new(pick_parser(cards.len()))),
+            Some(Pending::SpecialOrder) => parsers.push(Box::new(copy_parser(tray_len))),
+            Some(Pending::TakeoutBox) => {
+                parsers.push(Box::new(box_parser(tray_len)));
+                parsers.push(Box::new(pass_parser()));
+            }
+            None => {}
+        }
         if self.can_play(player) {
             parsers.push(Box::new(play_parser(self.hands[player].len())));
         }
+        if self.can_spoon(player)
+            && let Some(ref menu) = self.menu
+        {
+            parsers.push(Box::new(spoon_parser(
+                self.hands[player].len(),
+                menu.items(),
+            )));
+        }
         if self.can_dummy(player) {
             parsers.push(Box::new(dummy_parser(self.hands[player].len())));
         }
@@ -65,3 +89,89 @@ pub fn dummy_parser(max: usize) -> impl Parser<T = Command> {
         |(_, n): (String, i32)| Command::Dummy((n - 1) as usize),
     )
 }
+
+pub fn spoon_parser(max: usize, items: Vec<MenuItem>) -> impl Parser<T = Command> {
+    Map::new(
+        Chain3::new(
+            Doc::name_desc(
+                "spoon",
+                "play a card and use a spoon on your tray to ask for a menu item",
+                Token::new("spoon"),
+            ),
+            AfterSpace::new(Doc::name_desc(
+                "card",
+                "the card to play",
+                Int::bounded(1, max as i32),
+            )),
+            AfterSpace::new(Doc::name_desc(
+                "item",
+                "the menu item to ask for",
+                Enum::partial(items),
+            )),
+        ),
+        |(_, n, item): (String, i32, MenuItem)| Command::Spoon((n - 1) as usize, item),
+    )
+}
+
+pub fn pick_parser(max: usize) -> impl Parser<T = Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "pick",
+                "order one of the cards drawn for your menu",
+                Token::new("pick"),
+            ),
+            AfterSpace::new(Doc::name_desc(
+                "card",
+                "the drawn card to order",
+                Int::bounded(1, max as i32),
+            )),
+        ),
+        |(_, n): (String, i32)| Command::Pick((n - 1) as usize),
+    )
+}
+
+pub fn copy_parser(max: usize) -> impl Parser<T = Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "copy",
+                "turn your special order into a copy of a card on your tray",
+                Token::new("copy"),
+            ),
+            AfterSpace::new(Doc::name_desc(
+                "card",
+                "the tray card to copy",
+                Int::bounded(1, max as i32),
+            )),
+        ),
+        |(_, n): (String, i32)| Command::Copy((n - 1) as usize),
+    )
+}
+
+pub fn box_parser(max: usize) -> impl Parser<T = Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "box",
+                "put cards on your tray in your takeout box, worth 2 points each",
+                Token::new("box"),
+            ),
+            AfterSpace::new(Doc::name_desc(
+                "card",
+                "the tray cards to box",
+                Many::bounded_spaced(Int::bounded(1, max as i32), 1, max),
+            )),
+        ),
+        |(_, nums): (String, Vec<i32>)| {
+            Command::Box(nums.iter().map(|n| (*n - 1) as usize).collect())
+        },
+    )
+}
+
+pub fn pass_parser() -> impl Parser<T = Command> {
+    Map::new(
+        Doc::name_desc("pass", "don't box any cards", Token::new("pass")),
+        |_| Command::Pass,
+    )
+}
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use serde::{Deserialize, Serialize};
 
 mod command;
+pub mod party;
 mod render;
 
 use brdgme_game::command::Spec as CommandSpec;
@@ -10,14 +11,21 @@ use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
 use brdgme_game::rng::GameRng;
-use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
+use brdgme_game::{CommandResponse, GameOption, GameOptions, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
 use rand::prelude::*;
 
 use command::Command;
+use party::{
+    APPETIZER_OPTIONS, APPETIZERS, DESSERT_OPTION, DESSERTS, MENU_CLASSIC, MENU_CUSTOM,
+    MENU_OPTION, MENU_RANDOM, Menu, MenuItem, Pending, ROLL_OPTION, ROLLS, SPECIAL_OPTIONS,
+    SPECIALS, URAMAKI_AWARDS, URAMAKI_TARGET,
+};
 
 const MIN_PLAYERS: usize = 2;
-const MAX_PLAYERS: usize = 5;
+const MAX_PLAYERS: usize = 8;
+/// The original game only has enough cards for 5 players.
+const MAX_CLASSIC_PLAYERS: usize = 5;
 pub const DUMMY: usize = 2;
 const TOTAL_ROUNDS: usize = 3;
 
@@ -40,9 +48,37 @@ pub enum Card {
     Pudding,
     Wasabi,
     Chopsticks,
+    Temaki,
+    Uramaki5,
+    Uramaki4,
+    Uramaki3,
+    Edamame,
+    Eel,
+    OnigiriCircle,
+    OnigiriTriangle,
+    OnigiriSquare,
+    OnigiriRectangle,
+    MisoSoup,
+    Tofu,
+    Spoon,
+    Menu,
+    TakeoutBox,
+    Tea,
+    SoySauce,
+    SpecialOrder,
+    GreenTeaIceCream,
+    FruitWatermelons,
+    FruitPineapples,
+    FruitOranges,
+    FruitWatermelonPineapple,
+    FruitWatermelonOrange,
+    FruitPineappleOrange,
+    /// A card flipped face down by a takeout box.
+    Boxed,
 }
 
 impl Card {
+    /// The cards of the original game.
     pub fn all() -> &'static [Card] {
         &[
             Card::Tempura,
@@ -60,6 +96,8 @@ impl Card {
         ]
     }
 
+    /// How many of the card are in the original game's deck. Party cards
+    /// come from `MenuItem::cards`.
     pub fn count(self) -> usize {
         match self {
             Card::Tempura => 14,
@@ -74,7 +112,7 @@ impl Card {
             Card::Pudding => 10,
             Card::Wasabi => 6,
             Card::Chopsticks => 4,
-            Card::Played => 0,
+            _ => 0,
         }
     }
 
@@ -93,13 +131,39 @@ impl Card {
             Card::Pudding => "pudding",
             Card::Wasabi => "wasabi",
             Card::Chopsticks => "chopsticks",
+            Card::Temaki => "temaki",
+            Card::Uramaki5 => "uramaki x5",
+            Card::Uramaki4 => "uramaki x4",
+            Card::Uramaki3 => "uramaki x3",
+            Card::Edamame => "edamame",
+            Card::Eel => "eel",
+            Card::OnigiriCircle => "onigiri (circle)",
+            Card::OnigiriTriangle => "onigiri (triangle)",
+            Card::OnigiriSquare => "onigiri (square)",
+            Card::OnigiriRectangle => "onigiri (rectangle)",
+            Card::MisoSoup => "miso soup",
+            Card::Tofu => "tofu",
+            Card::Spoon => "spoon",
+            Card::Menu => "menu",
+            Card::TakeoutBox => "takeout box",
+            Card::Tea => "tea",
+            Card::SoySauce => "soy sauce",
+            Card::SpecialOrder => "special order",
+            Card::GreenTeaIceCream => "green tea ice cream",
+            Card::FruitWatermelons => "fruit (2 watermelon)",
+            Card::FruitPineapples => "fruit (2 pineapple)",
+            Card::FruitOranges => "fruit (2 orange)",
+            Card::FruitWatermelonPineapple => "fruit (watermelon, pineapple)",
+            Card::FruitWatermelonOrange => "fruit (watermelon, orange)",
+            Card::FruitPineappleOrange => "fruit (pineapple, orange)",
+            Card::Boxed => "boxed",
         }
     }
 
     pub fn color(self) -> brdgme_color::NamedColor {
         use brdgme_color::NamedColor;
         match self {
-            Card::Played => NamedColor::Grey,
+            Card::Played | Card::Boxed => NamedColor::Grey,
             Card::Tempura => NamedColor::Purple,
             Card::Sashimi => NamedColor::Purple,
             Card::Dumpling => NamedColor::Yellow,
@@ -108,6 +172,26 @@ impl Card {
             Card::Pudding => NamedColor::Blue,
             Card::Wasabi => NamedColor::Green,
             Card::Chopsticks => NamedColor::Foreground,
+            Card::Temaki | Card::Uramaki5 | Card::Uramaki4 | Card::Uramaki3 => NamedColor::Red,
+            Card::Edamame | Card::Tofu => NamedColor::Green,
+            Card::Eel | Card::MisoSoup => NamedColor::Brown,
+            Card::OnigiriCircle
+            | Card::OnigiriTriangle
+            | Card::OnigiriSquare
+            | Card::OnigiriRectangle => NamedColor::Purple,
+            Card::Spoon
+            | Card::Menu
+            | Card::TakeoutBox
+            | Card::Tea
+            | Card::SoySauce
+            | Card::SpecialOrder => NamedColor::Foreground,
+            Card::GreenTeaIceCream => NamedColor::Green,
+            Card::FruitWatermelons
+            | Card::FruitPineapples
+            | Card::FruitOranges
+            | Card::FruitWatermelonPineapple
+            | Card::FruitWatermelonOrange
+            | Card::FruitPineappleOrange => NamedColor::Orange,
         }
     }
 
@@ -116,13 +200,37 @@ impl Card {
             Card::Tempura => "x2 = 5",
             Card::Sashimi => "x3 = 10",
             Card::Dumpling => "1 3 6 10 15",
-            Card::MakiRoll3 | Card::MakiRoll2 | Card::MakiRoll1 => "most: 6/3",
+            Card::MakiRoll3 | Card::MakiRoll2 | Card::MakiRoll1 => "most: 6/3 (6/4/2 for 6+p)",
             Card::SalmonNigiri => "2",
             Card::SquidNigiri => "3",
             Card::EggNigiri => "1",
             Card::Pudding => "end: most 6, least -6 (no penalty in 2p)",
             Card::Wasabi => "next nigiri x3",
             Card::Chopsticks => "swap for 2",
+            Card::Temaki => "most 4, least -4 (no penalty in 2p)",
+            Card::Uramaki5 | Card::Uramaki4 | Card::Uramaki3 => "first to 10: 8/5/2",
+            Card::Edamame => "1 per opponent with edamame",
+            Card::Eel => "x1 = -3, x2+ = 7",
+            Card::OnigiriCircle
+            | Card::OnigiriTriangle
+            | Card::OnigiriSquare
+            | Card::OnigiriRectangle => "different shapes: 1 4 9 16",
+            Card::MisoSoup => "3, discarded if another is played",
+            Card::Tofu => "x1 = 2, x2 = 6, x3+ = 0",
+            Card::Spoon => "later: ask for an item",
+            Card::Menu => "draw 4, play 1",
+            Card::TakeoutBox => "box cards for 2 each",
+            Card::Tea => "1 per card of your biggest item",
+            Card::SoySauce => "most items: 4",
+            Card::SpecialOrder => "copy a tray card",
+            Card::GreenTeaIceCream => "end: x4 = 12",
+            Card::FruitWatermelons
+            | Card::FruitPineapples
+            | Card::FruitOranges
+            | Card::FruitWatermelonPineapple
+            | Card::FruitWatermelonOrange
+            | Card::FruitPineappleOrange => "end: per fruit -2 0 1 3 6 10",
+            Card::Boxed => "2",
             Card::Played => "",
         }
     }
@@ -135,6 +243,42 @@ impl Card {
             _ => None,
         }
     }
+
+    /// Dessert cards stay on the tray until the end of the game.
+    pub fn is_dessert(self) -> bool {
+        party::card_item(self).is_some_and(MenuItem::is_dessert)
+    }
+
+    pub fn maki(self) -> i32 {
+        match self {
+            Card::MakiRoll1 => 1,
+            Card::MakiRoll2 => 2,
+            Card::MakiRoll3 => 3,
+            _ => 0,
+        }
+    }
+
+    pub fn uramaki(self) -> i32 {
+        match self {
+            Card::Uramaki3 => 3,
+            Card::Uramaki4 => 4,
+            Card::Uramaki5 => 5,
+            _ => 0,
+        }
+    }
+
+    /// Watermelons, pineapples and oranges on a fruit card.
+    pub fn fruit(self) -> [i32; 3] {
+        match self {
+            Card::FruitWatermelons => [2, 0, 0],
+            Card::FruitPineapples => [0, 2, 0],
+            Card::FruitOranges => [0, 0, 2],
+            Card::FruitWatermelonPineapple => [1, 1, 0],
+            Card::FruitWatermelonOrange => [1, 0, 1],
+            Card::FruitPineappleOrange => [0, 1, 1],
+            _ => [0, 0, 0],
+        }
+    }
 }
 
 fn draw_count(players: usize) -> Result<usize, GameError> {
@@ -171,6 +315,20 @@ fn trim_played(cards: &[Card]) -> Vec<Card> {
         .collect()
 }
 
+fn points_nodes(s: i32) -> Vec<N> {
+    vec![
+        N::text(", "),
+        N::Bold(vec![N::text(s.to_string())]),
+        N::text(" points"),
+    ]
+}
+
+fn count_line(count: i32, c: Card, s: i32) -> Vec<N> {
+    let mut line = vec![N::text(format!("{} x ", count)), render::card(c)];
+    line.extend(points_nodes(s));
+    line
+}
+
 #[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
     pub players: usize,
@@ -186,11 +344,26 @@ pub struct Game {
     // Remove once no pre-RNG games remain active.
     #[serde(default = "GameRng::from_entropy")]
     pub rng: GameRng,
+    /// The Sushi Go Party menu, or `None` when playing the original game.
+    #[serde(default)]
+    pub menu: Option<Menu>,
+    /// Dessert cards not yet added to the deck.
+    #[serde(default)]
+    pub desserts: Vec<Card>,
+    /// The item each player is using a spoon on this hand.
+    #[serde(default)]
+    pub spoons: Vec<Option<MenuItem>>,
+    /// Choices each player must make before hands are passed.
+    #[serde(default)]
+    pub pending: Vec<Vec<Pending>>,
+    /// Uramaki awards given out so far this round.
+    #[serde(default)]
+    pub uramaki_awarded: usize,
 }
 
 #[derive(Default, Serialize, Deserialize, Clone)]
 pub struct PubState {
-    /// Number of real players in this game (2 to 5).
+    /// Number of real players in this game (2 to 8).
     pub players: usize,
     /// Total number of player slots including dummy (3 in 2-player, otherwise same as players).
     pub all_players: usize,
@@ -198,7 +371,7 @@ pub struct PubState {
     pub round: usize,
     /// Index of the player currently controlling the dummy (2-player only).
     pub controller: usize,
-    /// Cards played to each player's table, indexed by player slot. Puddings persist across rounds.
+    /// Cards played to each player's table, indexed by player slot. Desserts persist across rounds.
     pub played: Vec<Vec<Card>>,
     /// Cumulative points for each player slot across all rounds.
     pub player_points: Vec<i32>,
@@ -206,6 +379,8 @@ pub struct PubState {
     pub finished: bool,
     /// Final scores for each real player. Only populated when the game is finished; empty during play.
     pub final_scores: Vec<i32>,
+    /// The Sushi Go Party menu, or None when playing the original game.
+    pub menu: Option<Menu>,
 }
 
 #[derive(Default, Serialize, Deserialize, Clone)]
@@ -220,6 +395,10 @@ pub struct PlayerState {
     pub playing: Option<Vec<Card>>,
     /// Cards the dummy has been assigned this hand (2-player only, visible to controller).
     pub dummy_playing: Option<Vec<Card>>,
+    /// The item this player is using their spoon on this hand.
+    pub spoon: Option<MenuItem>,
+    /// Choices this player must make before hands are passed, the first being current.
+    pub pending: Vec<Pending>,
 }
 
 impl Game {
@@ -228,27 +407,56 @@ impl Game {
             && !self.hands.is_empty()
             && self.hands[0].is_empty()
             && self.playing.first().is_some_and(|p| p.is_none())
+            && !self.has_pending()
     }
 
     pub fn whose_turn_inner(&self) -> Vec<usize> {
         if self.is_finished() {
             return vec![];
         }
+        if self.has_pending() {
+            return (0..self.players)
+                .filter(|&p| self.pending_for(p).is_some())
+                .collect();
+        }
         (0..self.players)
             .filter(|&p| self.can_play(p) || self.can_dummy(p))
             .collect()
     }
 
+    /// Whether this is the original two player game with a dummy. Party
+    /// games with two players have no dummy.
+    pub fn has_dummy(&self) -> bool {
+        self.menu.is_none() && self.players == 2
+    }
+
+    pub fn has_pending(&self) -> bool {
+        self.pending.iter().any(|p| !p.is_empty())
+    }
+
+    /// The choice the player must make now, if any.
+    pub fn pending_for(&self, player: usize) -> Option<&Pending> {
+        self.pending.get(player).and_then(|p| p.first())
+    }
+
     pub fn can_play(&self, player: usize) -> bool {
-        player < self.playing.len() && self.playing[player].is_none()
+        player < self.playing.len() && self.playing[player].is_none() && !self.has_pending()
     }
 
     pub fn can_dummy(&self, player: usize) -> bool {
-        self.players == 2
+        self.has_dummy()
             && self.controller == player
             && self.playing.get(DUMMY).is_some_and(|p| p.is_none())
     }
 
+    pub fn can_spoon(&self, player: usize) -> bool {
+        self.can_play(player)
+            && self
+                .played
+                .get(player)
+                .is_some_and(|cards| cards.contains(&Card::Spoon))
+    }
+
     pub fn render_name(&self, player: usize) -> N {
         render::render_name(player, self.players)
     }
@@ -264,12 +472,19 @@ impl Game {
             .unwrap_or(0)
     }
 
+    pub fn dessert_cards(&self, player: usize) -> i32 {
+        self.played
+            .get(player)
+            .map(|cards| cards.iter().filter(|c| c.is_dessert()).count() as i32)
+            .unwrap_or(0)
+    }
+
     pub fn placings(&self) -> Vec<usize> {
         let metrics: Vec<Vec<i32>> = (0..self.players)
             .map(|p| {
                 vec![
                     self.player_points.get(p).copied().unwrap_or(0),
-                    self.pudding_cards(p),
+                    self.dessert_cards(p),
                 ]
             })
             .collect();
@@ -286,16 +501,31 @@ impl Game {
     pub fn start_round(&mut self) -> Result<Vec<Log>, GameError> {
         let mut logs = vec![];
         self.round += 1;
+        self.uramaki_awarded = 0;
         for p in 0..self.all_players {
             let new_played: Vec<Card> = self.played[p]
                 .iter()
-                .filter(|&&c| c == Card::Pudding)
+                .filter(|c| c.is_dessert())
                 .copied()
                 .collect();
             self.played[p] = new_played;
         }
         self.hands = vec![vec![]; self.all_players];
-        let dc = draw_count(self.all_players)?;
+        let dc = match self.menu {
+            Some(ref menu) => {
+                // Party rebuilds the deck each round, keeping undealt
+                // desserts and adding more from the dessert pile.
+                let mut deck = menu.round_cards();
+                deck.extend(self.deck.iter().filter(|c| c.is_dessert()));
+                let added =
+                    party::desserts_for_round(self.round, self.players).min(self.desserts.len());
+                deck.extend(self.desserts.drain(..added));
+                deck.shuffle(&mut self.rng);
+                self.deck = deck;
+                party::draw_count(self.players)
+            }
+            None => draw_count(self.all_players)?,
+        };
         let pass_dir = if self.round == 2 { "right" } else { "left" };
         logs.push(Log::public(vec![
             N::text("Starting round "),
@@ -316,7 +546,7 @@ impl Game {
 
     pub fn start_hand(&mut self) -> Vec<Log> {
         let mut logs = vec![];
-        if self.players == 2 && !self.hands[DUMMY].is_empty() {
+        if self.has_dummy() && !self.hands[DUMMY].is_empty() {
             let i = self.rng.random_range(0..self.hands[DUMMY].len());
             let drawn = self.hands[DUMMY][i];
             logs.push(Log::private(
@@ -335,8 +565,11 @@ impl Game {
         logs
     }
 
+    /// Reveals the cards played this hand. Hands are passed straight away
+    /// unless a player has a choice to make first.
     pub fn end_hand(&mut self) -> Result<Vec<Log>, GameError> {
         let mut logs = vec![];
+        let mut revealed: Vec<Vec<Card>> = vec![];
         for p in 0..self.all_players {
             self.hands[p] = trim_played(&self.hands[p]);
             let playing = self.playing[p].clone().unwrap_or_default();
@@ -353,8 +586,28 @@ impl Game {
                 self.played[p].remove(i);
             }
             self.playing[p] = None;
+            revealed.push(playing);
         }
-        if self.players == 2 {
+        if self.menu.is_some() {
+            logs.extend(self.miso_clash(&revealed));
+            logs.extend(self.use_spoons());
+            self.queue_pending(&revealed);
+            if self.has_pending() {
+                return Ok(logs);
+            }
+        }
+        logs.extend(self.finish_hand()?);
+        Ok(logs)
+    }
+
+    /// Scores uramaki and passes hands once every card this hand is
+    /// resolved.
+    fn finish_hand(&mut self) -> Result<Vec<Log>, GameError> {
+        let mut logs = vec![];
+        if self.menu.as_ref().is_some_and(|m| m.has(MenuItem::Uramaki)) {
+            logs.extend(self.score_uramaki());
+        }
+        if self.has_dummy() {
             self.controller = (self.controller + 1) % self.players;
         }
         if self.hands[0].is_empty() {
@@ -381,160 +634,474 @@ impl Game {
         Ok(logs)
     }
 
-    pub fn score(&self) -> (Vec<i32>, Vec<Vec<N>>) {
-        let mut scores = vec![0i32; self.all_players];
-        let mut output: Vec<Vec<N>> = vec![];
+    /// Discards every miso soup played this hand if more than one was
+    /// played.
+    fn miso_clash(&mut self, revealed: &[Vec<Card>]) -> Vec<Log> {
+        let misos: Vec<usize> = (0..self.all_players)
+            .flat_map(|p| {
+                revealed[p]
+                    .iter()
+                    .filter(|&&c| c == Card::MisoSoup)
+                    .map(move |_| p)
+            })
+            .collect();
+        if misos.len() < 2 {
+            return vec![];
+        }
+        for &p in &misos {
+            if let Some(i) = self.played[p].iter().rposition(|&c| c == Card::MisoSoup) {
+                self.played[p].remove(i);
+            }
+        }
+        let mut players = misos.clone();
+        players.dedup();
+        vec![Log::public(vec![
+            N::text("More than one "),
+            render::card(Card::MisoSoup),
+            N::text(" was played, discarding those played by "),
+            render::comma_list_nodes(self.render_names(&players)),
+        ])]
+    }
+
+    /// The players a spoon user asks in turn, following the direction hands
+    /// are passed.
+    fn spoon_order(&self, player: usize) -> Vec<usize> {
+        let n = self.all_players;
+        (1..n)
+            .map(|k| {
+                if self.round % 2 == 1 {
+                    (player + n - k) % n
+                } else {
+                    (player + k) % n
+                }
+            })
+            .collect()
+    }
 
-        // Score maki
-        let mut maki = vec![0i32; self.all_players];
-        for (p, maki_p) in maki.iter_mut().enumerate() {
-            for &c in &self.played[p] {
+    fn use_spoons(&mut self) -> Vec<Log> {
+        let mut logs = vec![];
+        for p in 0..self.all_players {
+            let Some(item) = self.spoons.get_mut(p).and_then(Option::take) else {
+                continue;
+            };
+            let Some(i) = self.played[p].iter().position(|&c| c == Card::Spoon) else {
+                continue;
+            };
+            self.played[p].remove(i);
+            let giver = self.spoon_order(p).into_iter().find_map(|q| {
+                self.hands[q]
+                    .iter()
+                    .position(|&c| party::card_item(c) == Some(item))
+                    .map(|i| (q, i))
+            });
+            let mut line = vec![
+                self.render_name(p),
+                N::text(" used a "),
+                render::card(Card::Spoon),
+                N::text(" asking for "),
+                N::Bold(vec![N::text(item.name())]),
+            ];
+            match giver {
+                Some((q, i)) => {
+                    let c = self.hands[q][i];
+                    self.hands[q][i] = Card::Spoon;
+                    self.hands[q] = sort_cards(self.hands[q].clone());
+                    self.played[p].push(c);
+                    line.extend(vec![
+                        N::text(", "),
+                        self.render_name(q),
+                        N::text(" gave them "),
+                        render::card(c),
+                    ]);
+                }
+                None => line.push(N::text(", but nobody had one")),
+            }
+            logs.push(Log::public(line));
+        }
+        logs
+    }
+
+    fn queue_pending(&mut self, revealed: &[Vec<Card>]) {
+        self.pending = vec![vec![]; self.all_players];
+        for (p, cards) in revealed.iter().enumerate() {
+            for &c in cards {
+                let others = |g: &Game| {
+                    g.played[p]
+                        .iter()
+                        .filter(|&&t| t != c && t != Card::Boxed)
+                        .count()
+                        > 0
+                };
                 match c {
-                    Card::MakiRoll1 => *maki_p += 1,
-                    Card::MakiRoll2 => *maki_p += 2,
-                    Card::MakiRoll3 => *maki_p += 3,
+                    Card::Menu => {
+                        let Some(i) = self.played[p].iter().rposition(|&t| t == Card::Menu) else {
+                            continue;
+                        };
+                        self.played[p].remove(i);
+                        let n = 4.min(self.deck.len());
+                        let drawn: Vec<Card> = self.deck.drain(..n).collect();
+                        self.deck.push(Card::Menu);
+                        self.deck.shuffle(&mut self.rng);
+                        if !drawn.is_empty() {
+                            self.pending[p].push(Pending::Menu(sort_cards(drawn)));
+                        }
+                    }
+                    Card::SpecialOrder if others(self) => {
+                        self.pending[p].push(Pending::SpecialOrder)
+                    }
+                    Card::TakeoutBox if others(self) => self.pending[p].push(Pending::TakeoutBox),
                     _ => {}
                 }
             }
         }
+    }
+
+    fn resolve_pending(&mut self, player: usize) -> Result<Vec<Log>, GameError> {
+        if !self.pending[player].is_empty() {
+            self.pending[player].remove(0);
+        }
+        if self.has_pending() {
+            return Ok(vec![]);
+        }
+        self.finish_hand()
+    }
+
+    pub fn pick(&mut self, player: usize, n: usize) -> Result<Vec<Log>, GameError> {
+        let Some(Pending::Menu(cards)) = self.pending_for(player).cloned() else {
+            return Err(GameError::invalid_input("you aren't choosing from a menu"));
+        };
+        let Some(&c) = cards.get(n) else {
+            return Err(GameError::invalid_input("that card number is not valid"));
+        };
+        self.played[player].push(c);
+        self.deck.extend(
+            cards
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != n)
+                .map(|(_, &c)| c),
+        );
+        self.deck.shuffle(&mut self.rng);
+        let mut logs = vec![Log::public(vec![
+            self.render_name(player),
+            N::text(" ordered "),
+            render::card(c),
+            N::text(" from the "),
+            render::card(Card::Menu),
+        ])];
+        logs.extend(self.resolve_pending(player)?);
+        Ok(logs)
+    }
+
+    pub fn copy(&mut self, player: usize, n: usize) -> Result<Vec<Log>, GameError> {
+        if self.pending_for(player) != Some(&Pending::SpecialOrder) {
+            return Err(GameError::invalid_input(
+                "you aren't placing a special order",
+            ));
+        }
+        let Some(&c) = self.played[player].get(n) else {
+            return Err(GameError::invalid_input("that card number is not valid"));
+        };
+        if matches!(c, Card::SpecialOrder | Card::Boxed) {
+            return Err(GameError::invalid_input(format!(
+                "you can't copy {}",
+                c.name()
+            )));
+        }
+        let Some(i) = self.played[player]
+            .iter()
+            .rposition(|&t| t == Card::SpecialOrder)
+        else {
+            return Err(GameError::internal("special order missing from tray"));
+        };
+        self.played[player][i] = c;
+        let mut logs = vec![Log::public(vec![
+            self.render_name(player),
+            N::text(" used a "),
+            render::card(Card::SpecialOrder),
+            N::text(" to copy "),
+            render::card(c),
+        ])];
+        logs.extend(self.resolve_pending(player)?);
+        Ok(logs)
+    }
+
+    pub fn takeout(&mut self, player: usize, cards: Vec<usize>) -> Result<Vec<Log>, GameError> {
+        if self.pending_for(player) != Some(&Pending::TakeoutBox) {
+            return Err(GameError::invalid_input("you aren't using a takeout box"));
+        }
+        let mut seen: HashSet<usize> = HashSet::new();
+        for &n in &cards {
+            match self.played[player].get(n) {
+                None => return Err(GameError::invalid_input("that card number is not valid")),
+                Some(Card::Boxed) | Some(Card::TakeoutBox) => {
+                    return Err(GameError::invalid_input(
+                        "you can't box boxed cards or takeout boxes",
+                    ));
+                }
+                Some(_) => {}
+            }
+            if !seen.insert(n) {
+                return Err(GameError::invalid_input("please specify different cards"));
+            }
+        }
+        let boxed: Vec<Card> = cards.iter().map(|&n| self.played[player][n]).collect();
+        for &n in &cards {
+            self.played[player][n] = Card::Boxed;
+        }
+        let mut logs = vec![Log::public(vec![
+            self.render_name(player),
+            N::text(" put "),
+            render::cards_list(&boxed),
+            N::text(" in a "),
+            render::card(Card::TakeoutBox),
+        ])];
+        logs.extend(self.resolve_pending(player)?);
+        Ok(logs)
+    }
+
+    pub fn pass(&mut self, player: usize) -> Result<Vec<Log>, GameError> {
+        if self.pending_for(player) != Some(&Pending::TakeoutBox) {
+            return Err(GameError::invalid_input("you aren't using a takeout box"));
+        }
+        self.resolve_pending(player)
+    }
+
+    /// Awards uramaki to players reaching 10 symbols this hand, discarding
+    /// the uramaki of those who score.
+    fn score_uramaki(&mut self) -> Vec<Log> {
+        let mut logs = vec![];
+        let counts: Vec<i32> = (0..self.all_players)
+            .map(|p| {
+                let n = self.uramaki_count(p);
+                if n >= URAMAKI_TARGET { n } else { 0 }
+            })
+            .collect();
+        let awards = &URAMAKI_AWARDS[self.uramaki_awarded.min(URAMAKI_AWARDS.len())..];
+        for (players, count, points) in party::majority_awards(&counts, awards) {
+            self.uramaki_awarded += players.len();
+            let mut line = vec![
+                render::comma_list_nodes(self.render_names(&players)),
+                N::text(" reached "),
+                N::Bold(vec![N::text(count.to_string())]),
+                N::text(" "),
+                render::card(Card::Uramaki3),
+                N::text(" symbols"),
+            ];
+            line.extend(points_nodes(points));
+            logs.push(Log::public(line));
+            for p in players {
+                self.player_points[p] += points;
+                self.played[p].retain(|c| c.uramaki() == 0);
+            }
+        }
+        logs
+    }
+
+    fn uramaki_count(&self, player: usize) -> i32 {
+        self.played[player].iter().map(|c| c.uramaki()).sum()
+    }
+
+    /// Pushes output for the most/least scoring used by puddings and
+    /// temaki.
+    fn score_most_least(
+        &self,
+        counts: &[i32],
+        card_str: N,
+        points: i32,
+        scores: &mut [i32],
+        output: &mut Vec<Vec<N>>,
+    ) {
         let mut first = 0i32;
         let mut first_players: Vec<usize> = vec![];
-        let mut second = 0i32;
-        let mut second_players: Vec<usize> = vec![];
-        for (p, &m) in maki.iter().enumerate() {
-            if m > first {
-                second = first;
-                second_players = first_players.clone();
-                first = m;
+        let mut last = 0i32;
+        let mut last_players: Vec<usize> = vec![];
+        for (p, &c) in counts.iter().enumerate() {
+            if c > first {
+                first = c;
                 first_players = vec![];
             }
-            if m == first {
+            if c == first {
                 first_players.push(p);
-            } else if m > second {
-                second = m;
-                second_players = vec![];
             }
-            if m != first && m == second {
-                second_players.push(p);
+            if c < last || last_players.is_empty() {
+                last = c;
+                last_players = vec![];
+            }
+            if c == last {
+                last_players.push(p);
             }
         }
-        let maki_str = N::Fg(
-            brdgme_color::NamedColor::Red.into(),
-            vec![N::Bold(vec![N::text("maki rolls")])],
-        );
-        if first == 0 {
+        if first == last {
             output.push(vec![
-                N::text("Nobody had "),
-                maki_str,
+                N::text("Everybody had the same number of "),
+                card_str,
                 N::text(", no points awarded"),
             ]);
-        } else {
-            let first_points = 6 / first_players.len() as i32;
+            return;
+        }
+        let first_points = points / first_players.len() as i32;
+        output.push(vec![
+            render::comma_list_nodes(self.render_names(&first_players)),
+            N::text(" had "),
+            N::Bold(vec![N::text(first.to_string())]),
+            N::text(" "),
+            card_str.clone(),
+            N::text(", awarding "),
+            N::Bold(vec![N::text(first_points.to_string())]),
+            N::text(" points"),
+        ]);
+        for &p in &first_players {
+            scores[p] += first_points;
+        }
+        if self.players != 2 {
+            let last_points = -points / last_players.len() as i32;
             output.push(vec![
-                render::comma_list_nodes(self.render_names(&first_players)),
+                render::comma_list_nodes(self.render_names(&last_players)),
                 N::text(" had "),
-                N::Bold(vec![N::text(first.to_string())]),
+                N::Bold(vec![N::text(last.to_string())]),
                 N::text(" "),
-                maki_str.clone(),
+                card_str,
                 N::text(", awarding "),
-                N::Bold(vec![N::text(first_points.to_string())]),
+                N::Bold(vec![N::text(last_points.to_string())]),
                 N::text(" points"),
             ]);
-            for &p in &first_players {
-                scores[p] += first_points;
+            for &p in &last_players {
+                scores[p] += last_points;
             }
-            if first_players.len() == 1 && second > 0 {
-                let second_points = 3 / second_players.len() as i32;
-                output.push(vec![
-                    render::comma_list_nodes(self.render_names(&second_players)),
-                    N::text(" had "),
-                    N::Bold(vec![N::text(second.to_string())]),
-                    N::text(" "),
-                    maki_str,
-                    N::text(", awarding "),
-                    N::Bold(vec![N::text(second_points.to_string())]),
-                    N::text(" points"),
-                ]);
-                for &p in &second_players {
-                    scores[p] += second_points;
-                }
+        }
+    }
+
+    /// Pushes output for majority awards such as maki and leftover uramaki.
+    fn score_majority(
+        &self,
+        counts: &[i32],
+        card_str: N,
+        awards: &[i32],
+        scores: &mut [i32],
+        output: &mut Vec<Vec<N>>,
+    ) {
+        let given = party::majority_awards(counts, awards);
+        if given.is_empty() {
+            output.push(vec![
+                N::text("Nobody had "),
+                card_str,
+                N::text(", no points awarded"),
+            ]);
+            return;
+        }
+        for (players, count, points) in given {
+            output.push(vec![
+                render::comma_list_nodes(self.render_names(&players)),
+                N::text(" had "),
+                N::Bold(vec![N::text(count.to_string())]),
+                N::text(" "),
+                card_str.clone(),
+                N::text(", awarding "),
+                N::Bold(vec![N::text(points.to_string())]),
+                N::text(" points"),
+            ]);
+            for p in players {
+                scores[p] += points;
             }
         }
+    }
+
+    fn on_menu(&self, item: MenuItem) -> bool {
+        match self.menu {
+            Some(ref menu) => menu.has(item),
+            None => matches!(item, MenuItem::Maki | MenuItem::Pudding),
+        }
+    }
+
+    pub fn score(&self) -> (Vec<i32>, Vec<Vec<N>>) {
+        let mut scores = vec![0i32; self.all_players];
+        let mut output: Vec<Vec<N>> = vec![];
+        let count_by = |f: &dyn Fn(Card) -> i32| -> Vec<i32> {
+            (0..self.all_players)
+                .map(|p| self.played[p].iter().map(|&c| f(c)).sum())
+                .collect()
+        };
+
+        // Score maki
+        if self.on_menu(MenuItem::Maki) {
+            let awards: &[i32] = if self.players > MAX_CLASSIC_PLAYERS {
+                &[6, 4, 2]
+            } else {
+                &[6, 3]
+            };
+            let maki_str = N::Fg(
+                brdgme_color::NamedColor::Red.into(),
+                vec![N::Bold(vec![N::text("maki rolls")])],
+            );
+            self.score_majority(
+                &count_by(&Card::maki),
+                maki_str,
+                awards,
+                &mut scores,
+                &mut output,
+            );
+        }
+
+        // Score temaki
+        if self.on_menu(MenuItem::Temaki) {
+            let temaki_str = N::Fg(
+                brdgme_color::NamedColor::Red.into(),
+                vec![N::Bold(vec![N::text("temaki")])],
+            );
+            self.score_most_least(
+                &count_by(&|c| (c == Card::Temaki) as i32),
+                temaki_str,
+                4,
+                &mut scores,
+                &mut output,
+            );
+        }
+
+        // Score the uramaki awards nobody reached during the round
+        if self.on_menu(MenuItem::Uramaki) && self.uramaki_awarded < URAMAKI_AWARDS.len() {
+            let uramaki_str = N::Fg(
+                brdgme_color::NamedColor::Red.into(),
+                vec![N::Bold(vec![N::text("uramaki")])],
+            );
+            self.score_majority(
+                &count_by(&Card::uramaki),
+                uramaki_str,
+                &URAMAKI_AWARDS[self.uramaki_awarded..],
+                &mut scores,
+                &mut output,
+            );
+        }
 
         // Score puddings (round 3 only)
-        if self.round == TOTAL_ROUNDS {
-            let mut pudding = vec![0i32; self.all_players];
-            for (p, pudding_p) in pudding.iter_mut().enumerate() {
-                for &c in &self.played[p] {
-                    if c == Card::Pudding {
-                        *pudding_p += 1;
-                    }
-                }
-            }
-            let mut first = 0i32;
-            let mut first_players: Vec<usize> = vec![];
-            let mut last = 0i32;
-            let mut last_players: Vec<usize> = vec![];
-            for (p, &c) in pudding.iter().enumerate() {
-                if c > first {
-                    first = c;
-                    first_players = vec![];
-                }
-                if c == first {
-                    first_players.push(p);
-                }
-                if c < last || last_players.is_empty() {
-                    last = c;
-                    last_players = vec![];
-                }
-                if c == last {
-                    last_players.push(p);
-                }
-            }
+        if self.round == TOTAL_ROUNDS && self.on_menu(MenuItem::Pudding) {
             let puddings_str = N::Fg(
                 brdgme_color::NamedColor::Blue.into(),
                 vec![N::Bold(vec![N::text("puddings")])],
             );
-            if first == last {
-                output.push(vec![
-                    N::text("Everybody had the same number of "),
-                    puddings_str,
-                    N::text(", no points awarded"),
-                ]);
-            } else {
-                let first_points = 6 / first_players.len() as i32;
-                output.push(vec![
-                    render::comma_list_nodes(self.render_names(&first_players)),
-                    N::text(" had "),
-                    N::Bold(vec![N::text(first.to_string())]),
-                    N::text(" "),
-                    puddings_str.clone(),
-                    N::text(", awarding "),
-                    N::Bold(vec![N::text(first_points.to_string())]),
-                    N::text(" points"),
-                ]);
-                for &p in &first_players {
-                    scores[p] += first_points;
-                }
-                if self.players != 2 {
-                    let last_points = -6 / last_players.len() as i32;
-                    output.push(vec![
-                        render::comma_list_nodes(self.render_names(&last_players)),
-                        N::text(" had "),
-                        N::Bold(vec![N::text(last.to_string())]),
-                        N::text(" "),
-                        puddings_str,
-                        N::text(", awarding "),
-                        N::Bold(vec![N::text(last_points.to_string())]),
-                        N::text(" points"),
-                    ]);
-                    for &p in &last_players {
-                        scores[p] += last_points;
-                    }
-                }
-            }
+            self.score_most_least(
+                &count_by(&|c| (c == Card::Pudding) as i32),
+                puddings_str,
+                6,
+                &mut scores,
+                &mut output,
+            );
         }
 
+        let edamame_players = (0..self.all_players)
+            .filter(|&p| self.played[p].contains(&Card::Edamame))
+            .count() as i32;
+        let items: Vec<usize> = (0..self.all_players)
+            .map(|p| {
+                self.played[p]
+                    .iter()
+                    .filter_map(|&c| party::card_item(c))
+                    .collect::<HashSet<_>>()
+                    .len()
+            })
+            .collect();
+        let most_items = items.iter().copied().max().unwrap_or(0);
+
         // Score normal cards
         for (p, score_p) in scores.iter_mut().enumerate() {
             output.push(vec![N::Bold(vec![
@@ -627,6 +1194,120 @@ impl Game {
                 ]);
                 *score_p += s;
             }
+            if self.menu.is_none() {
+                continue;
+            }
+
+            // Party cards
+            let edamame_count = get(&counts, Card::Edamame);
+            if edamame_count > 0 {
+                let s = edamame_count * (edamame_players - 1).min(4);
+                output.push(count_line(edamame_count, Card::Edamame, s));
+                *score_p += s;
+            }
+            let eel_count = get(&counts, Card::Eel);
+            if eel_count > 0 {
+                let s = if eel_count == 1 { -3 } else { 7 };
+                output.push(count_line(eel_count, Card::Eel, s));
+                *score_p += s;
+            }
+            let mut shapes: Vec<i32> = [
+                Card::OnigiriCircle,
+                Card::OnigiriTriangle,
+                Card::OnigiriSquare,
+                Card::OnigiriRectangle,
+            ]
+            .iter()
+            .map(|&c| get(&counts, c))
+            .collect();
+            let onigiri_count: i32 = shapes.iter().sum();
+            if onigiri_count > 0 {
+                let mut s = 0;
+                while shapes.iter().any(|&n| n > 0) {
+                    let set = shapes.iter().filter(|&&n| n > 0).count();
+                    s += party::onigiri_set_score(set);
+                    for n in shapes.iter_mut() {
+                        *n = (*n - 1).max(0);
+                    }
+                }
+                output.push(count_line(onigiri_count, Card::OnigiriCircle, s));
+                *score_p += s;
+            }
+            let miso_count = get(&counts, Card::MisoSoup);
+            if miso_count > 0 {
+                let s = miso_count * 3;
+                output.push(count_line(miso_count, Card::MisoSoup, s));
+                *score_p += s;
+            }
+            let tofu_count = get(&counts, Card::Tofu);
+            if tofu_count > 0 {
+                let s = match tofu_count {
+                    1 => 2,
+                    2 => 6,
+                    _ => 0,
+                };
+                output.push(count_line(tofu_count, Card::Tofu, s));
+                *score_p += s;
+            }
+            let tea_count = get(&counts, Card::Tea);
+            if tea_count > 0 {
+                let mut by_item: Vec<(MenuItem, i32)> = vec![];
+                for item in self.played[p].iter().filter_map(|&c| party::card_item(c)) {
+                    match by_item.iter_mut().find(|(i, _)| *i == item) {
+                        Some(entry) => entry.1 += 1,
+                        None => by_item.push((item, 1)),
+                    }
+                }
+                let biggest = by_item.iter().map(|(_, n)| *n).max().unwrap_or(0);
+                let s = tea_count * biggest;
+                output.push(count_line(tea_count, Card::Tea, s));
+                *score_p += s;
+            }
+            let soy_count = get(&counts, Card::SoySauce);
+            if soy_count > 0 {
+                let s = if items[p] == most_items {
+                    soy_count * 4
+                } else {
+                    0
+                };
+                output.push(count_line(soy_count, Card::SoySauce, s));
+                *score_p += s;
+            }
+            let boxed_count = get(&counts, Card::Boxed);
+            if boxed_count > 0 {
+                let s = boxed_count * 2;
+                output.push(count_line(boxed_count, Card::Boxed, s));
+                *score_p += s;
+            }
+            if self.round == TOTAL_ROUNDS {
+                let ice_cream_count = get(&counts, Card::GreenTeaIceCream);
+                if ice_cream_count > 0 {
+                    let s = ice_cream_count / 4 * 12;
+                    output.push(count_line(ice_cream_count, Card::GreenTeaIceCream, s));
+                    *score_p += s;
+                }
+                if self.on_menu(MenuItem::Fruit) {
+                    let mut fruit = [0i32; 3];
+                    let mut fruit_count = 0;
+                    for c in &self.played[p] {
+                        let f = c.fruit();
+                        if f != [0, 0, 0] {
+                            fruit_count += 1;
+                        }
+                        for (total, n) in fruit.iter_mut().zip(f) {
+                            *total += n;
+                        }
+                    }
+                    let s: i32 = fruit.iter().map(|&n| party::fruit_score(n)).sum();
+                    let mut line = vec![N::text(format!(
+                        "{} x fruit ({} watermelon, {} pineapple, {} orange)",
+                        fruit_count, fruit[0], fruit[1], fruit[2]
+                    ))];
+                    line.extend(points_nodes(s));
+                    output.push(line);
+                    *score_p += s;
+                }
+            }
         }
         (scores, output)
     }
@@ -678,7 +1359,7 @@ impl Game {
                     "you can only play a second card if you've previously played chopsticks",
                 ));
             }
-            if self.players == 2
+            if self.has_dummy()
                 && player == self.controller
                 && self.playing[DUMMY].is_none()
                 && self.hands[player].len() == 2
@@ -691,6 +1372,36 @@ impl Game {
         self.play_cards(player, player, &cards)
     }
 
+    /// Plays a card and uses a spoon from the tray to ask for an item when
+    /// the cards are revealed.
+    pub fn spoon(
+        &mut self,
+        player: usize,
+        card: usize,
+        item: MenuItem,
+    ) -> Result<Vec<Log>, GameError> {
+        if !self.can_spoon(player) {
+            return Err(GameError::invalid_input(
+                "you need a spoon on your tray to use one",
+            ));
+        }
+        if !self.on_menu(item) {
+            return Err(GameError::invalid_input(format!(
+                "{} isn't on the menu",
+                item.name()
+            )));
+        }
+        if self.spoons.len() < self.all_players {
+            self.spoons.resize(self.all_players, None);
+        }
+        self.spoons[player] = Some(item);
+        let result = self.play_cards(player, player, &[card]);
+        if result.is_err() {
+            self.spoons[player] = None;
+        }
+        result
+    }
+
     pub fn dummy(&mut self, player: usize, card: usize) -> Result<Vec<Log>, GameError> {
         if !self.can_dummy(player) {
             return Err(GameError::invalid_input("you can't dummy at the moment"));
@@ -738,6 +1449,63 @@ impl Gamer for Game {
     type PlayerState = PlayerState;
 
     fn start(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError> {
+        Self::start_with_options(players, seed, &GameOptions::new())
+    }
+
+    fn options() -> Vec<GameOption> {
+        let mut menus: Vec<(&str, &str)> = vec![(
+            MENU_CLASSIC,
+            "Sushi Go (the original deck, or the Sushi Go! menu for 6+ players)",
+        )];
+        let presets = party::preset_menus();
+        menus.extend(presets.iter().map(|(v, n, _)| (*v, *n)));
+        menus.push((MENU_RANDOM, "Random menu"));
+        menus.push((MENU_CUSTOM, "Custom menu"));
+        let mut options = vec![
+            GameOption::new(
+                MENU_OPTION,
+                "Menu",
+                "Play the original game or a Sushi Go Party menu",
+                &menus,
+            ),
+            GameOption::new(
+                ROLL_OPTION,
+                "Roll",
+                "The roll on a custom menu",
+                &party::item_values(&ROLLS, 0),
+            ),
+        ];
+        for (i, key) in APPETIZER_OPTIONS.iter().enumerate() {
+            options.push(GameOption::new(
+                key,
+                &format!("Appetizer {}", i + 1),
+                "An appetizer on a custom menu",
+                &party::item_values(&APPETIZERS, i),
+            ));
+        }
+        for (i, key) in SPECIAL_OPTIONS.iter().enumerate() {
+            options.push(GameOption::new(
+                key,
+                &format!("Special {}", i + 1),
+                "A special on a custom menu",
+                &party::item_values(&SPECIALS, i),
+            ));
+        }
+        options.push(GameOption::new(
+            DESSERT_OPTION,
+            "Dessert",
+            "The dessert on a custom menu",
+            &party::item_values(&DESSERTS, 0),
+        ));
+        options
+    }
+
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let options = options.resolve(&Self::options())?;
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
             return Err(GameError::PlayerCount {
                 min: MIN_PLAYERS,
@@ -745,24 +1513,41 @@ impl Gamer for Game {
                 given: players,
             });
         }
+        let mut rng = GameRng::seed_from_u64(seed);
+        let menu = party::chosen_menu(&options, players, &mut rng)?;
+        let has_dummy = menu.is_none() && players == 2;
         let mut g = Game {
             players,
-            all_players: if players == 2 { players + 1 } else { players },
+            all_players: if has_dummy { players + 1 } else { players },
             round: 0,
-            deck: deck(),
+            deck: if menu.is_none() { deck() } else { vec![] },
             hands: vec![],
             playing: vec![],
             played: vec![],
             player_points: vec![],
             controller: 0,
-            rng: GameRng::seed_from_u64(seed),
+            rng,
+            desserts: menu.as_ref().map(Menu::dessert_cards).unwrap_or_default(),
+            menu,
+            spoons: vec![],
+            pending: vec![],
+            uramaki_awarded: 0,
         };
         g.deck.shuffle(&mut g.rng);
+        g.desserts.shuffle(&mut g.rng);
         g.playing = vec![None; g.all_players];
         g.played = vec![vec![]; g.all_players];
         g.player_points = vec![0; g.all_players];
+        g.spoons = vec![None; g.all_players];
+        g.pending = vec![vec![]; g.all_players];
         let mut logs = vec![];
-        if players == 2 {
+        if let Some(ref menu) = g.menu {
+            logs.push(Log::public(vec![
+                N::text("Tonight's menu is "),
+                render::menu_nodes(menu),
+            ]));
+        }
+        if has_dummy {
             logs.push(Log::public(vec![
                 N::text("Because there are only two players, you will be joined by "),
                 g.render_name(DUMMY),
@@ -779,7 +1564,13 @@ impl Gamer for Game {
                 self.players
             )));
         }
-        let expected_all = if self.players == 2 { 3 } else { self.players };
+        if self.menu.is_none() && self.players > MAX_CLASSIC_PLAYERS {
+            return Err(GameError::internal(format!(
+                "sushi-go-2: {} players needs a Party menu",
+                self.players
+            )));
+        }
+        let expected_all = if self.has_dummy() { 3 } else { self.players };
         if self.all_players != expected_all {
             return Err(GameError::internal(format!(
                 "sushi-go-2: all_players {} does not match players {}",
@@ -800,6 +1591,9 @@ impl Gamer for Game {
                 "sushi-go-2: player_points length mismatch",
             ));
         }
+        if self.menu.is_some() && self.pending.len() != self.all_players {
+            return Err(GameError::internal("sushi-go-2: pending length mismatch"));
+        }
         if self.controller >= self.players {
             return Err(GameError::internal("sushi-go-2: controller out of range"));
         }
@@ -817,6 +1611,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -843,6 +1639,7 @@ impl Gamer for Game {
             } else {
                 vec![]
             },
+            menu: self.menu.clone(),
         }
     }
 
@@ -860,11 +1657,13 @@ impl Gamer for Game {
             } else {
                 None
             },
-            dummy_playing: if self.players == 2 && player == self.controller {
+            dummy_playing: if self.has_dummy() && player == self.controller {
                 self.playing.get(DUMMY).cloned().flatten()
             } else {
                 None
             },
+            spoon: self.spoons.get(player).copied().flatten(),
+            pending: self.pending.get(player).cloned().unwrap_or_default(),
         }
     }
 
@@ -895,6 +1694,31 @@ impl Gamer for Game {
                 value: Command::Dummy(card),
                 ..
             }) => (self.dummy(player, card)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Spoon(card, item),
+                ..
+            }) => (self.spoon(player, card, item)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Pick(n),
+                ..
+            }) => (self.pick(player, n)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Copy(n),
+                ..
+            }) => (self.copy(player, n)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Box(cards),
+                ..
+            }) => (self.takeout(player, cards)?, false, remaining),
+            Ok(ParseOutput {
+                remaining,
+                value: Command::Pass,
+                ..
+            }) => (self.pass(player)?, false, remaining),
             Err(e) => return Err(GameError::invalid_input(e.to_string())),
         };
         if !was_finished && self.is_finished() {
@@ -918,7 +1742,7 @@ impl Gamer for Game {
     }
 
     fn player_counts() -> Vec<usize> {
-        vec![2, 3, 4, 5]
+        (MIN_PLAYERS..=MAX_PLAYERS).collect()
     }
 
     fn player_count(&self) -> usize {
@@ -1269,11 +2093,12 @@ mod tests {
 
     #[test]
     fn test_player_counts() {
-        assert_eq!(vec![2, 3, 4, 5], Game::player_counts());
+        assert_eq!(vec![2, 3, 4, 5, 6, 7, 8], Game::player_counts());
         assert!(Game::start(1, 1).is_err());
-        assert!(Game::start(6, 1).is_err());
+        assert!(Game::start(9, 1).is_err());
         assert!(Game::start(2, 1).is_ok());
         assert!(Game::start(5, 1).is_ok());
+        assert!(Game::start(8, 1).is_ok());
     }
 
     #[test]
@@ -1862,4 +2687,407 @@ mod tests {
         assert!(text.contains("Passing hands"), "{}", text);
         assert!(!text.contains(hidden_card.name()), "{}", text);
     }
+
+    // --- Sushi Go Party ---
+
+    fn party_game(players: usize, menu: &str) -> Game {
+        let options = GameOptions::new().with(MENU_OPTION, menu);
+        Game::start_with_options(players, 1, &options).unwrap().0
+    }
+
+    fn party_names(players: usize) -> Vec<String> {
+        (0..players).map(|p| format!("Player {}", p)).collect()
+    }
+
+    #[test]
+    fn party_menu_builds_the_deck() {
+        let g = party_game(4, "master_menu");
+        let menu = g.menu.clone().unwrap();
+        assert_eq!(MenuItem::Temaki, menu.roll);
+        assert_eq!(4, g.all_players);
+        assert_eq!(9, g.hands[0].len());
+        let dealt: Vec<Card> = g.hands.iter().flatten().copied().collect();
+        let all: Vec<Card> = dealt.iter().chain(g.deck.iter()).copied().collect();
+        assert_eq!(54 + 5, all.len());
+        assert!(
+            all.iter()
+                .all(|&c| { party::card_item(c).is_some_and(|i| menu.has(i)) })
+        );
+        assert_eq!(5, all.iter().filter(|c| c.is_dessert()).count());
+        assert_eq!(10, g.desserts.len());
+        assert!(g.validate().is_ok());
+    }
+
+    #[test]
+    fn party_custom_menu_from_options() {
+        let options = GameOptions::new()
+            .with(MENU_OPTION, MENU_CUSTOM)
+            .with(ROLL_OPTION, "uramaki")
+            .with(APPETIZER_OPTIONS[0], "eel")
+            .with(SPECIAL_OPTIONS[1], "soy_sauce")
+            .with(DESSERT_OPTION, "fruit");
+        let (g, _) = Game::start_with_options(3, 1, &options).unwrap();
+        let menu = g.menu.unwrap();
+        assert_eq!(MenuItem::Uramaki, menu.roll);
+        assert_eq!(
+            vec![MenuItem::Eel, MenuItem::Sashimi, MenuItem::Dumpling],
+            menu.appetizers
+        );
+        assert_eq!(
+            vec![MenuItem::Chopsticks, MenuItem::SoySauce],
+            menu.specials
+        );
+        assert_eq!(MenuItem::Fruit, menu.dessert);
+
+        let options = options.with(APPETIZER_OPTIONS[1], "eel");
+        assert!(Game::start_with_options(3, 1, &options).is_err());
+    }
+
+    #[test]
+    fn party_random_menu_is_complete() {
+        let g = party_game(5, MENU_RANDOM);
+        let menu = g.menu.unwrap();
+        assert_eq!(3, menu.appetizers.len());
+        assert_eq!(2, menu.specials.len());
+        assert!(menu.dessert.is_dessert());
+    }
+
+    #[test]
+    fn party_two_players_have_no_dummy() {
+        let g = party_game(2, "dinner_for_two");
+        assert!(!g.has_dummy());
+        assert_eq!(2, g.all_players);
+        assert_eq!(10, g.hands[0].len());
+        assert_eq!(10, g.hands[1].len());
+        assert!(!g.can_dummy(0));
+        assert!(g.validate().is_ok());
+    }
+
+    #[test]
+    fn classic_menu_uses_party_cards_above_five_players() {
+        let g = party_game(5, MENU_CLASSIC);
+        assert!(g.menu.is_none());
+        let (g, _) = Game::start(8, 1).unwrap();
+        assert_eq!(Some("sushi_go"), {
+            let menu = g.menu.clone().unwrap();
+            party::preset_menus()
+                .into_iter()
+                .find(|(_, _, m)| *m == menu)
+                .map(|(v, _, _)| v)
+        });
+        assert_eq!(7, g.hands[7].len());
+        assert!(g.validate().is_ok());
+        let mut classic = g.clone();
+        classic.menu = None;
+        assert!(classic.validate().is_err());
+    }
+
+    #[test]
+    fn party_maki_pays_three_places_above_five_players() {
+        let mut g = party_game(6, "sushi_go");
+        g.played = vec![
+            vec![Card::MakiRoll3],
+            vec![Card::MakiRoll2],
+            vec![Card::MakiRoll1],
+            vec![],
+            vec![],
+            vec![],
+        ];
+        let (score, _) = g.score();
+        assert_eq!(vec![6, 4, 2, 0, 0, 0], score);
+    }
+
+    #[test]
+    fn party_temaki_most_and_least() {
+        let mut g = party_game(3, "party_sampler");
+        g.played = vec![vec![Card::Temaki, Card::Temaki], vec![Card::Temaki], vec![]];
+        let (score, _) = g.score();
+        assert_eq!(vec![4, 0, -4], score);
+
+        let mut g = party_game(2, "party_sampler");
+        g.played = vec![vec![Card::Temaki], vec![]];
+        let (score, _) = g.score();
+        assert_eq!(vec![4, 0], score);
+    }
+
+    #[test]
+    fn party_appetizer_scoring() {
+        let mut g = party_game(3, "points_platter");
+        g.played = vec![
+            vec![
+                Card::Edamame,
+                Card::Edamame,
+                Card::OnigiriCircle,
+                Card::OnigiriSquare,
+                Card::OnigiriCircle,
+            ],
+            vec![Card::Edamame],
+            vec![],
+        ];
+        let (score, _) = g.score();
+        // Edamame: 2 x 1 opponent, onigiri: a set of 2 and a set of 1.
+        assert_eq!(vec![2 + 4 + 1, 1, 0], score);
+
+        let mut g = party_game(3, "cutthroat_combo");
+        g.played = vec![
+            vec![Card::Eel],
+            vec![Card::Eel, Card::Eel, Card::Tofu, Card::Tofu],
+            vec![Card::Tofu, Card::Tofu, Card::Tofu, Card::MisoSoup],
+        ];
+        let (score, _) = g.score();
+        assert_eq!(vec![-3, 7 + 6, 3], score);
+    }
+
+    #[test]
+    fn party_special_scoring() {
+        let mut g = party_game(3, "my_first_meal");
+        g.played = vec![
+            vec![Card::Tea, Card::Tempura, Card::Tempura, Card::Tempura],
+            vec![Card::Boxed, Card::Boxed],
+            vec![],
+        ];
+        let (score, _) = g.score();
+        assert_eq!(vec![5 + 3, 4, 0], score);
+
+        let mut g = party_game(3, "cutthroat_combo");
+        g.played = vec![
+            vec![Card::SoySauce, Card::Eel, Card::Tofu],
+            vec![Card::SoySauce, Card::MisoSoup, Card::Temaki],
+            vec![Card::SoySauce],
+        ];
+        let (score, _) = g.score();
+        // Soy sauce pays the first two, tied on 3 items, and the last two
+        // split the temaki penalty.
+        assert_eq!(vec![4 - 3 + 2 - 2, 4 + 3 + 4, -2], score);
+    }
+
+    #[test]
+    fn party_desserts_score_in_the_last_round() {
+        let mut g = party_game(3, "my_first_meal");
+        g.played = vec![vec![Card::GreenTeaIceCream; 5], vec![], vec![]];
+        assert_eq!(vec![0, 0, 0], g.score().0);
+        g.round = 3;
+        assert_eq!(vec![12, 0, 0], g.score().0);
+
+        let mut g = party_game(3, "master_menu");
+        g.round = 3;
+        g.played = vec![
+            vec![
+                Card::FruitWatermelons,
+                Card::FruitWatermelonOrange,
+                Card::FruitWatermelonPineapple,
+            ],
+            vec![],
+            vec![],
+        ];
+        // 4 watermelon, 1 pineapple, 1 orange.
+        assert_eq!(vec![6, -6, -6], g.score().0);
+    }
+
+    #[test]
+    fn party_desserts_stay_on_trays() {
+        let mut g = party_game(3, "sushi_go");
+        g.played[0] = vec![Card::Pudding, Card::Tempura];
+        g.hands = vec![vec![]; 3];
+        g.playing = vec![
+            Some(vec![Card::Sashimi]),
+            Some(vec![Card::Sashimi]),
+            Some(vec![Card::Sashimi]),
+        ];
+        g.end_hand().unwrap();
+        assert_eq!(2, g.round);
+        assert_eq!(vec![Card::Pudding], g.played[0]);
+        assert_eq!(10, g.hands[0].len());
+        assert_eq!(7, g.desserts.len());
+    }
+
+    #[test]
+    fn party_miso_clash_discards_all() {
+        let mut g = party_game(3, "my_first_meal");
+        g.hands = vec![vec![Card::Tea; 2], vec![Card::Tea; 2], vec![Card::Tea; 2]];
+        g.playing = vec![
+            Some(vec![Card::MisoSoup]),
+            Some(vec![Card::MisoSoup]),
+            Some(vec![Card::Tempura]),
+        ];
+        g.end_hand().unwrap();
+        assert!(g.played[0].is_empty());
+        assert!(g.played[1].is_empty());
+        assert_eq!(vec![Card::Tempura], g.played[2]);
+    }
+
+    #[test]
+    fn party_spoon_takes_from_the_next_hand() {
+        let n = party_names(3);
+        let mut g = party_game(3, "cutthroat_combo");
+        g.played[0] = vec![Card::Spoon];
+        // Round 1 passes left, so player 0 asks player 2 first.
+        g.hands = vec![
+            vec![Card::Tofu, Card::Tofu],
+            vec![Card::Eel, Card::Temaki],
+            vec![Card::Temaki, Card::Tofu],
+        ];
+        assert!(g.command(1, "spoon 1 eel", &n).is_err());
+        g.command(0, "spoon 1 temaki", &n).unwrap();
+        assert_eq!(Some(MenuItem::Temaki), g.player_state(0).spoon);
+        g.command(1, "play 1", &n).unwrap();
+        g.command(2, "play 2", &n).unwrap();
+        assert_eq!(vec![Card::Tofu, Card::Temaki], g.played[0]);
+        assert_eq!(vec![Card::Tofu], g.played[2]);
+        // Player 2's hand had Temaki swapped for the spoon before passing.
+        assert_eq!(vec![Card::Spoon], g.hands[1]);
+    }
+
+    #[test]
+    fn party_menu_card_orders_from_the_deck() {
+        let n = party_names(3);
+        let mut g = party_game(3, "party_sampler");
+        g.hands = vec![
+            vec![Card::Menu, Card::Tofu],
+            vec![Card::Tofu; 2],
+            vec![Card::Tofu; 2],
+        ];
+        let deck_len = g.deck.len();
+        g.command(0, "play 1", &n).unwrap();
+        g.command(1, "play 1", &n).unwrap();
+        g.command(2, "play 1", &n).unwrap();
+        let Some(Pending::Menu(drawn)) = g.pending_for(0).cloned() else {
+            panic!("expected a menu choice");
+        };
+        assert_eq!(4, drawn.len());
+        assert_eq!(vec![0], g.whose_turn());
+        assert!(g.command(0, "play 1", &n).is_err());
+        assert!(g.played[0].is_empty());
+        g.command(0, "pick 2", &n).unwrap();
+        assert_eq!(vec![drawn[1]], g.played[0]);
+        assert_eq!(deck_len, g.deck.len());
+        assert!(!g.has_pending());
+        assert_eq!(1, g.hands[0].len());
+    }
+
+    #[test]
+    fn party_special_order_copies_a_tray_card() {
+        let n = party_names(3);
+        let mut g = party_game(3, "points_platter");
+        g.played[1] = vec![Card::Dumpling];
+        g.hands = vec![
+            vec![Card::Edamame; 2],
+            vec![Card::SpecialOrder, Card::Edamame],
+            vec![Card::SpecialOrder, Card::Edamame],
+        ];
+        for p in 0..3 {
+            g.command(p, "play 1", &n).unwrap();
+        }
+        // Player 2 had nothing to copy, so only player 1 chooses.
+        assert_eq!(vec![1], g.whose_turn());
+        assert!(g.command(1, "copy 2", &n).is_err());
+        g.command(1, "copy 1", &n).unwrap();
+        assert_eq!(vec![Card::Dumpling, Card::Dumpling], g.played[1]);
+        assert_eq!(vec![Card::SpecialOrder], g.played[2]);
+    }
+
+    #[test]
+    fn party_takeout_box_boxes_cards() {
+        let n = party_names(3);
+        let mut g = party_game(3, "master_menu");
+        g.played[0] = vec![Card::Tofu, Card::Tofu, Card::Tofu];
+        g.played[1] = vec![Card::Temaki];
+        g.hands = vec![
+            vec![Card::TakeoutBox, Card::Temaki],
+            vec![Card::TakeoutBox, Card::Temaki],
+            vec![Card::Temaki; 2],
+        ];
+        for p in 0..3 {
+            g.command(p, "play 1", &n).unwrap();
+        }
+        assert_eq!(vec![0, 1], g.whose_turn());
+        assert!(g.command(0, "box 4", &n).is_err());
+        g.command(0, "box 1 2", &n).unwrap();
+        g.command(1, "pass", &n).unwrap();
+        assert_eq!(
+            vec![Card::Boxed, Card::Boxed, Card::Tofu, Card::TakeoutBox],
+            g.played[0]
+        );
+        assert_eq!(vec![Card::Temaki, Card::TakeoutBox], g.played[1]);
+        assert!(!g.has_pending());
+    }
+
+    #[test]
+    fn party_uramaki_scores_during_the_round() {
+        let mut g = party_game(3, "points_platter");
+        g.played = vec![
+            vec![Card::Uramaki5, Card::Uramaki4],
+            vec![Card::Uramaki5, Card::Uramaki3],
+            vec![Card::Uramaki3],
+        ];
+        g.hands = vec![vec![Card::Tea; 2], vec![Card::Tea; 2], vec![Card::Tea; 2]];
+        g.playing = vec![
+            Some(vec![Card::Uramaki4]),
+            Some(vec![Card::Uramaki3]),
+            Some(vec![Card::Uramaki4]),
+        ];
+        g.end_hand().unwrap();
+        assert_eq!(vec![8, 5, 0], g.player_points);
+        assert!(g.played[0].is_empty());
+        assert!(g.played[1].is_empty());
+        assert_eq!(2, g.uramaki_awarded);
+        // The last award goes to the most uramaki at the end of the round.
+        let (score, _) = g.score();
+        assert_eq!(vec![0, 0, 2], score);
+    }
+
+    #[test]
+    fn party_full_games_complete() {
+        for (players, menu) in [
+            (2, "dinner_for_two"),
+            (4, "master_menu"),
+            (5, "points_platter"),
+            (6, "party_sampler"),
+            (8, "big_banquet"),
+            (7, MENU_RANDOM),
+        ] {
+            let n = party_names(players);
+            let mut g = party_game(players, menu);
+            for _ in 0..5000 {
+                if g.is_finished() {
+                    break;
+                }
+                let p = g.whose_turn()[0];
+                let input = match g.pending_for(p) {
+                    Some(Pending::Menu(_)) => "pick 1",
+                    Some(Pending::SpecialOrder) => {
+                        let i = g.played[p]
+                            .iter()
+                            .position(|&c| c != Card::SpecialOrder && c != Card::Boxed)
+                            .unwrap();
+                        g.command(p, &format!("copy {}", i + 1), &n).unwrap();
+                        continue;
+                    }
+                    Some(Pending::TakeoutBox) => "pass",
+                    None => "play 1",
+                };
+                g.command(p, input, &n).unwrap();
+                assert!(g.validate().is_ok());
+            }
+            assert!(g.is_finished(), "{} players on {}", players, menu);
+            assert_eq!(players, g.pub_state().final_scores.len());
+        }
+    }
+
+    #[test]
+    fn party_render_shows_menu_and_pending() {
+        let n = party_names(3);
+        let mut g = party_game(3, "party_sampler");
+        g.hands = vec![
+            vec![Card::Menu, Card::Tofu],
+            vec![Card::Tofu; 2],
+            vec![Card::Tofu; 2],
+        ];
+        for p in 0..3 {
+            g.command(p, "play 1", &n).unwrap();
+        }
+        let text = brdgme_markup::to_string(&brdgme_game::Renderer::render(&g.player_state(0)));
+        assert!(text.contains("Green Tea Ice Cream"), "{}", text);
+        assert!(text.contains("pick a card to order"), "{}", text);
+    }
 }
@@ -0,0 +1,618 @@
+//! Sushi Go Party: menus built from the full Party card pool.
+//!
+//! A menu is nigiri plus one roll, three appetizers, two specials and a
+//! dessert. The deck for each round is built from the menu rather than the
+//! fixed 108 card deck of the original game.
+
+use std::fmt;
+
+use brdgme_game::GameOptions;
+use brdgme_game::errors::GameError;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::Card;
+
+pub const MENU_OPTION: &str = "menu";
+pub const ROLL_OPTION: &str = "roll";
+pub const APPETIZER_OPTIONS: [&str; 3] = ["appetizer_1", "appetizer_2", "appetizer_3"];
+pub const SPECIAL_OPTIONS: [&str; 2] = ["special_1", "special_2"];
+pub const DESSERT_OPTION: &str = "dessert";
+
+pub const MENU_CLASSIC: &str = "classic";
+pub const MENU_CUSTOM: &str = "custom";
+pub const MENU_RANDOM: &str = "random";
+
+/// Uramaki awards, in the order players reach 10 symbols.
+pub const URAMAKI_AWARDS: [i32; 3] = [8, 5, 2];
+/// Uramaki symbols needed to score an award during the round.
+pub const URAMAKI_TARGET: i32 = 10;
+
+/// One entry on a menu, and the group its cards belong to. Nigiri is on
+/// every menu.
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum MenuItem {
+    Nigiri,
+    Maki,
+    Temaki,
+    Uramaki,
+    Tempura,
+    Sashimi,
+    Dumpling,
+    Edamame,
+    Eel,
+    Onigiri,
+    MisoSoup,
+    Tofu,
+    Chopsticks,
+    Wasabi,
+    Spoon,
+    Menu,
+    TakeoutBox,
+    Tea,
+    SoySauce,
+    SpecialOrder,
+    Pudding,
+    GreenTeaIceCream,
+    Fruit,
+}
+
+pub const ROLLS: [MenuItem; 3] = [MenuItem::Maki, MenuItem::Temaki, MenuItem::Uramaki];
+pub const APPETIZERS: [MenuItem; 8] = [
+    MenuItem::Tempura,
+    MenuItem::Sashimi,
+    MenuItem::Dumpling,
+    MenuItem::Edamame,
+    MenuItem::Eel,
+    MenuItem::Onigiri,
+    MenuItem::MisoSoup,
+    MenuItem::Tofu,
+];
+pub const SPECIALS: [MenuItem; 8] = [
+    MenuItem::Chopsticks,
+    MenuItem::Wasabi,
+    MenuItem::Spoon,
+    MenuItem::Menu,
+    MenuItem::TakeoutBox,
+    MenuItem::Tea,
+    MenuItem::SoySauce,
+    MenuItem::SpecialOrder,
+];
+pub const DESSERTS: [MenuItem; 3] = [
+    MenuItem::Pudding,
+    MenuItem::GreenTeaIceCream,
+    MenuItem::Fruit,
+];
+
+impl MenuItem {
+    /// The option value and command name for the item.
+    pub fn key(self) -> &'static str {
+        match self {
+            MenuItem::Nigiri => "nigiri",
+            MenuItem::Maki => "maki",
+            MenuItem::Temaki => "temaki",
+            MenuItem::Uramaki => "uramaki",
+            MenuItem::Tempura => "tempura",
+            MenuItem::Sashimi => "sashimi",
+            MenuItem::Dumpling => "dumpling",
+            MenuItem::Edamame => "edamame",
+            MenuItem::Eel => "eel",
+            MenuItem::Onigiri => "onigiri",
+            MenuItem::MisoSoup => "miso_soup",
+            MenuItem::Tofu => "tofu",
+            MenuItem::Chopsticks => "chopsticks",
+            MenuItem::Wasabi => "wasabi",
+            MenuItem::Spoon => "spoon",
+            MenuItem::Menu => "menu",
+            MenuItem::TakeoutBox => "takeout_box",
+            MenuItem::Tea => "tea",
+            MenuItem::SoySauce => "soy_sauce",
+            MenuItem::SpecialOrder => "special_order",
+            MenuItem::Pudding => "pudding",
+            MenuItem::GreenTeaIceCream => "green_tea_ice_cream",
+            MenuItem::Fruit => "fruit",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MenuItem::Nigiri => "Nigiri",
+            MenuItem::Maki => "Maki",
+            MenuItem::Temaki => "Temaki",
+            MenuItem::Uramaki => "Uramaki",
+            MenuItem::Tempura => "Tempura",
+            MenuItem::Sashimi => "Sashimi",
+            MenuItem::Dumpling => "Dumpling",
+            MenuItem::Edamame => "Edamame",
+            MenuItem::Eel => "Eel",
+            MenuItem::Onigiri => "Onigiri",
+            MenuItem::MisoSoup => "Miso Soup",
+            MenuItem::Tofu => "Tofu",
+            MenuItem::Chopsticks => "Chopsticks",
+            MenuItem::Wasabi => "Wasabi",
+            MenuItem::Spoon => "Spoon",
+            MenuItem::Menu => "Menu",
+            MenuItem::TakeoutBox => "Takeout Box",
+            MenuItem::Tea => "Tea",
+            MenuItem::SoySauce => "Soy Sauce",
+            MenuItem::SpecialOrder => "Special Order",
+            MenuItem::Pudding => "Pudding",
+            MenuItem::GreenTeaIceCream => "Green Tea Ice Cream",
+            MenuItem::Fruit => "Fruit",
+        }
+    }
+
+    pub fn is_dessert(self) -> bool {
+        DESSERTS.contains(&self)
+    }
+
+    /// The Party cards for the item, with their counts.
+    pub fn cards(self) -> Vec<(Card, usize)> {
+        match self {
+            MenuItem::Nigiri => vec![
+                (Card::EggNigiri, 4),
+                (Card::SalmonNigiri, 5),
+                (Card::SquidNigiri, 3),
+            ],
+            MenuItem::Maki => vec![
+                (Card::MakiRoll1, 4),
+                (Card::MakiRoll2, 5),
+                (Card::MakiRoll3, 3),
+            ],
+            MenuItem::Temaki => vec![(Card::Temaki, 12)],
+            MenuItem::Uramaki => vec![
+                (Card::Uramaki3, 4),
+                (Card::Uramaki4, 4),
+                (Card::Uramaki5, 4),
+            ],
+            MenuItem::Tempura => vec![(Card::Tempura, 8)],
+            MenuItem::Sashimi => vec![(Card::Sashimi, 8)],
+            MenuItem::Dumpling => vec![(Card::Dumpling, 8)],
+            MenuItem::Edamame => vec![(Card::Edamame, 8)],
+            MenuItem::Eel => vec![(Card::Eel, 8)],
+            MenuItem::Onigiri => vec![
+                (Card::OnigiriCircle, 2),
+                (Card::OnigiriTriangle, 2),
+                (Card::OnigiriSquare, 2),
+                (Card::OnigiriRectangle, 2),
+            ],
+            MenuItem::MisoSoup => vec![(Card::MisoSoup, 8)],
+            MenuItem::Tofu => vec![(Card::Tofu, 8)],
+            MenuItem::Chopsticks => vec![(Card::Chopsticks, 3)],
+            MenuItem::Wasabi => vec![(Card::Wasabi, 3)],
+            MenuItem::Spoon => vec![(Card::Spoon, 3)],
+            MenuItem::Menu => vec![(Card::Menu, 3)],
+            MenuItem::TakeoutBox => vec![(Card::TakeoutBox, 3)],
+            MenuItem::Tea => vec![(Card::Tea, 3)],
+            MenuItem::SoySauce => vec![(Card::SoySauce, 3)],
+            MenuItem::SpecialOrder => vec![(Card::SpecialOrder, 3)],
+            MenuItem::Pudding => vec![(Card::Pudding, 15)],
+            MenuItem::GreenTeaIceCream => vec![(Card::GreenTeaIceCream, 15)],
+            MenuItem::Fruit => vec![
+                (Card::FruitWatermelons, 2),
+                (Card::FruitPineapples, 2),
+                (Card::FruitOranges, 2),
+                (Card::FruitWatermelonPineapple, 3),
+                (Card::FruitWatermelonOrange, 3),
+                (Card::FruitPineappleOrange, 3),
+            ],
+        }
+    }
+
+    fn from_key(key: &str) -> Option<MenuItem> {
+        ROLLS
+            .iter()
+            .chain(APPETIZERS.iter())
+            .chain(SPECIALS.iter())
+            .chain(DESSERTS.iter())
+            .find(|i| i.key() == key)
+            .copied()
+    }
+}
+
+impl fmt::Display for MenuItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+/// The menu item a card belongs to. `None` for placeholders and boxed
+/// cards, which belong to no item.
+pub fn card_item(c: Card) -> Option<MenuItem> {
+    Some(match c {
+        Card::Played | Card::Boxed => return None,
+        Card::EggNigiri | Card::SalmonNigiri | Card::SquidNigiri => MenuItem::Nigiri,
+        Card::MakiRoll1 | Card::MakiRoll2 | Card::MakiRoll3 => MenuItem::Maki,
+        Card::Temaki => MenuItem::Temaki,
+        Card::Uramaki3 | Card::Uramaki4 | Card::Uramaki5 => MenuItem::Uramaki,
+        Card::Tempura => MenuItem::Tempura,
+        Card::Sashimi => MenuItem::Sashimi,
+        Card::Dumpling => MenuItem::Dumpling,
+        Card::Edamame => MenuItem::Edamame,
+        Card::Eel => MenuItem::Eel,
+        Card::OnigiriCircle
+        | Card::OnigiriTriangle
+        | Card::OnigiriSquare
+        | Card::OnigiriRectangle => MenuItem::Onigiri,
+        Card::MisoSoup => MenuItem::MisoSoup,
+        Card::Tofu => MenuItem::Tofu,
+        Card::Chopsticks => MenuItem::Chopsticks,
+        Card::Wasabi => MenuItem::Wasabi,
+        Card::Spoon => MenuItem::Spoon,
+        Card::Menu => MenuItem::Menu,
+        Card::TakeoutBox => MenuItem::TakeoutBox,
+        Card::Tea => MenuItem::Tea,
+        Card::SoySauce => MenuItem::SoySauce,
+        Card::SpecialOrder => MenuItem::SpecialOrder,
+        Card::Pudding => MenuItem::Pudding,
+        Card::GreenTeaIceCream => MenuItem::GreenTeaIceCream,
+        Card::FruitWatermelons
+        | Card::FruitPineapples
+        | Card::FruitOranges
+        | Card::FruitWatermelonPineapple
+        | Card::FruitWatermelonOrange
+        | Card::FruitPineappleOrange => MenuItem::Fruit,
+    })
+}
+
+/// The menu for a Party game.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Menu {
+    pub roll: MenuItem,
+    pub appetizers: Vec<MenuItem>,
+    pub specials: Vec<MenuItem>,
+    pub dessert: MenuItem,
+}
+
+impl Menu {
+    /// Every item on the menu, nigiri first and the dessert last.
+    pub fn items(&self) -> Vec<MenuItem> {
+        let mut items = vec![MenuItem::Nigiri, self.roll];
+        items.extend(&self.appetizers);
+        items.extend(&self.specials);
+        items.push(self.dessert);
+        items
+    }
+
+    pub fn has(&self, item: MenuItem) -> bool {
+        self.items().contains(&item)
+    }
+
+    /// The cards shuffled into every round: everything but the dessert.
+    pub fn round_cards(&self) -> Vec<Card> {
+        let mut cards = vec![];
+        for item in self.items() {
+            if !item.is_dessert() {
+                for (c, n) in item.cards() {
+                    cards.extend(std::iter::repeat_n(c, n));
+                }
+            }
+        }
+        cards
+    }
+
+    pub fn dessert_cards(&self) -> Vec<Card> {
+        let mut cards = vec![];
+        for (c, n) in self.dessert.cards() {
+            cards.extend(std::iter::repeat_n(c, n));
+        }
+        cards
+    }
+}
+
+fn menu(
+    roll: MenuItem,
+    appetizers: [MenuItem; 3],
+    specials: [MenuItem; 2],
+    dessert: MenuItem,
+) -> Menu {
+    Menu {
+        roll,
+        appetizers: appetizers.to_vec(),
+        specials: specials.to_vec(),
+        dessert,
+    }
+}
+
+/// The suggested menus from the Party rulebook, as `(value, name, menu)`.
+pub fn preset_menus() -> Vec<(&'static str, &'static str, Menu)> {
+    use MenuItem::*;
+    vec![
+        (
+            "my_first_meal",
+            "My First Meal",
+            menu(
+                Maki,
+                [Tempura, Sashimi, MisoSoup],
+                [Wasabi, Tea],
+                GreenTeaIceCream,
+            ),
+        ),
+        (
+            "sushi_go",
+            "Sushi Go!",
+            menu(
+                Maki,
+                [Tempura, Sashimi, Dumpling],
+                [Chopsticks, Wasabi],
+                Pudding,
+            ),
+        ),
+        (
+            "party_sampler",
+            "Party Sampler",
+            menu(
+                Temaki,
+                [Tempura, Dumpling, Tofu],
+                [Wasabi, Menu],
+                GreenTeaIceCream,
+            ),
+        ),
+        (
+            "master_menu",
+            "Master Menu",
+            menu(Temaki, [Onigiri, Tofu, Sashimi], [Spoon, TakeoutBox], Fruit),
+        ),
+        (
+            "points_platter",
+            "Points Platter",
+            menu(
+                Uramaki,
+                [Onigiri, Dumpling, Edamame],
+                [SpecialOrder, Tea],
+                GreenTeaIceCream,
+            ),
+        ),
+        (
+            "cutthroat_combo",
+            "Cutthroat Combo",
+            menu(Temaki, [Eel, Tofu, MisoSoup], [Spoon, SoySauce], Pudding),
+        ),
+        (
+            "big_banquet",
+            "Big Banquet",
+            menu(
+                Maki,
+                [Tempura, Dumpling, Eel],
+                [Spoon, Chopsticks],
+                GreenTeaIceCream,
+            ),
+        ),
+        (
+            "dinner_for_two",
+            "Dinner for Two",
+            menu(
+                Uramaki,
+                [Onigiri, Tofu, MisoSoup],
+                [Menu, SpecialOrder],
+                Fruit,
+            ),
+        ),
+    ]
+}
+
+/// `(value, name)` pairs for a pick from `items`, starting at `first` so
+/// each custom menu slot defaults to a different item.
+pub fn item_values(items: &[MenuItem], first: usize) -> Vec<(&'static str, &'static str)> {
+    (0..items.len())
+        .map(|i| {
+            let item = items[(first + i) % items.len()];
+            (item.key(), item.name())
+        })
+        .collect()
+}
+
+/// The menu chosen by the resolved options, or `None` for the original
+/// game. The original game only has cards for up to 5 players, so larger
+/// games play its cards from the Party pool instead.
+pub fn chosen_menu(
+    options: &GameOptions,
+    players: usize,
+    rng: &mut impl Rng,
+) -> Result<Option<Menu>, GameError> {
+    let value = options.get(MENU_OPTION).unwrap_or(MENU_CLASSIC);
+    let menu = match value {
+        MENU_CLASSIC if players <= 5 => return Ok(None),
+        MENU_CLASSIC => preset("sushi_go"),
+        MENU_RANDOM => Some(Menu {
+            roll: *ROLLS.choose(rng).unwrap_or(&MenuItem::Maki),
+            appetizers: pick_items(&APPETIZERS, 3, rng),
+            specials: pick_items(&SPECIALS, 2, rng),
+            dessert: *DESSERTS.choose(rng).unwrap_or(&MenuItem::Pudding),
+        }),
+        MENU_CUSTOM => Some(custom_menu(options)?),
+        v => preset(v),
+    };
+    menu.map(Some)
+        .ok_or_else(|| GameError::invalid_input(format!("unknown menu '{}'", value)))
+}
+
+fn pick_items(items: &[MenuItem], n: usize, rng: &mut impl Rng) -> Vec<MenuItem> {
+    let mut picked: Vec<MenuItem> = items.sample(rng, n).copied().collect();
+    picked.sort();
+    picked
+}
+
+fn preset(value: &str) -> Option<Menu> {
+    preset_menus()
+        .into_iter()
+        .find(|(v, _, _)| *v == value)
+        .map(|(_, _, m)| m)
+}
+
+fn custom_menu(options: &GameOptions) -> Result<Menu, GameError> {
+    let item = |key: &str| -> Result<MenuItem, GameError> {
+        options
+            .get(key)
+            .and_then(MenuItem::from_key)
+            .ok_or_else(|| GameError::invalid_input(format!("missing value for {}", key)))
+    };
+    let appetizers = APPETIZER_OPTIONS
+        .iter()
+        .map(|k| item(k))
+        .collect::<Result<Vec<_>, _>>()?;
+    let specials = SPECIAL_OPTIONS
+        .iter()
+        .map(|k| item(k))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (picked, what) in [(&appetizers, "appetizer"), (&specials, "special")] {
+        for (i, a) in picked.iter().enumerate() {
+            if picked[..i].contains(a) {
+                return Err(GameError::invalid_input(format!(
+                    "each {} on a custom menu must be different, {} is picked twice",
+                    what,
+                    a.name()
+                )));
+            }
+        }
+    }
+    Ok(Menu {
+        roll: item(ROLL_OPTION)?,
+        appetizers,
+        specials,
+        dessert: item(DESSERT_OPTION)?,
+    })
+}
+
+/// Cards dealt to each player per round in a Party game.
+pub fn draw_count(players: usize) -> usize {
+    match players {
+        0..=3 => 10,
+        4 | 5 => 9,
+        6 | 7 => 8,
+        _ => 7,
+    }
+}
+
+/// Dessert cards from the dessert pile added to the deck at the start of
+/// the given round (1-based).
+pub fn desserts_for_round(round: usize, players: usize) -> usize {
+    let per_round: [usize; 3] = if players <= 5 { [5, 3, 2] } else { [7, 5, 3] };
+    per_round.get(round.wrapping_sub(1)).copied().unwrap_or(0)
+}
+
+/// A choice a player must make after the cards are revealed, before hands
+/// are passed.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum Pending {
+    /// Menu: pick one of these cards drawn from the deck to play.
+    Menu(Vec<Card>),
+    /// Special Order: pick a card on your tray to copy.
+    SpecialOrder,
+    /// Takeout Box: pick cards on your tray to box, or pass.
+    TakeoutBox,
+}
+
+/// Gives out majority awards, best count first, ignoring players with no
+/// count. Players tied for an award split it, rounded down, and no lower
+/// awards are given after a tie. Returns `(players, count, points each)`
+/// per award given.
+pub fn majority_awards(counts: &[i32], awards: &[i32]) -> Vec<(Vec<usize>, i32, i32)> {
+    let mut out = vec![];
+    let mut remaining: Vec<usize> = (0..counts.len()).filter(|&p| counts[p] > 0).collect();
+    for &award in awards {
+        let Some(best) = remaining.iter().map(|&p| counts[p]).max() else {
+            break;
+        };
+        let tied: Vec<usize> = remaining
+            .iter()
+            .copied()
+            .filter(|&p| counts[p] == best)
+            .collect();
+        out.push((tied.clone(), best, award / tied.len() as i32));
+        if tied.len() > 1 {
+            break;
+        }
+        remaining.retain(|p| !tied.contains(p));
+    }
+    out
+}
+
+/// Onigiri score for sets of 1 to 4 different shapes.
+pub fn onigiri_set_score(size: usize) -> i32 {
+    match size {
+        0 => 0,
+        1 => 1,
+        2 => 4,
+        3 => 9,
+        _ => 16,
+    }
+}
+
+/// Score for each fruit by how many of it a player has.
+pub fn fruit_score(count: i32) -> i32 {
+    match count {
+        i32::MIN..=0 => -2,
+        1 => 0,
+        2 => 1,
+        3 => 3,
+        4 => 6,
+        _ => 10,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_full_menus() {
+        for (_, _, m) in preset_menus() {
+            assert!(ROLLS.contains(&m.roll));
+            assert_eq!(3, m.appetizers.len());
+            assert!(m.appetizers.iter().all(|a| APPETIZERS.contains(a)));
+            assert_eq!(2, m.specials.len());
+            assert!(m.specials.iter().all(|s| SPECIALS.contains(s)));
+            assert!(m.dessert.is_dessert());
+        }
+    }
+
+    #[test]
+    fn decks_cover_eight_players() {
+        for (_, _, m) in preset_menus() {
+            let round = m.round_cards().len();
+            assert_eq!(54, round);
+            assert_eq!(15, m.dessert_cards().len());
+            for players in 2..=8 {
+                assert!(round + desserts_for_round(1, players) >= players * draw_count(players));
+            }
+        }
+    }
+
+    #[test]
+    fn majority_awards_share_ties() {
+        assert_eq!(
+            vec![(vec![1], 5, 6), (vec![0, 2], 3, 2)],
+            majority_awards(&[3, 5, 3, 0], &[6, 4, 2])
+        );
+        assert_eq!(
+            vec![(vec![0, 1], 2, 3)],
+            majority_awards(&[2, 2, 1], &[6, 3])
+        );
+        assert_eq!(
+            vec![(vec![1], 3, 6), (vec![0], 2, 3)],
+            majority_awards(&[2, 3, 0], &[6, 3])
+        );
+        assert!(majority_awards(&[0, 0], &[6, 3]).is_empty());
+    }
+
+    #[test]
+    fn custom_menu_rejects_repeats() {
+        let mut options = GameOptions::new()
+            .with(MENU_OPTION, MENU_CUSTOM)
+            .with(ROLL_OPTION, "temaki")
+            .with(DESSERT_OPTION, "fruit");
+        for (k, v) in APPETIZER_OPTIONS.iter().zip(["eel", "tofu", "edamame"]) {
+            options.set(*k, v);
+        }
+        for (k, v) in SPECIAL_OPTIONS.iter().zip(["menu", "spoon"]) {
+            options.set(*k, v);
+        }
+        let menu = custom_menu(&options).unwrap();
+        assert_eq!(MenuItem::Temaki, menu.roll);
+        assert_eq!(MenuItem::Fruit, menu.dessert);
+        options.set(APPETIZER_OPTIONS[2], "eel");
+        assert!(custom_menu(&options).is_err());
+    }
+}
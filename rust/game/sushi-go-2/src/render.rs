@@ -4,6 +4,7 @@ use brdgme_color::NamedColor;
 use brdgme_game::Renderer;
 use brdgme_markup::{Align as A, Node as N, Row, table_with_gap};
 
+use crate::party::{Menu, Pending};
 use crate::{Card, PlayerState, PubState};
 
 pub fn card(c: Card) -> N {
@@ -36,6 +37,15 @@ pub fn comma_list_nodes(nodes: Vec<N>) -> N {
     N::Group(out)
 }
 
+pub(crate) fn menu_nodes(menu: &Menu) -> N {
+    comma_list_nodes(
+        menu.items()
+            .into_iter()
+            .map(|i| N::Bold(vec![N::text(i.name())]))
+            .collect(),
+    )
+}
+
 pub(crate) fn render_name(player: usize, players: usize) -> N {
     if player >= players {
         N::Fg(
@@ -57,10 +67,33 @@ fn card_column(c: Card) -> usize {
         Card::Pudding => 5,
         Card::Chopsticks => 6,
         Card::Played => 0,
+        Card::Temaki | Card::Uramaki5 | Card::Uramaki4 | Card::Uramaki3 => 3,
+        Card::GreenTeaIceCream
+        | Card::FruitWatermelons
+        | Card::FruitPineapples
+        | Card::FruitOranges
+        | Card::FruitWatermelonPineapple
+        | Card::FruitWatermelonOrange
+        | Card::FruitPineappleOrange => 5,
+        Card::Spoon
+        | Card::Menu
+        | Card::TakeoutBox
+        | Card::Tea
+        | Card::SoySauce
+        | Card::SpecialOrder => 6,
+        Card::Edamame => 7,
+        Card::Eel => 8,
+        Card::OnigiriCircle
+        | Card::OnigiriTriangle
+        | Card::OnigiriSquare
+        | Card::OnigiriRectangle => 9,
+        Card::MisoSoup => 10,
+        Card::Tofu => 11,
+        Card::Boxed => 12,
     }
 }
 
-const NUM_COLUMNS: usize = 7;
+const NUM_COLUMNS: usize = 13;
 
 fn cards_cells(cards: &[Card]) -> Vec<Row> {
     let mut columns: Vec<Vec<Vec<N>>> = vec![vec![]; NUM_COLUMNS];
@@ -109,7 +142,7 @@ fn cards_cells(cards: &[Card]) -> Vec<Row> {
 }
 
 fn hand_table(hand: &[Card]) -> Vec<Row> {
-    let mut explained: HashSet<Card> = HashSet::new();
+    let mut explained: HashSet<&str> = HashSet::new();
     let mut rows: Vec<Row> = vec![];
     for (i, &c) in hand.iter().enumerate() {
         let mut row: Row = vec![
@@ -122,7 +155,7 @@ fn hand_table(hand: &[Card]) -> Vec<Row> {
             ),
             (A::Left, vec![card(c)]),
         ];
-        if !explained.contains(&c) && !c.explanation().is_empty() {
+        if !explained.contains(c.explanation()) && !c.explanation().is_empty() {
             row.push((
                 A::Left,
                 vec![N::Fg(
@@ -130,12 +163,7 @@ fn hand_table(hand: &[Card]) -> Vec<Row> {
                     vec![N::text(format!("  {}", c.explanation()))],
                 )],
             ));
-            explained.insert(c);
-            if c == Card::MakiRoll1 || c == Card::MakiRoll2 || c == Card::MakiRoll3 {
-                explained.insert(Card::MakiRoll1);
-                explained.insert(Card::MakiRoll2);
-                explained.insert(Card::MakiRoll3);
-            }
+            explained.insert(c.explanation());
         }
         rows.push(row);
     }
@@ -150,20 +178,47 @@ fn render(pub_state: &PubState, own: Option<&PlayerState>) -> Vec<N> {
         N::Bold(vec![N::text("3")]),
         N::text("\n\n"),
     ];
+    if let Some(ref menu) = pub_state.menu {
+        out.push(N::Group(vec![
+            N::text("Menu: "),
+            menu_nodes(menu),
+            N::text("\n\n"),
+        ]));
+    }
 
     if let Some(own) = own {
         let p = own.player;
+        match own.pending.first() {
+            Some(Pending::Menu(cards)) => {
+                out.push(N::Bold(vec![N::text("Menu, pick a card to order:\n\n")]));
+                out.push(table_with_gap(&hand_table(cards), 2));
+                out.push(N::text("\n\n"));
+            }
+            Some(pending) => {
+                let prompt = if *pending == Pending::SpecialOrder {
+                    "Special order, copy a card on your tray:\n\n"
+                } else {
+                    "Takeout box, box cards on your tray or pass:\n\n"
+                };
+                out.push(N::Bold(vec![N::text(prompt)]));
+                out.push(table_with_gap(&hand_table(&pub_state.played[p]), 2));
+                out.push(N::text("\n\n"));
+            }
+            None => {}
+        }
         out.push(N::Bold(vec![N::text("Hand:\n\n")]));
         out.push(table_with_gap(&hand_table(&own.hand), 2));
         out.push(N::text("\n\n"));
 
         let mut playing_output = false;
         if let Some(ref playing) = own.playing {
-            out.push(N::Group(vec![
-                N::text("Playing: "),
-                cards_list(playing),
-                N::text("\n"),
-            ]));
+            let mut line = vec![N::text("Playing: "), cards_list(playing)];
+            if let Some(item) = own.spoon {
+                line.push(N::text(", using a spoon to ask for "));
+                line.push(N::Bold(vec![N::text(item.name())]));
+            }
+            line.push(N::text("\n"));
+            out.push(N::Group(line));
             playing_output = true;
         }
         if p == pub_state.controller
@@ -65,6 +65,14 @@ pub fn standard_52_deck_ace_high() -> Deck {
     d
 }
 
+/// The 36 card deck for short-deck hold'em: sixes up to aces.
+pub fn short_deck_ace_high() -> Deck {
+    standard_52_deck_ace_high()
+        .into_iter()
+        .filter(|c| c.rank >= RANK_6)
+        .collect()
+}
+
 /// Port of `Deck.Sort` - returns a new sorted deck, leaving the input
 /// untouched (Go's version copies before calling `sort.Sort`).
 pub fn sort(deck: &[Card]) -> Deck {
@@ -183,6 +191,13 @@ mod tests {
         assert!(d[d.len() - 1].rank > RANK_KING);
     }
 
+    #[test]
+    fn short_deck_starts_at_six() {
+        let d = short_deck_ace_high();
+        assert_eq!(36, d.len());
+        assert!(d.iter().all(|c| c.rank >= RANK_6));
+    }
+
     #[test]
     fn test_shuffle() {
         // Port of libcard's TestShuffle. Seed chosen arbitrarily; the point
@@ -44,11 +44,11 @@ impl Game {
     /// matching Go's `RaiseParser` which uses `g.MinRaise()`. This rejects
     /// too-small raises at parse time. `Game::can_raise` gates the parser's
     /// existence on the same `min_raise()` floor, so the bounds can never be
-    /// inverted (F-44).
+    /// inverted (F-44). The max bound is `max_raise()`, which caps raises at
+    /// the pot in pot-limit games.
     fn raise_parser(&self, player: usize) -> impl Parser<T = Command> {
-        let behind_current_bet = self.current_bet() - self.bets[player];
         let min = self.min_raise();
-        let max = self.player_money[player] - behind_current_bet;
+        let max = self.max_raise(player);
         Map::new(
             Chain2::new(
                 Doc::name_desc(
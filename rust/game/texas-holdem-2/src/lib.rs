@@ -3,12 +3,14 @@
 //! See `card.rs` (ported from `brdgme-go/libcard`) and `poker.rs` (ported
 //! from `brdgme-go/libpoker/hand.go`) for the card/hand-evaluation building
 //! blocks. This module ports `texas_holdem.go` itself: game state, betting
-//! rules, phase progression and status/points.
+//! rules, phase progression and status/points. `variant.rs` adds the pot-limit
+//! Omaha and short-deck formats, starting stacks and blind schedules.
 
 pub mod card;
 mod command;
 pub mod poker;
 mod render;
+pub mod variant;
 
 use std::collections::HashMap;
 
@@ -19,12 +21,13 @@ use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
 use brdgme_game::rng::GameRng;
-use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
+use brdgme_game::{CommandResponse, GameOption, GameOptions, Gamer, Log, Status, placings_log};
 use brdgme_markup::{Align as A, Node as N, Row, table_with_gap};
 
 use card::Deck;
 use command::Command;
 use rand::RngExt;
+use variant::{BlindSchedule, Variant};
 
 const STARTING_MONEY: i32 = 100;
 const STARTING_MINIMUM_BET: i32 = 10;
@@ -48,6 +51,13 @@ pub struct Game {
     pub hands_since_blinds_increase: i32,
     pub first_betting_player: usize,
     pub everyone_has_bet_once: bool,
+    #[serde(default)]
+    pub variant: Variant,
+    #[serde(default)]
+    pub blinds: BlindSchedule,
+    /// Number of times the blinds have gone up.
+    #[serde(default)]
+    pub blind_level: i32,
     #[serde(default = "GameRng::from_entropy")]
     pub rng: GameRng,
 }
@@ -70,6 +80,12 @@ pub struct PubState {
     pub bets: Vec<i32>,
     /// Whether each player has folded this hand, indexed by player. A folded player cannot win the pot.
     pub folded_players: Vec<bool>,
+    /// The game format: `Holdem`, `Omaha` (pot-limit, four hole cards) or `ShortDeck` (36 cards).
+    pub variant: Variant,
+    /// The current big blind, which is also the smallest allowed raise.
+    pub minimum_bet: i32,
+    /// How the minimum bet grows: `Doubling`, `Slow`, `Turbo`, `Tournament` or `Fixed`.
+    pub blinds: BlindSchedule,
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -78,7 +94,7 @@ pub struct PlayerState {
     pub public: PubState,
     /// Which player this private state belongs to.
     pub player: usize,
-    /// This player's two private (hole) cards, visible only to them.
+    /// This player's private (hole) cards, visible only to them: two, or four in Omaha.
     pub hand: Deck,
 }
 
@@ -296,6 +312,13 @@ impl Game {
                 min_raise
             )));
         }
+        let max_raise = self.max_raise(player_num);
+        if amount > max_raise {
+            return Err(GameError::invalid_input(format!(
+                "Your raise can be at most {}",
+                max_raise
+            )));
+        }
         self.bet(player_num, difference + amount)?;
         let mut logs = vec![Log::public(vec![
             render_player_name(player_num),
@@ -318,14 +341,40 @@ impl Game {
         let min_raise = self.min_raise();
         self.current_player == player
             && self.player_money[player] > current_bet - self.bets[player] + min_raise
+            && self.max_raise(player) >= min_raise
             && !self.is_finished()
     }
 
+    /// The largest raise `player` may make. In pot-limit games this is the
+    /// size of the pot after calling, otherwise whatever the player has
+    /// left after calling.
+    pub fn max_raise(&self, player: usize) -> i32 {
+        let behind = self.current_bet() - self.bets[player];
+        let stack = self.player_money[player] - behind;
+        if self.variant.pot_limit() {
+            stack.min(self.pot() + behind)
+        } else {
+            stack
+        }
+    }
+
+    /// Whether going all in stays within the pot limit, always true in
+    /// no-limit games.
+    fn all_in_within_limit(&self, player: usize) -> bool {
+        let behind = self.current_bet() - self.bets[player];
+        !self.variant.pot_limit() || self.player_money[player] - behind <= self.pot() + behind
+    }
+
     /// Port of `AllIn`.
     pub fn all_in(&mut self, player_num: usize) -> Result<Vec<Log>, GameError> {
         if self.is_finished() || self.current_player != player_num {
             return Err(GameError::invalid_input("Not your turn"));
         }
+        if !self.all_in_within_limit(player_num) {
+            return Err(GameError::invalid_input(
+                "Going all in would be more than the pot limit, raise by at most the pot instead",
+            ));
+        }
         let amount = self.player_money[player_num];
         self.bet(player_num, amount)?;
         let mut logs = vec![Log::public(vec![
@@ -339,7 +388,10 @@ impl Game {
 
     /// Port of `CanAllIn`.
     pub fn can_all_in(&self, player: usize) -> bool {
-        self.current_player == player && self.player_money[player] > 0 && !self.is_finished()
+        self.current_player == player
+            && self.player_money[player] > 0
+            && self.all_in_within_limit(player)
+            && !self.is_finished()
     }
 
     /// Port of `NextPlayer`.
@@ -458,9 +510,9 @@ impl Game {
                 pot += contribution;
                 self.bets[player_num] -= contribution;
                 if !self.folded_players[player_num] {
-                    let full_hand =
-                        card::push_many(&self.player_hands[player_num], &self.community_cards);
-                    let hand_result = poker::result(&full_hand);
+                    let hand_result = self
+                        .variant
+                        .hand_result(&self.player_hands[player_num], &self.community_cards);
                     hands_table.push(vec![
                         (A::Left, vec![render_player_name(player_num)]),
                         (A::Left, render::cards(&self.player_hands[player_num])),
@@ -575,15 +627,19 @@ impl Game {
         let num_active_players = active_players.len();
         let mut logs = vec![];
         // Raise blinds if we need to.
-        if self.hands_since_blinds_increase >= HANDS_PER_BLINDS_INCREASE {
-            self.hands_since_blinds_increase = 0;
-            self.minimum_bet *= 2;
-            logs.push(Log::public(vec![
-                N::text("Minimum bet increased to "),
-                render_cash(self.minimum_bet),
-            ]));
-        } else {
-            self.hands_since_blinds_increase += 1;
+        match self.blinds.hands_per_level() {
+            Some(hands) if self.hands_since_blinds_increase >= hands => {
+                self.hands_since_blinds_increase = 0;
+                self.minimum_bet = self
+                    .blinds
+                    .next_minimum_bet(self.minimum_bet, self.blind_level);
+                self.blind_level += 1;
+                logs.push(Log::public(vec![
+                    N::text("Minimum bet increased to "),
+                    render_cash(self.minimum_bet),
+                ]));
+            }
+            _ => self.hands_since_blinds_increase += 1,
         }
         // Set a new active dealer.
         self.current_dealer = self.next_active_player_num_from(self.current_dealer);
@@ -612,11 +668,11 @@ impl Game {
             N::text(" posted a big blind of "),
             render_cash(amount),
         ]));
-        // Shuffle and deal two cards to each player.
+        // Shuffle and deal the hole cards to each player.
         self.community_cards = Deck::new();
-        self.deck = card::shuffle(&card::standard_52_deck_ace_high(), &mut self.rng);
+        self.deck = card::shuffle(&self.variant.deck(), &mut self.rng);
         for &p in &active_players {
-            let (hand, remaining) = card::pop_n(&self.deck, 2);
+            let (hand, remaining) = card::pop_n(&self.deck, self.variant.hole_cards());
             self.deck = remaining;
             self.player_hands[p] = card::sort(&hand);
         }
@@ -671,6 +727,20 @@ impl Gamer for Game {
 
     /// Port of `New`.
     fn start(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError> {
+        Self::start_with_options(players, seed, &GameOptions::new())
+    }
+
+    fn options() -> Vec<GameOption> {
+        variant::options()
+    }
+
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let options = options.resolve(&Self::options())?;
+        let (variant, stack, blinds) = variant::chosen(&options)?;
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
             return Err(GameError::PlayerCount {
                 min: MIN_PLAYERS,
@@ -684,9 +754,11 @@ impl Gamer for Game {
         let mut g = Game {
             players,
             player_hands: vec![Deck::new(); players],
-            player_money: vec![STARTING_MONEY; players],
+            player_money: vec![stack; players],
             minimum_bet: STARTING_MINIMUM_BET,
             current_dealer,
+            variant,
+            blinds,
             rng,
             ..Game::default()
         };
@@ -704,6 +776,9 @@ impl Gamer for Game {
             player_money: self.player_money.clone(),
             bets: self.bets.clone(),
             folded_players: self.folded_players.clone(),
+            variant: self.variant,
+            minimum_bet: self.minimum_bet,
+            blinds: self.blinds,
         }
     }
 
@@ -778,6 +853,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![HashMap::new(); self.players],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -827,6 +904,14 @@ impl Gamer for Game {
                 "texas-holdem-2: first_betting_player out of range",
             ));
         }
+        for (p, hand) in self.player_hands.iter().enumerate() {
+            if hand.len() > self.variant.hole_cards() {
+                return Err(GameError::internal(format!(
+                    "texas-holdem-2: player {p} has {} hole cards",
+                    hand.len()
+                )));
+            }
+        }
         if !matches!(self.community_cards.len(), 0 | 3 | 4 | 5) {
             return Err(GameError::internal(format!(
                 "texas-holdem-2: community_cards length {} not in 0/3/4/5",
@@ -1632,4 +1717,140 @@ mod tests {
         build(&mut b);
         assert_eq!(log_text(&a.showdown()), log_text(&b.showdown()));
     }
+
+    fn start_with(players: usize, key: &str, value: &str) -> Game {
+        Game::start_with_options(players, 1, &GameOptions::new().with(key, value))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn defaults_play_the_original_game() {
+        let g = mock_game();
+        assert_eq!(Variant::Holdem, g.variant);
+        assert_eq!(BlindSchedule::Doubling, g.blinds);
+        assert_eq!(
+            STARTING_MONEY * 2,
+            g.player_total_money(0) + g.player_total_money(1)
+        );
+        assert!(g.player_hands.iter().all(|h| h.len() == 2));
+        assert_eq!(52 - 4, g.deck.len());
+    }
+
+    #[test]
+    fn unknown_option_values_are_rejected() {
+        for key in [
+            variant::VARIANT_OPTION,
+            variant::STACK_OPTION,
+            variant::BLINDS_OPTION,
+        ] {
+            assert!(Game::start_with_options(2, 1, &GameOptions::new().with(key, "nope")).is_err());
+        }
+    }
+
+    #[test]
+    fn omaha_deals_four_hole_cards() {
+        let g = start_with(3, variant::VARIANT_OPTION, "omaha");
+        assert_eq!(Variant::Omaha, g.variant);
+        assert!(g.player_hands.iter().all(|h| h.len() == 4));
+        assert_eq!(52 - 12, g.deck.len());
+        assert_eq!(4, g.player_state(0).hand.len());
+    }
+
+    #[test]
+    fn short_deck_deals_from_36_cards() {
+        let g = start_with(2, variant::VARIANT_OPTION, "short_deck");
+        assert_eq!(36 - 4, g.deck.len());
+        assert!(
+            g.deck
+                .iter()
+                .chain(g.player_hands.iter().flatten())
+                .all(|c| c.rank >= card::RANK_6)
+        );
+    }
+
+    #[test]
+    fn starting_stack_option() {
+        let g = start_with(3, variant::STACK_OPTION, "500");
+        for p in 0..3 {
+            assert_eq!(500, g.player_total_money(p));
+        }
+    }
+
+    #[test]
+    fn pot_limit_caps_raises_at_the_pot() {
+        let mut g = start_with(2, variant::VARIANT_OPTION, "omaha");
+        g.current_player = 0;
+        g.bets = vec![5, 10];
+        g.player_money = vec![95, 90];
+        g.largest_raise = 0;
+        // Calling 5 makes the pot 20, so the raise is capped at 20.
+        assert_eq!(20, g.max_raise(0));
+        assert!(g.can_raise(0));
+        assert!(!g.can_all_in(0));
+        assert!(g.all_in(0).is_err());
+        assert!(g.raise(0, 21).is_err());
+        let names = vec!["a".to_string(), "b".to_string()];
+        assert!(g.command(0, "raise 21", &names).is_err());
+        g.raise(0, 20).unwrap();
+        assert_eq!(vec![30, 10], g.bets);
+    }
+
+    #[test]
+    fn pot_limit_allows_all_in_within_the_pot() {
+        let mut g = start_with(2, variant::VARIANT_OPTION, "omaha");
+        g.current_player = 0;
+        g.bets = vec![5, 10];
+        g.player_money = vec![25, 90];
+        assert!(g.can_all_in(0));
+        g.all_in(0).unwrap();
+        assert_eq!(0, g.player_money[0]);
+    }
+
+    #[test]
+    fn no_limit_raise_is_capped_by_stack_only() {
+        let mut g = mock_game();
+        g.current_player = 0;
+        g.bets = vec![5, 10];
+        g.player_money = vec![95, 90];
+        assert_eq!(90, g.max_raise(0));
+        assert!(g.can_all_in(0));
+    }
+
+    fn play_hands(g: &mut Game, hands: i32) {
+        for _ in 0..hands {
+            g.player_money = vec![1000; g.players];
+            g.new_hand();
+        }
+    }
+
+    #[test]
+    fn fixed_blinds_never_increase() {
+        let mut g = start_with(2, variant::BLINDS_OPTION, "fixed");
+        play_hands(&mut g, 30);
+        assert_eq!(STARTING_MINIMUM_BET, g.minimum_bet);
+    }
+
+    #[test]
+    fn turbo_blinds_double_every_three_hands() {
+        let mut g = start_with(2, variant::BLINDS_OPTION, "turbo");
+        play_hands(&mut g, 2);
+        assert_eq!(STARTING_MINIMUM_BET, g.minimum_bet);
+        play_hands(&mut g, 1);
+        assert_eq!(STARTING_MINIMUM_BET * 2, g.minimum_bet);
+    }
+
+    #[test]
+    fn tournament_blinds_follow_levels() {
+        let mut g = start_with(2, variant::BLINDS_OPTION, "tournament");
+        let mut levels = vec![g.minimum_bet];
+        for _ in 0..25 {
+            play_hands(&mut g, 1);
+            if levels.last() != Some(&g.minimum_bet) {
+                levels.push(g.minimum_bet);
+            }
+        }
+        assert_eq!(vec![10, 15, 20, 30, 40, 60], levels);
+        assert_eq!(5, g.blind_level);
+    }
 }
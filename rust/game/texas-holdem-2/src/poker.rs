@@ -10,7 +10,7 @@
 
 use std::collections::HashMap;
 
-use crate::card::{Deck, RANK_2, RANK_ACE_HIGH, Suit};
+use crate::card::{Deck, RANK_2, RANK_6, RANK_ACE_HIGH, Suit};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
 pub enum Category {
@@ -27,17 +27,31 @@ pub enum Category {
     StraightFlush,
 }
 
+impl Category {
+    /// The category's strength. Short-deck hold'em ranks a flush above a
+    /// full house, as flushes are harder to make with only 9 cards a suit.
+    pub fn score(self, short_deck: bool) -> i32 {
+        match (self, short_deck) {
+            (Category::Flush, true) => Category::FullHouse as i32,
+            (Category::FullHouse, true) => Category::Flush as i32,
+            _ => self as i32,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct HandResult {
     pub category: Category,
     pub cards: Deck,
     pub name: String,
+    /// Whether the hand is ranked by short-deck rules.
+    pub short_deck: bool,
 }
 
 impl HandResult {
     /// Port of `HandResult.HandScore`.
     pub fn hand_score(&self) -> Vec<i32> {
-        let mut score = vec![self.category as i32];
+        let mut score = vec![self.category.score(self.short_deck)];
         score.extend(self.cards.iter().map(|c| c.rank as i32));
         score
     }
@@ -45,12 +59,47 @@ impl HandResult {
 
 /// Port of `Result`.
 pub fn result(hand: &Deck) -> HandResult {
+    result_with(hand, false)
+}
+
+/// Result for short-deck hold'em, where the ace also plays below the six in
+/// an A-6-7-8-9 straight and a flush beats a full house.
+pub fn short_deck_result(hand: &Deck) -> HandResult {
+    result_with(hand, true)
+}
+
+/// The best hand for pot-limit Omaha, which must use exactly two of the
+/// hole cards and three of the board.
+pub fn omaha_result(hole: &Deck, board: &Deck) -> HandResult {
+    let mut best = HandResult::default();
+    for (i, &a) in hole.iter().enumerate() {
+        for &b in &hole[i + 1..] {
+            for (j, &c) in board.iter().enumerate() {
+                for (k, &d) in board.iter().enumerate().skip(j + 1) {
+                    for &e in &board[k + 1..] {
+                        let res = result(&vec![a, b, c, d, e]);
+                        if best.category == Category::None || res.hand_score() > best.hand_score() {
+                            best = res;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+fn result_with(hand: &Deck, short_deck: bool) -> HandResult {
+    let lowest = if short_deck { RANK_6 } else { RANK_2 };
     let cards_by_suit = cards_by_suit(hand);
-    let mut res = HandResult::default();
+    let mut res = HandResult {
+        short_deck,
+        ..HandResult::default()
+    };
     // Straight flush.
     for suit in [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades] {
         let suit_cards = cards_by_suit.get(&suit).cloned().unwrap_or_default();
-        let (ok, cards) = is_straight(&suit_cards);
+        let (ok, cards) = is_straight_from(&suit_cards, lowest);
         if ok && (res.category < Category::StraightFlush || cards[0].rank > res.cards[0].rank) {
             res.category = Category::StraightFlush;
             res.cards = cards;
@@ -68,24 +117,26 @@ pub fn result(hand: &Deck) -> HandResult {
         res.name = "four of a kind".to_string();
         return res;
     }
-    // Full house.
-    let (ok, cards) = is_full_house(hand);
-    if ok {
-        res.category = Category::FullHouse;
-        res.cards = cards;
-        res.name = "full house".to_string();
-        return res;
-    }
-    // Flush.
-    let (ok, cards) = is_flush(hand);
-    if ok {
-        res.category = Category::Flush;
-        res.cards = cards;
-        res.name = "flush".to_string();
-        return res;
+    // Full house and flush, flush first in short deck.
+    type Check = fn(&Deck) -> (bool, Deck);
+    let mut checks: [(Category, &str, Check); 2] = [
+        (Category::FullHouse, "full house", is_full_house),
+        (Category::Flush, "flush", is_flush),
+    ];
+    if short_deck {
+        checks.reverse();
+    }
+    for (category, name, check) in checks {
+        let (ok, cards) = check(hand);
+        if ok {
+            res.category = category;
+            res.cards = cards;
+            res.name = name.to_string();
+            return res;
+        }
     }
     // Straight.
-    let (ok, cards) = is_straight(hand);
+    let (ok, cards) = is_straight_from(hand, lowest);
     if ok {
         res.category = Category::Straight;
         res.cards = cards;
@@ -126,6 +177,12 @@ pub fn result(hand: &Deck) -> HandResult {
 
 /// Port of `IsStraight`.
 pub fn is_straight(hand: &Deck) -> (bool, Deck) {
+    is_straight_from(hand, RANK_2)
+}
+
+/// `is_straight` for a deck whose lowest rank is `lowest`; the ace also
+/// plays below it.
+pub fn is_straight_from(hand: &Deck, lowest: u8) -> (bool, Deck) {
     if hand.len() < 5 {
         return (false, Deck::new());
     }
@@ -133,7 +190,7 @@ pub fn is_straight(hand: &Deck) -> (bool, Deck) {
     let mut ok = false;
     let mut cards: Deck = Deck::new();
     let mut rank = RANK_ACE_HIGH as i32;
-    while rank >= 2 {
+    while rank >= lowest as i32 {
         let r = rank as u8;
         if !by_rank[&r].is_empty() {
             cards.push(by_rank[&r][0]);
@@ -191,7 +248,7 @@ pub fn is_flush(hand: &Deck) -> (bool, Deck) {
                 HandResult {
                     category: Category::Flush,
                     cards: flush,
-                    name: String::new(),
+                    ..HandResult::default()
                 },
             );
             i += 1;
@@ -610,7 +667,7 @@ mod tests {
         let hr = HandResult {
             category: Category::Straight,
             cards: vec![c(Suit::Clubs, 3), c(Suit::Clubs, 4), c(Suit::Clubs, 5)],
-            name: String::new(),
+            ..HandResult::default()
         };
         let hs = hr.hand_score();
         assert_eq!(4, hs.len());
@@ -688,4 +745,85 @@ mod tests {
         let hand_result = result(&all);
         assert_eq!(5, hand_result.cards.len());
     }
+
+    #[test]
+    fn short_deck_flush_beats_full_house() {
+        let flush = short_deck_result(&vec![
+            c(Suit::Hearts, 6),
+            c(Suit::Hearts, 8),
+            c(Suit::Hearts, RANK_10),
+            c(Suit::Hearts, RANK_QUEEN),
+            c(Suit::Hearts, RANK_KING),
+        ]);
+        let full_house = short_deck_result(&vec![
+            c(Suit::Hearts, RANK_ACE_HIGH),
+            c(Suit::Spades, RANK_ACE_HIGH),
+            c(Suit::Clubs, RANK_ACE_HIGH),
+            c(Suit::Hearts, RANK_KING),
+            c(Suit::Spades, RANK_KING),
+        ]);
+        assert_eq!(Category::Flush, flush.category);
+        assert_eq!(Category::FullHouse, full_house.category);
+        assert!(flush.hand_score() > full_house.hand_score());
+        // The standard ranking is unchanged.
+        assert!(result(&flush.cards).hand_score() < result(&full_house.cards).hand_score());
+    }
+
+    #[test]
+    fn short_deck_prefers_flush_when_both_are_made() {
+        let hand_result = short_deck_result(&vec![
+            c(Suit::Hearts, 6),
+            c(Suit::Hearts, 7),
+            c(Suit::Hearts, 8),
+            c(Suit::Hearts, RANK_10),
+            c(Suit::Spades, RANK_10),
+            c(Suit::Hearts, RANK_KING),
+            c(Suit::Spades, RANK_KING),
+            c(Suit::Clubs, RANK_KING),
+        ]);
+        assert_eq!(Category::Flush, hand_result.category);
+    }
+
+    #[test]
+    fn short_deck_wheel_is_ace_to_nine() {
+        let hand_result = short_deck_result(&vec![
+            c(Suit::Hearts, RANK_ACE_HIGH),
+            c(Suit::Spades, 6),
+            c(Suit::Clubs, 7),
+            c(Suit::Hearts, 8),
+            c(Suit::Diamonds, 9),
+        ]);
+        assert_eq!(Category::Straight, hand_result.category);
+        assert_eq!(RANK_ACE_HIGH, hand_result.cards[4].rank);
+        assert_eq!(
+            Category::HighCard,
+            result(&hand_result.cards.clone()).category
+        );
+    }
+
+    #[test]
+    fn omaha_must_use_two_hole_cards() {
+        // Four hearts on the board with one in hand is no flush in Omaha.
+        let hole = vec![
+            c(Suit::Hearts, RANK_ACE_HIGH),
+            c(Suit::Spades, RANK_KING),
+            c(Suit::Clubs, 3),
+            c(Suit::Diamonds, 3),
+        ];
+        let board = vec![
+            c(Suit::Hearts, 2),
+            c(Suit::Hearts, 5),
+            c(Suit::Hearts, 9),
+            c(Suit::Hearts, RANK_JACK),
+            c(Suit::Spades, 7),
+        ];
+        assert_eq!(
+            Category::Flush,
+            result(&crate::card::push_many(&hole, &board)).category
+        );
+        let hand_result = omaha_result(&hole, &board);
+        assert_eq!(Category::OnePair, hand_result.category);
+        assert_eq!(5, hand_result.cards.len());
+        assert_eq!(3, hand_result.cards[0].rank);
+    }
 }
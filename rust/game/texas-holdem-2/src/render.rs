@@ -45,6 +45,16 @@ fn render(pub_state: &PubState, player: Option<usize>, hand: Option<&[Card]>) ->
     out.push(N::text("\n"));
     out.push(N::Bold(vec![N::text("Current bet:      ")]));
     out.push(cash(pub_state.bets.iter().copied().max().unwrap_or(0)));
+    out.push(N::text("\n"));
+    out.push(N::Bold(vec![N::text("Minimum bet:      ")]));
+    out.push(cash(pub_state.minimum_bet));
+    out.push(N::text(format!(
+        " ({})",
+        pub_state.blinds.name().to_lowercase()
+    )));
+    out.push(N::text("\n"));
+    out.push(N::Bold(vec![N::text("Game:             ")]));
+    out.push(N::text(pub_state.variant.name()));
     out.push(N::text("\n\n"));
 
     if let (Some(p), Some(h)) = (player, hand) {
@@ -0,0 +1,260 @@
+//! Game formats beyond the original no-limit Texas hold'em: pot-limit Omaha,
+//! short-deck hold'em, starting stacks and blind schedules. Each is chosen
+//! with a start option; the defaults reproduce the original game.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use brdgme_game::errors::GameError;
+use brdgme_game::{GameOption, GameOptions};
+
+use crate::card::{self, Deck};
+use crate::poker::{self, HandResult};
+
+pub const VARIANT_OPTION: &str = "variant";
+pub const STACK_OPTION: &str = "stack";
+pub const BLINDS_OPTION: &str = "blinds";
+
+/// Starting stacks offered as options, the first being the default.
+pub const STACKS: [i32; 4] = [crate::STARTING_MONEY, 200, 500, 1000];
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Variant {
+    /// No-limit Texas hold'em, the original game.
+    #[default]
+    Holdem,
+    /// Pot-limit Omaha: four hole cards, exactly two of which must be used.
+    Omaha,
+    /// No-limit hold'em with the 36 card short deck, where a flush beats a
+    /// full house.
+    ShortDeck,
+}
+
+pub const VARIANTS: [Variant; 3] = [Variant::Holdem, Variant::Omaha, Variant::ShortDeck];
+
+impl Variant {
+    pub fn key(self) -> &'static str {
+        match self {
+            Variant::Holdem => "holdem",
+            Variant::Omaha => "omaha",
+            Variant::ShortDeck => "short_deck",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Variant::Holdem => "No-limit Texas hold'em",
+            Variant::Omaha => "Pot-limit Omaha",
+            Variant::ShortDeck => "Short-deck hold'em",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Variant> {
+        VARIANTS.iter().copied().find(|v| v.key() == key)
+    }
+
+    /// Number of hole cards dealt to each player.
+    pub fn hole_cards(self) -> usize {
+        match self {
+            Variant::Omaha => 4,
+            Variant::Holdem | Variant::ShortDeck => 2,
+        }
+    }
+
+    /// The unshuffled deck for the variant.
+    pub fn deck(self) -> Deck {
+        match self {
+            Variant::ShortDeck => card::short_deck_ace_high(),
+            Variant::Holdem | Variant::Omaha => card::standard_52_deck_ace_high(),
+        }
+    }
+
+    /// Whether raises are capped at the size of the pot.
+    pub fn pot_limit(self) -> bool {
+        self == Variant::Omaha
+    }
+
+    /// The best hand a player can make from their hole cards and the board.
+    pub fn hand_result(self, hole: &Deck, board: &Deck) -> HandResult {
+        match self {
+            Variant::Holdem => poker::result(&card::push_many(hole, board)),
+            Variant::Omaha => poker::omaha_result(hole, board),
+            Variant::ShortDeck => poker::short_deck_result(&card::push_many(hole, board)),
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// How the minimum bet (the big blind) grows over the game.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlindSchedule {
+    /// Doubles every 5 hands, the original schedule.
+    #[default]
+    Doubling,
+    /// Doubles every 10 hands.
+    Slow,
+    /// Doubles every 3 hands.
+    Turbo,
+    /// Tournament levels every 4 hands: 10, 15, 20, 30, 40, 60, 80 and on.
+    Tournament,
+    /// Never increases.
+    Fixed,
+}
+
+pub const BLIND_SCHEDULES: [BlindSchedule; 5] = [
+    BlindSchedule::Doubling,
+    BlindSchedule::Slow,
+    BlindSchedule::Turbo,
+    BlindSchedule::Tournament,
+    BlindSchedule::Fixed,
+];
+
+impl BlindSchedule {
+    pub fn key(self) -> &'static str {
+        match self {
+            BlindSchedule::Doubling => "doubling",
+            BlindSchedule::Slow => "slow",
+            BlindSchedule::Turbo => "turbo",
+            BlindSchedule::Tournament => "tournament",
+            BlindSchedule::Fixed => "fixed",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BlindSchedule::Doubling => "Double every 5 hands",
+            BlindSchedule::Slow => "Double every 10 hands",
+            BlindSchedule::Turbo => "Double every 3 hands",
+            BlindSchedule::Tournament => "Tournament levels every 4 hands",
+            BlindSchedule::Fixed => "Fixed blinds",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<BlindSchedule> {
+        BLIND_SCHEDULES.iter().copied().find(|b| b.key() == key)
+    }
+
+    /// Hands played at each level before the blinds go up, `None` if they
+    /// never do.
+    pub fn hands_per_level(self) -> Option<i32> {
+        match self {
+            BlindSchedule::Doubling => Some(crate::HANDS_PER_BLINDS_INCREASE),
+            BlindSchedule::Slow => Some(10),
+            BlindSchedule::Turbo => Some(3),
+            BlindSchedule::Tournament => Some(4),
+            BlindSchedule::Fixed => None,
+        }
+    }
+
+    /// The minimum bet after moving up from `level`, the number of
+    /// increases so far.
+    pub fn next_minimum_bet(self, current: i32, level: i32) -> i32 {
+        match self {
+            BlindSchedule::Fixed => current,
+            // Alternating 1.5x and 4/3x steps give the familiar 10, 15, 20,
+            // 30, 40, 60 progression.
+            BlindSchedule::Tournament if level % 2 == 0 => current * 3 / 2,
+            BlindSchedule::Tournament => current * 4 / 3,
+            _ => current * 2,
+        }
+    }
+}
+
+impl fmt::Display for BlindSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+pub fn options() -> Vec<GameOption> {
+    let variants: Vec<(&str, &str)> = VARIANTS.iter().map(|v| (v.key(), v.name())).collect();
+    let stacks: Vec<String> = STACKS.iter().map(|s| s.to_string()).collect();
+    let stack_names: Vec<String> = STACKS.iter().map(|s| format!("${s}")).collect();
+    let stack_values: Vec<(&str, &str)> = stacks
+        .iter()
+        .zip(&stack_names)
+        .map(|(k, n)| (k.as_str(), n.as_str()))
+        .collect();
+    let schedules: Vec<(&str, &str)> = BLIND_SCHEDULES
+        .iter()
+        .map(|b| (b.key(), b.name()))
+        .collect();
+    vec![
+        GameOption::new(
+            VARIANT_OPTION,
+            "Variant",
+            "Texas hold'em, pot-limit Omaha or short-deck hold'em",
+            &variants,
+        ),
+        GameOption::new(
+            STACK_OPTION,
+            "Starting stack",
+            "How much money each player starts with",
+            &stack_values,
+        ),
+        GameOption::new(
+            BLINDS_OPTION,
+            "Blind schedule",
+            "How often the minimum bet goes up, starting from $10",
+            &schedules,
+        ),
+    ]
+}
+
+/// Reads the chosen format from options already resolved against
+/// `options()`.
+pub fn chosen(options: &GameOptions) -> Result<(Variant, i32, BlindSchedule), GameError> {
+    let variant = match options.get(VARIANT_OPTION) {
+        Some(key) => Variant::from_key(key)
+            .ok_or_else(|| GameError::invalid_input(format!("unknown variant {key}")))?,
+        None => Variant::default(),
+    };
+    let stack = match options.get(STACK_OPTION) {
+        Some(key) => key
+            .parse()
+            .map_err(|_| GameError::invalid_input(format!("invalid stack {key}")))?,
+        None => STACKS[0],
+    };
+    let blinds = match options.get(BLINDS_OPTION) {
+        Some(key) => BlindSchedule::from_key(key)
+            .ok_or_else(|| GameError::invalid_input(format!("unknown blind schedule {key}")))?,
+        None => BlindSchedule::default(),
+    };
+    Ok((variant, stack, blinds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tournament_levels() {
+        let mut bet = 10;
+        let mut levels = vec![bet];
+        for level in 0..7 {
+            bet = BlindSchedule::Tournament.next_minimum_bet(bet, level);
+            levels.push(bet);
+        }
+        assert_eq!(vec![10, 15, 20, 30, 40, 60, 80, 120], levels);
+    }
+
+    #[test]
+    fn chosen_defaults_to_original_game() {
+        let (variant, stack, blinds) = chosen(&GameOptions::new()).unwrap();
+        assert_eq!(Variant::Holdem, variant);
+        assert_eq!(100, stack);
+        assert_eq!(BlindSchedule::Doubling, blinds);
+    }
+
+    #[test]
+    fn short_deck_has_36_cards() {
+        assert_eq!(36, Variant::ShortDeck.deck().len());
+        assert_eq!(52, Variant::Omaha.deck().len());
+    }
+}
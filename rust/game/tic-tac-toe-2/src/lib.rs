@@ -4,6 +4,7 @@ use brdgme_game::command::Spec as CommandSpec;
 use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
+use brdgme_game::i18n::Locale;
 use brdgme_game::rng::GameRng;
 use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
@@ -267,6 +268,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -310,6 +313,14 @@ impl Gamer for Game {
     fn advanced_strategy() -> String {
         include_str!("../ADVANCED_STRATEGY.md").to_string()
     }
+
+    fn catalog_yaml(locale: Locale) -> Option<&'static str> {
+        match locale {
+            Locale::Es => Some(include_str!("../i18n/es.yaml")),
+            Locale::De => Some(include_str!("../i18n/de.yaml")),
+            Locale::En => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -520,6 +531,8 @@ mod tests {
             Status::Finished {
                 placings: vec![1, 1],
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         );
         assert_eq!(game.points(), vec![0.0, 0.0]);
@@ -535,6 +548,8 @@ mod tests {
             Status::Finished {
                 placings: vec![1, 2],
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         );
         assert_eq!(game.points(), vec![1.0, 0.0]);
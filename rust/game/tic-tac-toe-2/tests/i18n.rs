@@ -0,0 +1,73 @@
+use brdgme_cmd::api::{Request, Response};
+use brdgme_cmd::requester::{Requester, gamer};
+use brdgme_game::i18n::Locale;
+use tic_tac_toe_2::Game;
+
+fn new_game(r: &mut impl Requester) -> (String, usize) {
+    match r
+        .request(&Request::New {
+            players: 2,
+            seed: Some(1),
+            options: Default::default(),
+        })
+        .unwrap()
+    {
+        Response::New { game, .. } => match game.status {
+            brdgme_game::Status::Active { whose_turn, .. } => (game.state, whose_turn[0]),
+            s => panic!("expected active game, got {:?}", s),
+        },
+        resp => panic!("expected New, got {:?}", resp),
+    }
+}
+
+#[test]
+fn localized_commands_are_accepted() {
+    let mut r = gamer::new::<Game>();
+    let (state, player) = new_game(&mut r);
+    for (locale, word, played) in [
+        (Locale::Es, "jugar e", " jugó "),
+        (Locale::De, "setzen e", " setzte "),
+    ] {
+        let resp = r
+            .request(&Request::Localized {
+                locale,
+                request: Box::new(Request::Play {
+                    player,
+                    command: word.to_string(),
+                    names: vec!["a".into(), "b".into()],
+                    game: state.clone(),
+                }),
+            })
+            .unwrap();
+        match resp {
+            Response::Play { logs, .. } => {
+                assert!(
+                    logs.iter().any(|l| l.content.contains(played)),
+                    "{}: {:?}",
+                    locale,
+                    logs
+                );
+            }
+            resp => panic!("{}: expected Play, got {:?}", locale, resp),
+        }
+    }
+}
+
+#[test]
+fn localized_status_translates_render() {
+    let mut r = gamer::new::<Game>();
+    let (state, _) = new_game(&mut r);
+    match r
+        .request(&Request::Localized {
+            locale: Locale::De,
+            request: Box::new(Request::Status { game: state }),
+        })
+        .unwrap()
+    {
+        Response::Status { public_render, .. } => {
+            assert!(public_render.render.contains("ist X,"));
+            assert!(!public_render.render.contains("is X,"));
+        }
+        resp => panic!("expected Status, got {:?}", resp),
+    }
+}
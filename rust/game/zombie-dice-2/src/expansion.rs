@@ -0,0 +1,86 @@
+//! Expansion dice from Zombie Dice 2: Double Feature and the Santa promo,
+//! each added to the cup by a start option. Faces live on `Dice::faces`
+//! alongside the base dice; this module only decides which dice are in play.
+
+use serde::{Deserialize, Serialize};
+
+use brdgme_game::{GameOption, GameOptions};
+
+use crate::{Colour, Dice};
+
+pub const HUNK_HOTTIE_OPTION: &str = "hunk_hottie";
+pub const SANTA_OPTION: &str = "santa";
+pub const DOUBLE_FEATURE_OPTION: &str = "double_feature";
+
+/// Video store survivor dice added by the Double Feature option.
+pub const SURVIVOR_DICE: usize = 2;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Expansions {
+    /// The Hunk and the Hottie, the hero and heroine who fight back.
+    pub hunk_hottie: bool,
+    /// The Santa die with its helmet and energy drink.
+    pub santa: bool,
+    /// Survivors from the video store, who mostly run.
+    pub double_feature: bool,
+}
+
+impl Expansions {
+    pub fn from_options(options: &GameOptions) -> Self {
+        Expansions {
+            hunk_hottie: options.is_on(HUNK_HOTTIE_OPTION),
+            santa: options.is_on(SANTA_OPTION),
+            double_feature: options.is_on(DOUBLE_FEATURE_OPTION),
+        }
+    }
+
+    /// Expansion colours in play, in the fixed order they are reported in
+    /// `cup_counts`.
+    pub fn colours(self) -> Vec<Colour> {
+        let mut colours = vec![];
+        if self.hunk_hottie {
+            colours.extend([Colour::Hunk, Colour::Hottie]);
+        }
+        if self.santa {
+            colours.push(Colour::Santa);
+        }
+        if self.double_feature {
+            colours.push(Colour::Survivor);
+        }
+        colours
+    }
+
+    /// The expansion dice added to the base 13.
+    pub fn dice(self) -> Vec<Dice> {
+        let mut dice = vec![];
+        for colour in self.colours() {
+            let count = if colour == Colour::Survivor {
+                SURVIVOR_DICE
+            } else {
+                1
+            };
+            dice.extend(std::iter::repeat_n(Dice { colour }, count));
+        }
+        dice
+    }
+}
+
+pub fn options() -> Vec<GameOption> {
+    vec![
+        GameOption::toggle(
+            HUNK_HOTTIE_OPTION,
+            "Hunk and Hottie",
+            "Add the hero and heroine dice: double brains, double shotguns, and shots that steal back a brain",
+        ),
+        GameOption::toggle(
+            SANTA_OPTION,
+            "Santa",
+            "Add the Santa die with double brains, a helmet against one more shotgun and an energy drink",
+        ),
+        GameOption::toggle(
+            DOUBLE_FEATURE_OPTION,
+            "Double Feature survivors",
+            "Add two video store survivor dice that mostly run but can hit with a double shotgun",
+        ),
+    ]
+}
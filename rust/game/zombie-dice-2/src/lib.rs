@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 mod command;
+pub mod expansion;
 mod render;
 
 use brdgme_color as color;
@@ -9,15 +10,19 @@ use brdgme_game::command::parser::Output as ParseOutput;
 use brdgme_game::errors::GameError;
 use brdgme_game::game::gen_placings;
 use brdgme_game::rng::GameRng;
-use brdgme_game::{CommandResponse, Gamer, Log, Status, placings_log};
+use brdgme_game::{CommandResponse, GameOption, GameOptions, Gamer, Log, Status, placings_log};
 use brdgme_markup::Node as N;
 use rand::prelude::*;
 
 use command::Command;
+use expansion::Expansions;
 
-const MIN_PLAYERS: usize = 2;
+const MIN_PLAYERS: usize = 1;
 const MAX_PLAYERS: usize = 8;
 pub const WIN_SCORE: i32 = 13;
+/// Turns in a solo game, which eats as many brains as possible rather than
+/// racing to `WIN_SCORE`.
+pub const SOLO_TURNS: usize = 10;
 pub const ROLL_DICE_COUNT: usize = 3;
 pub const BUST_SHOTGUN_COUNT: i32 = 3;
 
@@ -26,6 +31,13 @@ pub enum Colour {
     Green,
     Yellow,
     Red,
+    /// Double Feature hero, the Hunk.
+    Hunk,
+    /// Double Feature heroine, the Hottie.
+    Hottie,
+    Santa,
+    /// Double Feature video store survivor.
+    Survivor,
 }
 
 impl Colour {
@@ -34,8 +46,18 @@ impl Colour {
             Colour::Green => color::NamedColor::Green,
             Colour::Yellow => color::NamedColor::Yellow,
             Colour::Red => color::NamedColor::Red,
+            Colour::Hunk => color::NamedColor::Blue,
+            Colour::Hottie => color::NamedColor::Pink,
+            Colour::Santa => color::NamedColor::Purple,
+            Colour::Survivor => color::NamedColor::Cyan,
         }
     }
+
+    /// The hero and heroine fight back: a shotgun from either also steals
+    /// back a brain eaten this turn.
+    pub fn is_hero(self) -> bool {
+        matches!(self, Colour::Hunk | Colour::Hottie)
+    }
 }
 
 /// All dice faces in zombie dice. The `faces` for a colour are static and
@@ -72,6 +94,38 @@ impl Dice {
                 Face::Shotgun,
                 Face::Shotgun,
             ],
+            Colour::Hunk => &[
+                Face::DoubleBrain,
+                Face::Footprints,
+                Face::Footprints,
+                Face::Shotgun,
+                Face::Shotgun,
+                Face::DoubleShotgun,
+            ],
+            Colour::Hottie => &[
+                Face::Brain,
+                Face::Footprints,
+                Face::Footprints,
+                Face::Footprints,
+                Face::Shotgun,
+                Face::Shotgun,
+            ],
+            Colour::Santa => &[
+                Face::DoubleBrain,
+                Face::Brain,
+                Face::Helmet,
+                Face::EnergyDrink,
+                Face::Footprints,
+                Face::Shotgun,
+            ],
+            Colour::Survivor => &[
+                Face::Brain,
+                Face::Footprints,
+                Face::Footprints,
+                Face::Footprints,
+                Face::Shotgun,
+                Face::DoubleShotgun,
+            ],
         }
     }
 
@@ -87,6 +141,12 @@ pub enum Face {
     Brain,
     Shotgun,
     Footprints,
+    DoubleBrain,
+    DoubleShotgun,
+    /// Santa's helmet: one more shotgun is needed to bust this turn.
+    Helmet,
+    /// Santa's energy drink: green footprints count as brains this turn.
+    EnergyDrink,
 }
 
 impl Face {
@@ -95,10 +155,39 @@ impl Face {
             Face::Brain => "Brain",
             Face::Shotgun => "Shot",
             Face::Footprints => "Run",
+            Face::DoubleBrain => "2 Brains",
+            Face::DoubleShotgun => "2 Shots",
+            Face::Helmet => "Helmet",
+            Face::EnergyDrink => "Energy",
+        }
+    }
+
+    pub fn brains(self) -> i32 {
+        match self {
+            Face::Brain => 1,
+            Face::DoubleBrain => 2,
+            _ => 0,
+        }
+    }
+
+    pub fn shotguns(self) -> i32 {
+        match self {
+            Face::Shotgun => 1,
+            Face::DoubleShotgun => 2,
+            _ => 0,
         }
     }
 }
 
+fn number_word(n: i32) -> String {
+    match n {
+        3 => "three".to_string(),
+        4 => "four".to_string(),
+        5 => "five".to_string(),
+        _ => n.to_string(),
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct DiceResult {
     pub dice: Dice,
@@ -151,6 +240,13 @@ pub fn all_dice() -> Vec<Dice> {
     ]
 }
 
+/// The full cup for a game: the base dice plus any expansion dice.
+pub fn cup_dice(expansions: Expansions) -> Vec<Dice> {
+    let mut dice = all_dice();
+    dice.extend(expansions.dice());
+    dice
+}
+
 pub fn roll_dice(dice: &[Dice], rng: &mut GameRng) -> DiceResultList {
     dice.iter()
         .map(|d| DiceResult {
@@ -176,6 +272,17 @@ pub struct Game {
     pub kept: DiceResultList,
     pub round_brains: i32,
     pub round_shotguns: i32,
+    #[serde(default)]
+    pub expansions: Expansions,
+    /// Santa helmets rolled this turn, each adding one to the bust count.
+    #[serde(default)]
+    pub round_helmets: i32,
+    /// Whether Santa's energy drink was rolled this turn.
+    #[serde(default)]
+    pub energy_drink: bool,
+    /// Turns completed, kept or bust. Only ends a solo game.
+    #[serde(default)]
+    pub turns: usize,
     // Migration shim: pre-seed games get a fresh RNG on first load.
     // Remove once no pre-RNG games remain active.
     #[serde(default = "GameRng::from_entropy")]
@@ -184,13 +291,13 @@ pub struct Game {
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct PubState {
-    /// Number of players in this game (2 to 8).
+    /// Number of players in this game (1 to 8).
     pub players: usize,
     /// Index of the player whose turn it is.
     pub current_turn: usize,
     /// Banked brain scores for each player, indexed by player.
     pub scores: Vec<i32>,
-    /// Number of dice remaining in the cup by colour, in fixed order Green, Yellow, Red.
+    /// Number of dice remaining in the cup by colour, in fixed order Green, Yellow, Red, then any expansion dice in play.
     pub cup_counts: Vec<(Colour, usize)>,
     /// Dice showing footprints from the latest roll; these will be re-rolled on the next roll.
     pub current_roll: DiceResultList,
@@ -198,14 +305,22 @@ pub struct PubState {
     pub kept: DiceResultList,
     /// Number of brains eaten this turn (not yet banked).
     pub round_brains: i32,
-    /// Number of shotguns taken this turn. Three shotguns busts the turn.
+    /// Number of shotguns taken this turn. Three shotguns busts the turn, one more per helmet.
     pub round_shotguns: i32,
+    /// Expansion dice in play.
+    pub expansions: Expansions,
+    /// Santa helmets rolled this turn; each lets the player survive one more shotgun.
+    pub round_helmets: i32,
+    /// Whether Santa's energy drink was rolled this turn, turning green footprints into brains.
+    pub energy_drink: bool,
     /// True when the game is over.
     pub finished: bool,
     /// Final placings for each player. Only populated when the game is finished; empty during play.
     pub placings: Vec<usize>,
     /// Players participating in a tie-breaker rolloff. Empty when no rolloff is active.
     pub roll_off_players: Vec<usize>,
+    /// Turns completed so far, which ends a solo game at `SOLO_TURNS`.
+    pub turns: usize,
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -256,12 +371,19 @@ impl Game {
     }
 
     fn reset_turn(&mut self) {
-        self.cup = all_dice();
+        self.cup = cup_dice(self.expansions);
         self.shake_cup();
         self.kept = vec![];
         self.current_roll = vec![];
         self.round_brains = 0;
         self.round_shotguns = 0;
+        self.round_helmets = 0;
+        self.energy_drink = false;
+    }
+
+    /// Shotguns needed to bust this turn.
+    pub fn bust_shotguns(&self) -> i32 {
+        BUST_SHOTGUN_COUNT + self.round_helmets
     }
 
     pub fn start_turn(&mut self) -> Vec<Log> {
@@ -272,8 +394,13 @@ impl Game {
     pub fn next_player(&mut self) -> Vec<Log> {
         let mut logs: Vec<Log> = vec![];
         loop {
+            self.turns += 1;
             self.current_turn = (self.current_turn + 1) % self.players;
-            if self.current_turn == 0 {
+            if self.players == 1 && self.turns >= SOLO_TURNS {
+                self.finished = true;
+                return logs;
+            }
+            if self.current_turn == 0 && self.players > 1 {
                 let (score, leaders) = self.leaders();
                 if score >= WIN_SCORE {
                     if leaders.len() == 1 {
@@ -341,26 +468,70 @@ impl Game {
         let mut run: DiceResultList = vec![];
         let mut new_brains = 0;
         let mut was_shot = false;
+        let mut rescuers: Vec<DiceResult> = vec![];
+        if drl.iter().any(|dr| dr.face == Face::EnergyDrink) {
+            self.energy_drink = true;
+        }
         for dr in &drl {
             match dr.face {
-                Face::Brain => {
-                    new_brains += 1;
+                Face::Brain | Face::DoubleBrain => {
+                    new_brains += dr.face.brains();
                     self.kept.push(*dr);
                 }
-                Face::Shotgun => {
-                    self.round_shotguns += 1;
+                Face::Shotgun | Face::DoubleShotgun => {
+                    self.round_shotguns += dr.face.shotguns();
                     self.kept.push(*dr);
                     was_shot = true;
+                    if dr.dice.colour.is_hero() {
+                        rescuers.push(*dr);
+                    }
+                }
+                Face::Footprints if self.energy_drink && dr.dice.colour == Colour::Green => {
+                    // Fuelled by the energy drink, the zombie catches the
+                    // runner.
+                    new_brains += 1;
+                    self.kept.push(DiceResult {
+                        face: Face::Brain,
+                        ..*dr
+                    });
                 }
                 Face::Footprints => {
                     run.push(*dr);
                 }
+                Face::Helmet => {
+                    self.round_helmets += 1;
+                    self.kept.push(*dr);
+                }
+                Face::EnergyDrink => {
+                    self.kept.push(*dr);
+                }
             }
         }
-        if self.round_shotguns >= BUST_SHOTGUN_COUNT {
+        for rescuer in rescuers {
+            // The hero steals back the most recently eaten brain, whose die
+            // goes back in the cup.
+            let Some(i) = self.kept.iter().rposition(|k| k.face.brains() > 0) else {
+                break;
+            };
+            let victim = self.kept.remove(i);
+            new_brains -= victim.face.brains();
+            self.cup.push(victim.dice);
             logs.push(Log::public(vec![
+                render::render_dice_result(rescuer),
+                N::text(" stole back "),
+                render::render_dice_result(victim),
+                N::text(" from "),
                 N::Player(self.current_turn),
-                N::text(" got shot three times and lost "),
+            ]));
+        }
+        let bust_shotguns = self.bust_shotguns();
+        if self.round_shotguns >= bust_shotguns {
+            logs.push(Log::public(vec![
+                N::Player(self.current_turn),
+                N::text(format!(
+                    " got shot {} times and lost ",
+                    number_word(bust_shotguns)
+                )),
                 N::Bold(vec![N::text(self.round_brains.to_string())]),
                 N::text(" brains!"),
             ]));
@@ -370,7 +541,7 @@ impl Game {
                 N::Player(self.current_turn),
                 N::text(" has "),
                 N::Bold(vec![N::text(
-                    (BUST_SHOTGUN_COUNT - self.round_shotguns).to_string(),
+                    (bust_shotguns - self.round_shotguns).to_string(),
                 )]),
                 N::text(" health remaining"),
             ]));
@@ -433,6 +604,19 @@ impl Gamer for Game {
     type PlayerState = PlayerState;
 
     fn start(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError> {
+        Self::start_with_options(players, seed, &GameOptions::new())
+    }
+
+    fn options() -> Vec<GameOption> {
+        expansion::options()
+    }
+
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let options = options.resolve(&Self::options())?;
         if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
             return Err(GameError::PlayerCount {
                 min: MIN_PLAYERS,
@@ -443,6 +627,7 @@ impl Gamer for Game {
         let mut g = Game {
             players,
             scores: vec![0; players],
+            expansions: Expansions::from_options(&options),
             rng: GameRng::seed_from_u64(seed),
             ..Game::default()
         };
@@ -451,8 +636,8 @@ impl Gamer for Game {
     }
 
     fn validate(&self) -> Result<(), GameError> {
-        if self.players < 2 {
-            return Err(GameError::internal("zombie-dice-2: players < 2"));
+        if self.players < MIN_PLAYERS {
+            return Err(GameError::internal("zombie-dice-2: players < 1"));
         }
         if self.scores.len() != self.players {
             return Err(GameError::internal(
@@ -472,7 +657,7 @@ impl Gamer for Game {
             }
         }
         let total_dice = self.cup.len() + self.kept.len() + self.current_roll.len();
-        if total_dice != all_dice().len() {
+        if total_dice != cup_dice(self.expansions).len() {
             return Err(GameError::internal(
                 "zombie-dice-2: dice not conserved across cup, kept, and current_roll",
             ));
@@ -485,6 +670,8 @@ impl Gamer for Game {
             Status::Finished {
                 placings: self.placings(),
                 stats: vec![],
+                teams: vec![],
+                cooperative: None,
             }
         } else {
             Status::Active {
@@ -499,27 +686,18 @@ impl Gamer for Game {
             players: self.players,
             current_turn: self.current_turn,
             scores: self.scores.clone(),
-            cup_counts: {
-                let mut green = 0;
-                let mut yellow = 0;
-                let mut red = 0;
-                for d in &self.cup {
-                    match d.colour {
-                        Colour::Green => green += 1,
-                        Colour::Yellow => yellow += 1,
-                        Colour::Red => red += 1,
-                    }
-                }
-                vec![
-                    (Colour::Green, green),
-                    (Colour::Yellow, yellow),
-                    (Colour::Red, red),
-                ]
-            },
+            cup_counts: [Colour::Green, Colour::Yellow, Colour::Red]
+                .into_iter()
+                .chain(self.expansions.colours())
+                .map(|c| (c, self.cup.iter().filter(|d| d.colour == c).count()))
+                .collect(),
             current_roll: self.current_roll.clone(),
             kept: self.kept.clone(),
             round_brains: self.round_brains,
             round_shotguns: self.round_shotguns,
+            expansions: self.expansions,
+            round_helmets: self.round_helmets,
+            energy_drink: self.energy_drink,
             finished: self.finished,
             placings: if self.finished {
                 self.placings()
@@ -527,6 +705,7 @@ impl Gamer for Game {
                 vec![]
             },
             roll_off_players: self.roll_off_players.clone(),
+            turns: self.turns,
         }
     }
 
@@ -584,7 +763,7 @@ impl Gamer for Game {
     }
 
     fn player_counts() -> Vec<usize> {
-        vec![2, 3, 4, 5, 6, 7, 8]
+        vec![1, 2, 3, 4, 5, 6, 7, 8]
     }
 
     fn player_count(&self) -> usize {
@@ -627,13 +806,31 @@ mod tests {
 
     #[test]
     fn test_player_counts() {
-        assert_eq!(vec![2, 3, 4, 5, 6, 7, 8], Game::player_counts());
-        assert!(Game::start(1, 1).is_err());
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8], Game::player_counts());
+        assert!(Game::start(0, 1).is_err());
+        assert!(Game::start(1, 1).is_ok());
         assert!(Game::start(9, 1).is_err());
         assert!(Game::start(2, 1).is_ok());
         assert!(Game::start(8, 1).is_ok());
     }
 
+    #[test]
+    fn solo_game_ends_after_ten_turns_regardless_of_score() {
+        let (mut g, _) = Game::start(1, 1).unwrap();
+        g.scores = vec![WIN_SCORE];
+        g.command(0, "keep", &[]).unwrap();
+        // Reaching the target score does not end a solo game.
+        assert!(!g.finished);
+        while !g.finished {
+            g.command(0, "keep", &[]).unwrap();
+        }
+        assert_eq!(SOLO_TURNS, g.turns);
+        assert!(g.scores[0] >= WIN_SCORE);
+        assert!(g.roll_off_players.is_empty());
+        assert_eq!(vec![1], g.placings());
+        assert!(!g.can_roll(0));
+    }
+
     #[test]
     fn test_start_initial_state() {
         let (g, logs) = Game::start(2, 1).unwrap();
@@ -1165,4 +1362,178 @@ mod tests {
                 .any(|r| r.contains("wins!") || r.contains("tie!"))
         );
     }
+
+    fn start_with(option: &str) -> Game {
+        Game::start_with_options(2, 1, &GameOptions::new().with(option, "on"))
+            .unwrap()
+            .0
+    }
+
+    /// Sets up a fresh turn for player 0 that will roll exactly `dice`.
+    fn turn_with_cup(g: &mut Game, dice: &[Colour]) {
+        g.current_turn = 0;
+        g.kept = vec![];
+        g.current_roll = vec![];
+        g.round_brains = 0;
+        g.round_shotguns = 0;
+        g.round_helmets = 0;
+        g.energy_drink = false;
+        g.cup = dice.iter().map(|&colour| Dice { colour }).collect();
+    }
+
+    #[test]
+    fn test_expansion_dice_are_added_to_the_cup() {
+        let g = start_with(expansion::HUNK_HOTTIE_OPTION);
+        assert_eq!(15, cup_dice(g.expansions).len());
+        assert!(g.validate().is_ok());
+        let colours: Vec<Colour> = g.pub_state().cup_counts.iter().map(|(c, _)| *c).collect();
+        assert_eq!(
+            vec![
+                Colour::Green,
+                Colour::Yellow,
+                Colour::Red,
+                Colour::Hunk,
+                Colour::Hottie
+            ],
+            colours
+        );
+        assert_eq!(
+            14,
+            cup_dice(start_with(expansion::SANTA_OPTION).expansions).len()
+        );
+        assert_eq!(
+            15,
+            cup_dice(start_with(expansion::DOUBLE_FEATURE_OPTION).expansions).len()
+        );
+        // The base game is unchanged.
+        assert_eq!(13, cup_dice(Game::start(2, 1).unwrap().0.expansions).len());
+    }
+
+    #[test]
+    fn test_expansion_dice_faces() {
+        for colour in [
+            Colour::Hunk,
+            Colour::Hottie,
+            Colour::Santa,
+            Colour::Survivor,
+        ] {
+            assert_eq!(6, Dice { colour }.faces().len());
+        }
+        let santa = Dice {
+            colour: Colour::Santa,
+        };
+        assert!(santa.faces().contains(&Face::Helmet));
+        assert!(santa.faces().contains(&Face::EnergyDrink));
+        assert_eq!(2, Face::DoubleBrain.brains());
+        assert_eq!(2, Face::DoubleShotgun.shotguns());
+    }
+
+    #[test]
+    fn test_double_brain_counts_two() {
+        let mut g = start_with(expansion::HUNK_HOTTIE_OPTION);
+        turn_with_cup(&mut g, &[Colour::Hunk, Colour::Green, Colour::Green]);
+        let seed = seed_where(&g, |probe| {
+            let mut probe = probe.clone();
+            probe.roll();
+            probe.current_turn == 0
+                && probe
+                    .kept
+                    .iter()
+                    .any(|k| k.face == Face::DoubleBrain && k.dice.colour == Colour::Hunk)
+        });
+        g.rng = GameRng::seed_from_u64(seed);
+        g.roll();
+        let kept_brains: i32 = g.kept.iter().map(|k| k.face.brains()).sum();
+        assert!(g.round_brains >= 2);
+        assert_eq!(kept_brains, g.round_brains);
+    }
+
+    #[test]
+    fn test_double_shotgun_busts_from_two() {
+        let mut g = start_with(expansion::DOUBLE_FEATURE_OPTION);
+        turn_with_cup(&mut g, &[Colour::Survivor, Colour::Green, Colour::Green]);
+        g.round_shotguns = 1;
+        let seed = seed_where(&g, |probe| {
+            let mut probe = probe.clone();
+            probe
+                .roll()
+                .iter()
+                .any(|l| brdgme_markup::to_string(&l.content).contains("2 Shots"))
+        });
+        g.rng = GameRng::seed_from_u64(seed);
+        let logs = g.roll();
+        assert!(
+            logs.iter()
+                .any(|l| brdgme_markup::to_string(&l.content).contains("got shot three times"))
+        );
+    }
+
+    #[test]
+    fn test_helmet_survives_an_extra_shotgun() {
+        let mut g = start_with(expansion::SANTA_OPTION);
+        turn_with_cup(&mut g, &[Colour::Red, Colour::Green, Colour::Green]);
+        g.round_helmets = 1;
+        g.round_shotguns = 2;
+        let seed = seed_where(&g, |probe| {
+            let mut probe = probe.clone();
+            probe.roll();
+            probe.round_shotguns == 3
+        });
+        g.rng = GameRng::seed_from_u64(seed);
+        let logs = g.roll();
+        assert_eq!(0, g.current_turn);
+        assert_eq!(4, g.bust_shotguns());
+        assert!(
+            logs.iter()
+                .any(|l| brdgme_markup::to_string(&l.content).contains("health remaining"))
+        );
+    }
+
+    #[test]
+    fn test_energy_drink_catches_green_runners() {
+        let mut g = start_with(expansion::SANTA_OPTION);
+        turn_with_cup(&mut g, &[Colour::Green, Colour::Green, Colour::Green]);
+        g.energy_drink = true;
+        let seed = seed_where(&g, |probe| {
+            let mut probe = probe.clone();
+            probe.roll();
+            probe.current_turn == 0
+        });
+        g.rng = GameRng::seed_from_u64(seed);
+        g.roll();
+        assert!(g.current_roll.is_empty());
+        assert_eq!(
+            3 - g.round_shotguns,
+            g.round_brains,
+            "every green die not shot is eaten"
+        );
+    }
+
+    #[test]
+    fn test_hero_shotgun_steals_back_a_brain() {
+        let mut g = start_with(expansion::HUNK_HOTTIE_OPTION);
+        turn_with_cup(&mut g, &[Colour::Hottie, Colour::Red, Colour::Red]);
+        g.kept = vec![DiceResult {
+            dice: Dice {
+                colour: Colour::Green,
+            },
+            face: Face::Brain,
+        }];
+        g.round_brains = 1;
+        let seed = seed_where(&g, |probe| {
+            let mut probe = probe.clone();
+            let logs = probe.roll();
+            probe.current_turn == 0
+                && probe.round_shotguns == 1
+                && probe.round_brains == 0
+                && logs
+                    .iter()
+                    .any(|l| brdgme_markup::to_string(&l.content).contains("stole back"))
+        });
+        g.rng = GameRng::seed_from_u64(seed);
+        g.roll();
+        assert_eq!(0, g.round_brains);
+        assert!(g.kept.iter().all(|k| k.face.brains() == 0));
+        assert!(g.cup.iter().any(|d| d.colour == Colour::Green));
+    }
 }
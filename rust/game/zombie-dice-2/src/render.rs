@@ -2,7 +2,7 @@ use brdgme_color as color;
 use brdgme_game::Renderer;
 use brdgme_markup::{Align as A, Node as N, Row, table_with_gap};
 
-use crate::{Colour, DiceResult, PlayerState, PubState};
+use crate::{BUST_SHOTGUN_COUNT, Colour, DiceResult, PlayerState, PubState};
 
 /// Port of Go `brdgme.CommaList` over markup nodes: "a", "a and b",
 /// "a, b and c".
@@ -73,6 +73,10 @@ fn colour_name(c: Colour) -> &'static str {
         Colour::Green => "green",
         Colour::Yellow => "yellow",
         Colour::Red => "red",
+        Colour::Hunk => "Hunk",
+        Colour::Hottie => "Hottie",
+        Colour::Santa => "Santa",
+        Colour::Survivor => "survivor",
     }
 }
 
@@ -80,38 +84,62 @@ impl Renderer for PubState {
     fn render(&self) -> Vec<N> {
         let mut out: Vec<N> = vec![];
 
-        // Main status table: Brains / Shots / Runners / Kept / In cup.
-        out.push(table_with_gap(
-            &[
-                vec![
-                    (A::Right, vec![N::text("Brains")]),
-                    (
-                        A::Left,
-                        vec![N::Bold(vec![N::text(self.round_brains.to_string())])],
-                    ),
-                ],
-                vec![
-                    (A::Right, vec![N::text("Shots:")]),
-                    (
-                        A::Left,
-                        vec![N::Bold(vec![N::text(self.round_shotguns.to_string())])],
-                    ),
-                ],
-                vec![
-                    (A::Right, vec![N::text("Runners:")]),
-                    (A::Left, vec![render_dice_result_list(&self.current_roll)]),
-                ],
-                vec![
-                    (A::Right, vec![N::text("Kept:")]),
-                    (A::Left, vec![render_dice_result_list(&self.kept)]),
-                ],
-                vec![
-                    (A::Right, vec![N::text("In cup:")]),
-                    (A::Left, vec![render_cup(&self.cup_counts)]),
-                ],
+        // Main status table: Brains / Shots / Runners / Kept / In cup, plus
+        // any Santa power-ups this turn.
+        let mut status_rows: Vec<Row> = vec![
+            vec![
+                (A::Right, vec![N::text("Brains")]),
+                (
+                    A::Left,
+                    vec![N::Bold(vec![N::text(self.round_brains.to_string())])],
+                ),
+            ],
+            vec![
+                (A::Right, vec![N::text("Shots:")]),
+                (
+                    A::Left,
+                    vec![N::Bold(vec![N::text(self.round_shotguns.to_string())])],
+                ),
+            ],
+            vec![
+                (A::Right, vec![N::text("Runners:")]),
+                (A::Left, vec![render_dice_result_list(&self.current_roll)]),
+            ],
+            vec![
+                (A::Right, vec![N::text("Kept:")]),
+                (A::Left, vec![render_dice_result_list(&self.kept)]),
+            ],
+            vec![
+                (A::Right, vec![N::text("In cup:")]),
+                (A::Left, vec![render_cup(&self.cup_counts)]),
             ],
-            2,
-        ));
+        ];
+        if self.round_helmets > 0 {
+            status_rows.push(vec![
+                (A::Right, vec![N::text("Helmets:")]),
+                (
+                    A::Left,
+                    vec![N::Bold(vec![N::text(format!(
+                        "{} (bust at {} shots)",
+                        self.round_helmets,
+                        BUST_SHOTGUN_COUNT + self.round_helmets
+                    ))])],
+                ),
+            ]);
+        }
+        if self.energy_drink {
+            status_rows.push(vec![
+                (A::Right, vec![N::text("Energy:")]),
+                (
+                    A::Left,
+                    vec![N::Fg(
+                        Colour::Green.to_color().into(),
+                        vec![N::text("green runners count as brains")],
+                    )],
+                ),
+            ]);
+        }
+        out.push(table_with_gap(&status_rows, 2));
 
         out.push(N::Bold(vec![N::text("\n\n\nScores:\n")]));
 
@@ -4,7 +4,8 @@ use time::PrimitiveDateTime;
 
 use brdgme_game::command::Spec as CommandSpec;
 use brdgme_game::errors::GameError;
-use brdgme_game::{Gamer, Log, Status};
+use brdgme_game::{GameOption, GameOptions, Gamer, Log, Status};
+use brdgme_i18n::{Catalog, Locale};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum Request {
@@ -12,7 +13,14 @@ pub enum Request {
     New {
         players: usize,
         seed: Option<u64>,
+        /// Values for the game's `Options`; unset options take their
+        /// default. Omitted when empty so Go V1 services never see it.
+        #[serde(default, skip_serializing_if = "GameOptions::is_empty")]
+        options: GameOptions,
     },
+    /// Lists the choices offered when creating a game. Only Rust game
+    /// services understand this variant.
+    Options,
     Status {
         game: String,
     },
@@ -41,6 +49,19 @@ pub enum Request {
         game: String,
         player: usize,
     },
+    /// Runs `request` for a player reading `locale`: localized command words
+    /// are accepted in `Play`, and renders, logs, command specs, rules and
+    /// strategy text in the response are translated through the game's
+    /// catalog. Only Rust game services understand this variant.
+    Localized {
+        locale: Locale,
+        request: Box<Request>,
+    },
+    /// Fetches the game's catalog for `locale`, for callers that store
+    /// canonical English logs and translate them per viewer.
+    Catalog {
+        locale: Locale,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -123,6 +144,9 @@ pub enum Response {
     PlayerCounts {
         player_counts: Vec<usize>,
     },
+    Options {
+        options: Vec<GameOption>,
+    },
     New {
         game: GameResponse,
         logs: Vec<CliLog>,
@@ -161,6 +185,9 @@ pub enum Response {
     AdvancedStrategy {
         strategy: String,
     },
+    Catalog {
+        catalog: Catalog,
+    },
     UserError {
         message: String,
     },
@@ -233,11 +260,38 @@ mod tests {
     #[test]
     fn new_request_without_seed_deserializes_to_none() {
         match serde_json::from_str::<Request>(r#"{"New":{"players":2}}"#).unwrap() {
-            Request::New { players, seed } => {
+            Request::New {
+                players,
+                seed,
+                options,
+            } => {
                 assert_eq!(2, players);
                 assert_eq!(None, seed);
+                assert!(options.is_empty());
             }
             r => panic!("expected New, got {:?}", r),
         }
     }
+
+    #[test]
+    fn new_request_omits_empty_options() {
+        let plain = Request::New {
+            players: 2,
+            seed: Some(1),
+            options: GameOptions::new(),
+        };
+        assert_eq!(
+            r#"{"New":{"players":2,"seed":1}}"#,
+            serde_json::to_string(&plain).unwrap()
+        );
+        let advanced = Request::New {
+            players: 2,
+            seed: Some(1),
+            options: GameOptions::new().with("mode", "advanced"),
+        };
+        assert_eq!(
+            r#"{"New":{"players":2,"seed":1,"options":{"mode":"advanced"}}}"#,
+            serde_json::to_string(&advanced).unwrap()
+        );
+    }
 }
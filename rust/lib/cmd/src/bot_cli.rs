@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use brdgme_game::command::Spec as CommandSpec;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Request {
     pub player: usize,
     pub player_state: String,
@@ -10,3 +10,22 @@ pub struct Request {
     pub command_spec: CommandSpec,
     pub game_id: Option<String>,
 }
+
+/// A command the game rejected earlier in the same turn.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Rejection {
+    pub command: String,
+    pub error: String,
+}
+
+/// A turn for an LLM bot played outside the bot service, as `bot arena`
+/// reads it: the bot request plus the public state and rules the live bot
+/// prompts with. `bot arena` answers with a JSON list of `BotCommand`s.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LlmRequest {
+    pub request: Request,
+    /// The public state, as JSON.
+    pub pub_state: String,
+    pub rules: String,
+    pub rejected: Vec<Rejection>,
+}
@@ -127,6 +127,7 @@ mod tests {
                 serde_json::to_vec(&Request::New {
                     players: 2,
                     seed: Some(1),
+                    options: Default::default(),
                 })
                 .unwrap(),
             ))
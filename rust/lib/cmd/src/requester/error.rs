@@ -18,12 +18,25 @@ pub enum RequestError {
     Stdin,
     #[error("child process exited with {status}")]
     ChildExit { status: std::process::ExitStatus },
+    #[cfg(feature = "http-client")]
+    #[error("HTTP error: {source}")]
+    Http {
+        #[from]
+        source: reqwest::Error,
+    },
+    #[cfg(feature = "http-client")]
+    #[error("game service returned {status}")]
+    HttpStatus { status: reqwest::StatusCode },
 }
 
 #[derive(Debug, Error)]
 pub enum ParseArgsError {
-    #[error("expected type argument, one of 'local'")]
+    #[error("expected type argument, one of 'local' or 'http'")]
     TypeMissing,
     #[error("path argument missing")]
     PathMissing,
+    #[error("URL argument missing")]
+    UrlMissing,
+    #[error("'http' requires brdgme_cmd to be built with the http-client feature")]
+    HttpUnsupported,
 }
@@ -4,8 +4,10 @@ use std::marker::PhantomData;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
+use brdgme_game::command::parser::Aliases;
 use brdgme_game::errors::GameError;
-use brdgme_game::{CommandResponse, Gamer, Renderer};
+use brdgme_game::{CommandResponse, GameOptions, Gamer, Renderer};
+use brdgme_i18n::{Catalog, I18nError, Locale};
 
 use crate::api::{
     CliLog, GameResponse, GameResponseError, PlayerRender, PubRender, Request, Response,
@@ -38,8 +40,15 @@ fn check_player<G: Gamer>(player: usize, game: &G) -> Option<Response> {
 impl<G: Gamer + Debug + Clone + Serialize + DeserializeOwned> Requester for GameRequester<G> {
     fn request(&mut self, req: &Request) -> Result<Response, RequestError> {
         match *req {
-            Request::New { players, seed } => Ok(handle_new::<G>(players, seed)),
+            Request::New {
+                players,
+                seed,
+                ref options,
+            } => Ok(handle_new::<G>(players, seed, options)),
             Request::PlayerCounts => Ok(handle_player_counts::<G>()),
+            Request::Options => Ok(Response::Options {
+                options: G::options(),
+            }),
             Request::Status { ref game } => {
                 let game: G = serde_json::from_str(game)?;
                 if let Err(e) = game.validate() {
@@ -121,10 +130,190 @@ impl<G: Gamer + Debug + Clone + Serialize + DeserializeOwned> Requester for Game
                 }
                 Ok(handle_advanced_strategy::<G>())
             }
+            Request::Localized {
+                locale,
+                ref request,
+            } => self.handle_localized(locale, request),
+            Request::Catalog { locale } => Ok(match load_catalog::<G>(locale) {
+                Ok(catalog) => Response::Catalog { catalog },
+                Err(e) => Response::SystemError {
+                    message: e.to_string(),
+                },
+            }),
         }
     }
 }
 
+impl<G: Gamer + Debug + Clone + Serialize + DeserializeOwned> GameRequester<G> {
+    fn handle_localized(
+        &mut self,
+        locale: Locale,
+        req: &Request,
+    ) -> Result<Response, RequestError> {
+        let catalog = match load_catalog::<G>(locale) {
+            Ok(catalog) => catalog,
+            Err(e) => {
+                return Ok(Response::SystemError {
+                    message: e.to_string(),
+                });
+            }
+        };
+        let aliases = Aliases::from(&catalog);
+        let response = match *req {
+            Request::Localized { .. } => {
+                return Ok(Response::UserError {
+                    message: "Localized requests cannot be nested".to_string(),
+                });
+            }
+            Request::Play {
+                player,
+                ref command,
+                ref names,
+                ref game,
+            } => self.request(&Request::Play {
+                player,
+                command: aliases.apply(command, names),
+                names: names.clone(),
+                game: game.clone(),
+            })?,
+            ref req => self.request(req)?,
+        };
+        Ok(
+            localize_response(response, &catalog, &aliases).unwrap_or_else(|e| {
+                Response::SystemError {
+                    message: e.to_string(),
+                }
+            }),
+        )
+    }
+}
+
+fn load_catalog<G: Gamer>(locale: Locale) -> Result<Catalog, I18nError> {
+    match G::catalog_yaml(locale) {
+        Some(source) => Catalog::from_yaml(source),
+        None => Ok(Catalog::default()),
+    }
+}
+
+fn localize_logs(logs: Vec<CliLog>, catalog: &Catalog) -> Result<Vec<CliLog>, I18nError> {
+    logs.into_iter()
+        .map(|log| {
+            Ok(CliLog {
+                content: catalog.translate_markup(&log.content)?,
+                ..log
+            })
+        })
+        .collect()
+}
+
+fn localize_pub_render(render: PubRender, catalog: &Catalog) -> Result<PubRender, I18nError> {
+    Ok(PubRender {
+        render: catalog.translate_markup(&render.render)?,
+        ..render
+    })
+}
+
+fn localize_player_render(
+    render: PlayerRender,
+    catalog: &Catalog,
+    aliases: &Aliases,
+) -> Result<PlayerRender, I18nError> {
+    Ok(PlayerRender {
+        render: catalog.translate_markup(&render.render)?,
+        command_spec: render.command_spec.map(|s| aliases.localize_spec(&s)),
+        ..render
+    })
+}
+
+fn localize_player_renders(
+    renders: Vec<PlayerRender>,
+    catalog: &Catalog,
+    aliases: &Aliases,
+) -> Result<Vec<PlayerRender>, I18nError> {
+    renders
+        .into_iter()
+        .map(|r| localize_player_render(r, catalog, aliases))
+        .collect()
+}
+
+/// Translates the player-facing text of a response. Game state, points and
+/// status are left untouched so a localized response is interchangeable
+/// with the plain one for everything but display.
+fn localize_response(
+    response: Response,
+    catalog: &Catalog,
+    aliases: &Aliases,
+) -> Result<Response, I18nError> {
+    Ok(match response {
+        Response::New {
+            game,
+            logs,
+            public_render,
+            player_renders,
+            seed,
+        } => Response::New {
+            game,
+            logs: localize_logs(logs, catalog)?,
+            public_render: localize_pub_render(public_render, catalog)?,
+            player_renders: localize_player_renders(player_renders, catalog, aliases)?,
+            seed,
+        },
+        Response::Status {
+            game,
+            public_render,
+            player_renders,
+        } => Response::Status {
+            game,
+            public_render: localize_pub_render(public_render, catalog)?,
+            player_renders: localize_player_renders(player_renders, catalog, aliases)?,
+        },
+        Response::Play {
+            game,
+            logs,
+            can_undo,
+            remaining_input,
+            public_render,
+            player_renders,
+        } => Response::Play {
+            game,
+            logs: localize_logs(logs, catalog)?,
+            can_undo,
+            remaining_input,
+            public_render: localize_pub_render(public_render, catalog)?,
+            player_renders: localize_player_renders(player_renders, catalog, aliases)?,
+        },
+        Response::PubRender { render } => Response::PubRender {
+            render: localize_pub_render(render, catalog)?,
+        },
+        Response::PlayerRender { render } => Response::PlayerRender {
+            render: localize_player_render(render, catalog, aliases)?,
+        },
+        Response::Rules { rules } => Response::Rules {
+            rules: catalog.rules.clone().unwrap_or(rules),
+        },
+        Response::Options { options } => Response::Options {
+            options: options
+                .into_iter()
+                .map(|mut o| {
+                    o.name = catalog.text(&o.name).to_string();
+                    o.desc = catalog.text(&o.desc).to_string();
+                    for v in &mut o.values {
+                        v.name = catalog.text(&v.name).to_string();
+                    }
+                    o
+                })
+                .collect(),
+        },
+        Response::BasicStrategy { strategy } => Response::BasicStrategy {
+            strategy: catalog.basic_strategy.clone().unwrap_or(strategy),
+        },
+        Response::AdvancedStrategy { strategy } => Response::AdvancedStrategy {
+            strategy: catalog.advanced_strategy.clone().unwrap_or(strategy),
+        },
+        response => response,
+    })
+}
+
 fn handle_player_counts<G: Gamer + Debug + Clone + Serialize + DeserializeOwned>() -> Response {
     Response::PlayerCounts {
         player_counts: G::player_counts(),
@@ -154,9 +343,10 @@ pub fn renders<G: Gamer + Debug + Clone + Serialize + DeserializeOwned>(
 fn handle_new<G: Gamer + Debug + Clone + Serialize + DeserializeOwned>(
     players: usize,
     seed: Option<u64>,
+    options: &GameOptions,
 ) -> Response {
     let seed = seed.unwrap_or_else(rand::random);
-    match G::start(players, seed) {
+    match G::start_with_options(players, seed, options) {
         Ok((game, logs)) => GameResponse::from_gamer(&game)
             .and_then(|gs| {
                 let (public_render, player_renders) = renders(&game)?;
@@ -336,6 +526,106 @@ mod tests {
         }
     }
 
+    fn localized(locale: Locale, request: Request) -> Request {
+        Request::Localized {
+            locale,
+            request: Box::new(request),
+        }
+    }
+
+    #[test]
+    fn localized_status_translates_renders_but_not_state() {
+        let state = serde_json::to_string(&TestGame::start(2, 1).unwrap().0).unwrap();
+        let mut r = new::<TestGame>();
+        match r
+            .request(&localized(
+                Locale::Es,
+                Request::Status {
+                    game: state.clone(),
+                },
+            ))
+            .unwrap()
+        {
+            Response::Status {
+                game,
+                public_render,
+                player_renders,
+            } => {
+                assert_eq!(state, game.state);
+                assert_eq!("prueba", public_render.render);
+                assert_eq!("prueba", player_renders[1].render);
+            }
+            resp => panic!("expected Status, got {:?}", resp),
+        }
+    }
+
+    #[test]
+    fn localized_play_accepts_aliases() {
+        let state = serde_json::to_string(&TestGame::start(2, 1).unwrap().0).unwrap();
+        let mut r = new::<TestGame>();
+        match r
+            .request(&localized(
+                Locale::Es,
+                Request::Play {
+                    player: 0,
+                    command: "jugar".to_string(),
+                    names: vec!["a".to_string(), "b".to_string()],
+                    game: state,
+                },
+            ))
+            .unwrap()
+        {
+            Response::Play { game, .. } => {
+                let game: TestGame = serde_json::from_str(&game.state).unwrap();
+                assert_eq!(1, game.plays);
+            }
+            resp => panic!("expected Play, got {:?}", resp),
+        }
+    }
+
+    #[test]
+    fn localized_rules_fall_back_to_english_without_catalog() {
+        let mut r = new::<TestGame>();
+        match r.request(&localized(Locale::Es, Request::Rules)).unwrap() {
+            Response::Rules { rules } => assert_eq!("Juega.", rules),
+            resp => panic!("expected Rules, got {:?}", resp),
+        }
+        match r.request(&localized(Locale::De, Request::Rules)).unwrap() {
+            Response::Rules { rules } => assert_eq!("Play.", rules),
+            resp => panic!("expected Rules, got {:?}", resp),
+        }
+    }
+
+    #[test]
+    fn nested_localized_request_is_rejected() {
+        let mut r = new::<TestGame>();
+        match r
+            .request(&localized(
+                Locale::Es,
+                localized(Locale::De, Request::Rules),
+            ))
+            .unwrap()
+        {
+            Response::UserError { .. } => {}
+            resp => panic!("expected UserError, got {:?}", resp),
+        }
+    }
+
+    #[test]
+    fn catalog_request_returns_parsed_catalog() {
+        let mut r = new::<TestGame>();
+        match r.request(&Request::Catalog { locale: Locale::Es }).unwrap() {
+            Response::Catalog { catalog } => {
+                assert_eq!("prueba", catalog.text("test"));
+                assert_eq!(
+                    Some("play"),
+                    catalog.aliases.get("jugar").map(String::as_str)
+                );
+            }
+            resp => panic!("expected Catalog, got {:?}", resp),
+        }
+    }
+
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     struct PanicGame {
         players: usize,
@@ -0,0 +1,32 @@
+use crate::api::{Request, Response};
+use crate::requester::Requester;
+use crate::requester::error::RequestError;
+
+/// Sends requests to a running game service over HTTP, the same wire format
+/// `crate::http::serve` accepts. Intended for developer tools; in-cluster
+/// callers must use `brdgme_game_client`, which sets the interceptor Host
+/// header.
+pub struct HttpRequester {
+    url: String,
+    client: reqwest::blocking::Client,
+}
+
+impl HttpRequester {
+    pub fn new<I: Into<String>>(url: I) -> Self {
+        HttpRequester {
+            url: url.into(),
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+}
+
+impl Requester for HttpRequester {
+    fn request(&mut self, req: &Request) -> Result<Response, RequestError> {
+        let resp = self.client.post(&self.url).json(req).send()?;
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(RequestError::HttpStatus { status });
+        }
+        Ok(serde_json::from_slice(&resp.bytes()?)?)
+    }
+}
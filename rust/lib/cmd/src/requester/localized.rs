@@ -0,0 +1,56 @@
+use brdgme_i18n::Locale;
+
+use crate::api::{Request, Response};
+use crate::requester::Requester;
+use crate::requester::error::RequestError;
+
+/// Wraps every request in `Request::Localized`, so a client can play a
+/// Rust game service in another language without changing its requests.
+pub struct LocalizedRequester<T: Requester> {
+    inner: T,
+    locale: Locale,
+}
+
+impl<T: Requester> LocalizedRequester<T> {
+    pub fn new(inner: T, locale: Locale) -> Self {
+        Self { inner, locale }
+    }
+}
+
+impl<T: Requester> Requester for LocalizedRequester<T> {
+    fn request(&mut self, req: &Request) -> Result<Response, RequestError> {
+        // English needs no wrapping, which keeps interface v1 services usable.
+        if self.locale == Locale::En {
+            return self.inner.request(req);
+        }
+        self.inner.request(&Request::Localized {
+            locale: self.locale,
+            request: Box::new(req.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requester::gamer;
+    use crate::test_game::TestGame;
+
+    #[test]
+    fn rules_are_requested_in_locale() {
+        let mut r = LocalizedRequester::new(gamer::new::<TestGame>(), Locale::Es);
+        match r.request(&Request::Rules).unwrap() {
+            Response::Rules { rules } => assert_eq!("Juega.", rules),
+            resp => panic!("expected Rules, got {:?}", resp),
+        }
+    }
+
+    #[test]
+    fn english_requests_are_passed_through() {
+        let mut r = LocalizedRequester::new(gamer::new::<TestGame>(), Locale::En);
+        match r.request(&Request::Rules).unwrap() {
+            Response::Rules { rules } => assert_eq!("Play.", rules),
+            resp => panic!("expected Rules, got {:?}", resp),
+        }
+    }
+}
@@ -2,7 +2,10 @@ use crate::api::{Request, Response};
 
 pub mod error;
 pub mod gamer;
+#[cfg(feature = "http-client")]
+pub mod http;
 pub mod local;
+pub mod localized;
 
 use crate::requester::error::{ParseArgsError, RequestError};
 
@@ -10,7 +13,16 @@ pub trait Requester {
     fn request(&mut self, req: &Request) -> Result<Response, RequestError>;
 }
 
-pub fn parse_args(args: &[String]) -> Result<impl Requester + use<>, ParseArgsError> {
+impl<T: Requester + ?Sized> Requester for Box<T> {
+    fn request(&mut self, req: &Request) -> Result<Response, RequestError> {
+        (**self).request(req)
+    }
+}
+
+/// Builds a requester from command line arguments, where `args[0]` is the
+/// program name: `local <path>` runs a game binary per request, and
+/// `http <url>` posts to a running game service.
+pub fn parse_args(args: &[String]) -> Result<Box<dyn Requester>, ParseArgsError> {
     let args_len = args.len();
     if args_len < 2 {
         return Err(ParseArgsError::TypeMissing);
@@ -20,8 +32,54 @@ pub fn parse_args(args: &[String]) -> Result<impl Requester + use<>, ParseArgsEr
             if args_len < 3 {
                 return Err(ParseArgsError::PathMissing);
             }
-            local::LocalRequester::new(&args[2])
+            Box::new(local::LocalRequester::new(&args[2]))
+        }
+        "http" => {
+            if args_len < 3 {
+                return Err(ParseArgsError::UrlMissing);
+            }
+            http_requester(&args[2])?
         }
         _ => return Err(ParseArgsError::TypeMissing),
     })
 }
+
+#[cfg(feature = "http-client")]
+fn http_requester(url: &str) -> Result<Box<dyn Requester>, ParseArgsError> {
+    Ok(Box::new(http::HttpRequester::new(url)))
+}
+
+#[cfg(not(feature = "http-client"))]
+fn http_requester(_url: &str) -> Result<Box<dyn Requester>, ParseArgsError> {
+    Err(ParseArgsError::HttpUnsupported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(a: &[&str]) -> Vec<String> {
+        a.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args_requires_type_and_target() {
+        assert!(matches!(
+            parse_args(&args(&["repl"])),
+            Err(ParseArgsError::TypeMissing)
+        ));
+        assert!(matches!(
+            parse_args(&args(&["repl", "ftp", "x"])),
+            Err(ParseArgsError::TypeMissing)
+        ));
+        assert!(matches!(
+            parse_args(&args(&["repl", "local"])),
+            Err(ParseArgsError::PathMissing)
+        ));
+        assert!(matches!(
+            parse_args(&args(&["repl", "http"])),
+            Err(ParseArgsError::UrlMissing)
+        ));
+        assert!(parse_args(&args(&["repl", "local", "/bin/true"])).is_ok());
+    }
+}
@@ -0,0 +1,511 @@
+//! Golden scenario tests for `Gamer` implementations.
+//!
+//! A scenario is a YAML file describing a seeded game and the commands
+//! played in it:
+//!
+//! ```yaml
+//! seed: 7
+//! players: 3
+//! options:                # optional, see `Gamer::options`
+//!   mode: advanced
+//! steps:
+//!   - command: take       # played by the first player whose turn it is
+//!   - player: 1
+//!     command: pass
+//!     error: true         # must be rejected
+//! expect:
+//!   whose_turn: [1]       # or `placings: [2, 1, 3]` for a finished game
+//! ```
+//!
+//! Every log, the final status and every render are written as plain text to
+//! a snapshot next to the scenario (`<name>.snap`) and compared on each run,
+//! so a rule change shows up as a snapshot diff. Set
+//! `BRDGME_ACCEPT_SNAPSHOTS=1` to write new snapshots instead of comparing.
+//!
+//! Game crates call `assert_scenarios::<Game>(dir)` from an integration test.
+
+use std::fmt::Debug;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use brdgme_color::LIGHT;
+use brdgme_game::{GameOptions, Gamer, Status};
+use brdgme_markup::Player;
+
+use crate::api::{CliLog, GameResponse, Request, Response};
+use crate::requester::Requester;
+use crate::requester::gamer;
+
+pub const ACCEPT_ENV: &str = "BRDGME_ACCEPT_SNAPSHOTS";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub seed: u64,
+    pub players: usize,
+    /// Defaults to `player0`, `player1`, ...
+    #[serde(default)]
+    pub names: Option<Vec<String>>,
+    #[serde(default)]
+    pub options: GameOptions,
+    pub steps: Vec<Step>,
+    #[serde(default)]
+    pub expect: Expect,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Step {
+    /// Defaults to the first player whose turn it is.
+    #[serde(default)]
+    pub player: Option<usize>,
+    pub command: String,
+    /// The command must be rejected; the game state is left as it was.
+    #[serde(default)]
+    pub error: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expect {
+    #[serde(default)]
+    pub whose_turn: Option<Vec<usize>>,
+    /// Implies the game is finished.
+    #[serde(default)]
+    pub placings: Option<Vec<usize>>,
+}
+
+impl Scenario {
+    pub fn from_yaml(source: &str) -> Result<Self, String> {
+        serde_yaml_ng::from_str(source).map_err(|e| format!("invalid scenario: {}", e))
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.names
+            .clone()
+            .unwrap_or_else(|| (0..self.players).map(|p| format!("player{}", p)).collect())
+    }
+}
+
+/// Plays `scenario` and returns its snapshot text, or a description of the
+/// first step or expectation that failed.
+pub fn run_scenario<G>(scenario: &Scenario) -> Result<String, String>
+where
+    G: Gamer + Debug + Clone + Serialize + DeserializeOwned,
+{
+    let mut requester = gamer::new::<G>();
+    let names = scenario.names();
+    if names.len() != scenario.players {
+        return Err(format!(
+            "{} names given for {} players",
+            names.len(),
+            scenario.players
+        ));
+    }
+    let players: Vec<Player> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| Player {
+            name: name.clone(),
+            color: LIGHT.player_color(i),
+        })
+        .collect();
+    let mut snapshot = String::new();
+
+    let mut game = match request(
+        &mut requester,
+        &Request::New {
+            players: scenario.players,
+            seed: Some(scenario.seed),
+            options: scenario.options.clone(),
+        },
+    )? {
+        Response::New { game, logs, .. } => {
+            section(&mut snapshot, "new");
+            for (key, value) in scenario.options.iter() {
+                let _ = writeln!(snapshot, "option {} = {}", key, value);
+            }
+            write_logs(&mut snapshot, &logs, &players)?;
+            game
+        }
+        resp => return Err(format!("new game failed: {}", describe(&resp))),
+    };
+
+    for (i, step) in scenario.steps.iter().enumerate() {
+        let step_no = i + 1;
+        let player = match step.player {
+            Some(p) if p >= scenario.players => {
+                return Err(format!("step {}: there is no player {}", step_no, p));
+            }
+            Some(p) => p,
+            None => match game.status {
+                Status::Active { ref whose_turn, .. } => *whose_turn
+                    .first()
+                    .ok_or_else(|| format!("step {}: no player's turn to default to", step_no))?,
+                Status::Finished { .. } => {
+                    return Err(format!("step {}: the game is already finished", step_no));
+                }
+            },
+        };
+        section(
+            &mut snapshot,
+            &format!("{}: {} > {}", step_no, names[player], step.command),
+        );
+        let resp = request(
+            &mut requester,
+            &Request::Play {
+                player,
+                command: step.command.clone(),
+                names: names.clone(),
+                game: game.state.clone(),
+            },
+        )?;
+        let rejection = match resp {
+            Response::Play {
+                ref remaining_input,
+                ..
+            } if !remaining_input.trim().is_empty() => {
+                Some(format!("unexpected input: {}", remaining_input.trim()))
+            }
+            Response::Play {
+                game: next, logs, ..
+            } => {
+                if step.error {
+                    return Err(format!(
+                        "step {}: expected '{}' to be rejected, but it was accepted",
+                        step_no, step.command
+                    ));
+                }
+                write_logs(&mut snapshot, &logs, &players)?;
+                game = next;
+                None
+            }
+            Response::UserError { message } => Some(message),
+            resp => {
+                return Err(format!("step {}: {}", step_no, describe(&resp)));
+            }
+        };
+        if let Some(message) = rejection {
+            if !step.error {
+                return Err(format!(
+                    "step {}: '{}' was rejected: {}",
+                    step_no, step.command, message
+                ));
+            }
+            let _ = writeln!(snapshot, "! {}", message);
+        }
+    }
+
+    check_expect(&scenario.expect, &game)?;
+
+    section(&mut snapshot, "status");
+    match game.status {
+        Status::Active {
+            ref whose_turn,
+            ref eliminated,
+        } => {
+            let _ = writeln!(snapshot, "whose turn: {:?}", whose_turn);
+            if !eliminated.is_empty() {
+                let _ = writeln!(snapshot, "eliminated: {:?}", eliminated);
+            }
+        }
+        Status::Finished { ref placings, .. } => {
+            let _ = writeln!(snapshot, "placings: {:?}", placings);
+        }
+    }
+
+    match request(
+        &mut requester,
+        &Request::Status {
+            game: game.state.clone(),
+        },
+    )? {
+        Response::Status {
+            public_render,
+            player_renders,
+            ..
+        } => {
+            section(&mut snapshot, "public render");
+            write_markup(&mut snapshot, &public_render.render, &players)?;
+            for (p, render) in player_renders.iter().enumerate() {
+                section(&mut snapshot, &format!("{} render", names[p]));
+                write_markup(&mut snapshot, &render.render, &players)?;
+            }
+        }
+        resp => return Err(format!("status failed: {}", describe(&resp))),
+    }
+    Ok(snapshot)
+}
+
+/// Runs the scenario at `path` and compares it with its snapshot, or writes
+/// the snapshot when `accept` is set.
+pub fn check_scenario<G>(path: &Path, accept: bool) -> Result<(), String>
+where
+    G: Gamer + Debug + Clone + Serialize + DeserializeOwned,
+{
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    let scenario = Scenario::from_yaml(&source)?;
+    let actual = run_scenario::<G>(&scenario)?;
+    let snap_path = path.with_extension("snap");
+    if accept {
+        return fs::write(&snap_path, actual)
+            .map_err(|e| format!("could not write {}: {}", snap_path.display(), e));
+    }
+    let expected = fs::read_to_string(&snap_path).map_err(|e| {
+        format!(
+            "could not read {} ({}); run with {}=1 to create it",
+            snap_path.display(),
+            e,
+            ACCEPT_ENV
+        )
+    })?;
+    if expected == actual {
+        return Ok(());
+    }
+    Err(format!(
+        "snapshot {} differs; run with {}=1 to accept\n{}",
+        snap_path.display(),
+        ACCEPT_ENV,
+        first_difference(&expected, &actual)
+    ))
+}
+
+/// Checks one scenario file, panicking with the failure.
+pub fn assert_scenario<G>(path: impl AsRef<Path>)
+where
+    G: Gamer + Debug + Clone + Serialize + DeserializeOwned,
+{
+    let path = path.as_ref();
+    if let Err(message) = check_scenario::<G>(path, accept_from_env()) {
+        panic!("{}: {}", path.display(), message);
+    }
+}
+
+/// Checks every `*.yaml` scenario in `dir`, reporting all failures together.
+pub fn assert_scenarios<G>(dir: impl AsRef<Path>)
+where
+    G: Gamer + Debug + Clone + Serialize + DeserializeOwned,
+{
+    let dir = dir.as_ref();
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", dir.display(), e))
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "yaml"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "no scenarios in {}", dir.display());
+    let accept = accept_from_env();
+    let failures: Vec<String> = paths
+        .iter()
+        .filter_map(|path| {
+            check_scenario::<G>(path, accept)
+                .err()
+                .map(|message| format!("{}: {}", path.display(), message))
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+fn accept_from_env() -> bool {
+    std::env::var(ACCEPT_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+fn request<R: Requester>(requester: &mut R, req: &Request) -> Result<Response, String> {
+    requester.request(req).map_err(|e| e.to_string())
+}
+
+fn describe(resp: &Response) -> String {
+    match resp {
+        Response::UserError { message } | Response::SystemError { message } => message.clone(),
+        resp => format!("unexpected response {:?}", resp),
+    }
+}
+
+fn check_expect(expect: &Expect, game: &GameResponse) -> Result<(), String> {
+    match (&game.status, expect) {
+        (
+            Status::Active { whose_turn, .. },
+            Expect {
+                placings: Some(_), ..
+            },
+        ) => Err(format!(
+            "expected the game to be finished, but it is {:?}'s turn",
+            whose_turn
+        )),
+        (
+            Status::Active { whose_turn, .. },
+            Expect {
+                whose_turn: Some(expected),
+                ..
+            },
+        ) if whose_turn != expected => Err(format!(
+            "expected whose turn {:?}, got {:?}",
+            expected, whose_turn
+        )),
+        (
+            Status::Finished { placings, .. },
+            Expect {
+                placings: Some(expected),
+                ..
+            },
+        ) if placings != expected => Err(format!(
+            "expected placings {:?}, got {:?}",
+            expected, placings
+        )),
+        (
+            Status::Finished { .. },
+            Expect {
+                whose_turn: Some(expected),
+                ..
+            },
+        ) => Err(format!(
+            "expected whose turn {:?}, but the game is finished",
+            expected
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn section(snapshot: &mut String, title: &str) {
+    if !snapshot.is_empty() {
+        snapshot.push('\n');
+    }
+    let _ = writeln!(snapshot, "== {}", title);
+}
+
+fn write_markup(snapshot: &mut String, markup: &str, players: &[Player]) -> Result<(), String> {
+    let nodes = brdgme_markup::from_string(markup).map_err(|e| e.to_string())?;
+    let text = brdgme_markup::plain(&brdgme_markup::transform(&nodes, players));
+    for line in text.lines() {
+        let _ = writeln!(snapshot, "{}", line.trim_end());
+    }
+    Ok(())
+}
+
+fn write_logs(snapshot: &mut String, logs: &[CliLog], players: &[Player]) -> Result<(), String> {
+    for log in logs {
+        if !log.public {
+            let _ = write!(snapshot, "(to {:?}) ", log.to);
+        }
+        write_markup(snapshot, &log.content, players)?;
+    }
+    Ok(())
+}
+
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (None, None) => return "snapshots differ only in trailing newlines".to_string(),
+            (e, a) => {
+                return format!(
+                    "first difference at line {}:\n- {}\n+ {}",
+                    line,
+                    e.unwrap_or("<end of snapshot>"),
+                    a.unwrap_or("<end of snapshot>")
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_game::TestGame;
+
+    fn scenario(yaml: &str) -> Scenario {
+        Scenario::from_yaml(yaml).unwrap()
+    }
+
+    #[test]
+    fn run_records_steps_and_renders() {
+        let s = scenario(
+            "seed: 1\nplayers: 2\nsteps:\n  - command: play\n  - command: pass\n    error: true\nexpect:\n  whose_turn: [0]\n",
+        );
+        let snapshot = run_scenario::<TestGame>(&s).unwrap();
+        assert!(snapshot.contains("== 1: player0 > play\n"), "{}", snapshot);
+        assert!(
+            snapshot.contains("== 2: player0 > pass\n! invalid input, expected 'play'"),
+            "{}",
+            snapshot
+        );
+        assert!(
+            snapshot.contains("== player1 render\ntest\n"),
+            "{}",
+            snapshot
+        );
+    }
+
+    #[test]
+    fn unexpected_rejection_and_acceptance_fail() {
+        let rejected = scenario("seed: 1\nplayers: 1\nsteps:\n  - command: pass\n");
+        assert!(
+            run_scenario::<TestGame>(&rejected)
+                .unwrap_err()
+                .contains("was rejected")
+        );
+        let accepted =
+            scenario("seed: 1\nplayers: 1\nsteps:\n  - command: play\n    error: true\n");
+        assert!(
+            run_scenario::<TestGame>(&accepted)
+                .unwrap_err()
+                .contains("to be rejected")
+        );
+        let leftover = scenario("seed: 1\nplayers: 1\nsteps:\n  - command: play extra\n");
+        assert!(
+            run_scenario::<TestGame>(&leftover)
+                .unwrap_err()
+                .contains("unexpected input: extra")
+        );
+    }
+
+    #[test]
+    fn expectations_are_checked() {
+        let wrong_turn = scenario("seed: 1\nplayers: 2\nsteps: []\nexpect:\n  whose_turn: [1]\n");
+        assert!(
+            run_scenario::<TestGame>(&wrong_turn)
+                .unwrap_err()
+                .contains("expected whose turn [1]")
+        );
+        let not_finished =
+            scenario("seed: 1\nplayers: 2\nsteps: []\nexpect:\n  placings: [1, 2]\n");
+        assert!(
+            run_scenario::<TestGame>(&not_finished)
+                .unwrap_err()
+                .contains("to be finished")
+        );
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        assert!(Scenario::from_yaml("seed: 1\nplayers: 1\nsteps: []\nstpes: []\n").is_err());
+    }
+
+    #[test]
+    fn check_scenario_accepts_then_compares() {
+        let dir = std::env::temp_dir().join(format!("brdgme_scenario_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("play.yaml");
+        fs::write(&path, "seed: 1\nplayers: 1\nsteps:\n  - command: play\n").unwrap();
+
+        let missing = check_scenario::<TestGame>(&path, false).unwrap_err();
+        assert!(missing.contains(ACCEPT_ENV), "{}", missing);
+
+        check_scenario::<TestGame>(&path, true).unwrap();
+        check_scenario::<TestGame>(&path, false).unwrap();
+
+        fs::write(path.with_extension("snap"), "== new\nstale\n").unwrap();
+        let diff = check_scenario::<TestGame>(&path, false).unwrap_err();
+        assert!(diff.contains("line 2"), "{}", diff);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
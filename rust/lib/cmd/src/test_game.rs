@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use brdgme_game::command::Spec as CommandSpec;
 use brdgme_game::errors::GameError;
+use brdgme_game::i18n::Locale;
 use brdgme_game::{CommandResponse, Gamer, Log, Renderer, Status};
 use brdgme_markup::Node;
 
@@ -89,6 +90,19 @@ impl Gamer for TestGame {
     fn validate(&self) -> Result<(), GameError> {
         Ok(())
     }
+
+    fn rules() -> String {
+        "Play.".to_string()
+    }
+
+    fn catalog_yaml(locale: Locale) -> Option<&'static str> {
+        match locale {
+            Locale::Es => {
+                Some("messages:\n  test: prueba\naliases:\n  jugar: play\nrules: Juega.\n")
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,7 +9,8 @@ use std::fmt::Debug;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
-use brdgme_game::Gamer;
+use brdgme_game::{GameOptions, Gamer};
+use brdgme_i18n::Locale;
 
 use crate::api::{Request, Response};
 use crate::requester::Requester;
@@ -36,6 +37,58 @@ where
         r => panic!("expected Rules response, got {:?}", r),
     }
 
+    for locale in Locale::ALL {
+        match requester.request(&Request::Catalog { locale }).unwrap() {
+            Response::Catalog { .. } => {}
+            r => panic!("expected Catalog response for {}, got {:?}", locale, r),
+        }
+    }
+
+    let options = match requester.request(&Request::Options).unwrap() {
+        Response::Options { options } => options,
+        r => panic!("expected Options response, got {:?}", r),
+    };
+    let min_count = *player_counts.iter().min().unwrap();
+    for (i, option) in options.iter().enumerate() {
+        assert!(
+            options[..i].iter().all(|o| o.key != option.key),
+            "option key {} must be unique",
+            option.key
+        );
+        assert!(
+            !option.values.is_empty(),
+            "option {} must offer at least one value",
+            option.key
+        );
+        for value in &option.values {
+            match requester
+                .request(&Request::New {
+                    players: min_count,
+                    seed: None,
+                    options: GameOptions::new().with(&option.key, &value.value),
+                })
+                .unwrap()
+            {
+                Response::New { .. } => {}
+                r => panic!(
+                    "expected New to succeed with option {}={}, got {:?}",
+                    option.key, value.value, r
+                ),
+            }
+        }
+    }
+    match requester
+        .request(&Request::New {
+            players: min_count,
+            seed: None,
+            options: GameOptions::new().with("no-such-option", "on"),
+        })
+        .unwrap()
+    {
+        Response::UserError { .. } => {}
+        r => panic!("expected New with an unknown option to fail, got {:?}", r),
+    }
+
     let max_count = *player_counts.iter().max().unwrap();
     let unadvertised_count = (0..=max_count + 1)
         .find(|c| !player_counts.contains(c))
@@ -44,6 +97,7 @@ where
         .request(&Request::New {
             players: unadvertised_count,
             seed: None,
+            options: GameOptions::new(),
         })
         .unwrap()
     {
@@ -59,6 +113,7 @@ where
             .request(&Request::New {
                 players: count,
                 seed: None,
+                options: GameOptions::new(),
             })
             .unwrap()
         {
@@ -0,0 +1,306 @@
+use std::ops::Deref;
+
+use brdgme_game::rng::GameRng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::view::{DeckView, HandView, PileView};
+
+/// A face-down draw pile. The top card is the first element.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Deck<T> {
+    cards: Vec<T>,
+}
+
+impl<T> Deck<T> {
+    pub fn new(cards: Vec<T>) -> Self {
+        Self { cards }
+    }
+
+    pub fn shuffled(cards: Vec<T>, rng: &mut GameRng) -> Self {
+        let mut deck = Self::new(cards);
+        deck.shuffle(rng);
+        deck
+    }
+
+    pub fn shuffle(&mut self, rng: &mut GameRng) {
+        self.cards.shuffle(rng);
+    }
+
+    pub fn draw(&mut self) -> Option<T> {
+        if self.cards.is_empty() {
+            None
+        } else {
+            Some(self.cards.remove(0))
+        }
+    }
+
+    /// Draws up to `n` cards, fewer if the deck runs out.
+    pub fn draw_n(&mut self, n: usize) -> Vec<T> {
+        let n = n.min(self.cards.len());
+        self.cards.drain(..n).collect()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.cards.first()
+    }
+
+    pub fn put_top(&mut self, card: T) {
+        self.cards.insert(0, card);
+    }
+
+    pub fn put_bottom(&mut self, card: T) {
+        self.cards.push(card);
+    }
+
+    /// Shuffles every card in `pile` back into the deck, leaving the pile
+    /// empty. Cards still in the deck are shuffled in with them.
+    pub fn reshuffle_from(&mut self, pile: &mut Pile<T>, rng: &mut GameRng) {
+        self.cards.append(&mut pile.cards);
+        self.shuffle(rng);
+    }
+
+    pub fn view(&self) -> DeckView {
+        DeckView {
+            count: self.cards.len(),
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.cards
+    }
+}
+
+impl<T> Default for Deck<T> {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl<T> From<Vec<T>> for Deck<T> {
+    fn from(cards: Vec<T>) -> Self {
+        Self::new(cards)
+    }
+}
+
+impl<T> Deref for Deck<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.cards
+    }
+}
+
+/// Cards held by one player, in the order they were received.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Hand<T> {
+    cards: Vec<T>,
+}
+
+impl<T> Hand<T> {
+    pub fn new(cards: Vec<T>) -> Self {
+        Self { cards }
+    }
+
+    pub fn push(&mut self, card: T) {
+        self.cards.push(card);
+    }
+
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index < self.cards.len() {
+            Some(self.cards.remove(index))
+        } else {
+            None
+        }
+    }
+
+    pub fn take_all(&mut self) -> Vec<T> {
+        std::mem::take(&mut self.cards)
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.cards
+    }
+}
+
+impl<T: PartialEq> Hand<T> {
+    /// Removes the first card equal to `card`.
+    pub fn remove(&mut self, card: &T) -> Option<T> {
+        let index = self.cards.iter().position(|c| c == card)?;
+        self.remove_at(index)
+    }
+}
+
+impl<T: Clone + Ord> Hand<T> {
+    pub fn sorted(&self) -> Vec<T> {
+        let mut cards = self.cards.clone();
+        cards.sort();
+        cards
+    }
+
+    /// The hand as `viewer` may see it: sorted cards when `visible`,
+    /// otherwise only the count.
+    pub fn view(&self, visible: bool) -> HandView<T> {
+        HandView {
+            count: self.cards.len(),
+            cards: visible.then(|| self.sorted()),
+        }
+    }
+}
+
+impl<T> Default for Hand<T> {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl<T> From<Vec<T>> for Hand<T> {
+    fn from(cards: Vec<T>) -> Self {
+        Self::new(cards)
+    }
+}
+
+impl<T> Extend<T> for Hand<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.cards.extend(iter);
+    }
+}
+
+impl<T> Deref for Hand<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.cards
+    }
+}
+
+/// A face-up stack such as a discard pile. The top card is the last element.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Pile<T> {
+    cards: Vec<T>,
+}
+
+impl<T> Pile<T> {
+    pub fn new(cards: Vec<T>) -> Self {
+        Self { cards }
+    }
+
+    pub fn push(&mut self, card: T) {
+        self.cards.push(card);
+    }
+
+    pub fn top(&self) -> Option<&T> {
+        self.cards.last()
+    }
+
+    pub fn take_top(&mut self) -> Option<T> {
+        self.cards.pop()
+    }
+
+    pub fn take_all(&mut self) -> Vec<T> {
+        std::mem::take(&mut self.cards)
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.cards
+    }
+}
+
+impl<T: Clone> Pile<T> {
+    pub fn view(&self) -> PileView<T> {
+        PileView {
+            count: self.cards.len(),
+            top: self.top().cloned(),
+        }
+    }
+}
+
+impl<T> Default for Pile<T> {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl<T> From<Vec<T>> for Pile<T> {
+    fn from(cards: Vec<T>) -> Self {
+        Self::new(cards)
+    }
+}
+
+impl<T> Deref for Pile<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.cards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deck_draws_from_the_front() {
+        let mut deck = Deck::new(vec![1, 2, 3, 4]);
+        assert_eq!(Some(&1), deck.peek());
+        assert_eq!(Some(1), deck.draw());
+        assert_eq!(vec![2, 3], deck.draw_n(2));
+        assert_eq!(vec![4], deck.draw_n(5));
+        assert_eq!(None, deck.draw());
+        assert!(deck.draw_n(1).is_empty());
+    }
+
+    #[test]
+    fn containers_serialize_like_vec() {
+        let deck = Deck::new(vec![3, 1]);
+        assert_eq!("[3,1]", serde_json::to_string(&deck).unwrap());
+        let hand: Hand<i32> = serde_json::from_str("[5,4]").unwrap();
+        assert_eq!(&[5, 4], &*hand);
+        let pile: Pile<i32> = serde_json::from_str("[]").unwrap();
+        assert!(pile.is_empty());
+    }
+
+    #[test]
+    fn shuffles_are_deterministic_per_seed() {
+        let shuffle = |seed| {
+            let mut rng = GameRng::seed_from_u64(seed);
+            Deck::shuffled((0..20).collect::<Vec<i32>>(), &mut rng).into_vec()
+        };
+        assert_eq!(shuffle(3), shuffle(3));
+        assert_ne!(shuffle(3), shuffle(4));
+    }
+
+    #[test]
+    fn reshuffle_from_empties_the_pile() {
+        let mut rng = GameRng::seed_from_u64(1);
+        let mut deck = Deck::new(vec![1]);
+        let mut pile = Pile::new(vec![2, 3]);
+        deck.reshuffle_from(&mut pile, &mut rng);
+        assert!(pile.is_empty());
+        let mut cards = deck.into_vec();
+        cards.sort();
+        assert_eq!(vec![1, 2, 3], cards);
+    }
+
+    #[test]
+    fn hand_remove_takes_first_match_only() {
+        let mut hand = Hand::new(vec![2, 1, 2]);
+        assert_eq!(Some(2), hand.remove(&2));
+        assert_eq!(&[1, 2], &*hand);
+        assert_eq!(None, hand.remove(&7));
+        assert_eq!(None, hand.remove_at(5));
+    }
+
+    #[test]
+    fn pile_top_is_last_pushed() {
+        let mut pile = Pile::default();
+        pile.push('a');
+        pile.push('b');
+        assert_eq!(Some(&'b'), pile.top());
+        assert_eq!(2, pile.view().count);
+        assert_eq!(Some('b'), pile.take_top());
+    }
+}
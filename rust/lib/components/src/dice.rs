@@ -0,0 +1,176 @@
+use std::ops::Deref;
+
+use brdgme_game::rng::GameRng;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A die with a fixed set of faces, each equally likely. Games with
+/// distinct dice (zombie dice colours, say) implement this on a small enum
+/// so state stores only which die it is, not its faces.
+pub trait Die {
+    type Face: Clone;
+
+    /// Must not be empty.
+    fn faces(&self) -> &[Self::Face];
+
+    fn roll(&self, rng: &mut GameRng) -> Self::Face {
+        let faces = self.faces();
+        faces[rng.random_range(0..faces.len())].clone()
+    }
+}
+
+/// A die whose faces are stored with it, for games where every die is the
+/// same shape.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomDie<F> {
+    pub faces: Vec<F>,
+}
+
+impl<F> CustomDie<F> {
+    pub fn new(faces: Vec<F>) -> Self {
+        Self { faces }
+    }
+}
+
+impl CustomDie<u8> {
+    /// A standard die numbered 1 to `sides`.
+    pub fn numbered(sides: u8) -> Self {
+        Self::new((1..=sides).collect())
+    }
+}
+
+impl<F: Clone> Die for CustomDie<F> {
+    type Face = F;
+
+    fn faces(&self) -> &[F] {
+        &self.faces
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rolled<D, F> {
+    pub die: D,
+    pub face: F,
+}
+
+/// A set of dice rolled together, such as a cup.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DicePool<D> {
+    dice: Vec<D>,
+}
+
+impl<D> DicePool<D> {
+    pub fn new(dice: Vec<D>) -> Self {
+        Self { dice }
+    }
+
+    pub fn push(&mut self, die: D) {
+        self.dice.push(die);
+    }
+
+    /// Takes up to `n` dice at random, fewer if the pool runs out.
+    pub fn take_random(&mut self, n: usize, rng: &mut GameRng) -> Vec<D> {
+        self.dice.shuffle(rng);
+        let n = n.min(self.dice.len());
+        self.dice.drain(..n).collect()
+    }
+
+    pub fn into_vec(self) -> Vec<D> {
+        self.dice
+    }
+}
+
+impl<D: Die + Clone> DicePool<D> {
+    pub fn roll(&self, rng: &mut GameRng) -> Vec<Rolled<D, D::Face>> {
+        self.dice
+            .iter()
+            .map(|die| Rolled {
+                die: die.clone(),
+                face: die.roll(rng),
+            })
+            .collect()
+    }
+}
+
+impl<D> Default for DicePool<D> {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl<D> From<Vec<D>> for DicePool<D> {
+    fn from(dice: Vec<D>) -> Self {
+        Self::new(dice)
+    }
+}
+
+impl<D> Extend<D> for DicePool<D> {
+    fn extend<I: IntoIterator<Item = D>>(&mut self, iter: I) {
+        self.dice.extend(iter);
+    }
+}
+
+impl<D> Deref for DicePool<D> {
+    type Target = [D];
+
+    fn deref(&self) -> &[D] {
+        &self.dice
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Colour {
+        Green,
+        Red,
+    }
+
+    impl Die for Colour {
+        type Face = char;
+
+        fn faces(&self) -> &[char] {
+            match self {
+                Colour::Green => &['b', 'b', 's'],
+                Colour::Red => &['s', 's', 's'],
+            }
+        }
+    }
+
+    #[test]
+    fn rolls_land_on_a_face() {
+        let mut rng = GameRng::seed_from_u64(9);
+        let d6 = CustomDie::numbered(6);
+        for _ in 0..100 {
+            assert!((1..=6).contains(&d6.roll(&mut rng)));
+        }
+        assert_eq!('s', Colour::Red.roll(&mut rng));
+    }
+
+    #[test]
+    fn pool_rolls_every_die_in_order() {
+        let mut rng = GameRng::seed_from_u64(2);
+        let pool = DicePool::new(vec![Colour::Red, Colour::Green]);
+        let rolled = pool.roll(&mut rng);
+        assert_eq!(2, rolled.len());
+        assert_eq!(Colour::Red, rolled[0].die);
+        assert_eq!(Colour::Green, rolled[1].die);
+    }
+
+    #[test]
+    fn take_random_is_seeded_and_bounded() {
+        let take = |seed| {
+            let mut rng = GameRng::seed_from_u64(seed);
+            let mut pool = DicePool::new((0..10).collect::<Vec<i32>>());
+            let taken = pool.take_random(3, &mut rng);
+            assert_eq!(7, pool.len());
+            taken
+        };
+        assert_eq!(take(5), take(5));
+        let mut rng = GameRng::seed_from_u64(1);
+        assert_eq!(2, DicePool::new(vec![1, 2]).take_random(5, &mut rng).len());
+    }
+}
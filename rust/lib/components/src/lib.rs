@@ -0,0 +1,18 @@
+//! Cards, decks and dice shared by game crates.
+//!
+//! Every container serializes exactly like the `Vec` it wraps, so a game can
+//! switch from a bare `Vec<Card>` without changing its stored state. All
+//! randomness comes from the game's `GameRng`.
+//!
+//! Hidden information is kept out of `PubState` by construction: `PubState`
+//! holds a view (`HandView`, `DeckView`, `PileView`), and a hidden view
+//! carries only a count, never the cards.
+
+pub use crate::deck::{Deck, Hand, Pile};
+pub use crate::dice::{CustomDie, DicePool, Die, Rolled};
+pub use crate::view::{Component, DeckView, HandView, PileView};
+
+mod deck;
+mod dice;
+pub mod parser;
+mod view;
@@ -0,0 +1,68 @@
+//! Parsers for commands naming a card, such as `play R5`.
+//!
+//! Cards are matched exactly by their `Display` form, so it should be short
+//! and unambiguous (`R5`, `QH`).
+
+use std::fmt::Display;
+
+use brdgme_game::command::parser::*;
+
+/// One of `cards`, documented as `card`. Duplicates are offered once.
+pub fn card_parser<T>(cards: &[T], desc: &str) -> impl Parser<T = T> + use<T>
+where
+    T: Display + Clone + Ord,
+{
+    let mut cards = cards.to_vec();
+    cards.sort();
+    cards.dedup();
+    Doc::name_desc("card", desc, Enum::exact(cards))
+}
+
+/// `<verb> <card>`, returning the card.
+pub fn card_action_parser<T>(
+    verb: &str,
+    desc: &str,
+    cards: &[T],
+    card_desc: &str,
+) -> impl Parser<T = T> + use<T>
+where
+    T: Display + Clone + Ord,
+{
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(verb, desc, Token::new(verb)),
+            AfterSpace::new(card_parser(cards, card_desc)),
+        ),
+        |(_, card)| card,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_action_parser_parses_verb_and_card() {
+        let parser = card_action_parser("play", "play a card", &[12, 5, 5], "the card");
+        assert_eq!(5, parser.parse("play 5", &[]).unwrap().value);
+        assert_eq!(12, parser.parse("PLAY 12", &[]).unwrap().value);
+        assert!(parser.parse("play 7", &[]).is_err());
+        assert!(parser.parse("discard 5", &[]).is_err());
+    }
+
+    #[test]
+    fn card_parser_offers_each_card_once_in_order() {
+        use brdgme_game::command::Spec;
+
+        match card_parser(&[3, 3, 1], "a card").to_spec() {
+            Spec::Doc { spec, .. } => assert_eq!(
+                Spec::Enum {
+                    values: vec!["1".to_string(), "3".to_string()],
+                    exact: true,
+                },
+                *spec
+            ),
+            spec => panic!("expected Doc, got {:?}", spec),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use brdgme_color::NamedColor;
+use brdgme_markup::Node as N;
+use serde::{Deserialize, Serialize};
+
+/// How a card, tile or die face renders.
+pub trait Component {
+    fn render(&self) -> N;
+
+    /// What the back of a hidden component looks like. There is no `self`,
+    /// so a hidden render can't depend on what is hidden.
+    fn render_back() -> N {
+        N::Fg(NamedColor::Grey.into(), vec![N::text("##")])
+    }
+}
+
+/// A hand as one viewer sees it. When hidden only the count exists, so a
+/// `PubState` holding a `HandView` can't leak the cards.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandView<T> {
+    pub count: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cards: Option<Vec<T>>,
+}
+
+impl<T: Component> HandView<T> {
+    /// Space separated faces, or one back per card when hidden.
+    pub fn render(&self) -> Vec<N> {
+        let nodes: Vec<N> = match self.cards {
+            Some(ref cards) => cards.iter().map(Component::render).collect(),
+            None => (0..self.count).map(|_| T::render_back()).collect(),
+        };
+        let mut output = Vec::with_capacity(nodes.len() * 2);
+        for (i, node) in nodes.into_iter().enumerate() {
+            if i > 0 {
+                output.push(N::text(" "));
+            }
+            output.push(node);
+        }
+        output
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckView {
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PileView<T> {
+    pub count: usize,
+    pub top: Option<T>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hand;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    struct Card(u8);
+
+    impl Component for Card {
+        fn render(&self) -> N {
+            N::text(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn hidden_view_serializes_without_cards() {
+        let hand = Hand::new(vec![Card(9), Card(2)]);
+        let json = serde_json::to_string(&hand.view(false)).unwrap();
+        assert_eq!(r#"{"count":2}"#, json);
+        let json = serde_json::to_string(&hand.view(true)).unwrap();
+        assert_eq!(r#"{"count":2,"cards":[2,9]}"#, json);
+    }
+
+    #[test]
+    fn render_shows_faces_or_backs() {
+        let hand = Hand::new(vec![Card(9), Card(2)]);
+        assert_eq!(
+            vec![N::text("2"), N::text(" "), N::text("9")],
+            hand.view(true).render()
+        );
+        assert_eq!(
+            vec![Card::render_back(), N::text(" "), Card::render_back()],
+            hand.view(false).render()
+        );
+    }
+}
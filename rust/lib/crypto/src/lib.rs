@@ -1,5 +1,7 @@
 use aes_gcm::aead::{Aead, KeyInit};
 use aes_gcm::{Aes256Gcm, Nonce};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
 use thiserror::Error;
 use zeroize::Zeroize;
 pub use zeroize::Zeroizing;
@@ -43,6 +45,18 @@ pub fn decrypt(key: &[u8; 32], data: &[u8]) -> Result<Vec<u8>, CryptoError> {
         .map_err(|_| CryptoError::DecryptionFailed)
 }
 
+/// HMAC-SHA256 of `data` under `key`, for values that must be stable yet
+/// unguessable without the key. `label` separates each use so one key never
+/// produces the same output for two purposes.
+pub fn keyed_hash(key: &[u8; 32], label: &str, data: &[u8]) -> [u8; 32] {
+    let mut mac =
+        <Hmac<Sha256> as hmac::KeyInit>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(label.as_bytes());
+    mac.update(&[0]);
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
 pub fn default_key() -> Zeroizing<[u8; 32]> {
     let mut key = Zeroizing::new([0u8; 32]);
     let seed = b"brdgme-dev-key-not-for-prod!!!";
@@ -117,6 +131,17 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn keyed_hash_depends_on_key_label_and_data() {
+        let key = default_key();
+        let other = [0xAB; 32];
+        let hash = keyed_hash(&key, "label", b"data");
+        assert_eq!(hash, keyed_hash(&key, "label", b"data"));
+        assert_ne!(hash, keyed_hash(&other, "label", b"data"));
+        assert_ne!(hash, keyed_hash(&key, "other", b"data"));
+        assert_ne!(hash, keyed_hash(&key, "label", b"more"));
+    }
+
     #[test]
     fn load_key_valid_hex() {
         let _guard = ENV_LOCK.lock().unwrap();
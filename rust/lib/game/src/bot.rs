@@ -8,6 +8,7 @@ use ::log::trace;
 use crate::command::Spec as CommandSpec;
 use crate::errors::GameError;
 use crate::game::Gamer;
+use crate::options::GameOptions;
 
 const BOT_COMMAND_QUALITY_DEFAULT: u8 = 128;
 
@@ -111,8 +112,14 @@ impl<G: Gamer, B: Botter<G>> Iterator for Fuzzer<G, B> {
                 .player_counts
                 .choose(&mut self.rng)
                 .expect("no player counts for game type");
+            let mut options = GameOptions::new();
+            for option in G::options() {
+                if let Some(value) = option.values.choose(&mut self.rng) {
+                    options.set(option.key.clone(), value.value.clone());
+                }
+            }
             self.game = Some(
-                G::start(self.player_count, rand::random())
+                G::start_with_options(self.player_count, rand::random(), &options)
                     .expect("failed to create new game")
                     .0,
             );
@@ -0,0 +1,250 @@
+//! Lists every complete command a `Spec` accepts, for action spaces small
+//! enough to offer as a menu.
+//!
+//! Walks the same structure as `brdgme_rand_bot::spec_to_command`, but takes
+//! every branch instead of a random one. Commands are spelled canonically
+//! (full tokens, declared enum values and names, single spaces), so each one
+//! parses. They are syntactically legal, not necessarily legal in the game:
+//! a spec may offer a bid the game then rejects as too low.
+
+use std::collections::HashSet;
+
+use crate::command::Spec;
+
+impl Spec {
+    /// Every complete command the spec accepts, in declaration order and
+    /// deduplicated case-insensitively, or `None` if there are more than
+    /// `limit` or the spec is unbounded (an open `Int` or `Many`).
+    pub fn enumerate(&self, names: &[String], limit: usize) -> Option<Vec<String>> {
+        enumerate(self, names, limit)
+    }
+}
+
+fn enumerate(spec: &Spec, names: &[String], limit: usize) -> Option<Vec<String>> {
+    let commands = match spec {
+        Spec::Token(token) => vec![token.clone()],
+        Spec::Enum { values, .. } => values.clone(),
+        Spec::Player => names.to_vec(),
+        Spec::Space => vec![" ".to_string()],
+        Spec::Int { min, max } => {
+            let (min, max) = ((*min)?, (*max)?);
+            if min > max {
+                return Some(vec![]);
+            }
+            if i64::from(max) - i64::from(min) >= limit as i64 {
+                return None;
+            }
+            (min..=max).map(|i| i.to_string()).collect()
+        }
+        Spec::OneOf(specs) => {
+            let mut commands = vec![];
+            for s in specs {
+                commands.extend(enumerate(s, names, limit)?);
+                // Checked as we go, so a huge spec bails out early.
+                if commands.len() > limit * 2 {
+                    commands = dedup(commands);
+                    if commands.len() > limit {
+                        return None;
+                    }
+                }
+            }
+            commands
+        }
+        Spec::Chain(specs) => {
+            let mut commands = vec![String::new()];
+            for s in specs {
+                commands = product(&commands, &enumerate(s, names, limit)?, limit)?;
+            }
+            commands
+        }
+        Spec::Opt(spec) => {
+            let mut commands = vec![String::new()];
+            commands.extend(enumerate(spec, names, limit)?);
+            commands
+        }
+        Spec::Doc { spec, .. } => enumerate(spec, names, limit)?,
+        Spec::Many {
+            spec,
+            min,
+            max,
+            delim,
+        } => {
+            let min = min.unwrap_or(0);
+            let max = (*max)?;
+            if max < min {
+                return Some(vec![]);
+            }
+            let items = enumerate(spec, names, limit)?;
+            let delims = match delim {
+                Some(d) => enumerate(d, names, limit)?,
+                None => vec![String::new()],
+            };
+            let mut commands = vec![];
+            // Sequences of exactly `n` items, grown one item at a time.
+            let mut sequences = vec![String::new()];
+            for n in 0..=max {
+                if n >= min {
+                    commands.extend(sequences.iter().cloned());
+                }
+                if n == max {
+                    break;
+                }
+                let joined = if n == 0 {
+                    items.clone()
+                } else {
+                    product(&delims, &items, limit)?
+                };
+                sequences = product(&sequences, &joined, limit)?;
+                if commands.len() + sequences.len() > limit * 2 {
+                    return None;
+                }
+            }
+            commands
+        }
+    };
+    let commands = dedup(commands);
+    (commands.len() <= limit).then_some(commands)
+}
+
+/// Every `a` followed by every `b`, or `None` past the limit.
+fn product(a: &[String], b: &[String], limit: usize) -> Option<Vec<String>> {
+    if a.len().saturating_mul(b.len()) > limit {
+        return None;
+    }
+    Some(
+        a.iter()
+            .flat_map(|a| b.iter().map(move |b| format!("{}{}", a, b)))
+            .collect(),
+    )
+}
+
+fn dedup(commands: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    commands
+        .into_iter()
+        .filter(|c| seen.insert(c.to_lowercase()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::parser::Parser;
+
+    fn names() -> Vec<String> {
+        vec!["mick".to_string(), "steve".to_string()]
+    }
+
+    fn token(t: &str) -> Spec {
+        Spec::Token(t.into())
+    }
+
+    #[test]
+    fn lists_a_small_action_space_in_order() {
+        // No Thanks: take the card or pay a chip.
+        let spec = Spec::OneOf(vec![
+            Spec::Doc {
+                name: "take".into(),
+                desc: None,
+                spec: Box::new(token("take")),
+            },
+            token("pass"),
+        ]);
+        assert_eq!(
+            Some(vec!["take".to_string(), "pass".to_string()]),
+            spec.enumerate(&names(), 10)
+        );
+    }
+
+    #[test]
+    fn chains_options_players_and_many() {
+        let spec = Spec::Chain(vec![
+            token("give"),
+            Spec::Space,
+            Spec::Player,
+            Spec::Opt(Box::new(Spec::Chain(vec![Spec::Space, token("all")]))),
+        ]);
+        assert_eq!(
+            Some(vec![
+                "give mick".to_string(),
+                "give mick all".to_string(),
+                "give steve".to_string(),
+                "give steve all".to_string(),
+            ]),
+            spec.enumerate(&names(), 10)
+        );
+
+        let spec = Spec::Chain(vec![
+            token("keep"),
+            Spec::Space,
+            Spec::Many {
+                spec: Box::new(Spec::Enum {
+                    values: vec!["a".into(), "b".into()],
+                    exact: true,
+                }),
+                min: Some(1),
+                max: Some(2),
+                delim: Some(Box::new(Spec::Space)),
+            },
+        ]);
+        let commands = spec.enumerate(&names(), 10).unwrap();
+        assert_eq!(
+            vec![
+                "keep a", "keep b", "keep a a", "keep a b", "keep b a", "keep b b"
+            ],
+            commands
+        );
+        for command in commands {
+            let out = spec.parse(&command, &names()).unwrap();
+            assert_eq!("", out.remaining);
+        }
+    }
+
+    #[test]
+    fn dedups_case_insensitively() {
+        let spec = Spec::OneOf(vec![
+            token("roll"),
+            Spec::Enum {
+                values: vec!["Roll".into(), "stop".into()],
+                exact: false,
+            },
+        ]);
+        assert_eq!(
+            Some(vec!["roll".to_string(), "stop".to_string()]),
+            spec.enumerate(&[], 10)
+        );
+    }
+
+    #[test]
+    fn large_or_unbounded_spaces_fall_back() {
+        let bid = |max| {
+            Spec::Chain(vec![
+                token("bid"),
+                Spec::Space,
+                Spec::Int { min: Some(1), max },
+            ])
+        };
+        assert_eq!(10, bid(Some(10)).enumerate(&[], 10).unwrap().len());
+        assert_eq!(None, bid(Some(11)).enumerate(&[], 10));
+        assert_eq!(None, bid(None).enumerate(&[], 10));
+        let many = Spec::Many {
+            spec: Box::new(token("x")),
+            min: Some(1),
+            max: None,
+            delim: None,
+        };
+        assert_eq!(None, many.enumerate(&[], 10));
+        let wide = Spec::Chain(vec![
+            Spec::Int {
+                min: Some(1),
+                max: Some(5),
+            },
+            Spec::Int {
+                min: Some(1),
+                max: Some(5),
+            },
+        ]);
+        assert_eq!(None, wide.enumerate(&[], 10));
+        assert_eq!(Some(25), wide.enumerate(&[], 25).map(|c| c.len()));
+    }
+}
@@ -0,0 +1,681 @@
+//! Compiles a `Spec` into grammars for constrained LLM generation.
+//!
+//! The compiled grammar accepts the canonical spelling of every command the
+//! spec describes: full tokens, enum values and player names as declared,
+//! integers without leading zeros, and a single space wherever the spec has a
+//! `Space`. That is a subset of what the parser accepts (it is also
+//! case-insensitive and takes unambiguous prefixes), so anything a model is
+//! constrained to emit parses.
+//!
+//! One caveat is inherited from the parser rather than the grammar: `OneOf`
+//! takes the first branch that parses, not the longest, so a spec whose early
+//! branch is a strict prefix of a later one can still leave input unconsumed.
+//! No game spec does that today.
+
+use crate::command::Spec;
+
+/// A compiled command grammar, renderable for the structured-output flavours
+/// LLM servers accept.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grammar {
+    pattern: Pattern,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Pattern {
+    Literal(String),
+    /// A single digit between the two bounds, inclusive.
+    Digit(u8, u8),
+    Seq(Vec<Pattern>),
+    Alt(Vec<Pattern>),
+    Repeat {
+        pattern: Box<Pattern>,
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+impl Spec {
+    /// Compiles the spec into a grammar over the commands it accepts, or
+    /// `None` if it accepts nothing (e.g. a `Player` with no names).
+    pub fn grammar(&self, names: &[String]) -> Option<Grammar> {
+        compile(self, names).map(|pattern| Grammar { pattern })
+    }
+}
+
+impl Grammar {
+    /// A GBNF grammar (llama.cpp's `grammar` field) with a single `root` rule.
+    pub fn gbnf(&self) -> String {
+        format!("root ::= {}\n", gbnf(&self.pattern, false))
+    }
+
+    /// An unanchored regex in the common subset of ECMAScript and RE2 syntax,
+    /// for servers that take a regex and match it against the whole output.
+    pub fn regex(&self) -> String {
+        regex(&self.pattern, false)
+    }
+
+    /// A JSON schema for an object `{"command": "..."}` whose command matches
+    /// the grammar, for servers that only support JSON-schema output.
+    pub fn json_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "command": {
+                    "type": "string",
+                    "pattern": format!("^({})$", self.regex()),
+                },
+            },
+            "required": ["command"],
+            "additionalProperties": false,
+        })
+    }
+}
+
+fn compile(spec: &Spec, names: &[String]) -> Option<Pattern> {
+    match spec {
+        Spec::Token(token) => Some(Pattern::Literal(token.clone())),
+        Spec::Enum { values, .. } => alt(values
+            .iter()
+            .map(|v| Some(Pattern::Literal(v.clone())))
+            .collect()),
+        Spec::Player => alt(names
+            .iter()
+            .map(|n| Some(Pattern::Literal(n.clone())))
+            .collect()),
+        Spec::Space => Some(Pattern::Literal(" ".to_string())),
+        Spec::Int { min, max } => int(min.map(i64::from), max.map(i64::from)),
+        Spec::OneOf(specs) => alt(specs.iter().map(|s| compile(s, names)).collect()),
+        Spec::Chain(specs) => specs
+            .iter()
+            .map(|s| compile(s, names))
+            .collect::<Option<Vec<_>>>()
+            .map(seq),
+        Spec::Opt(spec) => Some(repeat(compile(spec, names), 0, Some(1))),
+        Spec::Doc { spec, .. } => compile(spec, names),
+        Spec::Many {
+            spec,
+            min,
+            max,
+            delim,
+        } => {
+            let min = min.unwrap_or(0);
+            if max.is_some_and(|max| max == 0 || max < min) {
+                return if min == 0 {
+                    Some(Pattern::Seq(vec![]))
+                } else {
+                    None
+                };
+            }
+            let item = compile(spec, names);
+            let delim = match delim {
+                Some(d) => compile(d, names)?,
+                None => Pattern::Seq(vec![]),
+            };
+            let rest = repeat(
+                item.clone().map(|item| seq(vec![delim, item])),
+                min.saturating_sub(1),
+                max.map(|max| max - 1),
+            );
+            let items = item.map(|item| seq(vec![item, rest]));
+            if min == 0 {
+                Some(repeat(items, 0, Some(1)))
+            } else {
+                items
+            }
+        }
+    }
+}
+
+/// Alternatives, dropping the impossible ones; impossible if none are left.
+fn alt(options: Vec<Option<Pattern>>) -> Option<Pattern> {
+    let mut options: Vec<Pattern> = options.into_iter().flatten().collect();
+    options.dedup();
+    match options.len() {
+        0 => None,
+        1 => options.pop(),
+        _ => Some(Pattern::Alt(options)),
+    }
+}
+
+/// A sequence, flattened so nested and empty sequences don't render as
+/// stray groups.
+fn seq(patterns: Vec<Pattern>) -> Pattern {
+    let mut flat = vec![];
+    for pattern in patterns {
+        match pattern {
+            Pattern::Seq(inner) => flat.extend(inner),
+            pattern => flat.push(pattern),
+        }
+    }
+    if flat.len() == 1 {
+        flat.pop().expect("one pattern")
+    } else {
+        Pattern::Seq(flat)
+    }
+}
+
+/// Repetition of a possibly impossible pattern, which still matches the empty
+/// string when no repeats are required.
+fn repeat(pattern: Option<Pattern>, min: usize, max: Option<usize>) -> Pattern {
+    match pattern {
+        _ if max == Some(0) => Pattern::Seq(vec![]),
+        Some(pattern) if min == 1 && max == Some(1) => pattern,
+        Some(pattern) => Pattern::Repeat {
+            pattern: Box::new(pattern),
+            min,
+            max,
+        },
+        None => Pattern::Seq(vec![]),
+    }
+}
+
+/// Integers between the optional bounds, inclusive, with no leading zeros.
+fn int(min: Option<i64>, max: Option<i64>) -> Option<Pattern> {
+    if let (Some(min), Some(max)) = (min, max)
+        && min > max
+    {
+        return None;
+    }
+    let mut options = vec![];
+    // Negatives, as a minus sign before their magnitudes.
+    if min.is_none_or(|min| min < 0) {
+        let lo = max.map_or(1, |max| max.min(-1).unsigned_abs());
+        let hi = min.map(i64::unsigned_abs);
+        options.push(natural(lo, hi).map(|p| seq(vec![Pattern::Literal("-".to_string()), p])));
+    }
+    if max.is_none_or(|max| max >= 0) {
+        options.push(natural(
+            min.map_or(0, |min| min.max(0) as u64),
+            max.map(|m| m as u64),
+        ));
+    }
+    alt(options)
+}
+
+/// Non-negative integers from `lo` up to `hi`, or without limit.
+fn natural(lo: u64, hi: Option<u64>) -> Option<Pattern> {
+    let lo_digits = digits(lo);
+    let mut options = vec![];
+    match hi {
+        Some(hi) => {
+            if lo > hi {
+                return None;
+            }
+            for len in lo_digits..=digits(hi) {
+                let from = lo.max(smallest_with_digits(len));
+                let to = hi.min(largest_with_digits(len));
+                options.push(Some(same_length(&pad(from, len), &pad(to, len))));
+            }
+        }
+        None => {
+            options.push(Some(same_length(
+                &pad(lo, lo_digits),
+                &pad(largest_with_digits(lo_digits), lo_digits),
+            )));
+            // Anything longer than `lo` is larger than it.
+            options.push(Some(seq(vec![
+                Pattern::Digit(1, 9),
+                repeat(Some(Pattern::Digit(0, 9)), lo_digits, None),
+            ])));
+        }
+    }
+    alt(options)
+}
+
+fn digits(n: u64) -> usize {
+    n.checked_ilog10().map_or(1, |d| d as usize + 1)
+}
+
+fn smallest_with_digits(len: usize) -> u64 {
+    if len == 1 {
+        0
+    } else {
+        10u64.pow(len as u32 - 1)
+    }
+}
+
+fn largest_with_digits(len: usize) -> u64 {
+    10u64.pow(len as u32) - 1
+}
+
+fn pad(n: u64, len: usize) -> Vec<u8> {
+    format!("{:0len$}", n, len = len)
+        .bytes()
+        .map(|b| b - b'0')
+        .collect()
+}
+
+/// Digit strings of equal length between `from` and `to`, inclusive, by
+/// splitting on the first digit where they differ.
+fn same_length(from: &[u8], to: &[u8]) -> Pattern {
+    let Some((&f, from_rest)) = from.split_first() else {
+        return Pattern::Seq(vec![]);
+    };
+    let (&t, to_rest) = to.split_first().expect("bounds have equal length");
+    let any_rest = || {
+        repeat(
+            Some(Pattern::Digit(0, 9)),
+            from_rest.len(),
+            Some(from_rest.len()),
+        )
+    };
+    if f == t {
+        return seq(vec![Pattern::Digit(f, f), same_length(from_rest, to_rest)]);
+    }
+    // The first digits whose every continuation is in range.
+    let from_full = from_rest.iter().all(|&d| d == 0);
+    let to_full = to_rest.iter().all(|&d| d == 9);
+    let middle_lo = if from_full { f } else { f + 1 };
+    let middle_hi = if to_full { t } else { t - 1 };
+    let mut options = vec![];
+    if !from_full {
+        let nines = vec![9; from_rest.len()];
+        options.push(Some(seq(vec![
+            Pattern::Digit(f, f),
+            same_length(from_rest, &nines),
+        ])));
+    }
+    if middle_lo <= middle_hi {
+        options.push(Some(seq(vec![
+            Pattern::Digit(middle_lo, middle_hi),
+            any_rest(),
+        ])));
+    }
+    if !to_full {
+        let zeros = vec![0; to_rest.len()];
+        options.push(Some(seq(vec![
+            Pattern::Digit(t, t),
+            same_length(&zeros, to_rest),
+        ])));
+    }
+    alt(options).expect("a differing first digit leaves at least one option")
+}
+
+/// Whether a pattern renders as a single unit that a quantifier can follow.
+fn is_atom(pattern: &Pattern) -> bool {
+    match pattern {
+        Pattern::Literal(s) => s.chars().count() == 1,
+        Pattern::Digit(..) => true,
+        _ => false,
+    }
+}
+
+fn quantifier(min: usize, max: Option<usize>) -> String {
+    match (min, max) {
+        (0, Some(1)) => "?".to_string(),
+        (0, None) => "*".to_string(),
+        (1, None) => "+".to_string(),
+        (min, None) => format!("{{{},}}", min),
+        (min, Some(max)) if min == max => format!("{{{}}}", min),
+        (min, Some(max)) => format!("{{{},{}}}", min, max),
+    }
+}
+
+fn regex(pattern: &Pattern, nested: bool) -> String {
+    match pattern {
+        Pattern::Literal(s) => s
+            .chars()
+            .map(|c| {
+                if "\\^$.|?*+()[]{}".contains(c) {
+                    format!("\\{}", c)
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect(),
+        Pattern::Digit(lo, hi) if lo == hi => lo.to_string(),
+        Pattern::Digit(lo, hi) => format!("[{}-{}]", lo, hi),
+        Pattern::Seq(patterns) => {
+            let body: String = patterns.iter().map(|p| regex(p, true)).collect();
+            if nested && patterns.is_empty() {
+                "()".to_string()
+            } else {
+                body
+            }
+        }
+        Pattern::Alt(patterns) => {
+            let body = patterns
+                .iter()
+                .map(|p| regex(p, false))
+                .collect::<Vec<_>>()
+                .join("|");
+            if nested { format!("({})", body) } else { body }
+        }
+        Pattern::Repeat { pattern, min, max } => {
+            let inner = regex(pattern, false);
+            let inner = if is_atom(pattern) {
+                inner
+            } else {
+                format!("({})", inner)
+            };
+            format!("{}{}", inner, quantifier(*min, *max))
+        }
+    }
+}
+
+fn gbnf(pattern: &Pattern, nested: bool) -> String {
+    match pattern {
+        Pattern::Literal(s) => {
+            let escaped: String = s
+                .chars()
+                .map(|c| match c {
+                    '"' => "\\\"".to_string(),
+                    '\\' => "\\\\".to_string(),
+                    '\n' => "\\n".to_string(),
+                    c => c.to_string(),
+                })
+                .collect();
+            format!("\"{}\"", escaped)
+        }
+        Pattern::Digit(lo, hi) => format!("[{}-{}]", lo, hi),
+        Pattern::Seq(patterns) if patterns.is_empty() => "\"\"".to_string(),
+        Pattern::Seq(patterns) => {
+            let body = patterns
+                .iter()
+                .map(|p| gbnf(p, true))
+                .collect::<Vec<_>>()
+                .join(" ");
+            if nested && patterns.len() > 1 {
+                format!("({})", body)
+            } else {
+                body
+            }
+        }
+        Pattern::Alt(patterns) => {
+            let body = patterns
+                .iter()
+                .map(|p| gbnf(p, false))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            if nested { format!("({})", body) } else { body }
+        }
+        Pattern::Repeat { pattern, min, max } => {
+            let inner = gbnf(pattern, false);
+            let inner = if matches!(**pattern, Pattern::Literal(_) | Pattern::Digit(..)) {
+                inner
+            } else {
+                format!("({})", inner)
+            };
+            format!("{}{}", inner, quantifier(*min, *max))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::parser::Parser;
+
+    fn names() -> Vec<String> {
+        vec![
+            "mick".to_string(),
+            "steve".to_string(),
+            "o'brien".to_string(),
+        ]
+    }
+
+    fn full_regex(spec: &Spec) -> regex::Regex {
+        let grammar = spec.grammar(&names()).expect("spec accepts something");
+        regex::Regex::new(&format!("^(?:{})$", grammar.regex())).unwrap()
+    }
+
+    /// A game-shaped spec: `bid <quantity> <value>`, `call`, `give <player>
+    /// <cards...>` and `pass [all]`.
+    fn sample_spec() -> Spec {
+        Spec::OneOf(vec![
+            Spec::Doc {
+                name: "bid".into(),
+                desc: Some("raise the bid".into()),
+                spec: Box::new(Spec::Chain(vec![
+                    Spec::Token("bid".into()),
+                    Spec::Space,
+                    Spec::Int {
+                        min: Some(1),
+                        max: None,
+                    },
+                    Spec::Space,
+                    Spec::Int {
+                        min: Some(1),
+                        max: Some(6),
+                    },
+                ])),
+            },
+            Spec::Token("call".into()),
+            Spec::Chain(vec![
+                Spec::Token("give".into()),
+                Spec::Space,
+                Spec::Player,
+                Spec::Space,
+                Spec::Many {
+                    spec: Box::new(Spec::Enum {
+                        values: vec!["r5".into(), "b10".into(), "g2".into()],
+                        exact: true,
+                    }),
+                    min: Some(1),
+                    max: Some(2),
+                    delim: Some(Box::new(Spec::Space)),
+                },
+            ]),
+            Spec::Chain(vec![
+                Spec::Token("pass".into()),
+                Spec::Opt(Box::new(Spec::Chain(vec![
+                    Spec::Space,
+                    Spec::Token("all".into()),
+                ]))),
+            ]),
+        ])
+    }
+
+    /// Every command reachable by repeatedly accepting `suggest`'s
+    /// suggestions, up to `depth` words. Completed commands are those the
+    /// spec parses in full.
+    fn suggested_commands(spec: &Spec, input: &str, depth: usize, out: &mut Vec<String>) {
+        if depth == 0 {
+            return;
+        }
+        let base = &input[..input.rfind(' ').map_or(0, |i| i + 1)];
+        for suggestion in spec.suggest(input, &names()).into_iter().take(6) {
+            let word = format!("{}{}", base, suggestion.value);
+            if spec
+                .parse(&word, &names())
+                .is_ok_and(|out| out.remaining.is_empty())
+                && !out.contains(&word)
+            {
+                out.push(word.clone());
+            }
+            suggested_commands(spec, &format!("{} ", word), depth - 1, out);
+        }
+    }
+
+    /// Samples of the strings a pattern matches: every alternative, and
+    /// repeats at their minimum and one more.
+    fn samples(pattern: &Pattern) -> Vec<String> {
+        match pattern {
+            Pattern::Literal(s) => vec![s.clone()],
+            Pattern::Digit(lo, hi) => {
+                let mut v = vec![lo.to_string()];
+                if hi != lo {
+                    v.push(hi.to_string());
+                }
+                v
+            }
+            Pattern::Seq(patterns) => patterns.iter().fold(vec![String::new()], |acc, p| {
+                let next = samples(p);
+                acc.iter()
+                    .flat_map(|a| next.iter().map(move |n| format!("{}{}", a, n)))
+                    .take(200)
+                    .collect()
+            }),
+            Pattern::Alt(patterns) => patterns.iter().flat_map(samples).collect(),
+            Pattern::Repeat { pattern, min, max } => {
+                let counts = [*min, min + 1];
+                counts
+                    .into_iter()
+                    .filter(|n| max.is_none_or(|max| *n <= max))
+                    .flat_map(|n| samples(&Pattern::Seq(vec![(**pattern).clone(); n])))
+                    .collect()
+            }
+        }
+    }
+
+    #[test]
+    fn every_suggested_command_matches_the_grammar() {
+        let spec = sample_spec();
+        let re = full_regex(&spec);
+        let mut commands = vec![];
+        suggested_commands(&spec, "", 5, &mut commands);
+        for expected in [
+            "bid 1 1",
+            "bid 5 5",
+            "call",
+            "give steve g2",
+            "give mick r5 b10",
+            "pass all",
+        ] {
+            assert!(
+                commands.iter().any(|c| c == expected),
+                "{expected} not suggested"
+            );
+        }
+        for command in &commands {
+            assert!(
+                re.is_match(command),
+                "{command:?} doesn't match {}",
+                re.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn every_grammar_sample_parses_and_matches() {
+        let spec = sample_spec();
+        let re = full_regex(&spec);
+        let grammar = spec.grammar(&names()).unwrap();
+        let commands = samples(&grammar.pattern);
+        assert!(commands.len() > 10);
+        for command in &commands {
+            let out = spec
+                .parse(command, &names())
+                .unwrap_or_else(|e| panic!("{command:?} doesn't parse: {e}"));
+            assert_eq!("", out.remaining, "{command:?} left input");
+            assert!(re.is_match(command), "{command:?} doesn't match");
+        }
+    }
+
+    #[test]
+    fn grammar_rejects_what_the_parser_rejects() {
+        let re = full_regex(&sample_spec());
+        for command in [
+            "bid 0 3",
+            "bid 3 7",
+            "bid 03 4",
+            "give bob r5",
+            "give mick",
+            "give mick r5 b10 g2",
+            "passall",
+            "call ",
+        ] {
+            assert!(!re.is_match(command), "{command:?} matched");
+        }
+    }
+
+    #[test]
+    fn int_ranges_match_exactly() {
+        let bounds = [
+            (None, None),
+            (Some(0), None),
+            (Some(7), None),
+            (Some(95), None),
+            (None, Some(-3)),
+            (None, Some(12)),
+            (Some(-25), Some(-3)),
+            (Some(-9), Some(140)),
+            (Some(0), Some(0)),
+            (Some(3), Some(3)),
+            (Some(10), Some(99)),
+            (Some(19), Some(201)),
+            (Some(100), Some(1000)),
+        ];
+        for (min, max) in bounds {
+            let spec = Spec::Int { min, max };
+            let re = full_regex(&spec);
+            for n in -300..=1200 {
+                let expected = min.is_none_or(|min| n >= min) && max.is_none_or(|max| n <= max);
+                assert_eq!(
+                    expected,
+                    re.is_match(&n.to_string()),
+                    "{n} in {min:?}..={max:?} with {}",
+                    re.as_str()
+                );
+            }
+            assert!(!re.is_match("-0"));
+            assert!(!re.is_match("01"));
+        }
+        assert!(
+            full_regex(&Spec::Int {
+                min: None,
+                max: None
+            })
+            .is_match(&i32::MIN.to_string())
+        );
+        assert!(
+            full_regex(&Spec::Int {
+                min: None,
+                max: None
+            })
+            .is_match(&i32::MAX.to_string())
+        );
+    }
+
+    #[test]
+    fn impossible_specs_have_no_grammar() {
+        assert!(Spec::Player.grammar(&[]).is_none());
+        assert!(Spec::OneOf(vec![]).grammar(&[]).is_none());
+        assert!(
+            Spec::Int {
+                min: Some(5),
+                max: Some(4)
+            }
+            .grammar(&[])
+            .is_none()
+        );
+        // An impossible optional part leaves the rest.
+        let spec = Spec::Chain(vec![
+            Spec::Token("go".into()),
+            Spec::Opt(Box::new(Spec::Player)),
+        ]);
+        assert_eq!("go", spec.grammar(&[]).unwrap().regex());
+    }
+
+    #[test]
+    fn renders_gbnf_and_json_schema() {
+        let spec = Spec::Chain(vec![
+            Spec::Token("play".into()),
+            Spec::Space,
+            Spec::Enum {
+                values: vec!["a\"b".into(), "c".into()],
+                exact: true,
+            },
+            Spec::Opt(Box::new(Spec::Chain(vec![
+                Spec::Space,
+                Spec::Int {
+                    min: Some(1),
+                    max: Some(3),
+                },
+            ]))),
+        ]);
+        let grammar = spec.grammar(&[]).unwrap();
+        assert_eq!(
+            "root ::= \"play\" \" \" (\"a\\\"b\" | \"c\") (\" \" [1-3])?\n",
+            grammar.gbnf()
+        );
+        assert_eq!("play (a\"b|c)( [1-3])?", grammar.regex());
+        let schema = grammar.json_schema();
+        assert_eq!(
+            "^(play (a\"b|c)( [1-3])?)$",
+            schema["properties"]["command"]["pattern"]
+        );
+        assert_eq!(serde_json::json!(["command"]), schema["required"]);
+    }
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 pub mod doc;
+pub mod enumerate;
+pub mod grammar;
 pub mod parser;
 pub mod suggest;
 
@@ -0,0 +1,231 @@
+//! Localized command words.
+//!
+//! Game parsers only understand their English tokens and enum values.
+//! Rather than threading a locale through every parser, a localized command
+//! is rewritten word by word into English before it reaches
+//! `Gamer::command`, and the command spec sent to clients is widened so
+//! autocomplete offers the localized words too.
+
+use brdgme_i18n::Catalog;
+
+use crate::command::Spec as CommandSpec;
+
+/// Maps whole localized words to the English word the game's parser
+/// expects, matched case-insensitively.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Aliases {
+    /// `(lowercased alias, canonical)`, in catalog order.
+    words: Vec<(String, String)>,
+}
+
+impl Aliases {
+    pub fn new<I, A, C>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (A, C)>,
+        A: Into<String>,
+        C: Into<String>,
+    {
+        Self {
+            words: pairs
+                .into_iter()
+                .map(|(alias, canonical)| (alias.into().to_lowercase(), canonical.into()))
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    fn canonical(&self, word: &str) -> Option<&str> {
+        let lower = word.to_lowercase();
+        self.words
+            .iter()
+            .find(|(alias, _)| *alias == lower)
+            .map(|(_, canonical)| canonical.as_str())
+    }
+
+    fn aliases_of<'a>(&'a self, canonical: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        let lower = canonical.to_lowercase();
+        self.words
+            .iter()
+            .filter(move |(_, c)| c.to_lowercase() == lower)
+            .map(|(alias, _)| alias.as_str())
+    }
+
+    /// Rewrites every whole word that is an alias into its canonical form,
+    /// keeping whitespace exactly as typed. Words naming a player are never
+    /// rewritten, so a player called "Pasar" can still be targeted.
+    pub fn apply(&self, input: &str, names: &[String]) -> String {
+        if self.is_empty() {
+            return input.to_string();
+        }
+        let mut output = String::with_capacity(input.len());
+        let mut rest = input;
+        while !rest.is_empty() {
+            let word_start = rest.len() - rest.trim_start().len();
+            output.push_str(&rest[..word_start]);
+            rest = &rest[word_start..];
+            let word_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let word = &rest[..word_end];
+            let is_name = names
+                .iter()
+                .any(|n| n.to_lowercase() == word.to_lowercase());
+            match self.canonical(word) {
+                Some(canonical) if !is_name => output.push_str(canonical),
+                _ => output.push_str(word),
+            }
+            rest = &rest[word_end..];
+        }
+        output
+    }
+
+    /// Widens a command spec so every token and enum value with an alias
+    /// also accepts and suggests the alias.
+    pub fn localize_spec(&self, spec: &CommandSpec) -> CommandSpec {
+        if self.is_empty() {
+            return spec.clone();
+        }
+        match spec {
+            CommandSpec::Token(token) => {
+                let aliases: Vec<CommandSpec> = self
+                    .aliases_of(token)
+                    .map(|a| CommandSpec::Token(a.to_string()))
+                    .collect();
+                if aliases.is_empty() {
+                    return spec.clone();
+                }
+                let mut one_of = vec![spec.clone()];
+                one_of.extend(aliases);
+                CommandSpec::OneOf(one_of)
+            }
+            CommandSpec::Enum { values, exact } => {
+                let mut localized = values.clone();
+                for v in values {
+                    localized.extend(self.aliases_of(v).map(String::from));
+                }
+                CommandSpec::Enum {
+                    values: localized,
+                    exact: *exact,
+                }
+            }
+            CommandSpec::OneOf(specs) => {
+                CommandSpec::OneOf(specs.iter().map(|s| self.localize_spec(s)).collect())
+            }
+            CommandSpec::Chain(specs) => {
+                CommandSpec::Chain(specs.iter().map(|s| self.localize_spec(s)).collect())
+            }
+            CommandSpec::Many {
+                spec,
+                min,
+                max,
+                delim,
+            } => CommandSpec::Many {
+                spec: Box::new(self.localize_spec(spec)),
+                min: *min,
+                max: *max,
+                delim: delim.as_ref().map(|d| Box::new(self.localize_spec(d))),
+            },
+            CommandSpec::Opt(spec) => CommandSpec::Opt(Box::new(self.localize_spec(spec))),
+            CommandSpec::Doc { name, desc, spec } => CommandSpec::Doc {
+                name: name.clone(),
+                desc: desc.clone(),
+                spec: Box::new(self.localize_spec(spec)),
+            },
+            CommandSpec::Int { .. } | CommandSpec::Player | CommandSpec::Space => spec.clone(),
+        }
+    }
+}
+
+impl From<&Catalog> for Aliases {
+    fn from(catalog: &Catalog) -> Self {
+        Aliases::new(
+            catalog
+                .aliases
+                .iter()
+                .map(|(alias, canonical)| (alias.as_str(), canonical.as_str())),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::parser::Parser;
+
+    fn aliases() -> Aliases {
+        Aliases::new([("jugar", "play"), ("Rojo", "red"), ("pasar", "pass")])
+    }
+
+    fn names() -> Vec<String> {
+        vec!["Pasar".to_string(), "steve".to_string()]
+    }
+
+    fn play_spec() -> CommandSpec {
+        CommandSpec::OneOf(vec![
+            CommandSpec::Chain(vec![
+                CommandSpec::Token("play".to_string()),
+                CommandSpec::Space,
+                CommandSpec::Enum {
+                    values: vec!["red".to_string(), "blue".to_string()],
+                    exact: false,
+                },
+            ]),
+            CommandSpec::Token("pass".to_string()),
+        ])
+    }
+
+    #[test]
+    fn apply_rewrites_whole_words_case_insensitively() {
+        assert_eq!("play  red", aliases().apply("Jugar  rojo", &[]));
+        assert_eq!("pass\n", aliases().apply("pasar\n", &[]));
+    }
+
+    #[test]
+    fn apply_leaves_partial_words_and_unknown_words() {
+        assert_eq!("jug rojos", aliases().apply("jug rojos", &[]));
+        assert_eq!("play red", aliases().apply("play red", &[]));
+    }
+
+    #[test]
+    fn apply_never_rewrites_player_names() {
+        assert_eq!("play pasar", aliases().apply("jugar pasar", &names()));
+    }
+
+    #[test]
+    fn apply_handles_multibyte_whitespace() {
+        assert_eq!("play\u{a0}red", aliases().apply("jugar\u{a0}rojo", &[]));
+    }
+
+    #[test]
+    fn empty_aliases_leave_input_and_spec_alone() {
+        let empty = Aliases::default();
+        assert_eq!("jugar", empty.apply("jugar", &[]));
+        assert_eq!(play_spec(), empty.localize_spec(&play_spec()));
+    }
+
+    #[test]
+    fn localized_spec_suggests_and_parses_aliases() {
+        let spec = aliases().localize_spec(&play_spec());
+        let suggested: Vec<String> = spec
+            .suggest("ju", &[])
+            .into_iter()
+            .map(|s| s.value)
+            .collect();
+        assert_eq!(vec!["jugar"], suggested);
+        let suggested: Vec<String> = spec
+            .suggest("jugar r", &[])
+            .into_iter()
+            .map(|s| s.value)
+            .collect();
+        assert_eq!(vec!["red", "rojo"], suggested);
+        assert!(spec.parse("jugar rojo", &[]).is_ok());
+        assert!(spec.parse("play red", &[]).is_ok());
+    }
+
+    #[test]
+    fn from_catalog() {
+        let catalog = Catalog::from_yaml("aliases:\n  robar: draw\n").unwrap();
+        assert_eq!("draw", Aliases::from(&catalog).apply("robar", &[]));
+    }
+}
@@ -7,8 +7,10 @@ use unicase::UniCase;
 use crate::command::Spec as CommandSpec;
 use crate::errors::GameError;
 
+pub use self::alias::Aliases;
 pub use self::chain::*;
 
+pub mod alias;
 pub mod chain;
 
 #[derive(Debug, PartialEq)]
@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use brdgme_i18n::Locale;
 use brdgme_markup::Node;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::command;
 use crate::errors::GameError;
 use crate::game_log::Log;
+use crate::options::{GameOption, GameOptions};
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Stat {
@@ -28,9 +30,27 @@ pub enum Status {
         /// player, including ties and game-specific tie-breakers.
         placings: Vec<usize>,
         stats: Vec<HashMap<String, Stat>>,
+        /// 0-based team per player for partnership games, where every member
+        /// of a team shares its placing. Empty when everyone plays for
+        /// themselves.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        teams: Vec<usize>,
+        /// Set by cooperative games, where the players win or lose together.
+        /// The result is scored rather than rated, and `placings` should tie
+        /// every player.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cooperative: Option<CooperativeOutcome>,
     },
 }
 
+/// The shared result of a cooperative game.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CooperativeOutcome {
+    pub won: bool,
+    /// Game-specific and higher-is-better, e.g. Hanabi's firework total.
+    pub score: i32,
+}
+
 impl Status {
     pub fn is_finished(&self) -> bool {
         match *self {
@@ -52,6 +72,23 @@ pub trait Gamer: Sized {
     type PlayerState: Serialize + DeserializeOwned + Renderer;
 
     fn start(players: usize, seed: u64) -> Result<(Self, Vec<Log>), GameError>;
+
+    /// Choices offered when creating a game, such as rules variants or
+    /// expansions.
+    fn options() -> Vec<GameOption> {
+        vec![]
+    }
+
+    /// Starts a game with the values chosen for `options()`. Games offering
+    /// options override this; `start` is the game with every default.
+    fn start_with_options(
+        players: usize,
+        seed: u64,
+        options: &GameOptions,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        options.resolve(&Self::options())?;
+        Self::start(players, seed)
+    }
     fn pub_state(&self) -> Self::PubState;
     fn player_state(&self, player: usize) -> Self::PlayerState;
     fn command(
@@ -97,6 +134,20 @@ pub trait Gamer: Sized {
         }
     }
 
+    fn teams(&self) -> Vec<usize> {
+        match self.status() {
+            Status::Finished { teams, .. } => teams,
+            _ => vec![],
+        }
+    }
+
+    fn cooperative(&self) -> Option<CooperativeOutcome> {
+        match self.status() {
+            Status::Finished { cooperative, .. } => cooperative,
+            _ => None,
+        }
+    }
+
     fn assert_not_finished(&self) -> Result<(), GameError> {
         if self.is_finished() {
             Err(GameError::Finished)
@@ -140,6 +191,13 @@ pub trait Gamer: Sized {
     fn advanced_strategy() -> String {
         String::new()
     }
+
+    /// The YAML source of this game's `brdgme_i18n::Catalog` for `locale`,
+    /// usually `include_str!("../i18n/<code>.yaml")`. Text without a catalog
+    /// entry is shown in English.
+    fn catalog_yaml(_locale: Locale) -> Option<&'static str> {
+        None
+    }
 }
 
 pub trait Renderer {
@@ -210,4 +268,23 @@ mod tests {
             super::gen_placings(&[vec![12i32, 35i32, 0i32], vec![12i32, 35i32]])
         );
     }
+
+    #[test]
+    fn finished_status_without_teams_or_cooperative_round_trips() {
+        let status: super::Status =
+            serde_json::from_str(r#"{"Finished":{"placings":[1,2],"stats":[]}}"#).unwrap();
+        assert_eq!(
+            super::Status::Finished {
+                placings: vec![1, 2],
+                stats: vec![],
+                teams: vec![],
+                cooperative: None,
+            },
+            status
+        );
+        assert_eq!(
+            r#"{"Finished":{"placings":[1,2],"stats":[]}}"#,
+            serde_json::to_string(&status).unwrap()
+        );
+    }
 }
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use time::{OffsetDateTime, PrimitiveDateTime};
 
-use brdgme_markup::Node;
+use brdgme_markup::{Node, comma_list_and};
+
+use crate::game::CooperativeOutcome;
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Log {
@@ -69,3 +71,125 @@ pub fn placings_log(placings: &[usize], scores: Option<&[(usize, i32)]>) -> Log
 
     Log::public(content)
 }
+
+/// The `placings_log` for partnership games: announces the winning team with
+/// its members. `teams` is the 0-based team per player from
+/// `Status::Finished`, and `scores` are per team.
+pub fn team_placings_log(
+    placings: &[usize],
+    teams: &[usize],
+    scores: Option<&[(usize, i32)]>,
+) -> Log {
+    let mut winners: Vec<usize> = vec![];
+    for (player, &placing) in placings.iter().enumerate() {
+        if let Some(&team) = teams.get(player)
+            && placing == 1
+            && !winners.contains(&team)
+        {
+            winners.push(team);
+        }
+    }
+
+    let mut content: Vec<Node> = match winners.len() {
+        1 => {
+            let mut c = team_nodes(winners[0], teams);
+            c.push(Node::Bold(vec![Node::text(" wins!")]));
+            c
+        }
+        2 => {
+            let mut c = team_nodes(winners[0], teams);
+            c.push(Node::text(" and "));
+            c.extend(team_nodes(winners[1], teams));
+            c.push(Node::Bold(vec![Node::text(" tie!")]));
+            c
+        }
+        _ => vec![Node::Bold(vec![Node::text("It's a tie!")])],
+    };
+
+    if let Some(scores) = scores {
+        content.push(Node::text(" Final scores: "));
+        for (i, &(team, score)) in scores.iter().enumerate() {
+            if i > 0 {
+                content.push(Node::text(", "));
+            }
+            content.push(Node::text(format!("Team {}: ", team + 1)));
+            content.push(Node::Bold(vec![Node::text(score.to_string())]));
+        }
+    }
+
+    Log::public(content)
+}
+
+/// "Team 1 (alice and bob)"
+fn team_nodes(team: usize, teams: &[usize]) -> Vec<Node> {
+    let members: Vec<Vec<Node>> = (0..teams.len())
+        .filter(|&p| teams[p] == team)
+        .map(|p| vec![Node::Player(p)])
+        .collect();
+    let mut nodes = vec![Node::text(format!("Team {} (", team + 1))];
+    nodes.extend(comma_list_and(&members));
+    nodes.push(Node::text(")"));
+    nodes
+}
+
+/// The end-of-game log for cooperative games, where the players win or lose
+/// together.
+pub fn cooperative_log(outcome: &CooperativeOutcome) -> Log {
+    Log::public(vec![
+        Node::Bold(vec![Node::text(if outcome.won {
+            "Everyone wins!"
+        } else {
+            "Everyone loses."
+        })]),
+        Node::text(" Final score: "),
+        Node::Bold(vec![Node::text(outcome.score.to_string())]),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn team_placings_log_names_the_winning_team() {
+        assert_eq!(
+            vec![
+                Node::text("Team 2 ("),
+                Node::Player(1),
+                Node::text(" and "),
+                Node::Player(3),
+                Node::text(")"),
+                Node::Bold(vec![Node::text(" wins!")]),
+                Node::text(" Final scores: "),
+                Node::text("Team 2: "),
+                Node::Bold(vec![Node::text("320")]),
+                Node::text(", "),
+                Node::text("Team 1: "),
+                Node::Bold(vec![Node::text("180")]),
+            ],
+            team_placings_log(&[2, 1, 2, 1], &[0, 1, 0, 1], Some(&[(1, 320), (0, 180)])).content
+        );
+    }
+
+    #[test]
+    fn team_placings_log_reports_tied_teams() {
+        let content = team_placings_log(&[1, 1, 1, 1], &[0, 1, 0, 1], None).content;
+        assert_eq!(Some(&Node::Bold(vec![Node::text(" tie!")])), content.last());
+    }
+
+    #[test]
+    fn cooperative_log_reports_shared_result() {
+        assert_eq!(
+            vec![
+                Node::Bold(vec![Node::text("Everyone loses.")]),
+                Node::text(" Final score: "),
+                Node::Bold(vec![Node::text("17")]),
+            ],
+            cooperative_log(&CooperativeOutcome {
+                won: false,
+                score: 17
+            })
+            .content
+        );
+    }
+}
@@ -1,9 +1,12 @@
-pub use crate::game::{CommandResponse, Gamer, Renderer, Stat, Status};
-pub use crate::game_log::{Log, placings_log};
+pub use crate::game::{CommandResponse, CooperativeOutcome, Gamer, Renderer, Stat, Status};
+pub use crate::game_log::{Log, cooperative_log, placings_log, team_placings_log};
+pub use crate::options::{GameOption, GameOptions};
+pub use brdgme_i18n as i18n;
 
 pub mod bot;
 pub mod command;
 pub mod errors;
 pub mod game;
 pub mod game_log;
+pub mod options;
 pub mod rng;
@@ -0,0 +1,199 @@
+//! Choices made when a game is created, such as a rules variant or an
+//! expansion.
+//!
+//! A game lists what it offers from `Gamer::options` and reads the chosen
+//! values in `Gamer::start_with_options`. Every option is a pick from a fixed
+//! list of values, the first being the default, so clients can offer each
+//! one as a select without knowing anything about the game.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::GameError;
+
+/// One choice offered when creating a game.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GameOption {
+    pub key: String,
+    pub name: String,
+    pub desc: String,
+    /// The first value is the default.
+    pub values: Vec<OptionValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OptionValue {
+    pub value: String,
+    pub name: String,
+}
+
+impl GameOption {
+    /// Builds an option from `(value, name)` pairs, the first being the
+    /// default.
+    pub fn new(key: &str, name: &str, desc: &str, values: &[(&str, &str)]) -> Self {
+        Self {
+            key: key.to_string(),
+            name: name.to_string(),
+            desc: desc.to_string(),
+            values: values
+                .iter()
+                .map(|(value, name)| OptionValue {
+                    value: value.to_string(),
+                    name: name.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    /// An off/on option for an expansion or house rule, off by default.
+    pub fn toggle(key: &str, name: &str, desc: &str) -> Self {
+        Self::new(key, name, desc, &[("off", "Off"), ("on", "On")])
+    }
+
+    pub fn default_value(&self) -> Option<&str> {
+        self.values.first().map(|v| v.value.as_str())
+    }
+}
+
+/// The values chosen for a new game, keyed by `GameOption::key`. Options
+/// that aren't set take their default.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct GameOptions(BTreeMap<String, String>);
+
+impl GameOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Whether a toggle option is switched on.
+    pub fn is_on(&self, key: &str) -> bool {
+        self.get(key) == Some("on")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Checks every chosen value against `offered` and returns the full set
+    /// with defaults filled in, so games can read any offered key.
+    pub fn resolve(&self, offered: &[GameOption]) -> Result<GameOptions, GameError> {
+        for (key, value) in self.iter() {
+            let option = offered
+                .iter()
+                .find(|o| o.key == key)
+                .ok_or_else(|| GameError::invalid_input(format!("unknown option '{}'", key)))?;
+            if !option.values.iter().any(|v| v.value == value) {
+                return Err(GameError::invalid_input(format!(
+                    "'{}' is not a value for {}, expected one of {}",
+                    value,
+                    option.key,
+                    option
+                        .values
+                        .iter()
+                        .map(|v| v.value.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )));
+            }
+        }
+        let mut resolved = GameOptions::new();
+        for option in offered {
+            let value = self.get(&option.key).or_else(|| option.default_value());
+            if let Some(value) = value {
+                resolved.set(option.key.clone(), value);
+            }
+        }
+        Ok(resolved)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for GameOptions {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offered() -> Vec<GameOption> {
+        vec![
+            GameOption::new(
+                "mode",
+                "Mode",
+                "Which rules to play",
+                &[("basic", "Basic"), ("advanced", "Advanced")],
+            ),
+            GameOption::toggle("cities", "Cities", "Play with the Cities expansion"),
+        ]
+    }
+
+    #[test]
+    fn resolve_fills_defaults() {
+        let resolved = GameOptions::new().resolve(&offered()).unwrap();
+        assert_eq!(Some("basic"), resolved.get("mode"));
+        assert!(!resolved.is_on("cities"));
+        let resolved = GameOptions::new()
+            .with("cities", "on")
+            .resolve(&offered())
+            .unwrap();
+        assert!(resolved.is_on("cities"));
+        assert_eq!(Some("basic"), resolved.get("mode"));
+    }
+
+    #[test]
+    fn resolve_rejects_unknown_keys_and_values() {
+        let err = GameOptions::new()
+            .with("colour", "red")
+            .resolve(&offered())
+            .unwrap_err();
+        assert!(matches!(err, GameError::InvalidInput { .. }));
+        let err = GameOptions::new()
+            .with("mode", "expert")
+            .resolve(&offered())
+            .unwrap_err();
+        assert_eq!(
+            "invalid input, 'expert' is not a value for mode, expected one of basic, advanced",
+            err.to_string()
+        );
+        assert!(
+            GameOptions::new()
+                .with("mode", "basic")
+                .resolve(&[])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn serializes_as_a_plain_map() {
+        let options = GameOptions::new().with("mode", "advanced");
+        assert_eq!(
+            r#"{"mode":"advanced"}"#,
+            serde_json::to_string(&options).unwrap()
+        );
+    }
+}
@@ -60,7 +60,9 @@ pub enum GameClientError {
 }
 
 use brdgme_cmd::api::{PlayerRender, PubRender, Request, Response};
+use brdgme_game::GameOption;
 use brdgme_game::command::Spec as CommandSpec;
+use brdgme_game::i18n::{Catalog, Locale};
 use std::time::Duration;
 
 /// Bounded retry policy for transient transport failures (connect-refused,
@@ -293,6 +295,33 @@ pub async fn player_render(
     }
 }
 
+/// The game's translations for `locale`. Only interface v2 services answer
+/// this; callers must not ask v1 services.
+pub async fn catalog(
+    client: &reqwest::Client,
+    uri: &str,
+    version_name: &str,
+    locale: Locale,
+) -> Result<Catalog, GameClientError> {
+    match request(client, uri, version_name, &Request::Catalog { locale }).await? {
+        Response::Catalog { catalog } => Ok(catalog),
+        _ => Err(GameClientError::UnexpectedResponse { request: "Catalog" }),
+    }
+}
+
+/// The choices the game offers at creation. Only interface v2 services
+/// answer this; callers must not ask v1 services.
+pub async fn options(
+    client: &reqwest::Client,
+    uri: &str,
+    version_name: &str,
+) -> Result<Vec<GameOption>, GameClientError> {
+    match request(client, uri, version_name, &Request::Options).await? {
+        Response::Options { options } => Ok(options),
+        _ => Err(GameClientError::UnexpectedResponse { request: "Options" }),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GameData {
     pub pub_state_yaml: String,
@@ -722,6 +751,7 @@ mod tests {
         let req = Request::New {
             players: 2,
             seed: None,
+            options: Default::default(),
         };
         let client = reqwest::Client::new();
         let resp = request(&client, &uri, "test-game-1", &req)
@@ -812,6 +842,9 @@ mod tests {
             Request::Rules => Response::Rules {
                 rules: "Game rules here".to_string(),
             },
+            Request::Options => Response::Options {
+                options: vec![GameOption::toggle("cities", "Cities", "Play with cities")],
+            },
             _ => Response::SystemError {
                 message: "unsupported in mock".to_string(),
             },
@@ -870,6 +903,17 @@ mod tests {
         assert_eq!(data.rules, "Game rules here");
     }
 
+    #[tokio::test]
+    async fn test_options_returns_offered_options() {
+        let uri = start_mock_server().await;
+        let client = reqwest::Client::new();
+        let offered = options(&client, &uri, "test-v2")
+            .await
+            .expect("options failed");
+        assert_eq!(1, offered.len());
+        assert_eq!("cities", offered[0].key);
+    }
+
     #[tokio::test]
     async fn test_fetch_game_data_yaml_serialization() {
         let uri = start_mock_server().await;
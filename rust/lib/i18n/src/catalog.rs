@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use brdgme_markup::Node;
+
+use crate::error::I18nError;
+
+/// One locale's translations for one game, usually embedded in the game
+/// crate as `i18n/<code>.yaml`:
+///
+/// ```yaml
+/// messages:
+///   "Draw pile": "Mazo"
+/// aliases:
+///   robar: draw
+/// rules: |
+///   # Reglas
+/// ```
+///
+/// `messages` are keyed by the exact English text of a `Node::Text`
+/// (ignoring surrounding whitespace). A `{}` in a key stands for a run of
+/// digits, which is carried into the `{}`s of the translation in order, so
+/// `"({} cards remaining)"` also translates `"(5 cards remaining)"`.
+/// `aliases` map a localized command word to the English token or enum value
+/// the game's parser expects; see `brdgme_game::command::parser::Aliases`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Catalog {
+    #[serde(default)]
+    pub messages: BTreeMap<String, String>,
+    #[serde(default)]
+    pub aliases: BTreeMap<String, String>,
+    #[serde(default)]
+    pub rules: Option<String>,
+    #[serde(default)]
+    pub basic_strategy: Option<String>,
+    #[serde(default)]
+    pub advanced_strategy: Option<String>,
+}
+
+impl Catalog {
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(source: &str) -> Result<Self, I18nError> {
+        Ok(serde_yaml_ng::from_str(source)?)
+    }
+
+    /// The translation of `msgid`, or `msgid` itself when there is none.
+    pub fn text<'a>(&'a self, msgid: &'a str) -> &'a str {
+        self.messages.get(msgid).map_or(msgid, String::as_str)
+    }
+
+    /// Translates every text node in a markup tree. Leading and trailing
+    /// whitespace is kept from the source so spacing between adjacent nodes
+    /// survives, e.g. `" played "` between two `Node::Player`s.
+    pub fn translate(&self, nodes: &[Node]) -> Vec<Node> {
+        if self.messages.is_empty() {
+            return nodes.to_vec();
+        }
+        nodes.iter().map(|n| self.translate_node(n)).collect()
+    }
+
+    /// Parses, translates and re-serializes a markup string, for consumers
+    /// that store renders and logs as markup.
+    pub fn translate_markup(&self, markup: &str) -> Result<String, I18nError> {
+        if self.messages.is_empty() {
+            return Ok(markup.to_string());
+        }
+        Ok(brdgme_markup::to_string(
+            &self.translate(&brdgme_markup::from_string(markup)?),
+        ))
+    }
+
+    fn translate_node(&self, node: &Node) -> Node {
+        match node {
+            Node::Text(text) => Node::Text(self.translate_text(text)),
+            Node::Fg(col, children) => Node::Fg(col.clone(), self.translate(children)),
+            Node::Bg(col, children) => Node::Bg(col.clone(), self.translate(children)),
+            Node::Group(children) => Node::Group(self.translate(children)),
+            Node::Bold(children) => Node::Bold(self.translate(children)),
+            Node::Player(p) => Node::Player(*p),
+            Node::Table(rows) => Node::Table(
+                rows.iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|(align, children)| (align.clone(), self.translate(children)))
+                            .collect()
+                    })
+                    .collect(),
+            ),
+            Node::Align(align, width, children) => {
+                Node::Align(align.clone(), *width, self.translate(children))
+            }
+            Node::Indent(width, children) => Node::Indent(*width, self.translate(children)),
+            Node::Canvas(layers) => Node::Canvas(
+                layers
+                    .iter()
+                    .map(|(x, y, children)| (*x, *y, self.translate(children)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The translation of `msgid`, trying a `{}` key for its numbers when
+    /// there is no exact match.
+    fn message(&self, msgid: &str) -> Option<String> {
+        if let Some(translation) = self.messages.get(msgid) {
+            return Some(translation.clone());
+        }
+        let (key, numbers) = number_pattern(msgid);
+        if numbers.is_empty() {
+            return None;
+        }
+        let translation = self.messages.get(&key)?;
+        let mut numbers = numbers.into_iter();
+        let mut parts = translation.split("{}");
+        let mut out = parts.next().unwrap_or_default().to_string();
+        for part in parts {
+            out.push_str(numbers.next().unwrap_or("{}"));
+            out.push_str(part);
+        }
+        Some(out)
+    }
+
+    fn translate_text(&self, text: &str) -> String {
+        let trimmed = text.trim();
+        match self.message(trimmed) {
+            Some(translation) if !trimmed.is_empty() => {
+                let leading = &text[..text.len() - text.trim_start().len()];
+                let trailing = &text[text.trim_end().len()..];
+                format!("{}{}{}", leading, translation, trailing)
+            }
+            _ => text.to_string(),
+        }
+    }
+}
+
+/// `text` with each run of ASCII digits replaced by `{}`, and the runs.
+fn number_pattern(text: &str) -> (String, Vec<&str>) {
+    let mut pattern = String::with_capacity(text.len());
+    let mut numbers = vec![];
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        pattern.push_str(&rest[..start]);
+        let digits = &rest[start..];
+        let end = digits
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(digits.len());
+        numbers.push(&digits[..end]);
+        pattern.push_str("{}");
+        rest = &digits[end..];
+    }
+    pattern.push_str(rest);
+    (pattern, numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brdgme_markup::Align;
+
+    fn catalog() -> Catalog {
+        Catalog::from_yaml(
+            r#"
+messages:
+  "Draw pile": "Mazo"
+  "played": "jugó"
+  "({} cards remaining)": "(quedan {} cartas)"
+  "{} of {}": "{} de {}"
+aliases:
+  robar: draw
+rules: |
+  # Reglas
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn from_yaml_reads_all_sections() {
+        let c = catalog();
+        assert_eq!(Some("draw"), c.aliases.get("robar").map(String::as_str));
+        assert_eq!(Some("# Reglas\n"), c.rules.as_deref());
+        assert_eq!(None, c.basic_strategy);
+    }
+
+    #[test]
+    fn empty_yaml_is_empty_catalog() {
+        assert_eq!(Catalog::default(), Catalog::from_yaml("{}").unwrap());
+    }
+
+    #[test]
+    fn text_falls_back_to_msgid() {
+        let c = catalog();
+        assert_eq!("Mazo", c.text("Draw pile"));
+        assert_eq!("Discard pile", c.text("Discard pile"));
+    }
+
+    #[test]
+    fn translate_keeps_surrounding_whitespace_and_walks_children() {
+        let c = catalog();
+        let nodes = vec![
+            Node::Player(0),
+            Node::text(" played "),
+            Node::Bold(vec![Node::text("Draw pile")]),
+            Node::Table(vec![vec![(Align::Left, vec![Node::text("Draw pile")])]]),
+            Node::text("untranslated"),
+        ];
+        assert_eq!(
+            vec![
+                Node::Player(0),
+                Node::text(" jugó "),
+                Node::Bold(vec![Node::text("Mazo")]),
+                Node::Table(vec![vec![(Align::Left, vec![Node::text("Mazo")])]]),
+                Node::text("untranslated"),
+            ],
+            c.translate(&nodes)
+        );
+    }
+
+    #[test]
+    fn translate_fills_number_placeholders_in_order() {
+        let c = catalog();
+        assert_eq!(
+            vec![
+                Node::text(" (quedan 12 cartas)\n"),
+                Node::text("3 de 10"),
+                Node::text("(12 cards left)"),
+            ],
+            c.translate(&[
+                Node::text(" (12 cards remaining)\n"),
+                Node::text("3 of 10"),
+                Node::text("(12 cards left)"),
+            ])
+        );
+    }
+
+    #[test]
+    fn translate_markup_round_trips() {
+        let c = catalog();
+        assert_eq!(
+            "{{b}}Mazo{{/b}}",
+            c.translate_markup("{{b}}Draw pile{{/b}}").unwrap()
+        );
+        assert!(c.translate_markup("{{b}}unclosed").is_err());
+    }
+}
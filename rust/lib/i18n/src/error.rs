@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum I18nError {
+    #[error("unknown locale '{code}'")]
+    UnknownLocale { code: String },
+    #[cfg(feature = "yaml")]
+    #[error("invalid message catalog: {source}")]
+    Catalog {
+        #[from]
+        source: serde_yaml_ng::Error,
+    },
+    #[error("invalid markup: {source}")]
+    Markup {
+        #[from]
+        source: brdgme_markup::MarkupError,
+    },
+}
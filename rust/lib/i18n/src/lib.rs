@@ -0,0 +1,100 @@
+//! Player-facing translations for game renders, logs, rules and command
+//! words.
+//!
+//! Game crates keep English as the source language: renders and logs are
+//! built from English `Node::Text` literals as before, and a per-locale
+//! `Catalog` maps those literals (the msgid, gettext style) to translations.
+//! Anything without a catalog entry falls back to English, so a catalog can
+//! grow one string at a time.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::catalog::Catalog;
+pub use crate::error::I18nError;
+
+mod catalog;
+mod error;
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+    De,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::En, Locale::Es, Locale::De];
+
+    /// ISO 639-1 code, as stored in user preferences.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::De => "de",
+        }
+    }
+
+    /// The language's own name for itself, for locale pickers.
+    pub fn name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+            Locale::De => "Deutsch",
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = I18nError;
+
+    /// Accepts a bare language code or a language tag with a region, such as
+    /// an `Accept-Language` entry (`es-AR`, `de_DE`), case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_']).next().unwrap_or("").trim();
+        Locale::ALL
+            .into_iter()
+            .find(|l| l.code().eq_ignore_ascii_case(language))
+            .ok_or_else(|| I18nError::UnknownLocale {
+                code: s.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_parses_codes_and_tags() {
+        assert_eq!(Locale::Es, "es".parse().unwrap());
+        assert_eq!(Locale::De, "de-DE".parse().unwrap());
+        assert_eq!(Locale::Es, "ES_ar".parse().unwrap());
+        assert!("fr".parse::<Locale>().is_err());
+        assert!("".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn locale_code_round_trips() {
+        for locale in Locale::ALL {
+            assert_eq!(locale, locale.code().parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn locale_serializes_as_code() {
+        assert_eq!("de\n", serde_yaml_ng::to_string(&Locale::De).unwrap());
+    }
+}
@@ -1,10 +1,11 @@
 //! Shared NATS/JetStream wire protocol for the bot <-> web eventing channel.
 //!
-//! The wire types (`BotTurnEvent`, `BotCommandEvent`) and the stream/subject/
-//! consumer constants live here so the bot and the monolith cannot drift on the
-//! wire. The golden-fixture integration test under `tests/` pins the exact JSON
-//! encoding. Infra helpers (`connect`, stream/consumer setup, supervision) stay
-//! in each consumer.
+//! The wire types (`BotTurnEvent`, `BotCommandEvent`, and the `BotHintRequest`
+//! / `BotHintReply` pair) and the stream/subject/consumer constants live here
+//! so the bot and the monolith cannot drift on the wire. The golden-fixture
+//! integration test under `tests/` pins the exact JSON encoding. Infra
+//! helpers (`connect`, stream/consumer setup, supervision) stay in each
+//! consumer.
 
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -16,6 +17,16 @@ pub const SUBJECT_COMMAND: &str = "bot.command";
 pub const CONSUMER_TURN: &str = "bot-turn";
 pub const CONSUMER_COMMAND: &str = "bot-command";
 
+/// Hints are core NATS request/reply, not JetStream: a player is waiting on
+/// the answer, so a lost request is retried by the player rather than
+/// redelivered. Bot service replicas share requests through the queue group.
+pub const SUBJECT_HINT: &str = "bot.hint";
+pub const QUEUE_HINT: &str = "bot-hint";
+
+/// How long the monolith waits for a hint reply. Covers a full LLM
+/// conversation, with tool steps and a couple of retries.
+pub const HINT_TIMEOUT: Duration = Duration::from_secs(120);
+
 /// Overall cap on turn-level re-publishes after a stale-state conflict
 /// (`BotTurnEvent::attempt`), on top of the original publish.
 pub const MAX_TURN_ATTEMPTS: i32 = 3;
@@ -51,4 +62,31 @@ pub struct BotCommandEvent {
     /// turn-level retries have already happened before deciding whether a
     /// stale-state conflict should give up or re-publish `bot.turn` again.
     pub attempt: i32,
+    /// The bot's public explanation of the command, for bots that give one.
+    /// Already screened against the bot's private state. Left off the wire
+    /// when absent, so events from older bots still parse and vice versa.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<String>,
+}
+
+/// Asks the bot service what `bot_name` would play in the seat at
+/// `player_position`, seeing only what that player sees.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BotHintRequest {
+    pub game_id: Uuid,
+    pub player_position: i32,
+    pub bot_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BotHintReply {
+    /// A command the game service accepts, and the bot's reason for it if
+    /// it gave one.
+    Hint {
+        command: String,
+        rationale: Option<String>,
+    },
+    /// No hint could be given, with a message for the player.
+    Unavailable { message: String },
 }
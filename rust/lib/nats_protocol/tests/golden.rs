@@ -1,13 +1,15 @@
 //! Golden-fixture wire-format test for the shared NATS protocol (R-14).
 //!
-//! Pins the exact JSON encoding of `BotTurnEvent` and `BotCommandEvent`
-//! (field names, field order, UUID string form) so that the bot and web
-//! consumers can never silently drift on the wire. Also asserts the shared
-//! constants and the delivery-invariant relationships that both sides rely on.
+//! Pins the exact JSON encoding of `BotTurnEvent`, `BotCommandEvent` and
+//! the hint request/reply (field names, variant tags, field order, UUID
+//! string form) so that the bot and web consumers can never silently drift
+//! on the wire. Also asserts the shared constants and the delivery-invariant
+//! relationships that both sides rely on.
 
 use brdgme_nats::{
-    ACK_WAIT, BotCommandEvent, BotTurnEvent, CONSUMER_COMMAND, CONSUMER_TURN, MAX_DELIVER,
-    MAX_TURN_ATTEMPTS, STREAM_NAME, SUBJECT_COMMAND, SUBJECT_TURN,
+    ACK_WAIT, BotCommandEvent, BotHintReply, BotHintRequest, BotTurnEvent, CONSUMER_COMMAND,
+    CONSUMER_TURN, HINT_TIMEOUT, MAX_DELIVER, MAX_TURN_ATTEMPTS, QUEUE_HINT, STREAM_NAME,
+    SUBJECT_COMMAND, SUBJECT_HINT, SUBJECT_TURN,
 };
 use uuid::Uuid;
 
@@ -15,6 +17,13 @@ const GAME_ID: &str = "01234567-89ab-cdef-0123-456789abcdef";
 
 const TURN_JSON: &str = r#"{"game_id":"01234567-89ab-cdef-0123-456789abcdef","player_position":2,"bot_name":"acquire-1","attempt":1}"#;
 const COMMAND_JSON: &str = r#"{"game_id":"01234567-89ab-cdef-0123-456789abcdef","player_position":2,"command":"play tile A1","attempt":1}"#;
+const COMMAND_WITH_RATIONALE_JSON: &str = r#"{"game_id":"01234567-89ab-cdef-0123-456789abcdef","player_position":2,"command":"play tile A1","attempt":1,"rationale":"A1 merges the two smallest chains."}"#;
+
+const HINT_REQUEST_JSON: &str =
+    r#"{"game_id":"01234567-89ab-cdef-0123-456789abcdef","player_position":0,"bot_name":"coach"}"#;
+const HINT_REPLY_JSON: &str =
+    r#"{"hint":{"command":"play tile A1","rationale":"It founds a chain next to your shares."}}"#;
+const HINT_UNAVAILABLE_JSON: &str = r#"{"unavailable":{"message":"It isn't your turn."}}"#;
 
 fn turn_event() -> BotTurnEvent {
     BotTurnEvent {
@@ -31,6 +40,7 @@ fn command_event() -> BotCommandEvent {
         player_position: 2,
         command: "play tile A1".to_string(),
         attempt: 1,
+        rationale: None,
     }
 }
 
@@ -62,6 +72,23 @@ fn bot_command_event_deserializes_from_golden_fixture() {
     assert_eq!(event.player_position, 2);
     assert_eq!(event.command, "play tile A1");
     assert_eq!(event.attempt, 1);
+    assert_eq!(event.rationale, None);
+}
+
+#[test]
+fn bot_command_event_with_rationale_matches_golden_fixture() {
+    let event = BotCommandEvent {
+        rationale: Some("A1 merges the two smallest chains.".to_string()),
+        ..command_event()
+    };
+    assert_eq!(
+        serde_json::to_string(&event).unwrap(),
+        COMMAND_WITH_RATIONALE_JSON,
+        "BotCommandEvent rationale wire format drifted"
+    );
+    let parsed: BotCommandEvent = serde_json::from_str(COMMAND_WITH_RATIONALE_JSON).unwrap();
+    assert_eq!(parsed.command, "play tile A1");
+    assert_eq!(parsed.rationale, event.rationale);
 }
 
 #[test]
@@ -81,6 +108,49 @@ fn round_trip_preserves_every_field() {
     assert_eq!(command_rt.player_position, command.player_position);
     assert_eq!(command_rt.command, command.command);
     assert_eq!(command_rt.attempt, command.attempt);
+    assert_eq!(command_rt.rationale, command.rationale);
+}
+
+#[test]
+fn bot_hint_request_matches_golden_fixture() {
+    let request = BotHintRequest {
+        game_id: Uuid::parse_str(GAME_ID).unwrap(),
+        player_position: 0,
+        bot_name: "coach".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_string(&request).unwrap(),
+        HINT_REQUEST_JSON,
+        "BotHintRequest wire format drifted"
+    );
+    let parsed: BotHintRequest = serde_json::from_str(HINT_REQUEST_JSON).unwrap();
+    assert_eq!(parsed.game_id, request.game_id);
+    assert_eq!(parsed.player_position, 0);
+    assert_eq!(parsed.bot_name, "coach");
+}
+
+#[test]
+fn bot_hint_reply_matches_golden_fixtures() {
+    let hint = BotHintReply::Hint {
+        command: "play tile A1".to_string(),
+        rationale: Some("It founds a chain next to your shares.".to_string()),
+    };
+    let unavailable = BotHintReply::Unavailable {
+        message: "It isn't your turn.".to_string(),
+    };
+    assert_eq!(serde_json::to_string(&hint).unwrap(), HINT_REPLY_JSON);
+    assert_eq!(
+        serde_json::to_string(&unavailable).unwrap(),
+        HINT_UNAVAILABLE_JSON
+    );
+    assert_eq!(
+        serde_json::from_str::<BotHintReply>(HINT_REPLY_JSON).unwrap(),
+        hint
+    );
+    assert_eq!(
+        serde_json::from_str::<BotHintReply>(HINT_UNAVAILABLE_JSON).unwrap(),
+        unavailable
+    );
 }
 
 #[test]
@@ -97,6 +167,8 @@ fn shared_constants_match_wire_values() {
     assert_eq!(SUBJECT_COMMAND, "bot.command");
     assert_eq!(CONSUMER_TURN, "bot-turn");
     assert_eq!(CONSUMER_COMMAND, "bot-command");
+    assert_eq!(SUBJECT_HINT, "bot.hint");
+    assert_eq!(QUEUE_HINT, "bot-hint");
     assert_eq!(MAX_TURN_ATTEMPTS, 3);
     assert_eq!(MAX_DELIVER, 3);
 }
@@ -107,4 +179,5 @@ fn delivery_invariants_hold() {
     // turn-retry ceiling, and the ack window must outlast a long turn.
     assert!(i64::from(MAX_TURN_ATTEMPTS) <= MAX_DELIVER);
     assert_eq!(ACK_WAIT, std::time::Duration::from_secs(5 * 60));
+    assert!(HINT_TIMEOUT < ACK_WAIT);
 }
@@ -25,11 +25,11 @@ fn bounded_i32(v: i32, min: i32, max: i32) -> i32 {
     (min64 + (v - min64) % range_size) as i32
 }
 
-pub fn spec_to_command(
+pub fn spec_to_command<R: Rng + ?Sized>(
     spec: &command::Spec,
     _ctx: &command::Spec,
     players: &[String],
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) -> Vec<String> {
     match *spec {
         command::Spec::Int { min, max } => {
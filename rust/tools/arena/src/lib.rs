@@ -0,0 +1,414 @@
+//! Offline head-to-head evaluation of bots.
+//!
+//! Plays a series of seeded games through any `Requester`, rotating seats
+//! each game so no entrant keeps the first-player advantage, and reports win
+//! rates, placings, game length and invalid command rates.
+
+use anyhow::{Result, anyhow};
+
+use brdgme_cmd::api::{GameResponse, PlayerRender, PubRender, Request, Response};
+use brdgme_cmd::bot_cli;
+use brdgme_cmd::requester::Requester;
+use brdgme_game::{GameOptions, Status};
+
+pub mod mock_llm;
+pub mod report;
+pub mod seat;
+
+pub use report::{EntrantReport, Mean, Rate, Report};
+pub use seat::{BotterSeat, LlmSeat, MockLlmSeat, ProcessSeat, RandSeat, Rejection, Seat, Turn};
+
+/// Matches the live bot's retry budget per turn.
+pub const MAX_ATTEMPTS: usize = 20;
+
+pub struct Entrant {
+    /// Also used as the entrant's player name in game, so it must be unique.
+    pub name: String,
+    pub seat: Box<dyn Seat>,
+}
+
+impl Entrant {
+    pub fn new(name: impl Into<String>, seat: impl Seat + 'static) -> Self {
+        Self {
+            name: name.into(),
+            seat: Box::new(seat),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub games: usize,
+    /// Game `i` is played with seed `seed + i`.
+    pub seed: u64,
+    /// A game still running after this many accepted commands is abandoned.
+    pub max_commands: usize,
+    /// Options every game is created with.
+    pub options: GameOptions,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            games: 100,
+            seed: 0,
+            max_commands: 10_000,
+            options: GameOptions::new(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    placings: Vec<usize>,
+    placing_samples: Vec<f64>,
+    wins: usize,
+    submitted: usize,
+    rejected: usize,
+    stalls: usize,
+}
+
+enum Outcome {
+    Finished { placings: Vec<usize>, length: usize },
+    Abandoned,
+}
+
+/// Plays `config.games` games between `entrants`, one seat each.
+pub fn run(
+    requester: &mut dyn Requester,
+    entrants: &mut [Entrant],
+    config: &Config,
+) -> Result<Report> {
+    let players = entrants.len();
+    match requester.request(&Request::PlayerCounts)? {
+        Response::PlayerCounts { player_counts } if player_counts.contains(&players) => {}
+        Response::PlayerCounts { player_counts } => {
+            return Err(anyhow!(
+                "{} entrants given but the game supports {:?} players",
+                players,
+                player_counts
+            ));
+        }
+        resp => return Err(anyhow!("invalid response to player counts: {:?}", resp)),
+    }
+    let rules = match requester.request(&Request::Rules)? {
+        Response::Rules { rules } => rules,
+        resp => return Err(anyhow!("invalid response to rules: {:?}", resp)),
+    };
+
+    let mut tallies: Vec<Tally> = (0..players).map(|_| Tally::default()).collect();
+    let mut lengths = vec![];
+    let mut abandoned = 0;
+    for game in 0..config.games {
+        // seating[position] is the index of the entrant in that seat.
+        let seating: Vec<usize> = (0..players).map(|p| (p + game) % players).collect();
+        let seed = config.seed.wrapping_add(game as u64);
+        match play(
+            requester,
+            entrants,
+            &seating,
+            seed,
+            &rules,
+            config,
+            &mut tallies,
+        )
+        .map_err(|e| anyhow!("game {} (seed {}): {}", game, seed, e))?
+        {
+            Outcome::Finished { placings, length } => {
+                lengths.push(length as f64);
+                for (position, &placing) in placings.iter().enumerate() {
+                    let tally = &mut tallies[seating[position]];
+                    if tally.placings.len() < placing {
+                        tally.placings.resize(placing, 0);
+                    }
+                    if placing > 0 {
+                        tally.placings[placing - 1] += 1;
+                    }
+                    tally.placing_samples.push(placing as f64);
+                    if placing == 1 {
+                        tally.wins += 1;
+                    }
+                }
+            }
+            Outcome::Abandoned => abandoned += 1,
+        }
+    }
+
+    let finished = lengths.len();
+    Ok(Report {
+        games: config.games,
+        finished,
+        abandoned,
+        game_length: Mean::of(&lengths),
+        entrants: entrants
+            .iter()
+            .zip(tallies)
+            .map(|(entrant, tally)| EntrantReport {
+                name: entrant.name.clone(),
+                win_rate: Rate::new(tally.wins, finished),
+                mean_placing: Mean::of(&tally.placing_samples),
+                placings: tally.placings,
+                invalid_rate: Rate::new(tally.rejected, tally.submitted),
+                stalls: tally.stalls,
+            })
+            .collect(),
+    })
+}
+
+fn play(
+    requester: &mut dyn Requester,
+    entrants: &mut [Entrant],
+    seating: &[usize],
+    seed: u64,
+    rules: &str,
+    config: &Config,
+    tallies: &mut [Tally],
+) -> Result<Outcome> {
+    let names: Vec<String> = seating.iter().map(|&e| entrants[e].name.clone()).collect();
+    let (mut game, mut public, mut renders) = match requester.request(&Request::New {
+        players: seating.len(),
+        seed: Some(seed),
+        options: config.options.clone(),
+    })? {
+        Response::New {
+            game,
+            public_render,
+            player_renders,
+            ..
+        } => (game, public_render, player_renders),
+        resp => return Err(anyhow!("invalid response for new game: {:?}", resp)),
+    };
+    let mut length = 0;
+    loop {
+        let player = match game.status {
+            Status::Finished { placings, .. } => {
+                return Ok(Outcome::Finished { placings, length });
+            }
+            Status::Active { ref whose_turn, .. } => *whose_turn
+                .first()
+                .ok_or_else(|| anyhow!("the game is active but it is nobody's turn"))?,
+        };
+        if length >= config.max_commands {
+            return Ok(Outcome::Abandoned);
+        }
+        let entrant = seating[player];
+        match take_turn(
+            requester,
+            &mut *entrants[entrant].seat,
+            &game,
+            &public,
+            &renders,
+            player,
+            &names,
+            seed,
+            rules,
+            &mut tallies[entrant],
+        )? {
+            Some((next_game, next_public, next_renders)) => {
+                game = next_game;
+                public = next_public;
+                renders = next_renders;
+                length += 1;
+            }
+            None => {
+                tallies[entrant].stalls += 1;
+                return Ok(Outcome::Abandoned);
+            }
+        }
+    }
+}
+
+/// Asks the seat for commands until one is accepted, or returns `None` once
+/// `MAX_ATTEMPTS` have been rejected.
+#[allow(clippy::too_many_arguments)]
+fn take_turn(
+    requester: &mut dyn Requester,
+    seat: &mut dyn Seat,
+    game: &GameResponse,
+    public: &PubRender,
+    renders: &[PlayerRender],
+    player: usize,
+    names: &[String],
+    seed: u64,
+    rules: &str,
+    tally: &mut Tally,
+) -> Result<Option<(GameResponse, PubRender, Vec<PlayerRender>)>> {
+    let render = renders
+        .get(player)
+        .ok_or_else(|| anyhow!("there is no player render for player {}", player))?;
+    let command_spec = render
+        .command_spec
+        .clone()
+        .ok_or_else(|| anyhow!("player {}'s command_spec is None", player))?;
+    let turn = Turn {
+        request: bot_cli::Request {
+            player,
+            player_state: render.player_state.clone(),
+            players: names.to_vec(),
+            command_spec,
+            game_id: Some(format!("arena-{}", seed)),
+        },
+        pub_state: &public.pub_state,
+        rules,
+    };
+    let mut rejected: Vec<Rejection> = vec![];
+    while rejected.len() < MAX_ATTEMPTS {
+        tally.submitted += 1;
+        let command = match seat.command(&turn, &rejected) {
+            Ok(command) => command,
+            Err(e) => {
+                tally.rejected += 1;
+                rejected.push(Rejection {
+                    command: String::new(),
+                    error: format!("bot failed: {}", e),
+                });
+                continue;
+            }
+        };
+        let error = match requester.request(&Request::Play {
+            player,
+            command: command.clone(),
+            names: names.to_vec(),
+            game: game.state.clone(),
+        })? {
+            Response::Play {
+                ref remaining_input,
+                ..
+            } if !remaining_input.trim().is_empty() => {
+                format!("unexpected input: {}", remaining_input.trim())
+            }
+            Response::Play {
+                game,
+                public_render,
+                player_renders,
+                ..
+            } => return Ok(Some((game, public_render, player_renders))),
+            Response::UserError { message } => message,
+            resp => return Err(anyhow!("command '{}' failed: {:?}", command, resp)),
+        };
+        tally.rejected += 1;
+        rejected.push(Rejection { command, error });
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brdgme_cmd::requester::gamer;
+    use brdgme_rand_bot::RandBot;
+    use no_thanks_2::Game;
+
+    /// Plays randomly but garbles every third command, so the game rejects
+    /// some of its commands.
+    struct Sloppy {
+        seat: RandSeat,
+        commands: usize,
+    }
+
+    impl Sloppy {
+        fn new(seed: u64) -> Self {
+            Self {
+                seat: RandSeat::new(seed),
+                commands: 0,
+            }
+        }
+    }
+
+    impl Seat for Sloppy {
+        fn command(&mut self, turn: &Turn, rejected: &[Rejection]) -> Result<String> {
+            self.commands += 1;
+            let command = self.seat.command(turn, rejected)?;
+            Ok(if self.commands.is_multiple_of(3) {
+                format!("I think the best move here is: {}", command)
+            } else {
+                command
+            })
+        }
+    }
+
+    fn entrants() -> Vec<Entrant> {
+        vec![
+            Entrant::new("rand", RandSeat::new(1)),
+            Entrant::new("botter", BotterSeat::<Game, _>::new(RandBot)),
+            Entrant::new("sloppy", Sloppy::new(2)),
+        ]
+    }
+
+    fn config() -> Config {
+        Config {
+            games: 6,
+            seed: 42,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn run_tallies_every_seat() {
+        let report = run(&mut gamer::new::<Game>(), &mut entrants(), &config()).unwrap();
+        assert_eq!(6, report.games);
+        assert_eq!(6, report.finished + report.abandoned);
+        assert!(report.game_length.value > 0.0);
+        for e in &report.entrants {
+            assert_eq!(
+                report.finished,
+                e.placings.iter().sum::<usize>(),
+                "{}",
+                e.name
+            );
+        }
+        let sloppy = &report.entrants[2];
+        assert!(sloppy.invalid_rate.hits > 0, "{:?}", sloppy);
+        assert!(report.to_string().contains("Win rate"));
+    }
+
+    #[test]
+    fn seeded_runs_are_reproducible() {
+        // RandBot draws from the thread RNG, so only seeded seats here.
+        let seeded = || {
+            vec![
+                Entrant::new("rand", RandSeat::new(1)),
+                Entrant::new("rand-2", RandSeat::new(2)),
+                Entrant::new("sloppy", Sloppy::new(3)),
+            ]
+        };
+        let first = run(&mut gamer::new::<Game>(), &mut seeded(), &config()).unwrap();
+        let second = run(&mut gamer::new::<Game>(), &mut seeded(), &config()).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn unsupported_player_count_is_an_error() {
+        let mut one = vec![Entrant::new("rand", RandSeat::new(1))];
+        assert!(run(&mut gamer::new::<Game>(), &mut one, &config()).is_err());
+    }
+
+    struct Stubborn;
+
+    impl Seat for Stubborn {
+        fn command(&mut self, _: &Turn, _: &[Rejection]) -> Result<String> {
+            Ok("dance".to_string())
+        }
+    }
+
+    #[test]
+    fn a_stalled_turn_abandons_the_game() {
+        let mut entrants = vec![
+            Entrant::new("stubborn", Stubborn),
+            Entrant::new("stubborn2", Stubborn),
+            Entrant::new("stubborn3", Stubborn),
+        ];
+        let report = run(
+            &mut gamer::new::<Game>(),
+            &mut entrants,
+            &Config {
+                games: 3,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(3, report.abandoned);
+        assert_eq!(3, report.entrants.iter().map(|e| e.stalls).sum::<usize>());
+        assert_eq!(1.0, report.entrants[0].invalid_rate.value);
+    }
+}
@@ -0,0 +1,322 @@
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+
+use brdgme_arena::{Config, Entrant, LlmSeat, MockLlmSeat, ProcessSeat, RandSeat};
+use brdgme_cmd::requester;
+
+const USAGE: &str = "usage: brdgme_arena [--games N] [--seed N] [--max-commands N] \
+[--option KEY=VALUE]... [--llm-bot PATH] [--json] \
+--bot (rand | llm[:ARGS] | mock-llm[:CHATTER_RATE] | cmd:PROGRAM[ ARGS]) ... \
+(local PATH | http URL)";
+
+/// Chatter rate for `mock-llm` without an explicit rate.
+const DEFAULT_CHATTER_RATE: f64 = 0.1;
+
+/// The bot binary `llm` and `mock-llm` seats run, without `--llm-bot`.
+const DEFAULT_LLM_BOT: &str = "bot";
+
+#[derive(Debug, Clone, PartialEq)]
+enum BotSpec {
+    Rand,
+    /// The live LLM bot against the provider in the `LLM_*` env vars, with
+    /// extra `bot arena` arguments such as a draft prompt template.
+    Llm {
+        args: Vec<String>,
+    },
+    MockLlm {
+        chatter_rate: f64,
+    },
+    Process {
+        program: String,
+        args: Vec<String>,
+    },
+}
+
+impl BotSpec {
+    fn parse(s: &str) -> Result<Self, String> {
+        if s == "rand" {
+            return Ok(BotSpec::Rand);
+        }
+        if s == "llm" {
+            return Ok(BotSpec::Llm { args: vec![] });
+        }
+        if let Some(args) = s.strip_prefix("llm:") {
+            return Ok(BotSpec::Llm {
+                args: args.split_whitespace().map(String::from).collect(),
+            });
+        }
+        if s == "mock-llm" {
+            return Ok(BotSpec::MockLlm {
+                chatter_rate: DEFAULT_CHATTER_RATE,
+            });
+        }
+        if let Some(rate) = s.strip_prefix("mock-llm:") {
+            let chatter_rate: f64 = rate
+                .parse()
+                .map_err(|e| format!("invalid chatter rate '{}': {}", rate, e))?;
+            if !(0.0..=1.0).contains(&chatter_rate) {
+                return Err(format!("chatter rate {} is not between 0 and 1", rate));
+            }
+            return Ok(BotSpec::MockLlm { chatter_rate });
+        }
+        if let Some(command) = s.strip_prefix("cmd:") {
+            let mut words = command.split_whitespace().map(String::from);
+            let program = words.next().ok_or("cmd: requires a program")?;
+            return Ok(BotSpec::Process {
+                program,
+                args: words.collect(),
+            });
+        }
+        Err(format!("unknown bot '{}'", s))
+    }
+
+    fn name(&self) -> String {
+        match self {
+            BotSpec::Rand => "rand".to_string(),
+            BotSpec::Llm { .. } => "llm".to_string(),
+            BotSpec::MockLlm { .. } => "mock-llm".to_string(),
+            BotSpec::Process { program, .. } => Path::new(program)
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| program.clone()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Opts {
+    config: Config,
+    bots: Vec<BotSpec>,
+    /// The bot binary for `llm` and `mock-llm` seats.
+    llm_bot: String,
+    json: bool,
+}
+
+impl Default for Opts {
+    fn default() -> Self {
+        Self {
+            config: Config::default(),
+            bots: vec![],
+            llm_bot: DEFAULT_LLM_BOT.to_string(),
+            json: false,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    let (opts, requester_args) = match parse_opts(&args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let mut client = match requester::parse_args(&requester_args) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let mut entrants = match entrants(&opts) {
+        Ok(entrants) => entrants,
+        Err(e) => {
+            eprintln!("{:#}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let report = match brdgme_arena::run(&mut client, &mut entrants, &opts.config) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("{:#}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if opts.json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        print!("{}", report);
+    }
+    ExitCode::SUCCESS
+}
+
+/// Builds one entrant per `--bot`, numbering repeated names so every player
+/// name in game is unique.
+fn entrants(opts: &Opts) -> anyhow::Result<Vec<Entrant>> {
+    let mut names: Vec<String> = vec![];
+    opts.bots
+        .iter()
+        .enumerate()
+        .map(|(i, spec)| {
+            let base = spec.name();
+            let mut name = base.clone();
+            let mut n = 1;
+            while names.contains(&name) {
+                n += 1;
+                name = format!("{}-{}", base, n);
+            }
+            names.push(name.clone());
+            let seed = opts.config.seed.wrapping_mul(31).wrapping_add(i as u64);
+            Ok(match spec {
+                BotSpec::Rand => Entrant::new(name, RandSeat::new(seed)),
+                BotSpec::Llm { args } => {
+                    let args = std::iter::once("arena".to_string())
+                        .chain(args.iter().cloned())
+                        .collect();
+                    Entrant::new(name, LlmSeat::new(&opts.llm_bot, args))
+                }
+                BotSpec::MockLlm { chatter_rate } => {
+                    Entrant::new(name, MockLlmSeat::new(seed, *chatter_rate, &opts.llm_bot)?)
+                }
+                BotSpec::Process { program, args } => {
+                    Entrant::new(name, ProcessSeat::new(program, args.clone()))
+                }
+            })
+        })
+        .collect()
+}
+
+/// Splits the arena's own flags from the requester arguments, keeping the
+/// program name first so the remainder can go to `requester::parse_args`.
+fn parse_opts(args: &[String]) -> Result<(Opts, Vec<String>), String> {
+    let mut opts = Opts::default();
+    let mut rest: Vec<String> = args.iter().take(1).cloned().collect();
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--games" => opts.config.games = number(iter.next(), "--games")?,
+            "--seed" => opts.config.seed = number(iter.next(), "--seed")?,
+            "--max-commands" => opts.config.max_commands = number(iter.next(), "--max-commands")?,
+            "--option" => {
+                let value = iter.next().ok_or("--option requires KEY=VALUE")?;
+                let (key, value) = value
+                    .split_once('=')
+                    .ok_or_else(|| format!("invalid --option '{}', expected KEY=VALUE", value))?;
+                opts.config.options.set(key.trim(), value.trim());
+            }
+            "--bot" => {
+                let value = iter.next().ok_or("--bot requires a bot")?;
+                opts.bots.push(BotSpec::parse(value)?);
+            }
+            "--llm-bot" => {
+                opts.llm_bot = iter.next().ok_or("--llm-bot requires a path")?.clone();
+            }
+            "--json" => opts.json = true,
+            _ => rest.push(arg.clone()),
+        }
+    }
+    if opts.bots.is_empty() {
+        return Err("at least one --bot is required".to_string());
+    }
+    Ok((opts, rest))
+}
+
+fn number<T>(value: Option<&String>, flag: &str) -> Result<T, String>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let value = value.ok_or_else(|| format!("{} requires a value", flag))?;
+    value
+        .parse()
+        .map_err(|e| format!("invalid {} '{}': {}", flag, value, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brdgme_game::GameOptions;
+
+    fn args(a: &[&str]) -> Vec<String> {
+        a.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_opts_extracts_flags_and_keeps_requester_args() {
+        let (opts, rest) = parse_opts(&args(&[
+            "brdgme_arena",
+            "--games",
+            "20",
+            "local",
+            "./game_cli",
+            "--bot",
+            "rand",
+            "--bot",
+            "mock-llm:0.5",
+            "--bot",
+            "llm:--user-template draft.md",
+            "--bot",
+            "cmd:./bots/greedy --depth 2",
+            "--llm-bot",
+            "target/debug/bot",
+            "--seed",
+            "9",
+            "--option",
+            "mode=advanced",
+            "--json",
+        ]))
+        .unwrap();
+        assert_eq!(
+            Opts {
+                config: Config {
+                    games: 20,
+                    seed: 9,
+                    options: GameOptions::new().with("mode", "advanced"),
+                    ..Config::default()
+                },
+                bots: vec![
+                    BotSpec::Rand,
+                    BotSpec::MockLlm { chatter_rate: 0.5 },
+                    BotSpec::Llm {
+                        args: args(&["--user-template", "draft.md"]),
+                    },
+                    BotSpec::Process {
+                        program: "./bots/greedy".to_string(),
+                        args: args(&["--depth", "2"]),
+                    },
+                ],
+                llm_bot: "target/debug/bot".to_string(),
+                json: true,
+            },
+            opts
+        );
+        assert_eq!(args(&["brdgme_arena", "local", "./game_cli"]), rest);
+    }
+
+    #[test]
+    fn parse_opts_rejects_bad_values() {
+        assert!(parse_opts(&args(&["brdgme_arena", "local", "x"])).is_err());
+        assert!(parse_opts(&args(&["brdgme_arena", "--games", "many", "--bot", "rand"])).is_err());
+        assert!(parse_opts(&args(&["brdgme_arena", "--bot", "mock-llm:2"])).is_err());
+        assert!(parse_opts(&args(&["brdgme_arena", "--bot", "chess-engine"])).is_err());
+        assert!(parse_opts(&args(&["brdgme_arena", "--bot", "cmd:"])).is_err());
+    }
+
+    #[test]
+    fn repeated_bots_get_unique_names() {
+        let (opts, _) = parse_opts(&args(&[
+            "brdgme_arena",
+            "--bot",
+            "rand",
+            "--bot",
+            "rand",
+            "--bot",
+            "cmd:/usr/bin/rand_bot",
+        ]))
+        .unwrap();
+        let names: Vec<String> = entrants(&opts)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+        assert_eq!(args(&["rand", "rand-2", "rand_bot"]), names);
+    }
+}
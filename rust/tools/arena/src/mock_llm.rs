@@ -0,0 +1,175 @@
+//! A local stand-in for an OpenAI-compatible chat completions endpoint, so
+//! the live bot's prompt, provider request and answer parsing can be run
+//! offline. Replies are scripted ahead of each call.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use anyhow::{Context, Result, anyhow};
+
+#[derive(Default)]
+struct Script {
+    replies: VecDeque<String>,
+    requests: Vec<serde_json::Value>,
+}
+
+/// Serves one scripted reply per request, in order, on a local port until
+/// dropped. A request with nothing scripted gets a 500.
+pub struct MockChatServer {
+    addr: SocketAddr,
+    script: Arc<Mutex<Script>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl MockChatServer {
+    pub fn start() -> Result<Self> {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").context("failed to bind mock LLM server")?;
+        let addr = listener.local_addr()?;
+        let script = Arc::new(Mutex::new(Script::default()));
+        let stopped = Arc::new(AtomicBool::new(false));
+        {
+            let script = script.clone();
+            let stopped = stopped.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        // A broken connection only fails that one call.
+                        let _ = serve(stream, &script);
+                    }
+                }
+            });
+        }
+        Ok(Self {
+            addr,
+            script,
+            stopped,
+        })
+    }
+
+    /// The base URL to give the bot as `LLM_URL`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Queues the content of the next reply.
+    pub fn reply(&self, content: impl Into<String>) {
+        self.lock().replies.push_back(content.into());
+    }
+
+    /// The request bodies received so far.
+    pub fn requests(&self) -> Vec<serde_json::Value> {
+        self.lock().requests.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Script> {
+        self.script.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockChatServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wakes the accept loop so it sees the flag.
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+fn serve(stream: TcpStream, script: &Mutex<Script>) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(anyhow!("connection closed before the request body"));
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse()?;
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let reply = {
+        let mut script = script.lock().unwrap_or_else(|e| e.into_inner());
+        script
+            .requests
+            .push(serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null));
+        script.replies.pop_front()
+    };
+    let (status, body) = match reply {
+        Some(content) => (
+            "200 OK",
+            serde_json::json!({
+                "choices": [{"message": {"role": "assistant", "content": content}}],
+                "usage": {"prompt_tokens": 0, "completion_tokens": 0},
+            })
+            .to_string(),
+        ),
+        None => (
+            "500 Internal Server Error",
+            r#"{"error":"no reply scripted"}"#.to_string(),
+        ),
+    };
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(server: &MockChatServer, body: &str) -> String {
+        let mut stream = TcpStream::connect(server.addr).unwrap();
+        write!(
+            stream,
+            "POST /v1/chat/completions HTTP/1.1\r\nHost: localhost\r\ncontent-length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn serves_scripted_replies_in_order_and_records_requests() {
+        let server = MockChatServer::start().unwrap();
+        server.reply("take");
+        server.reply("2");
+        assert!(post(&server, r#"{"n":1}"#).contains(r#""content":"take""#));
+        assert!(post(&server, r#"{"n":2}"#).contains(r#""content":"2""#));
+        let unscripted = post(&server, r#"{"n":3}"#);
+        assert!(unscripted.starts_with("HTTP/1.1 500"), "{}", unscripted);
+        assert_eq!(
+            vec![
+                serde_json::json!({"n": 1}),
+                serde_json::json!({"n": 2}),
+                serde_json::json!({"n": 3}),
+            ],
+            server.requests()
+        );
+    }
+}
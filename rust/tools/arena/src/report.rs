@@ -0,0 +1,215 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// z for a two-sided 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// A proportion with its Wilson score interval, which stays inside [0, 1]
+/// and behaves for small samples and rates near 0 or 1.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Rate {
+    pub hits: usize,
+    pub total: usize,
+    pub value: f64,
+    pub low: f64,
+    pub high: f64,
+}
+
+impl Rate {
+    pub fn new(hits: usize, total: usize) -> Self {
+        if total == 0 {
+            return Self {
+                hits,
+                total,
+                value: 0.0,
+                low: 0.0,
+                high: 1.0,
+            };
+        }
+        let n = total as f64;
+        let p = hits as f64 / n;
+        let z2 = Z_95 * Z_95;
+        let denom = 1.0 + z2 / n;
+        let centre = (p + z2 / (2.0 * n)) / denom;
+        let half = Z_95 * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denom;
+        Self {
+            hits,
+            total,
+            value: p,
+            low: (centre - half).max(0.0),
+            high: (centre + half).min(1.0),
+        }
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.1}% ({:.1}-{:.1}%)",
+            self.value * 100.0,
+            self.low * 100.0,
+            self.high * 100.0
+        )
+    }
+}
+
+/// A sample mean with a normal-approximation 95% interval half width.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Mean {
+    pub count: usize,
+    pub value: f64,
+    pub margin: f64,
+}
+
+impl Mean {
+    pub fn of(samples: &[f64]) -> Self {
+        let count = samples.len();
+        if count == 0 {
+            return Self {
+                count,
+                value: 0.0,
+                margin: 0.0,
+            };
+        }
+        let n = count as f64;
+        let value = samples.iter().sum::<f64>() / n;
+        let margin = if count < 2 {
+            0.0
+        } else {
+            let variance = samples.iter().map(|s| (s - value).powi(2)).sum::<f64>() / (n - 1.0);
+            Z_95 * (variance / n).sqrt()
+        };
+        Self {
+            count,
+            value,
+            margin,
+        }
+    }
+}
+
+impl fmt::Display for Mean {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} ± {:.2}", self.value, self.margin)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EntrantReport {
+    pub name: String,
+    pub win_rate: Rate,
+    pub mean_placing: Mean,
+    /// `placings[i]` counts finished games placed `i + 1`.
+    pub placings: Vec<usize>,
+    pub invalid_rate: Rate,
+    /// Turns where the entrant never produced an accepted command.
+    pub stalls: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub games: usize,
+    pub finished: usize,
+    pub abandoned: usize,
+    /// Accepted commands per finished game.
+    pub game_length: Mean,
+    pub entrants: Vec<EntrantReport>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Games: {}   Finished: {}   Abandoned: {}",
+            self.games, self.finished, self.abandoned
+        )?;
+        writeln!(f, "Game length: {} commands (95% CI)", self.game_length)?;
+        let name_width = self
+            .entrants
+            .iter()
+            .map(|e| e.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("Bot".len());
+        let places = self
+            .entrants
+            .iter()
+            .map(|e| e.placings.len())
+            .max()
+            .unwrap_or(0);
+        writeln!(f)?;
+        write!(
+            f,
+            "{:name_width$}  {:22}  {:13}",
+            "Bot", "Win rate", "Mean placing"
+        )?;
+        for place in 1..=places {
+            write!(f, "  {:>4}", ordinal(place))?;
+        }
+        writeln!(f, "  {:22}  Stalls", "Invalid commands")?;
+        for e in &self.entrants {
+            write!(
+                f,
+                "{:name_width$}  {:22}  {:13}",
+                e.name,
+                e.win_rate.to_string(),
+                e.mean_placing.to_string()
+            )?;
+            for place in 0..places {
+                write!(f, "  {:>4}", e.placings.get(place).copied().unwrap_or(0))?;
+            }
+            writeln!(f, "  {:22}  {}", e.invalid_rate.to_string(), e.stalls)?;
+        }
+        Ok(())
+    }
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wilson_interval_brackets_the_rate() {
+        let r = Rate::new(50, 100);
+        assert!((r.value - 0.5).abs() < 1e-9);
+        assert!((r.low - 0.404).abs() < 0.001, "{:?}", r);
+        assert!((r.high - 0.596).abs() < 0.001, "{:?}", r);
+        let none = Rate::new(0, 10);
+        assert_eq!(0.0, none.low);
+        assert!(none.high > 0.0);
+        assert_eq!((0.0, 1.0), (Rate::new(0, 0).low, Rate::new(0, 0).high));
+    }
+
+    #[test]
+    fn mean_margin_shrinks_with_samples() {
+        let few = Mean::of(&[1.0, 3.0]);
+        let many = Mean::of(&[1.0, 3.0].repeat(50));
+        assert_eq!(2.0, few.value);
+        assert_eq!(2.0, many.value);
+        assert!(many.margin < few.margin);
+        assert_eq!(0.0, Mean::of(&[4.0]).margin);
+    }
+
+    #[test]
+    fn ordinals() {
+        let got: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22].map(ordinal).to_vec();
+        assert_eq!(
+            vec![
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd"
+            ],
+            got
+        );
+    }
+}
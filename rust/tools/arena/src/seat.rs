@@ -0,0 +1,367 @@
+use std::io::Write;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, anyhow};
+use rand::prelude::*;
+
+use brdgme_cmd::bot_cli;
+pub use brdgme_cmd::bot_cli::Rejection;
+use brdgme_game::Gamer;
+use brdgme_game::bot::{BotCommand, Botter};
+use brdgme_game::rng::GameRng;
+
+use crate::mock_llm::MockChatServer;
+
+/// What a seat is shown on its turn: the bot CLI request, plus the public
+/// state and rules an LLM bot prompts with.
+pub struct Turn<'a> {
+    pub request: bot_cli::Request,
+    /// The public state, as JSON.
+    pub pub_state: &'a str,
+    pub rules: &'a str,
+}
+
+/// Something that can take a seat in the arena. Called again with the
+/// growing list of rejections until it proposes a command the game accepts.
+pub trait Seat {
+    fn command(&mut self, turn: &Turn, rejected: &[Rejection]) -> Result<String>;
+}
+
+/// Picks the highest quality command that hasn't already been rejected this
+/// turn, falling back to the best one when every option has been tried.
+fn pick(mut commands: Vec<BotCommand>, rejected: &[Rejection]) -> Result<String> {
+    commands.sort_by_key(|c| std::cmp::Reverse(c.quality));
+    let mut candidates = commands
+        .into_iter()
+        .filter_map(|c| c.commands.into_iter().next());
+    let first = candidates
+        .next()
+        .ok_or_else(|| anyhow!("bot returned no commands"))?;
+    if !rejected.iter().any(|r| r.command == first) {
+        return Ok(first);
+    }
+    Ok(candidates
+        .find(|c| !rejected.iter().any(|r| &r.command == c))
+        .unwrap_or(first))
+}
+
+/// Plays random commands generated from the command spec, like `RandBot`,
+/// but from a seeded RNG so arena runs are reproducible.
+pub struct RandSeat {
+    rng: GameRng,
+}
+
+impl RandSeat {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: GameRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Seat for RandSeat {
+    fn command(&mut self, turn: &Turn, _rejected: &[Rejection]) -> Result<String> {
+        Ok(random_command(&turn.request, &mut self.rng))
+    }
+}
+
+fn random_command(request: &bot_cli::Request, rng: &mut GameRng) -> String {
+    brdgme_rand_bot::spec_to_command(
+        &request.command_spec,
+        &request.command_spec,
+        &request.players,
+        rng,
+    )
+    .join("")
+}
+
+/// Seats an in-process `Botter`, decoding the player state for its game.
+pub struct BotterSeat<G, B> {
+    bot: B,
+    game: PhantomData<fn() -> G>,
+}
+
+impl<G, B> BotterSeat<G, B> {
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot,
+            game: PhantomData,
+        }
+    }
+}
+
+impl<G: Gamer, B: Botter<G>> Seat for BotterSeat<G, B> {
+    fn command(&mut self, turn: &Turn, rejected: &[Rejection]) -> Result<String> {
+        let request = &turn.request;
+        let player_state: G::PlayerState = serde_json::from_str(&request.player_state)
+            .context("failed to decode player state for bot")?;
+        pick(
+            self.bot.commands(
+                request.player,
+                &player_state,
+                &request.players,
+                &request.command_spec,
+                request.game_id.clone(),
+            ),
+            rejected,
+        )
+    }
+}
+
+/// Runs an external bot once per attempt, writing a `bot_cli::Request` to
+/// its stdin and reading a JSON list of `BotCommand`s from its stdout.
+pub struct ProcessSeat {
+    program: PathBuf,
+    args: Vec<String>,
+}
+
+impl ProcessSeat {
+    pub fn new(program: impl Into<PathBuf>, args: Vec<String>) -> Self {
+        Self {
+            program: program.into(),
+            args,
+        }
+    }
+}
+
+impl Seat for ProcessSeat {
+    fn command(&mut self, turn: &Turn, rejected: &[Rejection]) -> Result<String> {
+        pick(
+            run_bot(&self.program, &self.args, &[], &turn.request)?,
+            rejected,
+        )
+    }
+}
+
+/// Runs a bot once, writing `input` to its stdin and reading a JSON list of
+/// `BotCommand`s from its stdout.
+fn run_bot(
+    program: &Path,
+    args: &[String],
+    envs: &[(String, String)],
+    input: &impl serde::Serialize,
+) -> Result<Vec<BotCommand>> {
+    let mut child = Command::new(program)
+        .args(args)
+        .envs(envs.iter().map(|(k, v)| (k, v)))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .with_context(|| format!("failed to run bot {}", program.display()))?;
+    {
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow!("bot stdin is unavailable"))?;
+        serde_json::to_writer(&mut stdin, input)?;
+        stdin.flush()?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "bot {} exited with {}",
+            program.display(),
+            output.status
+        ));
+    }
+    serde_json::from_slice(&output.stdout)
+        .with_context(|| format!("invalid output from bot {}", program.display()))
+}
+
+/// Plays through the live LLM bot: runs `bot arena` once per attempt, which
+/// renders the bot's prompt, calls the provider set by the `LLM_*` env vars
+/// (or `envs`) and parses the reply exactly as a live turn does.
+pub struct LlmSeat {
+    program: PathBuf,
+    args: Vec<String>,
+    envs: Vec<(String, String)>,
+}
+
+impl LlmSeat {
+    /// `program` is the bot binary and `args` start with `arena`.
+    pub fn new(program: impl Into<PathBuf>, args: Vec<String>) -> Self {
+        Self {
+            program: program.into(),
+            args,
+            envs: vec![],
+        }
+    }
+
+    /// Sets an env var for the bot, overriding the arena's own.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+}
+
+impl Seat for LlmSeat {
+    fn command(&mut self, turn: &Turn, rejected: &[Rejection]) -> Result<String> {
+        let request = bot_cli::LlmRequest {
+            request: turn.request.clone(),
+            pub_state: turn.pub_state.to_string(),
+            rules: turn.rules.to_string(),
+            rejected: rejected.to_vec(),
+        };
+        let commands = run_bot(&self.program, &self.args, &self.envs, &request)?;
+        commands
+            .into_iter()
+            .find_map(|c| c.commands.into_iter().next())
+            .ok_or_else(|| anyhow!("bot returned no commands"))
+    }
+}
+
+/// The most commands the live bot lists as a numbered menu; keep in step
+/// with `MENU_LIMIT` in the bot.
+const MENU_LIMIT: usize = 30;
+
+/// Measures the live LLM bot offline: an `LlmSeat` pointed at a local mock
+/// provider whose replies are scripted here. Each reply is a random legal
+/// command, given as its menu number when the bot lists a menu, and a share
+/// are wrapped in chatter the way a model that ignores the "command only"
+/// instruction answers, which the game then rejects.
+pub struct MockLlmSeat {
+    rng: GameRng,
+    chatter_rate: f64,
+    server: MockChatServer,
+    seat: LlmSeat,
+}
+
+impl MockLlmSeat {
+    /// `program` is the bot binary.
+    pub fn new(seed: u64, chatter_rate: f64, program: impl Into<PathBuf>) -> Result<Self> {
+        let server = MockChatServer::start()?;
+        let seat = LlmSeat::new(program, vec!["arena".to_string()])
+            .env("LLM_URL", server.url())
+            .env("BOT_MODEL", "mock")
+            .env("LLM_PROVIDER_KIND", "openai");
+        Ok(Self {
+            rng: GameRng::seed_from_u64(seed),
+            chatter_rate: chatter_rate.clamp(0.0, 1.0),
+            server,
+            seat,
+        })
+    }
+
+    fn reply(&mut self, request: &bot_cli::Request) -> String {
+        let answer = match request
+            .command_spec
+            .enumerate(&request.players, MENU_LIMIT)
+            .filter(|menu| !menu.is_empty())
+        {
+            Some(menu) => (self.rng.random_range(0..menu.len()) + 1).to_string(),
+            None => random_command(request, &mut self.rng),
+        };
+        if self.rng.random_bool(self.chatter_rate) {
+            format!("I think the best move here is: {}", answer)
+        } else {
+            format!("{}\n", answer)
+        }
+    }
+
+    /// The request bodies the bot has sent the mock provider.
+    pub fn requests(&self) -> Vec<serde_json::Value> {
+        self.server.requests()
+    }
+}
+
+impl Seat for MockLlmSeat {
+    fn command(&mut self, turn: &Turn, rejected: &[Rejection]) -> Result<String> {
+        let reply = self.reply(&turn.request);
+        self.server.reply(reply);
+        self.seat.command(turn, rejected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brdgme_game::command::Spec;
+
+    fn request() -> bot_cli::Request {
+        bot_cli::Request {
+            player: 0,
+            player_state: "null".to_string(),
+            players: vec!["a".to_string(), "b".to_string()],
+            command_spec: Spec::Token("take".to_string()),
+            game_id: None,
+        }
+    }
+
+    fn turn() -> Turn<'static> {
+        Turn {
+            request: request(),
+            pub_state: "{}",
+            rules: "Take cards",
+        }
+    }
+
+    fn bot_command(quality: u8, command: &str) -> BotCommand {
+        BotCommand {
+            quality,
+            commands: vec![command.to_string()],
+        }
+    }
+
+    #[test]
+    fn pick_prefers_quality_then_untried_commands() {
+        let commands = || vec![bot_command(10, "pass"), bot_command(200, "take")];
+        assert_eq!("take", pick(commands(), &[]).unwrap());
+        let rejected = [Rejection {
+            command: "take".to_string(),
+            error: "no".to_string(),
+        }];
+        assert_eq!("pass", pick(commands(), &rejected).unwrap());
+        assert!(pick(vec![], &[]).is_err());
+    }
+
+    #[test]
+    fn mock_llm_scripts_menu_numbers_and_chatter() {
+        let mut quiet = MockLlmSeat::new(1, 0.0, "bot").unwrap();
+        let mut chatty = MockLlmSeat::new(1, 1.0, "bot").unwrap();
+        let open = bot_cli::Request {
+            command_spec: Spec::Int {
+                min: Some(1),
+                max: None,
+            },
+            ..request()
+        };
+        for _ in 0..10 {
+            assert_eq!("1\n", quiet.reply(&request()));
+            assert!(quiet.reply(&open).trim().parse::<i32>().is_ok());
+            assert_eq!("I think the best move here is: 1", chatty.reply(&request()));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn process_seat_speaks_bot_cli() {
+        let mut seat = ProcessSeat::new(
+            "sh",
+            vec![
+                "-c".to_string(),
+                r#"cat > /dev/null; echo '[{"quality":128,"commands":["take"]}]'"#.to_string(),
+            ],
+        );
+        assert_eq!("take", seat.command(&turn(), &[]).unwrap());
+        let mut failing = ProcessSeat::new("sh", vec!["-c".to_string(), "exit 3".to_string()]);
+        assert!(failing.command(&turn(), &[]).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn llm_seat_sends_the_prompt_inputs_and_env() {
+        let mut seat = LlmSeat::new(
+            "sh",
+            vec![
+                "-c".to_string(),
+                r#"grep -q '"rules":"Take cards"' && echo "[{\"quality\":255,\"commands\":[\"$ANSWER\"]}]""#
+                    .to_string(),
+            ],
+        )
+        .env("ANSWER", "take");
+        assert_eq!("take", seat.command(&turn(), &[]).unwrap());
+    }
+}
@@ -11,7 +11,7 @@ use serde::de::DeserializeOwned;
 
 use brdgme_cmd::api;
 use brdgme_cmd::requester;
-use brdgme_game::{Gamer, command};
+use brdgme_game::{GameOption, GameOptions, Gamer, command};
 
 pub fn fuzz<F, R>(new_requester: F)
 where
@@ -69,12 +69,14 @@ where
                     command,
                     error,
                     seed,
+                    options,
                     commands,
                 } => {
                     println!(
-                        "\nError detected: {}\n\nSeed: {:?}\nCommands: {:#?}\n\nCommand: {}\n\nGame: {:?}",
+                        "\nError detected: {}\n\nSeed: {:?}\nOptions: {:?}\nCommands: {:#?}\n\nCommand: {}\n\nGame: {:?}",
                         error,
                         seed,
+                        options,
                         commands,
                         command.unwrap_or_else(|| "none".to_string()),
                         game
@@ -122,10 +124,12 @@ impl FuzzTally {
 struct Fuzzer {
     client: Box<dyn requester::Requester>,
     player_counts: Vec<usize>,
+    offered: Vec<GameOption>,
     names: Vec<String>,
     game: Option<FuzzGame>,
     rng: ThreadRng,
     seed: Option<u64>,
+    options: GameOptions,
     command_log: Vec<String>,
 }
 
@@ -140,13 +144,21 @@ impl Fuzzer {
                 ));
             }
         };
+        // Services that predate options reject the request, so fuzz them with
+        // defaults only.
+        let offered = match client.request(&api::Request::Options) {
+            Ok(api::Response::Options { options }) => options,
+            _ => vec![],
+        };
         Ok(Fuzzer {
             client,
             player_counts,
+            offered,
             names: vec![],
             game: None,
             rng: rand::rng(),
             seed: None,
+            options: GameOptions::new(),
             command_log: vec![],
         })
     }
@@ -158,9 +170,17 @@ impl Fuzzer {
             .ok_or_else(|| anyhow!("could not get player counts from {:?}", self.player_counts))?;
         self.names = names(players);
         let seed: u64 = self.rng.random();
+        let mut options = GameOptions::new();
+        for option in &self.offered {
+            if let Some(value) = option.values.choose(&mut self.rng) {
+                options.set(option.key.clone(), value.value.clone());
+            }
+        }
+        self.options = options.clone();
         match self.client.request(&api::Request::New {
             players,
             seed: Some(seed),
+            options,
         })? {
             api::Response::New {
                 game,
@@ -239,10 +259,11 @@ enum FuzzStep {
     },
     Finished,
     Error {
-        game: Option<FuzzGame>,
+        game: Option<Box<FuzzGame>>,
         command: Option<String>,
         error: String,
         seed: Option<u64>,
+        options: GameOptions,
         commands: Vec<String>,
     },
 }
@@ -270,10 +291,11 @@ impl Iterator for Fuzzer {
                 }
                 Ok(CommandResponse::UserError { message }) => Some(FuzzStep::UserError { message }),
                 Err(e) => Some(FuzzStep::Error {
-                    game: self.game.clone(),
+                    game: self.game.clone().map(Box::new),
                     command: None,
                     error: e.to_string(),
                     seed: self.seed,
+                    options: self.options.clone(),
                     commands: self.command_log.clone(),
                 }),
             },
@@ -284,6 +306,7 @@ impl Iterator for Fuzzer {
                     command: None,
                     error: e.to_string(),
                     seed: self.seed,
+                    options: self.options.clone(),
                     commands: self.command_log.clone(),
                 }),
             },
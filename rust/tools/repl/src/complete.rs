@@ -0,0 +1,143 @@
+use rustyline::completion::{Completer, Pair};
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper};
+
+use brdgme_game::command::Spec;
+
+/// REPL commands handled locally rather than sent to the game, with the help
+/// text shown by `:help`.
+pub const META_COMMANDS: &[(&str, &str)] = &[
+    (":help", "show this help and the current command reference"),
+    (":rules", "print the game rules"),
+    (":undo", "undo the last command"),
+    (":save", "save the session, optionally to a given file"),
+    (":load", "load a session, optionally from a given file"),
+    (":seed", "print the seed the game was started with"),
+    (":json", "print the game state as JSON"),
+    (":dump", "print the game response debug output"),
+    (":quit", "exit the REPL"),
+];
+
+/// Tab completion for the current player's command spec, offering the same
+/// suggestions as the web command input.
+#[derive(Default)]
+pub struct ReplHelper {
+    pub spec: Option<Spec>,
+    pub names: Vec<String>,
+}
+
+impl ReplHelper {
+    /// Returns the byte offset the candidates replace from, which is the
+    /// start of the trailing whitespace-delimited word.
+    fn candidates(&self, input: &str) -> (usize, Vec<Pair>) {
+        let fragment = input.rsplit(char::is_whitespace).next().unwrap_or("");
+        let start = input.len() - fragment.len();
+        if start == 0 && fragment.starts_with(':') {
+            return (
+                start,
+                META_COMMANDS
+                    .iter()
+                    .filter(|(name, _)| name.starts_with(fragment))
+                    .map(|(name, _)| Pair {
+                        display: name.to_string(),
+                        replacement: name.to_string(),
+                    })
+                    .collect(),
+            );
+        }
+        let Some(ref spec) = self.spec else {
+            return (start, vec![]);
+        };
+        (
+            start,
+            spec.suggest(input, &self.names)
+                .into_iter()
+                .map(|s| Pair {
+                    display: match s.desc {
+                        Some(desc) => format!("{} - {}", s.value, desc),
+                        None => s.value.clone(),
+                    },
+                    replacement: s.value,
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        Ok(self.candidates(&line[..pos]))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn helper() -> ReplHelper {
+        ReplHelper {
+            spec: Some(Spec::OneOf(vec![
+                Spec::Chain(vec![
+                    Spec::Token("play".to_string()),
+                    Spec::Space,
+                    Spec::Enum {
+                        values: vec!["red".to_string(), "green".to_string()],
+                        exact: false,
+                    },
+                ]),
+                Spec::Token("pass".to_string()),
+            ])),
+            names: vec!["mick".to_string(), "steve".to_string()],
+        }
+    }
+
+    fn replacements(pairs: &[Pair]) -> Vec<&str> {
+        pairs.iter().map(|p| p.replacement.as_str()).collect()
+    }
+
+    #[test]
+    fn completes_first_word_from_spec() {
+        let (start, pairs) = helper().candidates("p");
+        assert_eq!(0, start);
+        assert_eq!(vec!["play", "pass"], replacements(&pairs));
+    }
+
+    #[test]
+    fn completes_trailing_word_after_token() {
+        let (start, pairs) = helper().candidates("play g");
+        assert_eq!(5, start);
+        assert_eq!(vec!["green"], replacements(&pairs));
+    }
+
+    #[test]
+    fn completes_meta_commands() {
+        let (start, pairs) = helper().candidates(":s");
+        assert_eq!(0, start);
+        assert_eq!(vec![":save", ":seed"], replacements(&pairs));
+    }
+
+    #[test]
+    fn no_spec_offers_nothing() {
+        let (_, pairs) = ReplHelper::default().candidates("pl");
+        assert!(pairs.is_empty());
+    }
+}
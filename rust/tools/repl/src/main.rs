@@ -1,10 +1,141 @@
 use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
 
-use brdgme_cmd::repl;
 use brdgme_cmd::requester;
+use brdgme_cmd::requester::localized::LocalizedRequester;
+use brdgme_game::GameOptions;
+use brdgme_game::i18n::Locale;
 
-fn main() {
+mod complete;
+mod output;
+mod repl;
+
+const USAGE: &str = "usage: brdgme_repl [--seed N] [--option KEY=VALUE]... [--load FILE] [--locale en|es|de] (local PATH | http URL)";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Opts {
+    /// Seed for a new game; a random one is used (and printed) when unset so
+    /// every session can be replayed.
+    pub seed: Option<u64>,
+    /// Game options for a new game, from repeated `--option key=value`.
+    pub options: GameOptions,
+    /// Saved session to resume instead of starting a new game.
+    pub load: Option<PathBuf>,
+    /// Language for renders, logs, rules and command words.
+    pub locale: Locale,
+}
+
+fn main() -> ExitCode {
     let args: Vec<String> = env::args().collect();
-    let mut client = requester::parse_args(&args).unwrap();
-    repl(&mut client);
+    let (opts, requester_args) = match parse_opts(&args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let mut client = match requester::parse_args(&requester_args) {
+        Ok(client) => LocalizedRequester::new(client, opts.locale),
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    repl::repl(&mut client, &opts);
+    ExitCode::SUCCESS
+}
+
+/// Splits the REPL's own flags from the requester arguments, keeping the
+/// program name first so the remainder can go to `requester::parse_args`.
+fn parse_opts(args: &[String]) -> Result<(Opts, Vec<String>), String> {
+    let mut opts = Opts::default();
+    let mut rest: Vec<String> = args.iter().take(1).cloned().collect();
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = iter.next().ok_or("--seed requires a value")?;
+                opts.seed = Some(
+                    value
+                        .parse()
+                        .map_err(|e| format!("invalid --seed '{}': {}", value, e))?,
+                );
+            }
+            "--option" => {
+                let value = iter.next().ok_or("--option requires KEY=VALUE")?;
+                let (key, value) = value
+                    .split_once('=')
+                    .ok_or_else(|| format!("invalid --option '{}', expected KEY=VALUE", value))?;
+                opts.options.set(key.trim(), value.trim());
+            }
+            "--load" => {
+                let value = iter.next().ok_or("--load requires a file")?;
+                opts.load = Some(PathBuf::from(value));
+            }
+            "--locale" => {
+                let value = iter.next().ok_or("--locale requires a language code")?;
+                opts.locale = value
+                    .parse()
+                    .map_err(|e| format!("invalid --locale: {}", e))?;
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+    Ok((opts, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(a: &[&str]) -> Vec<String> {
+        a.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_opts_extracts_flags_and_keeps_requester_args() {
+        let (opts, rest) = parse_opts(&args(&[
+            "brdgme_repl",
+            "--seed",
+            "42",
+            "--option",
+            "mode=advanced",
+            "local",
+            "./game_cli",
+            "--load",
+            "save.json",
+            "--locale",
+            "es",
+        ]))
+        .unwrap();
+        assert_eq!(
+            Opts {
+                seed: Some(42),
+                options: GameOptions::new().with("mode", "advanced"),
+                load: Some(PathBuf::from("save.json")),
+                locale: Locale::Es,
+            },
+            opts
+        );
+        assert_eq!(args(&["brdgme_repl", "local", "./game_cli"]), rest);
+    }
+
+    #[test]
+    fn parse_opts_rejects_bad_seed() {
+        assert!(parse_opts(&args(&["brdgme_repl", "--seed", "abc"])).is_err());
+        assert!(parse_opts(&args(&["brdgme_repl", "--seed"])).is_err());
+    }
+
+    #[test]
+    fn parse_opts_rejects_bad_option() {
+        assert!(parse_opts(&args(&["brdgme_repl", "--option", "advanced"])).is_err());
+        assert!(parse_opts(&args(&["brdgme_repl", "--option"])).is_err());
+    }
+
+    #[test]
+    fn parse_opts_rejects_unknown_locale() {
+        assert!(parse_opts(&args(&["brdgme_repl", "--locale", "fr"])).is_err());
+        assert!(parse_opts(&args(&["brdgme_repl", "--locale"])).is_err());
+    }
 }
@@ -0,0 +1,61 @@
+use brdgme_cmd::api::CliLog;
+use brdgme_color::Style;
+use brdgme_markup::{self, Node, Player, TNode, ansi, from_lines, to_lines, transform};
+
+pub fn output_logs(logs: Vec<CliLog>, players: &[Player]) {
+    for l in logs {
+        let content = match brdgme_markup::from_string(&l.content) {
+            Ok(nodes) => nodes,
+            Err(_) => vec![Node::text(&l.content)],
+        };
+        let mut l_line = vec![Node::Bold(vec![Node::text(format!("{}", l.at))])];
+        l_line.push(Node::text(" - "));
+        l_line.extend(content);
+        output_nodes(&l_line, players);
+    }
+}
+
+pub fn output_nodes(nodes: &[Node], players: &[Player]) {
+    let term_w = terminal_size::terminal_size().map_or(0, |(w, _)| w.0 as usize);
+    print!(
+        "{}",
+        ansi(&from_lines(
+            &to_lines(&transform(nodes, players))
+                .iter()
+                .map(|l| {
+                    let l_len = TNode::len(l);
+                    let mut l = l.to_owned();
+                    if l_len < term_w {
+                        l.push(TNode::Bg(
+                            *Style::default().bg,
+                            vec![TNode::Text(" ".repeat(term_w - l_len))],
+                        ));
+                    }
+                    l
+                })
+                .collect::<Vec<Vec<TNode>>>()
+        ))
+    );
+}
+
+pub fn output_error<I: Into<String>>(s: I) {
+    output_nodes(
+        &[Node::Bold(vec![Node::Fg(
+            brdgme_color::NamedColor::Red.into(),
+            vec![Node::text(s)],
+        )])],
+        &[],
+    );
+}
+
+pub fn output_markup(markup: &str, players: &[Player]) {
+    let nodes = match brdgme_markup::from_string(markup) {
+        Ok(nodes) => nodes,
+        Err(_) => vec![Node::text(markup.to_string())],
+    };
+    output_nodes(&nodes, players)
+}
+
+pub fn output_nl() {
+    output_markup("", &[]);
+}
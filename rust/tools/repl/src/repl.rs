@@ -0,0 +1,533 @@
+use std::fs;
+use std::path::Path;
+
+use rustyline::error::ReadlineError;
+use rustyline::history::DefaultHistory;
+use rustyline::{CompletionType, Config, Editor};
+use serde::{Deserialize, Serialize};
+
+use brdgme_cmd::api::{GameResponse, PlayerRender, PubRender, Request, Response};
+use brdgme_cmd::requester::Requester;
+use brdgme_color::{LIGHT, Style};
+use brdgme_game::command::doc;
+use brdgme_game::{GameOptions, Status};
+use brdgme_markup::{Node, Player, ansi, transform};
+
+use crate::Opts;
+use crate::complete::{META_COMMANDS, ReplHelper};
+use crate::output::{output_error, output_logs, output_markup, output_nl, output_nodes};
+
+const DEFAULT_SAVE_FILE: &str = "game.json";
+
+type LineEditor = Editor<ReplHelper, DefaultHistory>;
+
+/// Everything needed to resume a hot-seat game: the game service only knows
+/// the state, so player names travel alongside it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SavedSession {
+    names: Vec<String>,
+    seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "GameOptions::is_empty")]
+    options: GameOptions,
+    game: GameResponse,
+}
+
+struct Session {
+    saved: SavedSession,
+    players: Vec<Player>,
+    public_render: PubRender,
+    player_renders: Vec<PlayerRender>,
+    undo_stack: Vec<GameResponse>,
+}
+
+pub fn repl<T: Requester>(client: &mut T, opts: &Opts) {
+    if let Err(message) = run(client, opts) {
+        output_error(message);
+    }
+}
+
+fn run<T: Requester>(client: &mut T, opts: &Opts) -> Result<(), String> {
+    print!("{}", Style::default().ansi());
+    let config = Config::builder()
+        .completion_type(CompletionType::List)
+        .auto_add_history(true)
+        .build();
+    let mut editor: LineEditor = Editor::with_config(config).map_err(|e| e.to_string())?;
+    editor.set_helper(Some(ReplHelper::default()));
+    let session = match opts.load {
+        Some(ref path) => load_session(client, path, &[])?,
+        None => match new_session(client, &mut editor, opts.seed, &opts.options)? {
+            Some(session) => session,
+            None => return Ok(()),
+        },
+    };
+    play(client, &mut editor, session)
+}
+
+fn new_session<T: Requester>(
+    client: &mut T,
+    editor: &mut LineEditor,
+    seed: Option<u64>,
+    options: &GameOptions,
+) -> Result<Option<Session>, String> {
+    let mut names: Vec<String> = vec![];
+    loop {
+        let Some(name) = read_line(
+            editor,
+            &format!("Enter player {} (or blank to finish): ", names.len() + 1),
+        )?
+        else {
+            return Ok(None);
+        };
+        if name.is_empty() {
+            break;
+        }
+        names.push(name);
+    }
+    let seed = seed.unwrap_or_else(rand::random);
+    match client
+        .request(&Request::New {
+            players: names.len(),
+            seed: Some(seed),
+            options: options.clone(),
+        })
+        .map_err(|e| e.to_string())?
+    {
+        Response::New {
+            game,
+            logs,
+            public_render,
+            player_renders,
+            seed,
+        } => {
+            let players = to_players(&names);
+            output_nl();
+            output_markup(&format!("Seed: {}", seed), &[]);
+            if !options.is_empty() {
+                let chosen: Vec<String> = options
+                    .iter()
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect();
+                output_markup(&format!("Options: {}", chosen.join(", ")), &[]);
+            }
+            output_logs(logs, &players);
+            Ok(Some(Session {
+                saved: SavedSession {
+                    names,
+                    seed: Some(seed),
+                    options: options.clone(),
+                    game,
+                },
+                players,
+                public_render,
+                player_renders,
+                undo_stack: vec![],
+            }))
+        }
+        resp => {
+            output_nl();
+            output_error(response_error_message(&resp, "new game request"));
+            Ok(None)
+        }
+    }
+}
+
+/// Loads a session, or a bare game from `:save` before sessions kept names
+/// alongside it. Old games take `names` if they fit the player count and
+/// default names otherwise.
+fn load_session<T: Requester>(
+    client: &mut T,
+    path: &Path,
+    names: &[String],
+) -> Result<Session, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    let saved = match serde_json::from_str::<SavedSession>(&json) {
+        Ok(saved) => saved,
+        Err(session_err) => match serde_json::from_str::<GameResponse>(&json) {
+            Ok(game) => SavedSession {
+                names: vec![],
+                seed: None,
+                options: GameOptions::default(),
+                game,
+            },
+            Err(_) => {
+                return Err(format!(
+                    "could not parse {}: {}",
+                    path.display(),
+                    session_err
+                ));
+            }
+        },
+    };
+    let (public_render, player_renders) = fetch_renders(client, &saved.game)?;
+    let saved = SavedSession {
+        names: if saved.names.is_empty() {
+            fallback_names(names, player_renders.len())
+        } else {
+            saved.names
+        },
+        ..saved
+    };
+    Ok(Session {
+        players: to_players(&saved.names),
+        saved,
+        public_render,
+        player_renders,
+        undo_stack: vec![],
+    })
+}
+
+fn fallback_names(names: &[String], players: usize) -> Vec<String> {
+    if names.len() == players {
+        return names.to_vec();
+    }
+    (1..=players).map(|p| format!("Player {}", p)).collect()
+}
+
+fn play<T: Requester>(
+    client: &mut T,
+    editor: &mut LineEditor,
+    mut session: Session,
+) -> Result<(), String> {
+    loop {
+        let whose_turn = match session.saved.game.status {
+            Status::Finished { ref placings, .. } => {
+                output_nl();
+                output_placings(placings, &session.players);
+                output_nl();
+                output_markup(&session.public_render.render, &session.players);
+                return Ok(());
+            }
+            Status::Active { ref whose_turn, .. } => whose_turn.clone(),
+        };
+        output_nl();
+        let Some(&current_player) = whose_turn.first() else {
+            output_nodes(&[Node::text("no player's turn, exiting")], &session.players);
+            return Ok(());
+        };
+        let Some(player_render) = session.player_renders.get(current_player) else {
+            return Err(format!("no player render for player {}", current_player));
+        };
+        output_markup(&player_render.render, &session.players);
+        println!();
+        if let Some(ref spec) = player_render.command_spec {
+            output_nl();
+            output_nodes(&doc::render(&spec.doc()), &session.players);
+        }
+        println!();
+        if let Some(helper) = editor.helper_mut() {
+            helper.spec = player_render.command_spec.clone();
+            helper.names = session.saved.names.clone();
+        }
+        let prompt = format!(
+            "{}: ",
+            ansi(&transform(
+                &[Node::Player(current_player)],
+                &session.players
+            ))
+        );
+        let Some(input) = read_line(editor, &prompt)? else {
+            return Ok(());
+        };
+        let (command, arg) = input
+            .split_once(char::is_whitespace)
+            .map(|(c, a)| (c, a.trim()))
+            .unwrap_or((input.as_str(), ""));
+        match command {
+            ":help" | ":h" => output_help(&session, current_player),
+            ":rules" => match client.request(&Request::Rules) {
+                Ok(Response::Rules { rules }) => println!("{}", rules),
+                Ok(resp) => output_error(response_error_message(&resp, "rules request")),
+                Err(e) => output_error(e.to_string()),
+            },
+            ":dump" | ":d" => println!("{:#?}", session.saved.game),
+            ":json" => match serde_json::to_string_pretty(&session.saved.game) {
+                Ok(json) => println!("{}", json),
+                Err(e) => output_error(format!("could not serialize game: {}", e)),
+            },
+            ":seed" => match session.saved.seed {
+                Some(seed) => println!("{}", seed),
+                None => output_error("this session was saved without a seed"),
+            },
+            ":save" => {
+                let path = save_path(arg);
+                match save_session(&session.saved, path) {
+                    Ok(()) => println!("Saved to {}", path.display()),
+                    Err(message) => output_error(message),
+                }
+            }
+            ":load" => match load_session(client, save_path(arg), &session.saved.names) {
+                Ok(loaded) => session = loaded,
+                Err(message) => output_error(message),
+            },
+            ":undo" | ":u" => match session.undo_stack.pop() {
+                Some(game) => match fetch_renders(client, &game) {
+                    Ok((public_render, player_renders)) => {
+                        session.saved.game = game;
+                        session.public_render = public_render;
+                        session.player_renders = player_renders;
+                    }
+                    Err(message) => {
+                        session.undo_stack.push(game);
+                        output_error(message);
+                    }
+                },
+                None => output_error("No undos available"),
+            },
+            ":quit" | ":q" => return Ok(()),
+            _ => match client.request(&Request::Play {
+                player: current_player,
+                command: input.clone(),
+                names: session.saved.names.clone(),
+                game: session.saved.game.state.clone(),
+            }) {
+                Ok(Response::Play {
+                    game,
+                    logs,
+                    remaining_input,
+                    public_render,
+                    player_renders,
+                    ..
+                }) => {
+                    if !remaining_input.trim().is_empty() {
+                        output_nl();
+                        output_error(format!("Unexpected: '{}'", remaining_input));
+                        continue;
+                    }
+                    let previous = std::mem::replace(&mut session.saved.game, game);
+                    session.undo_stack.push(previous);
+                    session.public_render = public_render;
+                    session.player_renders = player_renders;
+                    output_nl();
+                    output_logs(logs, &session.players);
+                }
+                Ok(resp) => {
+                    output_nl();
+                    output_error(response_error_message(&resp, "play request"));
+                }
+                Err(e) => return Err(e.to_string()),
+            },
+        }
+    }
+}
+
+fn fetch_renders<T: Requester>(
+    client: &mut T,
+    game: &GameResponse,
+) -> Result<(PubRender, Vec<PlayerRender>), String> {
+    let response = client
+        .request(&Request::Status {
+            game: game.state.clone(),
+        })
+        .map_err(|e| e.to_string())?;
+    match response {
+        Response::Status {
+            public_render,
+            player_renders,
+            ..
+        } => Ok((public_render, player_renders)),
+        resp => Err(response_error_message(&resp, "status request")),
+    }
+}
+
+fn save_path(arg: &str) -> &Path {
+    Path::new(if arg.is_empty() {
+        DEFAULT_SAVE_FILE
+    } else {
+        arg
+    })
+}
+
+fn save_session(saved: &SavedSession, path: &Path) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(saved)
+        .map_err(|e| format!("could not get game JSON: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+fn to_players(names: &[String]) -> Vec<Player> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| Player {
+            name: name.to_string(),
+            color: LIGHT.player_color(i),
+        })
+        .collect()
+}
+
+fn output_placings(placings: &[usize], players: &[Player]) {
+    if placings.is_empty() {
+        println!("The game is over, there are no winners");
+        return;
+    }
+    println!(
+        "The game is over, placings: {}",
+        placings
+            .iter()
+            .enumerate()
+            .filter_map(|(player, placing)| players
+                .get(player)
+                .map(|p| format!("{} ({})", p.name, placing)))
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+}
+
+fn output_help(session: &Session, player: usize) {
+    output_nl();
+    for (name, desc) in META_COMMANDS {
+        output_nodes(
+            &[
+                Node::Bold(vec![Node::text(*name)]),
+                Node::text(format!(" - {}", desc)),
+            ],
+            &[],
+        );
+    }
+    if let Some(spec) = session
+        .player_renders
+        .get(player)
+        .and_then(|r| r.command_spec.as_ref())
+    {
+        output_nl();
+        output_nodes(&doc::render(&spec.doc()), &session.players);
+    }
+}
+
+/// Reads a trimmed line, treating end of input and Ctrl-C as a request to
+/// quit.
+fn read_line(editor: &mut LineEditor, prompt: &str) -> Result<Option<String>, String> {
+    match editor.readline(prompt) {
+        Ok(line) => Ok(Some(line.trim().to_owned())),
+        Err(ReadlineError::Eof | ReadlineError::Interrupted) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Renders the user-facing message for a non-success REPL response. `context`
+/// names the request that produced it for the unexpected-response case. Used by
+/// the new-game, play and status handlers so a normal `Response::UserError` (or
+/// any other non-success variant) prints a message instead of panicking.
+fn response_error_message(response: &Response, context: &str) -> String {
+    match response {
+        Response::UserError { message } | Response::SystemError { message } => message.clone(),
+        r => format!("unexpected response to {}: {:?}", context, r),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_error_response_produces_message_without_panicking() {
+        let message = response_error_message(
+            &Response::UserError {
+                message: "no more moves".to_string(),
+            },
+            "new game request",
+        );
+        assert!(message.contains("no more moves"), "got: {}", message);
+    }
+
+    #[test]
+    fn saved_session_round_trips() {
+        let saved = SavedSession {
+            names: vec!["mick".to_string(), "steve".to_string()],
+            seed: Some(7),
+            options: GameOptions::new().with("mode", "advanced"),
+            game: GameResponse {
+                state: "{}".to_string(),
+                points: vec![0.0, 1.0],
+                status: Status::Active {
+                    whose_turn: vec![1],
+                    eliminated: vec![],
+                },
+            },
+        };
+        let path = std::env::temp_dir().join("brdgme_repl_saved_session_round_trips.json");
+        save_session(&saved, &path).unwrap();
+        let json = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        let loaded: SavedSession = serde_json::from_str(&json).unwrap();
+        assert_eq!(saved.names, loaded.names);
+        assert_eq!(saved.seed, loaded.seed);
+        assert_eq!(saved.options, loaded.options);
+        assert_eq!(saved.game.state, loaded.game.state);
+    }
+
+    /// Answers status requests for a game of `players` players.
+    struct StatusOnly {
+        players: usize,
+    }
+
+    impl Requester for StatusOnly {
+        fn request(
+            &mut self,
+            req: &Request,
+        ) -> Result<Response, brdgme_cmd::requester::error::RequestError> {
+            let Request::Status { game } = req else {
+                panic!("unexpected request {:?}", req);
+            };
+            Ok(Response::Status {
+                game: GameResponse {
+                    state: game.clone(),
+                    points: vec![0.0; self.players],
+                    status: Status::Active {
+                        whose_turn: vec![0],
+                        eliminated: vec![],
+                    },
+                },
+                public_render: PubRender {
+                    pub_state: "{}".to_string(),
+                    render: String::new(),
+                },
+                player_renders: (0..self.players)
+                    .map(|_| PlayerRender {
+                        player_state: "{}".to_string(),
+                        render: String::new(),
+                        command_spec: None,
+                    })
+                    .collect(),
+            })
+        }
+    }
+
+    #[test]
+    fn load_session_reads_old_bare_game_saves() {
+        let path = std::env::temp_dir().join("brdgme_repl_load_old_bare_game.json");
+        fs::write(
+            &path,
+            r#"{"state":"{\"board\":[]}","points":[0.0,0.0],"status":{"Active":{"whose_turn":[1],"eliminated":[]}}}"#,
+        )
+        .unwrap();
+        let mut client = StatusOnly { players: 2 };
+        let defaults = load_session(&mut client, &path, &[]).unwrap();
+        let current = vec!["mick".to_string(), "steve".to_string()];
+        let kept = load_session(&mut client, &path, &current).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(r#"{"board":[]}"#, defaults.saved.game.state);
+        assert_eq!(vec!["Player 1", "Player 2"], defaults.saved.names);
+        assert_eq!(2, defaults.players.len());
+        assert_eq!(None, defaults.saved.seed);
+        assert_eq!(current, kept.saved.names);
+    }
+
+    #[test]
+    fn load_session_reports_unparseable_files() {
+        let path = std::env::temp_dir().join("brdgme_repl_load_unparseable.json");
+        fs::write(&path, r#"{"names":["mick"]}"#).unwrap();
+        let result = load_session(&mut StatusOnly { players: 1 }, &path, &[]);
+        let _ = fs::remove_file(&path);
+        let message = result.err().expect("load should fail");
+        assert!(message.contains("could not parse"), "got: {}", message);
+    }
+
+    #[test]
+    fn save_path_defaults_to_game_json() {
+        assert_eq!(Path::new(DEFAULT_SAVE_FILE), save_path(""));
+        assert_eq!(Path::new("other.json"), save_path("other.json"));
+    }
+}
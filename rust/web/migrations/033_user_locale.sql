@@ -0,0 +1,5 @@
+-- Player-facing language for game renders, logs, rules and command words.
+-- ISO 639-1 code matching brdgme_i18n::Locale; unknown codes read as English.
+
+ALTER TABLE public.users
+    ADD COLUMN IF NOT EXISTS locale text NOT NULL DEFAULT 'en';
@@ -0,0 +1,9 @@
+-- Values chosen for the game's options (brdgme_game::GameOptions) at
+-- creation, e.g. {"mode": "advanced"}. Proposals carry them until the game
+-- starts; games keep them so a restart plays the same variant.
+
+ALTER TABLE public.game_proposals
+    ADD COLUMN IF NOT EXISTS options jsonb NOT NULL DEFAULT '{}';
+
+ALTER TABLE public.games
+    ADD COLUMN IF NOT EXISTS options jsonb NOT NULL DEFAULT '{}';
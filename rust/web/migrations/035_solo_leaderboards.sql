@@ -0,0 +1,24 @@
+-- Solo play: a finished one-player game scores onto a per-game-type
+-- leaderboard instead of Elo (which needs two humans). daily_date marks a
+-- daily challenge game; every challenge for a game type on a UTC date starts
+-- from the same seed, so its scores are directly comparable.
+
+ALTER TABLE public.games
+    ADD COLUMN IF NOT EXISTS daily_date date;
+
+CREATE TABLE IF NOT EXISTS public.solo_scores (
+    id uuid PRIMARY KEY DEFAULT uuid_generate_v4(),
+    created_at timestamp NOT NULL DEFAULT (now() AT TIME ZONE 'utc'),
+    game_id uuid NOT NULL UNIQUE REFERENCES public.games(id) ON DELETE CASCADE,
+    game_type_id uuid NOT NULL REFERENCES public.game_types(id),
+    user_id uuid NOT NULL REFERENCES public.users(id),
+    score real NOT NULL,
+    daily_date date
+);
+CREATE INDEX IF NOT EXISTS idx_solo_scores_game_type
+    ON public.solo_scores(game_type_id, score DESC);
+CREATE INDEX IF NOT EXISTS idx_solo_scores_daily
+    ON public.solo_scores(game_type_id, daily_date, score DESC)
+    WHERE daily_date IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_games_daily_date
+    ON public.games(daily_date) WHERE daily_date IS NOT NULL;
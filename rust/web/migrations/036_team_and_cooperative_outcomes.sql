@@ -0,0 +1,18 @@
+-- Team and cooperative results. game_players.team is the 0-based partnership
+-- a player finished in (NULL when everyone plays for themselves); team games
+-- are rated team against team. A cooperative game's shared win/loss and score
+-- go to cooperative_scores instead of Elo, mirroring solo_scores.
+
+ALTER TABLE public.game_players
+    ADD COLUMN IF NOT EXISTS team integer;
+
+CREATE TABLE IF NOT EXISTS public.cooperative_scores (
+    id uuid PRIMARY KEY DEFAULT uuid_generate_v4(),
+    created_at timestamp NOT NULL DEFAULT (now() AT TIME ZONE 'utc'),
+    game_id uuid NOT NULL UNIQUE REFERENCES public.games(id) ON DELETE CASCADE,
+    game_type_id uuid NOT NULL REFERENCES public.game_types(id),
+    won boolean NOT NULL,
+    score real NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_cooperative_scores_game_type
+    ON public.cooperative_scores(game_type_id, score DESC);
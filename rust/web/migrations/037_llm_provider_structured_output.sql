@@ -0,0 +1,5 @@
+-- How the bot constrains a provider's output to the command grammar:
+-- llama.cpp GBNF, vLLM guided regex, or a JSON schema response format.
+-- NULL leaves output free-form, validated after the fact.
+ALTER TABLE llm_providers ADD COLUMN structured_output TEXT
+    CHECK (structured_output IN ('gbnf', 'regex', 'json_schema'));
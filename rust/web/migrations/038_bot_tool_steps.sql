@@ -0,0 +1,4 @@
+-- Rounds of tool calls (suggest, preview, rules_section) a bot may make
+-- before it must answer. 0 keeps the single-shot prompt, for providers
+-- without function calling.
+ALTER TABLE bots ADD COLUMN tool_steps INTEGER NOT NULL DEFAULT 0 CHECK (tool_steps >= 0);
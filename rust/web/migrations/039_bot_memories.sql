@@ -0,0 +1,12 @@
+-- Private notes a bot keeps for itself between turns of a game, one row per
+-- bot seat. Only the bot service reads this table: it must never be shown to
+-- players or included in game exports, since notes can quote the bot's hand.
+-- bots.memory_chars caps the notes kept; 0 (the default) turns memory off.
+
+ALTER TABLE bots ADD COLUMN memory_chars INTEGER NOT NULL DEFAULT 0 CHECK (memory_chars >= 0);
+
+CREATE TABLE IF NOT EXISTS public.bot_memories (
+    game_player_id uuid PRIMARY KEY REFERENCES public.game_players(id) ON DELETE CASCADE,
+    notes text NOT NULL,
+    updated_at timestamp NOT NULL DEFAULT (now() AT TIME ZONE 'utc')
+);
@@ -0,0 +1,5 @@
+-- The API a provider speaks. 'openai' covers OpenAI-compatible chat
+-- completions (vLLM, DeepSeek, OpenRouter); the others are native APIs with
+-- their own request shapes for thinking and structured output.
+ALTER TABLE llm_providers ADD COLUMN kind TEXT NOT NULL DEFAULT 'openai'
+    CHECK (kind IN ('openai', 'anthropic', 'ollama', 'gemini', 'llamacpp'));
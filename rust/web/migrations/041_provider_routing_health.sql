@@ -0,0 +1,19 @@
+-- Weighted load balancing: within a priority tier, each link takes a share
+-- of turns proportional to its weight.
+ALTER TABLE bot_providers ADD COLUMN weight INTEGER NOT NULL DEFAULT 1 CHECK (weight > 0);
+
+-- The bot service's latest view of each provider and model's health, for the
+-- admin pages. Each bot replica keeps its own circuit breakers in memory and
+-- reports here after every call, so this is the most recent report from any
+-- replica.
+CREATE TABLE IF NOT EXISTS public.llm_provider_health (
+    provider_id uuid NOT NULL REFERENCES public.llm_providers(id) ON DELETE CASCADE,
+    model text NOT NULL,
+    circuit text NOT NULL CHECK (circuit IN ('closed', 'open', 'half_open')),
+    samples integer NOT NULL,
+    error_rate real NOT NULL,
+    avg_latency_ms integer,
+    consecutive_failures integer NOT NULL,
+    updated_at timestamp NOT NULL DEFAULT (now() AT TIME ZONE 'utc'),
+    PRIMARY KEY (provider_id, model)
+);
@@ -0,0 +1,40 @@
+-- Token usage and cost accounting for LLM bots.
+--
+-- Prices are per bot-provider link, since they depend on the model, in US
+-- dollars per million tokens. 0 (the default) counts a link as free, as for
+-- a self-hosted model.
+ALTER TABLE bot_providers
+    ADD COLUMN input_usd_per_mtok DOUBLE PRECISION NOT NULL DEFAULT 0 CHECK (input_usd_per_mtok >= 0),
+    ADD COLUMN output_usd_per_mtok DOUBLE PRECISION NOT NULL DEFAULT 0 CHECK (output_usd_per_mtok >= 0);
+
+-- Spend caps per UTC day and calendar month, NULL for no cap. A provider
+-- over budget is skipped, so its bots fall over to their next provider; a
+-- bot over budget, or with every provider over budget, plays random moves.
+ALTER TABLE bots
+    ADD COLUMN daily_budget_usd DOUBLE PRECISION CHECK (daily_budget_usd >= 0),
+    ADD COLUMN monthly_budget_usd DOUBLE PRECISION CHECK (monthly_budget_usd >= 0);
+ALTER TABLE llm_providers
+    ADD COLUMN daily_budget_usd DOUBLE PRECISION CHECK (daily_budget_usd >= 0),
+    ADD COLUMN monthly_budget_usd DOUBLE PRECISION CHECK (monthly_budget_usd >= 0);
+
+-- One row per conversation with a provider: a bot turn that retries or
+-- fails over has several, sharing its turn_id. Bots, providers and games
+-- may be deleted without losing the spend history.
+CREATE TABLE IF NOT EXISTS public.bot_llm_usage (
+    id uuid PRIMARY KEY DEFAULT uuid_generate_v4(),
+    turn_id uuid NOT NULL,
+    bot_id uuid REFERENCES public.bots(id) ON DELETE SET NULL,
+    bot_name text NOT NULL,
+    provider_id uuid REFERENCES public.llm_providers(id) ON DELETE SET NULL,
+    model text NOT NULL,
+    game_id uuid REFERENCES public.games(id) ON DELETE SET NULL,
+    llm_calls integer NOT NULL,
+    prompt_tokens bigint NOT NULL,
+    completion_tokens bigint NOT NULL,
+    cost_usd DOUBLE PRECISION NOT NULL,
+    created_at timestamp NOT NULL DEFAULT (now() AT TIME ZONE 'utc')
+);
+
+CREATE INDEX IF NOT EXISTS bot_llm_usage_created_at_idx ON public.bot_llm_usage (created_at);
+CREATE INDEX IF NOT EXISTS bot_llm_usage_bot_idx ON public.bot_llm_usage (bot_id, created_at);
+CREATE INDEX IF NOT EXISTS bot_llm_usage_provider_idx ON public.bot_llm_usage (provider_id, created_at);
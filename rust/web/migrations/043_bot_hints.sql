@@ -0,0 +1,14 @@
+-- Hints: a player may ask a coach bot what it would play in their seat.
+-- The coach is the first enabled bot, by display order, with coach = true;
+-- with none, hints are off. Hints are only offered in games that can't be
+-- rated (fewer than two human players).
+ALTER TABLE bots ADD COLUMN coach BOOLEAN NOT NULL DEFAULT false;
+
+-- One row per hint asked for, to rate-limit each seat.
+CREATE TABLE IF NOT EXISTS public.game_hints (
+    id uuid PRIMARY KEY DEFAULT uuid_generate_v4(),
+    game_player_id uuid NOT NULL REFERENCES public.game_players(id) ON DELETE CASCADE,
+    created_at timestamp NOT NULL DEFAULT (now() AT TIME ZONE 'utc')
+);
+
+CREATE INDEX IF NOT EXISTS game_hints_game_player_idx ON public.game_hints (game_player_id, created_at);
@@ -0,0 +1,4 @@
+-- Bots with explain_moves = true give a short public reason with each move,
+-- posted to the game log under the bot's name. The bot service screens the
+-- reason against its private state before sending it.
+ALTER TABLE bots ADD COLUMN explain_moves BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,18 @@
+-- One daily challenge attempt per player, game type and UTC date. games has
+-- neither column, so the claim gets its own table; the primary key makes two
+-- racing starts settle on one game.
+CREATE TABLE IF NOT EXISTS public.daily_challenges (
+    user_id uuid NOT NULL REFERENCES public.users(id) ON DELETE CASCADE,
+    game_type_id uuid NOT NULL REFERENCES public.game_types(id),
+    daily_date date NOT NULL,
+    game_id uuid NOT NULL UNIQUE REFERENCES public.games(id) ON DELETE CASCADE,
+    PRIMARY KEY (user_id, game_type_id, daily_date)
+);
+
+INSERT INTO public.daily_challenges (user_id, game_type_id, daily_date, game_id)
+SELECT gp.user_id, gv.game_type_id, g.daily_date, g.id
+FROM public.games g
+JOIN public.game_versions gv ON gv.id = g.game_version_id
+JOIN public.game_players gp ON gp.game_id = g.id
+WHERE g.daily_date IS NOT NULL AND gp.user_id IS NOT NULL
+ON CONFLICT DO NOTHING;
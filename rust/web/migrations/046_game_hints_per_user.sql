@@ -0,0 +1,15 @@
+-- Hints are limited per user per game rather than per seat, so taking over
+-- another seat in the same game doesn't come with a fresh allowance. Seats
+-- without a user are still limited on their own.
+ALTER TABLE public.game_hints
+    ADD COLUMN IF NOT EXISTS game_id uuid REFERENCES public.games(id) ON DELETE CASCADE,
+    ADD COLUMN IF NOT EXISTS user_id uuid REFERENCES public.users(id) ON DELETE CASCADE;
+
+UPDATE public.game_hints h
+SET game_id = gp.game_id, user_id = gp.user_id
+FROM public.game_players gp
+WHERE gp.id = h.game_player_id;
+
+ALTER TABLE public.game_hints ALTER COLUMN game_id SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS game_hints_game_user_idx ON public.game_hints (game_id, user_id, created_at);
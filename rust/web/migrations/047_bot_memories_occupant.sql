@@ -0,0 +1,14 @@
+-- Notes belong to the bot in the seat, not the seat: a bot that takes over a
+-- seat starts without the notes of whoever held it before. Notes saved by an
+-- earlier occupant are kept until the new one saves its own, but never read.
+ALTER TABLE public.bot_memories
+    ADD COLUMN IF NOT EXISTS game_bot_id uuid REFERENCES public.game_bots(id) ON DELETE CASCADE;
+
+UPDATE public.bot_memories m
+SET game_bot_id = gp.game_bot_id
+FROM public.game_players gp
+WHERE gp.id = m.game_player_id;
+
+DELETE FROM public.bot_memories WHERE game_bot_id IS NULL;
+
+ALTER TABLE public.bot_memories ALTER COLUMN game_bot_id SET NOT NULL;
@@ -115,6 +115,42 @@ pub struct BotProviderRow {
     pub enabled: bool,
     pub bot_name: String,
     pub provider_name: String,
+    pub weight: i32,
+    /// The bot service's last health report for this provider and model,
+    /// if it has called it.
+    pub health: Option<ProviderHealth>,
+}
+
+/// A provider and model's circuit breaker state, from `llm_provider_health`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderHealth {
+    pub circuit: String,
+    pub samples: i32,
+    pub error_rate: f32,
+    pub avg_latency_ms: Option<i32>,
+    pub consecutive_failures: i32,
+    pub reported_secs_ago: i64,
+}
+
+impl ProviderHealth {
+    fn summary(&self) -> String {
+        let mut parts = vec![
+            self.circuit.replace('_', "-"),
+            format!(
+                "{:.0}% errors over {} calls",
+                self.error_rate * 100.0,
+                self.samples
+            ),
+        ];
+        if let Some(ms) = self.avg_latency_ms {
+            parts.push(format!("{}ms avg", ms));
+        }
+        if self.consecutive_failures > 0 {
+            parts.push(format!("{} failing in a row", self.consecutive_failures));
+        }
+        parts.push(format!("reported {}s ago", self.reported_secs_ago));
+        parts.join(" · ")
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -125,6 +161,55 @@ pub struct TestBotProviderResponse {
     pub elapsed_ms: u64,
 }
 
+/// A bot or provider's spend against its budget, in US dollars. Budgets are
+/// set in SQL on `bots` and `llm_providers`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetRow {
+    pub scope: String,
+    pub name: String,
+    pub daily_budget_usd: Option<f64>,
+    pub monthly_budget_usd: Option<f64>,
+    pub spent_today_usd: f64,
+    pub spent_month_usd: f64,
+}
+
+/// One UTC day's LLM usage for a bot, provider and model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailySpendRow {
+    pub day: String,
+    pub bot_name: String,
+    /// `None` for the env fallback provider or a deleted one.
+    pub provider_name: Option<String>,
+    pub model: String,
+    pub turns: i64,
+    pub llm_calls: i64,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    pub cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpendReport {
+    pub budgets: Vec<BudgetRow>,
+    pub daily: Vec<DailySpendRow>,
+}
+
+/// Days of usage the admin spend report covers, today included.
+pub const SPEND_REPORT_DAYS: i32 = 30;
+
+fn format_usd(usd: f64) -> String {
+    format!("${:.2}", usd)
+}
+
+impl BudgetRow {
+    fn summary(spent: f64, budget: Option<f64>) -> String {
+        match budget {
+            Some(budget) => format!("{} of {}", format_usd(spent), format_usd(budget)),
+            None => format_usd(spent),
+        }
+    }
+}
+
 #[cfg(feature = "ssr")]
 type BotDbRow = (Uuid, String, i32, bool, bool, bool, f32, bool);
 #[cfg(feature = "ssr")]
@@ -141,7 +226,24 @@ type BotProviderDbRow = (
     bool,
     String,
     String,
+    i32,
+);
+#[cfg(feature = "ssr")]
+type BudgetDbRow = (String, String, Option<f64>, Option<f64>, f64, f64);
+#[cfg(feature = "ssr")]
+type DailySpendDbRow = (
+    String,
+    String,
+    Option<String>,
+    String,
+    i64,
+    i64,
+    i64,
+    i64,
+    f64,
 );
+#[cfg(feature = "ssr")]
+type ProviderHealthDbRow = (Uuid, String, String, i32, f32, Option<i32>, i32, i64);
 
 #[cfg(feature = "ssr")]
 pub async fn list_bots(pool: &sqlx::PgPool) -> Result<Vec<BotRow>, ServerFnError> {
@@ -641,13 +743,48 @@ pub async fn delete_provider(pool: &sqlx::PgPool, id: Uuid) -> Result<(), Server
 #[cfg(feature = "ssr")]
 pub async fn list_bot_providers(pool: &sqlx::PgPool) -> Result<Vec<BotProviderRow>, ServerFnError> {
     let rows: Vec<BotProviderDbRow> = sqlx::query_as(
-        "SELECT bp.id, bp.bot_id, bp.provider_id, bp.model, bp.reasoning_effort, bp.extra_body, bp.priority, bp.enabled, b.name, p.name \
+        "SELECT bp.id, bp.bot_id, bp.provider_id, bp.model, bp.reasoning_effort, bp.extra_body, bp.priority, bp.enabled, b.name, p.name, bp.weight \
          FROM bot_providers bp JOIN bots b ON bp.bot_id = b.id JOIN llm_providers p ON bp.provider_id = p.id \
          ORDER BY b.display_order, bp.priority",
     )
     .fetch_all(pool)
     .await
     .map_err(internal("admin_list_bot_providers: query"))?;
+    let health: Vec<ProviderHealthDbRow> = sqlx::query_as(
+        "SELECT provider_id, model, circuit, samples, error_rate, avg_latency_ms, consecutive_failures, \
+         EXTRACT(EPOCH FROM (now() AT TIME ZONE 'utc') - updated_at)::bigint \
+         FROM llm_provider_health",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(internal("admin_list_bot_providers: health query"))?;
+    let health: std::collections::HashMap<(Uuid, String), ProviderHealth> = health
+        .into_iter()
+        .map(
+            |(
+                provider_id,
+                model,
+                circuit,
+                samples,
+                error_rate,
+                avg_latency_ms,
+                consecutive_failures,
+                reported_secs_ago,
+            )| {
+                (
+                    (provider_id, model),
+                    ProviderHealth {
+                        circuit,
+                        samples,
+                        error_rate,
+                        avg_latency_ms,
+                        consecutive_failures,
+                        reported_secs_ago,
+                    },
+                )
+            },
+        )
+        .collect();
 
     Ok(rows
         .into_iter()
@@ -663,7 +800,10 @@ pub async fn list_bot_providers(pool: &sqlx::PgPool) -> Result<Vec<BotProviderRo
                 enabled,
                 bot_name,
                 provider_name,
+                weight,
             )| {
+                // Links sharing a provider and model share its health.
+                let health = health.get(&(provider_id, model.clone())).cloned();
                 BotProviderRow {
                     id,
                     bot_id,
@@ -675,6 +815,8 @@ pub async fn list_bot_providers(pool: &sqlx::PgPool) -> Result<Vec<BotProviderRo
                     enabled,
                     bot_name,
                     provider_name,
+                    weight,
+                    health,
                 }
             },
         )
@@ -699,7 +841,7 @@ pub async fn create_bot_provider(
         "INSERT INTO bot_providers (bot_id, provider_id, model, reasoning_effort, extra_body, priority) \
          VALUES ($1, $2, $3, $4, $5, $6) \
          RETURNING id, bot_id, provider_id, model, reasoning_effort, extra_body, priority, enabled, \
-         (SELECT name FROM bots WHERE id = $1), (SELECT name FROM llm_providers WHERE id = $2)",
+         (SELECT name FROM bots WHERE id = $1), (SELECT name FROM llm_providers WHERE id = $2), weight",
     )
     .bind(bot_id)
     .bind(provider_id)
@@ -722,6 +864,8 @@ pub async fn create_bot_provider(
         enabled: row.7,
         bot_name: row.8,
         provider_name: row.9,
+        weight: row.10,
+        health: None,
     })
 }
 
@@ -771,6 +915,103 @@ pub async fn delete_bot_provider(pool: &sqlx::PgPool, id: Uuid) -> Result<(), Se
     Ok(())
 }
 
+/// Spend against every bot and provider's budget, and daily usage over the
+/// last `days` UTC days.
+#[cfg(feature = "ssr")]
+pub async fn spend_report(pool: &sqlx::PgPool, days: i32) -> Result<SpendReport, ServerFnError> {
+    let budgets: Vec<BudgetDbRow> = sqlx::query_as(
+        "WITH month AS ( \
+             SELECT bot_id, provider_id, cost_usd, \
+                    created_at >= date_trunc('day', now() AT TIME ZONE 'utc') AS today \
+             FROM bot_llm_usage \
+             WHERE created_at >= date_trunc('month', now() AT TIME ZONE 'utc')) \
+         SELECT 'Bot', b.name, b.daily_budget_usd, b.monthly_budget_usd, \
+                COALESCE(SUM(m.cost_usd) FILTER (WHERE m.today), 0), COALESCE(SUM(m.cost_usd), 0) \
+         FROM bots b LEFT JOIN month m ON m.bot_id = b.id \
+         GROUP BY b.id ORDER BY b.display_order, b.name",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(internal("admin_spend_report: bot budgets query"))?;
+    let provider_budgets: Vec<BudgetDbRow> = sqlx::query_as(
+        "WITH month AS ( \
+             SELECT bot_id, provider_id, cost_usd, \
+                    created_at >= date_trunc('day', now() AT TIME ZONE 'utc') AS today \
+             FROM bot_llm_usage \
+             WHERE created_at >= date_trunc('month', now() AT TIME ZONE 'utc')) \
+         SELECT 'Provider', p.name, p.daily_budget_usd, p.monthly_budget_usd, \
+                COALESCE(SUM(m.cost_usd) FILTER (WHERE m.today), 0), COALESCE(SUM(m.cost_usd), 0) \
+         FROM llm_providers p LEFT JOIN month m ON m.provider_id = p.id \
+         GROUP BY p.id ORDER BY p.name",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(internal("admin_spend_report: provider budgets query"))?;
+    let daily: Vec<DailySpendDbRow> = sqlx::query_as(
+        "SELECT to_char(date_trunc('day', u.created_at), 'YYYY-MM-DD') AS day, u.bot_name, p.name, \
+                u.model, COUNT(DISTINCT u.turn_id), SUM(u.llm_calls)::bigint, \
+                SUM(u.prompt_tokens)::bigint, SUM(u.completion_tokens)::bigint, SUM(u.cost_usd) \
+         FROM bot_llm_usage u LEFT JOIN llm_providers p ON p.id = u.provider_id \
+         WHERE u.created_at >= date_trunc('day', now() AT TIME ZONE 'utc') - make_interval(days => $1 - 1) \
+         GROUP BY day, u.bot_name, p.name, u.model \
+         ORDER BY day DESC, SUM(u.cost_usd) DESC, u.bot_name",
+    )
+    .bind(days)
+    .fetch_all(pool)
+    .await
+    .map_err(internal("admin_spend_report: daily query"))?;
+
+    Ok(SpendReport {
+        budgets: budgets
+            .into_iter()
+            .chain(provider_budgets)
+            .map(
+                |(
+                    scope,
+                    name,
+                    daily_budget_usd,
+                    monthly_budget_usd,
+                    spent_today_usd,
+                    spent_month_usd,
+                )| BudgetRow {
+                    scope,
+                    name,
+                    daily_budget_usd,
+                    monthly_budget_usd,
+                    spent_today_usd,
+                    spent_month_usd,
+                },
+            )
+            .collect(),
+        daily: daily
+            .into_iter()
+            .map(
+                |(
+                    day,
+                    bot_name,
+                    provider_name,
+                    model,
+                    turns,
+                    llm_calls,
+                    prompt_tokens,
+                    completion_tokens,
+                    cost_usd,
+                )| DailySpendRow {
+                    day,
+                    bot_name,
+                    provider_name,
+                    model,
+                    turns,
+                    llm_calls,
+                    prompt_tokens,
+                    completion_tokens,
+                    cost_usd,
+                },
+            )
+            .collect(),
+    })
+}
+
 /// Cap on bytes read from an admin-configured upstream during a test call.
 /// The 10s reqwest timeout bounds how long a hostile endpoint can stream, not
 /// how much (ws F23). Comfortably above any real completion or error envelope.
@@ -1265,6 +1506,16 @@ pub async fn admin_test_bot_provider(
     test_bot_provider(&pool, &http_client, bot_provider_id, &prompt).await
 }
 
+#[server(AdminSpendReport, "/api")]
+pub async fn admin_spend_report() -> Result<SpendReport, ServerFnError> {
+    use sqlx::PgPool;
+
+    let pool = expect_context::<PgPool>();
+    require_admin(&pool, "admin_spend_report: check admin").await?;
+
+    spend_report(&pool, SPEND_REPORT_DAYS).await
+}
+
 #[component]
 pub fn AdminPage() -> impl IntoView {
     use crate::components::MainLayout;
@@ -1323,6 +1574,7 @@ pub fn AdminPage() -> impl IntoView {
                                     providers=provider_list
                                     version=version
                                 />
+                                <SpendSection version=version/>
                             }.into_any(),
                             _ => view! { <p>"Loading..."</p> }.into_any(),
                         }
@@ -2156,8 +2408,10 @@ fn BotProvidersSection(
                                     <th>"Model"</th>
                                     <th>"Reasoning"</th>
                                     <th>"Priority"</th>
+                                    <th>"Weight"</th>
                                     <th>"Extra Body"</th>
                                     <th>"Enabled"</th>
+                                    <th>"Health"</th>
                                     <th>"Actions"</th>
                                 </tr>
                             </thead>
@@ -2169,6 +2423,11 @@ fn BotProvidersSection(
                                     let model = link.model.clone();
                                     let reasoning = link.reasoning_effort.clone().unwrap_or_default();
                                     let priority = link.priority;
+                                    let weight = link.weight;
+                                    let health = link
+                                        .health
+                                        .as_ref()
+                                        .map_or_else(|| "No calls yet".to_string(), ProviderHealth::summary);
                                     let has_extra = link.extra_body.is_some();
                                     let enabled = link.enabled;
                                     let link_model = link.model.clone();
@@ -2183,8 +2442,10 @@ fn BotProvidersSection(
                                             <td>{model}</td>
                                             <td>{reasoning}</td>
                                             <td>{priority}</td>
+                                            <td>{weight}</td>
                                             <td>{if has_extra { "Yes" } else { "None" }}</td>
                                             <td>{if enabled { "Yes" } else { "No" }}</td>
+                                            <td>{health}</td>
                                             <td>
                                                 <div class="form-actions">
                                                     <button on:click=move |_| editing_id.set(Some(id))>"Edit"</button>
@@ -2224,7 +2485,7 @@ fn BotProvidersSection(
                                             test_result.with(|r| r.as_ref().is_some_and(|(rid, _)| *rid == id))
                                         }>
                                             <tr>
-                                                <td colspan="8">
+                                                <td colspan="10">
                                                     {move || {
                                                         test_result.with(|r| match r {
                                                             Some((_, Ok(resp))) => view! {
@@ -2457,7 +2718,7 @@ fn BotProviderEditForm(
 
     view! {
         <tr>
-            <td colspan="8">
+            <td colspan="10">
                 <form on:submit=on_submit>
                     <FormField label="Model">
                         <input type="text" node_ref=model_input required prop:value=link_model/>
@@ -2495,6 +2756,88 @@ fn BotProviderEditForm(
     }
 }
 
+#[component]
+fn SpendSection(version: RwSignal<u32>) -> impl IntoView {
+    let report: LocalResource<Result<SpendReport, ServerFnError>> = LocalResource::new(move || {
+        version.track();
+        admin_spend_report()
+    });
+
+    view! {
+        <h2>"Spend"</h2>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || {
+                report.get().map(|res| match res {
+                    Err(e) => view! { <p class="error">{e.to_string()}</p> }.into_any(),
+                    Ok(report) => view! {
+                        <table class="admin-table">
+                            <thead>
+                                <tr>
+                                    <th>"Budget"</th>
+                                    <th>"Name"</th>
+                                    <th>"Today (UTC)"</th>
+                                    <th>"This month"</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {report.budgets.into_iter().map(|row| {
+                                    let today = BudgetRow::summary(row.spent_today_usd, row.daily_budget_usd);
+                                    let month = BudgetRow::summary(row.spent_month_usd, row.monthly_budget_usd);
+                                    view! {
+                                        <tr>
+                                            <td>{row.scope}</td>
+                                            <td>{row.name}</td>
+                                            <td>{today}</td>
+                                            <td>{month}</td>
+                                        </tr>
+                                    }
+                                }).collect_view()}
+                            </tbody>
+                        </table>
+                        <h3>{format!("Last {} days", SPEND_REPORT_DAYS)}</h3>
+                        {if report.daily.is_empty() {
+                            view! { <p>"No LLM usage recorded."</p> }.into_any()
+                        } else {
+                            view! {
+                                <table class="admin-table">
+                                    <thead>
+                                        <tr>
+                                            <th>"Day"</th>
+                                            <th>"Bot"</th>
+                                            <th>"Provider"</th>
+                                            <th>"Model"</th>
+                                            <th>"Turns"</th>
+                                            <th>"Calls"</th>
+                                            <th>"Prompt tokens"</th>
+                                            <th>"Completion tokens"</th>
+                                            <th>"Cost"</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {report.daily.into_iter().map(|row| view! {
+                                            <tr>
+                                                <td>{row.day}</td>
+                                                <td>{row.bot_name}</td>
+                                                <td>{row.provider_name.unwrap_or_else(|| "-".to_string())}</td>
+                                                <td>{row.model}</td>
+                                                <td>{row.turns}</td>
+                                                <td>{row.llm_calls}</td>
+                                                <td>{row.prompt_tokens}</td>
+                                                <td>{row.completion_tokens}</td>
+                                                <td>{format_usd(row.cost_usd)}</td>
+                                            </tr>
+                                        }).collect_view()}
+                                    </tbody>
+                                </table>
+                            }.into_any()
+                        }}
+                    }.into_any(),
+                })
+            }}
+        </Suspense>
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3060,6 +3403,102 @@ mod tests {
         assert_eq!(admin.expect("admin caller must succeed").status, 200);
     }
 
+    #[sqlx::test]
+    async fn admin_spend_report_distinguishes_anonymous_non_admin_admin(pool: sqlx::PgPool) {
+        let anonymous =
+            crate::test_support::anonymous(&pool, || async { admin_spend_report().await }).await;
+        let err = anonymous.expect_err("anonymous caller must be rejected");
+        assert!(
+            err.to_string().contains("Not authenticated"),
+            "anonymous error was: {err}"
+        );
+
+        let non_admin =
+            crate::test_support::non_admin(&pool, || async { admin_spend_report().await }).await;
+        let err = non_admin.expect_err("non-admin caller must be rejected");
+        match err {
+            ServerFnError::ServerError(msg) => assert_eq!(msg, ADMIN_REQUIRED),
+            other => panic!("expected ServerError(ADMIN_REQUIRED), got {other:?}"),
+        }
+
+        let admin =
+            crate::test_support::admin(&pool, || async { admin_spend_report().await }).await;
+        assert!(
+            admin.expect("admin caller must succeed").daily.is_empty(),
+            "no usage recorded"
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_spend_report_sums_budgets_and_days(pool: sqlx::PgPool) {
+        let provider = create_provider(
+            &pool,
+            "spendy".to_string(),
+            "http://localhost:1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+        sqlx::query("UPDATE bots SET daily_budget_usd = 1.5 WHERE name = 'easy'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE llm_providers SET monthly_budget_usd = 20 WHERE id = $1")
+            .bind(provider.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let turn = Uuid::new_v4();
+        for (turn_id, cost_usd, ago) in [
+            (turn, 0.25, "0 days"),
+            (turn, 0.5, "0 days"),
+            (Uuid::new_v4(), 2.0, "40 days"),
+        ] {
+            sqlx::query(
+                "INSERT INTO bot_llm_usage \
+                 (turn_id, bot_id, bot_name, provider_id, model, llm_calls, prompt_tokens, \
+                  completion_tokens, cost_usd, created_at) \
+                 SELECT $1, id, 'easy', $2, 'm', 2, 100, 10, $3, \
+                        (now() AT TIME ZONE 'utc') - $4::interval \
+                 FROM bots WHERE name = 'easy'",
+            )
+            .bind(turn_id)
+            .bind(provider.id)
+            .bind(cost_usd)
+            .bind(ago)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let report = spend_report(&pool, 30).await.unwrap();
+
+        let easy = report
+            .budgets
+            .iter()
+            .find(|b| b.scope == "Bot" && b.name == "easy")
+            .unwrap();
+        assert_eq!(Some(1.5), easy.daily_budget_usd);
+        assert_eq!(0.75, easy.spent_today_usd);
+        assert_eq!(0.75, easy.spent_month_usd);
+        let spendy = report
+            .budgets
+            .iter()
+            .find(|b| b.scope == "Provider" && b.name == "spendy")
+            .unwrap();
+        assert_eq!(Some(20.0), spendy.monthly_budget_usd);
+        assert_eq!(0.75, spendy.spent_month_usd);
+        assert_eq!("$0.75 of $20.00", BudgetRow::summary(0.75, Some(20.0)));
+
+        assert_eq!(1, report.daily.len(), "the 40-day-old row is out of range");
+        let today = &report.daily[0];
+        assert_eq!(Some("spendy"), today.provider_name.as_deref());
+        assert_eq!(1, today.turns);
+        assert_eq!(4, today.llm_calls);
+        assert_eq!(200, today.prompt_tokens);
+        assert_eq!(0.75, today.cost_usd);
+    }
+
     #[sqlx::test]
     async fn test_admin_list_bots_rejects_non_admin(pool: sqlx::PgPool) {
         sqlx::query(
@@ -3681,8 +4120,8 @@ mod tests {
         let server_fns = src.matches(server_fn_needle).count();
         let gates = src.matches(gate_needle).count();
         assert_eq!(
-            server_fns, 16,
-            "expected 16 admin server fns, found {server_fns} - update this test \
+            server_fns, 17,
+            "expected 17 admin server fns, found {server_fns} - update this test \
              deliberately if an admin server fn was added or removed"
         );
         assert_eq!(
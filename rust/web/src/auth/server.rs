@@ -710,6 +710,7 @@ pub struct SettingsData {
     pub turn_emails_enabled: bool,
     pub invite_emails_enabled: bool,
     pub reminder_emails_enabled: bool,
+    pub locale: brdgme_game::i18n::Locale,
 }
 
 #[server(GetSettings, "/api")]
@@ -731,6 +732,10 @@ pub async fn get_settings() -> Result<SettingsData, ServerFnError> {
             .await
             .map_err(internal("get_settings: load email prefs"))?;
 
+    let locale = crate::db::get_user_locale(&pool, user.id)
+        .await
+        .map_err(internal("get_settings: load locale"))?;
+
     Ok(SettingsData {
         // From the DB, not the session-cached AuthUser - the session copy
         // is stale after a rename (see set_username's session refresh).
@@ -742,6 +747,7 @@ pub async fn get_settings() -> Result<SettingsData, ServerFnError> {
         turn_emails_enabled,
         invite_emails_enabled,
         reminder_emails_enabled,
+        locale,
     })
 }
 
@@ -811,6 +817,18 @@ pub async fn set_pref_colors(colors: Vec<String>) -> Result<(), ServerFnError> {
         .map_err(internal("set_pref_colors: update"))
 }
 
+#[server(SetLocale, "/api")]
+pub async fn set_locale(locale: brdgme_game::i18n::Locale) -> Result<(), ServerFnError> {
+    let pool = expect_context::<PgPool>();
+    let user = get_current_user()
+        .await?
+        .ok_or_else(|| ServerFnError::new("Not authenticated"))?;
+
+    crate::db::set_user_locale(&pool, user.id, locale)
+        .await
+        .map_err(internal("set_locale: update"))
+}
+
 #[server(SetEmailTurnEnabled, "/api")]
 pub async fn set_email_turn_enabled(enabled: bool) -> Result<(), ServerFnError> {
     use sqlx::PgPool;
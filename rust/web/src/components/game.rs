@@ -1,5 +1,5 @@
 use crate::game::server_fns::{
-    BumpBotTurns, ConcedeGame, EndGame, ForceDeleteGame, GameViewData, PlayerViewData,
+    BumpBotTurns, ConcedeGame, EndGame, ForceDeleteGame, GameViewData, PlayerViewData, RequestHint,
     SubmitCommand, UndoGame,
 };
 use leptos::prelude::*;
@@ -30,6 +30,7 @@ pub fn GameMeta(data: GameViewData) -> impl IntoView {
     let is_finished = data.is_finished;
     let can_concede = data.can_concede;
     let can_end_game = data.can_end_game;
+    let can_hint = data.can_hint;
     let restarted_game_id = data.restarted_game_id;
     let previous_game_id = data.previous_game_id;
     let restart_proposal_id = data.restart_proposal_id;
@@ -51,6 +52,8 @@ pub fn GameMeta(data: GameViewData) -> impl IntoView {
     let end_game_action = ServerAction::<EndGame>::new();
     let bump_bot_action = ServerAction::<BumpBotTurns>::new();
     let force_delete_action = ServerAction::<ForceDeleteGame>::new();
+    let hint_action = ServerAction::<RequestHint>::new();
+    let command_text = expect_context::<CommandInputText>().0;
 
     // Shared error slot for every mutation on this panel (wfe F52). One slot,
     // not one per action: the prefix names the action that failed. Written
@@ -110,6 +113,17 @@ pub fn GameMeta(data: GameViewData) -> impl IntoView {
         None => {}
     });
 
+    // The hint itself renders from the action's value; only errors go to
+    // the shared slot.
+    Effect::new(move |_| match hint_action.value().get() {
+        Some(Ok(_)) => meta_error.set(None),
+        Some(Err(e)) => meta_error.set(Some(format!(
+            "Hint failed: {}",
+            crate::error::action_error_message(&e)
+        ))),
+        None => {}
+    });
+
     // Navigate away after force delete (spec D3); bump the sidebar trigger so
     // the deleted game drops out of the active-games list. On Err stay put and
     // say so - this is the most destructive action on the page.
@@ -165,6 +179,29 @@ pub fn GameMeta(data: GameViewData) -> impl IntoView {
                                 }>"Undo"</a>
                             </div>
                         </Show>
+                        <Show when=move || can_hint>
+                            <div>
+                                <a href="#" on:click=move |ev| {
+                                    ev.prevent_default();
+                                    if !hint_action.pending().get_untracked() {
+                                        hint_action.dispatch(RequestHint { game_id });
+                                    }
+                                }>{move || if hint_action.pending().get() { "Asking for a hint..." } else { "Hint" }}</a>
+                            </div>
+                            {move || hint_action.value().get().and_then(Result::ok).map(|hint| {
+                                let command = hint.command.clone();
+                                view! {
+                                    <div class="game-hint">
+                                        <div>"Suggested: "<code>{hint.command}</code></div>
+                                        {hint.rationale.map(|r| view! { <div>{r}</div> })}
+                                        <a href="#" on:click=move |ev| {
+                                            ev.prevent_default();
+                                            command_text.set(command.clone());
+                                        }>"Use this command"</a>
+                                    </div>
+                                }
+                            })}
+                        </Show>
                         <Show when=move || can_concede>
                             <div>
                                 <a href="#" on:click=move |ev| {
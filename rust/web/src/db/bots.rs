@@ -128,6 +128,17 @@ pub async fn replacement_bot_available(pool: &PgPool) -> Result<bool> {
     Ok(exists.map(|(b,)| b).unwrap_or(false))
 }
 
+/// The bot that gives hints: the first enabled coach by display order.
+#[cfg(feature = "ssr")]
+pub async fn find_coach_bot(pool: &PgPool) -> Result<Option<String>> {
+    sqlx::query_scalar(
+        "SELECT name FROM bots WHERE coach = true AND enabled = true ORDER BY display_order LIMIT 1",
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| anyhow::anyhow!("find_coach_bot: {e}"))
+}
+
 #[cfg(all(test, feature = "ssr"))]
 mod tests {
     use super::*;
@@ -89,6 +89,18 @@ pub async fn find_game_version_render_meta(
     .map_err(Into::into)
 }
 
+/// Plain query, like `find_game_version_render_meta`, but without the
+/// `is_public` filter: games on retired versions still need translating.
+#[cfg(feature = "ssr")]
+pub async fn find_game_version_interface_version(pool: &PgPool, id: Uuid) -> Result<Option<i32>> {
+    let row: Option<(i32,)> =
+        sqlx::query_as("SELECT interface_version FROM game_versions WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool)
+            .await?;
+    Ok(row.map(|(v,)| v))
+}
+
 #[cfg(feature = "ssr")]
 pub async fn find_available_game_types(
     pool: &PgPool,
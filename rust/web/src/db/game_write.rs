@@ -1052,7 +1052,12 @@ pub async fn update_game_command_success(
 
     if status.is_finished && !status.placings.is_empty() {
         write_ranked_placings(&mut tx, game_id).await?;
+        write_teams_tx(&mut tx, game_id, &status.teams).await?;
+        if let Some(outcome) = &status.cooperative {
+            record_cooperative_score(&mut tx, game_id, outcome).await?;
+        }
         apply_rating_changes(&mut tx, game_id).await?;
+        record_solo_score(&mut tx, game_id).await?;
     }
 
     insert_game_logs_tx(&mut tx, game_id, logs).await?;
@@ -1174,6 +1179,8 @@ mod tests {
                 whose_turn: vec![1], // whose_turn moves to position 1
                 eliminated: vec![0], // position 0 is eliminated
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[3.5, 1.5],
             ge_before.game.updated_at,
@@ -1249,6 +1256,8 @@ mod tests {
                 whose_turn: vec![0], // position 0 stays in turn (mid-turn command)
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[3.5, 1.5],
             ge_before.game.updated_at,
@@ -1293,6 +1302,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings: vec![1, 2], // placings by position
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 5.0],
             ge.game.updated_at,
@@ -1336,6 +1347,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 5.0],
             ge_after.game.updated_at,
@@ -1381,6 +1394,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -1404,6 +1419,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge_after_1.game.updated_at,
@@ -1449,6 +1466,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -1472,6 +1491,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge_after_1.game.updated_at,
@@ -1526,6 +1547,8 @@ mod tests {
                 whose_turn: vec![1],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -1559,6 +1582,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge_after_1.game.updated_at,
@@ -1616,6 +1641,8 @@ mod tests {
             whose_turn: vec![0],
             eliminated: vec![1],
             placings: vec![],
+            teams: vec![],
+            cooperative: None,
         };
         let updated_at: time::PrimitiveDateTime =
             sqlx::query_scalar("SELECT updated_at FROM games WHERE id = $1")
@@ -2446,6 +2473,8 @@ mod tests {
                 whose_turn: vec![1],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -2466,6 +2495,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             p0_id,
@@ -2801,6 +2832,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -2820,6 +2853,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             p0_id,
@@ -2873,6 +2908,8 @@ mod tests {
                 whose_turn: vec![1],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -2896,6 +2933,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge_after_p0.game.updated_at,
@@ -2914,6 +2953,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             p0_id,
@@ -2962,6 +3003,8 @@ mod tests {
                 whose_turn: vec![1],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -2982,6 +3025,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             p0_id,
@@ -3001,6 +3046,8 @@ mod tests {
                 whose_turn: vec![0],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             p0_id,
@@ -3052,6 +3099,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -3114,6 +3163,8 @@ mod tests {
                 whose_turn: vec![1],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -3178,6 +3229,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings: vec![1, 2, 3],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -3794,6 +3847,8 @@ mod tests {
                 whose_turn: vec![p0_pos as usize],
                 eliminated: vec![p1_pos as usize],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 3.0],
             updated_at,
@@ -3849,6 +3904,8 @@ mod tests {
                 whose_turn: vec![p0_pos as usize],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 5.0],
             p0_id,
@@ -4037,6 +4094,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![p1_pos as usize],
                 placings: vec![p0_pos as usize, p1_pos as usize],
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 0.0],
             updated_at,
@@ -4125,6 +4184,8 @@ mod tests {
                 whose_turn: vec![creator_pos as usize],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             updated_at,
@@ -4303,6 +4364,8 @@ mod tests {
             whose_turn: vec![],
             eliminated: vec![creator_pos as usize, a_pos as usize],
             placings: vec![],
+            teams: vec![],
+            cooperative: None,
         };
         let updated_at: time::PrimitiveDateTime =
             sqlx::query_scalar("SELECT updated_at FROM games WHERE id = $1")
@@ -4400,6 +4463,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![bot_pos as usize],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             updated_at,
@@ -4462,6 +4527,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![opp_pos as usize],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             updated_at,
@@ -4516,6 +4583,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 5.0],
             ge.game.updated_at,
@@ -4569,6 +4638,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 5.0],
             ge.game.updated_at,
@@ -4629,6 +4700,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 5.0],
             ge.game.updated_at,
@@ -4690,6 +4763,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![opp_pos as usize],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[10.0, 5.0],
             ge.game.updated_at,
@@ -579,6 +579,28 @@ pub async fn find_game_version_id_for_game(pool: &PgPool, game_id: Uuid) -> Resu
     )
 }
 
+/// The options a game was created with. Plain query to avoid `.sqlx` churn;
+/// the jsonb column is read as text because sqlx's `json` feature is off.
+#[cfg(feature = "ssr")]
+pub async fn find_game_options(
+    executor: impl sqlx::Executor<'_, Database = sqlx::Postgres>,
+    game_id: Uuid,
+) -> Result<brdgme_game::GameOptions> {
+    let raw: Option<String> = sqlx::query_scalar("SELECT options::text FROM games WHERE id = $1")
+        .bind(game_id)
+        .fetch_optional(executor)
+        .await?;
+    parse_options(raw)
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn parse_options(raw: Option<String>) -> Result<brdgme_game::GameOptions> {
+    match raw {
+        Some(raw) => Ok(serde_json::from_str(&raw)?),
+        None => Ok(brdgme_game::GameOptions::new()),
+    }
+}
+
 #[cfg(all(test, feature = "ssr"))]
 mod tests {
     use super::*;
@@ -38,6 +38,8 @@
 //!   and the command-success update.
 //! - `bots` - bot turn lookups and replacement-bot selection.
 //! - `rating` - ELO math and the ranked-placing / rating-change writes.
+//! - `solo` - solo leaderboard scores and daily challenge seeds.
+//! - `outcomes` - team assignments and cooperative game scores.
 //! - `users` - user reads, name/theme/pref/email-pref settings, and presence.
 //! - `emails` - multiple-emails-per-account (`#22d`) reads and writes.
 //! - `social` - friends and blocks (`#30`), opponent and game suggestions.
@@ -69,9 +71,11 @@ mod emails;
 mod game_types;
 mod game_write;
 mod games;
+mod outcomes;
 mod proposals;
 mod rating;
 mod social;
+mod solo;
 mod users;
 mod visibility;
 
@@ -85,9 +89,11 @@ pub use emails::*;
 pub use game_types::*;
 pub use game_write::*;
 pub use games::*;
+pub use outcomes::*;
 pub use proposals::*;
 pub(crate) use rating::*;
 pub use social::*;
+pub use solo::*;
 pub use users::*;
 pub use visibility::*;
 
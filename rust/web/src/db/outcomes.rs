@@ -0,0 +1,147 @@
+#[cfg(feature = "ssr")]
+use anyhow::Result;
+#[cfg(feature = "ssr")]
+use uuid::Uuid;
+
+/// Stamps `game_players.team` from a finished status' `teams` (0-based team
+/// per position). A no-op for games without teams. Must run before
+/// `apply_rating_changes`, which rates team games team against team.
+#[cfg(feature = "ssr")]
+pub(crate) async fn write_teams_tx(
+    tx: &mut sqlx::PgConnection,
+    game_id: Uuid,
+    teams: &[usize],
+) -> Result<()> {
+    for (position, &team) in teams.iter().enumerate() {
+        sqlx::query("UPDATE game_players SET team = $1 WHERE game_id = $2 AND position = $3")
+            .bind(team as i32)
+            .bind(game_id)
+            .bind(position as i32)
+            .execute(&mut *tx)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Records the shared result of a cooperative game that finished naturally.
+/// Its presence is what keeps `apply_rating_changes` from rating the game, so
+/// it must run first in the same transaction. Idempotent on `game_id`.
+#[cfg(feature = "ssr")]
+pub(crate) async fn record_cooperative_score(
+    tx: &mut sqlx::PgConnection,
+    game_id: Uuid,
+    outcome: &brdgme_game::CooperativeOutcome,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO cooperative_scores (game_id, game_type_id, won, score)
+         SELECT g.id, gv.game_type_id, $2, $3
+         FROM games g
+         JOIN game_versions gv ON gv.id = g.game_version_id
+         WHERE g.id = $1
+         ON CONFLICT (game_id) DO NOTHING",
+    )
+    .bind(game_id)
+    .bind(outcome.won)
+    .bind(outcome.score as f32)
+    .execute(&mut *tx)
+    .await?;
+    Ok(())
+}
+
+/// The shared result of a cooperative game, if it finished with one.
+#[cfg(feature = "ssr")]
+pub async fn find_cooperative_score(
+    pool: &sqlx::PgPool,
+    game_id: Uuid,
+) -> Result<Option<brdgme_game::CooperativeOutcome>> {
+    let row: Option<(bool, f32)> =
+        sqlx::query_as("SELECT won, score FROM cooperative_scores WHERE game_id = $1")
+            .bind(game_id)
+            .fetch_optional(pool)
+            .await?;
+    Ok(row.map(|(won, score)| brdgme_game::CooperativeOutcome {
+        won,
+        score: score as i32,
+    }))
+}
+
+/// Team per position for a finished team game; empty for games without teams.
+#[cfg(feature = "ssr")]
+pub async fn find_game_teams(
+    pool: &sqlx::PgPool,
+    game_id: Uuid,
+) -> Result<std::collections::HashMap<i32, i32>> {
+    let rows: Vec<(i32, i32)> = sqlx::query_as(
+        "SELECT position, team FROM game_players WHERE game_id = $1 AND team IS NOT NULL",
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().collect())
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::db::test_support::*;
+    use sqlx::postgres::PgPool;
+
+    #[sqlx::test]
+    async fn write_teams_tx_stamps_each_position(pool: PgPool) {
+        let creator = make_user(&pool, "creator").await;
+        let opp = make_user(&pool, "opp").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let game =
+            make_game_with_players(&pool, game_version_id, creator.id, &[opp.id], 2, &[0]).await;
+
+        let mut tx = pool.begin().await.unwrap();
+        write_teams_tx(&mut tx, game.id, &[0, 1, 0, 1])
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+
+        let teams: Vec<Option<i32>> = sqlx::query_scalar(
+            "SELECT team FROM game_players WHERE game_id = $1 ORDER BY position",
+        )
+        .bind(game.id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(vec![Some(0), Some(1), Some(0), Some(1)], teams);
+        assert_eq!(
+            Some(&1),
+            find_game_teams(&pool, game.id).await.unwrap().get(&3)
+        );
+    }
+
+    #[sqlx::test]
+    async fn record_cooperative_score_records_once(pool: PgPool) {
+        let creator = make_user(&pool, "creator").await;
+        let opp = make_user(&pool, "opp").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let game =
+            make_game_with_players(&pool, game_version_id, creator.id, &[opp.id], 0, &[0]).await;
+
+        let mut tx = pool.begin().await.unwrap();
+        let lost = brdgme_game::CooperativeOutcome {
+            won: false,
+            score: 17,
+        };
+        record_cooperative_score(&mut tx, game.id, &lost)
+            .await
+            .unwrap();
+        let won = brdgme_game::CooperativeOutcome {
+            won: true,
+            score: 25,
+        };
+        record_cooperative_score(&mut tx, game.id, &won)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+
+        assert_eq!(
+            Some(lost),
+            find_cooperative_score(&pool, game.id).await.unwrap()
+        );
+    }
+}
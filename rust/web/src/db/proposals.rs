@@ -55,6 +55,20 @@ pub async fn is_proposal_visible_to_user(
     .await?)
 }
 
+/// The options chosen for a proposal's game, see `find_game_options`.
+#[cfg(feature = "ssr")]
+pub async fn find_proposal_options(
+    executor: impl sqlx::Executor<'_, Database = sqlx::Postgres>,
+    proposal_id: Uuid,
+) -> Result<brdgme_game::GameOptions> {
+    let raw: Option<String> =
+        sqlx::query_scalar("SELECT options::text FROM game_proposals WHERE id = $1")
+            .bind(proposal_id)
+            .fetch_optional(executor)
+            .await?;
+    super::parse_options(raw)
+}
+
 #[cfg(all(test, feature = "ssr"))]
 mod tests {
     use super::*;
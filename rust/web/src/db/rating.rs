@@ -77,6 +77,13 @@ pub(crate) async fn write_ranked_placings(
 /// count, the ELO pairs, and the rating stamps; replaced humans (still
 /// carrying a `user_id`) participate. `ranked_placing` is the only ranking
 /// source - `place`, points, position, and departure fields are never used.
+///
+/// Cooperative games (a `cooperative_scores` row, written first by the
+/// command-success path) are never rated; they have a score leaderboard
+/// instead. Team games (`game_players.team` set) are rated team against
+/// team: each side plays at its humans' average rating and best ranked
+/// placing, and every member takes the side's full change. Fewer than two
+/// sides with humans is ineligible, like fewer than two humans.
 #[cfg(feature = "ssr")]
 pub(crate) async fn apply_rating_changes(tx: &mut sqlx::PgConnection, game_id: Uuid) -> Result<()> {
     #[derive(sqlx::FromRow)]
@@ -86,12 +93,13 @@ pub(crate) async fn apply_rating_changes(tx: &mut sqlx::PgConnection, game_id: U
         user_id: Option<Uuid>,
         ranked_placing: Option<i32>,
         rating_change: Option<i32>,
+        team: Option<i32>,
     }
 
     // Plain (non-macro) query like `write_ranked_placings`, so the committed
     // offline `.sqlx` cache needs no regeneration for this changed SELECT.
     let players = sqlx::query_as::<_, PlayerRow>(
-        "SELECT id, position, user_id, ranked_placing, rating_change FROM game_players WHERE game_id = $1",
+        "SELECT id, position, user_id, ranked_placing, rating_change, team FROM game_players WHERE game_id = $1",
     )
     .bind(game_id)
     .fetch_all(&mut *tx)
@@ -110,6 +118,22 @@ pub(crate) async fn apply_rating_changes(tx: &mut sqlx::PgConnection, game_id: U
     if humans.len() < 2 || humans.iter().any(|p| p.ranked_placing.is_none()) {
         return Ok(());
     }
+    let is_cooperative: bool =
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM cooperative_scores WHERE game_id = $1)")
+            .bind(game_id)
+            .fetch_one(&mut *tx)
+            .await?;
+    if is_cooperative {
+        return Ok(());
+    }
+    // A side is a team, or a lone player when the game has no teams.
+    let side_of = |p: &PlayerRow| p.team.map_or(-1 - p.position, |team| team);
+    let mut sides: Vec<i32> = humans.iter().map(|p| side_of(p)).collect();
+    sides.sort_unstable();
+    sides.dedup();
+    if sides.len() < 2 {
+        return Ok(());
+    }
 
     let game_type_id = sqlx::query_scalar!(
         r#"
@@ -128,6 +152,7 @@ pub(crate) async fn apply_rating_changes(tx: &mut sqlx::PgConnection, game_id: U
         position: i32,
         user_id: Uuid,
         rating: i32,
+        side: i32,
     }
 
     let mut rated_players = Vec::with_capacity(humans.len());
@@ -157,6 +182,7 @@ pub(crate) async fn apply_rating_changes(tx: &mut sqlx::PgConnection, game_id: U
             position: p.position,
             user_id,
             rating,
+            side: side_of(p),
         });
     }
 
@@ -175,34 +201,51 @@ pub(crate) async fn apply_rating_changes(tx: &mut sqlx::PgConnection, game_id: U
         places.insert(p.position, ranked_placing);
     }
 
-    let mut rating_changes: std::collections::HashMap<i32, i32> = std::collections::HashMap::new();
-    // Each unordered pair exactly once: index `i` against the tail slice.
-    // (Was `.take(len - 1).enumerate()` + `.skip(a_index + 1)`; the `take` was
-    // redundant because the last index yields an empty tail - ws F50.)
-    for (i, a) in rated_players.iter().enumerate() {
-        for b in &rated_players[i + 1..] {
-            let a_place = places.get(&a.position).copied().ok_or_else(|| {
-                anyhow::anyhow!(
-                    "rated player position {}: missing ranked placing",
-                    a.position
-                )
-            })?;
-            let b_place = places.get(&b.position).copied().ok_or_else(|| {
+    // One rated side per team, or per player when the game has no teams, at
+    // its members' average rating and best ranked placing.
+    struct RatedSide {
+        id: i32,
+        rating: i32,
+        place: i32,
+    }
+    let mut rated_sides: Vec<RatedSide> = Vec::with_capacity(sides.len());
+    for &id in &sides {
+        let members: Vec<&RatedPlayer> = rated_players.iter().filter(|p| p.side == id).collect();
+        let rating = (members.iter().map(|p| p.rating as f32).sum::<f32>() / members.len() as f32)
+            .round() as i32;
+        let mut place = i32::MAX;
+        for m in &members {
+            let m_place = places.get(&m.position).copied().ok_or_else(|| {
                 anyhow::anyhow!(
                     "rated player position {}: missing ranked placing",
-                    b.position
+                    m.position
                 )
             })?;
-            let a_score: f32 = match a_place.cmp(&b_place) {
+            place = place.min(m_place);
+        }
+        rated_sides.push(RatedSide { id, rating, place });
+    }
+
+    let mut side_changes: std::collections::HashMap<i32, i32> = std::collections::HashMap::new();
+    // Each unordered pair exactly once: index `i` against the tail slice.
+    // (Was `.take(len - 1).enumerate()` + `.skip(a_index + 1)`; the `take` was
+    // redundant because the last index yields an empty tail - ws F50.)
+    for (i, a) in rated_sides.iter().enumerate() {
+        for b in &rated_sides[i + 1..] {
+            let a_score: f32 = match a.place.cmp(&b.place) {
                 std::cmp::Ordering::Less => 1.0,
                 std::cmp::Ordering::Equal => 0.5,
                 std::cmp::Ordering::Greater => 0.0,
             };
             let change = elo_rating_change(a.rating, b.rating, a_score);
-            *rating_changes.entry(a.position).or_insert(0) += change;
-            *rating_changes.entry(b.position).or_insert(0) -= change;
+            *side_changes.entry(a.id).or_insert(0) += change;
+            *side_changes.entry(b.id).or_insert(0) -= change;
         }
     }
+    let rating_changes: std::collections::HashMap<i32, i32> = rated_players
+        .iter()
+        .map(|p| (p.position, side_changes.get(&p.side).copied().unwrap_or(0)))
+        .collect();
 
     for p in &rated_players {
         let change = rating_changes.get(&p.position).copied().unwrap_or(0);
@@ -433,6 +476,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -456,6 +501,125 @@ mod tests {
         assert_eq!(loser_peak, 1200);
     }
 
+    #[sqlx::test]
+    async fn finishing_a_team_game_rates_team_against_team(pool: PgPool) {
+        let creator = make_user(&pool, "creator").await;
+        let partner = make_user(&pool, "partner").await;
+        let opp1 = make_user(&pool, "opp1").await;
+        let opp2 = make_user(&pool, "opp2").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let game = make_game_with_players(
+            &pool,
+            game_version_id,
+            creator.id,
+            &[partner.id, opp1.id, opp2.id],
+            0,
+            &[0],
+        )
+        .await;
+        let ge = find_game_extended(&pool, game.id).await.unwrap().unwrap();
+        let played_player_id = ge.game_players[0].game_player.id;
+        let winners = [
+            position_of(&ge, creator.id) as usize,
+            position_of(&ge, partner.id) as usize,
+        ];
+
+        // creator and partner are team 0 and share 1st; opp1 and opp2 share 2nd.
+        let mut placings = vec![2usize; 4];
+        let mut teams = vec![1usize; 4];
+        for &w in &winners {
+            placings[w] = 1;
+            teams[w] = 0;
+        }
+
+        update_game_command_success(
+            &pool,
+            game.id,
+            played_player_id,
+            "prev_state",
+            "final_state",
+            false,
+            &StatusUpdate {
+                is_finished: true,
+                whose_turn: vec![],
+                eliminated: vec![],
+                placings,
+                teams,
+                cooperative: None,
+            },
+            &[],
+            ge.game.updated_at,
+            vec![],
+        )
+        .await
+        .unwrap();
+
+        // One 1200-vs-1200 team pairing, not four individual pairings: every
+        // member takes the full team change and teammates never rate each other.
+        for pos in 0..4 {
+            let expected = if winners.contains(&(pos as usize)) {
+                16
+            } else {
+                -16
+            };
+            assert_eq!(
+                Some(expected),
+                find_rating_change(&pool, game.id, pos).await
+            );
+        }
+    }
+
+    #[sqlx::test]
+    async fn finishing_a_cooperative_game_is_scored_not_rated(pool: PgPool) {
+        let creator = make_user(&pool, "creator").await;
+        let opponent = make_user(&pool, "opponent").await;
+        let (game_type_id, game_version_id) = make_game_type_and_version(&pool).await;
+        let game =
+            make_game_with_players(&pool, game_version_id, creator.id, &[opponent.id], 0, &[0])
+                .await;
+        let ge = find_game_extended(&pool, game.id).await.unwrap().unwrap();
+        let played_player_id = ge.game_players[0].game_player.id;
+
+        update_game_command_success(
+            &pool,
+            game.id,
+            played_player_id,
+            "prev_state",
+            "final_state",
+            false,
+            &StatusUpdate {
+                is_finished: true,
+                whose_turn: vec![],
+                eliminated: vec![],
+                placings: vec![1, 1],
+                teams: vec![],
+                cooperative: Some(brdgme_game::CooperativeOutcome {
+                    won: true,
+                    score: 25,
+                }),
+            },
+            &[],
+            ge.game.updated_at,
+            vec![],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(None, find_rating_change(&pool, game.id, 0).await);
+        assert_eq!(None, find_rating_change(&pool, game.id, 1).await);
+        assert_eq!(
+            (1200, 1200),
+            game_type_rating(&pool, game_type_id, creator.id).await
+        );
+        assert_eq!(
+            Some(brdgme_game::CooperativeOutcome {
+                won: true,
+                score: 25
+            }),
+            find_cooperative_score(&pool, game.id).await.unwrap()
+        );
+    }
+
     #[sqlx::test]
     async fn finishing_a_three_player_game_rates_all_pairs(pool: PgPool) {
         let creator = make_user(&pool, "creator").await;
@@ -489,6 +653,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -535,6 +701,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings: vec![1, 2],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -560,6 +728,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings: vec![1, 2],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge_after_first.game.updated_at,
@@ -598,6 +768,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings: vec![1, 2],
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -652,6 +824,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -715,6 +889,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -841,6 +1017,8 @@ mod tests {
                 whose_turn: vec![],
                 eliminated: vec![],
                 placings,
+                teams: vec![],
+                cooperative: None,
             },
             &[],
             ge.game.updated_at,
@@ -0,0 +1,241 @@
+#[cfg(feature = "ssr")]
+use anyhow::Result;
+#[cfg(feature = "ssr")]
+use sqlx::postgres::PgPool;
+#[cfg(feature = "ssr")]
+use uuid::Uuid;
+
+/// The seed for a game type's daily challenge on `date` (`YYYY-MM-DD`,
+/// UTC). Every player gets the same deal, but the seed is keyed with the
+/// server's `secret` so nobody can rebuild the day's RNG and rehearse it
+/// offline before playing.
+#[cfg(feature = "ssr")]
+pub fn daily_seed(secret: &[u8; 32], game_type_id: Uuid, date: &str) -> u64 {
+    let data = [game_type_id.as_bytes().as_slice(), date.as_bytes()].concat();
+    let hash = crate::crypto::keyed_hash(secret, "daily-seed", &data);
+    u64::from_le_bytes(hash[..8].try_into().expect("hash is 32 bytes"))
+}
+
+/// Today's UTC date as `YYYY-MM-DD`, the daily challenge key.
+#[cfg(feature = "ssr")]
+pub fn daily_date_today() -> String {
+    time::OffsetDateTime::now_utc().date().to_string()
+}
+
+/// The user's daily challenge game for a game type on `date`, if they have
+/// already started one. Each player gets one attempt per day.
+#[cfg(feature = "ssr")]
+pub async fn find_daily_game(
+    pool: &PgPool,
+    user_id: Uuid,
+    game_type_id: Uuid,
+    date: &str,
+) -> Result<Option<Uuid>> {
+    Ok(sqlx::query_scalar(
+        "SELECT game_id FROM daily_challenges
+         WHERE user_id = $1 AND game_type_id = $2 AND daily_date = $3::date",
+    )
+    .bind(user_id)
+    .bind(game_type_id)
+    .bind(date)
+    .fetch_optional(pool)
+    .await?)
+}
+
+/// Marks `game_id` as the user's daily challenge for a game type on `date`.
+/// Returns `false` when they already have one: a concurrent start waits on
+/// the claim's primary key and loses, and its transaction should be dropped.
+#[cfg(feature = "ssr")]
+pub async fn claim_daily_game_tx(
+    tx: &mut sqlx::PgConnection,
+    user_id: Uuid,
+    game_type_id: Uuid,
+    game_id: Uuid,
+    date: &str,
+) -> Result<bool> {
+    let claimed = sqlx::query(
+        "INSERT INTO daily_challenges (user_id, game_type_id, daily_date, game_id)
+         VALUES ($1, $2, $3::date, $4)
+         ON CONFLICT DO NOTHING",
+    )
+    .bind(user_id)
+    .bind(game_type_id)
+    .bind(date)
+    .bind(game_id)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected()
+        == 1;
+    if claimed {
+        sqlx::query("UPDATE games SET daily_date = $2::date WHERE id = $1")
+            .bind(game_id)
+            .bind(date)
+            .execute(&mut *tx)
+            .await?;
+    }
+    Ok(claimed)
+}
+
+/// Records the score of a game that finished naturally if it was a solo game:
+/// exactly one seat, held by a human. Solo games never reach Elo
+/// (`apply_rating_changes` needs two humans), so this is their only result.
+/// Idempotent on `game_id`. Conceded or stopped games are not recorded.
+#[cfg(feature = "ssr")]
+pub(crate) async fn record_solo_score(tx: &mut sqlx::PgConnection, game_id: Uuid) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO solo_scores (game_id, game_type_id, user_id, score, daily_date)
+         SELECT g.id, gv.game_type_id, gp.user_id, COALESCE(gp.points, 0), g.daily_date
+         FROM games g
+         JOIN game_versions gv ON gv.id = g.game_version_id
+         JOIN game_players gp ON gp.game_id = g.id
+         WHERE g.id = $1 AND gp.user_id IS NOT NULL
+           AND (SELECT count(*) FROM game_players WHERE game_id = g.id) = 1
+         ON CONFLICT (game_id) DO NOTHING",
+    )
+    .bind(game_id)
+    .execute(&mut *tx)
+    .await?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::db::test_support::*;
+
+    async fn finish_with_points(pool: &PgPool, game_id: Uuid, points: f32) {
+        sqlx::query("UPDATE games SET is_finished = true WHERE id = $1")
+            .bind(game_id)
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE game_players SET points = $2 WHERE game_id = $1")
+            .bind(game_id)
+            .bind(points)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    async fn solo_scores(pool: &PgPool) -> Vec<(Uuid, f32, Option<String>)> {
+        sqlx::query_as("SELECT game_id, score, daily_date::text FROM solo_scores")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn daily_seed_is_shared_per_day_and_type() {
+        let secret = [7; 32];
+        let a = Uuid::from_u128(1);
+        let b = Uuid::from_u128(2);
+        assert_eq!(
+            daily_seed(&secret, a, "2026-10-19"),
+            daily_seed(&secret, a, "2026-10-19")
+        );
+        assert_ne!(
+            daily_seed(&secret, a, "2026-10-19"),
+            daily_seed(&secret, a, "2026-10-20")
+        );
+        assert_ne!(
+            daily_seed(&secret, a, "2026-10-19"),
+            daily_seed(&secret, b, "2026-10-19")
+        );
+    }
+
+    #[test]
+    fn daily_seed_depends_on_the_server_secret() {
+        let a = Uuid::from_u128(1);
+        assert_ne!(
+            daily_seed(&[7; 32], a, "2026-10-19"),
+            daily_seed(&[8; 32], a, "2026-10-19")
+        );
+    }
+
+    #[sqlx::test]
+    async fn record_solo_score_records_one_player_games_once(pool: PgPool) {
+        let user = make_user(&pool, "solo").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let game = make_game_with_players(&pool, game_version_id, user.id, &[], 0, &[0]).await;
+        finish_with_points(&pool, game.id, 42.0).await;
+
+        let game_type_id = game_type_of(&pool, game.id).await;
+        let mut tx = pool.begin().await.unwrap();
+        assert!(
+            claim_daily_game_tx(&mut tx, user.id, game_type_id, game.id, "2026-10-19")
+                .await
+                .unwrap()
+        );
+        record_solo_score(&mut tx, game.id).await.unwrap();
+        record_solo_score(&mut tx, game.id).await.unwrap();
+        tx.commit().await.unwrap();
+
+        assert_eq!(
+            vec![(game.id, 42.0, Some("2026-10-19".to_string()))],
+            solo_scores(&pool).await
+        );
+        assert_eq!(
+            Some(game.id),
+            find_daily_game(&pool, user.id, game_type_id, "2026-10-19")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[sqlx::test]
+    async fn claim_daily_game_allows_one_game_per_user_type_and_day(pool: PgPool) {
+        let user = make_user(&pool, "solo").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let first = make_game_with_players(&pool, game_version_id, user.id, &[], 0, &[0]).await;
+        let second = make_game_with_players(&pool, game_version_id, user.id, &[], 0, &[0]).await;
+        let game_type_id = game_type_of(&pool, first.id).await;
+
+        let mut conn = pool.acquire().await.unwrap();
+        assert!(
+            claim_daily_game_tx(&mut conn, user.id, game_type_id, first.id, "2026-10-19")
+                .await
+                .unwrap()
+        );
+        assert!(
+            !claim_daily_game_tx(&mut conn, user.id, game_type_id, second.id, "2026-10-19")
+                .await
+                .unwrap()
+        );
+        assert!(
+            claim_daily_game_tx(&mut conn, user.id, game_type_id, second.id, "2026-10-20")
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Some(first.id),
+            find_daily_game(&pool, user.id, game_type_id, "2026-10-19")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[sqlx::test]
+    async fn record_solo_score_skips_multiplayer_games(pool: PgPool) {
+        let user = make_user(&pool, "host").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let game = make_game_with_players(&pool, game_version_id, user.id, &[], 1, &[0]).await;
+        finish_with_points(&pool, game.id, 42.0).await;
+
+        let mut tx = pool.begin().await.unwrap();
+        record_solo_score(&mut tx, game.id).await.unwrap();
+        tx.commit().await.unwrap();
+
+        assert!(solo_scores(&pool).await.is_empty());
+    }
+
+    async fn game_type_of(pool: &PgPool, game_id: Uuid) -> Uuid {
+        sqlx::query_scalar(
+            "SELECT gv.game_type_id FROM games g
+             JOIN game_versions gv ON gv.id = g.game_version_id WHERE g.id = $1",
+        )
+        .bind(game_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+}
@@ -293,6 +293,31 @@ pub async fn get_user_email_prefs(pool: &PgPool, user_id: Uuid) -> Result<(bool,
     Ok(row)
 }
 
+/// A stored code this build no longer knows reads as English rather than
+/// failing the page.
+#[cfg(feature = "ssr")]
+pub async fn get_user_locale(pool: &PgPool, user_id: Uuid) -> Result<brdgme_game::i18n::Locale> {
+    let (code,): (String,) = sqlx::query_as("SELECT locale FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+    Ok(code.parse().unwrap_or_default())
+}
+
+#[cfg(feature = "ssr")]
+pub async fn set_user_locale(
+    pool: &PgPool,
+    user_id: Uuid,
+    locale: brdgme_game::i18n::Locale,
+) -> Result<()> {
+    sqlx::query("UPDATE users SET locale = $1 WHERE id = $2")
+        .bind(locale.code())
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
 #[cfg(feature = "ssr")]
 pub async fn set_user_turn_emails_enabled(
     pool: &PgPool,
@@ -593,6 +618,31 @@ mod tests {
         );
     }
 
+    #[sqlx::test]
+    async fn user_locale_defaults_to_english_and_round_trips(pool: PgPool) {
+        use brdgme_game::i18n::Locale;
+
+        let user_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO users (name, pref_colors) VALUES ($1, $2) RETURNING id",
+        )
+        .bind(format!("u-{}", Uuid::new_v4()))
+        .bind(Vec::<String>::new())
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(get_user_locale(&pool, user_id).await.unwrap(), Locale::En);
+        set_user_locale(&pool, user_id, Locale::De).await.unwrap();
+        assert_eq!(get_user_locale(&pool, user_id).await.unwrap(), Locale::De);
+
+        sqlx::query("UPDATE users SET locale = 'xx' WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(get_user_locale(&pool, user_id).await.unwrap(), Locale::En);
+    }
+
     /// ws F35 + ws F45: `is_user_admin` had no test at all, and now returns
     /// `anyhow::Result`. Covers all three outcomes including the fail-closed
     /// unknown-user case.
@@ -187,6 +187,7 @@ pub fn help_text() -> String {
      concede - concede the current game\n\
      end - end the current game (only when you are the last human)\n\
      undo - undo your last move\n\
+     hint - ask the coach bot to suggest a move without playing it (unrated games only)\n\
      bump - re-send all games waiting on your turn to your active address\n\
      restart - restart a finished game\n\
      rules [basic|advanced] - email the game rules and strategy\n\
@@ -449,6 +450,8 @@ async fn run_new_command(
         ctx.http_client,
         &game_version,
         player_count,
+        &brdgme_game::GameOptions::new(),
+        None,
     )
     .await
     .map_err(|e| CommandError::Internal(anyhow::anyhow!("new: fetch game: {e}")))?;
@@ -821,6 +824,28 @@ async fn run_undo(ctx: &EmailCommandCtx<'_>) -> Result<CommandReply, CommandErro
     Ok(CommandReply::Status("Undo applied.".to_string()))
 }
 
+pub fn format_hint(hint: &crate::game::server_fns::HintView) -> String {
+    let mut out = format!("Suggested command: {}", hint.command);
+    if let Some(rationale) = &hint.rationale {
+        out.push_str(&format!("\n\n{}", rationale));
+    }
+    out.push_str("\n\nNothing has been played. Reply with the command to play it.");
+    out
+}
+
+async fn run_hint(ctx: &EmailCommandCtx<'_>) -> Result<CommandReply, CommandError> {
+    let hint = crate::game::hint::hint_core(
+        ctx.pool,
+        ctx.jetstream,
+        ctx.game_id,
+        crate::game::server_fns::ActingPlayer::GamePlayer(ctx.game_player_id),
+    )
+    .await
+    .map_err(|e| classify_server_fn_error("hint", e))?;
+
+    Ok(CommandReply::Status(format_hint(&hint)))
+}
+
 async fn run_restart(ctx: &EmailCommandCtx<'_>) -> Result<CommandReply, CommandError> {
     use crate::game::server_fns::{BotSlot, RestartOutcome, restart_core};
 
@@ -1001,6 +1026,7 @@ pub async fn dispatch_email_command(
         "concede" => return run_concede(ctx).await,
         "end" => return run_end(ctx).await,
         "undo" => return run_undo(ctx).await,
+        "hint" => return run_hint(ctx).await,
         "restart" => return run_restart(ctx).await,
         "rules" => return run_rules(ctx, parse_rules_arg(arg)).await,
         "help" | "commands" => return Ok(CommandReply::Status(help_text())),
@@ -1098,6 +1124,7 @@ mod tests {
         let text = help_text();
         assert!(text.contains("concede"));
         assert!(text.contains("undo"));
+        assert!(text.contains("hint"));
         assert!(text.contains("restart"));
         assert!(text.contains("subscribe"));
         assert!(text.contains("unsubscribe"));
@@ -1106,6 +1133,20 @@ mod tests {
         assert!(text.contains("Settings commands"));
     }
 
+    #[test]
+    fn format_hint_shows_command_and_rationale() {
+        let mut hint = crate::game::server_fns::HintView {
+            command: "play A1".to_string(),
+            rationale: Some("It founds a chain.".to_string()),
+        };
+        let text = format_hint(&hint);
+        assert!(text.starts_with("Suggested command: play A1\n\nIt founds a chain."));
+        assert!(text.contains("Nothing has been played"));
+
+        hint.rationale = None;
+        assert!(format_hint(&hint).starts_with("Suggested command: play A1\n\nNothing"));
+    }
+
     #[test]
     fn help_text_is_nonempty() {
         assert!(!help_text().is_empty());
@@ -1689,6 +1730,7 @@ mod tests {
             "concede",
             "end",
             "undo",
+            "hint",
             "bump",
             "restart",
             "rules",
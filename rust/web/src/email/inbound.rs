@@ -1095,6 +1095,7 @@ async fn handle_invite_reply(
         crate::models::game::GameVersion,
         Vec<crate::proposals::ProposalPlayer>,
         usize,
+        brdgme_game::GameOptions,
     )> = None;
 
     if accept {
@@ -1122,8 +1123,12 @@ async fn handle_invite_reply(
                     Ok(p) => p,
                     Err(e) => return transient_failure(e, "invite roster lookup failed"),
                 };
+            let options = match crate::db::find_proposal_options(&mut *tx, proposal_id).await {
+                Ok(o) => o,
+                Err(e) => return transient_failure(e, "invite options lookup failed"),
+            };
             let accepted_count = roster.iter().filter(|p| p.response == "accepted").count();
-            start_inputs = Some((game_version, roster, accepted_count));
+            start_inputs = Some((game_version, roster, accepted_count, options));
         }
     }
 
@@ -1139,12 +1144,14 @@ async fn handle_invite_reply(
     // as "already responded", so it could not re-attempt the start.
     let mut started_game_id: Option<uuid::Uuid> = None;
 
-    if let Some((game_version, roster_snapshot, accepted_count)) = start_inputs {
+    if let Some((game_version, roster_snapshot, accepted_count, options)) = start_inputs {
         // External game-service call: NO transaction open, NO row lock held.
         let fetched = match crate::game::server_fns::fetch_game_from_service(
             &state.http_client,
             &game_version,
             accepted_count,
+            &options,
+            None,
         )
         .await
         {
@@ -1421,10 +1428,14 @@ async fn send_game_reply_response(
             return;
         }
     };
+    let catalog =
+        crate::email::notify::recipient_catalog(pool, &state.http_client, &ge, recipient_player)
+            .await;
     let (board, you_can) = crate::email::notify::render_board_and_you_can(
         &state.http_client,
         &ge,
         player.position as usize,
+        catalog.as_deref(),
     )
     .await;
     let content = crate::email::render::EmailContent {
@@ -61,6 +61,34 @@ pub fn finished_header_text(winners: &[(String, Option<i32>)]) -> String {
     format!("Game over. Winners: {}", results.join(", "))
 }
 
+/// A 0-based team and its members' names and rating changes.
+pub type TeamResult = (usize, Vec<(String, Option<i32>)>);
+
+/// The game-over header for a team game: each team's members in placing
+/// order, teams numbered from 1 as in the game log.
+pub fn team_finished_header_text(teams: &[TeamResult]) -> String {
+    let results: Vec<String> = teams
+        .iter()
+        .map(|(team, members)| {
+            let members: Vec<String> = members
+                .iter()
+                .map(|(name, rc)| format_player_result(name, *rc))
+                .collect();
+            format!("Team {}: {}", team + 1, members.join(", "))
+        })
+        .collect();
+    format!("Game over. Winners: {}", results.join("; "))
+}
+
+/// The game-over header for a cooperative game, which is scored, not rated.
+pub fn cooperative_header_text(outcome: &brdgme_game::CooperativeOutcome) -> String {
+    format!(
+        "Game over. Everyone {} with a score of {}.",
+        if outcome.won { "won" } else { "lost" },
+        outcome.score
+    )
+}
+
 pub fn browser_url(game_id: uuid::Uuid) -> String {
     let base = crate::config::public_base_url();
     format!("{base}/games/{game_id}")
@@ -93,13 +121,33 @@ pub fn turn_subject(game_type_name: &str, game_id: uuid::Uuid, turn: i64) -> Str
     format!("{game_type_name} {game_id}-{turn}")
 }
 
+/// The catalog to write `recipient_player`'s emails in, or `None` for English.
+/// Best-effort like the rest of the email: a failed locale lookup sends English.
+pub async fn recipient_catalog(
+    pool: &sqlx::PgPool,
+    http_client: &reqwest::Client,
+    ge: &crate::db::GameExtended,
+    recipient_player: &crate::db::GamePlayerExtended,
+) -> Option<std::sync::Arc<brdgme_game::i18n::Catalog>> {
+    let user = recipient_player.user.as_ref()?;
+    let locale = match crate::db::get_user_locale(pool, user.id).await {
+        Ok(locale) => locale,
+        Err(e) => {
+            tracing::warn!("Failed to load locale for user {}: {}", user.id, e);
+            return None;
+        }
+    };
+    crate::game::catalog::catalog_for(pool, http_client, &ge.game_version, locale).await
+}
+
 /// Renders the board markup + "You can" command usages for `position`'s view of
-/// `ge`, best-effort: a failed game-service render degrades to absent blocks
-/// rather than failing the caller.
+/// `ge`, translated with `catalog`, best-effort: a failed game-service render
+/// degrades to absent blocks rather than failing the caller.
 pub async fn render_board_and_you_can(
     http_client: &reqwest::Client,
     ge: &crate::db::GameExtended,
     position: usize,
+    catalog: Option<&brdgme_game::i18n::Catalog>,
 ) -> (Option<String>, Option<Vec<String>>) {
     let render_resp = crate::game::client::render(
         http_client,
@@ -113,13 +161,17 @@ pub async fn render_board_and_you_can(
         Ok(resp) => {
             let you_can = resp.command_spec.as_ref().map(|spec| {
                 let nodes = brdgme_game::command::doc::render(&spec.doc());
-                let s = brdgme_markup::to_string(&nodes);
+                let s = crate::game::catalog::translate_markup(
+                    catalog,
+                    &brdgme_markup::to_string(&nodes),
+                );
                 s.split('\n')
                     .filter(|l| !l.is_empty())
                     .map(String::from)
                     .collect()
             });
-            (Some(resp.render), you_can)
+            let board = crate::game::catalog::translate_markup(catalog, &resp.render);
+            (Some(board), you_can)
         }
         Err(e) => {
             tracing::error!("Failed to render game {}: {}", ge.game.id, e);
@@ -142,19 +194,20 @@ enum SendMode {
 
 /// The "Since last time" digest lines for one recipient: `get_game_logs` already
 /// filters to public + this player's targeted logs, so we keep only those newer
-/// than the recipient's `last_turn_at`. Best-effort: `None` on error or when
-/// there are no new lines.
+/// than the recipient's `last_turn_at`, translated with `catalog`. Best-effort:
+/// `None` on error or when there are no new lines.
 async fn digest_since_last_turn(
     pool: &sqlx::PgPool,
     ge: &crate::db::GameExtended,
     recipient_player: &crate::db::GamePlayerExtended,
+    catalog: Option<&brdgme_game::i18n::Catalog>,
 ) -> Option<Vec<String>> {
     match crate::db::get_game_logs(pool, ge.game.id, recipient_player.game_player.id).await {
         Ok(logs) => {
             let lines: Vec<String> = logs
                 .into_iter()
                 .filter(|l| l.logged_at > recipient_player.game_player.last_turn_at)
-                .map(|l| l.body)
+                .map(|l| crate::game::catalog::translate_markup(catalog, &l.body))
                 .collect();
             if lines.is_empty() { None } else { Some(lines) }
         }
@@ -183,25 +236,49 @@ async fn build_content(
             // rating-delta line from place/points/placing/rating (DRM-03c2a).
             if ge.game.end_reason.as_deref() == Some("last_human_stop") {
                 "Game ended early. No game result.".to_string()
+            } else if let Ok(Some(outcome)) =
+                crate::db::find_cooperative_score(pool, ge.game.id).await
+            {
+                cooperative_header_text(&outcome)
             } else {
                 let mut placed: Vec<&crate::db::GamePlayerExtended> =
                     ge.game_players.iter().collect();
                 placed.sort_by_key(|p| p.game_player.place.unwrap_or(i32::MAX));
-                let winners: Vec<(String, Option<i32>)> = placed
-                    .iter()
-                    .map(|p| (p.name().to_string(), p.game_player.rating_change))
-                    .collect();
-                finished_header_text(&winners)
+                let teams = crate::db::find_game_teams(pool, ge.game.id)
+                    .await
+                    .unwrap_or_default();
+                if teams.is_empty() {
+                    let winners: Vec<(String, Option<i32>)> = placed
+                        .iter()
+                        .map(|p| (p.name().to_string(), p.game_player.rating_change))
+                        .collect();
+                    finished_header_text(&winners)
+                } else {
+                    let mut grouped: Vec<TeamResult> = vec![];
+                    for p in placed {
+                        let Some(&team) = teams.get(&p.game_player.position) else {
+                            continue;
+                        };
+                        let member = (p.name().to_string(), p.game_player.rating_change);
+                        match grouped.iter_mut().find(|(t, _)| *t == team as usize) {
+                            Some((_, members)) => members.push(member),
+                            None => grouped.push((team as usize, vec![member])),
+                        }
+                    }
+                    team_finished_header_text(&grouped)
+                }
             }
         }
     });
 
-    let digest = digest_since_last_turn(pool, ge, recipient_player).await;
+    let catalog = recipient_catalog(pool, http_client, ge, recipient_player).await;
+    let digest = digest_since_last_turn(pool, ge, recipient_player, catalog.as_deref()).await;
 
     let (board, you_can) = render_board_and_you_can(
         http_client,
         ge,
         recipient_player.game_player.position as usize,
+        catalog.as_deref(),
     )
     .await;
 
@@ -229,11 +306,13 @@ pub async fn failure_report_content(
     recipient_player: &crate::db::GamePlayerExtended,
     header: String,
 ) -> crate::email::render::EmailContent {
-    let digest = digest_since_last_turn(pool, ge, recipient_player).await;
+    let catalog = recipient_catalog(pool, http_client, ge, recipient_player).await;
+    let digest = digest_since_last_turn(pool, ge, recipient_player, catalog.as_deref()).await;
     let (board, you_can) = render_board_and_you_can(
         http_client,
         ge,
         recipient_player.game_player.position as usize,
+        catalog.as_deref(),
     )
     .await;
     let log_count = game_log_count(pool, ge.game.id).await;
@@ -706,6 +785,31 @@ mod tests {
         assert_eq!(finished_header_text(&[]), "Game over.");
     }
 
+    #[test]
+    fn team_and_cooperative_headers() {
+        let teams = vec![
+            (
+                1,
+                vec![
+                    ("Bob".to_string(), Some(16)),
+                    ("Dana".to_string(), Some(16)),
+                ],
+            ),
+            (0, vec![("Alice".to_string(), Some(-16))]),
+        ];
+        assert_eq!(
+            team_finished_header_text(&teams),
+            "Game over. Winners: Team 2: Bob (+16), Dana (+16); Team 1: Alice (-16)"
+        );
+        assert_eq!(
+            cooperative_header_text(&brdgme_game::CooperativeOutcome {
+                won: false,
+                score: 17
+            }),
+            "Game over. Everyone lost with a score of 17."
+        );
+    }
+
     // DRM-03c2a: a last-human-stop game has no result. The finished mail's
     // header must be the exact no-result line and must not derive any
     // winner/result language from the players' place, points, ranked placing,
@@ -1332,4 +1436,124 @@ mod tests {
             "on-turn player 2 must be mailed exactly once"
         );
     }
+
+    /// A turn email is written in its recipient's language: board, "Since last
+    /// time" logs and "You can" lines all go through their catalog, while a
+    /// recipient reading English gets the game's own words.
+    #[sqlx::test]
+    async fn turn_email_is_translated_for_each_recipient(pool: sqlx::PgPool) {
+        use axum::{Json, Router, routing::post};
+        use brdgme_cmd::api::{PlayerRender as PlayerRenderApi, Request, Response};
+        use brdgme_game::command::Spec;
+        use brdgme_game::i18n::{Catalog, Locale};
+        use tokio::net::TcpListener;
+
+        let app = Router::new().route(
+            "/",
+            post(move |Json(req): Json<Request>| async move {
+                Json(match req {
+                    Request::Catalog { .. } => {
+                        let mut catalog = Catalog::default();
+                        for (en, de) in [
+                            ("Your hand", "Deine Hand"),
+                            ("took a card", "nahm eine Karte"),
+                            ("take a card", "eine Karte nehmen"),
+                        ] {
+                            catalog.messages.insert(en.to_string(), de.to_string());
+                        }
+                        Response::Catalog { catalog }
+                    }
+                    _ => Response::PlayerRender {
+                        render: PlayerRenderApi {
+                            player_state: "state".to_string(),
+                            render: "Your hand".to_string(),
+                            command_spec: Some(Spec::Doc {
+                                name: "take".to_string(),
+                                desc: Some("take a card".to_string()),
+                                spec: Box::new(Spec::Token("take".to_string())),
+                            }),
+                        },
+                    },
+                })
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let mock_uri = format!("http://{addr}");
+
+        let (game_id, players) = seed_game_with_emailable_players(&pool, 2).await;
+        let ge = crate::db::find_game_extended(&pool, game_id)
+            .await
+            .unwrap()
+            .expect("game exists");
+        sqlx::query(
+            "UPDATE game_versions SET uri = $1, name = $2, interface_version = 2 WHERE id = $3",
+        )
+        .bind(&mock_uri)
+        .bind(format!("notify-mock-{}", uuid::Uuid::new_v4().simple()))
+        .bind(ge.game.game_version_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO game_logs (game_id, body, is_public, logged_at) \
+             VALUES ($1, '{{player 1}} took a card', true, NOW() + INTERVAL '1 minute')",
+        )
+        .bind(game_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        let (german, _) = players[0];
+        crate::db::set_user_locale(&pool, german, Locale::De)
+            .await
+            .unwrap();
+
+        let ge = crate::db::find_game_extended(&pool, game_id)
+            .await
+            .unwrap()
+            .expect("game exists");
+        let recipient = |user_id: uuid::Uuid| {
+            ge.game_players
+                .iter()
+                .find(|p| p.user.as_ref().is_some_and(|u| u.id == user_id))
+                .expect("recipient is in the game")
+        };
+        let http = reqwest::Client::new();
+
+        let content = build_content(
+            &pool,
+            &http,
+            &ge,
+            recipient(german),
+            NotifyKind::Turn,
+            "subject".to_string(),
+        )
+        .await;
+        assert_eq!(content.board.as_deref(), Some("Deine Hand"));
+        assert_eq!(
+            content.digest,
+            Some(vec!["{{player 1}} nahm eine Karte".to_string()])
+        );
+        let you_can = content.you_can.expect("you can lines").join("\n");
+        assert!(you_can.contains("eine Karte nehmen"), "{you_can}");
+
+        let (english, _) = players[1];
+        let content = build_content(
+            &pool,
+            &http,
+            &ge,
+            recipient(english),
+            NotifyKind::Turn,
+            "subject".to_string(),
+        )
+        .await;
+        assert_eq!(content.board.as_deref(), Some("Your hand"));
+        assert_eq!(
+            content.digest,
+            Some(vec!["{{player 1}} took a card".to_string()])
+        );
+    }
 }
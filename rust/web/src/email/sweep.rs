@@ -168,10 +168,13 @@ async fn send_reminder(
         recipient_player.name(),
     ));
 
+    let catalog =
+        crate::email::notify::recipient_catalog(pool, http_client, &ge, recipient_player).await;
     let (board, you_can) = crate::email::notify::render_board_and_you_can(
         http_client,
         &ge,
         recipient_player.game_player.position as usize,
+        catalog.as_deref(),
     )
     .await;
 
@@ -0,0 +1,237 @@
+//! Per-viewer translation of game boards, logs and command words.
+//!
+//! Game logs are stored exactly as the game service emitted them (English),
+//! so each viewer can read the same log in their own language. Boards and
+//! logs are translated here at display time from the game's `Catalog`, and
+//! a localized command is rewritten to the game's English words before it
+//! is sent as a `Request::Play`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use brdgme_game::command::Spec as CommandSpec;
+use brdgme_game::command::parser::Aliases;
+use brdgme_game::i18n::{Catalog, Locale};
+use uuid::Uuid;
+
+use crate::models::game::GameVersion;
+
+/// Bounds memory if many versions are deployed over a long uptime; a
+/// cleared cache only costs a refetch.
+const MAX_ENTRIES: usize = 256;
+
+/// How long a failed fetch is remembered as an empty catalog, so an outage
+/// costs one request and one warning per version and locale, not one per
+/// page view.
+const FAILURE_TTL: Duration = Duration::from_secs(60);
+
+type CatalogKey = (Uuid, Locale);
+
+struct Entry {
+    catalog: Arc<Catalog>,
+    /// When a failure's empty stand-in should be refetched; fetched catalogs
+    /// never expire.
+    expires: Option<Instant>,
+}
+
+/// Keyed by version id: a deployed game version never changes, so a catalog
+/// is fetched once per process. Interface v1 versions cache an empty catalog.
+static CATALOGS: LazyLock<Mutex<HashMap<CatalogKey, Entry>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn cached(key: &CatalogKey, now: Instant) -> Option<Arc<Catalog>> {
+    let catalogs = CATALOGS.lock().ok()?;
+    let entry = catalogs.get(key)?;
+    if entry.expires.is_some_and(|expires| expires <= now) {
+        return None;
+    }
+    Some(entry.catalog.clone())
+}
+
+fn store(key: CatalogKey, catalog: Catalog, expires: Option<Instant>) -> Arc<Catalog> {
+    let catalog = Arc::new(catalog);
+    if let Ok(mut catalogs) = CATALOGS.lock() {
+        if catalogs.len() >= MAX_ENTRIES {
+            catalogs.clear();
+        }
+        catalogs.insert(
+            key,
+            Entry {
+                catalog: catalog.clone(),
+                expires,
+            },
+        );
+    }
+    catalog
+}
+
+/// The catalog to display `game_version` in for `locale`, or `None` for
+/// English. Failures are logged and fall back to English: an untranslated
+/// board is better than no board.
+pub async fn catalog_for(
+    pool: &sqlx::PgPool,
+    http: &reqwest::Client,
+    game_version: &GameVersion,
+    locale: Locale,
+) -> Option<Arc<Catalog>> {
+    if locale == Locale::En {
+        return None;
+    }
+    let key = (game_version.id, locale);
+    if let Some(catalog) = cached(&key, Instant::now()) {
+        return Some(catalog);
+    }
+    let interface_version = match crate::db::find_game_version_interface_version(
+        pool,
+        game_version.id,
+    )
+    .await
+    {
+        Ok(Some(v)) => v,
+        Ok(None) => return None,
+        Err(e) => {
+            tracing::warn!(version = %game_version.name, error = %e, "catalog: interface version lookup failed");
+            return None;
+        }
+    };
+    if interface_version < 2 {
+        return Some(store(key, Catalog::default(), None));
+    }
+    match crate::game::client::catalog(http, &game_version.uri, &game_version.name, locale).await {
+        Ok(catalog) => Some(store(key, catalog, None)),
+        Err(e) => {
+            tracing::warn!(version = %game_version.name, %locale, error = %e, "catalog: fetch failed");
+            Some(store(
+                key,
+                Catalog::default(),
+                Some(Instant::now() + FAILURE_TTL),
+            ))
+        }
+    }
+}
+
+/// Translates a markup string for display, leaving it untouched when there
+/// is no catalog or it fails to parse (the caller reports parse errors).
+pub fn translate_markup(catalog: Option<&Catalog>, markup: &str) -> String {
+    match catalog {
+        Some(c) => c
+            .translate_markup(markup)
+            .unwrap_or_else(|_| markup.to_string()),
+        None => markup.to_string(),
+    }
+}
+
+pub fn localize_spec(catalog: Option<&Catalog>, spec: Option<CommandSpec>) -> Option<CommandSpec> {
+    match (catalog, spec) {
+        (Some(c), Some(spec)) => Some(Aliases::from(c).localize_spec(&spec)),
+        (_, spec) => spec,
+    }
+}
+
+/// Rewrites a localized command into the game's own words.
+pub fn canonical_command(catalog: Option<&Catalog>, command: &str, names: &[String]) -> String {
+    match catalog {
+        Some(c) => Aliases::from(c).apply(command, names),
+        None => command.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog() -> Catalog {
+        let mut c = Catalog::default();
+        c.messages.insert("took".to_string(), "nahm".to_string());
+        c.aliases.insert("nehmen".to_string(), "take".to_string());
+        c
+    }
+
+    #[test]
+    fn translate_markup_uses_catalog_when_present() {
+        let c = catalog();
+        assert_eq!(
+            "{{player 0}} nahm",
+            translate_markup(Some(&c), "{{player 0}} took")
+        );
+        assert_eq!(
+            "{{player 0}} took",
+            translate_markup(None, "{{player 0}} took")
+        );
+    }
+
+    #[test]
+    fn canonical_command_rewrites_aliases_only_with_catalog() {
+        let c = catalog();
+        assert_eq!("take", canonical_command(Some(&c), "Nehmen", &[]));
+        assert_eq!("nehmen", canonical_command(None, "nehmen", &[]));
+    }
+
+    #[test]
+    fn failed_fetches_expire_after_the_ttl() {
+        let key = (Uuid::new_v4(), Locale::De);
+        let now = Instant::now();
+        store(key, Catalog::default(), Some(now + FAILURE_TTL));
+        assert_eq!(
+            Some(Catalog::default()),
+            cached(&key, now).as_deref().cloned()
+        );
+        assert!(cached(&key, now + FAILURE_TTL).is_none());
+
+        let fetched = (Uuid::new_v4(), Locale::De);
+        store(fetched, catalog(), None);
+        assert!(cached(&fetched, now + FAILURE_TTL * 100).is_some());
+    }
+
+    #[sqlx::test]
+    async fn a_failed_fetch_is_not_retried_within_the_ttl(pool: sqlx::PgPool) {
+        use brdgme_cmd::api::Response;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = calls.clone();
+        let uri = crate::game::tests::spawn_mock_game_service(move |_req| {
+            counted.fetch_add(1, Ordering::SeqCst);
+            Response::SystemError {
+                message: "catalog unavailable".to_string(),
+            }
+        })
+        .await;
+        let (_, version_id) = crate::db::test_support::make_game_type_and_version(&pool).await;
+        sqlx::query("UPDATE game_versions SET uri = $1, interface_version = 2 WHERE id = $2")
+            .bind(&uri)
+            .bind(version_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let version = crate::db::find_game_version(&pool, version_id)
+            .await
+            .unwrap()
+            .expect("version exists");
+        let http = reqwest::Client::new();
+
+        for _ in 0..3 {
+            let catalog = catalog_for(&pool, &http, &version, Locale::De).await;
+            assert_eq!(
+                "took",
+                translate_markup(catalog.as_deref(), "took"),
+                "a failed fetch falls back to English"
+            );
+        }
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn localize_spec_offers_aliases() {
+        let c = catalog();
+        let spec = CommandSpec::Token("take".to_string());
+        assert_eq!(
+            Some(CommandSpec::OneOf(vec![
+                CommandSpec::Token("take".to_string()),
+                CommandSpec::Token("nehmen".to_string()),
+            ])),
+            localize_spec(Some(&c), Some(spec))
+        );
+    }
+}
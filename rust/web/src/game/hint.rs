@@ -0,0 +1,348 @@
+//! Hints: a coach bot suggests a command for a player's turn, with a short
+//! reason, without playing it. The bot service works the hint out from the
+//! asking player's own view of the game, answering over `bot.hint`. Hints
+//! are only given in games that can't be rated, and each player may ask for
+//! `HINTS_PER_HOUR` an hour in each game.
+
+use leptos::prelude::ServerFnError;
+use uuid::Uuid;
+
+use crate::error::internal;
+use crate::game::server_fns::{ActingPlayer, HintView};
+use crate::nats::{BotHintReply, BotHintRequest};
+
+/// Hints each player may ask for in an hour in one game.
+pub const HINTS_PER_HOUR: i64 = 5;
+
+/// Whether hints may be given in a game: only while it can't be rated,
+/// which takes at least two human players (see `db::rating`).
+pub fn hints_allowed(ge: &crate::db::GameExtended) -> bool {
+    ge.game_players
+        .iter()
+        .filter(|p| p.game_player.user_id.is_some())
+        .count()
+        < 2
+}
+
+pub(crate) async fn hint_core(
+    pool: &sqlx::PgPool,
+    jetstream: &async_nats::jetstream::Context,
+    game_id: Uuid,
+    actor: ActingPlayer,
+) -> Result<HintView, ServerFnError> {
+    let ge = crate::db::find_game_extended(pool, game_id)
+        .await
+        .map_err(internal("hint_core: find game"))?
+        .ok_or_else(|| ServerFnError::new("Game not found"))?;
+
+    if ge.game.is_finished {
+        return Err(ServerFnError::new("Game is already finished"));
+    }
+
+    let player = match &actor {
+        ActingPlayer::User(user_id) => ge
+            .game_players
+            .iter()
+            .find(|p| p.user.as_ref().is_some_and(|u| u.id == *user_id))
+            .ok_or_else(|| ServerFnError::new("You are not a player in this game"))?,
+        ActingPlayer::GamePlayer(gp_id) => ge
+            .game_players
+            .iter()
+            .find(|p| p.game_player.id == *gp_id)
+            .ok_or_else(|| ServerFnError::new("You are not a player in this game"))?,
+    };
+
+    if player.game_player.left_at.is_some() {
+        return Err(ServerFnError::new("You have already left this game"));
+    }
+    if !hints_allowed(&ge) {
+        return Err(ServerFnError::new(
+            "Hints are only available in unrated games (fewer than two human players).",
+        ));
+    }
+    if !player.game_player.is_turn {
+        return Err(ServerFnError::new("Hints are only available on your turn."));
+    }
+
+    let coach = crate::db::find_coach_bot(pool)
+        .await
+        .map_err(internal("hint_core: find coach"))?
+        .ok_or_else(|| ServerFnError::new("Hints are not available: no coach bot is set up."))?;
+
+    if !claim_hint(
+        pool,
+        game_id,
+        player.game_player.user_id,
+        player.game_player.id,
+    )
+    .await
+    .map_err(internal("hint_core: claim hint"))?
+    {
+        return Err(ServerFnError::new(format!(
+            "You can ask for {} hints an hour in each game. Please try again later.",
+            HINTS_PER_HOUR
+        )));
+    }
+
+    let request = BotHintRequest {
+        game_id,
+        player_position: player.game_player.position,
+        bot_name: coach,
+    };
+    match ask_coach(jetstream, &request).await? {
+        BotHintReply::Hint { command, rationale } => Ok(HintView { command, rationale }),
+        BotHintReply::Unavailable { message } => Err(ServerFnError::new(message)),
+    }
+}
+
+/// Records a hint for the player unless they've used up their hints for the
+/// hour in this game, counting every seat they've asked from. A seat without
+/// a user counts only its own hints. The game's row is locked so concurrent
+/// asks can't overrun the limit.
+async fn claim_hint(
+    pool: &sqlx::PgPool,
+    game_id: Uuid,
+    user_id: Option<Uuid>,
+    game_player_id: Uuid,
+) -> anyhow::Result<bool> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("SELECT 1 FROM games WHERE id = $1 FOR UPDATE")
+        .bind(game_id)
+        .execute(&mut *tx)
+        .await?;
+    let used: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM game_hints \
+         WHERE game_id = $1 \
+           AND user_id IS NOT DISTINCT FROM $2 \
+           AND (user_id IS NOT NULL OR game_player_id = $3) \
+           AND created_at > (now() AT TIME ZONE 'utc') - interval '1 hour'",
+    )
+    .bind(game_id)
+    .bind(user_id)
+    .bind(game_player_id)
+    .fetch_one(&mut *tx)
+    .await?;
+    if used >= HINTS_PER_HOUR {
+        return Ok(false);
+    }
+    sqlx::query("INSERT INTO game_hints (game_id, user_id, game_player_id) VALUES ($1, $2, $3)")
+        .bind(game_id)
+        .bind(user_id)
+        .bind(game_player_id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(true)
+}
+
+async fn ask_coach(
+    jetstream: &async_nats::jetstream::Context,
+    request: &BotHintRequest,
+) -> Result<BotHintReply, ServerFnError> {
+    let payload = serde_json::to_vec(request).map_err(internal("hint: serialize request"))?;
+    let message = jetstream
+        .client()
+        .send_request(
+            crate::nats::SUBJECT_HINT,
+            async_nats::Request::new()
+                .payload(payload.into())
+                .timeout(Some(crate::nats::HINT_TIMEOUT)),
+        )
+        .await
+        .map_err(|e| {
+            // No bot service running, or it took too long.
+            tracing::warn!(game_id = %request.game_id, error = %e, "hint request failed");
+            ServerFnError::new("The coach didn't answer. Please try again later.")
+        })?;
+    serde_json::from_slice(&message.payload).map_err(internal("hint: parse reply"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::*;
+    use sqlx::PgPool;
+
+    /// A context whose client never connects: every check under test
+    /// rejects before the coach is asked.
+    async fn unconnected_jetstream() -> async_nats::jetstream::Context {
+        let client = async_nats::ConnectOptions::new()
+            .retry_on_initial_connect()
+            .connect("nats://127.0.0.1:1")
+            .await
+            .unwrap();
+        async_nats::jetstream::new(client)
+    }
+
+    async fn hint_error(pool: &PgPool, game_id: Uuid, user_id: Uuid) -> String {
+        let jetstream = unconnected_jetstream().await;
+        match hint_core(pool, &jetstream, game_id, ActingPlayer::User(user_id)).await {
+            Err(ServerFnError::ServerError(m)) => m,
+            other => panic!("expected ServerError, got {other:?}"),
+        }
+    }
+
+    /// `make_game_with_players` shuffles positions, so the turn is set by
+    /// seat kind rather than by position.
+    async fn give_turn_to_human(pool: &PgPool, game_id: Uuid, human: bool) {
+        sqlx::query(
+            "UPDATE game_players SET is_turn = ((user_id IS NOT NULL) = $2) WHERE game_id = $1",
+        )
+        .bind(game_id)
+        .bind(human)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn make_coach(pool: &PgPool) {
+        sqlx::query("UPDATE bots SET coach = true WHERE name = 'hard'")
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn hint_core_refuses_games_that_can_be_rated(pool: PgPool) {
+        make_coach(&pool).await;
+        let creator = make_user(&pool, "creator").await;
+        let opponent = make_user(&pool, "opponent").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let game =
+            make_game_with_players(&pool, game_version_id, creator.id, &[opponent.id], 1, &[0])
+                .await;
+
+        let message = hint_error(&pool, game.id, creator.id).await;
+        assert!(
+            message.contains("unrated"),
+            "unexpected rejection: {message}"
+        );
+    }
+
+    #[sqlx::test]
+    async fn hint_core_needs_the_players_turn_and_a_coach(pool: PgPool) {
+        let creator = make_user(&pool, "creator").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let bots_turn =
+            make_game_with_players(&pool, game_version_id, creator.id, &[], 1, &[]).await;
+        give_turn_to_human(&pool, bots_turn.id, false).await;
+        let my_turn = make_game_with_players(&pool, game_version_id, creator.id, &[], 1, &[]).await;
+        give_turn_to_human(&pool, my_turn.id, true).await;
+
+        let message = hint_error(&pool, bots_turn.id, creator.id).await;
+        assert!(
+            message.contains("your turn"),
+            "unexpected rejection: {message}"
+        );
+        let message = hint_error(&pool, my_turn.id, creator.id).await;
+        assert!(
+            message.contains("no coach"),
+            "unexpected rejection: {message}"
+        );
+
+        let stranger = make_user(&pool, "stranger").await;
+        let message = hint_error(&pool, my_turn.id, stranger.id).await;
+        assert!(
+            message.contains("not a player"),
+            "unexpected rejection: {message}"
+        );
+    }
+
+    #[sqlx::test]
+    async fn hint_core_stops_at_the_hourly_limit(pool: PgPool) {
+        make_coach(&pool).await;
+        let creator = make_user(&pool, "creator").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let game = make_game_with_players(&pool, game_version_id, creator.id, &[], 1, &[]).await;
+        give_turn_to_human(&pool, game.id, true).await;
+        let seat: Uuid =
+            sqlx::query_scalar("SELECT id FROM game_players WHERE game_id = $1 AND user_id = $2")
+                .bind(game.id)
+                .bind(creator.id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        for _ in 0..HINTS_PER_HOUR {
+            assert!(
+                claim_hint(&pool, game.id, Some(creator.id), seat)
+                    .await
+                    .unwrap()
+            );
+        }
+
+        let message = hint_error(&pool, game.id, creator.id).await;
+        assert!(
+            message.contains("an hour"),
+            "unexpected rejection: {message}"
+        );
+        let claimed: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM game_hints WHERE game_player_id = $1")
+                .bind(seat)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            HINTS_PER_HOUR, claimed,
+            "a refused hint must not be claimed"
+        );
+    }
+
+    #[sqlx::test]
+    async fn claim_hint_counts_the_last_hour_per_user_and_game(pool: PgPool) {
+        let creator = make_user(&pool, "creator").await;
+        let (_, game_version_id) = make_game_type_and_version(&pool).await;
+        let game = make_game_with_players(&pool, game_version_id, creator.id, &[], 1, &[0]).await;
+        let other_game =
+            make_game_with_players(&pool, game_version_id, creator.id, &[], 1, &[0]).await;
+        let seat = |game_id: Uuid, human: bool| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_scalar::<_, Uuid>(
+                    "SELECT id FROM game_players WHERE game_id = $1 AND (user_id IS NOT NULL) = $2",
+                )
+                .bind(game_id)
+                .bind(human)
+                .fetch_one(&pool)
+                .await
+                .unwrap()
+            }
+        };
+        let (mine, bots) = (seat(game.id, true).await, seat(game.id, false).await);
+        let claim = |game_id: Uuid, user_id: Option<Uuid>, seat: Uuid| {
+            let pool = pool.clone();
+            async move { claim_hint(&pool, game_id, user_id, seat).await.unwrap() }
+        };
+
+        for _ in 0..HINTS_PER_HOUR {
+            assert!(claim(game.id, Some(creator.id), mine).await);
+        }
+        assert!(!claim(game.id, Some(creator.id), mine).await);
+        assert!(
+            !claim(game.id, Some(creator.id), bots).await,
+            "another seat in the same game shares the limit"
+        );
+        assert!(
+            claim(game.id, None, bots).await,
+            "a seat without a user has its own limit"
+        );
+        assert!(
+            claim(
+                other_game.id,
+                Some(creator.id),
+                seat(other_game.id, true).await
+            )
+            .await,
+            "each game has its own limit"
+        );
+
+        sqlx::query(
+            "UPDATE game_hints SET created_at = created_at - interval '2 hours' \
+             WHERE user_id = $1",
+        )
+        .bind(creator.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert!(claim(game.id, Some(creator.id), bots).await);
+    }
+}
@@ -1,8 +1,12 @@
 #[cfg(feature = "ssr")]
 pub use brdgme_game_client as client;
 #[cfg(feature = "ssr")]
+pub mod catalog;
+#[cfg(feature = "ssr")]
 pub mod export;
 #[cfg(feature = "ssr")]
+pub mod hint;
+#[cfg(feature = "ssr")]
 pub mod import;
 pub mod placing;
 pub mod server_fns;
@@ -15,6 +19,8 @@ pub struct StatusUpdate {
     pub whose_turn: Vec<usize>,
     pub eliminated: Vec<usize>,
     pub placings: Vec<usize>,
+    pub teams: Vec<usize>,
+    pub cooperative: Option<brdgme_game::CooperativeOutcome>,
 }
 
 /// Splits a game service `Status` into the `StatusUpdate` fields used to
@@ -33,12 +39,21 @@ pub fn status_fields(status: brdgme_game::Status) -> StatusUpdate {
             whose_turn,
             eliminated,
             placings: vec![],
+            teams: vec![],
+            cooperative: None,
         },
-        Status::Finished { placings, .. } => StatusUpdate {
+        Status::Finished {
+            placings,
+            teams,
+            cooperative,
+            ..
+        } => StatusUpdate {
             is_finished: true,
             whose_turn: vec![],
             eliminated: vec![],
             placings,
+            teams,
+            cooperative,
         },
     }
 }
@@ -94,6 +109,70 @@ pub async fn execute_command(
     game_id: uuid::Uuid,
     player_position: usize,
     command: String,
+) -> Result<crate::db::GameExtended, ExecuteCommandError> {
+    execute_explained_command(
+        pool,
+        http_client,
+        broadcaster,
+        jetstream,
+        game_id,
+        player_position,
+        command,
+        None,
+    )
+    .await
+}
+
+/// Longest bot rationale kept in the game log. The bot caps its own well
+/// under this; it's a backstop.
+#[cfg(feature = "ssr")]
+const RATIONALE_MAX_CHARS: usize = 500;
+
+/// The public log explaining a bot's move, attributed to its seat, or
+/// `None` for a blank rationale. The text is flattened to one line and
+/// escaped, so it can't carry markup of its own.
+#[cfg(feature = "ssr")]
+pub(crate) fn rationale_log(
+    player_position: i32,
+    rationale: &str,
+) -> Option<brdgme_cmd::api::CliLog> {
+    let text: String = rationale
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(RATIONALE_MAX_CHARS)
+        .collect();
+    if text.is_empty() {
+        return None;
+    }
+    let t = time::OffsetDateTime::now_utc();
+    Some(brdgme_cmd::api::CliLog {
+        content: format!(
+            "{{{{player {}}}}} explains: {}",
+            player_position,
+            text.replace('{', "{{lbrace}}")
+        ),
+        at: time::PrimitiveDateTime::new(t.date(), t.time()),
+        public: true,
+        to: vec![],
+    })
+}
+
+/// `execute_command` for a bot move that comes with a `rationale`, which is
+/// stored after the move's own logs as a public log under the bot's name.
+/// The bot has already screened it against its private state.
+#[cfg(feature = "ssr")]
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_explained_command(
+    pool: &sqlx::PgPool,
+    http_client: &reqwest::Client,
+    broadcaster: &crate::websocket::GameBroadcaster,
+    jetstream: &async_nats::jetstream::Context,
+    game_id: uuid::Uuid,
+    player_position: usize,
+    command: String,
+    rationale: Option<&str>,
 ) -> Result<crate::db::GameExtended, ExecuteCommandError> {
     use brdgme_cmd::api::{Request, Response};
 
@@ -121,6 +200,15 @@ pub async fn execute_command(
         .map(|p| p.name().to_string())
         .collect();
 
+    // Bots always send English, including bots replacing a human.
+    let locale = match player.user {
+        Some(ref u) if player.game_bot.is_none() => crate::db::get_user_locale(pool, u.id).await?,
+        _ => Default::default(),
+    };
+    let catalog =
+        crate::game::catalog::catalog_for(pool, http_client, &ge.game_version, locale).await;
+    let command = crate::game::catalog::canonical_command(catalog.as_deref(), &command, &names);
+
     let resp = client::request(
         http_client,
         &ge.game_version.uri,
@@ -138,7 +226,7 @@ pub async fn execute_command(
         e => anyhow::Error::from(e).into(),
     })?;
 
-    let (game_response, logs, can_undo, remaining_input) = match resp {
+    let (game_response, mut logs, can_undo, remaining_input) = match resp {
         Response::Play {
             game,
             logs,
@@ -157,6 +245,8 @@ pub async fn execute_command(
         )));
     }
 
+    logs.extend(rationale.and_then(|r| rationale_log(player.game_player.position, r)));
+
     let prev_game_state = ge.game.game_state.clone();
     let status = status_fields(game_response.status);
 
@@ -500,7 +590,7 @@ pub async fn handle_bot_command_event(
     event: &crate::nats::BotCommandEvent,
 ) -> Result<(), ExecuteCommandError> {
     let attempt = event.attempt;
-    let result = execute_command(
+    let result = execute_explained_command(
         pool,
         http_client,
         broadcaster,
@@ -508,6 +598,7 @@ pub async fn handle_bot_command_event(
         event.game_id,
         event.player_position as usize,
         event.command.clone(),
+        event.rationale.as_deref(),
     )
     .await;
 
@@ -646,6 +737,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rationale_log_is_public_escaped_and_attributed() {
+        let log = rationale_log(2, "  Blocks {{b}}Mick{{/b}}\n from the lead. ").unwrap();
+        assert_eq!(
+            log.content,
+            "{{player 2}} explains: Blocks {{lbrace}}{{lbrace}}b}}Mick{{lbrace}}{{lbrace}}/b}} from the lead."
+        );
+        assert!(log.public);
+        assert!(log.to.is_empty());
+        assert_eq!(
+            brdgme_markup::from_string(&log.content).unwrap(),
+            vec![
+                brdgme_markup::Node::Player(2),
+                brdgme_markup::Node::Text(
+                    " explains: Blocks {{b}}Mick{{/b}} from the lead.".to_string()
+                ),
+            ]
+        );
+        assert!(rationale_log(0, " \n ").is_none());
+    }
+
     /// Starts an in-process mock game service that answers every request with
     /// whatever `handler` returns; mirrors the pattern in `brdgme_game_client`'s tests.
     pub(crate) async fn spawn_mock_game_service<F>(handler: F) -> String
@@ -865,6 +977,34 @@ mod tests {
         assert!(player1.game_player.undo_game_state.is_none());
     }
 
+    #[sqlx::test]
+    async fn explained_command_logs_the_rationale_after_the_move(pool: PgPool) {
+        let uri = spawn_mock_game_service(|_req| play_response("new_state", vec![1], true)).await;
+        let (game_id, _p0, _p1) = make_two_player_game(&pool, &uri).await;
+        let broadcaster = make_broadcaster().await;
+        let http_client = reqwest::Client::new();
+        let jetstream = make_jetstream().await;
+
+        execute_explained_command(
+            &pool,
+            &http_client,
+            &broadcaster,
+            &jetstream,
+            game_id,
+            0,
+            "abc".to_string(),
+            Some("It keeps the lead."),
+        )
+        .await
+        .unwrap();
+
+        let logs = db::get_all_game_logs(&pool, game_id).await.unwrap();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].body, "did a thing");
+        assert_eq!(logs[1].body, "{{player 0}} explains: It keeps the lead.");
+        assert!(logs[1].is_public);
+    }
+
     // wd F8 / wfe F42: the notification diff baseline must come from the load
     // execute_command already does, not from a second best-effort read whose
     // failure silently becomes "brand-new game".
@@ -971,6 +1111,8 @@ mod tests {
                 whose_turn: vec![1],
                 eliminated: vec![],
                 placings: vec![],
+                teams: vec![],
+                cooperative: None,
             },
             &[0.0, 0.0],
             stale_ge.game.updated_at,
@@ -1171,6 +1313,8 @@ mod tests {
                 status: brdgme_game::Status::Finished {
                     placings: vec![0, 1],
                     stats: vec![],
+                    teams: vec![],
+                    cooperative: None,
                 },
             },
             logs: vec![],
@@ -1266,6 +1410,8 @@ mod tests {
                 status: brdgme_game::Status::Finished {
                     placings: vec![0, 1],
                     stats: vec![],
+                    teams: vec![],
+                    cooperative: None,
                 },
             },
             logs: vec![],
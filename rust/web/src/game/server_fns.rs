@@ -82,6 +82,9 @@ pub struct GameViewData {
     /// human participants tied in the latest departure event) (#47, dual result
     /// model).
     pub can_end_game: bool,
+    /// Whether the viewer may ask for a hint: it's their turn in an
+    /// unfinished game that can't be rated, and a coach bot is set up.
+    pub can_hint: bool,
     pub players: Vec<PlayerViewData>,
     pub command_spec: Option<brdgme_game::command::Spec>,
     /// `--mk-player-{n}`/`--mk-player-{n}-contrast` var declarations for this
@@ -96,6 +99,13 @@ pub struct GameViewData {
     pub viewer_user_id: Option<Uuid>,
 }
 
+/// A coach bot's suggested command, not yet played.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HintView {
+    pub command: String,
+    pub rationale: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerViewData {
     pub name: String,
@@ -282,11 +292,20 @@ pub async fn get_game_details(game_id: Uuid) -> Result<GameViewData, ServerFnErr
     .await
     .map_err(internal("get_game_details: render game"))?;
 
+    let locale = crate::db::get_user_locale(&pool, user.id)
+        .await
+        .map_err(internal("get_game_details: load locale"))?;
+    let catalog =
+        crate::game::catalog::catalog_for(&pool, &http_client, &ge.game_version, locale).await;
+
     // Convert markup to HTML, semantically: colours stay symbolic (CSS
     // classes referencing `--mk-*` vars) rather than baked-in hex, so the
     // rendered board follows the viewer's active theme.
-    let nodes = brdgme_markup::from_string(&render_resp.render)
+    let mut nodes = brdgme_markup::from_string(&render_resp.render)
         .map_err(internal("get_game_details: parse markup"))?;
+    if let Some(ref catalog) = catalog {
+        nodes = catalog.translate(&nodes);
+    }
 
     let html = brdgme_markup::html_class(&brdgme_markup::transform_semantic(
         &nodes,
@@ -338,6 +357,14 @@ pub async fn get_game_details(game_id: Uuid) -> Result<GameViewData, ServerFnErr
 
     let can_end_game = !ge.game.is_finished && end_eligible(&ge, Some(user.id));
 
+    let can_hint = !ge.game.is_finished
+        && player.is_some_and(|p| p.game_player.is_turn && p.game_player.left_at.is_none())
+        && crate::game::hint::hints_allowed(&ge)
+        && crate::db::find_coach_bot(&pool)
+            .await
+            .map_err(internal("get_game_details: find coach"))?
+            .is_some();
+
     Ok(GameViewData {
         id: ge.game.id,
         version_id: ge.game_version.id,
@@ -356,6 +383,7 @@ pub async fn get_game_details(game_id: Uuid) -> Result<GameViewData, ServerFnErr
         is_2player: ge.game_players.len() == 2,
         can_concede,
         can_end_game,
+        can_hint,
         players: ge
             .game_players
             .iter()
@@ -382,7 +410,10 @@ pub async fn get_game_details(game_id: Uuid) -> Result<GameViewData, ServerFnErr
                     .unwrap_or_default(),
             })
             .collect(),
-        command_spec: render_resp.command_spec,
+        command_spec: crate::game::catalog::localize_spec(
+            catalog.as_deref(),
+            render_resp.command_spec,
+        ),
         player_style,
         viewer_is_admin,
         viewer_user_id: Some(user.id),
@@ -599,6 +630,7 @@ pub(crate) struct CreateGameSeed<'a> {
 pub(crate) struct FetchedGame {
     pub(crate) game_info: brdgme_cmd::api::GameResponse,
     pub(crate) logs: Vec<brdgme_cmd::api::CliLog>,
+    pub(crate) options: brdgme_game::GameOptions,
 }
 
 #[cfg(feature = "ssr")]
@@ -606,6 +638,8 @@ pub(crate) async fn fetch_game_from_service(
     http_client: &reqwest::Client,
     game_version: &crate::models::game::GameVersion,
     player_count: usize,
+    options: &brdgme_game::GameOptions,
+    seed: Option<u64>,
 ) -> Result<FetchedGame, ServerFnError> {
     use crate::game::client;
     use brdgme_cmd::api::{Request, Response};
@@ -616,7 +650,8 @@ pub(crate) async fn fetch_game_from_service(
         &game_version.name,
         &Request::New {
             players: player_count,
-            seed: None,
+            seed,
+            options: options.clone(),
         },
     )
     .await
@@ -624,10 +659,44 @@ pub(crate) async fn fetch_game_from_service(
 
     let (game_info, logs) = match resp {
         Response::New { game, logs, .. } => (game, logs),
+        Response::UserError { message } => return Err(ServerFnError::new(message)),
         _ => return Err(ServerFnError::new("Unexpected response from game service")),
     };
 
-    Ok(FetchedGame { game_info, logs })
+    Ok(FetchedGame {
+        game_info,
+        logs,
+        options: options.clone(),
+    })
+}
+
+/// Checks chosen options against what the game offers before a proposal is
+/// stored, so a bad choice fails when the invite is sent rather than when
+/// the last player accepts. V1 games offer no options.
+#[cfg(feature = "ssr")]
+pub(crate) async fn validate_game_options(
+    pool: &sqlx::PgPool,
+    http_client: &reqwest::Client,
+    game_version: &crate::models::game::GameVersion,
+    options: &brdgme_game::GameOptions,
+) -> Result<(), ServerFnError> {
+    if options.is_empty() {
+        return Ok(());
+    }
+    let interface_version = crate::db::find_game_version_interface_version(pool, game_version.id)
+        .await
+        .map_err(internal("validate_game_options: interface version"))?
+        .unwrap_or(1);
+    if interface_version < 2 {
+        return Err(ServerFnError::new("This game has no options"));
+    }
+    let offered = crate::game::client::options(http_client, &game_version.uri, &game_version.name)
+        .await
+        .map_err(internal("validate_game_options: fetch options"))?;
+    options
+        .resolve(&offered)
+        .map(|_| ())
+        .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
 #[cfg(feature = "ssr")]
@@ -661,6 +730,18 @@ pub(crate) async fn insert_game_from_service(
     .await
     .map_err(internal("insert_game_from_service: create game"))?;
 
+    if !fetched.options.is_empty() {
+        sqlx::query("UPDATE games SET options = $2::jsonb WHERE id = $1")
+            .bind(game.id)
+            .bind(
+                serde_json::to_string(&fetched.options)
+                    .map_err(internal("insert_game_from_service: encode options"))?,
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(internal("insert_game_from_service: store options"))?;
+    }
+
     crate::db::insert_game_logs_tx(&mut *tx, game.id, fetched.logs)
         .await
         .map_err(internal("insert_game_from_service: create game logs"))?;
@@ -706,12 +787,44 @@ pub async fn get_available_bots() -> Result<Vec<String>, ServerFnError> {
     Ok(bots)
 }
 
+/// The choices offered when creating a game on `game_version_id`, for the
+/// new-game form. V1 games offer none.
+#[server(GetGameOptions, "/api")]
+pub async fn get_game_options(
+    game_version_id: Uuid,
+) -> Result<Vec<brdgme_game::GameOption>, ServerFnError> {
+    use crate::auth::server::get_current_user;
+    use sqlx::PgPool;
+
+    let pool = expect_context::<PgPool>();
+    let http_client = expect_context::<reqwest::Client>();
+    let _ = get_current_user()
+        .await?
+        .ok_or_else(|| ServerFnError::new("Not authenticated"))?;
+
+    let version = crate::db::find_game_version(&pool, game_version_id)
+        .await
+        .map_err(internal("get_game_options: find game version"))?
+        .ok_or_else(|| ServerFnError::new("Game version not found"))?;
+    let interface_version = crate::db::find_game_version_interface_version(&pool, version.id)
+        .await
+        .map_err(internal("get_game_options: interface version"))?
+        .unwrap_or(1);
+    if interface_version < 2 {
+        return Ok(vec![]);
+    }
+    crate::game::client::options(&http_client, &version.uri, &version.name)
+        .await
+        .map_err(internal("get_game_options: fetch options"))
+}
+
 #[server(GetGameLogs, "/api")]
 pub async fn get_game_logs(game_id: Uuid) -> Result<Vec<GameLogEntry>, ServerFnError> {
     use crate::auth::server::get_current_user;
     use sqlx::PgPool;
 
     let pool = expect_context::<PgPool>();
+    let http_client = expect_context::<reqwest::Client>();
     let user = get_current_user()
         .await?
         .ok_or_else(|| ServerFnError::new("Not authenticated"))?;
@@ -734,15 +847,24 @@ pub async fn get_game_logs(game_id: Uuid) -> Result<Vec<GameLogEntry>, ServerFnE
         .await
         .map_err(internal("get_game_logs: load logs"))?;
 
+    let locale = crate::db::get_user_locale(&pool, user.id)
+        .await
+        .map_err(internal("get_game_logs: load locale"))?;
+    let catalog =
+        crate::game::catalog::catalog_for(&pool, &http_client, &ge.game_version, locale).await;
+
     let semantic_players = ge.semantic_players();
 
     let entries = logs
         .into_iter()
         .map(|log| {
-            let nodes = brdgme_markup::from_string(&log.body).unwrap_or_else(|e| {
+            let mut nodes = brdgme_markup::from_string(&log.body).unwrap_or_else(|e| {
                 tracing::warn!(game_id = %game_id, log_id = %log.id, error = %e, "failed to parse log markup");
                 vec![]
             });
+            if let Some(ref catalog) = catalog {
+                nodes = catalog.translate(&nodes);
+            }
             let body_html = brdgme_markup::html_class(&brdgme_markup::transform_semantic(
                 &nodes,
                 &semantic_players,
@@ -804,6 +926,20 @@ pub async fn undo_game(game_id: Uuid) -> Result<(), ServerFnError> {
     Ok(())
 }
 
+#[server(RequestHint, "/api")]
+pub async fn request_hint(game_id: Uuid) -> Result<HintView, ServerFnError> {
+    use crate::auth::server::get_current_user;
+    use sqlx::PgPool;
+
+    let pool = expect_context::<PgPool>();
+    let jetstream = expect_context::<async_nats::jetstream::Context>();
+    let user = get_current_user()
+        .await?
+        .ok_or_else(|| ServerFnError::new("Not authenticated"))?;
+
+    crate::game::hint::hint_core(&pool, &jetstream, game_id, ActingPlayer::User(user.id)).await
+}
+
 #[cfg(feature = "ssr")]
 fn count_active_humans(ge: &crate::db::GameExtended) -> usize {
     ge.game_players
@@ -1219,8 +1355,12 @@ pub(crate) async fn restart_core(
         slot.bot_name = canonical;
     }
 
+    let options = crate::db::find_game_options(pool, old_game_id)
+        .await
+        .map_err(internal("restart_core: find game options"))?;
+
     let fetched = if opponent_ids.is_empty() && opponent_emails.is_empty() {
-        Some(fetch_game_from_service(http_client, version, player_count).await?)
+        Some(fetch_game_from_service(http_client, version, player_count, &options, None).await?)
     } else {
         None
     };
@@ -1322,10 +1462,15 @@ pub(crate) async fn restart_core(
         }));
     }
 
-    let proposal_id =
-        crate::proposals::insert_proposal(&mut tx, version.id, user_id, Some(old_game_id))
-            .await
-            .map_err(internal("restart_core: insert proposal"))?;
+    let proposal_id = crate::proposals::insert_proposal(
+        &mut tx,
+        version.id,
+        user_id,
+        Some(old_game_id),
+        &options,
+    )
+    .await
+    .map_err(internal("restart_core: insert proposal"))?;
 
     let mut position = 0;
     crate::proposals::insert_proposal_player(
@@ -1493,6 +1638,92 @@ pub async fn restart_game_with_roster(
     Ok(outcome)
 }
 
+/// Starts today's solo challenge for a game type: a one-player game with
+/// default options, dealt from a seed every player shares for the UTC day.
+/// One attempt per player per day; asking again returns the game already
+/// started. Its finished score goes on the daily solo leaderboard.
+#[server(StartDailyChallenge, "/api")]
+#[cfg_attr(feature = "ssr", tracing::instrument(skip_all))]
+pub async fn start_daily_challenge(game_type_id: Uuid) -> Result<Uuid, ServerFnError> {
+    use crate::proposals::InviteMailer;
+    use crate::websocket::GameBroadcaster;
+    use sqlx::PgPool;
+
+    let pool = expect_context::<PgPool>();
+    let broadcaster = expect_context::<GameBroadcaster>();
+    let http_client = expect_context::<reqwest::Client>();
+    let jetstream = expect_context::<async_nats::jetstream::Context>();
+    let user = crate::friends::require_user().await?;
+
+    let today = crate::db::daily_date_today();
+    if let Some(game_id) = crate::db::find_daily_game(&pool, user.id, game_type_id, &today)
+        .await
+        .map_err(internal("start_daily_challenge: find daily game"))?
+    {
+        return Ok(game_id);
+    }
+
+    let version = crate::db::find_latest_non_deprecated_game_version(&pool, game_type_id)
+        .await
+        .map_err(internal("start_daily_challenge: find game version"))?
+        .ok_or_else(|| ServerFnError::new("Game type not found"))?;
+    let player_counts = crate::db::find_game_type_player_counts(&pool, version.id)
+        .await
+        .map_err(internal("start_daily_challenge: find player counts"))?
+        .ok_or_else(|| ServerFnError::new("Game type not found"))?;
+    if let Some(msg) = roster_error(&player_counts, 1) {
+        return Err(ServerFnError::new(msg));
+    }
+
+    let secret = crate::crypto::load_key().map_err(internal("start_daily_challenge: load key"))?;
+    let fetched = fetch_game_from_service(
+        &http_client,
+        &version,
+        1,
+        &brdgme_game::GameOptions::new(),
+        Some(crate::db::daily_seed(&secret, game_type_id, &today)),
+    )
+    .await?;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(internal("start_daily_challenge: begin transaction"))?;
+    let game = insert_game_from_service(
+        &mut tx,
+        version.id,
+        CreateGameSeed {
+            creator_id: user.id,
+            opponent_ids: &[],
+            opponent_emails: &[],
+            bot_slots: &[],
+            all_accepted: false,
+        },
+        fetched,
+    )
+    .await?;
+    let claimed = crate::db::claim_daily_game_tx(&mut tx, user.id, game_type_id, game.id, &today)
+        .await
+        .map_err(internal("start_daily_challenge: claim daily game"))?;
+    if !claimed {
+        // A concurrent start won the claim; drop this game and return theirs.
+        drop(tx);
+        return crate::db::find_daily_game(&pool, user.id, game_type_id, &today)
+            .await
+            .map_err(internal("start_daily_challenge: find daily game"))?
+            .ok_or_else(|| ServerFnError::new("Daily challenge not found"));
+    }
+    tx.commit()
+        .await
+        .map_err(internal("start_daily_challenge: commit transaction"))?;
+
+    crate::proposals::mailer()
+        .notify_game_started(game.id)
+        .await;
+    crate::game::broadcast_and_trigger(&pool, &broadcaster, &jetstream, game.id).await;
+    Ok(game.id)
+}
+
 #[cfg(feature = "ssr")]
 pub(crate) async fn get_restart_prefill_impl(
     pool: &sqlx::PgPool,
@@ -16,6 +16,22 @@ pub struct GameInfoRankingEntry {
     pub form: Vec<crate::stats::FormResult>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoloScoreEntry {
+    pub user_id: Uuid,
+    pub name: String,
+    pub score: f32,
+    pub game_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CooperativeScoreEntry {
+    pub game_id: Uuid,
+    pub score: f32,
+    pub won: bool,
+    pub players: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GameInfoData {
     pub name: String,
@@ -25,6 +41,17 @@ pub struct GameInfoData {
     pub active_today: i64,
     pub distinct_players: i64,
     pub ranking: Vec<GameInfoRankingEntry>,
+    pub game_type_id: Uuid,
+    /// Whether the game can be played solo, which shows the solo
+    /// leaderboards and the daily challenge.
+    pub offers_solo: bool,
+    /// Today's daily challenge scores.
+    pub solo_today: Vec<SoloScoreEntry>,
+    /// Best solo scores of all time, daily challenges included.
+    pub solo_best: Vec<SoloScoreEntry>,
+    /// Best cooperative game scores; cooperative games are not rated, so
+    /// this replaces the ranking for them.
+    pub cooperative_best: Vec<CooperativeScoreEntry>,
 }
 
 #[cfg(feature = "ssr")]
@@ -56,6 +83,28 @@ pub async fn get_game_info(name: String) -> Result<Option<GameInfoData>, ServerF
             .map_err(internal("get_game_info: top_ranking")),
     )?;
 
+    let today = crate::db::daily_date_today();
+    let (offers_solo, solo_today, solo_best, cooperative_best) = tokio::try_join!(
+        queries::game_info_offers_solo(&pool, game_type_id)
+            .map_err(internal("get_game_info: offers_solo")),
+        queries::game_info_solo_leaderboard(&pool, game_type_id, Some(&today))
+            .map_err(internal("get_game_info: solo_today")),
+        queries::game_info_solo_leaderboard(&pool, game_type_id, None)
+            .map_err(internal("get_game_info: solo_best")),
+        queries::game_info_cooperative_leaderboard(&pool, game_type_id)
+            .map_err(internal("get_game_info: cooperative_best")),
+    )?;
+    let solo_entries = |rows: Vec<(Uuid, String, f32, Uuid)>| -> Vec<SoloScoreEntry> {
+        rows.into_iter()
+            .map(|(user_id, name, score, game_id)| SoloScoreEntry {
+                user_id,
+                name,
+                score,
+                game_id,
+            })
+            .collect()
+    };
+
     let user_ids: Vec<Uuid> = ranking_rows.iter().map(|(id, _, _, _)| *id).collect();
     let form = crate::stats::recent_form_for_game_type(&pool, &user_ids, game_type_id, 10)
         .await
@@ -82,9 +131,155 @@ pub async fn get_game_info(name: String) -> Result<Option<GameInfoData>, ServerF
         active_today,
         distinct_players,
         ranking,
+        game_type_id,
+        offers_solo,
+        solo_today: solo_entries(solo_today),
+        solo_best: solo_entries(solo_best),
+        cooperative_best: cooperative_best
+            .into_iter()
+            .map(|(game_id, score, won, players)| CooperativeScoreEntry {
+                game_id,
+                score,
+                won,
+                players,
+            })
+            .collect(),
     }))
 }
 
+fn solo_score_table(entries: Vec<SoloScoreEntry>, empty: &'static str) -> AnyView {
+    use crate::players::encode_path_segment;
+    use leptos_router::components::A;
+
+    if entries.is_empty() {
+        return view! { <p>{empty}</p> }.into_any();
+    }
+    let rows = entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let href = format!("/players/{}", encode_path_segment(&entry.name));
+            view! {
+                <tr>
+                    <td>{i + 1}</td>
+                    <td><A href=href>{entry.name.clone()}</A></td>
+                    <td><A href=format!("/games/{}", entry.game_id)>{entry.score}</A></td>
+                </tr>
+            }
+        })
+        .collect_view();
+    view! {
+        <div class="table-scroll">
+            <table>
+                <thead>
+                    <tr>
+                        <th>"Rank"</th>
+                        <th>"Player"</th>
+                        <th>"Score"</th>
+                    </tr>
+                </thead>
+                <tbody>{rows}</tbody>
+            </table>
+        </div>
+    }
+    .into_any()
+}
+
+/// The best cooperative games, shown once any have finished.
+fn cooperative_score_table(entries: Vec<CooperativeScoreEntry>) -> AnyView {
+    use leptos_router::components::A;
+
+    let rows = entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            view! {
+                <tr>
+                    <td>{i + 1}</td>
+                    <td>{entry.players.join(", ")}</td>
+                    <td><A href=format!("/games/{}", entry.game_id)>{entry.score}</A></td>
+                    <td>{if entry.won { "Won" } else { "Lost" }}</td>
+                </tr>
+            }
+        })
+        .collect_view();
+    view! {
+        <section class="game-info-cooperative">
+            <h2>"Best team scores"</h2>
+            <div class="table-scroll">
+                <table>
+                    <thead>
+                        <tr>
+                            <th>"Rank"</th>
+                            <th>"Players"</th>
+                            <th>"Score"</th>
+                            <th>"Result"</th>
+                        </tr>
+                    </thead>
+                    <tbody>{rows}</tbody>
+                </table>
+            </div>
+        </section>
+    }
+    .into_any()
+}
+
+/// Solo leaderboards and the daily challenge button, shown for games that
+/// can be played by one player.
+#[component]
+fn SoloSection(
+    game_type_id: Uuid,
+    today: Vec<SoloScoreEntry>,
+    best: Vec<SoloScoreEntry>,
+) -> impl IntoView {
+    use crate::game::server_fns::start_daily_challenge;
+    use leptos_router::NavigateOptions;
+    use leptos_router::hooks::use_navigate;
+
+    let daily_action = Action::new(move |_: &()| start_daily_challenge(game_type_id));
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let Some(Ok(game_id)) = daily_action.value().get() {
+            navigate(&format!("/games/{game_id}"), NavigateOptions::default());
+        }
+    });
+
+    view! {
+        <section class="game-info-solo">
+            <h2>"Daily challenge"</h2>
+            <p>
+                "Everyone plays the same solo game today. You get one attempt."
+            </p>
+            <p>
+                <button
+                    on:click=move |_| {
+                        daily_action.dispatch(());
+                    }
+                    disabled=move || daily_action.pending().get()
+                >
+                    "Play today's challenge"
+                </button>
+            </p>
+            {move || {
+                daily_action
+                    .value()
+                    .get()
+                    .and_then(|r| r.err())
+                    .map(|e| {
+                        view! {
+                            <div class="form-error">
+                                {crate::error::user_facing_server_error(&e)}
+                            </div>
+                        }
+                    })
+            }}
+            {solo_score_table(today, "No scores today yet.")}
+            <h2>"Best solo scores"</h2>
+            {solo_score_table(best, "No solo games finished yet.")}
+        </section>
+    }
+}
+
 #[component]
 pub fn GameInfoPage() -> impl IntoView {
     use crate::components::MainLayout;
@@ -188,6 +383,15 @@ pub fn GameInfoPage() -> impl IntoView {
                                         <h2>"Top players"</h2>
                                         {ranking_view}
                                     </section>
+                                    {d.offers_solo.then(|| view! {
+                                        <SoloSection
+                                            game_type_id=d.game_type_id
+                                            today=d.solo_today.clone()
+                                            best=d.solo_best.clone()
+                                        />
+                                    })}
+                                    {(!d.cooperative_best.is_empty())
+                                        .then(|| cooperative_score_table(d.cooperative_best.clone()))}
                                 </div>
                             }.into_any()
                         }
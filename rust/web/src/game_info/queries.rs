@@ -79,6 +79,64 @@ pub async fn game_info_top_ranking(
     .map_err(Into::into)
 }
 
+pub async fn game_info_offers_solo(pool: &PgPool, game_type_id: Uuid) -> Result<bool> {
+    let row: Option<(bool,)> =
+        sqlx::query_as("SELECT 1 = ANY(player_counts) FROM game_types WHERE id = $1")
+            .bind(game_type_id)
+            .fetch_optional(pool)
+            .await?;
+    Ok(row.is_some_and(|(solo,)| solo))
+}
+
+/// Each player's best solo score, highest first; earlier scores win ties.
+/// With `daily_date` (`YYYY-MM-DD`) only that day's challenge games count.
+pub async fn game_info_solo_leaderboard(
+    pool: &PgPool,
+    game_type_id: Uuid,
+    daily_date: Option<&str>,
+) -> Result<Vec<(Uuid, String, f32, Uuid)>> {
+    sqlx::query_as::<_, (Uuid, String, f32, Uuid)>(
+        "SELECT user_id, name, score, game_id FROM (
+             SELECT DISTINCT ON (s.user_id) s.user_id, u.name, s.score, s.game_id, s.created_at
+             FROM solo_scores s
+             JOIN users u ON u.id = s.user_id
+             WHERE s.game_type_id = $1 AND ($2::text IS NULL OR s.daily_date = $2::date)
+             ORDER BY s.user_id, s.score DESC, s.created_at
+         ) best
+         ORDER BY score DESC, created_at, name
+         LIMIT 10",
+    )
+    .bind(game_type_id)
+    .bind(daily_date)
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+/// The highest-scoring cooperative games of this type, with their players in
+/// seat order. Wins rank above losses on equal scores.
+pub async fn game_info_cooperative_leaderboard(
+    pool: &PgPool,
+    game_type_id: Uuid,
+) -> Result<Vec<(Uuid, f32, bool, Vec<String>)>> {
+    sqlx::query_as::<_, (Uuid, f32, bool, Vec<String>)>(
+        "SELECT cs.game_id, cs.score, cs.won,
+                array_agg(COALESCE(u.name, gb.name) ORDER BY gp.position)
+         FROM cooperative_scores cs
+         JOIN game_players gp ON gp.game_id = cs.game_id
+         LEFT JOIN users u ON u.id = gp.user_id
+         LEFT JOIN game_bots gb ON gb.id = gp.game_bot_id
+         WHERE cs.game_type_id = $1
+         GROUP BY cs.id
+         ORDER BY cs.score DESC, cs.won DESC, cs.created_at
+         LIMIT 10",
+    )
+    .bind(game_type_id)
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -547,4 +605,97 @@ mod tests {
             "a type with only ineligible versions must be absent from new-game availability"
         );
     }
+
+    async fn insert_solo_score(
+        pool: &PgPool,
+        game_type_id: Uuid,
+        game_version_id: Uuid,
+        user_id: Uuid,
+        score: f32,
+        daily_date: Option<&str>,
+    ) {
+        let game_id =
+            insert_finished_game(pool, game_version_id, &[(Some(user_id), Some(1), None)]).await;
+        sqlx::query(
+            "INSERT INTO solo_scores (game_id, game_type_id, user_id, score, daily_date)
+             VALUES ($1, $2, $3, $4, $5::date)",
+        )
+        .bind(game_id)
+        .bind(game_type_id)
+        .bind(user_id)
+        .bind(score)
+        .bind(daily_date)
+        .execute(pool)
+        .await
+        .expect("insert solo score");
+    }
+
+    #[sqlx::test]
+    async fn solo_leaderboard_ranks_best_score_per_player(pool: PgPool) {
+        let alice = make_user(&pool, "alice").await;
+        let bob = make_user(&pool, "bob").await;
+        let (gt, gv) = make_game_type(&pool, "Farkle").await;
+
+        insert_solo_score(&pool, gt, gv, alice, 3000.0, None).await;
+        insert_solo_score(&pool, gt, gv, alice, 4500.0, Some("2026-10-19")).await;
+        insert_solo_score(&pool, gt, gv, bob, 4000.0, Some("2026-10-18")).await;
+
+        let best: Vec<(String, f32)> = game_info_solo_leaderboard(&pool, gt, None)
+            .await
+            .expect("query ok")
+            .into_iter()
+            .map(|(_, name, score, _)| (name, score))
+            .collect();
+        assert_eq!(
+            vec![("alice".to_string(), 4500.0), ("bob".to_string(), 4000.0)],
+            best
+        );
+
+        let today: Vec<String> = game_info_solo_leaderboard(&pool, gt, Some("2026-10-18"))
+            .await
+            .expect("query ok")
+            .into_iter()
+            .map(|(_, name, _, _)| name)
+            .collect();
+        assert_eq!(vec!["bob".to_string()], today);
+        assert!(!game_info_offers_solo(&pool, gt).await.expect("query ok"));
+    }
+
+    #[sqlx::test]
+    async fn cooperative_leaderboard_ranks_games_by_score(pool: PgPool) {
+        let alice = make_user(&pool, "alice").await;
+        let bob = make_user(&pool, "bob").await;
+        let (gt, gv) = make_game_type(&pool, "Hanabi").await;
+
+        for (score, won) in [(17.0f32, false), (25.0, true)] {
+            let game_id = insert_finished_game(
+                &pool,
+                gv,
+                &[(Some(alice), Some(1), None), (Some(bob), Some(1), None)],
+            )
+            .await;
+            sqlx::query(
+                "INSERT INTO cooperative_scores (game_id, game_type_id, won, score) VALUES ($1, $2, $3, $4)",
+            )
+            .bind(game_id)
+            .bind(gt)
+            .bind(won)
+            .bind(score)
+            .execute(&pool)
+            .await
+            .expect("insert cooperative score");
+        }
+
+        let best: Vec<(f32, bool, Vec<String>)> = game_info_cooperative_leaderboard(&pool, gt)
+            .await
+            .expect("query ok")
+            .into_iter()
+            .map(|(_, score, won, names)| (score, won, names))
+            .collect();
+        let names = vec!["alice".to_string(), "bob".to_string()];
+        assert_eq!(
+            vec![(25.0, true, names.clone()), (17.0, false, names)],
+            best
+        );
+    }
 }
@@ -8,10 +8,11 @@ use uuid::Uuid;
 
 use crate::components::{OpponentSlot, OpponentSlotEditor};
 use crate::game::server_fns::{
-    BotSlot, GameTypeInfo, PrefillSlot, RestartOutcome, get_available_bots, get_restart_prefill,
-    restart_game_with_roster,
+    BotSlot, GameTypeInfo, PrefillSlot, RestartOutcome, get_available_bots, get_game_options,
+    get_restart_prefill, restart_game_with_roster,
 };
 use crate::players::encode_path_segment;
+use brdgme_game::GameOptions;
 
 /// Formats supported player counts, honoring non-contiguous sets:
 /// [2,3,4] -> "2-4 players", [2] -> "2 players", [2,4,6] -> "2, 4, 6 players".
@@ -78,6 +79,18 @@ fn clamp_player_count(counts: &[i32], wanted: i32) -> i32 {
         .unwrap_or(wanted)
 }
 
+/// The count the setup form starts on: the smallest multiplayer count, so a
+/// game that also offers solo play still opens on an opponent slot. Solo-only
+/// types start solo; with nothing offered it falls back to 2.
+fn default_player_count(counts: &[i32]) -> i32 {
+    counts
+        .iter()
+        .copied()
+        .find(|&c| c > 1)
+        .or_else(|| counts.first().copied())
+        .unwrap_or(2)
+}
+
 /// Client-side filter + sort over the already-fetched list. `sort_key` is
 /// one of "alpha" (default), "weight-asc", "weight-desc"; weight ties break
 /// alphabetically.
@@ -263,9 +276,24 @@ fn GameSetupPanel(gt: GameTypeInfo, restart: Option<Uuid>) -> impl IntoView {
     });
 
     let (selected_version_id, set_selected_version_id) = signal(gt.versions.first().map(|v| v.id));
-    let (player_count, set_player_count) = signal(gt.player_counts.first().copied().unwrap_or(2));
+    let (player_count, set_player_count) = signal(default_player_count(&gt.player_counts));
     let (opponent_slots, set_opponent_slots) = signal(Vec::<OpponentSlot>::new());
     let (form_error, set_form_error) = signal(None::<String>);
+    // A restart reuses the finished game's options, so only new games ask.
+    let game_options = LocalResource::new(move || {
+        let version_id = selected_version_id.get();
+        async move {
+            match (restart, version_id) {
+                (None, Some(vid)) => get_game_options(vid).await.unwrap_or_default(),
+                _ => vec![],
+            }
+        }
+    });
+    let (chosen_options, set_chosen_options) = signal(GameOptions::new());
+    Effect::new(move |_| {
+        selected_version_id.track();
+        set_chosen_options.set(GameOptions::new());
+    });
 
     // Users already taken by any slot never appear as chips again.
     let taken = Signal::derive(move || -> Vec<Uuid> {
@@ -323,14 +351,27 @@ fn GameSetupPanel(gt: GameTypeInfo, restart: Option<Uuid>) -> impl IntoView {
     });
 
     let create_action = Action::new(
-        |(version_id, ids, emails, bots): &(Uuid, Vec<Uuid>, Vec<String>, Vec<BotSlot>)| {
+        |(version_id, ids, emails, bots, options): &(
+            Uuid,
+            Vec<Uuid>,
+            Vec<String>,
+            Vec<BotSlot>,
+            GameOptions,
+        )| {
             let version_id = *version_id;
             let ids = ids.clone();
             let emails = emails.clone();
             let bots = bots.clone();
+            let options = options.clone();
             async move {
-                crate::proposals::create_proposal(version_id, Some(ids), Some(emails), Some(bots))
-                    .await
+                crate::proposals::create_proposal(
+                    version_id,
+                    Some(ids),
+                    Some(emails),
+                    Some(bots),
+                    Some(options),
+                )
+                .await
             }
         },
     );
@@ -461,7 +502,13 @@ fn GameSetupPanel(gt: GameTypeInfo, restart: Option<Uuid>) -> impl IntoView {
         if let Some(game_id) = restart {
             restart_action.dispatch((game_id, version_id, ids, emails, bots));
         } else {
-            create_action.dispatch((version_id, ids, emails, bots));
+            create_action.dispatch((
+                version_id,
+                ids,
+                emails,
+                bots,
+                chosen_options.get_untracked(),
+            ));
         }
     };
 
@@ -491,6 +538,16 @@ fn GameSetupPanel(gt: GameTypeInfo, restart: Option<Uuid>) -> impl IntoView {
                 (!g.blurb.is_empty())
                     .then(|| view! { <p class="new-game-blurb">{g.blurb.clone()}</p> })
             })}
+            {gt.with_value(|g| {
+                (restart.is_none() && g.player_counts.contains(&1)).then(|| {
+                    let href = format!("/games/type/{}", encode_path_segment(&g.name));
+                    view! {
+                        <p class="new-game-solo">
+                            <A href=href>"Solo leaderboard and daily challenge"</A>
+                        </p>
+                    }
+                })
+            })}
             {restart.map(|gid| view! {
                 <p class="new-game-back">
                     <A href=format!("/games/{gid}")>"Back to finished game"</A>
@@ -542,6 +599,53 @@ fn GameSetupPanel(gt: GameTypeInfo, restart: Option<Uuid>) -> impl IntoView {
                         }
                     })
                 }}
+                {move || {
+                    game_options
+                        .get()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|option| {
+                            let key = option.key.clone();
+                            let key_for_change = key.clone();
+                            view! {
+                                <div class="form-field">
+                                    <label class="form-label" title=option.desc.clone()>
+                                        {option.name.clone()}
+                                    </label>
+                                    <div class="form-control">
+                                        <select
+                                            aria-label=option.name.clone()
+                                            on:change=move |ev| {
+                                                let value = event_target_value(&ev);
+                                                set_chosen_options
+                                                    .update(|o| o.set(key_for_change.clone(), value));
+                                            }
+                                        >
+                                            {option
+                                                .values
+                                                .into_iter()
+                                                .map(|v| {
+                                                    let key = key.clone();
+                                                    let value = v.value.clone();
+                                                    view! {
+                                                        <option
+                                                            value=v.value.clone()
+                                                            selected=move || {
+                                                                chosen_options.with(|o| o.get(&key) == Some(value.as_str()))
+                                                            }
+                                                        >
+                                                            {v.name}
+                                                        </option>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </select>
+                                    </div>
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                }}
                 <div class="form-field">
                     <label class="form-label">"Players"</label>
                     <div
@@ -687,6 +791,14 @@ mod tests {
         assert_eq!(player_range(&[]), "");
     }
 
+    #[test]
+    fn default_player_count_prefers_multiplayer() {
+        assert_eq!(default_player_count(&[1, 2, 3, 4]), 2);
+        assert_eq!(default_player_count(&[2, 3, 4]), 2);
+        assert_eq!(default_player_count(&[1]), 1);
+        assert_eq!(default_player_count(&[]), 2);
+    }
+
     #[test]
     fn clamp_player_count_keeps_offered_counts() {
         assert_eq!(clamp_player_count(&[2, 3, 4], 3), 3);
@@ -908,13 +908,15 @@ pub async fn insert_proposal(
     game_version_id: Uuid,
     owner_user_id: Uuid,
     restarted_game_id: Option<Uuid>,
+    options: &brdgme_game::GameOptions,
 ) -> sqlx::Result<Uuid> {
     sqlx::query_scalar(
-        "INSERT INTO game_proposals (game_version_id, owner_user_id, restarted_game_id) VALUES ($1,$2,$3) RETURNING id",
+        "INSERT INTO game_proposals (game_version_id, owner_user_id, restarted_game_id, options) VALUES ($1,$2,$3,$4::jsonb) RETURNING id",
     )
     .bind(game_version_id)
     .bind(owner_user_id)
     .bind(restarted_game_id)
+    .bind(serde_json::to_string(options).map_err(|e| sqlx::Error::Encode(Box::new(e)))?)
     .fetch_one(&mut *tx)
     .await
 }
@@ -1491,9 +1493,10 @@ pub async fn create_proposal(
     opponent_ids: Option<Vec<Uuid>>,
     opponent_emails: Option<Vec<String>>,
     bot_slots: Option<Vec<crate::game::server_fns::BotSlot>>,
+    options: Option<brdgme_game::GameOptions>,
 ) -> Result<ProposalOutcome, ServerFnError> {
     use crate::game::server_fns::{
-        CreateGameSeed, fetch_game_from_service, insert_game_from_service,
+        CreateGameSeed, fetch_game_from_service, insert_game_from_service, validate_game_options,
     };
     use crate::websocket::GameBroadcaster;
     use sqlx::PgPool;
@@ -1541,9 +1544,14 @@ pub async fn create_proposal(
         slot.bot_name = canonical;
     }
 
+    let options = options.unwrap_or_default();
     let fetched = if opponent_ids.is_empty() && opponent_emails.is_empty() {
-        Some(fetch_game_from_service(&http_client, &game_version, player_count).await?)
+        Some(
+            fetch_game_from_service(&http_client, &game_version, player_count, &options, None)
+                .await?,
+        )
     } else {
+        validate_game_options(&pool, &http_client, &game_version, &options).await?;
         None
     };
 
@@ -1601,7 +1609,7 @@ pub async fn create_proposal(
         });
     }
 
-    let proposal_id = insert_proposal(&mut tx, game_version_id, user.id, None)
+    let proposal_id = insert_proposal(&mut tx, game_version_id, user.id, None, &options)
         .await
         .map_err(internal("create_proposal: insert proposal"))?;
 
@@ -1821,10 +1829,15 @@ pub async fn start_proposal(proposal_id: Uuid) -> Result<Uuid, ServerFnError> {
         .iter()
         .filter(|p| p.response == "accepted")
         .count();
+    let options = crate::db::find_proposal_options(&pool, proposal_id)
+        .await
+        .map_err(internal("start_proposal: options"))?;
     let fetched = crate::game::server_fns::fetch_game_from_service(
         &http_client,
         &game_version,
         accepted_count,
+        &options,
+        None,
     )
     .await?;
 
@@ -3860,6 +3873,66 @@ mod tests {
         assert_eq!(player.response, "accepted");
     }
 
+    #[sqlx::test]
+    async fn proposal_options_carry_over_to_the_game(pool: PgPool) {
+        let gv = seed_game_version(&pool).await;
+        let owner = seed_invite_user(&pool, true).await;
+        let options = brdgme_game::GameOptions::new().with("mode", "advanced");
+
+        let mut tx = pool.begin().await.unwrap();
+        let pid = insert_proposal(&mut tx, gv, owner, None, &options)
+            .await
+            .unwrap();
+        insert_proposal_player(&mut tx, pid, 0, Some(owner), None, None, "accepted", None)
+            .await
+            .unwrap();
+        insert_proposal_player(
+            &mut tx,
+            pid,
+            1,
+            None,
+            Some("Bot 1".to_string()),
+            Some("easy".to_string()),
+            "accepted",
+            None,
+        )
+        .await
+        .unwrap();
+        tx.commit().await.unwrap();
+        assert_eq!(
+            options,
+            crate::db::find_proposal_options(&pool, pid).await.unwrap()
+        );
+
+        let proposal = find_proposal(&pool, pid).await.unwrap().unwrap();
+        let players = find_proposal_players(&pool, pid).await.unwrap();
+        let game_version = crate::db::find_game_version(&pool, gv)
+            .await
+            .unwrap()
+            .unwrap();
+        let fetched = crate::game::server_fns::FetchedGame {
+            game_info: brdgme_cmd::api::GameResponse {
+                state: String::new(),
+                points: vec![0.0, 0.0],
+                status: brdgme_game::Status::Active {
+                    whose_turn: vec![0],
+                    eliminated: vec![],
+                },
+            },
+            logs: vec![],
+            options: options.clone(),
+        };
+        let mut tx = pool.begin().await.unwrap();
+        let game_id = start_proposal_tx(&mut tx, &proposal, &players, &game_version, fetched)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+        assert_eq!(
+            options,
+            crate::db::find_game_options(&pool, game_id).await.unwrap()
+        );
+    }
+
     #[sqlx::test]
     async fn start_proposal_tx_rejects_disabled_bot(pool: PgPool) {
         let gv = seed_game_version(&pool).await;
@@ -3905,6 +3978,7 @@ mod tests {
                 },
             },
             logs: vec![],
+            options: brdgme_game::GameOptions::new(),
         };
 
         let mut tx = pool.begin().await.unwrap();
@@ -4849,7 +4923,7 @@ mod tests {
         };
 
         let outcome = with_start_proposal_context(&pool, session_user, || {
-            create_proposal(gv, None, None, None)
+            create_proposal(gv, None, None, None, None)
         })
         .await
         .expect("create_proposal solo must succeed");
@@ -255,6 +255,13 @@ fn non_empty(s: String) -> Option<String> {
     if s.trim().is_empty() { None } else { Some(s) }
 }
 
+/// A translated doc from the viewer's catalog, or `None` when the catalog
+/// doesn't translate it and the game's own should be shown.
+#[cfg(feature = "ssr")]
+fn translation(doc: Option<&String>) -> Option<String> {
+    doc.cloned().and_then(non_empty)
+}
+
 /// Fetches basic + advanced strategy for a game version. Returns
 /// `(basic, advanced)`; each is `None` when absent/empty or when the game is V1.
 /// The V2 strategy handlers VALIDATE the `game`/`player` request fields
@@ -297,6 +304,7 @@ pub(crate) async fn fetch_strategy(
         &Request::New {
             players,
             seed: None,
+            options: Default::default(),
         },
     )
     .await?
@@ -347,6 +355,7 @@ pub(crate) async fn fetch_strategy(
 
 #[server(GetRenderedRules, "/api")]
 pub async fn get_rendered_rules(version_id: Uuid) -> Result<RenderedDocs, ServerFnError> {
+    use crate::auth::server::get_current_user;
     use crate::error::internal;
     use sqlx::PgPool;
 
@@ -369,6 +378,28 @@ pub async fn get_rendered_rules(version_id: Uuid) -> Result<RenderedDocs, Server
         .map_err(internal("get_rendered_rules: find player counts"))?
         .ok_or_else(|| ServerFnError::new("Game type not found"))?;
 
+    // Signed-out viewers, and anyone reading English, get the game's own docs.
+    let locale = match get_current_user()
+        .await
+        .map_err(internal("get_rendered_rules: current user"))?
+    {
+        Some(user) => crate::db::get_user_locale(&pool, user.id)
+            .await
+            .map_err(internal("get_rendered_rules: load locale"))?,
+        None => Default::default(),
+    };
+    let catalog = match crate::db::find_game_version(&pool, version_id)
+        .await
+        .map_err(internal("get_rendered_rules: find game version"))?
+    {
+        Some(game_version) => {
+            crate::game::catalog::catalog_for(&pool, &http_client, &game_version, locale).await
+        }
+        None => None,
+    };
+    let rules_src =
+        translation(catalog.as_ref().and_then(|c| c.rules.as_ref())).unwrap_or(rules_src);
+
     let (players, player_style) = synthetic_players(&player_counts);
 
     let rules = render_doc(&rules_src, &players, &player_style)
@@ -382,6 +413,10 @@ pub async fn get_rendered_rules(version_id: Uuid) -> Result<RenderedDocs, Server
                 (None, None)
             }
         };
+    let basic_src =
+        translation(catalog.as_ref().and_then(|c| c.basic_strategy.as_ref())).or(basic_src);
+    let advanced_src =
+        translation(catalog.as_ref().and_then(|c| c.advanced_strategy.as_ref())).or(advanced_src);
 
     let basic_strategy = match basic_src {
         Some(src) => Some(
@@ -543,6 +578,21 @@ mod tests {
         assert_eq!(non_empty("x".to_string()), Some("x".to_string()));
     }
 
+    #[test]
+    fn translation_only_replaces_docs_the_catalog_translates() {
+        let catalog = brdgme_game::i18n::Catalog {
+            rules: Some("# Regeln".to_string()),
+            basic_strategy: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            translation(catalog.rules.as_ref()).unwrap_or("# Rules".to_string()),
+            "# Regeln"
+        );
+        assert_eq!(translation(catalog.basic_strategy.as_ref()), None);
+        assert_eq!(translation(catalog.advanced_strategy.as_ref()), None);
+    }
+
     /// A minimal V2 game whose `start`/`player_counts` only support 2 players.
     /// The mock game service below runs it through the REAL hardened
     /// `GameRequester`, so `fetch_strategy`'s payloads must pass the same
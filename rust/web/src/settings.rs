@@ -1,5 +1,5 @@
-//! The /settings page: username, preferred colours, theme picker, email
-//! management. Logged-in only - anonymous visitors are sent to /login.
+//! The /settings page: username, preferred colours, language, theme picker,
+//! email management. Logged-in only - anonymous visitors are sent to /login.
 //! See docs/changes/archive/2026-07-16-35-settings-page/spec.md.
 
 use leptos::prelude::*;
@@ -32,6 +32,7 @@ pub fn SettingsPage() -> impl IntoView {
                 <h1>"Settings"</h1>
                 <UsernameSection settings=settings/>
                 <ColorsSection settings=settings/>
+                <LanguageSection settings=settings/>
                 <EmailPreferencesSection settings=settings/>
                 <EmailSection settings=settings/>
                 <ThemeSection/>
@@ -191,6 +192,70 @@ fn ColorsSection(
     }
 }
 
+/// Language for game boards, logs, rules and command words. Games without a
+/// translation stay in English. Saves immediately on change, like the
+/// colour selects.
+#[component]
+fn LanguageSection(
+    settings: LocalResource<Result<crate::auth::SettingsData, ServerFnError>>,
+) -> impl IntoView {
+    use crate::components::FormField;
+    use brdgme_game::i18n::Locale;
+
+    let locale = RwSignal::new(Locale::default());
+    let initialized = RwSignal::new(false);
+    Effect::new(move |_| {
+        if let Some(Ok(s)) = settings.get()
+            && !initialized.get_untracked()
+        {
+            initialized.set(true);
+            locale.set(s.locale);
+        }
+    });
+
+    let save_action = ServerAction::<crate::auth::SetLocale>::new();
+    let error = RwSignal::new(None::<String>);
+    let before_pick = StoredValue::new(Locale::default());
+    Effect::new(move |_| match save_action.value().get() {
+        Some(Ok(())) => error.set(None),
+        Some(Err(e)) => {
+            locale.set(before_pick.get_value());
+            error.set(Some(format!(
+                "Could not save your language: {}",
+                crate::error::action_error_message(&e)
+            )));
+        }
+        None => {}
+    });
+    let pick = move |code: String| {
+        let Ok(picked) = code.parse::<Locale>() else {
+            return;
+        };
+        before_pick.set_value(locale.get_untracked());
+        locale.set(picked);
+        save_action.dispatch(crate::auth::SetLocale { locale: picked });
+    };
+
+    view! {
+        <h2>"Language"</h2>
+        {move || error.get().map(|e| view! { <div class="form-error">{e}</div> })}
+        <FormField
+            label="Game language"
+            help="Boards, logs, rules and commands, where the game has a translation."
+        >
+            <select
+                on:change=move |ev| pick(event_target_value(&ev))
+                prop:value=move || locale.get().code()
+            >
+                {Locale::ALL
+                    .into_iter()
+                    .map(|l| view! { <option value=l.code()>{l.name()}</option> })
+                    .collect_view()}
+            </select>
+        </FormField>
+    }
+}
+
 #[component]
 fn EmailPreferencesSection(
     settings: LocalResource<Result<crate::auth::SettingsData, ServerFnError>>,
@@ -56,9 +56,10 @@ pub async fn overall_totals(
         r#"
         SELECT
             count(*) AS finished_games,
-            count(*) FILTER (WHERE gp.ranked_placing = 1) AS wins
+            count(*) FILTER (WHERE COALESCE(cs.won, gp.ranked_placing = 1)) AS wins
         FROM game_players gp
         JOIN games g ON g.id = gp.game_id
+        LEFT JOIN cooperative_scores cs ON cs.game_id = g.id
         WHERE gp.user_id = $1
           AND g.is_finished = true
           AND gp.ranked_placing IS NOT NULL
@@ -110,12 +111,14 @@ pub async fn game_type_stats(
                 gt.id AS game_type_id,
                 gt.name AS game_type_name,
                 gp.ranked_placing AS place,
+                cs.won AS cooperative_won,
                 (SELECT count(*) FROM game_players gp2
                  WHERE gp2.game_id = g.id AND gp2.user_id IS NOT NULL AND gp2.ranked_placing IS NOT NULL) AS n
             FROM game_players gp
             JOIN games g ON g.id = gp.game_id
             JOIN game_versions gv ON gv.id = g.game_version_id
             JOIN game_types gt ON gt.id = gv.game_type_id
+            LEFT JOIN cooperative_scores cs ON cs.game_id = g.id
             WHERE gp.user_id = $1
               AND g.is_finished = true
               AND gp.ranked_placing IS NOT NULL
@@ -130,9 +133,10 @@ pub async fn game_type_stats(
                 game_type_id,
                 game_type_name,
                 count(*) AS games,
-                count(*) FILTER (WHERE place = 1) AS wins,
+                count(*) FILTER (WHERE COALESCE(cooperative_won, place = 1)) AS wins,
                 avg((n - place)::float8 / (n - 1))
-                    FILTER (WHERE place IS NOT NULL AND n >= 2) AS avg_place_percentile
+                    FILTER (WHERE place IS NOT NULL AND n >= 2 AND cooperative_won IS NULL)
+                    AS avg_place_percentile
             FROM qualifying
             GROUP BY game_type_id, game_type_name
         )
@@ -632,6 +636,8 @@ struct HeadToHeadRow {
     ties: i64,
 }
 
+/// Record against each opponent. Cooperative games have no opponents, and
+/// a team game only counts the other teams.
 pub async fn head_to_head(
     pool: &PgPool,
     user_id: Uuid,
@@ -643,7 +649,7 @@ pub async fn head_to_head(
     let rows: Vec<HeadToHeadRow> = sqlx::query_as(
         r#"
         WITH qualifying AS (
-            SELECT g.id AS game_id, gp.ranked_placing AS place
+            SELECT g.id AS game_id, gp.ranked_placing AS place, gp.team
             FROM game_players gp
             JOIN games g ON g.id = gp.game_id
             JOIN game_versions gv ON gv.id = g.game_version_id
@@ -652,6 +658,7 @@ pub async fn head_to_head(
               AND g.is_finished = true
               AND gt.name = $2
               AND gp.ranked_placing IS NOT NULL
+              AND NOT EXISTS (SELECT 1 FROM cooperative_scores cs WHERE cs.game_id = g.id)
               AND (
                   SELECT count(*) FROM game_players gp2
                   WHERE gp2.game_id = g.id AND gp2.user_id IS NOT NULL AND gp2.ranked_placing IS NOT NULL
@@ -667,6 +674,7 @@ pub async fn head_to_head(
             JOIN game_players gp
                 ON gp.game_id = q.game_id AND gp.user_id IS NOT NULL AND gp.user_id <> $1
                 AND gp.ranked_placing IS NOT NULL
+                AND (q.team IS NULL OR gp.team IS DISTINCT FROM q.team)
             JOIN users u ON u.id = gp.user_id
         )
         SELECT
@@ -2207,6 +2215,61 @@ mod tests {
         assert_eq!(h2h[0].ties, 1);
     }
 
+    #[sqlx::test]
+    async fn head_to_head_skips_teammates_and_cooperative_games(pool: PgPool) {
+        let user = make_user(&pool, "alice").await;
+        let partner = make_user(&pool, "bob").await;
+        let opponent = make_user(&pool, "carl").await;
+        let (gt, gv) = make_game_type(&pool, "Camel Up").await;
+
+        let team_game = insert_finished_game(
+            &pool,
+            gv,
+            datetime!(2026-01-01 00:00:00),
+            &[
+                (Some(user), Some(1), None),
+                (Some(opponent), Some(2), None),
+                (Some(partner), Some(1), None),
+            ],
+        )
+        .await;
+        sqlx::query("UPDATE game_players SET team = position % 2 WHERE game_id = $1")
+            .bind(team_game)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let coop_game = insert_finished_game(
+            &pool,
+            gv,
+            datetime!(2026-01-02 00:00:00),
+            &[(Some(user), Some(1), None), (Some(opponent), Some(1), None)],
+        )
+        .await;
+        sqlx::query(
+            "INSERT INTO cooperative_scores (game_id, game_type_id, won, score) VALUES ($1, $2, false, 12)",
+        )
+        .bind(coop_game)
+        .bind(gt)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let h2h = head_to_head(&pool, user, "Camel Up", false, None)
+            .await
+            .expect("query ok");
+        assert_eq!(h2h.len(), 1);
+        assert_eq!(h2h[0].user_id, Some(opponent));
+        assert_eq!((h2h[0].games, h2h[0].wins), (1, 1));
+
+        // The lost cooperative game is a loss despite its shared 1st place.
+        let totals = overall_totals(&pool, user, false).await.expect("query ok");
+        assert_eq!((totals.finished_games, totals.wins), (2, 1));
+        let stats = game_type_stats(&pool, user, false, Some("Camel Up"))
+            .await
+            .expect("query ok");
+        assert_eq!((stats[0].games, stats[0].wins), (2, 1));
+    }
+
     #[sqlx::test]
     async fn head_to_head_excludes_bots(pool: PgPool) {
         let user = make_user(&pool, "alice").await;
@@ -318,6 +318,7 @@ async fn bot_command_consumed_executes_and_commits(pool: PgPool) {
         player_position: 0,
         command: "abc".to_string(),
         attempt: 0,
+        rationale: None,
     };
     let _ = handle_bot_command_event(&pool, &http_client, &broadcaster, &jetstream, &None, &event)
         .await;
@@ -335,6 +336,37 @@ async fn bot_command_consumed_executes_and_commits(pool: PgPool) {
     assert!(player1.game_player.is_turn);
 }
 
+#[sqlx::test]
+#[serial]
+async fn bot_command_rationale_is_logged_publicly(pool: PgPool) {
+    let jetstream = make_jetstream().await;
+    let http_client = reqwest::Client::new();
+    let broadcaster = make_broadcaster().await;
+    let uri = spawn_mock_game_service(|_req| play_response("new_state", vec![1], true)).await;
+    let (game_id, _p0, _p1) = make_two_player_game(&pool, &uri).await;
+
+    let event = BotCommandEvent {
+        game_id,
+        player_position: 0,
+        command: "abc".to_string(),
+        attempt: 0,
+        rationale: Some("Taking the lead before the merger.".to_string()),
+    };
+    let _ = handle_bot_command_event(&pool, &http_client, &broadcaster, &jetstream, &None, &event)
+        .await;
+
+    let logs = db::get_all_game_logs(&pool, game_id).await.unwrap();
+    let explained = logs
+        .iter()
+        .find(|l| l.body.contains("explains:"))
+        .expect("rationale log missing");
+    assert_eq!(
+        explained.body,
+        "{{player 0}} explains: Taking the lead before the merger."
+    );
+    assert!(explained.is_public);
+}
+
 #[sqlx::test]
 #[serial]
 async fn stale_conflict_republishes_bot_turn_with_incremented_attempt(pool: PgPool) {
@@ -386,6 +418,7 @@ async fn stale_conflict_republishes_bot_turn_with_incremented_attempt(pool: PgPo
         player_position: bot_pos,
         command: "abc".to_string(),
         attempt: 0,
+        rationale: None,
     };
     let _ = handle_bot_command_event(&pool, &http_client, &broadcaster, &jetstream, &None, &event)
         .await;
@@ -482,6 +515,7 @@ async fn attempt_limit_exhaustion_gives_up(pool: PgPool) {
             player_position: bot_pos,
             command: "abc".to_string(),
             attempt,
+            rationale: None,
         };
         let _ =
             handle_bot_command_event(&pool, &http_client, &broadcaster, &jetstream, &None, &event)
@@ -523,6 +557,7 @@ async fn bot_command_delivered_exactly_once_across_two_fetchers(pool: PgPool) {
             player_position: 0,
             command: format!("marker:{}", marker),
             attempt: 0,
+            rationale: None,
         };
         let payload = serde_json::to_vec(&event).unwrap();
         jetstream
@@ -626,6 +661,7 @@ async fn max_deliver_exhaustion_emits_parseable_advisory(pool: PgPool) {
         player_position: 0,
         command: "advisory-test".to_string(),
         attempt: 0,
+        rationale: None,
     };
     let ack = jetstream
         .publish(
@@ -762,6 +798,7 @@ async fn user_error_republishes_bot_turn_with_incremented_attempt(pool: PgPool)
         player_position: bot_pos,
         command: "abc".to_string(),
         attempt: 0,
+        rationale: None,
     };
     let _ = handle_bot_command_event(&pool, &http_client, &broadcaster, &jetstream, &None, &event)
         .await;
@@ -836,6 +873,7 @@ async fn user_error_attempt_limit_exhaustion_gives_up(pool: PgPool) {
             player_position: bot_pos,
             command: "abc".to_string(),
             attempt,
+            rationale: None,
         };
         let _ =
             handle_bot_command_event(&pool, &http_client, &broadcaster, &jetstream, &None, &event)
@@ -913,6 +951,7 @@ async fn conflict_republish_targets_only_the_conflicting_bot(pool: PgPool) {
         player_position: conflicting_pos,
         command: "abc".to_string(),
         attempt: 0,
+        rationale: None,
     };
     let _ = handle_bot_command_event(&pool, &http_client, &broadcaster, &jetstream, &None, &event)
         .await;
@@ -1088,6 +1127,7 @@ async fn transient_failure_redelivers_command_well_inside_ack_wait(pool: PgPool)
         player_position: 0,
         command: "abc".to_string(),
         attempt: 0,
+        rationale: None,
     };
     let ack = jetstream
         .publish(